@@ -0,0 +1,327 @@
+use std::path::{Path, PathBuf};
+
+use image::RgbImage;
+use sha2::{Digest, Sha256};
+
+use crate::cancel::CancelToken;
+use crate::compress::Compression;
+use crate::config::{FrameConfig, Workspace, PROTOCOL_VERSION};
+use crate::crypto::Token;
+use crate::encode::{self, encode_segment, segment_path, work_dir_for};
+use crate::error::{Result, VstorageError};
+use crate::index::{PayloadEntry, PayloadIndex};
+use crate::memory::render_frame;
+use crate::{collect, crypto, frame, header, progress, video};
+
+/// Add `input_path` as a new payload at the end of an existing vstorage
+/// video, followed by an updated index frame.
+///
+/// The frames already in the video are stream-copied, not re-encoded; the new
+/// frames use the block size, levels and ECC of the existing ones and the
+/// codec, pixel format and frame rate of its video stream. Returns the id
+/// of the new payload (pass it to decode with `--payload`). A password's key
+/// takes the `hardware` token's response if the video's keys do; the frames
+/// are written in `workspace`.
+pub fn append(
+    existing: &Path,
+    input_path: &Path,
+    password: Option<&str>,
+    crf: u8,
+    hardware: Option<&dyn Token>,
+    workspace: &Workspace,
+) -> Result<u16> {
+    video::check_ffmpeg()?;
+    if crate::s3::S3Url::parse(existing).is_some() {
+        return Err(VstorageError::Config(
+            "append rewrites the video in place; download it first".into(),
+        ));
+    }
+
+    let data = std::fs::read(input_path)?;
+    let file_size = data.len() as u64;
+    tracing::info!("Read {} bytes from {}", data.len(), input_path.display());
+
+    let work_dir = work_dir_for(existing, workspace);
+    if work_dir.exists() {
+        std::fs::remove_dir_all(&work_dir)?;
+    }
+    let frames_dir = work_dir.join("frames");
+    std::fs::create_dir_all(&frames_dir)?;
+
+    // Append takes no cancel token, so its ffmpeg runs go to the end
+    let cancel = CancelToken::new();
+
+    // 1. Parameters of the existing video
+    let info = video::probe_video(existing)?;
+    let first_png = work_dir.join("first.png");
+    video::extract_frame(existing, 0, &first_png, &cancel)?;
+    let first_img = video::load_frame(&first_png)?;
+    let (first_header, mut config) = collect::detect_config_from_frame(&first_img, None)?;
+    config.fps = info.fps;
+    config.crf = crf;
+    config.codec = info.codec;
+    config.workspace = workspace.clone();
+    if info.pix_fmt == "yuv420p" {
+        config.enable_yuv420p()?;
+    }
+    tracing::info!(
+        "Existing video: {} frames, {} {}, block_size={}, levels={}, ecc={}",
+        info.frames,
+        info.codec,
+        info.pix_fmt,
+        config.block_size,
+        config.levels,
+        config.ecc_len
+    );
+
+    // 2. Current index: from the trailing index frame, the leading one of a
+    // packed video, or just payload 0
+    let last_png = work_dir.join("last.png");
+    video::extract_frame(existing, info.frames - 1, &last_png, &cancel)?;
+    let last_img = video::load_frame(&last_png)?;
+    let (mut index, generation) = current_index(&config, &first_img, &first_header, &last_img)?;
+    let generation = generation + 1;
+
+    // 3. Frames of the new payload
+    let payload = index.next_payload();
+    if payload == header::INDEX_PAYLOAD {
+        return Err(VstorageError::Config(
+            "too many payloads in this video".into(),
+        ));
+    }
+    let (cipher, nonce, salt) = match password {
+        Some(pw) => {
+            let (cipher, n, s) =
+                crypto::FrameCipher::generate(pw, config.kdf, config.token, hardware)?;
+            (Some(cipher), n, s)
+        }
+        None => (None, [0u8; 12], [0u8; 16]),
+    };
+    let per_frame = config.max_plain_per_frame(cipher.is_some());
+    if per_frame == 0 {
+        return Err(VstorageError::Config(
+            "frame capacity is zero — check block_size/levels/ecc settings".into(),
+        ));
+    }
+    let num_frames = data.len().div_ceil(per_frame).max(1);
+    let context =
+        data_header(&config, payload, 0, num_frames, file_size, nonce, salt).cipher_context();
+    let cipher = cipher.map(|c| c.with_context(context));
+
+    index.entries.push(PayloadEntry {
+        payload,
+        name: input_path
+            .file_name()
+            .map_or(String::new(), |n| n.to_string_lossy().into_owned()),
+        file_size,
+        total_frames: num_frames as u32,
+        first_frame: info.frames as u32,
+        sha256: Some(Sha256::digest(&data).into()),
+    });
+    let index_bytes = index.serialize();
+    if index_bytes.len() > config.max_raw_per_frame() {
+        return Err(VstorageError::Config(
+            "payload index does not fit in one frame".into(),
+        ));
+    }
+
+    let new_frames = num_frames + 1;
+    let pb = progress::frames(new_frames as u64);
+    let segment_frames = encode::segment_frames(&config)?;
+    let mut segments_done = 0;
+    for i in 0..new_frames {
+        let img = if i < num_frames {
+            let start = i * per_frame;
+            let end = std::cmp::min(start + per_frame, data.len());
+            let chunk = match &cipher {
+                Some(c) => c.encrypt_frame(i as u32, &data[start..end])?,
+                None => data[start..end].to_vec(),
+            };
+            let hdr = data_header(&config, payload, i, num_frames, file_size, nonce, salt);
+            render_frame(&config, hdr, &chunk)
+        } else {
+            render_frame(&config, index_header(&config, generation), &index_bytes)
+        };
+        video::save_frame(
+            &img,
+            &video::frame_path(&frames_dir, i + 1, workspace.intermediate),
+        )?;
+
+        if (i + 1) % segment_frames == 0 || i + 1 == new_frames {
+            encode_segment(
+                &work_dir,
+                segments_done,
+                segment_frames,
+                new_frames,
+                &config,
+                &cancel,
+            )?;
+            segments_done += 1;
+        }
+        pb.inc(1);
+    }
+    pb.finish_with_message(format!("{new_frames} frames encoded"));
+
+    // 4. Re-mux into a temporary file, then replace the original
+    let segments: Vec<PathBuf> = (0..segments_done)
+        .map(|s| segment_path(&work_dir, s))
+        .collect();
+    let tmp = work_dir.join(
+        existing
+            .file_name()
+            .ok_or_else(|| VstorageError::Config("invalid video path".into()))?,
+    );
+    video::append_segments(
+        existing,
+        &segments,
+        &tmp,
+        &config,
+        info.frames + new_frames,
+        &cancel,
+    )?;
+    std::fs::rename(&tmp, existing).or_else(|_| std::fs::copy(&tmp, existing).map(|_| ()))?;
+    video::remove_work_dir(&work_dir, workspace)?;
+
+    tracing::info!(
+        "Appended {} as payload {payload} ({num_frames} frames)",
+        input_path.display()
+    );
+    Ok(payload)
+}
+
+/// The payload index in effect and its generation: the trailing index frame
+/// written by the last append, else a leading one (see [`crate::pack`]),
+/// else the single payload described by the first frame (generation 0). A
+/// streamed payload (see [`header::FrameHeader::streamed`]) describes no
+/// such payload, so it is refused.
+pub(crate) fn current_index(
+    config: &FrameConfig,
+    first_img: &RgbImage,
+    first_header: &header::FrameHeader,
+    last_img: &RgbImage,
+) -> Result<(PayloadIndex, u32)> {
+    let header_bytes = frame::decode_header_area(last_img, config.block_size, config.levels);
+    match header::decode_header_triple(&header_bytes) {
+        Ok(hdr) if hdr.kind == header::KIND_INDEX => Ok((
+            collect::decode_index_frame(last_img, config, &hdr)?,
+            hdr.frame_number,
+        )),
+        _ if first_header.kind == header::KIND_INDEX => Ok((
+            collect::decode_index_frame(first_img, config, first_header)?,
+            first_header.frame_number,
+        )),
+        _ if first_header.streamed => Err(VstorageError::Config(
+            "the video was encoded from a stream, so only its last data frame records the \
+             file's size; decode it in full"
+                .into(),
+        )),
+        _ => Ok((initial_index(first_header), 0)),
+    }
+}
+
+/// Index of a video that has never been appended to: the single payload
+/// described by its first frame, starting after it if it is an intro frame.
+pub(crate) fn initial_index(first_header: &header::FrameHeader) -> PayloadIndex {
+    PayloadIndex {
+        entries: vec![PayloadEntry {
+            payload: 0,
+            name: String::new(),
+            file_size: first_header.file_size,
+            total_frames: first_header.total_frames,
+            first_frame: u32::from(first_header.kind == header::KIND_INTRO),
+            sha256: None,
+        }],
+    }
+}
+
+pub(crate) fn data_header(
+    config: &FrameConfig,
+    payload: u16,
+    frame_number: usize,
+    total_frames: usize,
+    file_size: u64,
+    nonce: [u8; 12],
+    salt: [u8; 16],
+) -> header::FrameHeader {
+    header::FrameHeader {
+        version: PROTOCOL_VERSION,
+        frame_number: frame_number as u32,
+        total_frames: total_frames as u32,
+        block_size: config.block_size,
+        levels: config.levels,
+        file_size,
+        data_length: 0,
+        ecc_len: config.ecc_len,
+        rs_data_len: config.rs_data_len() as u16,
+        nonce,
+        salt,
+        data_sha256: [0; 32],
+        kind: header::KIND_DATA,
+        payload,
+        part: 0,
+        total_parts: 1,
+        set_id: [0; 16],
+        kdf: config.kdf,
+        token: config.token,
+        // Appended payloads are stored as they are
+        compression: Compression::None,
+        data_levels: config.levels,
+        channel_levels: config.channel_levels(),
+        layout: config.layout,
+        crc_map: config.crc_map,
+        bottom_header: config.bottom_header,
+        banner: config.banner,
+        barcode: config.barcode,
+        pilots: config.pilots,
+        bin_centers: config.bin_centers,
+        streamed: false,
+    }
+}
+
+/// Header of an index frame; `frame_number` holds the index generation.
+pub(crate) fn index_header(config: &FrameConfig, generation: u32) -> header::FrameHeader {
+    header::FrameHeader {
+        kind: header::KIND_INDEX,
+        ..data_header(
+            config,
+            header::INDEX_PAYLOAD,
+            generation as usize,
+            1,
+            0,
+            [0; 12],
+            [0; 16],
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_index_frame_roundtrip() {
+        let config = FrameConfig::new(2, 4, 32, 30, 18).unwrap();
+        let first = data_header(&config, 0, 0, 3, 1000, [0; 12], [0; 16]);
+        let mut index = initial_index(&first);
+        assert_eq!(index.next_payload(), 1);
+        index.entries.push(PayloadEntry {
+            payload: 1,
+            name: "b.bin".into(),
+            file_size: 10,
+            total_frames: 1,
+            first_frame: 3,
+            sha256: Some([1; 32]),
+        });
+
+        let img = render_frame(&config, index_header(&config, 4), &index.serialize());
+        let header_bytes = frame::decode_header_area(&img, config.block_size, config.levels);
+        let hdr = header::decode_header_triple(&header_bytes).unwrap();
+        assert_eq!(hdr.kind, header::KIND_INDEX);
+        assert_eq!(hdr.payload, header::INDEX_PAYLOAD);
+        assert_eq!(hdr.frame_number, 4);
+        assert_eq!(
+            collect::decode_index_frame(&img, &config, &hdr).unwrap(),
+            index
+        );
+    }
+}
@@ -0,0 +1,459 @@
+use std::collections::VecDeque;
+use std::path::{Path, PathBuf};
+
+use image::RgbImage;
+use zeroize::Zeroizing;
+
+use crate::cancel::CancelToken;
+use crate::collect::{self, expected_chunk_len, hash_matches, locate_damage};
+use crate::compress::Compression;
+use crate::config::{FrameConfig, Workspace};
+use crate::crypto::Token;
+use crate::error::{Result, VstorageError};
+use crate::header::{self, FrameHeader};
+use crate::{crypto, decode, ecc, frame, video};
+
+/// Frames extracted together when a read misses the cache, so sequential
+/// reads start one ffmpeg per run of frames rather than per frame.
+pub const READAHEAD_FRAMES: usize = 8;
+
+/// Where an [`Archive`] reads frame images from.
+pub trait FrameSource {
+    /// Frames in the video.
+    fn frame_count(&self) -> usize;
+
+    /// Frames `first..first + count` (0-based positions in the video).
+    fn frames(&mut self, first: usize, count: usize) -> Result<Vec<RgbImage>>;
+}
+
+/// Frames already in memory, e.g. from [`crate::memory::encode_bytes`].
+impl FrameSource for Vec<RgbImage> {
+    fn frame_count(&self) -> usize {
+        self.len()
+    }
+
+    fn frames(&mut self, first: usize, count: usize) -> Result<Vec<RgbImage>> {
+        Ok(self[first..(first + count).min(self.len())].to_vec())
+    }
+}
+
+/// A video file; frames are extracted with ffmpeg as they are asked for.
+pub struct VideoSource {
+    path: PathBuf,
+    frames: usize,
+    temp_dir: tempfile::TempDir,
+    workspace: Workspace,
+    cancel: CancelToken,
+}
+
+impl VideoSource {
+    /// Open `path`, extracting frames in `workspace` and stopping an
+    /// extraction under way once `cancel` is cancelled.
+    pub fn open(path: &Path, workspace: &Workspace, cancel: &CancelToken) -> Result<Self> {
+        video::check_ffmpeg()?;
+        Ok(Self {
+            path: path.to_path_buf(),
+            frames: video::probe_video(path)?.frames,
+            temp_dir: video::temp_dir(workspace)?,
+            workspace: workspace.clone(),
+            cancel: cancel.clone(),
+        })
+    }
+}
+
+impl FrameSource for VideoSource {
+    fn frame_count(&self) -> usize {
+        self.frames
+    }
+
+    fn frames(&mut self, first: usize, count: usize) -> Result<Vec<RgbImage>> {
+        let dir = self.temp_dir.path().join(format!("frames_{first}"));
+        std::fs::create_dir(&dir)?;
+        let format = self.workspace.intermediate;
+        let images =
+            video::extract_frame_range(&self.path, first, count, &dir, format, None, &self.cancel)
+                .and_then(|_| {
+                    (1..=count)
+                        .map(|i| video::load_frame(&video::frame_path(&dir, i, format)))
+                        .collect()
+                });
+        if !self.workspace.keep_frames {
+            std::fs::remove_dir_all(&dir)?;
+        }
+        images
+    }
+}
+
+/// Payload and frame number of a cached frame.
+type FrameKey = (u16, usize);
+
+/// Least recently used decoded frames, keyed by payload and frame number.
+struct FrameCache {
+    capacity: usize,
+    entries: VecDeque<(FrameKey, Zeroizing<Vec<u8>>)>,
+}
+
+impl FrameCache {
+    fn new(capacity: usize) -> Self {
+        Self {
+            capacity: capacity.max(1),
+            entries: VecDeque::new(),
+        }
+    }
+
+    fn get(&mut self, key: FrameKey) -> Option<&[u8]> {
+        let pos = self.entries.iter().position(|(k, _)| *k == key)?;
+        let entry = self.entries.remove(pos)?;
+        self.entries.push_back(entry);
+        self.entries.back().map(|(_, data)| data.as_slice())
+    }
+
+    fn insert(&mut self, key: FrameKey, data: Zeroizing<Vec<u8>>) {
+        self.entries.retain(|(k, _)| *k != key);
+        if self.entries.len() == self.capacity {
+            self.entries.pop_front();
+        }
+        self.entries.push_back((key, data));
+    }
+}
+
+/// One payload of an archive, readable as a file.
+pub struct ArchiveFile {
+    /// Unique file name: the path stored by pack or append, or
+    /// `payload_<id>`
+    pub name: String,
+    pub payload: u16,
+    pub size: u64,
+    /// SHA-256 of the whole file, if the index records one
+    pub sha256: Option<[u8; 32]>,
+    /// Position of the payload's first frame in the video
+    first_frame: usize,
+    total_frames: usize,
+    cipher: Option<crypto::FrameCipher>,
+}
+
+/// Random access to the payloads stored in a video, decoding only the frames
+/// a read touches and keeping recently used ones in an LRU cache.
+pub struct Archive {
+    source: Box<dyn FrameSource>,
+    config: FrameConfig,
+    pub files: Vec<ArchiveFile>,
+    cache: FrameCache,
+}
+
+impl Archive {
+    /// Read the layout and payload index of `source`. Each encrypted payload's
+    /// first frame is decrypted here (with the `hardware` token if its key
+    /// needs one), so a wrong password fails now.
+    pub fn open(
+        source: Box<dyn FrameSource>,
+        password: Option<&str>,
+        hardware: Option<&dyn Token>,
+        cache_frames: usize,
+    ) -> Result<Self> {
+        Self::open_files(source, password, hardware, cache_frames, |_, _| true)
+    }
+
+    /// Like [`Archive::open`], but with only the file called `name`, so no
+    /// other payload's frames are read.
+    pub fn open_file(
+        source: Box<dyn FrameSource>,
+        password: Option<&str>,
+        hardware: Option<&dyn Token>,
+        cache_frames: usize,
+        name: &str,
+    ) -> Result<Self> {
+        let archive = Self::open_files(source, password, hardware, cache_frames, |n, _| n == name)?;
+        if archive.files.is_empty() {
+            return Err(VstorageError::Config(format!(
+                "no file named {name} in the video"
+            )));
+        }
+        Ok(archive)
+    }
+
+    /// Like [`Archive::open_file`], for the file of payload `payload`.
+    pub fn open_payload(
+        source: Box<dyn FrameSource>,
+        password: Option<&str>,
+        hardware: Option<&dyn Token>,
+        cache_frames: usize,
+        payload: u16,
+    ) -> Result<Self> {
+        let archive = Self::open_files(source, password, hardware, cache_frames, |_, p| {
+            p == payload
+        })?;
+        if archive.files.is_empty() {
+            return Err(VstorageError::Config(format!(
+                "payload {payload} not found in the video"
+            )));
+        }
+        Ok(archive)
+    }
+
+    fn open_files(
+        mut source: Box<dyn FrameSource>,
+        password: Option<&str>,
+        hardware: Option<&dyn Token>,
+        cache_frames: usize,
+        keep: impl Fn(&str, u16) -> bool,
+    ) -> Result<Self> {
+        if source.frame_count() == 0 {
+            return Err(VstorageError::Config("the video has no frames".into()));
+        }
+        let first_img = frame_at(source.as_mut(), 0)?;
+        let (first_header, config) = collect::detect_config_from_frame(&first_img, None)?;
+        if first_header.total_parts > 1 {
+            return Err(VstorageError::Config(
+                "split encodings cannot be mounted; decode them instead".into(),
+            ));
+        }
+
+        let last = source.frame_count() - 1;
+        let last_img = match last {
+            0 => first_img.clone(),
+            n => frame_at(source.as_mut(), n)?,
+        };
+        let (index, _) =
+            crate::append::current_index(&config, &first_img, &first_header, &last_img)?;
+
+        let mut archive = Self {
+            source,
+            config,
+            files: Vec::new(),
+            cache: FrameCache::new(cache_frames),
+        };
+        let mut names: Vec<String> = Vec::new();
+        for entry in &index.entries {
+            let mut name = entry.name.clone();
+            if name.is_empty() || names.contains(&name) {
+                name = format!("payload_{}", entry.payload);
+            }
+            names.push(name.clone());
+            if !keep(&name, entry.payload) {
+                continue;
+            }
+            let img = match entry.first_frame {
+                0 => first_img.clone(),
+                n => frame_at(archive.source.as_mut(), n as usize)?,
+            };
+            let hdr = read_header(&img, &archive.config)?;
+            if hdr.compression != Compression::None {
+                return Err(VstorageError::Config(format!(
+                    "{name} is stored with {} compression and cannot be read in place; \
+                     decode it instead",
+                    hdr.compression
+                )));
+            }
+            archive.files.push(ArchiveFile {
+                name,
+                payload: entry.payload,
+                size: entry.file_size,
+                sha256: entry.sha256,
+                first_frame: entry.first_frame as usize,
+                total_frames: entry.total_frames as usize,
+                cipher: collect::cipher_for(&hdr, password, hardware)?,
+            });
+            let file = archive.files.len() - 1;
+            let chunk = archive.decode_frame(file, 0, &img).map_err(|e| match e {
+                VstorageError::AuthFailed { .. } => VstorageError::WrongPassword,
+                e => e,
+            })?;
+            archive.cache.insert((entry.payload, 0), chunk);
+        }
+        tracing::info!(
+            "Archive: {} file(s), block_size={}, levels={}, ecc={}",
+            archive.files.len(),
+            archive.config.block_size,
+            archive.config.levels,
+            archive.config.ecc_len
+        );
+        Ok(archive)
+    }
+
+    /// Up to `len` bytes of file `file` (an index into [`Archive::files`]) from
+    /// `offset`; shorter at the end of the file.
+    pub fn read(&mut self, file: usize, offset: u64, len: usize) -> Result<Vec<u8>> {
+        let f = &self.files[file];
+        let end = offset.saturating_add(len as u64).min(f.size);
+        if offset >= end {
+            return Ok(Vec::new());
+        }
+        let per_frame = self.config.max_plain_per_frame(f.cipher.is_some()) as u64;
+        let (first, last) = decode::frame_span(offset, end - offset, per_frame);
+
+        let mut data = Vec::with_capacity((end - offset) as usize);
+        for index in first..=last {
+            let frame_start = index as u64 * per_frame;
+            let from = offset.saturating_sub(frame_start) as usize;
+            let to = (end - frame_start).min(per_frame) as usize;
+            data.extend_from_slice(&self.frame_data(file, index)?[from..to]);
+        }
+        Ok(data)
+    }
+
+    /// Plaintext of frame `index` of a file, from the cache or decoded along
+    /// with the frames after it.
+    fn frame_data(&mut self, file: usize, index: usize) -> Result<&[u8]> {
+        let payload = self.files[file].payload;
+        if self.cache.get((payload, index)).is_none() {
+            let (first_frame, total) =
+                (self.files[file].first_frame, self.files[file].total_frames);
+            let count = READAHEAD_FRAMES.min(self.cache.capacity).min(total - index);
+            let images = self.source.frames(first_frame + index, count)?;
+            if images.is_empty() {
+                return Err(VstorageError::MissingFrames {
+                    missing: vec![index],
+                    total,
+                    missing_parts: Vec::new(),
+                    total_parts: 1,
+                });
+            }
+            // Insert the requested frame last so the readahead cannot evict it
+            for (k, img) in images.iter().enumerate().rev() {
+                let chunk = self.decode_frame(file, index + k, img)?;
+                self.cache.insert((payload, index + k), chunk);
+            }
+        }
+        Ok(self.cache.get((payload, index)).expect("just cached"))
+    }
+
+    /// RS-decode and decrypt frame `index` of a file. An unencrypted frame
+    /// whose header is unreadable has no hash to check, so it is refused.
+    fn decode_frame(
+        &self,
+        file: usize,
+        index: usize,
+        img: &RgbImage,
+    ) -> Result<Zeroizing<Vec<u8>>> {
+        let f = &self.files[file];
+        let config = &self.config;
+        let header = read_header(img, config).ok();
+        if let Some(hdr) = &header {
+            if hdr.payload != f.payload || hdr.frame_number as usize != index {
+                return Err(VstorageError::Header(format!(
+                    "expected frame {} of payload {}, found frame {} of payload {}",
+                    index + 1,
+                    f.payload,
+                    hdr.frame_number + 1,
+                    hdr.payload
+                )));
+            }
+        }
+        let encrypted = f.cipher.is_some();
+        let levels = header
+            .as_ref()
+            .map_or(config.channel_levels(), |h| h.area_levels());
+        let data_bytes = frame::decode_data_area(img, config, levels, index as u32);
+        let chunk = ecc::rs_decode(
+            &data_bytes,
+            config.ecc_len as usize,
+            config.rs_data_len(),
+            expected_chunk_len(config, f.size, encrypted, index),
+            config.worker_threads(),
+        )
+        .map_err(|e| locate_damage(e, config, f.size, encrypted, index))?;
+        match (&f.cipher, header) {
+            (Some(c), _) => c.decrypt_frame(index as u32, &chunk),
+            (None, Some(h)) if hash_matches(&chunk, config, &h.data_sha256) => {
+                Ok(Zeroizing::new(chunk))
+            }
+            (None, _) => Err(VstorageError::HashMismatch { frame: index }),
+        }
+    }
+}
+
+fn frame_at(source: &mut dyn FrameSource, position: usize) -> Result<RgbImage> {
+    source
+        .frames(position, 1)?
+        .pop()
+        .ok_or_else(|| VstorageError::Ffmpeg(format!("could not read frame {}", position + 1)))
+}
+
+fn read_header(img: &RgbImage, config: &FrameConfig) -> Result<FrameHeader> {
+    let header_bytes = frame::decode_header_area(img, config.block_size, config.levels);
+    Ok(header::decode_header_triple(&header_bytes)?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::memory::{encode_bytes, EncodeOptions};
+
+    #[test]
+    fn test_frame_cache_evicts_least_recent() {
+        let mut cache = FrameCache::new(2);
+        cache.insert((0, 0), Zeroizing::new(vec![0]));
+        cache.insert((0, 1), Zeroizing::new(vec![1]));
+        assert_eq!(cache.get((0, 0)), Some(&[0u8][..]));
+        cache.insert((1, 0), Zeroizing::new(vec![2]));
+        assert!(cache.get((0, 1)).is_none());
+        assert_eq!(cache.get((0, 0)), Some(&[0u8][..]));
+        assert_eq!(cache.get((1, 0)), Some(&[2u8][..]));
+    }
+
+    #[test]
+    fn test_read_across_frames() {
+        let config = FrameConfig::new(8, 2, 32, 30, 18).unwrap();
+        let per_frame = config.max_plain_per_frame(true);
+        let data = crate::testutil::pattern(per_frame * 2 + 50, 1, 251);
+        let options = EncodeOptions {
+            password: Some("hunter2".into()),
+            ..EncodeOptions::new(config)
+        };
+        let frames = encode_bytes(&data, &options).unwrap();
+
+        let mut archive =
+            Archive::open(Box::new(frames.clone()), Some("hunter2"), None, 2).unwrap();
+        assert_eq!(archive.files.len(), 1);
+        assert_eq!(archive.files[0].name, "payload_0");
+        let (offset, len) = (per_frame - 10, per_frame + 20);
+        assert_eq!(
+            archive.read(0, offset as u64, len).unwrap(),
+            &data[offset..offset + len]
+        );
+        let tail = archive.read(0, data.len() as u64 - 5, 100).unwrap();
+        assert_eq!(tail, &data[data.len() - 5..]);
+
+        let single =
+            Archive::open_payload(Box::new(frames.clone()), Some("hunter2"), None, 2, 0).unwrap();
+        assert_eq!(single.files.len(), 1);
+        assert!(
+            Archive::open_payload(Box::new(frames.clone()), Some("hunter2"), None, 2, 1).is_err()
+        );
+
+        assert!(matches!(
+            Archive::open(Box::new(frames), Some("wrong"), None, 2),
+            Err(VstorageError::WrongPassword)
+        ));
+    }
+
+    #[test]
+    fn test_unreadable_header_refused() {
+        let config = FrameConfig::new(8, 2, 32, 30, 18).unwrap();
+        let per_frame = config.max_plain_per_frame(false);
+        let data = crate::testutil::pattern(per_frame * 2, 1, 239);
+        let options = EncodeOptions::new(config.clone());
+        let mut frames = encode_bytes(&data, &options).unwrap();
+        // Every copy of the second frame's header wiped, its data left intact
+        let bs = config.block_size as u32;
+        let (top, bottom) = (
+            config.header_rows as u32 * bs,
+            config.bottom_header_rows() as u32 * bs,
+        );
+        let height = frames[1].height();
+        for y in (0..top).chain(height - bottom..height) {
+            for x in 0..frames[1].width() {
+                frames[1].put_pixel(x, y, image::Rgb([0, 0, 0]));
+            }
+        }
+        assert!(read_header(&frames[1], &config).is_err());
+
+        let mut archive = Archive::open(Box::new(frames), None, None, 2).unwrap();
+        assert_eq!(archive.read(0, 0, 10).unwrap(), &data[..10]);
+        assert!(matches!(
+            archive.read(0, per_frame as u64, 10),
+            Err(VstorageError::HashMismatch { frame: 1 })
+        ));
+    }
+}
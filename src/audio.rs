@@ -0,0 +1,357 @@
+use std::path::Path;
+
+use zeroize::Zeroizing;
+
+use crate::error::{Result, VstorageError};
+use crate::{crypto, ecc};
+
+pub const SAMPLE_RATE: u32 = 48_000;
+/// Samples per FSK symbol (5 ms). Every tone completes a whole number of
+/// cycles per symbol, so the tones are orthogonal and phase-continuous.
+pub const SYMBOL_SAMPLES: usize = 240;
+const TONE_COUNT: usize = 16;
+const BASE_FREQ: f64 = 1200.0;
+const TONE_SPACING: f64 = 400.0;
+const AMPLITUDE: f64 = 12_000.0;
+
+const ECC_LEN: usize = 32;
+const RS_DATA_LEN: usize = 255 - ECC_LEN;
+/// How far into the track to search for the preamble (covers AAC priming delay).
+const MAX_SYNC_OFFSET: usize = 4096;
+const LENGTH_COPIES: usize = 3;
+
+const MAGIC: &[u8; 4] = b"VSAU";
+const FLAG_ENCRYPTED: u8 = 0x01;
+/// The ciphertext is a [`crypto::seal_stream`] stream.
+const FLAG_STREAM: u8 = 0x02;
+/// The key is derived with scrypt instead of Argon2id.
+const FLAG_SCRYPT: u8 = 0x04;
+// The top two bits hold the key's [`crypto::TokenMode`], as in a frame header
+const SEALED_HEADER_LEN: usize = 4 + 1 + 12 + 16;
+
+/// Known symbol sequence used to locate the start of the payload.
+const PREAMBLE: [u8; 32] = [
+    0, 15, 1, 14, 2, 13, 3, 12, 4, 11, 5, 10, 6, 9, 7, 8, 8, 7, 9, 6, 10, 5, 11, 4, 12, 3, 13, 2,
+    14, 1, 15, 0,
+];
+
+// ── Modem ───────────────────────────────────────────────────────────────────
+
+fn tone_freq(symbol: u8) -> f64 {
+    BASE_FREQ + symbol as f64 * TONE_SPACING
+}
+
+/// Modulate bytes as 16-FSK (one nibble per symbol), prefixed with a preamble
+/// and a triple-redundant length, and protected with Reed-Solomon.
+pub fn modulate(data: &[u8]) -> Vec<i16> {
+    let mut bytes = Vec::new();
+    for _ in 0..LENGTH_COPIES {
+        bytes.extend_from_slice(&(data.len() as u32).to_be_bytes());
+    }
+    // Far too few blocks to be worth splitting across threads
+    bytes.extend_from_slice(&ecc::rs_encode(data, ECC_LEN, RS_DATA_LEN, 1));
+
+    let mut symbols = PREAMBLE.to_vec();
+    for b in bytes {
+        symbols.push(b >> 4);
+        symbols.push(b & 0x0F);
+    }
+
+    let mut samples = Vec::with_capacity(symbols.len() * SYMBOL_SAMPLES);
+    for s in symbols {
+        let freq = tone_freq(s);
+        for n in 0..SYMBOL_SAMPLES {
+            let t = n as f64 / SAMPLE_RATE as f64;
+            let v = AMPLITUDE * (2.0 * std::f64::consts::PI * freq * t).sin();
+            samples.push(v.round() as i16);
+        }
+    }
+    samples
+}
+
+/// Goertzel power of every tone over one symbol window.
+fn tone_powers(window: &[i16]) -> [f64; TONE_COUNT] {
+    let mut powers = [0.0; TONE_COUNT];
+    for (k, power) in powers.iter_mut().enumerate() {
+        let w = 2.0 * std::f64::consts::PI * tone_freq(k as u8) / SAMPLE_RATE as f64;
+        let coeff = 2.0 * w.cos();
+        let (mut s1, mut s2) = (0.0f64, 0.0f64);
+        for &x in window {
+            let s0 = x as f64 + coeff * s1 - s2;
+            s2 = s1;
+            s1 = s0;
+        }
+        *power = s1 * s1 + s2 * s2 - coeff * s1 * s2;
+    }
+    powers
+}
+
+fn detect_symbol(window: &[i16]) -> u8 {
+    let powers = tone_powers(window);
+    let mut best = 0;
+    for k in 1..TONE_COUNT {
+        if powers[k] > powers[best] {
+            best = k;
+        }
+    }
+    best as u8
+}
+
+/// Fraction of preamble energy landing on the expected tones at `offset`.
+fn preamble_score(samples: &[i16], offset: usize) -> f64 {
+    let mut score = 0.0;
+    for (i, &expected) in PREAMBLE.iter().enumerate() {
+        let start = offset + i * SYMBOL_SAMPLES;
+        let powers = tone_powers(&samples[start..start + SYMBOL_SAMPLES]);
+        let total: f64 = powers.iter().sum();
+        if total > 0.0 {
+            score += powers[expected as usize] / total;
+        }
+    }
+    score
+}
+
+fn best_offset(samples: &[i16], offsets: impl Iterator<Item = usize>) -> (usize, f64) {
+    offsets
+        .map(|o| (o, preamble_score(samples, o)))
+        .fold(
+            (0, f64::MIN),
+            |best, cur| if cur.1 > best.1 { cur } else { best },
+        )
+}
+
+fn find_sync(samples: &[i16]) -> Option<usize> {
+    let preamble_len = PREAMBLE.len() * SYMBOL_SAMPLES;
+    if samples.len() < preamble_len {
+        return None;
+    }
+    let last = MAX_SYNC_OFFSET.min(samples.len() - preamble_len);
+
+    // Coarse search, then refine around the best coarse hit.
+    let (coarse, _) = best_offset(samples, (0..=last).step_by(8));
+    let lo = coarse.saturating_sub(8);
+    let hi = (coarse + 8).min(last);
+    let (offset, score) = best_offset(samples, lo..=hi);
+
+    // Require most of the preamble energy to sit on the expected tones
+    if score < PREAMBLE.len() as f64 * 0.5 {
+        return None;
+    }
+    Some(offset)
+}
+
+fn read_bytes(samples: &[i16], start: usize, count: usize) -> Result<Vec<u8>> {
+    let end = start + count * 2 * SYMBOL_SAMPLES;
+    if end > samples.len() {
+        return Err(VstorageError::Audio(format!(
+            "audio track truncated: need {end} samples, have {}",
+            samples.len()
+        )));
+    }
+    let symbol = |i: usize| {
+        let s = start + i * SYMBOL_SAMPLES;
+        detect_symbol(&samples[s..s + SYMBOL_SAMPLES])
+    };
+    Ok((0..count)
+        .map(|i| (symbol(2 * i) << 4) | symbol(2 * i + 1))
+        .collect())
+}
+
+/// Demodulate bytes produced by [`modulate`].
+pub fn demodulate(samples: &[i16]) -> Result<Vec<u8>> {
+    let offset = find_sync(samples)
+        .ok_or_else(|| VstorageError::Audio("no side-channel preamble found".into()))?;
+    let mut pos = offset + PREAMBLE.len() * SYMBOL_SAMPLES;
+
+    let len_bytes = read_bytes(samples, pos, 4 * LENGTH_COPIES)?;
+    pos += len_bytes.len() * 2 * SYMBOL_SAMPLES;
+    let mut len = [0u8; 4];
+    for (i, b) in len.iter_mut().enumerate() {
+        let (x, y, z) = (len_bytes[i], len_bytes[4 + i], len_bytes[8 + i]);
+        *b = if x == y || x == z { x } else { y };
+    }
+    let data_len = u32::from_be_bytes(len) as usize;
+
+    let rs_len = data_len.div_ceil(RS_DATA_LEN) * 255;
+    let rs_bytes = read_bytes(samples, pos, rs_len)?;
+    ecc::rs_decode(&rs_bytes, ECC_LEN, RS_DATA_LEN, data_len, 1)
+}
+
+// ── Side-channel payload ────────────────────────────────────────────────────
+
+/// Seal `data` (encrypting it when a password is given, with `kdf` and
+/// `token`, answered by `hardware`) and modulate it.
+pub fn encode_side_channel(
+    data: &[u8],
+    password: Option<&str>,
+    kdf: crypto::Kdf,
+    token: crypto::TokenMode,
+    hardware: Option<&dyn crypto::Token>,
+) -> Result<Vec<i16>> {
+    let mut sealed = Vec::with_capacity(SEALED_HEADER_LEN + data.len());
+    sealed.extend_from_slice(MAGIC);
+    if let Some(pw) = password {
+        let (cipher, nonce, salt) = crypto::FrameCipher::generate(pw, kdf, token, hardware)?;
+        let kdf_flag = if kdf == crypto::Kdf::Scrypt {
+            FLAG_SCRYPT
+        } else {
+            0
+        };
+        sealed.push(FLAG_ENCRYPTED | FLAG_STREAM | kdf_flag | token.bits());
+        sealed.extend_from_slice(&nonce);
+        sealed.extend_from_slice(&salt);
+        sealed.extend(crypto::seal_stream(&cipher, data)?);
+    } else {
+        sealed.push(0);
+        sealed.extend_from_slice(&[0u8; 28]);
+        sealed.extend_from_slice(data);
+    }
+    Ok(modulate(&sealed))
+}
+
+/// Demodulate and open a payload produced by [`encode_side_channel`], with
+/// the `hardware` token if its key needs one.
+pub fn decode_side_channel(
+    samples: &[i16],
+    password: Option<&str>,
+    hardware: Option<&dyn crypto::Token>,
+) -> Result<Zeroizing<Vec<u8>>> {
+    let sealed = demodulate(samples)?;
+    if sealed.len() < SEALED_HEADER_LEN || &sealed[0..4] != MAGIC {
+        return Err(VstorageError::Audio("invalid side-channel payload".into()));
+    }
+    let body = &sealed[SEALED_HEADER_LEN..];
+    if sealed[4] & FLAG_ENCRYPTED == 0 {
+        return Ok(Zeroizing::new(body.to_vec()));
+    }
+    let token =
+        crypto::TokenMode::from_bits(sealed[4]).map_err(|e| VstorageError::Audio(e.to_string()))?;
+    let pw = match token {
+        crypto::TokenMode::Only => "",
+        _ => password.ok_or(VstorageError::PasswordRequired)?,
+    };
+    let nonce: [u8; 12] = sealed[5..17].try_into().unwrap();
+    let salt: [u8; 16] = sealed[17..33].try_into().unwrap();
+    let kdf = match sealed[4] & FLAG_SCRYPT {
+        0 => crypto::Kdf::Argon2id,
+        _ => crypto::Kdf::Scrypt,
+    };
+    crypto::open_stream(
+        &crypto::FrameCipher::new(pw, kdf, token, hardware, &salt, nonce)?,
+        body,
+    )
+}
+
+// ── WAV I/O ─────────────────────────────────────────────────────────────────
+
+/// Write mono 16-bit PCM samples as a WAV file.
+pub fn write_wav(path: &Path, samples: &[i16]) -> Result<()> {
+    let data_len = (samples.len() * 2) as u32;
+    let mut buf = Vec::with_capacity(44 + samples.len() * 2);
+    buf.extend_from_slice(b"RIFF");
+    buf.extend_from_slice(&(36 + data_len).to_le_bytes());
+    buf.extend_from_slice(b"WAVEfmt ");
+    buf.extend_from_slice(&16u32.to_le_bytes());
+    buf.extend_from_slice(&1u16.to_le_bytes()); // PCM
+    buf.extend_from_slice(&1u16.to_le_bytes()); // mono
+    buf.extend_from_slice(&SAMPLE_RATE.to_le_bytes());
+    buf.extend_from_slice(&(SAMPLE_RATE * 2).to_le_bytes());
+    buf.extend_from_slice(&2u16.to_le_bytes()); // block align
+    buf.extend_from_slice(&16u16.to_le_bytes()); // bits per sample
+    buf.extend_from_slice(b"data");
+    buf.extend_from_slice(&data_len.to_le_bytes());
+    for s in samples {
+        buf.extend_from_slice(&s.to_le_bytes());
+    }
+    std::fs::write(path, buf)?;
+    Ok(())
+}
+
+/// Read a 16-bit PCM WAV file, keeping only the first channel.
+pub fn read_wav(path: &Path) -> Result<Vec<i16>> {
+    let buf = std::fs::read(path)?;
+    if buf.len() < 12 || &buf[0..4] != b"RIFF" || &buf[8..12] != b"WAVE" {
+        return Err(VstorageError::Audio("not a WAV file".into()));
+    }
+
+    let mut channels = 0usize;
+    let mut pos = 12;
+    while pos + 8 <= buf.len() {
+        let id = &buf[pos..pos + 4];
+        let size = u32::from_le_bytes(buf[pos + 4..pos + 8].try_into().unwrap()) as usize;
+        let body = pos + 8;
+        // Streamed WAVs may carry a placeholder size; clamp to what is present
+        let end = body.saturating_add(size).min(buf.len());
+        if id == b"fmt " && end - body >= 16 {
+            let format = u16::from_le_bytes([buf[body], buf[body + 1]]);
+            let bits = u16::from_le_bytes([buf[body + 14], buf[body + 15]]);
+            if format != 1 || bits != 16 {
+                return Err(VstorageError::Audio("WAV must be 16-bit PCM".into()));
+            }
+            channels = u16::from_le_bytes([buf[body + 2], buf[body + 3]]) as usize;
+        } else if id == b"data" {
+            if channels == 0 {
+                return Err(VstorageError::Audio("WAV data before fmt chunk".into()));
+            }
+            return Ok(buf[body..end]
+                .chunks_exact(2 * channels)
+                .map(|frame| i16::from_le_bytes([frame[0], frame[1]]))
+                .collect());
+        }
+        pos = body + size + (size & 1);
+    }
+    Err(VstorageError::Audio("WAV has no data chunk".into()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_modem_roundtrip() {
+        let data = crate::testutil::pattern(300, 7, 256);
+        let samples = modulate(&data);
+        assert_eq!(demodulate(&samples).unwrap(), data);
+    }
+
+    #[test]
+    fn test_modem_offset_and_noise() {
+        let data = b"manifest: 3 entries".to_vec();
+        let mut samples = vec![0i16; 1500];
+        samples.extend(modulate(&data));
+
+        // Deterministic pseudo-noise, attenuation, and a trailing silence pad
+        let mut state = 0x1234_5678u32;
+        for s in samples.iter_mut() {
+            state = state.wrapping_mul(1_664_525).wrapping_add(1_013_904_223);
+            let noise = ((state >> 16) as i32 % 3000) - 1500;
+            *s = ((*s as i32 * 3 / 4) + noise).clamp(-32768, 32767) as i16;
+        }
+        samples.extend(vec![0i16; 4800]);
+
+        assert_eq!(demodulate(&samples).unwrap(), data);
+    }
+
+    #[test]
+    fn test_side_channel_encrypted() {
+        for kdf in [crypto::Kdf::Argon2id, crypto::Kdf::Scrypt] {
+            let samples =
+                encode_side_channel(b"parity", Some("pw"), kdf, crypto::TokenMode::Off, None)
+                    .unwrap();
+            assert!(decode_side_channel(&samples, None, None).is_err());
+            assert_eq!(
+                *decode_side_channel(&samples, Some("pw"), None).unwrap(),
+                b"parity"
+            );
+        }
+    }
+
+    #[test]
+    fn test_wav_roundtrip() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("side.wav");
+        let samples: Vec<i16> = (0..1000).map(|i| (i * 31 - 15000) as i16).collect();
+        write_wav(&path, &samples).unwrap();
+        assert_eq!(read_wav(&path).unwrap(), samples);
+    }
+}
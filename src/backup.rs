@@ -0,0 +1,523 @@
+use std::collections::btree_map::Entry;
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt;
+use std::io::{Read, Seek, SeekFrom, Write};
+use std::path::{Component, Path, PathBuf};
+use std::sync::Arc;
+
+use sha2::{Digest, Sha256};
+
+use crate::cancel::CancelToken;
+use crate::cdc;
+use crate::config::{FrameConfig, Workspace};
+use crate::crypto::Token;
+use crate::decode::{self, DecodeOptions};
+use crate::encode::{self, SplitLimits};
+use crate::error::{Result, VstorageError};
+use crate::progress::ProgressSink;
+use crate::video;
+
+const MANIFEST_MAGIC: &str = "vstorage-manifest 1";
+
+/// Where a chunk is stored: a byte range of the file encoded in a video.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ChunkRef {
+    /// Video file name, in the manifest's directory
+    pub video: String,
+    pub offset: u64,
+    pub len: u64,
+}
+
+/// One backed-up file.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FileEntry {
+    /// Relative to the backed-up directory, `/`-separated
+    pub path: String,
+    pub size: u64,
+    /// SHA-256 of each chunk, in order
+    pub chunks: Vec<[u8; 32]>,
+}
+
+/// Everything needed to restore one backup: its files, and where each of
+/// their chunks is stored (possibly in videos of earlier backups).
+///
+/// Stored as text, one record per line: `base`, `chunk` lines (hash, video,
+/// offset, length) and `file` lines (size, chunk hashes, path last).
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct Manifest {
+    /// File name of the manifest this backup was made against
+    pub base: Option<String>,
+    pub files: Vec<FileEntry>,
+    pub chunks: BTreeMap<[u8; 32], ChunkRef>,
+}
+
+impl Manifest {
+    pub fn load(path: &Path) -> Result<Self> {
+        Self::parse(&std::fs::read_to_string(path)?)
+    }
+
+    pub fn save(&self, path: &Path) -> Result<()> {
+        let tmp = path.with_extension("manifest.tmp");
+        std::fs::write(&tmp, self.serialize())?;
+        std::fs::rename(&tmp, path)?;
+        Ok(())
+    }
+
+    pub fn serialize(&self) -> String {
+        let mut text = format!("{MANIFEST_MAGIC}\n");
+        if let Some(base) = &self.base {
+            text += &format!("base\t{base}\n");
+        }
+        for (hash, c) in &self.chunks {
+            text += &format!(
+                "chunk\t{}\t{}\t{}\t{}\n",
+                hex(hash),
+                c.video,
+                c.offset,
+                c.len
+            );
+        }
+        for f in &self.files {
+            let chunks: Vec<String> = f.chunks.iter().map(hex).collect();
+            text += &format!("file\t{}\t{}\t{}\n", f.size, chunks.join(","), f.path);
+        }
+        text
+    }
+
+    pub fn parse(text: &str) -> Result<Self> {
+        let mut lines = text.lines().enumerate();
+        if lines.next().map(|(_, l)| l) != Some(MANIFEST_MAGIC) {
+            return Err(VstorageError::Config("not a vstorage manifest".into()));
+        }
+        let mut manifest = Manifest::default();
+        for (i, line) in lines {
+            let err = |msg: &str| VstorageError::Config(format!("manifest line {}: {msg}", i + 1));
+            let fields: Vec<&str> = line.splitn(4, '\t').collect();
+            match fields[..] {
+                ["base", name] => manifest.base = Some(name.to_string()),
+                ["chunk", hash, video, rest] => {
+                    let (offset, len) =
+                        rest.split_once('\t').ok_or_else(|| err("missing length"))?;
+                    let chunk = ChunkRef {
+                        video: video.to_string(),
+                        offset: offset.parse().map_err(|_| err("bad offset"))?,
+                        len: len.parse().map_err(|_| err("bad length"))?,
+                    };
+                    manifest
+                        .chunks
+                        .insert(parse_hash(hash).ok_or_else(|| err("bad hash"))?, chunk);
+                }
+                ["file", size, chunks, path] => {
+                    let chunks = chunks
+                        .split(',')
+                        .filter(|h| !h.is_empty())
+                        .map(parse_hash)
+                        .collect::<Option<Vec<_>>>()
+                        .ok_or_else(|| err("bad chunk hash"))?;
+                    if let Some(missing) = chunks.iter().find(|h| !manifest.chunks.contains_key(*h))
+                    {
+                        return Err(err(&format!("chunk {} has no location", hex(missing))));
+                    }
+                    manifest.files.push(FileEntry {
+                        path: path.to_string(),
+                        size: size.parse().map_err(|_| err("bad size"))?,
+                        chunks,
+                    });
+                }
+                [""] => {}
+                _ => return Err(err("unknown record")),
+            }
+        }
+        Ok(manifest)
+    }
+
+    /// Videos holding this backup's chunks.
+    pub fn videos(&self) -> BTreeSet<&str> {
+        self.chunks.values().map(|c| c.video.as_str()).collect()
+    }
+}
+
+fn hex(hash: &[u8; 32]) -> String {
+    hash.iter().map(|b| format!("{b:02x}")).collect()
+}
+
+fn parse_hash(s: &str) -> Option<[u8; 32]> {
+    if s.len() != 64 || !s.is_ascii() {
+        return None;
+    }
+    let mut hash = [0u8; 32];
+    for (i, byte) in hash.iter_mut().enumerate() {
+        *byte = u8::from_str_radix(&s[i * 2..i * 2 + 2], 16).ok()?;
+    }
+    Some(hash)
+}
+
+/// What [`backup`] did.
+#[derive(Debug, Clone)]
+pub struct BackupSummary {
+    pub manifest: PathBuf,
+    /// The delta video with the new chunks (`None` if nothing changed)
+    pub video: Option<PathBuf>,
+    pub files: usize,
+    pub chunks: usize,
+    pub new_chunks: usize,
+    pub new_bytes: u64,
+}
+
+impl fmt::Display for BackupSummary {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "Backed up {} files: {} of {} chunks new ({} bytes)",
+            self.files, self.new_chunks, self.chunks, self.new_bytes
+        )?;
+        match &self.video {
+            Some(video) => write!(f, " in {}", video.display())?,
+            None => write!(f, ", no video needed")?,
+        }
+        write!(f, "\nManifest: {}", self.manifest.display())
+    }
+}
+
+/// Back up `dir` as `<output>.manifest` plus a `<output>.mp4` holding the
+/// chunks that are not already stored by the `base` backup (whose videos
+/// must stay next to the new manifest).
+#[allow(clippy::too_many_arguments)]
+pub fn backup(
+    dir: &Path,
+    output: &Path,
+    base: Option<&Path>,
+    password: Option<&str>,
+    config: &FrameConfig,
+    progress: &dyn ProgressSink,
+    cancel: &CancelToken,
+) -> Result<BackupSummary> {
+    let base_manifest = base.map(Manifest::load).transpose()?;
+    let manifest_path = with_suffix(output, ".manifest");
+    let video_path = with_suffix(output, ".mp4");
+    let video_name = file_name(&video_path)?;
+
+    let pack_path = with_suffix(output, ".pack");
+    let mut pack = std::io::BufWriter::new(std::fs::File::create(&pack_path)?);
+    let scanned = scan_chunks(dir, base_manifest.as_ref(), &video_name, &mut pack, cancel);
+    drop(pack);
+    let (mut manifest, new_chunks, new_bytes) = match scanned {
+        Ok(scanned) => scanned,
+        Err(e) => {
+            let _ = std::fs::remove_file(&pack_path);
+            return Err(e);
+        }
+    };
+    manifest.base = base.map(file_name).transpose()?;
+
+    let video = if new_chunks > 0 {
+        tracing::info!("Encoding {new_chunks} new chunks ({new_bytes} bytes)");
+        let encoded = encode::encode(
+            &pack_path,
+            &video_path,
+            password,
+            &[],
+            config,
+            None,
+            &[],
+            None,
+            &SplitLimits::default(),
+            false,
+            progress,
+            cancel,
+        );
+        std::fs::remove_file(&pack_path)?;
+        encoded?;
+        Some(video_path)
+    } else {
+        std::fs::remove_file(&pack_path)?;
+        None
+    };
+    manifest.save(&manifest_path)?;
+    Ok(BackupSummary {
+        manifest: manifest_path,
+        video,
+        files: manifest.files.len(),
+        chunks: manifest.files.iter().map(|f| f.chunks.len()).sum(),
+        new_chunks,
+        new_bytes,
+    })
+}
+
+/// Split every file under `dir` into content-defined chunks, appending
+/// chunks not found in `base` (or earlier in this scan) to `pack` as the
+/// stored file of `video_name`. Returns the manifest, keeping only the chunk
+/// locations it uses.
+fn scan_chunks(
+    dir: &Path,
+    base: Option<&Manifest>,
+    video_name: &str,
+    pack: &mut impl Write,
+    cancel: &CancelToken,
+) -> Result<(Manifest, usize, u64)> {
+    let mut manifest = Manifest::default();
+    let (mut new_chunks, mut pack_len) = (0, 0u64);
+    let mut buf = vec![0u8; cdc::MAX_CHUNK];
+    for (rel, path) in list_files(dir)? {
+        cancel.check()?;
+        let mut file = std::fs::File::open(&path)?;
+        let mut entry = FileEntry {
+            path: rel,
+            size: 0,
+            chunks: Vec::new(),
+        };
+        let mut filled = 0;
+        loop {
+            filled += read_full(&mut file, &mut buf[filled..])?;
+            if filled == 0 {
+                break;
+            }
+            let n = cdc::cut(&buf[..filled]);
+            let hash: [u8; 32] = Sha256::digest(&buf[..n]).into();
+            if let Entry::Vacant(slot) = manifest.chunks.entry(hash) {
+                slot.insert(match base.and_then(|b| b.chunks.get(&hash)) {
+                    Some(location) => location.clone(),
+                    None => {
+                        pack.write_all(&buf[..n])?;
+                        new_chunks += 1;
+                        pack_len += n as u64;
+                        ChunkRef {
+                            video: video_name.to_string(),
+                            offset: pack_len - n as u64,
+                            len: n as u64,
+                        }
+                    }
+                });
+            }
+            entry.size += n as u64;
+            entry.chunks.push(hash);
+            buf.copy_within(n..filled, 0);
+            filled -= n;
+        }
+        manifest.files.push(entry);
+    }
+    pack.flush()?;
+    Ok((manifest, new_chunks, pack_len))
+}
+
+/// Regular files under `dir` as (relative `/`-separated path, full path),
+/// sorted. Symlinks, special files and names the manifest cannot hold are
+/// skipped with a warning.
+fn list_files(dir: &Path) -> Result<Vec<(String, PathBuf)>> {
+    let mut files = Vec::new();
+    let mut pending = vec![(String::new(), dir.to_path_buf())];
+    while let Some((prefix, dir)) = pending.pop() {
+        for entry in std::fs::read_dir(&dir)? {
+            let entry = entry?;
+            let path = entry.path();
+            let Some(name) = entry.file_name().to_str().map(str::to_string) else {
+                tracing::warn!("Skipping {}: name is not UTF-8", path.display());
+                continue;
+            };
+            if name.contains(['\n', '\r']) {
+                tracing::warn!("Skipping {}: name contains a line break", path.display());
+                continue;
+            }
+            let rel = if prefix.is_empty() {
+                name
+            } else {
+                format!("{prefix}/{name}")
+            };
+            let kind = entry.file_type()?;
+            if kind.is_dir() {
+                pending.push((rel, path));
+            } else if kind.is_file() {
+                files.push((rel, path));
+            } else {
+                tracing::warn!("Skipping {}: not a regular file", path.display());
+            }
+        }
+    }
+    files.sort();
+    Ok(files)
+}
+
+fn read_full(reader: &mut impl Read, buf: &mut [u8]) -> Result<usize> {
+    let mut filled = 0;
+    while filled < buf.len() {
+        match reader.read(&mut buf[filled..])? {
+            0 => break,
+            n => filled += n,
+        }
+    }
+    Ok(filled)
+}
+
+/// Restore the backup described by `manifest_path` into `dir`, decoding
+/// each video it references once (extracting its frames in `workspace`),
+/// with the `hardware` token for keys that need one. Every chunk is checked
+/// against its hash.
+pub fn restore(
+    manifest_path: &Path,
+    dir: &Path,
+    password: Option<&str>,
+    hardware: Option<Arc<dyn Token>>,
+    workspace: &Workspace,
+    progress: &dyn ProgressSink,
+    cancel: &CancelToken,
+) -> Result<usize> {
+    let manifest = Manifest::load(manifest_path)?;
+    let videos_dir = manifest_path.parent().unwrap_or(Path::new("."));
+    let temp_dir = video::temp_dir(workspace)?;
+    let options = DecodeOptions {
+        workspace: workspace.clone(),
+        hardware_token: hardware,
+        ..DecodeOptions::default()
+    };
+
+    let mut packs = BTreeMap::new();
+    for (i, video) in manifest.videos().into_iter().enumerate() {
+        cancel.check()?;
+        let pack_path = temp_dir.path().join(format!("pack_{i}"));
+        tracing::info!("Decoding {video}...");
+        decode::decode(
+            &[videos_dir.join(video).as_path()],
+            &pack_path,
+            password,
+            &options,
+            progress,
+            cancel,
+        )?;
+        packs.insert(video, std::fs::File::open(&pack_path)?);
+    }
+
+    let corrupt = |what: String| {
+        VstorageError::Io(std::io::Error::new(std::io::ErrorKind::InvalidData, what))
+    };
+    for entry in &manifest.files {
+        cancel.check()?;
+        let out_path = restore_path(dir, &entry.path)
+            .ok_or_else(|| corrupt(format!("unsafe path in manifest: {}", entry.path)))?;
+        if let Some(parent) = out_path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        let mut out = std::io::BufWriter::new(std::fs::File::create(&out_path)?);
+        for hash in &entry.chunks {
+            let location = &manifest.chunks[hash];
+            let pack = packs
+                .get_mut(location.video.as_str())
+                .expect("decoded above");
+            let mut chunk = vec![0u8; location.len as usize];
+            pack.seek(SeekFrom::Start(location.offset))?;
+            pack.read_exact(&mut chunk)?;
+            if <[u8; 32]>::from(Sha256::digest(&chunk)) != *hash {
+                return Err(corrupt(format!(
+                    "{}: chunk {} does not match its hash",
+                    entry.path,
+                    hex(hash)
+                )));
+            }
+            out.write_all(&chunk)?;
+        }
+        out.flush()?;
+    }
+    tracing::info!(
+        "Restored {} files into {}",
+        manifest.files.len(),
+        dir.display()
+    );
+    Ok(manifest.files.len())
+}
+
+/// `rel` under `dir`, or `None` if it would escape it.
+fn restore_path(dir: &Path, rel: &str) -> Option<PathBuf> {
+    let rel = Path::new(rel);
+    rel.components()
+        .all(|c| matches!(c, Component::Normal(_)))
+        .then(|| dir.join(rel))
+}
+
+fn with_suffix(path: &Path, suffix: &str) -> PathBuf {
+    let mut name = path.as_os_str().to_owned();
+    name.push(suffix);
+    PathBuf::from(name)
+}
+
+fn file_name(path: &Path) -> Result<String> {
+    path.file_name()
+        .and_then(|n| n.to_str())
+        .map(str::to_string)
+        .ok_or_else(|| VstorageError::Config(format!("bad output name {}", path.display())))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_manifest_roundtrip() {
+        let mut manifest = Manifest {
+            base: Some("monday.manifest".into()),
+            ..Default::default()
+        };
+        let hash = [7u8; 32];
+        manifest.chunks.insert(
+            hash,
+            ChunkRef {
+                video: "monday.mp4".into(),
+                offset: 42,
+                len: 10,
+            },
+        );
+        manifest.files.push(FileEntry {
+            path: "docs/a\tb.txt".into(),
+            size: 10,
+            chunks: vec![hash],
+        });
+        manifest.files.push(FileEntry {
+            path: "empty".into(),
+            size: 0,
+            chunks: Vec::new(),
+        });
+        let text = manifest.serialize();
+        assert_eq!(Manifest::parse(&text).unwrap(), manifest);
+        assert!(Manifest::parse("vstorage-manifest 1\nfile\t1\tzz\tx\n").is_err());
+        assert!(restore_path(Path::new("/out"), "../etc/passwd").is_none());
+    }
+
+    #[test]
+    fn test_incremental_scan() {
+        let dir = tempfile::tempdir().unwrap();
+        let mut state = 1u32;
+        let big: Vec<u8> = (0..3 * cdc::AVG_CHUNK)
+            .map(|_| {
+                state = state.wrapping_mul(1664525).wrapping_add(1013904223);
+                (state >> 24) as u8
+            })
+            .collect();
+        let first = cdc::cut(&big);
+        std::fs::create_dir(dir.path().join("sub")).unwrap();
+        std::fs::write(dir.path().join("sub/big.bin"), &big).unwrap();
+        std::fs::write(dir.path().join("copy.bin"), &big[..first]).unwrap();
+        let cancel = CancelToken::new();
+
+        let mut pack = Vec::new();
+        let (base, new_chunks, _) =
+            scan_chunks(dir.path(), None, "mon.mp4", &mut pack, &cancel).unwrap();
+        // copy.bin repeats the first chunk of big.bin
+        assert_eq!(new_chunks, cdc::chunk_lengths(&big).len());
+        assert_eq!(pack, big);
+        assert_eq!(base.files[1].path, "sub/big.bin");
+
+        // Bytes inserted into the first chunk leave the later chunks in place
+        let mut changed = big.clone();
+        changed.splice(5..5, *b"new");
+        std::fs::write(dir.path().join("sub/big.bin"), &changed).unwrap();
+        let mut pack = Vec::new();
+        let (delta, new_chunks, new_bytes) =
+            scan_chunks(dir.path(), Some(&base), "tue.mp4", &mut pack, &cancel).unwrap();
+        assert_eq!((new_chunks, new_bytes), (1, first as u64 + 3));
+        assert_eq!(
+            delta.videos().into_iter().collect::<Vec<_>>(),
+            ["mon.mp4", "tue.mp4"]
+        );
+        let head = delta.files[1].chunks[0];
+        assert_eq!(delta.chunks[&head].video, "tue.mp4");
+        assert_eq!(delta.files[1].chunks[1..], base.files[1].chunks[1..]);
+    }
+}
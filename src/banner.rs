@@ -0,0 +1,139 @@
+use std::ops::Range;
+
+use image::{Rgb, RgbImage};
+
+use crate::header::FrameHeader;
+
+/// Least height of the banner, in pixels: two pixels per font row.
+const BANNER_PIXELS: usize = 16;
+
+/// Logical rows of a banner drawn with `block_size` blocks.
+pub fn rows(block_size: u8) -> usize {
+    BANNER_PIXELS.div_ceil(block_size as usize)
+}
+
+/// The banner line of a frame, in the capitals the font has.
+pub fn text(hdr: &FrameHeader) -> String {
+    // A streamed payload's frame count is not known until its last frame
+    let frame = match hdr.total_frames {
+        0 => format!("{}", hdr.frame_number + 1),
+        total => format!("{}/{total}", hdr.frame_number + 1),
+    };
+    format!(
+        "VSTORAGE V{} - FRAME {frame} - DECODE WITH GITHUB.COM/LEVISH0/VSTORAGE",
+        hdr.version
+    )
+}
+
+/// Draw `text` in white on black across the logical `rows` of `img`, as
+/// large as their height allows; what does not fit the width is cut off.
+pub fn paint(img: &mut RgbImage, rows: Range<usize>, block_size: u8, text: &str) {
+    let bs = block_size as u32;
+    let (top, height) = (rows.start as u32 * bs, rows.len() as u32 * bs);
+    for y in top..top + height {
+        for x in 0..img.width() {
+            img.put_pixel(x, y, Rgb([0, 0, 0]));
+        }
+    }
+    let scale = (height / 8).max(1);
+    let y0 = top + (height - 7 * scale) / 2;
+    draw_text(img, (6 * scale, y0), scale, text, Rgb([255, 255, 255]));
+}
+
+/// Draw `text` with its top left corner at `(x, y)`, each font pixel
+/// `scale` pixels square and glyphs 6 font pixels apart; what does not fit
+/// the width is cut off.
+pub fn draw_text(img: &mut RgbImage, (x, y): (u32, u32), scale: u32, text: &str, color: Rgb<u8>) {
+    let chars = text.chars().map(|c| glyph(c.to_ascii_uppercase()));
+    for (i, rows) in chars.enumerate() {
+        let x0 = x + i as u32 * 6 * scale;
+        if x0 + 5 * scale > img.width() {
+            break;
+        }
+        for (gy, bits) in rows.iter().enumerate() {
+            for gx in 0..5 {
+                if bits >> (4 - gx) & 1 == 0 {
+                    continue;
+                }
+                for dy in 0..scale {
+                    for dx in 0..scale {
+                        let (px, py) = (x0 + gx * scale + dx, y + gy as u32 * scale + dy);
+                        img.put_pixel(px, py, color);
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Rows of a 5x7 glyph, the top bit of each five the leftmost pixel; blank
+/// for characters the font lacks.
+fn glyph(c: char) -> [u8; 7] {
+    match c {
+        'A' => [0x0E, 0x11, 0x11, 0x1F, 0x11, 0x11, 0x11],
+        'B' => [0x1E, 0x11, 0x11, 0x1E, 0x11, 0x11, 0x1E],
+        'C' => [0x0E, 0x11, 0x10, 0x10, 0x10, 0x11, 0x0E],
+        'D' => [0x1C, 0x12, 0x11, 0x11, 0x11, 0x12, 0x1C],
+        'E' => [0x1F, 0x10, 0x10, 0x1E, 0x10, 0x10, 0x1F],
+        'F' => [0x1F, 0x10, 0x10, 0x1E, 0x10, 0x10, 0x10],
+        'G' => [0x0E, 0x11, 0x10, 0x17, 0x11, 0x11, 0x0F],
+        'H' => [0x11, 0x11, 0x11, 0x1F, 0x11, 0x11, 0x11],
+        'I' => [0x0E, 0x04, 0x04, 0x04, 0x04, 0x04, 0x0E],
+        'J' => [0x07, 0x02, 0x02, 0x02, 0x02, 0x12, 0x0C],
+        'K' => [0x11, 0x12, 0x14, 0x18, 0x14, 0x12, 0x11],
+        'L' => [0x10, 0x10, 0x10, 0x10, 0x10, 0x10, 0x1F],
+        'M' => [0x11, 0x1B, 0x15, 0x15, 0x11, 0x11, 0x11],
+        'N' => [0x11, 0x11, 0x19, 0x15, 0x13, 0x11, 0x11],
+        'O' => [0x0E, 0x11, 0x11, 0x11, 0x11, 0x11, 0x0E],
+        'P' => [0x1E, 0x11, 0x11, 0x1E, 0x10, 0x10, 0x10],
+        'Q' => [0x0E, 0x11, 0x11, 0x11, 0x15, 0x12, 0x0D],
+        'R' => [0x1E, 0x11, 0x11, 0x1E, 0x14, 0x12, 0x11],
+        'S' => [0x0F, 0x10, 0x10, 0x0E, 0x01, 0x01, 0x1E],
+        'T' => [0x1F, 0x04, 0x04, 0x04, 0x04, 0x04, 0x04],
+        'U' => [0x11, 0x11, 0x11, 0x11, 0x11, 0x11, 0x0E],
+        'V' => [0x11, 0x11, 0x11, 0x11, 0x11, 0x0A, 0x04],
+        'W' => [0x11, 0x11, 0x11, 0x15, 0x15, 0x15, 0x0A],
+        'X' => [0x11, 0x11, 0x0A, 0x04, 0x0A, 0x11, 0x11],
+        'Y' => [0x11, 0x11, 0x11, 0x0A, 0x04, 0x04, 0x04],
+        'Z' => [0x1F, 0x01, 0x02, 0x04, 0x08, 0x10, 0x1F],
+        '0' => [0x0E, 0x11, 0x13, 0x15, 0x19, 0x11, 0x0E],
+        '1' => [0x04, 0x0C, 0x04, 0x04, 0x04, 0x04, 0x0E],
+        '2' => [0x0E, 0x11, 0x01, 0x02, 0x04, 0x08, 0x1F],
+        '3' => [0x1F, 0x02, 0x04, 0x02, 0x01, 0x11, 0x0E],
+        '4' => [0x02, 0x06, 0x0A, 0x12, 0x1F, 0x02, 0x02],
+        '5' => [0x1F, 0x10, 0x1E, 0x01, 0x01, 0x11, 0x0E],
+        '6' => [0x06, 0x08, 0x10, 0x1E, 0x11, 0x11, 0x0E],
+        '7' => [0x1F, 0x01, 0x02, 0x04, 0x08, 0x08, 0x08],
+        '8' => [0x0E, 0x11, 0x11, 0x0E, 0x11, 0x11, 0x0E],
+        '9' => [0x0E, 0x11, 0x11, 0x0F, 0x01, 0x02, 0x0C],
+        '.' => [0x00, 0x00, 0x00, 0x00, 0x00, 0x0C, 0x0C],
+        ',' => [0x00, 0x00, 0x00, 0x00, 0x0C, 0x04, 0x08],
+        '/' => [0x00, 0x01, 0x02, 0x04, 0x08, 0x10, 0x00],
+        '-' => [0x00, 0x00, 0x00, 0x1F, 0x00, 0x00, 0x00],
+        ':' => [0x00, 0x0C, 0x0C, 0x00, 0x0C, 0x0C, 0x00],
+        _ => [0; 7],
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_paint_scales_glyphs() {
+        // 8-pixel blocks: two rows of 16 pixels, glyphs at twice the size
+        assert_eq!(rows(8), 2);
+        assert_eq!(rows(2), 8);
+        let mut img = RgbImage::from_pixel(100, 40, Rgb([9, 9, 9]));
+        paint(&mut img, 1..3, 8, "v-");
+        // 'V' starts one cell (12 pixels) in, its top row 1 pixel down
+        assert_eq!(*img.get_pixel(12, 9), Rgb([255, 255, 255]));
+        assert_eq!(*img.get_pixel(14, 9), Rgb([0, 0, 0]));
+        // '-' is the middle row of the next cell
+        assert_eq!(*img.get_pixel(24, 9 + 6), Rgb([255, 255, 255]));
+        assert_eq!(*img.get_pixel(24, 9), Rgb([0, 0, 0]));
+        // Only the banner rows are drawn
+        assert_eq!(*img.get_pixel(0, 7), Rgb([9, 9, 9]));
+        assert_eq!(*img.get_pixel(0, 24), Rgb([9, 9, 9]));
+    }
+}
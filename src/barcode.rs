@@ -0,0 +1,137 @@
+use std::ops::Range;
+
+use image::{Rgb, RgbImage};
+
+use crate::crcmap::crc16;
+
+/// Least width of the barcode, in pixels.
+const BARCODE_PIXELS: usize = 8;
+
+/// Bars of one copy of the code: the frame number, the frame kind and a
+/// CRC-16 of both, one bar per bit.
+const CODE_BITS: usize = 56;
+
+/// Logical columns of a barcode drawn with `block_size` blocks.
+pub fn columns(block_size: u8) -> usize {
+    BARCODE_PIXELS.div_ceil(block_size as usize)
+}
+
+/// The code of a frame, as bytes.
+fn code(frame_number: u32, kind: u8) -> [u8; 7] {
+    let mut code = [0u8; 7];
+    code[..4].copy_from_slice(&frame_number.to_be_bytes());
+    code[4] = kind;
+    let crc = crc16(&code[..5]);
+    code[5..].copy_from_slice(&crc.to_be_bytes());
+    code
+}
+
+/// The frame number and kind of `code`, if its CRC holds.
+fn parse(code: &[u8; 7]) -> Option<(u32, u8)> {
+    (crc16(&code[..5]).to_be_bytes() == code[5..])
+        .then(|| (u32::from_be_bytes(code[..4].try_into().unwrap()), code[4]))
+}
+
+/// Draw the code of a frame over the logical `columns` of `rows`, one row
+/// per bar (white for a 1 bit), repeated as many whole times as fit.
+pub fn paint(
+    img: &mut RgbImage,
+    rows: Range<usize>,
+    columns: Range<usize>,
+    block_size: u8,
+    (frame_number, kind): (u32, u8),
+) {
+    let bs = block_size as u32;
+    let code = code(frame_number, kind);
+    let copies = rows.len() / CODE_BITS;
+    for (i, ly) in rows.enumerate().take(copies * CODE_BITS) {
+        let j = i % CODE_BITS;
+        let bit = code[j / 8] >> (7 - j % 8) & 1;
+        for y in ly as u32 * bs..(ly as u32 + 1) * bs {
+            for x in columns.start as u32 * bs..columns.end as u32 * bs {
+                img.put_pixel(x, y, Rgb([255 * bit; 3]));
+            }
+        }
+    }
+}
+
+/// Read the frame number and kind drawn by [`paint`]: the bitwise majority
+/// of the copies if its CRC holds, otherwise the first copy whose CRC does.
+pub fn read(
+    img: &RgbImage,
+    rows: Range<usize>,
+    columns: Range<usize>,
+    block_size: u8,
+) -> Option<(u32, u8)> {
+    let bs = block_size as u32;
+    let copies = rows.len() / CODE_BITS;
+    let xs = columns.start as u32 * bs..columns.end as u32 * bs;
+    // A bar is a 1 when its mean is above mid-grey
+    let bars: Vec<bool> = rows
+        .take(copies * CODE_BITS)
+        .map(|ly| {
+            let (mut sum, mut n) = (0u64, 0u64);
+            for y in ly as u32 * bs..(ly as u32 + 1) * bs {
+                for x in xs.clone() {
+                    sum += img.get_pixel(x, y).0.iter().map(|&v| v as u64).sum::<u64>();
+                    n += 3;
+                }
+            }
+            sum > n * 128
+        })
+        .collect();
+    let voted: Vec<bool> = (0..CODE_BITS)
+        .map(|j| {
+            bars.iter()
+                .skip(j)
+                .step_by(CODE_BITS)
+                .filter(|&&b| b)
+                .count()
+                * 2
+                > copies
+        })
+        .collect();
+    let to_code = |bits: &[bool]| {
+        let mut code = [0u8; 7];
+        for (j, &bit) in bits.iter().enumerate() {
+            code[j / 8] |= u8::from(bit) << (7 - j % 8);
+        }
+        code
+    };
+    std::iter::once(voted.as_slice())
+        .chain(bars.chunks_exact(CODE_BITS))
+        .find_map(|bits| parse(&to_code(bits)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_read_survives_damaged_copies() {
+        // Three copies in 180 rows of 2-pixel blocks, 4 columns wide
+        assert_eq!(columns(2), 4);
+        let mut img = RgbImage::new(20, 360);
+        paint(&mut img, 0..180, 6..10, 2, (123_456, 4));
+        assert_eq!(read(&img, 0..180, 6..10, 2), Some((123_456, 4)));
+
+        // The first copy wiped out and a set bar of the second cleared: the
+        // vote fails its CRC, the third copy still reads
+        for y in 0..CODE_BITS as u32 * 2 {
+            for x in 12..20 {
+                img.put_pixel(x, y, Rgb([0; 3]));
+            }
+        }
+        let cleared = (CODE_BITS as u32 + 16) * 2;
+        for y in cleared..cleared + 2 {
+            for x in 12..20 {
+                img.put_pixel(x, y, Rgb([0; 3]));
+            }
+        }
+        assert_eq!(read(&img, 0..180, 6..10, 2), Some((123_456, 4)));
+
+        // Too few rows for a copy, or no barcode at all
+        assert_eq!(read(&img, 0..50, 6..10, 2), None);
+        assert_eq!(read(&RgbImage::new(20, 360), 0..180, 6..10, 2), None);
+    }
+}
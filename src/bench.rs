@@ -0,0 +1,254 @@
+use std::fmt;
+use std::path::Path;
+use std::time::Instant;
+
+use crate::cancel::CancelToken;
+use crate::capacity::{human_bytes, sample_header};
+use crate::config::FrameConfig;
+use crate::decode::{self, DecodeOptions};
+use crate::encode::{self, SplitLimits};
+use crate::error::{Result, VstorageError};
+use crate::progress::NoProgress;
+use crate::{crypto, ecc, frame, header};
+
+/// Throughput of each pipeline stage on this machine.
+#[derive(Debug, Clone, PartialEq)]
+pub struct BenchReport {
+    pub block_size: u8,
+    pub levels: u8,
+    pub ecc_len: u8,
+    /// Frames painted/read and encoded end to end
+    pub frames: usize,
+    /// File bytes carried by each frame
+    pub bytes_per_frame: usize,
+    /// Frames drawn into images per second
+    pub paint_fps: f64,
+    /// Frames read back from images per second
+    pub read_fps: f64,
+    /// Bytes per second split into channel values and packed back
+    pub bits_bps: f64,
+    /// Reed-Solomon throughput, in data bytes per second
+    pub rs_encode_bps: f64,
+    pub rs_decode_bps: f64,
+    /// Seconds to derive one Argon2 key
+    pub argon2_secs: f64,
+    /// File bytes per second through the full encode and decode with ffmpeg,
+    /// or why it was skipped
+    pub end_to_end: std::result::Result<(f64, f64), String>,
+}
+
+impl BenchReport {
+    fn rate(&self, frames_per_sec: f64) -> String {
+        format!(
+            "{frames_per_sec:.1} frames/s ({}/s)",
+            human_bytes((frames_per_sec * self.bytes_per_frame as f64) as u64)
+        )
+    }
+}
+
+impl fmt::Display for BenchReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let per_sec = |bps: f64| format!("{}/s", human_bytes(bps as u64));
+        writeln!(
+            f,
+            "Settings:    block={}, levels={}, ecc={} ({} per frame)",
+            self.block_size,
+            self.levels,
+            self.ecc_len,
+            human_bytes(self.bytes_per_frame as u64)
+        )?;
+        writeln!(f, "Frame paint: {}", self.rate(self.paint_fps))?;
+        writeln!(f, "Frame read:  {}", self.rate(self.read_fps))?;
+        writeln!(f, "Bit packing: {}", per_sec(self.bits_bps))?;
+        writeln!(f, "RS encode:   {}", per_sec(self.rs_encode_bps))?;
+        writeln!(f, "RS decode:   {}", per_sec(self.rs_decode_bps))?;
+        writeln!(f, "Argon2:      {:.2}s per key", self.argon2_secs)?;
+        match &self.end_to_end {
+            Ok((enc, dec)) => write!(
+                f,
+                "End to end:  encode {}, decode {} ({} frames)",
+                per_sec(*enc),
+                per_sec(*dec),
+                self.frames
+            ),
+            Err(reason) => write!(f, "End to end:  skipped ({reason})"),
+        }
+    }
+}
+
+/// Measure each stage with `frames` frames of random data; the end-to-end
+/// run needs ffmpeg and is skipped when `end_to_end` is false.
+pub fn bench(
+    config: &FrameConfig,
+    frames: usize,
+    end_to_end: bool,
+    cancel: &CancelToken,
+) -> Result<BenchReport> {
+    let frames = frames.max(1);
+    let raw_per_frame = config.max_raw_per_frame();
+    let mut data = vec![0u8; raw_per_frame * frames];
+    rand::fill(&mut data[..]);
+
+    let ecc_len = config.ecc_len as usize;
+    let start = Instant::now();
+    let rs_chunks: Vec<Vec<u8>> = data
+        .chunks(raw_per_frame)
+        .map(|chunk| {
+            ecc::rs_encode(
+                chunk,
+                ecc_len,
+                config.rs_data_len(),
+                config.worker_threads(),
+            )
+        })
+        .collect();
+    let rs_encode_bps = data.len() as f64 / secs_since(start);
+
+    let start = Instant::now();
+    for (chunk, rs) in data.chunks(raw_per_frame).zip(&rs_chunks) {
+        ecc::rs_decode(
+            rs,
+            ecc_len,
+            config.rs_data_len(),
+            chunk.len(),
+            config.worker_threads(),
+        )?;
+    }
+    let rs_decode_bps = data.len() as f64 / secs_since(start);
+
+    let header_bytes = header::encode_header_triple(&sample_header(config, 0, frames));
+    let start = Instant::now();
+    let images: Vec<image::RgbImage> = rs_chunks
+        .iter()
+        .map(|rs| {
+            cancel.check()?;
+            Ok(frame::encode_frame_to_image(&header_bytes, rs, config))
+        })
+        .collect::<Result<_>>()?;
+    let paint_fps = frames as f64 / secs_since(start);
+
+    let start = Instant::now();
+    for img in &images {
+        cancel.check()?;
+        frame::decode_data_area(img, config, config.channel_levels(), 0);
+    }
+    let read_fps = frames as f64 / secs_since(start);
+    drop(images);
+
+    let bpc = config.bits_per_channel();
+    let start = Instant::now();
+    let mut reader = frame::BitReader::new(&data);
+    let mut writer = frame::BitWriter::new();
+    for _ in 0..(data.len() * 8).div_ceil(bpc as usize) {
+        writer.write_bits(reader.read_bits(bpc), bpc);
+    }
+    if writer.finish()[..data.len()] != data[..] {
+        return Err(VstorageError::Config(
+            "bit packing did not round-trip".into(),
+        ));
+    }
+    let bits_bps = data.len() as f64 / secs_since(start);
+
+    let start = Instant::now();
+    crypto::FrameCipher::generate(
+        "vstorage bench",
+        crypto::Kdf::Argon2id,
+        crypto::TokenMode::Off,
+        None,
+    )?;
+    let argon2_secs = secs_since(start);
+
+    let end_to_end = if !end_to_end {
+        Err("--no-end-to-end".to_string())
+    } else if let Err(e) = crate::video::check_ffmpeg() {
+        Err(e.to_string())
+    } else {
+        let plain_len = config.max_plain_per_frame(false) * frames;
+        Ok(round_trip(config, &data[..plain_len], cancel)?)
+    };
+
+    Ok(BenchReport {
+        block_size: config.block_size,
+        levels: config.levels,
+        ecc_len: config.ecc_len,
+        frames,
+        bytes_per_frame: config.max_plain_per_frame(false),
+        paint_fps,
+        read_fps,
+        bits_bps,
+        rs_encode_bps,
+        rs_decode_bps,
+        argon2_secs,
+        end_to_end,
+    })
+}
+
+/// Encode and decode `payload` through ffmpeg in a temporary directory,
+/// returning encode and decode bytes per second.
+fn round_trip(config: &FrameConfig, payload: &[u8], cancel: &CancelToken) -> Result<(f64, f64)> {
+    let temp_dir = crate::video::temp_dir(&config.workspace)?;
+    let dir = temp_dir.path();
+    let (input, video, restored) = (
+        dir.join("payload.bin"),
+        dir.join("bench.mp4"),
+        dir.join("restored.bin"),
+    );
+    std::fs::write(&input, payload)?;
+
+    let start = Instant::now();
+    encode::encode(
+        &input,
+        &video,
+        None,
+        &[],
+        config,
+        None,
+        &[],
+        None,
+        &SplitLimits::default(),
+        false,
+        &NoProgress,
+        cancel,
+    )?;
+    let encode_bps = payload.len() as f64 / secs_since(start);
+
+    let start = Instant::now();
+    decode::decode(
+        &[video.as_path()],
+        &restored,
+        None,
+        &DecodeOptions {
+            workspace: config.workspace.clone(),
+            ..DecodeOptions::default()
+        },
+        &NoProgress,
+        cancel,
+    )?;
+    let decode_bps = payload.len() as f64 / secs_since(start);
+
+    if std::fs::read(Path::new(&restored))? != payload {
+        return Err(VstorageError::Config(
+            "benchmark payload did not round-trip".into(),
+        ));
+    }
+    Ok((encode_bps, decode_bps))
+}
+
+fn secs_since(start: Instant) -> f64 {
+    start.elapsed().as_secs_f64().max(1e-9)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_bench_without_ffmpeg() {
+        let config = FrameConfig::new(8, 2, 64, 30, 18).unwrap();
+        let report = bench(&config, 1, false, &CancelToken::new()).unwrap();
+        assert_eq!(report.frames, 1);
+        assert!(report.paint_fps > 0.0 && report.rs_decode_bps > 0.0);
+        assert!(report.bits_bps > 0.0);
+        assert!(report.to_string().contains("End to end:  skipped"));
+    }
+}
@@ -0,0 +1,82 @@
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, OnceLock};
+
+use crate::error::{Result, VstorageError};
+
+/// Shared flag asking a running encode or decode to stop. Clones refer to
+/// the same flag.
+#[derive(Debug, Clone, Default)]
+pub struct CancelToken(Arc<AtomicBool>);
+
+impl CancelToken {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn cancel(&self) {
+        self.0.store(true, Ordering::Relaxed);
+    }
+
+    pub fn is_cancelled(&self) -> bool {
+        self.0.load(Ordering::Relaxed)
+    }
+
+    /// `Err(Cancelled)` once cancelled; called between units of work.
+    pub fn check(&self) -> Result<()> {
+        if self.is_cancelled() {
+            Err(VstorageError::Cancelled)
+        } else {
+            Ok(())
+        }
+    }
+}
+
+static SIGNAL_TOKEN: OnceLock<CancelToken> = OnceLock::new();
+
+/// Cancel `token` on the first Ctrl-C (SIGINT); a second one terminates the
+/// process as usual. Only one token per process can be registered.
+#[cfg(unix)]
+pub fn cancel_on_ctrl_c(token: &CancelToken) -> bool {
+    extern "C" fn on_sigint(_: libc::c_int) {
+        if let Some(token) = SIGNAL_TOKEN.get() {
+            token.cancel();
+        }
+        // SAFETY: restoring the default disposition is async-signal-safe
+        unsafe {
+            libc::signal(libc::SIGINT, libc::SIG_DFL);
+        }
+    }
+
+    if SIGNAL_TOKEN.set(token.clone()).is_err() {
+        return false;
+    }
+    // SAFETY: the handler only touches atomics and calls signal(2)
+    unsafe {
+        libc::signal(
+            libc::SIGINT,
+            on_sigint as extern "C" fn(libc::c_int) as libc::sighandler_t,
+        );
+    }
+    true
+}
+
+/// Ctrl-C handling is only implemented for Unix; elsewhere this does nothing.
+#[cfg(not(unix))]
+pub fn cancel_on_ctrl_c(token: &CancelToken) -> bool {
+    SIGNAL_TOKEN.set(token.clone()).is_ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_cancel_token() {
+        let token = CancelToken::new();
+        let other = CancelToken::new();
+        assert!(token.check().is_ok());
+        token.clone().cancel();
+        assert!(matches!(token.check(), Err(VstorageError::Cancelled)));
+        assert!(!other.is_cancelled());
+    }
+}
@@ -0,0 +1,209 @@
+use std::fmt;
+
+use crate::config::{FrameConfig, PROTOCOL_VERSION};
+use crate::encode::calibrate;
+use crate::error::Result;
+use crate::header;
+use crate::memory::render_frame;
+use crate::progress::NoProgress;
+
+/// Frames encoded to measure the MP4 size per frame.
+const SAMPLE_FRAMES: usize = 30;
+
+/// Space needed to store a file with given settings.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CapacityEstimate {
+    pub fps: u32,
+    /// File bytes carried by each frame
+    pub bytes_per_frame: usize,
+    /// Size of the file to plan for, if given
+    pub input_size: Option<u64>,
+    /// Measured MP4 bytes per frame at the chosen CRF/codec, if measured
+    pub mp4_bytes_per_frame: Option<f64>,
+}
+
+impl CapacityEstimate {
+    pub fn new(config: &FrameConfig, encrypted: bool, input_size: Option<u64>) -> Self {
+        Self {
+            fps: config.fps,
+            bytes_per_frame: config.max_plain_per_frame(encrypted),
+            input_size,
+            mp4_bytes_per_frame: None,
+        }
+    }
+
+    /// Frames needed for the input (at least one, like encode), with its
+    /// piece frames.
+    pub fn frames(&self) -> Option<u64> {
+        let size = self.input_size?;
+        let data = size.div_ceil(self.bytes_per_frame.max(1) as u64).max(1);
+        Some(data + crate::pieces::piece_frames(size, self.bytes_per_frame) as u64)
+    }
+
+    pub fn duration_secs(&self) -> Option<f64> {
+        Some(self.frames()? as f64 / self.fps as f64)
+    }
+
+    pub fn mp4_bytes(&self) -> Option<u64> {
+        Some((self.frames()? as f64 * self.mp4_bytes_per_frame?) as u64)
+    }
+}
+
+impl fmt::Display for CapacityEstimate {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let per_minute = self.bytes_per_frame as u64 * self.fps as u64 * 60;
+        writeln!(f, "Bytes per frame:  {}", self.bytes_per_frame)?;
+        write!(
+            f,
+            "Per minute:       {} ({} fps)",
+            human_bytes(per_minute),
+            self.fps
+        )?;
+        if let Some(mp4) = self.mp4_bytes_per_frame {
+            write!(
+                f,
+                "\nMP4 per frame:    {} (measured, {:.2}x the data)",
+                human_bytes(mp4 as u64),
+                mp4 / self.bytes_per_frame.max(1) as f64
+            )?;
+        }
+        if let (Some(size), Some(frames), Some(secs)) =
+            (self.input_size, self.frames(), self.duration_secs())
+        {
+            write!(
+                f,
+                "\nFor {}: {frames} frames, {}",
+                human_bytes(size),
+                human_duration(secs)
+            )?;
+            if let Some(mp4) = self.mp4_bytes() {
+                write!(f, ", ~{} MP4", human_bytes(mp4))?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Encode a short sample of random frames with `config` and return the MP4
+/// bytes per frame.
+pub fn measure_mp4_bytes_per_frame(config: &FrameConfig) -> Result<f64> {
+    crate::video::check_ffmpeg()?;
+    let temp_dir = crate::video::temp_dir(&config.workspace)?;
+    let per_frame = config.max_raw_per_frame();
+    calibrate(
+        temp_dir.path(),
+        config,
+        SAMPLE_FRAMES,
+        |i| {
+            let mut chunk = vec![0u8; per_frame];
+            rand::fill(&mut chunk[..]);
+            let hdr = sample_header(config, i, SAMPLE_FRAMES);
+            Ok(render_frame(config, hdr, &chunk))
+        },
+        &NoProgress,
+        &crate::cancel::CancelToken::new(),
+    )
+}
+
+/// Header for frame `frame_number` of a synthetic `total_frames`-frame
+/// video, used for measurements.
+pub(crate) fn sample_header(
+    config: &FrameConfig,
+    frame_number: usize,
+    total_frames: usize,
+) -> header::FrameHeader {
+    header::FrameHeader {
+        version: PROTOCOL_VERSION,
+        frame_number: frame_number as u32,
+        total_frames: total_frames as u32,
+        block_size: config.block_size,
+        levels: config.levels,
+        file_size: (config.max_raw_per_frame() * total_frames) as u64,
+        data_length: 0,
+        ecc_len: config.ecc_len,
+        rs_data_len: config.rs_data_len() as u16,
+        nonce: [0; 12],
+        salt: [0; 16],
+        data_sha256: [0; 32],
+        kind: header::KIND_DATA,
+        payload: 0,
+        part: 0,
+        total_parts: 1,
+        set_id: [0; 16],
+        kdf: config.kdf,
+        token: config.token,
+        compression: config.compression,
+        data_levels: config.levels,
+        channel_levels: config.channel_levels(),
+        layout: config.layout,
+        crc_map: config.crc_map,
+        bottom_header: config.bottom_header,
+        banner: config.banner,
+        barcode: config.barcode,
+        pilots: config.pilots,
+        bin_centers: config.bin_centers,
+        streamed: false,
+    }
+}
+
+pub(crate) fn human_bytes(n: u64) -> String {
+    const UNITS: [&str; 5] = ["B", "KB", "MB", "GB", "TB"];
+    let mut value = n as f64;
+    let mut unit = 0;
+    while value >= 1000.0 && unit < UNITS.len() - 1 {
+        value /= 1000.0;
+        unit += 1;
+    }
+    if unit == 0 {
+        format!("{n} B")
+    } else {
+        format!("{value:.1} {}", UNITS[unit])
+    }
+}
+
+fn human_duration(secs: f64) -> String {
+    let total = secs.ceil() as u64;
+    let (h, m, s) = (total / 3600, total / 60 % 60, total % 60);
+    if h > 0 {
+        format!("{h}h{m:02}m{s:02}s")
+    } else if m > 0 {
+        format!("{m}m{s:02}s")
+    } else {
+        format!("{secs:.1}s")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_estimate() {
+        let config = FrameConfig::new(2, 4, 32, 30, 18).unwrap();
+        let per_frame = config.max_plain_per_frame(false) as u64;
+        let mut est = CapacityEstimate::new(&config, false, Some(per_frame * 60 + 1));
+        // 61 data frames and one piece frame
+        assert_eq!(est.frames(), Some(62));
+        assert!((est.duration_secs().unwrap() - 62.0 / 30.0).abs() < 1e-9);
+        assert_eq!(est.mp4_bytes(), None);
+
+        est.mp4_bytes_per_frame = Some(2.0 * per_frame as f64);
+        assert_eq!(est.mp4_bytes(), Some(62 * 2 * per_frame));
+
+        let encrypted = CapacityEstimate::new(&config, true, None);
+        assert_eq!(
+            encrypted.bytes_per_frame,
+            per_frame as usize - crate::crypto::TAG_LEN
+        );
+        assert_eq!(encrypted.frames(), None);
+    }
+
+    #[test]
+    fn test_human_units() {
+        assert_eq!(human_bytes(999), "999 B");
+        assert_eq!(human_bytes(2_300_000_000), "2.3 GB");
+        assert_eq!(human_duration(3725.0), "1h02m05s");
+        assert_eq!(human_duration(90.0), "1m30s");
+    }
+}
@@ -0,0 +1,94 @@
+/// Chunks are cut at content-defined points (FastCDC), so an insertion or
+/// deletion only changes the chunks around it instead of shifting every
+/// later chunk boundary.
+pub const MIN_CHUNK: usize = 256 << 10;
+pub const AVG_CHUNK: usize = 1 << 20;
+pub const MAX_CHUNK: usize = 4 << 20;
+
+/// Cut masks on the high bits of the gear hash, which depend on the last 64
+/// bytes: harder to match before the average size and easier after it, so
+/// chunk sizes cluster around [`AVG_CHUNK`].
+const MASK_SMALL: u64 = !(u64::MAX >> (AVG_CHUNK.trailing_zeros() + 2));
+const MASK_LARGE: u64 = !(u64::MAX >> (AVG_CHUNK.trailing_zeros() - 2));
+
+/// Random value per byte, from SplitMix64 with a fixed seed. Changing it
+/// moves every cut point and so defeats dedup against older backups.
+const GEAR: [u64; 256] = {
+    let mut table = [0u64; 256];
+    let mut state = 0u64;
+    let mut i = 0;
+    while i < 256 {
+        state = state.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        let mut z = state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        table[i] = z ^ (z >> 31);
+        i += 1;
+    }
+    table
+};
+
+/// Length of the first chunk of `data`, which must hold at least
+/// [`MAX_CHUNK`] bytes unless it runs to the end of the input.
+pub fn cut(data: &[u8]) -> usize {
+    if data.len() <= MIN_CHUNK {
+        return data.len();
+    }
+    let end = data.len().min(MAX_CHUNK);
+    let normal = end.min(AVG_CHUNK);
+    let mut hash = 0u64;
+    for (i, &byte) in data.iter().enumerate().take(end).skip(MIN_CHUNK) {
+        hash = (hash << 1).wrapping_add(GEAR[byte as usize]);
+        let mask = if i < normal { MASK_SMALL } else { MASK_LARGE };
+        if hash & mask == 0 {
+            return i + 1;
+        }
+    }
+    end
+}
+
+/// Lengths of all chunks of `data`.
+pub fn chunk_lengths(mut data: &[u8]) -> Vec<usize> {
+    let mut lengths = Vec::new();
+    while !data.is_empty() {
+        let n = cut(data);
+        lengths.push(n);
+        data = &data[n..];
+    }
+    lengths
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn noise(len: usize, mut state: u64) -> Vec<u8> {
+        (0..len)
+            .map(|_| {
+                state ^= state << 13;
+                state ^= state >> 7;
+                state ^= state << 17;
+                state as u8
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_cut_points_follow_content() {
+        let data = noise(12 << 20, 1);
+        let lengths = chunk_lengths(&data);
+        assert_eq!(lengths.iter().sum::<usize>(), data.len());
+        let (last, rest) = lengths.split_last().unwrap();
+        assert!(rest.iter().all(|&n| (MIN_CHUNK..=MAX_CHUNK).contains(&n)));
+        assert!(*last <= MAX_CHUNK);
+        assert!(lengths.len() >= 4, "{lengths:?}");
+
+        // Bytes inserted near the start only change the first chunk
+        let mut shifted = b"inserted".to_vec();
+        shifted.extend_from_slice(&data);
+        let moved = chunk_lengths(&shifted);
+        assert_eq!(moved[0], lengths[0] + 8);
+        assert_eq!(moved[1..], lengths[1..]);
+        assert_eq!(cut(&data[..1000]), 1000);
+    }
+}
@@ -0,0 +1,346 @@
+use std::path::{Path, PathBuf};
+
+use crate::config::FrameConfig;
+use crate::encode::{SplitLimits, SEGMENT_FRAMES};
+use crate::error::{Result, VstorageError};
+
+/// Progress of an interrupted encode, persisted in the work directory so a
+/// later run with `--resume` can continue where it stopped.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Checkpoint {
+    pub input: PathBuf,
+    pub input_size: u64,
+    pub input_mtime: u64,
+    pub block_size: u8,
+    pub levels: u8,
+    pub ecc_len: u8,
+    pub fps: u32,
+    pub crf: u8,
+    pub codec: String,
+    pub target_bitrate: Option<u32>,
+    pub yuv420p: bool,
+    pub kdf: String,
+    pub compression: String,
+    pub layout: String,
+    pub crc_map: bool,
+    pub bottom_header: bool,
+    pub banner: bool,
+    pub barcode: bool,
+    pub pilots: bool,
+    pub bin_centers: bool,
+    pub header_rows: usize,
+    pub header_copies: usize,
+    pub intro: bool,
+    pub metadata_levels: Option<u8>,
+    pub channel_levels: [u8; 3],
+    pub max_output_size: Option<u64>,
+    pub max_duration: Option<u64>,
+    /// Format of the frames written so far, see [`crate::config::Intermediate`]
+    pub intermediate: String,
+    /// Frames per ffmpeg segment (see [`crate::encode::segment_frames`])
+    pub segment_frames: usize,
+    pub nonce: [u8; 12],
+    pub salt: [u8; 16],
+    /// Tag proving the resumed run derived the same key (zeros if unencrypted)
+    pub key_check: [u8; 16],
+    /// Set id stamped into every frame, kept so resumed parts match
+    pub set_id: [u8; 16],
+    pub total_frames: usize,
+    /// Frames whose image has been written (or already went into a segment)
+    pub frames_done: usize,
+    /// Segments already produced by ffmpeg
+    pub segments_done: usize,
+    /// Frames per output video (0 until decided)
+    pub frames_per_part: usize,
+}
+
+impl Checkpoint {
+    /// Whether this checkpoint was written for the same input and settings.
+    pub fn matches(
+        &self,
+        input: &Path,
+        input_size: u64,
+        input_mtime: u64,
+        config: &FrameConfig,
+        limits: &SplitLimits,
+    ) -> bool {
+        self.input == input
+            && self.input_size == input_size
+            && self.input_mtime == input_mtime
+            && self.block_size == config.block_size
+            && self.levels == config.levels
+            && self.ecc_len == config.ecc_len
+            && self.fps == config.fps
+            && self.crf == config.crf
+            && self.codec == config.codec.to_string()
+            && self.target_bitrate == config.target_bitrate
+            && self.yuv420p == config.yuv420p
+            && self.kdf == config.kdf.to_string()
+            && self.compression == config.compression.to_string()
+            && self.layout == config.layout.to_string()
+            && self.crc_map == config.crc_map
+            && self.bottom_header == config.bottom_header
+            && self.banner == config.banner
+            && self.barcode == config.barcode
+            && self.pilots == config.pilots
+            && self.bin_centers == config.bin_centers
+            && (self.header_rows, self.header_copies) == (config.header_rows, config.header_copies)
+            && self.intro == config.intro
+            && self.metadata_levels == config.metadata_levels
+            && self.channel_levels == config.channel_levels()
+            && self.max_output_size == limits.max_bytes
+            && self.max_duration == limits.max_secs
+    }
+
+    /// Write the checkpoint atomically (temp file + rename).
+    pub fn save(&self, path: &Path) -> Result<()> {
+        fn opt<T: ToString>(v: Option<T>) -> String {
+            v.map_or(String::new(), |v| v.to_string())
+        }
+        let text = format!(
+            "input={}\ninput_size={}\ninput_mtime={}\nblock_size={}\nlevels={}\necc_len={}\n\
+             fps={}\ncrf={}\ncodec={}\ntarget_bitrate={}\nyuv420p={}\nkdf={}\n\
+             compression={}\nlayout={}\ncrc_map={}\nbottom_header={}\nbanner={}\nbarcode={}\npilots={}\nbin_centers={}\nheader_rows={}\nheader_copies={}\nintro={}\nmetadata_levels={}\nchannel_levels={}/{}/{}\nmax_output_size={}\nmax_duration={}\nintermediate={}\nsegment_frames={}\nnonce={}\nsalt={}\nkey_check={}\nset_id={}\ntotal_frames={}\n\
+             frames_done={}\nsegments_done={}\nframes_per_part={}\n",
+            self.input.display(),
+            self.input_size,
+            self.input_mtime,
+            self.block_size,
+            self.levels,
+            self.ecc_len,
+            self.fps,
+            self.crf,
+            self.codec,
+            opt(self.target_bitrate),
+            self.yuv420p,
+            self.kdf,
+            self.compression,
+            self.layout,
+            self.crc_map,
+            self.bottom_header,
+            self.banner,
+            self.barcode,
+            self.pilots,
+            self.bin_centers,
+            self.header_rows,
+            self.header_copies,
+            self.intro,
+            opt(self.metadata_levels),
+            self.channel_levels[0],
+            self.channel_levels[1],
+            self.channel_levels[2],
+            opt(self.max_output_size),
+            opt(self.max_duration),
+            self.intermediate,
+            self.segment_frames,
+            to_hex(&self.nonce),
+            to_hex(&self.salt),
+            to_hex(&self.key_check),
+            to_hex(&self.set_id),
+            self.total_frames,
+            self.frames_done,
+            self.segments_done,
+            self.frames_per_part,
+        );
+        let tmp = path.with_extension("tmp");
+        std::fs::write(&tmp, text)?;
+        std::fs::rename(&tmp, path)?;
+        Ok(())
+    }
+
+    pub fn load(path: &Path) -> Result<Self> {
+        let text = std::fs::read_to_string(path)?;
+        let get = |key: &str| -> Result<&str> {
+            text.lines()
+                .find_map(|l| l.strip_prefix(key).and_then(|r| r.strip_prefix('=')))
+                .ok_or_else(|| bad(format!("missing '{key}'")))
+        };
+        fn num<T: std::str::FromStr>(v: &str, key: &str) -> Result<T> {
+            v.parse().map_err(|_| bad(format!("invalid '{key}'")))
+        }
+        let opt_num = |key: &str| -> Result<Option<u64>> {
+            let v = get(key)?;
+            if v.is_empty() {
+                Ok(None)
+            } else {
+                num(v, key).map(Some)
+            }
+        };
+
+        let levels = num(get("levels")?, "levels")?;
+        // Checkpoints from before per-channel levels used `levels` for all
+        let channel_levels = match get("channel_levels") {
+            Ok(v) => {
+                let parts: Vec<u8> = v
+                    .split('/')
+                    .map(|l| num(l, "channel_levels"))
+                    .collect::<Result<_>>()?;
+                parts
+                    .try_into()
+                    .map_err(|_| bad("invalid 'channel_levels'".into()))?
+            }
+            Err(_) => [levels; 3],
+        };
+
+        Ok(Self {
+            input: PathBuf::from(get("input")?),
+            input_size: num(get("input_size")?, "input_size")?,
+            input_mtime: num(get("input_mtime")?, "input_mtime")?,
+            block_size: num(get("block_size")?, "block_size")?,
+            levels,
+            ecc_len: num(get("ecc_len")?, "ecc_len")?,
+            fps: num(get("fps")?, "fps")?,
+            crf: num(get("crf")?, "crf")?,
+            codec: get("codec")?.to_string(),
+            target_bitrate: opt_num("target_bitrate")?.map(|b| b as u32),
+            yuv420p: num(get("yuv420p")?, "yuv420p")?,
+            // Checkpoints from before the KDF was selectable used Argon2id
+            kdf: get("kdf").unwrap_or("argon2id").to_string(),
+            compression: get("compression").unwrap_or("none").to_string(),
+            layout: get("layout").unwrap_or("raster").to_string(),
+            crc_map: match get("crc_map") {
+                Ok(v) => num(v, "crc_map")?,
+                Err(_) => false,
+            },
+            bottom_header: match get("bottom_header") {
+                Ok(v) => num(v, "bottom_header")?,
+                Err(_) => false,
+            },
+            banner: match get("banner") {
+                Ok(v) => num(v, "banner")?,
+                Err(_) => false,
+            },
+            barcode: match get("barcode") {
+                Ok(v) => num(v, "barcode")?,
+                Err(_) => false,
+            },
+            pilots: match get("pilots") {
+                Ok(v) => num(v, "pilots")?,
+                Err(_) => false,
+            },
+            bin_centers: match get("bin_centers") {
+                Ok(v) => num(v, "bin_centers")?,
+                Err(_) => false,
+            },
+            header_rows: match get("header_rows") {
+                Ok(v) => num(v, "header_rows")?,
+                Err(_) => crate::config::HEADER_ROWS,
+            },
+            header_copies: match get("header_copies") {
+                Ok(v) => num(v, "header_copies")?,
+                Err(_) => crate::config::HEADER_COPIES,
+            },
+            intro: match get("intro") {
+                Ok(v) => num(v, "intro")?,
+                Err(_) => false,
+            },
+            metadata_levels: match get("metadata_levels") {
+                Ok(_) => opt_num("metadata_levels")?.map(|l| l as u8),
+                Err(_) => None,
+            },
+            channel_levels,
+            max_output_size: opt_num("max_output_size")?,
+            max_duration: opt_num("max_duration")?,
+            intermediate: get("intermediate").unwrap_or("png").to_string(),
+            segment_frames: match get("segment_frames") {
+                Ok(v) => num(v, "segment_frames")?,
+                Err(_) => SEGMENT_FRAMES,
+            },
+            nonce: from_hex(get("nonce")?)?,
+            salt: from_hex(get("salt")?)?,
+            key_check: from_hex(get("key_check")?)?,
+            set_id: from_hex(get("set_id")?)?,
+            total_frames: num(get("total_frames")?, "total_frames")?,
+            frames_done: num(get("frames_done")?, "frames_done")?,
+            segments_done: num(get("segments_done")?, "segments_done")?,
+            frames_per_part: num(get("frames_per_part")?, "frames_per_part")?,
+        })
+    }
+}
+
+fn bad(msg: String) -> VstorageError {
+    VstorageError::Config(format!("corrupt checkpoint: {msg}"))
+}
+
+fn to_hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|b| format!("{b:02x}")).collect()
+}
+
+fn from_hex<const N: usize>(s: &str) -> Result<[u8; N]> {
+    if s.len() != N * 2 {
+        return Err(bad(format!("expected {N} hex bytes")));
+    }
+    let mut out = [0u8; N];
+    for (i, b) in out.iter_mut().enumerate() {
+        *b = u8::from_str_radix(&s[i * 2..i * 2 + 2], 16).map_err(|_| bad("invalid hex".into()))?;
+    }
+    Ok(out)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_checkpoint_roundtrip() {
+        let config = FrameConfig::new(2, 4, 32, 30, 18).unwrap();
+        let cp = Checkpoint {
+            input: PathBuf::from("/data/in.bin"),
+            input_size: 123,
+            input_mtime: 456,
+            block_size: config.block_size,
+            levels: config.levels,
+            ecc_len: config.ecc_len,
+            fps: config.fps,
+            crf: config.crf,
+            codec: config.codec.to_string(),
+            target_bitrate: None,
+            yuv420p: false,
+            kdf: config.kdf.to_string(),
+            compression: config.compression.to_string(),
+            layout: config.layout.to_string(),
+            crc_map: config.crc_map,
+            bottom_header: config.bottom_header,
+            banner: config.banner,
+            barcode: config.barcode,
+            pilots: config.pilots,
+            bin_centers: config.bin_centers,
+            header_rows: config.header_rows,
+            header_copies: config.header_copies,
+            intro: config.intro,
+            metadata_levels: None,
+            channel_levels: config.channel_levels(),
+            max_output_size: Some(2_000_000_000),
+            max_duration: None,
+            intermediate: "ppm".into(),
+            segment_frames: 120,
+            nonce: [7; 12],
+            salt: [9; 16],
+            key_check: [1; 16],
+            set_id: [2; 16],
+            total_frames: 10_000,
+            frames_done: 9_000,
+            segments_done: 30,
+            frames_per_part: 27_000,
+        };
+
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("checkpoint");
+        cp.save(&path).unwrap();
+        let loaded = Checkpoint::load(&path).unwrap();
+        assert_eq!(loaded, cp);
+        let limits = SplitLimits {
+            max_bytes: Some(2_000_000_000),
+            max_secs: None,
+        };
+        assert!(loaded.matches(Path::new("/data/in.bin"), 123, 456, &config, &limits));
+        assert!(!loaded.matches(Path::new("/data/in.bin"), 124, 456, &config, &limits));
+        assert!(!loaded.matches(
+            Path::new("/data/in.bin"),
+            123,
+            456,
+            &config,
+            &SplitLimits::default()
+        ));
+    }
+}
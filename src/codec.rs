@@ -0,0 +1,935 @@
+use alloc::vec::Vec;
+use core::fmt;
+
+use reed_solomon::{Decoder, Encoder};
+
+/// Map a quantization level (0..levels-1) to a pixel channel value (0..255)
+pub fn quantize(value: u8, levels: u8) -> u8 {
+    if levels <= 1 {
+        return 0;
+    }
+    (value as u16 * 255 / (levels as u16 - 1)) as u8
+}
+
+/// Map a pixel channel value (0..255) to the nearest quantization level (0..levels-1)
+pub fn dequantize(pixel: u8, levels: u8) -> u8 {
+    if levels <= 1 {
+        return 0;
+    }
+    // pixel * (levels - 1) / 255, rounded; never exactly half way
+    let steps = levels as u32 - 1;
+    (((pixel as u32 * steps * 2 + 255) / 510) as u8).min(levels - 1)
+}
+
+/// Map a quantization level to the centre of its bin of 256/levels pixel
+/// values (32, 96, 160, 224 for 4 levels), so the lowest and highest levels
+/// have room on both sides before clipping
+pub fn quantize_centered(value: u8, levels: u8) -> u8 {
+    if levels <= 1 {
+        return 0;
+    }
+    ((value as u16 * 2 + 1) * 128 / levels as u16) as u8
+}
+
+/// Map a pixel channel value to the bin it falls in, each level's bin
+/// 256/levels values wide
+pub fn dequantize_centered(pixel: u8, levels: u8) -> u8 {
+    if levels <= 1 {
+        return 0;
+    }
+    (pixel as u16 * levels as u16 / 256) as u8
+}
+
+/// [`quantize`] of every value, so whole rows are mapped by lookup.
+pub fn quantize_table(levels: u8) -> [u8; 256] {
+    core::array::from_fn(|v| quantize(v as u8, levels))
+}
+
+/// [`dequantize`] of every pixel value.
+pub fn dequantize_table(levels: u8) -> [u8; 256] {
+    core::array::from_fn(|p| dequantize(p as u8, levels))
+}
+
+/// Bits carried by a channel of `levels` levels (a power of 2).
+pub fn bits_for(levels: u8) -> u8 {
+    levels.max(1).ilog2() as u8
+}
+
+// ── Bit stream helpers ──────────────────────────────────────────────────────
+
+pub struct BitWriter {
+    bytes: Vec<u8>,
+    /// Pending bits, in the low `count` bits
+    current: u16,
+    count: u8,
+}
+
+impl Default for BitWriter {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl BitWriter {
+    pub fn new() -> Self {
+        Self {
+            bytes: Vec::new(),
+            current: 0,
+            count: 0,
+        }
+    }
+
+    /// Write `num_bits` from `value` (MSB first). num_bits must be <= 8.
+    pub fn write_bits(&mut self, value: u8, num_bits: u8) {
+        if self.count == 0 && num_bits == 8 {
+            self.bytes.push(value);
+            return;
+        }
+        let mask = (1u16 << num_bits) - 1;
+        self.current = (self.current << num_bits) | (value as u16 & mask);
+        self.count += num_bits;
+        if self.count >= 8 {
+            self.count -= 8;
+            self.bytes.push((self.current >> self.count) as u8);
+            self.current &= (1 << self.count) - 1;
+        }
+    }
+
+    /// Write every bit of `data`, as [`BitWriter::write_bits`] with 8 bits
+    /// per byte.
+    pub fn write_bytes(&mut self, data: &[u8]) {
+        if self.count == 0 {
+            self.bytes.extend_from_slice(data);
+            return;
+        }
+        self.bytes.reserve(data.len());
+        for &byte in data {
+            self.write_bits(byte, 8);
+        }
+    }
+
+    pub fn finish(mut self) -> Vec<u8> {
+        if self.count > 0 {
+            self.bytes.push((self.current << (8 - self.count)) as u8);
+        }
+        self.bytes
+    }
+}
+
+pub struct BitReader<'a> {
+    data: &'a [u8],
+    byte_pos: usize,
+    bit_pos: u8,
+}
+
+impl<'a> BitReader<'a> {
+    pub fn new(data: &'a [u8]) -> Self {
+        Self {
+            data,
+            byte_pos: 0,
+            bit_pos: 0,
+        }
+    }
+
+    /// Read `num_bits` (MSB first). Pads with 0 if past end of data.
+    pub fn read_bits(&mut self, num_bits: u8) -> u8 {
+        if num_bits == 0 {
+            return 0;
+        }
+        let byte = |i: usize| self.data.get(i).copied().unwrap_or(0);
+        if self.bit_pos == 0 && num_bits == 8 {
+            self.byte_pos += 1;
+            return byte(self.byte_pos - 1);
+        }
+        // The bits straddle at most two bytes
+        let window = u16::from_be_bytes([byte(self.byte_pos), byte(self.byte_pos + 1)]);
+        let value = ((window << self.bit_pos) >> (16 - num_bits)) as u8;
+        self.bit_pos += num_bits;
+        self.byte_pos += self.bit_pos as usize / 8;
+        self.bit_pos %= 8;
+        value
+    }
+
+    /// Fill `out` with the next bytes, as [`BitReader::read_bits`] with 8
+    /// bits per byte.
+    pub fn read_bytes(&mut self, out: &mut [u8]) {
+        if self.bit_pos != 0 {
+            out.iter_mut().for_each(|b| *b = self.read_bits(8));
+            return;
+        }
+        let rest = self.data.get(self.byte_pos..).unwrap_or_default();
+        let n = rest.len().min(out.len());
+        out[..n].copy_from_slice(&rest[..n]);
+        out[n..].fill(0);
+        self.byte_pos += out.len();
+    }
+}
+
+// ── Checksums and Reed-Solomon blocks ───────────────────────────────────────
+
+/// CRC-16/CCITT-FALSE of `data`.
+pub fn crc16(data: &[u8]) -> u16 {
+    let mut crc = 0xFFFFu16;
+    for &byte in data {
+        crc ^= (byte as u16) << 8;
+        for _ in 0..8 {
+            crc = if crc & 0x8000 != 0 {
+                crc << 1 ^ 0x1021
+            } else {
+                crc << 1
+            };
+        }
+    }
+    crc
+}
+
+/// Reed-Solomon encode `data` into the 255-byte blocks of `out`, one per
+/// `rs_data_len` bytes, the last zero-padded.
+pub fn rs_encode_blocks(data: &[u8], out: &mut [u8], ecc_len: usize, rs_data_len: usize) {
+    let block_len = rs_data_len + ecc_len;
+    let enc = Encoder::new(ecc_len);
+    let mut padded = alloc::vec![0u8; rs_data_len];
+    for (chunk, out) in data.chunks(rs_data_len).zip(out.chunks_mut(block_len)) {
+        let input = if chunk.len() < rs_data_len {
+            padded[..chunk.len()].copy_from_slice(chunk);
+            &padded[..]
+        } else {
+            chunk
+        };
+        out.copy_from_slice(&enc.encode(input));
+    }
+}
+
+/// Correct one RS `block` into `out` (its `rs_data_len` data bytes), first
+/// with the `erasures` if given, and return the symbols corrected; `None`,
+/// with the block's data copied as it is, if it is beyond repair.
+pub fn rs_decode_block(
+    dec: &Decoder,
+    block: &[u8],
+    erasures: Option<&[u8]>,
+    out: &mut [u8],
+) -> Option<usize> {
+    let fixed = erasures
+        .and_then(|e| dec.correct_err_count(block, Some(e)).ok())
+        .map_or_else(|| dec.correct_err_count(block, None), Ok);
+    match fixed {
+        Ok((corrected, fixed)) => {
+            out.copy_from_slice(corrected.data());
+            Some(fixed)
+        }
+        Err(_) => {
+            out.copy_from_slice(&block[..out.len()]);
+            None
+        }
+    }
+}
+
+// ── Frame headers ───────────────────────────────────────────────────────────
+
+/// Why a frame header was rejected; converts into
+/// [`crate::error::VstorageError::Header`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum HeaderError {
+    TooShort,
+    TooShortForCopies,
+    Magic([u8; 4]),
+    Version(u8),
+    Crc,
+    ChannelLevels([u8; 3]),
+    Kdf(u8),
+    Compression(u8, u8),
+    Layout(u8),
+    TokenMode(u8),
+}
+
+impl fmt::Display for HeaderError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::TooShort => write!(f, "buffer too short"),
+            Self::TooShortForCopies => write!(f, "header data too short for triple decode"),
+            Self::Magic(magic) => write!(f, "invalid magic: {magic:?}"),
+            Self::Version(version) => write!(f, "unsupported version: {version}"),
+            Self::Crc => write!(f, "header CRC mismatch"),
+            Self::ChannelLevels(bits) => write!(f, "invalid channel levels: {bits:?}"),
+            Self::Kdf(id) => write!(f, "unknown key derivation id {id}"),
+            Self::Compression(id, level) => {
+                write!(f, "unknown compression id {id} (level {level})")
+            }
+            Self::Layout(id) => write!(f, "unknown layout id {id}"),
+            Self::TokenMode(bits) => write!(f, "unknown token mode {bits:#04x}"),
+        }
+    }
+}
+
+/// Password-based key derivation of an encrypted payload, recorded in its
+/// frame headers.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "lowercase"))]
+pub enum Kdf {
+    /// Argon2id with the argon2 crate's default parameters (19 MiB)
+    #[default]
+    Argon2id,
+    /// scrypt with N = 2^15, r = 8, p = 1 (32 MiB)
+    Scrypt,
+}
+
+impl Kdf {
+    /// Id of the KDF in the frame header.
+    pub fn id(self) -> u8 {
+        match self {
+            Self::Argon2id => 0,
+            Self::Scrypt => 1,
+        }
+    }
+
+    pub fn from_id(id: u8) -> Result<Self, HeaderError> {
+        match id {
+            0 => Ok(Self::Argon2id),
+            1 => Ok(Self::Scrypt),
+            _ => Err(HeaderError::Kdf(id)),
+        }
+    }
+}
+
+/// Compression of a file before it is cut into frames, recorded in its frame
+/// headers so decode undoes it.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "lowercase"))]
+pub enum Compression {
+    #[default]
+    None,
+    /// DEFLATE at level 0-9: smaller output, for size-bound storage
+    Deflate(u8),
+    /// LZ4 block format: fast, for CPU-bound encodes
+    Lz4,
+    /// Zstandard at level 1-22: fast at low levels, close to xz at high ones
+    Zstd(u8),
+    /// xz (LZMA2) at level 0-9: the smallest output and the slowest
+    Xz(u8),
+}
+
+impl Compression {
+    /// Algorithm id and level in the frame header.
+    pub fn id(self) -> (u8, u8) {
+        match self {
+            Self::None => (0, 0),
+            Self::Deflate(level) => (1, level),
+            Self::Lz4 => (2, 0),
+            Self::Zstd(level) => (3, level),
+            Self::Xz(level) => (4, level),
+        }
+    }
+
+    pub fn from_id(id: u8, level: u8) -> Result<Self, HeaderError> {
+        match (id, level) {
+            (0, _) => Ok(Self::None),
+            (1, 0..=9) => Ok(Self::Deflate(level)),
+            (2, _) => Ok(Self::Lz4),
+            (3, 1..=22) => Ok(Self::Zstd(level)),
+            (4, 0..=9) => Ok(Self::Xz(level)),
+            _ => Err(HeaderError::Compression(id, level)),
+        }
+    }
+}
+
+/// Whether the key of a password-encrypted payload also needs a hardware
+/// token's response to its salt (see [`crate::crypto::Token`]), recorded in
+/// the top two bits of the key derivation byte so decode can ask for it.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "kebab-case"))]
+pub enum TokenMode {
+    /// The password alone
+    #[default]
+    Off,
+    /// The password and the token
+    WithPassword,
+    /// The token alone; the password is ignored
+    Only,
+}
+
+impl TokenMode {
+    /// Bits of the mode in the key derivation byte.
+    pub fn bits(self) -> u8 {
+        match self {
+            Self::Off => 0,
+            Self::WithPassword => 0x80,
+            Self::Only => 0xc0,
+        }
+    }
+
+    /// The mode in the top two bits of `byte`.
+    pub fn from_bits(byte: u8) -> Result<Self, HeaderError> {
+        match byte & 0xc0 {
+            0 => Ok(Self::Off),
+            0x80 => Ok(Self::WithPassword),
+            0xc0 => Ok(Self::Only),
+            bits => Err(HeaderError::TokenMode(bits)),
+        }
+    }
+}
+
+/// Order in which the bytes of a frame's data area are laid onto its blocks,
+/// recorded in the frame headers.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "lowercase"))]
+pub enum Layout {
+    /// Row by row, each RS codeword in one run of blocks
+    #[default]
+    Raster,
+    /// Along a Hilbert curve, with the RS codewords interleaved byte by
+    /// byte: a damaged patch of blocks is a few runs of the curve, and each
+    /// run is shared out over many codewords
+    Hilbert,
+}
+
+impl Layout {
+    /// Id of the layout in the frame header.
+    pub fn id(self) -> u8 {
+        match self {
+            Self::Raster => 0,
+            Self::Hilbert => 1,
+        }
+    }
+
+    pub fn from_id(id: u8) -> Result<Self, HeaderError> {
+        match id {
+            0 => Ok(Self::Raster),
+            1 => Ok(Self::Hilbert),
+            _ => Err(HeaderError::Layout(id)),
+        }
+    }
+}
+
+pub const HEADER_SIZE: usize = 120;
+/// Header size of protocol version 1, the first release: the fields up to
+/// the data hash, without a CRC.
+pub const HEADER_SIZE_V1: usize = 90;
+pub const MAGIC: &[u8; 4] = b"VSTR";
+
+/// Most logical rows a header area may take.
+pub const MAX_HEADER_ROWS: usize = 16;
+/// Most copies of the header a header area may hold.
+pub const MAX_HEADER_COPIES: usize = 9;
+/// Bytes of the preamble (see [`encode_preamble`]).
+pub const PREAMBLE_LEN: usize = 12;
+
+/// Serialized length of a header of protocol `version`, or `None` if this
+/// build cannot read that version.
+pub fn header_size(version: u8) -> Option<usize> {
+    match version {
+        2 => Some(HEADER_SIZE),
+        1 => Some(HEADER_SIZE_V1),
+        _ => None,
+    }
+}
+
+/// Frame carrying a chunk of a payload.
+pub const KIND_DATA: u8 = 0;
+/// Frame carrying a [`crate::index::PayloadIndex`] instead of payload data.
+pub const KIND_INDEX: u8 = 1;
+/// Frame carrying part of the [`crate::pieces::PieceHashes`] of payload 0;
+/// these follow its data frames.
+pub const KIND_PIECES: u8 = 2;
+/// Frame carrying the key list of a payload encrypted to
+/// [`crate::recipient::Recipient`]s; it follows the data frames.
+pub const KIND_KEYS: u8 = 3;
+/// Random filler after the piece frames, possibly holding a hidden file
+/// (see [`crate::hidden`]).
+pub const KIND_PADDING: u8 = 4;
+/// Frame carrying the [`crate::signature`] of payload 0, between its piece
+/// and padding frames.
+pub const KIND_SIGNATURE: u8 = 5;
+/// Instructions for people (see [`crate::intro`]) before the first data
+/// frame; otherwise the header of that frame, so the video's settings can be
+/// read from either. Decoders skip it.
+pub const KIND_INTRO: u8 = 6;
+/// Payload id used by index frames.
+pub const INDEX_PAYLOAD: u16 = 0xFFFF;
+/// `file_size` of the data frames of a streamed payload (see
+/// [`FrameHeader::streamed`]) before its last.
+pub const STREAMED_SIZE: u64 = u64::MAX;
+
+/// Frame header containing metadata for one video frame. A version 1
+/// header has the fields up to `data_sha256`; the rest read as a single
+/// unsplit, uncompressed, raster payload 0 (see [`FrameHeader::deserialize`]).
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct FrameHeader {
+    pub version: u8,
+    pub frame_number: u32,
+    pub total_frames: u32,
+    pub block_size: u8,
+    pub levels: u8,
+    pub file_size: u64,
+    pub data_length: u32,
+    pub ecc_len: u8,
+    /// Always `255 - ecc_len`; from version 2 its two bytes hold a CRC-16
+    /// of the rest of the header instead (see [`header_crc`])
+    pub rs_data_len: u16,
+    pub nonce: [u8; 12],
+    pub salt: [u8; 16],
+    pub data_sha256: [u8; 32],
+    /// KIND_DATA, KIND_INDEX, KIND_PIECES, KIND_KEYS, KIND_PADDING,
+    /// KIND_SIGNATURE or KIND_INTRO
+    pub kind: u8,
+    /// Which payload of the video this frame belongs to (0 unless appended)
+    pub payload: u16,
+    /// Index (0-based) of the output video holding this frame
+    pub part: u16,
+    /// Number of videos the encoding was split into
+    pub total_parts: u16,
+    /// Random id shared by every frame of one encoding, so parts of different
+    /// encodings are not mixed up (all zero in frames written by append or
+    /// pack, which are never split)
+    pub set_id: [u8; 16],
+    /// How the key is derived from the password
+    pub kdf: Kdf,
+    /// Whether the key also needs a hardware token; the top two bits of the
+    /// key derivation byte
+    pub token: TokenMode,
+    /// How the payload was compressed before framing
+    pub compression: Compression,
+    /// Levels of this frame's data area; the header area always uses
+    /// `levels`
+    pub data_levels: u8,
+    /// Levels of the red, green and blue channels of the data frames, the
+    /// largest being `levels`; stored as their log2 in a nibble each
+    pub channel_levels: [u8; 3],
+    /// Order of the data area's bytes on its blocks
+    pub layout: Layout,
+    /// Whether the frames keep a CRC map (see [`crate::crcmap`]); the top bit
+    /// of the layout byte
+    pub crc_map: bool,
+    /// Whether the frames repeat the header area in their bottom rows; the
+    /// next bit of the layout byte
+    pub bottom_header: bool,
+    /// Whether the frames carry a banner (see [`crate::banner`]); the next
+    /// bit of the layout byte
+    pub banner: bool,
+    /// Whether the frames carry a frame-number barcode (see
+    /// [`crate::barcode`]); the next bit of the layout byte
+    pub barcode: bool,
+    /// Whether the data areas hold pilot blocks (see [`crate::pilot`]); the
+    /// next bit of the layout byte
+    pub pilots: bool,
+    /// Whether the data areas' levels sit at their bin centres (see
+    /// [`crate::frame::quantize_centered`]); the next bit of the layout byte
+    pub bin_centers: bool,
+    /// Whether the payload was encoded from a stream of unknown length: its
+    /// data frames record [`STREAMED_SIZE`] and no frame count, apart from
+    /// the last, which records both; the low bit of byte 118
+    pub streamed: bool,
+}
+
+impl FrameHeader {
+    /// Associated data the chunks of this frame's payload are sealed with
+    /// (see [`crate::crypto::FrameCipher::with_context`]): the
+    /// [`cipher_context`] of its fields, or nothing in version 1, which seals
+    /// the whole payload as one message. A streamed payload is sealed before
+    /// its size is known, so under [`STREAMED_SIZE`] and no frame count.
+    pub fn cipher_context(&self) -> Vec<u8> {
+        let (file_size, total_frames) = match self.streamed {
+            true => (STREAMED_SIZE, 0),
+            false => (self.file_size, self.total_frames),
+        };
+        match self.version {
+            1 => Vec::new(),
+            _ => cipher_context(file_size, total_frames, self.payload, self.compression),
+        }
+    }
+
+    /// Levels of each channel of this frame's data area: `channel_levels`,
+    /// capped at `data_levels`.
+    pub fn area_levels(&self) -> [u8; 3] {
+        self.channel_levels.map(|l| l.min(self.data_levels))
+    }
+
+    /// Serialize to HEADER_SIZE bytes (big-endian), with the CRC of
+    /// [`header_crc`] from version 2; a version 1 header is the first
+    /// [`HEADER_SIZE_V1`] of them.
+    pub fn serialize(&self) -> [u8; HEADER_SIZE] {
+        let mut buf = [0u8; HEADER_SIZE];
+        buf[0..4].copy_from_slice(MAGIC);
+        buf[4] = self.version;
+        buf[5..9].copy_from_slice(&self.frame_number.to_be_bytes());
+        buf[9..13].copy_from_slice(&self.total_frames.to_be_bytes());
+        buf[13] = self.block_size;
+        buf[14] = self.levels;
+        buf[15..23].copy_from_slice(&self.file_size.to_be_bytes());
+        buf[23..27].copy_from_slice(&self.data_length.to_be_bytes());
+        buf[27] = self.ecc_len;
+        buf[28..30].copy_from_slice(&self.rs_data_len.to_be_bytes());
+        buf[30..42].copy_from_slice(&self.nonce);
+        buf[42..58].copy_from_slice(&self.salt);
+        buf[58..90].copy_from_slice(&self.data_sha256);
+        buf[90] = self.kind;
+        buf[91..93].copy_from_slice(&self.payload.to_be_bytes());
+        buf[93..95].copy_from_slice(&self.part.to_be_bytes());
+        buf[95..97].copy_from_slice(&self.total_parts.to_be_bytes());
+        buf[97..113].copy_from_slice(&self.set_id);
+        buf[113] = self.kdf.id() | self.token.bits();
+        (buf[114], buf[115]) = self.compression.id();
+        buf[116] = self.data_levels;
+        let [r, g, b] = self.channel_levels.map(|l| l.trailing_zeros() as u8);
+        (buf[117], buf[118]) = (r << 4 | g, b << 4 | u8::from(self.streamed));
+        buf[119] = self.layout.id()
+            | u8::from(self.crc_map) << 7
+            | u8::from(self.bottom_header) << 6
+            | u8::from(self.banner) << 5
+            | u8::from(self.barcode) << 4
+            | u8::from(self.pilots) << 3
+            | u8::from(self.bin_centers) << 2;
+        if self.version != 1 {
+            let crc = header_crc(&buf);
+            buf[28..30].copy_from_slice(&crc.to_be_bytes());
+        }
+        buf
+    }
+
+    /// Deserialize from bytes, checking the CRC; a version 1 header is
+    /// [`HEADER_SIZE_V1`] long and has none.
+    pub fn deserialize(buf: &[u8]) -> Result<Self, HeaderError> {
+        if buf.len() < HEADER_SIZE_V1 {
+            return Err(HeaderError::TooShort);
+        }
+        if &buf[0..4] != MAGIC {
+            return Err(HeaderError::Magic(buf[0..4].try_into().unwrap()));
+        }
+        let version = buf[4];
+        let Some(size) = header_size(version) else {
+            return Err(HeaderError::Version(version));
+        };
+        if buf.len() < size {
+            return Err(HeaderError::TooShort);
+        }
+        if version == 1 {
+            return Ok(Self::deserialize_v1(buf));
+        }
+        let stored = u16::from_be_bytes(buf[28..30].try_into().unwrap());
+        if stored != header_crc(&buf[..HEADER_SIZE]) {
+            return Err(HeaderError::Crc);
+        }
+        let bits = [buf[117] >> 4, buf[117] & 0xf, buf[118] >> 4];
+        if bits.iter().any(|b| !(1..8).contains(b)) {
+            return Err(HeaderError::ChannelLevels(bits));
+        }
+        Ok(Self {
+            version,
+            frame_number: u32::from_be_bytes(buf[5..9].try_into().unwrap()),
+            total_frames: u32::from_be_bytes(buf[9..13].try_into().unwrap()),
+            block_size: buf[13],
+            levels: buf[14],
+            file_size: u64::from_be_bytes(buf[15..23].try_into().unwrap()),
+            data_length: u32::from_be_bytes(buf[23..27].try_into().unwrap()),
+            ecc_len: buf[27],
+            rs_data_len: 255 - buf[27] as u16,
+            nonce: buf[30..42].try_into().unwrap(),
+            salt: buf[42..58].try_into().unwrap(),
+            data_sha256: buf[58..90].try_into().unwrap(),
+            kind: buf[90],
+            payload: u16::from_be_bytes(buf[91..93].try_into().unwrap()),
+            part: u16::from_be_bytes(buf[93..95].try_into().unwrap()),
+            total_parts: u16::from_be_bytes(buf[95..97].try_into().unwrap()),
+            set_id: buf[97..113].try_into().unwrap(),
+            kdf: Kdf::from_id(buf[113] & 0x3f)?,
+            token: TokenMode::from_bits(buf[113])?,
+            compression: Compression::from_id(buf[114], buf[115])?,
+            data_levels: buf[116],
+            channel_levels: bits.map(|b| 1 << b),
+            layout: Layout::from_id(buf[119] & 0x03)?,
+            crc_map: buf[119] & 0x80 != 0,
+            bottom_header: buf[119] & 0x40 != 0,
+            banner: buf[119] & 0x20 != 0,
+            barcode: buf[119] & 0x10 != 0,
+            pilots: buf[119] & 0x08 != 0,
+            bin_centers: buf[119] & 0x04 != 0,
+            streamed: buf[118] & 0x01 != 0,
+        })
+    }
+
+    /// A version 1 header, whose fields all deserialize.
+    fn deserialize_v1(buf: &[u8]) -> Self {
+        let levels = buf[14];
+        Self {
+            version: 1,
+            frame_number: u32::from_be_bytes(buf[5..9].try_into().unwrap()),
+            total_frames: u32::from_be_bytes(buf[9..13].try_into().unwrap()),
+            block_size: buf[13],
+            levels,
+            file_size: u64::from_be_bytes(buf[15..23].try_into().unwrap()),
+            data_length: u32::from_be_bytes(buf[23..27].try_into().unwrap()),
+            ecc_len: buf[27],
+            rs_data_len: u16::from_be_bytes(buf[28..30].try_into().unwrap()),
+            nonce: buf[30..42].try_into().unwrap(),
+            salt: buf[42..58].try_into().unwrap(),
+            data_sha256: buf[58..90].try_into().unwrap(),
+            kind: KIND_DATA,
+            payload: 0,
+            part: 0,
+            total_parts: 1,
+            set_id: [0; 16],
+            kdf: Kdf::Argon2id,
+            token: TokenMode::Off,
+            compression: Compression::None,
+            data_levels: levels,
+            channel_levels: [levels; 3],
+            layout: Layout::Raster,
+            crc_map: false,
+            bottom_header: false,
+            banner: false,
+            barcode: false,
+            pilots: false,
+            bin_centers: false,
+            streamed: false,
+        }
+    }
+}
+
+/// The header fields every data frame of a payload shares, as associated
+/// data for its chunks: a frame whose header says otherwise, e.g. about the
+/// file's size, fails to authenticate. The frame number is already in the
+/// nonce.
+pub fn cipher_context(
+    file_size: u64,
+    total_frames: u32,
+    payload: u16,
+    compression: Compression,
+) -> Vec<u8> {
+    let (algo, level) = compression.id();
+    let mut context = Vec::with_capacity(16);
+    context.extend_from_slice(&file_size.to_be_bytes());
+    context.extend_from_slice(&total_frames.to_be_bytes());
+    context.extend_from_slice(&payload.to_be_bytes());
+    context.extend_from_slice(&[algo, level]);
+    context
+}
+
+/// CRC-16 of a serialized header, its CRC bytes (28 and 29) left out.
+pub fn header_crc(buf: &[u8]) -> u16 {
+    crc16(&[&buf[..28], &buf[30..HEADER_SIZE]].concat())
+}
+
+/// Encode header with triple redundancy for error resilience.
+pub fn encode_header_triple(header: &FrameHeader) -> Vec<u8> {
+    encode_header_copies(header, 3)
+}
+
+/// Encode header `copies` times over.
+pub fn encode_header_copies(header: &FrameHeader, copies: usize) -> Vec<u8> {
+    header.serialize().repeat(copies)
+}
+
+/// Per-byte plurality of the `copies` headers in `data` (ties go to the
+/// earliest copy); bytes past the end of `data` are left out of the vote.
+/// If the vote does not deserialize (e.g. fails its CRC), the first copy
+/// that does.
+pub fn vote_copies(data: &[u8], copies: usize) -> Vec<u8> {
+    let voted: Vec<u8> = (0..HEADER_SIZE)
+        .map(|i| {
+            let values: Vec<u8> = (0..copies)
+                .filter_map(|c| data.get(c * HEADER_SIZE + i).copied())
+                .collect();
+            let mut best = (0u8, 0usize);
+            for &v in &values {
+                let n = values.iter().filter(|&&w| w == v).count();
+                if n > best.1 {
+                    best = (v, n);
+                }
+            }
+            best.0
+        })
+        .collect();
+    if FrameHeader::deserialize(&voted).is_ok() {
+        return voted;
+    }
+    data.chunks_exact(HEADER_SIZE)
+        .take(copies)
+        .find(|copy| FrameHeader::deserialize(copy).is_ok())
+        .map_or(voted, <[u8]>::to_vec)
+}
+
+/// The preamble ahead of a header area of `rows` rows holding `copies`
+/// copies of the header: both counts and their complements, three times.
+pub fn encode_preamble(rows: usize, copies: usize) -> [u8; PREAMBLE_LEN] {
+    let (rows, copies) = (rows as u8, copies as u8);
+    let once = [rows, copies, !rows, !copies];
+    once.repeat(3).try_into().unwrap()
+}
+
+/// Rows and copies from the first copy in a preamble that checks out and
+/// is within bounds.
+pub fn decode_preamble(bytes: &[u8]) -> Option<(usize, usize)> {
+    bytes.chunks_exact(4).find_map(|c| {
+        let (rows, copies) = (c[0] as usize, c[1] as usize);
+        (c[2] == !c[0]
+            && c[3] == !c[1]
+            && (1..=MAX_HEADER_ROWS).contains(&rows)
+            && (1..=MAX_HEADER_COPIES).contains(&copies))
+        .then_some((rows, copies))
+    })
+}
+
+/// Decode header from triple-redundant data using byte-level majority vote.
+pub fn decode_header_triple(data: &[u8]) -> Result<FrameHeader, HeaderError> {
+    if data.len() < HEADER_SIZE_V1 * 3 {
+        return Err(HeaderError::TooShortForCopies);
+    }
+
+    // Each version's copies are as long as its header, so try both lengths
+    // and keep the header whose version matches it
+    let mut first_err = None;
+    for size in [HEADER_SIZE, HEADER_SIZE_V1] {
+        if data.len() < size * 3 {
+            continue;
+        }
+        let err = match vote(data, size) {
+            Ok(hdr) if header_size(hdr.version) == Some(size) => return Ok(hdr),
+            Ok(hdr) => HeaderError::Version(hdr.version),
+            Err(e) => e,
+        };
+        first_err.get_or_insert(err);
+    }
+    Err(first_err.unwrap())
+}
+
+/// Majority vote over three copies of a `size`-byte header; if the voted
+/// header is rejected (e.g. by its CRC, when no two copies agree on a
+/// byte), the first copy that deserializes on its own.
+fn vote(data: &[u8], size: usize) -> Result<FrameHeader, HeaderError> {
+    let voted: Vec<u8> = (0..size)
+        .map(|i| majority_vote(data[i], data[size + i], data[2 * size + i]))
+        .collect();
+    FrameHeader::deserialize(&voted).or_else(|e| {
+        data.chunks_exact(size)
+            .take(3)
+            .find_map(|copy| FrameHeader::deserialize(copy).ok())
+            .ok_or(e)
+    })
+}
+
+fn majority_vote(a: u8, b: u8, c: u8) -> u8 {
+    if a == b || a == c {
+        a
+    } else if b == c {
+        b
+    } else {
+        a // no majority — return first
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::vec;
+
+    #[test]
+    fn test_quantize_roundtrip() {
+        for levels in [2u8, 4, 8, 16] {
+            for v in 0..levels {
+                let pixel = quantize(v, levels);
+                let recovered = dequantize(pixel, levels);
+                assert_eq!(v, recovered, "levels={levels}, value={v}, pixel={pixel}");
+            }
+        }
+    }
+
+    #[test]
+    fn test_level_tables() {
+        for levels in [2u8, 4, 8, 16] {
+            let (quantized, dequantized) = (quantize_table(levels), dequantize_table(levels));
+            for v in 0..levels {
+                assert_eq!(quantized[v as usize], quantize(v, levels));
+            }
+            for p in 0..=255u8 {
+                assert_eq!(dequantized[p as usize], dequantize(p, levels));
+                // The nearest level, as computed in floating point
+                let step = 255.0 / (levels as f64 - 1.0);
+                assert_eq!(dequantize(p, levels), (p as f64 / step).round() as u8);
+            }
+            assert_eq!(1 << bits_for(levels), levels);
+        }
+    }
+
+    #[test]
+    fn test_noise_tolerance() {
+        let levels = 4u8;
+        for v in 0..levels {
+            let pixel = quantize(v, levels);
+            // Add noise up to ±30
+            for noise in -30i16..=30 {
+                let noisy = (pixel as i16 + noise).clamp(0, 255) as u8;
+                let recovered = dequantize(noisy, levels);
+                assert_eq!(
+                    v, recovered,
+                    "levels={levels}, value={v}, pixel={pixel}, noise={noise}, noisy={noisy}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_bit_roundtrip() {
+        let data = vec![0xDE, 0xAD, 0xBE, 0xEF];
+        let mut reader = BitReader::new(&data);
+        let mut writer = BitWriter::new();
+        for _ in 0..(data.len() * 8 / 2) {
+            let bits = reader.read_bits(2);
+            writer.write_bits(bits, 2);
+        }
+        assert_eq!(writer.finish(), data);
+    }
+
+    #[test]
+    fn test_bits_mixed_widths() {
+        // One bit at a time, MSB first, as the reference
+        let mut bits = Vec::new();
+        let mut push = |value: u8, n: u8| (0..n).rev().for_each(|i| bits.push((value >> i) & 1));
+        let mut writer = BitWriter::new();
+        let mut widths = Vec::new();
+        for i in 0..200u32 {
+            let (value, n) = ((i.wrapping_mul(2654435761) >> 13) as u8, (i % 9) as u8);
+            writer.write_bits(value, n);
+            push(value, n);
+            widths.push(n);
+            if i % 50 == 7 {
+                writer.write_bytes(b"vstorage");
+                b"vstorage".iter().for_each(|&b| push(b, 8));
+            }
+        }
+        let packed = writer.finish();
+        let expected: Vec<u8> = bits
+            .chunks(8)
+            .map(|c| {
+                c.iter()
+                    .enumerate()
+                    .fold(0, |acc, (i, b)| acc | b << (7 - i))
+            })
+            .collect();
+        assert_eq!(packed, expected);
+
+        let mut reader = BitReader::new(&packed);
+        let mut pos = 0;
+        for (i, &n) in widths.iter().enumerate() {
+            let value = bits[pos..pos + n as usize]
+                .iter()
+                .fold(0, |acc, b| acc << 1 | b);
+            assert_eq!(reader.read_bits(n), value, "read {i}");
+            pos += n as usize;
+            if i % 50 == 7 {
+                let mut word = [0u8; 8];
+                reader.read_bytes(&mut word);
+                assert_eq!(&word, b"vstorage");
+                pos += 64;
+            }
+        }
+        // Past the end reads as zeros, aligned or not
+        let mut tail = [0xffu8; 4];
+        reader.read_bytes(&mut tail);
+        assert_eq!(tail, [0; 4]);
+        assert_eq!(reader.read_bits(5), 0);
+    }
+}
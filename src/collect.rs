@@ -0,0 +1,1457 @@
+use std::borrow::Cow;
+use std::collections::BTreeSet;
+use std::fmt;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::OnceLock;
+
+use sha2::{Digest, Sha256};
+use zeroize::Zeroizing;
+
+use crate::config::{worker_threads, FrameConfig};
+use crate::crcmap::{self, TileDamage};
+use crate::error::{DamagedBlock, Result, VstorageError};
+use crate::frame::Preprocess;
+use crate::header::FrameHeader;
+use crate::index::PayloadIndex;
+use crate::json::Json;
+use crate::pieces::{PieceCheck, PieceHashes, PIECE_NONCE_BASE};
+use crate::recipient::{self, Identity};
+use crate::recovery::{GapKind, GapReport};
+use crate::signature::{self, VerifyKey, SIGNATURE_NONCE};
+use crate::{crypto, ecc, frame, header};
+
+/// Decrypt the collected frames in order and cut the result to the recorded
+/// file size. With `partial`, frames that are missing or fail authentication
+/// are zero-filled and reported instead of failing.
+pub(crate) fn assemble(
+    collected: &mut Collected,
+    cipher: Option<&crypto::FrameCipher>,
+    partial: bool,
+) -> Result<(Zeroizing<Vec<u8>>, GapReport)> {
+    if let (1, Some(cipher)) = (collected.first_header.version, cipher) {
+        return assemble_sealed(collected, cipher);
+    }
+    let file_size = collected.first_header.file_size;
+    let per_frame = collected.config.max_plain_per_frame(cipher.is_some()) as u64;
+    let mut report = GapReport::new(file_size);
+    let mut plaintext = Zeroizing::new(Vec::with_capacity(file_size as usize));
+    let mut decrypted = match cipher {
+        Some(c) => decrypt_all(
+            c,
+            &mut collected.chunks,
+            collected.first_header.streamed,
+            collected.config.worker_threads(),
+        ),
+        None => Vec::new(),
+    };
+    let mut authenticated = false;
+    for i in 0..collected.chunks.len() {
+        let frame = match cipher {
+            Some(_) => decrypted[i].take(),
+            None => collected.chunks[i].take().map(|c| Ok(Zeroizing::new(c))),
+        };
+        let plain = match frame {
+            Some(Ok(plain)) => {
+                authenticated = true;
+                Some(plain)
+            }
+            Some(Err(e)) => {
+                // A password that opens no frame at all is wrong, not the
+                // data corrupted
+                if !authenticated && !decrypted[i + 1..].iter().any(|d| matches!(d, Some(Ok(_)))) {
+                    return Err(VstorageError::WrongPassword);
+                }
+                authenticated = true;
+                if !partial {
+                    return Err(e);
+                }
+                report.add_frame(i, per_frame, GapKind::Missing, "authentication failed");
+                None
+            }
+            None => {
+                let reason = if collected.failures.iter().any(|(f, _)| *f == i) {
+                    "uncorrectable"
+                } else {
+                    "frame not found"
+                };
+                report.add_frame(i, per_frame, GapKind::Missing, reason);
+                None
+            }
+        };
+        match plain {
+            Some(plain) => {
+                if collected.checks[i].is_some_and(|c| c.hash_ok == Some(false)) {
+                    if !partial {
+                        return Err(VstorageError::HashMismatch { frame: i });
+                    }
+                    report.add_frame(i, per_frame, GapKind::Suspect, "data hash mismatch");
+                }
+                plaintext.extend_from_slice(&plain);
+            }
+            None => {
+                let len = per_frame.min(file_size.saturating_sub(i as u64 * per_frame));
+                let end = plaintext.len() + len as usize;
+                plaintext.resize(end, 0);
+            }
+        }
+    }
+
+    if (plaintext.len() as u64) < file_size {
+        return Err(VstorageError::Header(format!(
+            "decoded {} bytes but header records file_size={file_size}",
+            plaintext.len()
+        )));
+    }
+    plaintext.truncate(file_size as usize);
+    Ok((plaintext, report))
+}
+
+/// [`assemble`] for a version 1 payload, encrypted as one message across
+/// its frames: it opens only with every frame there, so nothing is
+/// recovered in part.
+fn assemble_sealed(
+    collected: &mut Collected,
+    cipher: &crypto::FrameCipher,
+) -> Result<(Zeroizing<Vec<u8>>, GapReport)> {
+    if let Some(e) = collected.missing_error() {
+        return Err(e);
+    }
+    let sealed: Vec<u8> = collected
+        .chunks
+        .iter_mut()
+        .flat_map(|c| c.take().unwrap())
+        .collect();
+    let mut plaintext = cipher.decrypt_message(&sealed)?;
+    let file_size = collected.first_header.file_size;
+    if (plaintext.len() as u64) < file_size {
+        return Err(VstorageError::Header(format!(
+            "decoded {} bytes but header records file_size={file_size}",
+            plaintext.len()
+        )));
+    }
+    plaintext.truncate(file_size as usize);
+    Ok((plaintext, GapReport::new(file_size)))
+}
+
+/// [`assemble`], or the file hidden in the padding frames (see
+/// [`crate::hidden`]) when `password` opens that instead: the payload is
+/// unencrypted or `password` is not its own. There is no gap report then.
+pub(crate) fn assemble_or_hidden(
+    collected: &mut Collected,
+    cipher: Option<&crypto::FrameCipher>,
+    password: Option<&str>,
+    partial: bool,
+) -> Result<(Zeroizing<Vec<u8>>, Option<GapReport>)> {
+    let hidden = |collected: &Collected| {
+        let password = password?;
+        crate::hidden::open(&collected.padding_bytes()?, password).ok()
+    };
+    if cipher.is_none() {
+        if let Some(data) = hidden(collected) {
+            return Ok((data, None));
+        }
+    }
+    match assemble(collected, cipher, partial) {
+        Err(VstorageError::WrongPassword) => hidden(collected)
+            .map(|data| (data, None))
+            .ok_or(VstorageError::WrongPassword),
+        result => result.map(|(data, report)| (data, Some(report))),
+    }
+}
+
+/// The frame cipher for an encrypted payload (from its first header), with
+/// the `hardware` token if its key needs one; `None` if unencrypted.
+pub(crate) fn cipher_for(
+    hdr: &FrameHeader,
+    password: Option<&str>,
+    hardware: Option<&dyn crypto::Token>,
+) -> Result<Option<crypto::FrameCipher>> {
+    if !is_encrypted(hdr) {
+        return Ok(None);
+    }
+    if is_public_key(hdr) {
+        return Err(VstorageError::Config(
+            "the payload is encrypted to public keys; only a full decode with --identity reads it"
+                .into(),
+        ));
+    }
+    let pw = match hdr.token {
+        crypto::TokenMode::Only => "",
+        _ => password.ok_or(VstorageError::PasswordRequired)?,
+    };
+    let cipher = crypto::FrameCipher::new(pw, hdr.kdf, hdr.token, hardware, &hdr.salt, hdr.nonce)?;
+    Ok(Some(cipher.with_context(hdr.cipher_context())))
+}
+
+/// Decrypt `chunks` (frames `0..`, taken out of the slice) on up to
+/// `threads` threads. Every frame has its own nonce, so they open in any
+/// order; the last of a `streamed` payload must be sealed as its end.
+fn decrypt_all(
+    cipher: &crypto::FrameCipher,
+    chunks: &mut [Option<Vec<u8>>],
+    streamed: bool,
+    threads: usize,
+) -> Vec<Option<Result<Zeroizing<Vec<u8>>>>> {
+    let total = chunks.len();
+    let mut out: Vec<_> = (0..total).map(|_| None).collect();
+    let decrypt = |first: usize, chunks: &mut [Option<Vec<u8>>], out: &mut [_]| {
+        for (k, (chunk, out)) in chunks.iter_mut().zip(out).enumerate() {
+            let i = first + k;
+            *out = chunk.take().map(|c| {
+                cipher.decrypt_stream_frame(i as u32, is_stream_end(streamed, i, total), &c)
+            });
+        }
+    };
+    if threads <= 1 || chunks.len() < 2 {
+        decrypt(0, chunks, &mut out);
+        return out;
+    }
+    let per_thread = chunks.len().div_ceil(threads);
+    std::thread::scope(|s| {
+        for (t, (chunks, out)) in chunks
+            .chunks_mut(per_thread)
+            .zip(out.chunks_mut(per_thread))
+            .enumerate()
+        {
+            s.spawn(move || decrypt(t * per_thread, chunks, out));
+        }
+    });
+    out
+}
+
+/// Whether frame `index` of `total` ends a `streamed` payload, and so was
+/// sealed as its last (see [`crypto::FrameCipher::encrypt_stream_frame`]).
+pub(crate) fn is_stream_end(streamed: bool, index: usize, total: usize) -> bool {
+    streamed && index + 1 == total
+}
+
+/// How one frame of the selected payload decoded.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct FrameCheck {
+    /// Symbols the Reed-Solomon decoder corrected
+    pub corrected: usize,
+    /// RS blocks in the frame (each can correct `ecc_len / 2` symbols)
+    pub blocks: usize,
+    /// Most symbols corrected in any single block of the frame
+    pub worst_block: usize,
+    /// Whether the corrected data reproduces the header's hash (`None` if
+    /// the header was unreadable or hashes were not checked)
+    pub hash_ok: Option<bool>,
+}
+
+/// Reed-Solomon statistics over the decoded frames of a payload.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct EccStats {
+    /// Symbols a single block can correct (`ecc_len / 2`)
+    pub per_block_capacity: usize,
+    pub frames: usize,
+    pub corrected: usize,
+    /// Frame (0-based) with the most corrections in one block, and that count
+    pub worst_block: Option<(usize, usize)>,
+}
+
+impl EccStats {
+    pub fn new(checks: &[Option<FrameCheck>], ecc_len: u8) -> Self {
+        let mut stats = Self {
+            per_block_capacity: ecc_len as usize / 2,
+            frames: 0,
+            corrected: 0,
+            worst_block: None,
+        };
+        for (i, c) in checks.iter().enumerate() {
+            let Some(c) = c else { continue };
+            stats.frames += 1;
+            stats.corrected += c.corrected;
+            if stats.worst_block.is_none_or(|(_, w)| c.worst_block > w) {
+                stats.worst_block = Some((i, c.worst_block));
+            }
+        }
+        stats
+    }
+
+    pub fn to_json(&self) -> Json {
+        Json::object([
+            ("corrected", self.corrected.into()),
+            ("frames", self.frames.into()),
+            ("per_block_capacity", self.per_block_capacity.into()),
+            (
+                "worst_block_frame",
+                self.worst_block.map(|(f, _)| f + 1).into(),
+            ),
+            (
+                "worst_block_corrected",
+                self.worst_block.map(|(_, w)| w).into(),
+            ),
+            ("margin", self.margin().into()),
+        ])
+    }
+
+    /// Further symbol errors the worst block could have absorbed.
+    pub fn margin(&self) -> usize {
+        let worst = self.worst_block.map_or(0, |(_, w)| w);
+        self.per_block_capacity.saturating_sub(worst)
+    }
+}
+
+impl fmt::Display for EccStats {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "ECC: {} symbols corrected over {} frames ({:.1} per frame)",
+            self.corrected,
+            self.frames,
+            self.corrected as f64 / self.frames.max(1) as f64
+        )?;
+        if let Some((frame, worst)) = self.worst_block {
+            write!(
+                f,
+                ", worst block in frame {} at {worst}/{} — margin {} symbols",
+                frame + 1,
+                self.per_block_capacity,
+                self.margin()
+            )?;
+        }
+        Ok(())
+    }
+}
+
+/// RS-decoded frames of one payload, gathered from all input videos.
+pub(crate) struct Collected {
+    pub first_header: FrameHeader,
+    pub config: FrameConfig,
+    pub chunks: Vec<Option<Vec<u8>>>,
+    pub checks: Vec<Option<FrameCheck>>,
+    /// Frames that failed RS correction (possibly filled by another copy)
+    pub failures: Vec<(usize, VstorageError)>,
+    pub parts_seen: BTreeSet<u16>,
+    /// RS-decoded piece frames (empty for payloads stored without them)
+    pub pieces: Vec<Option<Vec<u8>>>,
+    /// Key list from the key frame, for payloads encrypted to recipients
+    pub keys: Option<Vec<u8>>,
+    /// Contents of the signature frame (still encrypted), if signed
+    pub signature: Option<Vec<u8>>,
+    /// RS-decoded padding frames (see [`crate::hidden`])
+    pub padding: Vec<Option<Vec<u8>>>,
+    /// Tiles that failed the CRC map of the data frames, if they have one
+    /// (reported by `verify`)
+    #[cfg_attr(not(feature = "pipeline"), allow(dead_code))]
+    pub tile_damage: Option<TileDamage>,
+}
+
+impl Collected {
+    /// Contents of the padding frames, if there are any and all decoded.
+    pub fn padding_bytes(&self) -> Option<Vec<u8>> {
+        if self.padding.is_empty() {
+            return None;
+        }
+        self.padding
+            .iter()
+            .map(Option::as_deref)
+            .collect::<Option<Vec<_>>>()
+            .map(|c| c.concat())
+    }
+
+    /// The frame cipher of a payload encrypted to recipients, from the file
+    /// key wrapped for `identity`.
+    pub fn recipient_cipher(&self, identity: Option<&Identity>) -> Result<crypto::FrameCipher> {
+        let identity = identity.ok_or(VstorageError::IdentityRequired)?;
+        let keys = self.keys.as_deref().ok_or_else(|| {
+            VstorageError::Header("the key frame is missing or unreadable".into())
+        })?;
+        let file_key = recipient::unwrap_key(keys, identity)?;
+        crypto::FrameCipher::from_key(&file_key, self.first_header.nonce)
+            .map(|c| c.with_context(self.first_header.cipher_context()))
+    }
+
+    /// The piece hashes stored after the data frames, or `None` (with a
+    /// warning if they were stored) when any piece frame is unreadable.
+    pub fn piece_hashes(&self, cipher: Option<&crypto::FrameCipher>) -> Option<PieceHashes> {
+        if self.pieces.is_empty() {
+            return None;
+        }
+        let mut bytes = Vec::new();
+        for (k, chunk) in self.pieces.iter().enumerate() {
+            let plain = match (chunk, cipher) {
+                (None, _) => None,
+                (Some(c), Some(cipher)) => {
+                    cipher.decrypt_frame(PIECE_NONCE_BASE + k as u32, c).ok()
+                }
+                (Some(c), None) => Some(Zeroizing::new(c.clone())),
+            };
+            let Some(plain) = plain else {
+                tracing::warn!("piece frame {} unreadable, pieces not verified", k + 1);
+                return None;
+            };
+            bytes.extend_from_slice(&plain);
+        }
+        PieceHashes::deserialize(&bytes)
+            .inspect_err(|e| tracing::warn!("piece list unreadable ({e}), pieces not verified"))
+            .ok()
+    }
+
+    /// Check `data` (the assembled file) against the stored piece hashes and
+    /// BLAKE3 of the file.
+    pub fn check_pieces(
+        &self,
+        cipher: Option<&crypto::FrameCipher>,
+        data: &[u8],
+    ) -> Option<PieceCheck> {
+        let pieces = self.piece_hashes(cipher)?;
+        Some(PieceCheck {
+            piece_size: pieces.piece_size,
+            total: pieces.hashes.len(),
+            damaged: pieces.damaged(data),
+            file_size: data.len() as u64,
+            file_ok: pieces.file_matches(data),
+        })
+    }
+
+    /// Check the payload's signature of `data` (the assembled file) against
+    /// `key`; without a key, only name the signer of a signed payload.
+    pub fn check_signature(
+        &self,
+        cipher: Option<&crypto::FrameCipher>,
+        data: &[u8],
+        key: Option<&VerifyKey>,
+    ) -> Result<()> {
+        let frame = match (&self.signature, cipher) {
+            (None, _) => None,
+            (Some(f), Some(cipher)) => cipher.decrypt_frame(SIGNATURE_NONCE, f).ok(),
+            (Some(f), None) => Some(Zeroizing::new(f.clone())),
+        };
+        let Some(key) = key else {
+            match frame.as_deref().map(|f| signature::signer(f)) {
+                Some(Ok(signer)) => {
+                    tracing::info!("Signed by {signer} (not verified; pass --verify-key)")
+                }
+                Some(Err(e)) => tracing::warn!("{e}"),
+                None if self.signature.is_some() => {
+                    tracing::warn!("the signature frame is corrupt")
+                }
+                None => {}
+            }
+            return Ok(());
+        };
+        let frame = frame.ok_or_else(|| {
+            VstorageError::Signature(match self.signature {
+                Some(_) => "the signature frame is corrupt".into(),
+                None => "the payload is not signed".into(),
+            })
+        })?;
+        signature::verify(&frame, data, key)?;
+        tracing::info!("Signature by {key} verified");
+        Ok(())
+    }
+
+    /// Fail unless every frame was decoded: uncorrectable blocks of frames no
+    /// other copy filled, or else the missing frames, are reported.
+    pub fn ensure_complete(&mut self) -> Result<()> {
+        let mut damaged = Vec::new();
+        for (i, e) in std::mem::take(&mut self.failures) {
+            match e {
+                _ if self.chunks[i].is_some() => {}
+                VstorageError::Uncorrectable(blocks) => damaged.extend(blocks),
+                e => return Err(e),
+            }
+        }
+        if !damaged.is_empty() {
+            damaged.sort_by_key(|b| (b.frame, b.block));
+            return Err(VstorageError::Uncorrectable(damaged));
+        }
+        match self.missing_error() {
+            Some(e) => Err(e),
+            None => Ok(()),
+        }
+    }
+
+    /// Error naming the frames (and parts) that were never decoded.
+    pub fn missing_error(&self) -> Option<VstorageError> {
+        let missing: Vec<usize> = (0..self.chunks.len())
+            .filter(|&i| self.chunks[i].is_none())
+            .collect();
+        if missing.is_empty() {
+            return None;
+        }
+        let total_parts = self.first_header.total_parts;
+        Some(VstorageError::MissingFrames {
+            missing,
+            total: self.chunks.len(),
+            missing_parts: (0..total_parts)
+                .filter(|p| !self.parts_seen.contains(p))
+                .collect(),
+            total_parts,
+        })
+    }
+}
+
+/// `forced`, or the config detected from `first_images` (with the
+/// correction that made their header readable), or failing that from the
+/// first of the `later` frames whose header reads as it is; either way
+/// capped at `threads` (see [`FrameConfig::threads`]).
+pub(crate) fn choose_config(
+    forced: Option<&FrameConfig>,
+    first_images: &[image::RgbImage],
+    later: impl Iterator<Item = Result<image::RgbImage>>,
+    threads: Option<usize>,
+) -> Result<(FrameConfig, Preprocess)> {
+    let (config, preprocess, how) = match forced {
+        Some(config) => {
+            // The header area describes itself, whatever was forced
+            let mut config = config.clone();
+            if let Some(img) = first_images.first() {
+                let layout = frame::header_layout(img, config.block_size);
+                (config.header_rows, config.header_copies) = layout;
+            }
+            config.threads = threads;
+            (config, Preprocess::None, "Forced")
+        }
+        None => {
+            let (config, preprocess) = detect_config_with_fallbacks(first_images, later, threads)?;
+            (config, preprocess, "Detected")
+        }
+    };
+    tracing::info!(
+        "{how}: block_size={}, levels={}, ecc={}",
+        config.block_size,
+        config.levels,
+        config.ecc_len
+    );
+    Ok((config, preprocess))
+}
+
+/// Reads frames one at a time, in any order and from any number of inputs,
+/// RS-decoding those of one payload into a [`Collected`].
+pub(crate) struct FrameCollector {
+    payload: u16,
+    config: FrameConfig,
+    preprocess: Preprocess,
+    check_hashes: bool,
+    first_header: Option<FrameHeader>,
+    /// Headers of the data frames read, one per distinct file size, nonce
+    /// and salt, with how many frames gave each
+    globals: Vec<(FrameHeader, usize)>,
+    /// Header of the last data frame of a streamed payload, the one that
+    /// records its size and frame count
+    stream_end: Option<FrameHeader>,
+    chunks: Vec<Option<Vec<u8>>>,
+    checks: Vec<Option<FrameCheck>>,
+    /// Frames whose index was already decoded
+    duplicates: usize,
+    failures: Vec<(usize, VstorageError)>,
+    payload_index: Option<(u32, PayloadIndex)>,
+    parts_seen: BTreeSet<u16>,
+    pieces: Vec<Option<Vec<u8>>>,
+    keys: Option<Vec<u8>>,
+    signature: Option<Vec<u8>>,
+    padding: Vec<Option<Vec<u8>>>,
+    tile_damage: Option<TileDamage>,
+    current_payload: u16,
+    next_index: usize,
+}
+
+impl FrameCollector {
+    pub fn new(
+        payload: u16,
+        config: FrameConfig,
+        preprocess: Preprocess,
+        check_hashes: bool,
+    ) -> Self {
+        Self {
+            payload,
+            config,
+            preprocess,
+            check_hashes,
+            first_header: None,
+            globals: Vec::new(),
+            stream_end: None,
+            chunks: Vec::new(),
+            checks: Vec::new(),
+            duplicates: 0,
+            failures: Vec::new(),
+            payload_index: None,
+            parts_seen: BTreeSet::new(),
+            pieces: Vec::new(),
+            keys: None,
+            signature: None,
+            padding: Vec::new(),
+            tile_damage: None,
+            current_payload: 0,
+            next_index: 0,
+        }
+    }
+
+    /// Read one frame of `source` (named in messages); returns the data bytes
+    /// it contributed.
+    pub fn add(&mut self, source: &dyn fmt::Display, img: &image::RgbImage) -> Result<usize> {
+        let config = &self.config;
+        let img = self.preprocess.apply_ref(img);
+        let img = match frame::realign(&img, config.block_size, config.levels) {
+            Some(realigned) => Cow::Owned(realigned),
+            None => img,
+        };
+
+        // Try to read per-frame header; fall back to the next sequential
+        // index of the previous frame's payload and max capacity
+        let header_bytes = frame::decode_header_area(&img, config.block_size, config.levels);
+        let (index, data_len, hash, levels) = match header::decode_header_triple(&header_bytes) {
+            Ok(fh) if fh.kind == header::KIND_INDEX => {
+                self.current_payload = header::INDEX_PAYLOAD;
+                let generation = fh.frame_number;
+                if self
+                    .payload_index
+                    .as_ref()
+                    .is_none_or(|(g, _)| generation > *g)
+                {
+                    match decode_index_frame(&img, config, &fh) {
+                        Ok(index) => self.payload_index = Some((generation, index)),
+                        Err(e) => tracing::warn!("index frame unreadable ({e}), ignoring"),
+                    }
+                }
+                return Ok(0);
+            }
+            Ok(fh) if fh.kind == header::KIND_INTRO => return Ok(0),
+            Ok(fh)
+                if matches!(
+                    fh.kind,
+                    header::KIND_PIECES
+                        | header::KIND_KEYS
+                        | header::KIND_PADDING
+                        | header::KIND_SIGNATURE
+                ) =>
+            {
+                // Not followed by data of this payload
+                self.current_payload = header::INDEX_PAYLOAD;
+                if fh.payload == self.payload {
+                    self.add_meta_frame(source, &img, &fh)?;
+                }
+                return Ok(0);
+            }
+            Ok(fh) => {
+                self.current_payload = fh.payload;
+                if fh.payload != self.payload {
+                    return Ok(0);
+                }
+                // The frames of a streamed payload are told apart by the
+                // size and count only the last records
+                let global = match fh.streamed {
+                    true => FrameHeader {
+                        file_size: header::STREAMED_SIZE,
+                        total_frames: 0,
+                        ..fh.clone()
+                    },
+                    false => fh.clone(),
+                };
+                if fh.streamed && fh.file_size != header::STREAMED_SIZE {
+                    self.stream_end.get_or_insert_with(|| fh.clone());
+                }
+                let (chunks, checks) = (&mut self.chunks, &mut self.checks);
+                let first = self.first_header.get_or_insert_with(|| {
+                    *chunks = vec![None; global.total_frames as usize];
+                    *checks = vec![None; global.total_frames as usize];
+                    global.clone()
+                });
+                if global.total_frames != first.total_frames {
+                    return Err(VstorageError::Header(format!(
+                        "{source} reports {} total frames, expected {} — \
+                         inputs are not parts of the same video",
+                        fh.total_frames, first.total_frames
+                    )));
+                }
+                if fh.set_id != first.set_id {
+                    return Err(mixed_sets(source, &fh, first));
+                }
+                match self.globals.iter_mut().find(|(g, _)| same_file(g, &global)) {
+                    Some((_, n)) => *n += 1,
+                    None => self.globals.push((global, 1)),
+                }
+                self.parts_seen.insert(fh.part);
+                (
+                    fh.frame_number as usize,
+                    fh.data_length as usize,
+                    Some(fh.data_sha256),
+                    fh.area_levels(),
+                )
+            }
+            Err(e) => {
+                let Some(fh) = self
+                    .first_header
+                    .as_ref()
+                    .filter(|_| self.current_payload == self.payload)
+                else {
+                    return Ok(0);
+                };
+                let index = match frame::read_barcode(&img, config) {
+                    Some((number, header::KIND_DATA)) => {
+                        tracing::warn!(
+                            "{source} frame {}: header unreadable ({e}), numbered by its barcode",
+                            number + 1
+                        );
+                        number as usize
+                    }
+                    Some(_) => {
+                        self.current_payload = header::INDEX_PAYLOAD;
+                        return Ok(0);
+                    }
+                    None => {
+                        tracing::warn!(
+                            "{source} frame {}: header unreadable ({e}), assuming next frame",
+                            self.next_index + 1
+                        );
+                        self.next_index
+                    }
+                };
+                let len = expected_chunk_len(config, fh.file_size, is_encrypted(fh), index);
+                (index, len, None, config.channel_levels())
+            }
+        };
+        self.next_index = index + 1;
+
+        // A streamed payload grows as its frames are read
+        let streamed_total = match &self.stream_end {
+            Some(end) => end.total_frames as usize,
+            None => usize::MAX,
+        };
+        let streamed = self.first_header.as_ref().is_some_and(|fh| fh.streamed);
+        if streamed && (self.chunks.len()..streamed_total).contains(&index) {
+            self.chunks.resize(index + 1, None);
+            self.checks.resize(index + 1, None);
+        }
+        if index >= self.chunks.len() {
+            tracing::warn!(
+                "{source} frame {}: beyond the {} frames of payload {}, ignoring",
+                index + 1,
+                self.chunks.len(),
+                self.payload
+            );
+            return Ok(0);
+        }
+        if self.chunks[index].is_some() {
+            self.duplicates += 1;
+            return Ok(0);
+        }
+
+        // Decode data area
+        let data_bytes = frame::decode_data_area(&img, config, levels, index as u32);
+
+        // Bytes of tiles failing the CRC map are decoded as erasures
+        let erasures = match frame::damaged_tiles(&img, config, levels, index as u32) {
+            Some(damaged) => {
+                let (tw, th) = crcmap::tile_grid(config.data_width(), config.data_rows().len());
+                self.tile_damage
+                    .get_or_insert_with(|| TileDamage::new(tw, th))
+                    .add(&damaged);
+                frame::tile_erasures(config, levels, &damaged, index as u32)
+            }
+            None => Vec::new(),
+        };
+
+        // RS decode
+        match ecc::rs_decode_erased(
+            &data_bytes,
+            config.ecc_len as usize,
+            config.rs_data_len(),
+            data_len,
+            &erasures,
+            config.worker_threads(),
+        ) {
+            Ok((rs_decoded, per_block)) => {
+                // Unencrypted frames have no tag, so their hash is always checked
+                let check_hashes = self.check_hashes
+                    || self
+                        .first_header
+                        .as_ref()
+                        .is_some_and(|fh| !is_encrypted(fh));
+                let hash_ok = hash
+                    .filter(|_| check_hashes)
+                    .map(|expected| hash_matches(&rs_decoded, config, &expected));
+                let decoded = rs_decoded.len();
+                self.checks[index] = Some(FrameCheck {
+                    corrected: per_block.iter().sum(),
+                    blocks: per_block.len(),
+                    worst_block: per_block.iter().copied().max().unwrap_or(0),
+                    hash_ok,
+                });
+                self.chunks[index] = Some(rs_decoded);
+                Ok(decoded)
+            }
+            Err(e) => {
+                let fh = self
+                    .first_header
+                    .as_ref()
+                    .expect("set before decoding data");
+                let e = locate_damage(e, config, fh.file_size, is_encrypted(fh), index);
+                self.failures.push((index, e));
+                Ok(0)
+            }
+        }
+    }
+
+    /// RS-decode a piece, padding, key or signature frame. An uncorrectable
+    /// piece frame only leaves the pieces unverified.
+    fn add_meta_frame(
+        &mut self,
+        source: &dyn fmt::Display,
+        img: &image::RgbImage,
+        fh: &FrameHeader,
+    ) -> Result<()> {
+        if let Some(first) = &self.first_header {
+            if fh.set_id != first.set_id {
+                return Err(mixed_sets(source, fh, first));
+            }
+        }
+        self.parts_seen.insert(fh.part);
+        let config = &self.config;
+        let decode = || {
+            let chunk = ecc::rs_decode(
+                &frame::decode_data_area(img, config, fh.area_levels(), fh.frame_number),
+                config.ecc_len as usize,
+                config.rs_data_len(),
+                fh.data_length as usize,
+                config.worker_threads(),
+            )?;
+            // Nothing else catches a miscorrection in an unencrypted payload
+            if !is_encrypted(fh) && !hash_matches(&chunk, config, &fh.data_sha256) {
+                return Err(VstorageError::HashMismatch {
+                    frame: fh.frame_number as usize,
+                });
+            }
+            Ok(chunk)
+        };
+        let single = match fh.kind {
+            header::KIND_KEYS => Some((&mut self.keys, "key")),
+            header::KIND_SIGNATURE => Some((&mut self.signature, "signature")),
+            _ => None,
+        };
+        if let Some((slot, what)) = single {
+            if slot.is_none() {
+                match decode() {
+                    Ok(bytes) => *slot = Some(bytes),
+                    Err(e) => tracing::warn!("{source} {what} frame: {e}"),
+                }
+            }
+            return Ok(());
+        }
+        let (slots, what) = match fh.kind {
+            header::KIND_PIECES => (&mut self.pieces, "piece"),
+            _ => (&mut self.padding, "padding"),
+        };
+        let index = fh.frame_number as usize;
+        if slots.is_empty() {
+            *slots = vec![None; fh.total_frames as usize];
+        }
+        if index >= slots.len() || slots[index].is_some() {
+            return Ok(());
+        }
+        match decode() {
+            Ok(chunk) => slots[index] = Some(chunk),
+            Err(e) => tracing::warn!("{source} {what} frame {}: {e}", index + 1),
+        }
+        Ok(())
+    }
+
+    pub fn finish(self) -> Result<Collected> {
+        let payload = self.payload;
+        if let Some((_, index)) = &self.payload_index {
+            tracing::info!("Video holds {} payloads:", index.entries.len());
+            for e in &index.entries {
+                tracing::info!(
+                    "  payload {}: {} bytes, {} frames{}",
+                    e.payload,
+                    e.file_size,
+                    e.total_frames,
+                    if e.name.is_empty() {
+                        String::new()
+                    } else {
+                        format!(" ({})", e.name)
+                    }
+                );
+            }
+        }
+        let mut first_header = self.first_header.ok_or_else(|| {
+            VstorageError::Header(format!("payload {payload} not found in the video"))
+        })?;
+        // A header damaged in a way its CRC missed is outvoted by the rest;
+        // ties go to the earliest read
+        if let [_, _, ..] = &self.globals[..] {
+            let (best, n) = self.globals.iter().rev().max_by_key(|(_, n)| *n).unwrap();
+            let total: usize = self.globals.iter().map(|(_, n)| n).sum();
+            tracing::warn!(
+                "Frame headers disagree on the file size, nonce or salt; using those of {n} of {total} frames"
+            );
+            first_header = best.clone();
+        }
+        let (mut chunks, mut checks, mut failures) = (self.chunks, self.checks, self.failures);
+        if first_header.streamed {
+            let end = self.stream_end.ok_or_else(|| {
+                VstorageError::Header(
+                    "the last data frame of the stream is missing or unreadable, so the \
+                     file's size is unknown"
+                        .into(),
+                )
+            })?;
+            (first_header.file_size, first_header.total_frames) = (end.file_size, end.total_frames);
+            chunks.resize(end.total_frames as usize, None);
+            checks.resize(end.total_frames as usize, None);
+            failures.retain(|(i, _)| *i < chunks.len());
+        }
+        tracing::info!(
+            "Payload {payload}: {} frames, file_size={}",
+            chunks.len(),
+            first_header.file_size
+        );
+        if let Some(damage) = self.tile_damage.as_ref().filter(|d| d.any()) {
+            tracing::info!("{damage}");
+        }
+        if self.duplicates > 0 {
+            tracing::info!("Skipped {} duplicate frames", self.duplicates);
+        }
+
+        Ok(Collected {
+            first_header,
+            config: self.config,
+            chunks,
+            checks,
+            failures,
+            parts_seen: self.parts_seen,
+            pieces: self.pieces,
+            keys: self.keys,
+            signature: self.signature,
+            padding: self.padding,
+            tile_damage: self.tile_damage,
+        })
+    }
+}
+
+/// Turn the failed RS blocks of frame `index` into the file byte ranges they
+/// carried; other errors pass through unchanged.
+pub(crate) fn locate_damage(
+    e: VstorageError,
+    config: &FrameConfig,
+    file_size: u64,
+    encrypted: bool,
+    index: usize,
+) -> VstorageError {
+    let VstorageError::EccBlocks(blocks) = e else {
+        return e;
+    };
+    // GCM is a stream cipher: ciphertext byte k is plaintext byte k, and the
+    // tag follows the data
+    let per_frame = config.max_plain_per_frame(encrypted) as u64;
+    let frame_start = index as u64 * per_frame;
+    let frame_len = file_size.saturating_sub(frame_start).min(per_frame);
+    let rs_data_len = config.rs_data_len() as u64;
+    VstorageError::Uncorrectable(
+        blocks
+            .into_iter()
+            .map(|block| {
+                let start = (block as u64 * rs_data_len).min(frame_len);
+                let end = (start + rs_data_len).min(frame_len);
+                DamagedBlock {
+                    frame: index,
+                    block,
+                    offset: frame_start + start,
+                    len: end - start,
+                }
+            })
+            .collect(),
+    )
+}
+
+/// Length of the (possibly encrypted) chunk stored in frame `index`.
+pub(crate) fn expected_chunk_len(
+    config: &FrameConfig,
+    file_size: u64,
+    encrypted: bool,
+    index: usize,
+) -> usize {
+    let per_frame = config.max_plain_per_frame(encrypted) as u64;
+    let start = index as u64 * per_frame;
+    let plain = file_size.saturating_sub(start).min(per_frame) as usize;
+    if encrypted {
+        plain + crypto::TAG_LEN
+    } else {
+        plain
+    }
+}
+
+/// Error for a frame (or input) from a different encoding than the first.
+pub(crate) fn mixed_sets(
+    source: &dyn fmt::Display,
+    hdr: &FrameHeader,
+    first: &FrameHeader,
+) -> VstorageError {
+    VstorageError::Header(format!(
+        "{source} belongs to set {}, not {} — inputs are not parts of the same encoding",
+        header::format_set_id(&hdr.set_id),
+        header::format_set_id(&first.set_id)
+    ))
+}
+
+/// Whether data frame headers `a` and `b` agree on the file they carry: its
+/// size, nonce, salt, KDF and compression.
+fn same_file(a: &FrameHeader, b: &FrameHeader) -> bool {
+    (a.file_size, a.nonce, a.salt, a.kdf, a.compression)
+        == (b.file_size, b.nonce, b.salt, b.kdf, b.compression)
+}
+
+/// Whether `chunk`, RS-encoded again, has the data hash `expected` from its
+/// header.
+pub(crate) fn hash_matches(chunk: &[u8], config: &FrameConfig, expected: &[u8; 32]) -> bool {
+    let reencoded = ecc::rs_encode(
+        chunk,
+        config.ecc_len as usize,
+        config.rs_data_len(),
+        config.worker_threads(),
+    );
+    <[u8; 32]>::from(Sha256::digest(&reencoded)) == *expected
+}
+
+pub(crate) fn is_encrypted(hdr: &FrameHeader) -> bool {
+    hdr.nonce != [0u8; 12] || hdr.salt != [0u8; 16]
+}
+
+/// Whether the payload is encrypted to recipients: a random base nonce but
+/// no password salt.
+pub(crate) fn is_public_key(hdr: &FrameHeader) -> bool {
+    hdr.nonce != [0u8; 12] && hdr.salt == [0u8; 16]
+}
+
+/// Read the payload index stored in an index frame.
+pub(crate) fn decode_index_frame(
+    img: &image::RgbImage,
+    config: &FrameConfig,
+    hdr: &FrameHeader,
+) -> Result<PayloadIndex> {
+    let data_bytes = frame::decode_data_area(img, config, hdr.area_levels(), hdr.frame_number);
+    let bytes = ecc::rs_decode(
+        &data_bytes,
+        config.ecc_len as usize,
+        config.rs_data_len(),
+        hdr.data_length as usize,
+        config.worker_threads(),
+    )?;
+    PayloadIndex::deserialize(&bytes)
+}
+
+/// Frames tried by [`detect_config_with_fallbacks`].
+pub(crate) const DETECT_FRAMES: usize = 3;
+
+/// Detect the config from the first frames, falling back step by step:
+/// the second and third frame, then each of them median-filtered, then with
+/// stretched (relaxed) level thresholds. Returns the correction that worked,
+/// to be applied to every frame. Each step tries every frame and candidate
+/// at once on up to `threads` threads. Last, the `later` frames are tried as
+/// they are, one at a time, for a video whose first frames are all damaged.
+fn detect_config_with_fallbacks(
+    images: &[image::RgbImage],
+    later: impl Iterator<Item = Result<image::RgbImage>>,
+    threads: Option<usize>,
+) -> Result<(FrameConfig, Preprocess)> {
+    let images = &images[..images.len().min(DETECT_FRAMES)];
+    for preprocess in [Preprocess::None, Preprocess::Median, Preprocess::Stretch] {
+        // Filtered once per frame, by whichever candidate needs it first
+        let filtered: Vec<OnceLock<image::RgbImage>> =
+            images.iter().map(|_| OnceLock::new()).collect();
+        let candidates: Vec<(usize, (u8, u8))> = (0..images.len())
+            .flat_map(|k| detect_grid().map(move |c| (k, c)))
+            .collect();
+        let hit = first_hit(&candidates, threads, |&(k, (block_size, levels))| {
+            let img = match preprocess {
+                Preprocess::None => &images[k],
+                _ => filtered[k].get_or_init(|| preprocess.apply(images[k].clone())),
+            };
+            try_candidate(img, block_size, levels)
+        });
+        if let Some((i, (_, mut config))) = hit {
+            let k = candidates[i].0;
+            if k > 0 || preprocess != Preprocess::None {
+                tracing::info!(
+                    "Header detected on frame {} ({})",
+                    k + 1,
+                    match preprocess {
+                        Preprocess::None => "as-is",
+                        Preprocess::Median => "after median filtering",
+                        Preprocess::Stretch => "with relaxed level thresholds",
+                    }
+                );
+            }
+            config.threads = threads;
+            return Ok((config, preprocess));
+        }
+    }
+    for (k, img) in later.enumerate() {
+        if let Some((_, config)) = try_detect_config(&img?, threads) {
+            tracing::info!("Header detected on frame {}", images.len() + k + 1);
+            return Ok((config, Preprocess::None));
+        }
+    }
+
+    detect_config_from_frame(&images[0], threads).map(|(_, config)| (config, Preprocess::None))
+}
+
+/// Try combinations of block_size and levels to find a valid header, on up
+/// to `threads` threads; the config found keeps that cap.
+pub(crate) fn detect_config_from_frame(
+    img: &image::RgbImage,
+    threads: Option<usize>,
+) -> Result<(FrameHeader, FrameConfig)> {
+    if let Some(found) = try_detect_config(img, threads) {
+        return Ok(found);
+    }
+    let width = img.width();
+    let height = img.height();
+
+    // Print the first few pixel values to help diagnose
+    let pixels: Vec<String> = (0..8u32.min(width))
+        .map(|x| {
+            let p = img.get_pixel(x, 0);
+            format!("({},{},{})", p[0], p[1], p[2])
+        })
+        .collect();
+    tracing::debug!(
+        "Header detection failed on a {width}x{height} frame; first pixels: {}",
+        pixels.join(" ")
+    );
+
+    Err(VstorageError::DetectFailed)
+}
+
+fn try_detect_config(
+    img: &image::RgbImage,
+    threads: Option<usize>,
+) -> Option<(FrameHeader, FrameConfig)> {
+    let candidates: Vec<(u8, u8)> = detect_grid().collect();
+    let (_, (hdr, mut config)) = first_hit(&candidates, threads, |&(block_size, levels)| {
+        try_candidate(img, block_size, levels)
+    })?;
+    config.threads = threads;
+    Some((hdr, config))
+}
+
+/// `(block_size, levels)` pairs detection tries, in order of preference.
+fn detect_grid() -> impl Iterator<Item = (u8, u8)> {
+    [1u8, 2, 4, 8, 16]
+        .into_iter()
+        .flat_map(|block_size| [2u8, 4, 8, 16].map(|levels| (block_size, levels)))
+}
+
+/// Read the header area of `img` as `block_size` and `levels`, and keep it
+/// if it decodes and names those same settings.
+fn try_candidate(
+    img: &image::RgbImage,
+    block_size: u8,
+    levels: u8,
+) -> Option<(FrameHeader, FrameConfig)> {
+    let (width, height) = (img.width(), img.height());
+    if !width.is_multiple_of(block_size as u32) || !height.is_multiple_of(block_size as u32) {
+        return None;
+    }
+    let realigned = frame::realign(img, block_size, levels);
+    let img = realigned.as_ref().unwrap_or(img);
+    let header_bytes = frame::decode_header_area(img, block_size, levels);
+    let hdr = header::decode_header_triple(&header_bytes).ok()?;
+    if hdr.block_size != block_size || hdr.levels != levels {
+        return None;
+    }
+    let (header_rows, header_copies) = frame::header_layout(img, block_size);
+    let config = FrameConfig {
+        width,
+        height,
+        block_size,
+        levels,
+        ecc_len: hdr.ecc_len,
+        fps: 30,
+        crf: 18,
+        codec: crate::config::VideoCodec::H264,
+        target_bitrate: None,
+        yuv420p: false,
+        ffmpeg_args: Vec::new(),
+        kdf: hdr.kdf,
+        token: hdr.token,
+        hardware_token: None,
+        compression: hdr.compression,
+        metadata_levels: None,
+        channel_levels: (hdr.channel_levels != [levels; 3]).then_some(hdr.channel_levels),
+        layout: hdr.layout,
+        crc_map: hdr.crc_map,
+        bottom_header: hdr.bottom_header,
+        banner: hdr.banner,
+        barcode: hdr.barcode,
+        pilots: hdr.pilots,
+        bin_centers: hdr.bin_centers,
+        header_rows,
+        header_copies,
+        intro: false,
+        seed: None,
+        threads: None,
+        workspace: Default::default(),
+        curve: Default::default(),
+    };
+    Some((hdr, config))
+}
+
+/// The first of `candidates` (and its index) for which `f` returns a value,
+/// trying them on up to `threads` threads (see [`worker_threads`]).
+/// Candidates after a hit are skipped; the result is the same as trying
+/// them in order.
+fn first_hit<T: Sync, R: Send>(
+    candidates: &[T],
+    threads: Option<usize>,
+    f: impl Fn(&T) -> Option<R> + Sync,
+) -> Option<(usize, R)> {
+    let threads = worker_threads(threads);
+    if threads == 1 || candidates.len() < 2 {
+        return candidates
+            .iter()
+            .enumerate()
+            .find_map(|(i, c)| f(c).map(|r| (i, r)));
+    }
+    let (next, best) = (AtomicUsize::new(0), AtomicUsize::new(usize::MAX));
+    let (next, best, f) = (&next, &best, &f);
+    std::thread::scope(|s| {
+        let workers: Vec<_> = (0..threads.min(candidates.len()))
+            .map(|_| {
+                s.spawn(move || loop {
+                    // Taken in order, so every earlier candidate is already
+                    // being tried by some thread
+                    let i = next.fetch_add(1, Ordering::Relaxed);
+                    if i >= candidates.len() || i > best.load(Ordering::Relaxed) {
+                        return None;
+                    }
+                    if let Some(r) = f(&candidates[i]) {
+                        best.fetch_min(i, Ordering::Relaxed);
+                        return Some((i, r));
+                    }
+                })
+            })
+            .collect();
+        workers
+            .into_iter()
+            .filter_map(|w| w.join().unwrap())
+            .min_by_key(|&(i, _)| i)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_expected_chunk_len() {
+        let config = FrameConfig::new(2, 4, 32, 30, 18).unwrap();
+        let per_frame = config.max_plain_per_frame(true);
+        let file_size = per_frame as u64 + 10;
+        assert_eq!(
+            expected_chunk_len(&config, file_size, true, 0),
+            per_frame + crypto::TAG_LEN
+        );
+        assert_eq!(
+            expected_chunk_len(&config, file_size, true, 1),
+            10 + crypto::TAG_LEN
+        );
+        assert_eq!(
+            expected_chunk_len(&config, file_size, false, 0),
+            file_size as usize
+        );
+    }
+
+    #[test]
+    fn test_decrypt_all() {
+        let cipher = crypto::FrameCipher::from_key(&[7; 32], [0; 12]).unwrap();
+        let mut chunks: Vec<_> = (0..9u8)
+            .map(|i| Some(cipher.encrypt_frame(i as u32, &[i; 5]).unwrap()))
+            .collect();
+        chunks[4] = None;
+        chunks[6].as_mut().unwrap()[0] ^= 1;
+        let out = decrypt_all(&cipher, &mut chunks, false, 4);
+        assert!(chunks.iter().all(Option::is_none));
+        for (i, frame) in out.iter().enumerate() {
+            match i {
+                4 => assert!(frame.is_none()),
+                6 => assert!(matches!(frame, Some(Err(_)))),
+                _ => assert_eq!(**frame.as_ref().unwrap().as_ref().unwrap(), [i as u8; 5]),
+            }
+        }
+    }
+
+    #[test]
+    fn test_ecc_stats() {
+        let check = |corrected, worst_block| {
+            Some(FrameCheck {
+                corrected,
+                blocks: 4,
+                worst_block,
+                hash_ok: None,
+            })
+        };
+        let stats = EccStats::new(&[check(10, 3), None, check(20, 12), check(5, 5)], 32);
+        assert_eq!(stats.frames, 3);
+        assert_eq!(stats.corrected, 35);
+        assert_eq!(stats.worst_block, Some((2, 12)));
+        assert_eq!(stats.margin(), 4);
+        assert!(stats
+            .to_string()
+            .ends_with("worst block in frame 3 at 12/16 — margin 4 symbols"));
+    }
+
+    #[test]
+    fn test_locate_damage() {
+        let config = FrameConfig::new(2, 4, 32, 30, 18).unwrap();
+        let per_frame = config.max_plain_per_frame(true) as u64;
+        let file_size = per_frame + 300;
+        // The last frame stores 300 bytes + tag in two RS blocks
+        let e = locate_damage(
+            VstorageError::EccBlocks(vec![0, 1]),
+            &config,
+            file_size,
+            true,
+            1,
+        );
+        let VstorageError::Uncorrectable(blocks) = e else {
+            panic!("expected Uncorrectable, got {e:?}");
+        };
+        assert_eq!((blocks[0].offset, blocks[0].len), (per_frame, 223));
+        assert_eq!((blocks[1].offset, blocks[1].len), (per_frame + 223, 77));
+        assert_eq!(
+            blocks[1].to_string(),
+            format!(
+                "frame 2 block 1 (file bytes {}..{})",
+                per_frame + 223,
+                file_size
+            )
+        );
+    }
+
+    #[test]
+    fn test_detect_washed_out_frame() {
+        let config = FrameConfig::new(4, 4, 32, 30, 18).unwrap();
+        let hdr = FrameHeader {
+            version: crate::config::PROTOCOL_VERSION,
+            frame_number: 0,
+            total_frames: 1,
+            block_size: config.block_size,
+            levels: config.levels,
+            file_size: 0,
+            data_length: 0,
+            ecc_len: config.ecc_len,
+            rs_data_len: config.rs_data_len() as u16,
+            nonce: [0; 12],
+            salt: [0; 16],
+            data_sha256: [0; 32],
+            kind: header::KIND_DATA,
+            payload: 0,
+            part: 0,
+            total_parts: 1,
+            set_id: [0; 16],
+            kdf: config.kdf,
+            token: config.token,
+            compression: config.compression,
+            data_levels: config.levels,
+            channel_levels: config.channel_levels(),
+            layout: config.layout,
+            crc_map: config.crc_map,
+            bottom_header: config.bottom_header,
+            banner: config.banner,
+            barcode: config.barcode,
+            pilots: config.pilots,
+            bin_centers: config.bin_centers,
+            streamed: false,
+        };
+        let data = crate::testutil::pattern(config.max_raw_per_frame(), 151, 256);
+        let mut img = crate::memory::render_frame(&config, hdr, &data);
+        let clean = img.clone();
+        // Squeeze into 60..195: the outer levels now read as their neighbours
+        for p in img.pixels_mut() {
+            for c in 0..3 {
+                p[c] = (60 + p[c] as u32 * 135 / 255) as u8;
+            }
+        }
+        assert!(try_detect_config(&img, None).is_none());
+
+        let (detected, preprocess) =
+            detect_config_with_fallbacks(&[img], std::iter::empty(), None).unwrap();
+        assert_eq!(preprocess, Preprocess::Stretch);
+        assert_eq!((detected.block_size, detected.levels), (4, 4));
+
+        // Nothing in the first frames: a later one, as it is
+        let blank = image::RgbImage::new(64, 60);
+        let later = std::iter::once(Ok(clean));
+        let (detected, preprocess) = detect_config_with_fallbacks(&[blank], later, None).unwrap();
+        assert_eq!(preprocess, Preprocess::None);
+        assert_eq!((detected.block_size, detected.levels), (4, 4));
+    }
+
+    #[test]
+    fn test_forced_config() {
+        let config = FrameConfig::new(8, 2, 32, 30, 18).unwrap();
+        let data = crate::testutil::pattern(20_000, 13, 251);
+        let options = crate::memory::EncodeOptions::new(config.clone());
+        let frames = crate::memory::encode_bytes(&data, &options).unwrap();
+
+        // Taken as given but for the header area, which describes itself
+        let (chosen, preprocess) =
+            choose_config(Some(&config), &frames[..1], std::iter::empty(), Some(2)).unwrap();
+        assert_eq!(preprocess, Preprocess::None);
+        assert_eq!(
+            (chosen.block_size, chosen.levels, chosen.ecc_len),
+            (8, 2, 32)
+        );
+        assert_eq!(
+            (chosen.header_rows, chosen.header_copies),
+            (config.header_rows, config.header_copies)
+        );
+        assert_eq!(chosen.threads(), Some(2));
+
+        let decode = |forced: FrameConfig| {
+            let options = crate::memory::DecodeOptions {
+                forced: Some(forced),
+                ..Default::default()
+            };
+            crate::memory::decode_frames(&frames, &options)
+        };
+        assert_eq!(decode(config).unwrap(), data);
+        // Forcing the wrong levels reads the data as noise
+        assert!(decode(FrameConfig::new(8, 4, 32, 30, 18).unwrap()).is_err());
+    }
+
+    #[test]
+    fn test_frames_in_any_order() {
+        let config = FrameConfig::new(8, 2, 32, 30, 18).unwrap();
+        let data = crate::testutil::pattern(100_000, 29, 247);
+        let options = crate::memory::EncodeOptions::new(config.clone());
+        let frames = crate::memory::encode_bytes(&data, &options).unwrap();
+        assert!(frames.len() >= 3);
+
+        // A frame numbered past the payload's last, as a stray one would be
+        let bytes = frame::decode_header_area(&frames[0], config.block_size, config.levels);
+        let mut stray = header::decode_header_triple(&bytes).unwrap();
+        stray.frame_number = stray.total_frames;
+        let stray = crate::memory::render_frame(&config, stray, &[]);
+
+        let mut collector = FrameCollector::new(0, config, Preprocess::None, false);
+        for img in frames.iter().rev().chain(&frames[..2]) {
+            collector.add(&"input", img).unwrap();
+        }
+        assert_eq!(collector.add(&"input", &stray).unwrap(), 0);
+        assert_eq!(collector.duplicates, 2);
+        let mut collected = collector.finish().unwrap();
+        collected.ensure_complete().unwrap();
+        assert_eq!(*assemble(&mut collected, None, false).unwrap().0, data);
+    }
+
+    #[test]
+    fn test_first_hit_is_earliest() {
+        // One thread takes the sequential path; either way the earliest hit
+        // must win, not the first one found
+        let candidates: Vec<usize> = (0..1000).collect();
+        for threads in [Some(1), Some(4), None] {
+            let hit = first_hit(&candidates, threads, |&n| {
+                (n > 300 && n % 7 == 0).then_some(n * 2)
+            });
+            assert_eq!(hit, Some((301, 602)));
+            assert_eq!(first_hit(&candidates, threads, |_| None::<()>), None);
+        }
+    }
+}
@@ -0,0 +1,191 @@
+use std::io::{Read, Write};
+
+use crate::error::{Result, VstorageError};
+
+pub use crate::codec::Compression;
+
+impl Compression {
+    /// Compress `data`; the result starts with the original length.
+    pub fn compress(self, data: &[u8]) -> Result<Vec<u8>> {
+        let mut out = (data.len() as u64).to_be_bytes().to_vec();
+        match self {
+            Self::None => out.extend_from_slice(data),
+            Self::Deflate(level) => {
+                let mut encoder =
+                    flate2::write::DeflateEncoder::new(out, flate2::Compression::new(level as u32));
+                encoder.write_all(data)?;
+                out = encoder.finish()?;
+            }
+            Self::Lz4 => out.extend_from_slice(&lz4_flex::block::compress(data)),
+            Self::Zstd(level) => {
+                let mut encoder = zstd::Encoder::new(out, level as i32)?;
+                encoder.write_all(data)?;
+                out = encoder.finish()?;
+            }
+            Self::Xz(level) => {
+                let mut encoder = liblzma::write::XzEncoder::new(out, level as u32);
+                encoder.write_all(data)?;
+                out = encoder.finish()?;
+            }
+        }
+        Ok(out)
+    }
+
+    /// Undo [`Compression::compress`].
+    pub fn decompress(self, data: &[u8]) -> Result<Vec<u8>> {
+        let corrupt = || VstorageError::Compression(format!("{self} data is corrupt"));
+        let (len, body) = data.split_first_chunk::<8>().ok_or_else(corrupt)?;
+        let len = u64::from_be_bytes(*len) as usize;
+        let out = match self {
+            Self::None => body.to_vec(),
+            Self::Deflate(_) => {
+                read_to_len(flate2::read::DeflateDecoder::new(body), len).map_err(|_| corrupt())?
+            }
+            // LZ4 expands at most 255 times, which bounds the buffer a
+            // corrupt length allocates
+            Self::Lz4 if len > body.len().saturating_mul(255) => return Err(corrupt()),
+            Self::Lz4 => lz4_flex::block::decompress(body, len).map_err(|_| corrupt())?,
+            Self::Zstd(_) => zstd::Decoder::new(body)
+                .and_then(|decoder| read_to_len(decoder, len))
+                .map_err(|_| corrupt())?,
+            Self::Xz(_) => {
+                read_to_len(liblzma::read::XzDecoder::new(body), len).map_err(|_| corrupt())?
+            }
+        };
+        if out.len() != len {
+            return Err(corrupt());
+        }
+        Ok(out)
+    }
+}
+
+impl std::str::FromStr for Compression {
+    type Err = VstorageError;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let lower = s.to_ascii_lowercase();
+        let (name, level) = match lower.split_once(':') {
+            Some((name, level)) => (name, Some(level)),
+            None => (lower.as_str(), None),
+        };
+        let bad = || {
+            VstorageError::Config(format!(
+                "unknown compression '{s}' (expected none, deflate[:0-9], lz4, zstd[:1-22] or xz[:0-9])"
+            ))
+        };
+        match (name, level) {
+            ("none", None) => Ok(Self::None),
+            ("lz4", None) => Ok(Self::Lz4),
+            ("deflate", None) => Ok(Self::Deflate(6)),
+            ("deflate", Some(level)) => match level.parse() {
+                Ok(level @ 0..=9) => Ok(Self::Deflate(level)),
+                _ => Err(bad()),
+            },
+            ("zstd", None) => Ok(Self::Zstd(3)),
+            ("zstd", Some(level)) => match level.parse() {
+                Ok(level @ 1..=22) => Ok(Self::Zstd(level)),
+                _ => Err(bad()),
+            },
+            ("xz", None) => Ok(Self::Xz(6)),
+            ("xz", Some(level)) => match level.parse() {
+                Ok(level @ 0..=9) => Ok(Self::Xz(level)),
+                _ => Err(bad()),
+            },
+            _ => Err(bad()),
+        }
+    }
+}
+
+impl std::fmt::Display for Compression {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::None => f.write_str("none"),
+            Self::Deflate(level) => write!(f, "deflate:{level}"),
+            Self::Lz4 => f.write_str("lz4"),
+            Self::Zstd(level) => write!(f, "zstd:{level}"),
+            Self::Xz(level) => write!(f, "xz:{level}"),
+        }
+    }
+}
+
+/// Read `reader` to its end, but no more than one byte past `len`, so a
+/// corrupt stream cannot inflate without bound.
+fn read_to_len(reader: impl Read, len: usize) -> std::io::Result<Vec<u8>> {
+    let mut out = Vec::new();
+    reader.take(len as u64 + 1).read_to_end(&mut out)?;
+    Ok(out)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_roundtrip() {
+        let mut data: Vec<u8> = b"vstorage ".repeat(2000);
+        data.extend((0..5000u32).map(|i| (i.wrapping_mul(2654435761) >> 24) as u8));
+        for compression in [
+            Compression::None,
+            Compression::Deflate(1),
+            Compression::Deflate(9),
+            Compression::Lz4,
+            Compression::Zstd(3),
+            Compression::Zstd(19),
+            Compression::Xz(6),
+        ] {
+            let packed = compression.compress(&data).unwrap();
+            if compression != Compression::None {
+                assert!(packed.len() < data.len() / 2, "{compression}");
+            }
+            assert_eq!(compression.decompress(&packed).unwrap(), data);
+            assert_eq!(
+                compression
+                    .decompress(&compression.compress(b"").unwrap())
+                    .unwrap(),
+                b""
+            );
+            assert!(compression.decompress(&packed[..packed.len() - 3]).is_err());
+        }
+    }
+
+    #[test]
+    fn test_parse_and_ids() {
+        assert_eq!(
+            "deflate".parse::<Compression>().unwrap(),
+            Compression::Deflate(6)
+        );
+        assert_eq!(
+            "Deflate:9".parse::<Compression>().unwrap(),
+            Compression::Deflate(9)
+        );
+        assert_eq!("lz4".parse::<Compression>().unwrap(), Compression::Lz4);
+        assert_eq!(
+            "zstd:19".parse::<Compression>().unwrap(),
+            Compression::Zstd(19)
+        );
+        assert_eq!("xz".parse::<Compression>().unwrap(), Compression::Xz(6));
+        for bad in [
+            "deflate:10",
+            "lz4:3",
+            "zstd:0",
+            "zstd:23",
+            "xz:10",
+            "brotli",
+        ] {
+            assert!(bad.parse::<Compression>().is_err(), "{bad}");
+        }
+        for c in [
+            Compression::None,
+            Compression::Deflate(3),
+            Compression::Lz4,
+            Compression::Zstd(19),
+            Compression::Xz(9),
+        ] {
+            let (id, level) = c.id();
+            assert_eq!(Compression::from_id(id, level).unwrap(), c);
+            assert_eq!(c.to_string().parse::<Compression>().unwrap(), c);
+        }
+        assert!(Compression::from_id(3, 0).is_err());
+        assert!(Compression::from_id(5, 0).is_err());
+    }
+}
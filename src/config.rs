@@ -1,24 +1,896 @@
+use std::path::PathBuf;
+use std::sync::Arc;
+
+use crate::compress::Compression;
+use crate::crypto::{Kdf, Token, TokenMode};
 use crate::error::{Result, VstorageError};
+use crate::layout::{CurveCache, Layout};
 
 pub const FRAME_WIDTH: u32 = 3840;
 pub const FRAME_HEIGHT: u32 = 2160;
+/// Default logical rows of the header area.
 pub const HEADER_ROWS: usize = 2;
+/// Default copies of the header in the header area.
 pub const HEADER_COPIES: usize = 3;
-pub const PROTOCOL_VERSION: u8 = 1;
+pub const PROTOCOL_VERSION: u8 = 2;
+
+/// Luma weights of red and blue in the BT.601 RGB to YCbCr transform
+/// ffmpeg converts the frames with; green's is the rest.
+const KR: f64 = 0.299;
+const KB: f64 = 0.114;
+
+/// Video codec used for the output file.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "lowercase"))]
+pub enum VideoCodec {
+    H264,
+    Vp9,
+}
+
+impl std::str::FromStr for VideoCodec {
+    type Err = VstorageError;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s.to_ascii_lowercase().as_str() {
+            "h264" | "x264" | "libx264" => Ok(Self::H264),
+            "vp9" | "libvpx" | "libvpx-vp9" => Ok(Self::Vp9),
+            other => Err(VstorageError::Config(format!(
+                "unknown codec '{other}' (expected h264 or vp9)"
+            ))),
+        }
+    }
+}
+
+impl std::fmt::Display for VideoCodec {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            Self::H264 => "h264",
+            Self::Vp9 => "vp9",
+        })
+    }
+}
+
+/// Image format of the numbered frames handed to and read back from ffmpeg.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "lowercase"))]
+pub enum Intermediate {
+    /// Compressed: least temporary disk space, slowest to write and read
+    #[default]
+    Png,
+    /// Uncompressed binary PPM (about 25 MB per 4K frame): fastest
+    Ppm,
+    /// Uncompressed BMP
+    Bmp,
+}
+
+impl Intermediate {
+    pub fn extension(self) -> &'static str {
+        match self {
+            Self::Png => "png",
+            Self::Ppm => "ppm",
+            Self::Bmp => "bmp",
+        }
+    }
+}
+
+impl std::str::FromStr for Intermediate {
+    type Err = VstorageError;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s.to_ascii_lowercase().as_str() {
+            "png" => Ok(Self::Png),
+            "ppm" => Ok(Self::Ppm),
+            "bmp" => Ok(Self::Bmp),
+            other => Err(VstorageError::Config(format!(
+                "unknown intermediate format '{other}' (expected png, ppm or bmp)"
+            ))),
+        }
+    }
+}
+
+impl std::fmt::Display for Intermediate {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.extension())
+    }
+}
+
+/// Where a run keeps its intermediate frames on disk, and in what format;
+/// none of it is recorded in the video.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Workspace {
+    /// Format of every intermediate frame written or extracted
+    pub intermediate: Intermediate,
+    /// Directory for work directories and extracted frames, instead of
+    /// next to the output video and in the system temp directory
+    pub dir: Option<PathBuf>,
+    /// Leave intermediate frames and work directories on disk once a
+    /// command is done, for debugging or re-muxing
+    pub keep_frames: bool,
+    /// Keep the frame images waiting for ffmpeg under this many bytes by
+    /// handing it fewer frames at a time
+    pub max_temp: Option<u64>,
+}
+
+/// Named, tested combinations of encode settings.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "lowercase"))]
+pub enum Preset {
+    /// Lossless H.264 (CRF 0) with 16 levels: densest, for files kept as-is
+    Archival,
+    /// Survives YouTube's re-encode: 4x4 blocks, 4 levels, 4:2:0
+    Youtube,
+    /// For filming a screen: 8x8 blocks, 2 levels, heavy ECC, and 10 fps so
+    /// a 30 fps camera captures every frame three times
+    Camera,
+}
+
+impl Preset {
+    pub const ALL: [Preset; 3] = [Preset::Archival, Preset::Youtube, Preset::Camera];
+
+    /// Settings of this preset; fields left `None` take the standard values.
+    pub fn settings(self) -> FrameSettings {
+        match self {
+            Preset::Archival => FrameSettings {
+                block_size: Some(2),
+                levels: Some(16),
+                ecc_len: Some(32),
+                crf: Some(0),
+                codec: Some(VideoCodec::H264),
+                ..Default::default()
+            },
+            Preset::Youtube => FrameSettings {
+                block_size: Some(4),
+                levels: Some(4),
+                ecc_len: Some(64),
+                yuv420p: Some(true),
+                ..Default::default()
+            },
+            Preset::Camera => FrameSettings {
+                block_size: Some(8),
+                levels: Some(2),
+                ecc_len: Some(128),
+                fps: Some(10),
+                yuv420p: Some(true),
+                ..Default::default()
+            },
+        }
+    }
+}
+
+impl std::str::FromStr for Preset {
+    type Err = VstorageError;
+
+    fn from_str(s: &str) -> Result<Self> {
+        Preset::ALL
+            .into_iter()
+            .find(|p| p.to_string() == s.to_ascii_lowercase())
+            .ok_or_else(|| {
+                VstorageError::Config(format!(
+                    "unknown preset '{s}' (expected archival, youtube or camera)"
+                ))
+            })
+    }
+}
+
+impl std::fmt::Display for Preset {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            Self::Archival => "archival",
+            Self::Youtube => "youtube",
+            Self::Camera => "camera",
+        })
+    }
+}
+
+/// Encode settings that may each be unset, so that explicit flags can be
+/// layered over a preset.
+#[derive(Debug, Clone, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct FrameSettings {
+    pub block_size: Option<u8>,
+    pub levels: Option<u8>,
+    pub ecc_len: Option<u8>,
+    pub fps: Option<u32>,
+    pub crf: Option<u8>,
+    pub codec: Option<VideoCodec>,
+    pub yuv420p: Option<bool>,
+    pub ffmpeg_args: Option<Vec<String>>,
+    pub kdf: Option<Kdf>,
+    pub compression: Option<Compression>,
+    pub layout: Option<Layout>,
+}
+
+impl FrameSettings {
+    /// Fill the fields unset in `self` from `fallback`.
+    pub fn or(self, fallback: FrameSettings) -> FrameSettings {
+        FrameSettings {
+            block_size: self.block_size.or(fallback.block_size),
+            levels: self.levels.or(fallback.levels),
+            ecc_len: self.ecc_len.or(fallback.ecc_len),
+            fps: self.fps.or(fallback.fps),
+            crf: self.crf.or(fallback.crf),
+            codec: self.codec.or(fallback.codec),
+            yuv420p: self.yuv420p.or(fallback.yuv420p),
+            ffmpeg_args: self.ffmpeg_args.or(fallback.ffmpeg_args),
+            kdf: self.kdf.or(fallback.kdf),
+            compression: self.compression.or(fallback.compression),
+            layout: self.layout.or(fallback.layout),
+        }
+    }
+
+    /// Build the config, taking block 8, levels 2, ecc 64, 30 fps, CRF 18,
+    /// H.264, Argon2id, no compression and the raster layout for anything
+    /// unset.
+    pub fn resolve(&self) -> Result<FrameConfig> {
+        self.builder().build()
+    }
+
+    /// A builder starting from these settings, for the ones they lack.
+    pub fn builder(&self) -> FrameConfigBuilder {
+        FrameConfigBuilder {
+            settings: self.clone(),
+            ..Default::default()
+        }
+    }
+}
+
+/// Builds a [`FrameConfig`] from whichever settings are given, the rest
+/// taking the defaults of [`FrameSettings::resolve`], and checks them
+/// together: e.g. `FrameConfig::builder().levels(4).resolution(1920, 1080)`.
+#[derive(Debug, Clone, Default)]
+pub struct FrameConfigBuilder {
+    settings: FrameSettings,
+    resolution: Option<(u32, u32)>,
+    header: Option<(usize, usize)>,
+    metadata_levels: Option<u8>,
+    channel_levels: Option<[u8; 3]>,
+    target_bitrate: Option<u32>,
+    crc_map: bool,
+    bottom_header: bool,
+    banner: bool,
+    barcode: bool,
+    pilots: bool,
+    bin_centers: bool,
+    intro: bool,
+    seed: Option<Vec<u8>>,
+    token: TokenMode,
+    hardware_token: Option<Arc<dyn Token>>,
+    threads: Option<usize>,
+    workspace: Workspace,
+}
+
+impl FrameConfigBuilder {
+    pub fn block_size(mut self, block_size: u8) -> Self {
+        self.settings.block_size = Some(block_size);
+        self
+    }
+
+    pub fn levels(mut self, levels: u8) -> Self {
+        self.settings.levels = Some(levels);
+        self
+    }
+
+    pub fn ecc_len(mut self, ecc_len: u8) -> Self {
+        self.settings.ecc_len = Some(ecc_len);
+        self
+    }
+
+    pub fn fps(mut self, fps: u32) -> Self {
+        self.settings.fps = Some(fps);
+        self
+    }
+
+    pub fn crf(mut self, crf: u8) -> Self {
+        self.settings.crf = Some(crf);
+        self
+    }
+
+    pub fn codec(mut self, codec: VideoCodec) -> Self {
+        self.settings.codec = Some(codec);
+        self
+    }
 
+    /// 4:2:0 output, with red and blue at [`chroma_aware_levels`].
+    pub fn yuv420p(mut self, yuv420p: bool) -> Self {
+        self.settings.yuv420p = Some(yuv420p);
+        self
+    }
+
+    pub fn kdf(mut self, kdf: Kdf) -> Self {
+        self.settings.kdf = Some(kdf);
+        self
+    }
+
+    pub fn compression(mut self, compression: Compression) -> Self {
+        self.settings.compression = Some(compression);
+        self
+    }
+
+    pub fn layout(mut self, layout: Layout) -> Self {
+        self.settings.layout = Some(layout);
+        self
+    }
+
+    /// Fill the settings not given (before or after) from `preset`.
+    pub fn preset(mut self, preset: Preset) -> Self {
+        self.settings = self.settings.or(preset.settings());
+        self
+    }
+
+    /// Frames of `width` x `height` pixels instead of
+    /// [`FRAME_WIDTH`] x [`FRAME_HEIGHT`].
+    pub fn resolution(mut self, width: u32, height: u32) -> Self {
+        self.resolution = Some((width, height));
+        self
+    }
+
+    /// Give the header area `rows` logical rows holding `copies` copies of
+    /// the header. Anything but the default two rows and three copies is
+    /// announced by a preamble at the start of the area, which the copies
+    /// must fit beside.
+    pub fn header_redundancy(mut self, rows: usize, copies: usize) -> Self {
+        self.header = Some((rows, copies));
+        self
+    }
+
+    /// Draw key, piece and signature frames with `levels` (a power of 2 no
+    /// larger than the data area's levels) instead.
+    pub fn metadata_levels(mut self, levels: u8) -> Self {
+        self.metadata_levels = Some(levels);
+        self
+    }
+
+    /// Give the red, green and blue channels of the data area their own
+    /// level counts (powers of 2), e.g. `[8, 4, 4]` to keep fine levels out
+    /// of the channels a codec treats worst. `levels` becomes the largest.
+    pub fn channel_levels(mut self, levels: [u8; 3]) -> Self {
+        self.channel_levels = Some(levels);
+        self
+    }
+
+    /// Target video bitrate in kbit/s, for two-pass encoding instead of CRF.
+    pub fn target_bitrate(mut self, kbps: u32) -> Self {
+        self.target_bitrate = Some(kbps);
+        self
+    }
+
+    /// See [`FrameConfig::crc_map`].
+    pub fn crc_map(mut self, on: bool) -> Self {
+        self.crc_map = on;
+        self
+    }
+
+    /// See [`FrameConfig::bottom_header`].
+    pub fn bottom_header(mut self, on: bool) -> Self {
+        self.bottom_header = on;
+        self
+    }
+
+    /// See [`FrameConfig::banner`].
+    pub fn banner(mut self, on: bool) -> Self {
+        self.banner = on;
+        self
+    }
+
+    /// See [`FrameConfig::barcode`].
+    pub fn barcode(mut self, on: bool) -> Self {
+        self.barcode = on;
+        self
+    }
+
+    /// See [`FrameConfig::pilots`].
+    pub fn pilots(mut self, on: bool) -> Self {
+        self.pilots = on;
+        self
+    }
+
+    /// See [`FrameConfig::bin_centers`].
+    pub fn bin_centers(mut self, on: bool) -> Self {
+        self.bin_centers = on;
+        self
+    }
+
+    /// See [`FrameConfig::intro`].
+    pub fn intro(mut self, on: bool) -> Self {
+        self.intro = on;
+        self
+    }
+
+    /// See [`FrameConfig::seed`].
+    pub fn seed(mut self, seed: Vec<u8>) -> Self {
+        self.seed = Some(seed);
+        self
+    }
+
+    /// See [`FrameConfig::token`].
+    pub fn token(mut self, token: TokenMode) -> Self {
+        self.token = token;
+        self
+    }
+
+    /// See [`FrameConfig::hardware_token`].
+    pub fn hardware_token(mut self, token: Option<Arc<dyn Token>>) -> Self {
+        self.hardware_token = token;
+        self
+    }
+
+    /// See [`FrameConfig::threads`].
+    pub fn threads(mut self, threads: Option<usize>) -> Self {
+        self.threads = threads;
+        self
+    }
+
+    /// See [`FrameConfig::workspace`].
+    pub fn workspace(mut self, workspace: Workspace) -> Self {
+        self.workspace = workspace;
+        self
+    }
+
+    /// The config, or why the settings do not go together: a block size
+    /// that does not divide the frame, a header whose copies do not all fit,
+    /// channel levels 4:2:0 cannot carry, or frames left with no room for
+    /// data. With 4:2:0 an odd block size is rounded up to an even one of at
+    /// least 2 and levels are capped at 4, with a warning.
+    pub fn build(&self) -> Result<FrameConfig> {
+        let s = &self.settings;
+        let (width, height) = self.resolution.unwrap_or((FRAME_WIDTH, FRAME_HEIGHT));
+        let (mut block_size, mut levels) = (s.block_size.unwrap_or(8), s.levels.unwrap_or(2));
+        if s.yuv420p == Some(true) {
+            let fit = ((block_size.saturating_add(1) & !1).max(2), levels.min(4));
+            if fit != (block_size, levels) {
+                tracing::warn!(
+                    "yuv420p needs an even block size of at least 2 and at most 4 levels: \
+                     using block size {} and {} levels instead of {block_size} and {levels}",
+                    fit.0,
+                    fit.1
+                );
+                (block_size, levels) = fit;
+            }
+        }
+        let mut config = FrameConfig::unchecked(
+            width,
+            height,
+            block_size,
+            levels,
+            s.ecc_len.unwrap_or(64),
+            s.fps.unwrap_or(30),
+            s.crf.unwrap_or(18),
+        )?;
+        config.codec = s.codec.unwrap_or(VideoCodec::H264);
+        config.ffmpeg_args = s.ffmpeg_args.clone().unwrap_or_default();
+        config.kdf = s.kdf.unwrap_or_default();
+        config.compression = s.compression.unwrap_or_default();
+        config.layout = s.layout.unwrap_or_default();
+        if s.yuv420p == Some(true) {
+            config.enable_yuv420p()?;
+            config.set_channel_levels(chroma_aware_levels(config.levels))?;
+        }
+        if let Some(levels) = self.channel_levels {
+            config.set_channel_levels(levels)?;
+        }
+        if self.target_bitrate == Some(0) {
+            return Err(VstorageError::Config("target bitrate must be > 0".into()));
+        }
+        if self.seed.as_ref().is_some_and(|seed| seed.is_empty()) {
+            return Err(VstorageError::Config("the seed is empty".into()));
+        }
+        config.target_bitrate = self.target_bitrate;
+        config.crc_map = self.crc_map;
+        config.bottom_header = self.bottom_header;
+        config.banner = self.banner;
+        config.barcode = self.barcode;
+        config.pilots = self.pilots;
+        config.bin_centers = self.bin_centers;
+        config.intro = self.intro;
+        config.seed = self.seed.clone();
+        config.token = self.token;
+        config.hardware_token = self.hardware_token.clone();
+        config.threads = self.threads;
+        config.workspace = self.workspace.clone();
+        match self.header {
+            Some((rows, copies)) => config.set_header_redundancy(rows, copies)?,
+            None => config.check_header()?,
+        }
+        if let Some(levels) = self.metadata_levels {
+            config.set_metadata_levels(levels)?;
+        }
+        let no_room = VstorageError::Config(format!(
+            "{width}x{height} frames of {bs}x{bs} blocks have no room for data",
+            bs = config.block_size
+        ));
+        // The barcode, CRC map, banner and bottom header come out of the
+        // data area, which must be left with a block
+        if config.barcode_columns() >= config.logical_width() {
+            return Err(no_room);
+        }
+        let below = config.map_rows() + config.banner_rows() + config.bottom_header_rows();
+        if config.header_rows + below >= config.logical_height()
+            || config.max_plain_per_frame(true) == 0
+        {
+            return Err(no_room);
+        }
+        Ok(config)
+    }
+}
+
+/// Share of a step in `channel` (0 red, 1 green, 2 blue) that lands in luma,
+/// by energy, rather than in the chroma 4:2:0 halves and codecs quantize
+/// harder.
+pub fn luma_share(channel: usize) -> f64 {
+    let y = [KR, 1.0 - KR - KB, KB][channel];
+    // Cb = (B - Y) / 2(1 - Kb), Cr = (R - Y) / 2(1 - Kr)
+    let cb = (f64::from(channel == 2) - y) / (2.0 * (1.0 - KB));
+    let cr = (f64::from(channel == 0) - y) / (2.0 * (1.0 - KR));
+    y * y / (y * y + cb * cb + cr * cr)
+}
+
+/// Levels of the red, green and blue channels for 4:2:0 output at `levels`:
+/// a channel whose steps land mostly in chroma ([`luma_share`] under a half,
+/// red and blue) gets half as many, at least 2, for twice the spacing.
+pub fn chroma_aware_levels(levels: u8) -> [u8; 3] {
+    std::array::from_fn(|c| {
+        if luma_share(c) < 0.5 {
+            (levels / 2).max(2)
+        } else {
+            levels
+        }
+    })
+}
+
+/// Worker threads for a cap of `limit` (see [`FrameConfig::threads`]), else
+/// the available cores.
+pub fn worker_threads(limit: Option<usize>) -> usize {
+    limit.unwrap_or_else(|| std::thread::available_parallelism().map_or(1, |n| n.get()))
+}
+
+/// Checked encode settings, built with [`FrameConfig::builder`] (or
+/// [`FrameConfig::new`]) and changed only through its setters, so that they
+/// always go together; the fields are read through the methods of the
+/// same names.
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "serde",
+    serde(into = "FrameConfigFields", try_from = "FrameConfigFields")
+)]
 pub struct FrameConfig {
-    pub width: u32,
-    pub height: u32,
-    pub block_size: u8,
-    pub levels: u8,
-    pub ecc_len: u8,
-    pub fps: u32,
-    pub crf: u8,
+    pub(crate) width: u32,
+    pub(crate) height: u32,
+    pub(crate) block_size: u8,
+    pub(crate) levels: u8,
+    pub(crate) ecc_len: u8,
+    pub(crate) fps: u32,
+    pub(crate) crf: u8,
+    pub(crate) codec: VideoCodec,
+    pub(crate) target_bitrate: Option<u32>,
+    pub(crate) yuv420p: bool,
+    pub(crate) ffmpeg_args: Vec<String>,
+    pub(crate) kdf: Kdf,
+    pub(crate) token: TokenMode,
+    pub(crate) hardware_token: Option<Arc<dyn Token>>,
+    pub(crate) compression: Compression,
+    pub(crate) metadata_levels: Option<u8>,
+    pub(crate) channel_levels: Option<[u8; 3]>,
+    pub(crate) layout: Layout,
+    pub(crate) crc_map: bool,
+    pub(crate) bottom_header: bool,
+    pub(crate) banner: bool,
+    pub(crate) barcode: bool,
+    pub(crate) pilots: bool,
+    pub(crate) bin_centers: bool,
+    pub(crate) header_rows: usize,
+    pub(crate) header_copies: usize,
+    pub(crate) intro: bool,
+    pub(crate) seed: Option<Vec<u8>>,
+    pub(crate) threads: Option<usize>,
+    pub(crate) workspace: Workspace,
+    /// Block order of [`Layout::Hilbert`], built once for the frames
+    pub(crate) curve: CurveCache,
+}
+
+/// The fields of a [`FrameConfig`] as serialized, checked together through
+/// [`FrameConfigBuilder::build`] when deserialized.
+#[cfg(feature = "serde")]
+#[derive(serde::Serialize, serde::Deserialize)]
+struct FrameConfigFields {
+    width: u32,
+    height: u32,
+    block_size: u8,
+    levels: u8,
+    ecc_len: u8,
+    fps: u32,
+    crf: u8,
+    codec: VideoCodec,
+    target_bitrate: Option<u32>,
+    yuv420p: bool,
+    ffmpeg_args: Vec<String>,
+    kdf: Kdf,
+    token: TokenMode,
+    compression: Compression,
+    metadata_levels: Option<u8>,
+    channel_levels: Option<[u8; 3]>,
+    layout: Layout,
+    crc_map: bool,
+    bottom_header: bool,
+    banner: bool,
+    barcode: bool,
+    pilots: bool,
+    bin_centers: bool,
+    header_rows: usize,
+    header_copies: usize,
+    intro: bool,
+    seed: Option<Vec<u8>>,
+    threads: Option<usize>,
+    workspace: Workspace,
+}
+
+#[cfg(feature = "serde")]
+impl From<FrameConfig> for FrameConfigFields {
+    fn from(c: FrameConfig) -> Self {
+        Self {
+            width: c.width,
+            height: c.height,
+            block_size: c.block_size,
+            levels: c.levels,
+            ecc_len: c.ecc_len,
+            fps: c.fps,
+            crf: c.crf,
+            codec: c.codec,
+            target_bitrate: c.target_bitrate,
+            yuv420p: c.yuv420p,
+            ffmpeg_args: c.ffmpeg_args,
+            kdf: c.kdf,
+            token: c.token,
+            compression: c.compression,
+            metadata_levels: c.metadata_levels,
+            channel_levels: c.channel_levels,
+            layout: c.layout,
+            crc_map: c.crc_map,
+            bottom_header: c.bottom_header,
+            banner: c.banner,
+            barcode: c.barcode,
+            pilots: c.pilots,
+            bin_centers: c.bin_centers,
+            header_rows: c.header_rows,
+            header_copies: c.header_copies,
+            intro: c.intro,
+            seed: c.seed,
+            threads: c.threads,
+            workspace: c.workspace,
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+impl TryFrom<FrameConfigFields> for FrameConfig {
+    type Error = VstorageError;
+
+    fn try_from(f: FrameConfigFields) -> Result<Self> {
+        let mut builder = FrameConfig::builder()
+            .resolution(f.width, f.height)
+            .block_size(f.block_size)
+            .levels(f.levels)
+            .ecc_len(f.ecc_len)
+            .fps(f.fps)
+            .crf(f.crf)
+            .codec(f.codec)
+            .yuv420p(f.yuv420p)
+            .kdf(f.kdf)
+            .token(f.token)
+            .compression(f.compression)
+            .layout(f.layout)
+            // Given even when equal, so 4:2:0 does not pick its own
+            .channel_levels(f.channel_levels.unwrap_or([f.levels; 3]))
+            .header_redundancy(f.header_rows, f.header_copies)
+            .crc_map(f.crc_map)
+            .bottom_header(f.bottom_header)
+            .banner(f.banner)
+            .barcode(f.barcode)
+            .pilots(f.pilots)
+            .bin_centers(f.bin_centers)
+            .intro(f.intro)
+            .threads(f.threads)
+            .workspace(f.workspace);
+        builder.settings.ffmpeg_args = Some(f.ffmpeg_args);
+        if let Some(kbps) = f.target_bitrate {
+            builder = builder.target_bitrate(kbps);
+        }
+        if let Some(levels) = f.metadata_levels {
+            builder = builder.metadata_levels(levels);
+        }
+        if let Some(seed) = f.seed {
+            builder = builder.seed(seed);
+        }
+        let config = builder.build()?;
+        // The builder fits 4:2:0 settings it is given; these must fit already
+        if (config.block_size, config.levels) != (f.block_size, f.levels) {
+            return Err(VstorageError::Config(format!(
+                "block size {} and {} levels do not go with the other settings",
+                f.block_size, f.levels
+            )));
+        }
+        Ok(config)
+    }
+}
+
+/// Read-only accessors of [`FrameConfig`] fields of `Copy` types.
+macro_rules! getters {
+    ($($(#[$doc:meta])* $name:ident: $ty:ty,)*) => {
+        impl FrameConfig {
+            $(
+                $(#[$doc])*
+                pub fn $name(&self) -> $ty {
+                    self.$name
+                }
+            )*
+        }
+    };
+}
+
+getters! {
+    width: u32,
+    height: u32,
+    block_size: u8,
+    levels: u8,
+    ecc_len: u8,
+    fps: u32,
+    crf: u8,
+    codec: VideoCodec,
+    /// Target video bitrate in kbit/s; enables two-pass encoding instead of CRF.
+    target_bitrate: Option<u32>,
+    /// Encode with 4:2:0 chroma subsampling instead of 4:4:4.
+    yuv420p: bool,
+    /// Key derivation for a password; recorded in the frame headers.
+    kdf: Kdf,
+    /// Whether a password's key also needs the hardware token (see
+    /// [`FrameConfig::hardware_token`]); recorded in the frame headers.
+    token: TokenMode,
+    /// Compression of the file before framing; recorded in the frame headers.
+    compression: Compression,
+    /// Levels of the key, piece and signature frames, if fewer than
+    /// `levels`, so the frames that unlock and verify the file survive more
+    /// damage; recorded in each frame header.
+    metadata_levels: Option<u8>,
+    /// Order of the data area's bytes on its blocks; recorded in the frame
+    /// headers.
+    layout: Layout,
+    /// Keep a CRC per tile of the data area in the bottom rows, so a decode
+    /// can tell which blocks are damaged; recorded in the frame headers.
+    crc_map: bool,
+    /// Repeat the header area in the bottom rows, for frames whose top is
+    /// cropped or covered; recorded in the frame headers.
+    bottom_header: bool,
+    /// Draw a line of text saying what the video is above the bottom header
+    /// (see [`crate::banner`]); recorded in the frame headers.
+    banner: bool,
+    /// Draw the frame number as a barcode down the right edge of the data
+    /// area (see [`crate::barcode`]); recorded in the frame headers.
+    barcode: bool,
+    /// Scatter pilot blocks of known levels over the data area, from which
+    /// a decode fits its level thresholds (see [`crate::pilot`]); recorded
+    /// in the frame headers.
+    pilots: bool,
+    /// Draw the data area's levels at the centres of their bins rather than
+    /// from 0 to 255 (see [`crate::frame::quantize_centered`]); recorded in
+    /// the frame headers.
+    bin_centers: bool,
+    /// Logical rows of the header area (see
+    /// [`FrameConfigBuilder::header_redundancy`])
+    header_rows: usize,
+    /// Copies of the header in the header area
+    header_copies: usize,
+    /// Start the video with an intro frame of instructions (see
+    /// [`crate::intro`]).
+    intro: bool,
+    /// Cap on the threads of RS coding, decryption, layout detection and
+    /// ffmpeg (`-threads`); `None` uses every core. Not recorded.
+    threads: Option<usize>,
+}
+
+impl std::fmt::Display for FrameConfig {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let levels = match self.channel_levels() {
+            [r, g, b] if r != g || g != b => format!("{r}/{g}/{b}"),
+            _ => self.levels.to_string(),
+        };
+        let per_frame = self.max_plain_per_frame(false);
+        write!(
+            f,
+            "{}x{}, {bs}x{bs} blocks, {levels} levels, RS(255,{}): {per_frame} bytes per \
+             frame, {:.1} MB per minute at {} fps",
+            self.width,
+            self.height,
+            self.rs_data_len(),
+            (per_frame as u64 * self.fps as u64 * 60) as f64 / 1e6,
+            self.fps,
+            bs = self.block_size
+        )
+    }
 }
 
 impl FrameConfig {
     pub fn new(block_size: u8, levels: u8, ecc_len: u8, fps: u32, crf: u8) -> Result<Self> {
+        Self::sized(
+            FRAME_WIDTH,
+            FRAME_HEIGHT,
+            block_size,
+            levels,
+            ecc_len,
+            fps,
+            crf,
+        )
+    }
+
+    /// Settings given piecemeal, checked together (see [`FrameConfigBuilder`]).
+    pub fn builder() -> FrameConfigBuilder {
+        FrameConfigBuilder::default()
+    }
+
+    /// Extra ffmpeg arguments appended to the encoder options.
+    pub fn ffmpeg_args(&self) -> &[String] {
+        &self.ffmpeg_args
+    }
+
+    /// Derive the salt, nonce and set id from this seed instead of at
+    /// random, so the same input always gives the same frames (see
+    /// [`crate::memory::seeded_parameters`])
+    pub fn seed(&self) -> Option<&[u8]> {
+        self.seed.as_deref()
+    }
+
+    /// The token whose response goes into a password's key when
+    /// [`FrameConfig::token`] asks for one. Not recorded.
+    pub fn hardware_token(&self) -> Option<&dyn Token> {
+        self.hardware_token.as_deref()
+    }
+
+    /// Where the intermediate frames of an encode go, and in what format.
+    /// Not recorded.
+    pub fn workspace(&self) -> &Workspace {
+        &self.workspace
+    }
+
+    /// Worker threads to use: the [`FrameConfig::threads`] cap, else the
+    /// available cores.
+    pub fn worker_threads(&self) -> usize {
+        worker_threads(self.threads)
+    }
+
+    /// [`FrameConfig::new`] for frames of `width` x `height` pixels.
+    pub fn sized(
+        width: u32,
+        height: u32,
+        block_size: u8,
+        levels: u8,
+        ecc_len: u8,
+        fps: u32,
+        crf: u8,
+    ) -> Result<Self> {
+        Self::builder()
+            .resolution(width, height)
+            .block_size(block_size)
+            .levels(levels)
+            .ecc_len(ecc_len)
+            .fps(fps)
+            .crf(crf)
+            .build()
+    }
+
+    /// The settings each on their own checked, for
+    /// [`FrameConfigBuilder::build`] to finish and check together.
+    fn unchecked(
+        width: u32,
+        height: u32,
+        block_size: u8,
+        levels: u8,
+        ecc_len: u8,
+        fps: u32,
+        crf: u8,
+    ) -> Result<Self> {
         if block_size == 0 {
             return Err(VstorageError::Config("block_size must be > 0".into()));
         }
@@ -30,22 +902,180 @@ impl FrameConfig {
         if ecc_len == 0 || ecc_len as u16 >= 255 {
             return Err(VstorageError::Config("ecc_len must be in 1..254".into()));
         }
-        if FRAME_WIDTH % block_size as u32 != 0 || FRAME_HEIGHT % block_size as u32 != 0 {
+        if !width.is_multiple_of(block_size as u32) || !height.is_multiple_of(block_size as u32) {
             return Err(VstorageError::Config(
                 "frame dimensions must be divisible by block_size".into(),
             ));
         }
         Ok(Self {
-            width: FRAME_WIDTH,
-            height: FRAME_HEIGHT,
+            width,
+            height,
             block_size,
             levels,
             ecc_len,
             fps,
             crf,
+            codec: VideoCodec::H264,
+            target_bitrate: None,
+            yuv420p: false,
+            ffmpeg_args: Vec::new(),
+            kdf: Kdf::Argon2id,
+            token: TokenMode::Off,
+            hardware_token: None,
+            compression: Compression::None,
+            metadata_levels: None,
+            channel_levels: None,
+            layout: Layout::Raster,
+            crc_map: false,
+            bottom_header: false,
+            banner: false,
+            barcode: false,
+            pilots: false,
+            bin_centers: false,
+            header_rows: HEADER_ROWS,
+            header_copies: HEADER_COPIES,
+            intro: false,
+            seed: None,
+            threads: None,
+            workspace: Workspace::default(),
+            curve: CurveCache::default(),
         })
     }
 
+    /// See [`FrameConfigBuilder::channel_levels`].
+    pub(crate) fn set_channel_levels(&mut self, levels: [u8; 3]) -> Result<()> {
+        if levels.iter().any(|l| !l.is_power_of_two() || *l < 2) {
+            return Err(VstorageError::Config(
+                "channel levels must be powers of 2 and >= 2".into(),
+            ));
+        }
+        let max = levels.into_iter().max().unwrap_or(2);
+        if self.yuv420p && max > 4 {
+            return Err(VstorageError::Config("yuv420p requires levels <= 4".into()));
+        }
+        // Fewer levels draw the header with fewer bits per block
+        let previous = self.levels;
+        self.levels = max;
+        if let Err(e) = self.check_header() {
+            self.levels = previous;
+            return Err(e);
+        }
+        self.channel_levels = levels.iter().any(|&l| l != max).then_some(levels);
+        self.metadata_levels = self.metadata_levels.filter(|&l| l < max);
+        Ok(())
+    }
+
+    /// Levels of the red, green and blue channels of a data frame; `levels`
+    /// is the largest of them and draws the header. Recorded in each frame
+    /// header.
+    pub fn channel_levels(&self) -> [u8; 3] {
+        self.channel_levels.unwrap_or([self.levels; 3])
+    }
+
+    /// See [`FrameConfigBuilder::metadata_levels`].
+    pub(crate) fn set_metadata_levels(&mut self, levels: u8) -> Result<()> {
+        if !levels.is_power_of_two() || levels < 2 || levels > self.levels {
+            return Err(VstorageError::Config(format!(
+                "metadata levels must be a power of 2 in 2..={}",
+                self.levels
+            )));
+        }
+        // The piece map needs at least one metadata frame
+        if self.with_levels(levels).max_plain_per_frame(true) == 0 {
+            return Err(VstorageError::Config(format!(
+                "frames at {levels} metadata levels have no room for data"
+            )));
+        }
+        self.metadata_levels = (levels < self.levels).then_some(levels);
+        Ok(())
+    }
+
+    /// This config with each channel capped at `levels` levels, e.g. for
+    /// the data area of a frame whose header records other
+    /// [`FrameHeader::data_levels`].
+    ///
+    /// [`FrameHeader::data_levels`]: crate::header::FrameHeader::data_levels
+    pub fn with_levels(&self, levels: u8) -> Self {
+        let capped = self.channel_levels().map(|l| l.min(levels));
+        let max = capped.into_iter().max().unwrap_or(levels);
+        Self {
+            levels: max,
+            channel_levels: capped.iter().any(|&l| l != max).then_some(capped),
+            ..self.clone()
+        }
+    }
+
+    /// See [`FrameConfigBuilder::header_redundancy`].
+    pub(crate) fn set_header_redundancy(&mut self, rows: usize, copies: usize) -> Result<()> {
+        use crate::header::{MAX_HEADER_COPIES, MAX_HEADER_ROWS};
+        if !(1..=MAX_HEADER_ROWS).contains(&rows) || !(1..=MAX_HEADER_COPIES).contains(&copies) {
+            return Err(VstorageError::Config(format!(
+                "header rows must be in 1..={MAX_HEADER_ROWS} and copies in 1..={MAX_HEADER_COPIES}"
+            )));
+        }
+        let previous = (self.header_rows, self.header_copies);
+        (self.header_rows, self.header_copies) = (rows, copies);
+        self.check_header()
+            .inspect_err(|_| (self.header_rows, self.header_copies) = previous)
+    }
+
+    /// Check that every copy of the header fits whole in the header area: a
+    /// decode reads the default area as three full copies, and a cut-off
+    /// copy only outvotes the others.
+    pub(crate) fn check_header(&self) -> Result<()> {
+        let (rows, copies) = (self.header_rows, self.header_copies);
+        if rows >= self.logical_height() {
+            return Err(VstorageError::Config(format!(
+                "{rows} header rows leave no room for data"
+            )));
+        }
+        let preamble = match self.has_preamble() {
+            true => crate::frame::PREAMBLE_BLOCKS,
+            false => 0,
+        };
+        let blocks = (rows * self.logical_width()).saturating_sub(preamble);
+        let room = blocks * 3 * self.bits_per_channel() as usize / 8;
+        let needed = copies * crate::header::HEADER_SIZE;
+        if needed > room {
+            return Err(VstorageError::Config(format!(
+                "{copies} header copies need {needed} bytes, {rows} rows of {}x{} frames hold \
+                 {room}; give the header more rows with header_redundancy",
+                self.width, self.height
+            )));
+        }
+        Ok(())
+    }
+
+    /// Whether the header area starts with a preamble giving its rows and
+    /// copies, i.e. they are not the defaults.
+    pub fn has_preamble(&self) -> bool {
+        (self.header_rows, self.header_copies) != (HEADER_ROWS, HEADER_COPIES)
+    }
+
+    /// Switch to 4:2:0 output. Chroma is stored at half resolution, so blocks
+    /// must cover whole 2x2 chroma samples and levels must stay coarse.
+    pub fn enable_yuv420p(&mut self) -> Result<()> {
+        if self.block_size < 2 || !self.block_size.is_multiple_of(2) {
+            return Err(VstorageError::Config(
+                "yuv420p requires an even block_size >= 2".into(),
+            ));
+        }
+        if self.levels > 4 {
+            return Err(VstorageError::Config("yuv420p requires levels <= 4".into()));
+        }
+        self.yuv420p = true;
+        Ok(())
+    }
+
+    /// FFmpeg pixel format for the output video.
+    pub fn pix_fmt(&self) -> &'static str {
+        if self.yuv420p {
+            "yuv420p"
+        } else {
+            "yuv444p"
+        }
+    }
+
     pub fn logical_width(&self) -> usize {
         self.width as usize / self.block_size as usize
     }
@@ -58,15 +1088,74 @@ impl FrameConfig {
         (self.levels as f64).log2() as u8
     }
 
+    /// Bits of one data area block, summed over the channels' levels.
     pub fn bits_per_pixel(&self) -> u8 {
-        self.bits_per_channel() * 3
+        self.channel_levels()
+            .iter()
+            .map(|&l| (l as f64).log2() as u8)
+            .sum()
+    }
+
+    /// Logical rows of the CRC map at the bottom of the frame (none without
+    /// [`FrameConfig::crc_map`]).
+    pub fn map_rows(&self) -> usize {
+        if !self.crc_map {
+            return 0;
+        }
+        crate::crcmap::map_rows(self.data_width(), self.logical_height() - self.header_rows)
+    }
+
+    /// Logical rows of the header copy at the bottom of the frame (none
+    /// without [`FrameConfig::bottom_header`]).
+    pub fn bottom_header_rows(&self) -> usize {
+        if self.bottom_header {
+            self.header_rows
+        } else {
+            0
+        }
+    }
+
+    /// Logical rows of the banner (none without [`FrameConfig::banner`]).
+    pub fn banner_rows(&self) -> usize {
+        if self.banner {
+            crate::banner::rows(self.block_size)
+        } else {
+            0
+        }
+    }
+
+    /// Logical columns of the barcode at the right of the data area (none
+    /// without [`FrameConfig::barcode`]).
+    pub fn barcode_columns(&self) -> usize {
+        if self.barcode {
+            crate::barcode::columns(self.block_size)
+        } else {
+            0
+        }
+    }
+
+    /// Logical columns of the data area, left of the barcode.
+    pub fn data_width(&self) -> usize {
+        self.logical_width() - self.barcode_columns()
     }
 
-    /// Number of logical pixels available for data (excluding header rows)
+    /// Logical rows of the data area, between the header and the CRC map
+    /// (which sits above the banner and the bottom header copy).
+    pub fn data_rows(&self) -> std::ops::Range<usize> {
+        let below = self.map_rows() + self.banner_rows() + self.bottom_header_rows();
+        self.header_rows..self.logical_height() - below
+    }
+
+    /// Number of logical pixels available for data (excluding header rows
+    /// and pilots)
     pub fn data_area_pixels(&self) -> usize {
-        let lw = self.logical_width();
-        let lh = self.logical_height();
-        lw * (lh - HEADER_ROWS)
+        let (width, rows) = (self.data_width(), self.data_rows().len());
+        let pilots = if self.pilots {
+            crate::pilot::count(width, rows)
+        } else {
+            0
+        };
+        width * rows - pilots
     }
 
     /// Number of bytes that fit in the data area
@@ -88,6 +1177,16 @@ impl FrameConfig {
     pub fn max_raw_per_frame(&self) -> usize {
         self.max_rs_blocks_per_frame() * self.rs_data_len()
     }
+
+    /// File bytes carried per frame; encrypted frames lose room for the GCM tag.
+    pub fn max_plain_per_frame(&self, encrypted: bool) -> usize {
+        let raw = self.max_raw_per_frame();
+        if encrypted {
+            raw.saturating_sub(crate::crypto::TAG_LEN)
+        } else {
+            raw
+        }
+    }
 }
 
 #[cfg(test)]
@@ -107,6 +1206,93 @@ mod tests {
         assert!(config.max_raw_per_frame() < 1_400_000);
     }
 
+    #[cfg(all(feature = "serde", feature = "pipeline"))]
+    #[test]
+    fn test_serde_roundtrip() {
+        use crate::capacity::sample_header;
+        use crate::codec::FrameHeader;
+        use crate::stats::VideoStats;
+
+        let config = FrameConfig::builder()
+            .block_size(4)
+            .levels(4)
+            .kdf(Kdf::Scrypt)
+            .token(TokenMode::WithPassword)
+            .compression(Compression::Deflate(6))
+            .channel_levels([2, 4, 2])
+            .seed(b"seed".to_vec())
+            .build()
+            .unwrap();
+        let mut header = sample_header(&config, 3, 10);
+        header.nonce = [7; 12];
+        header.data_sha256 = [0xab; 32];
+        header.file_size = u64::MAX;
+        let stats = VideoStats {
+            config: config.clone(),
+            encrypted: true,
+            payload_bytes: None,
+            data_frames: Some(10),
+            video_frames: 12,
+            fps: 30,
+            video_bytes: Some(123_456),
+        };
+
+        // Each value comes back as it went out, through JSON and TOML
+        let text = serde_json::to_string(&header).unwrap();
+        assert!(text.contains("\"kdf\":\"scrypt\",\"token\":\"with-password\""));
+        assert!(text.contains(&format!("\"file_size\":{}", u64::MAX)));
+        let back: FrameHeader = serde_json::from_str(&text).unwrap();
+        assert_eq!(back.serialize(), header.serialize());
+        assert_eq!(serde_json::to_string(&back).unwrap(), text);
+        let back: FrameHeader = toml::from_str(&toml::to_string(&header).unwrap()).unwrap();
+        assert_eq!(back.serialize(), header.serialize());
+
+        let text = serde_json::to_string(&config).unwrap();
+        assert!(text.contains("\"compression\":{\"deflate\":6}"));
+        for back in [
+            serde_json::from_str::<FrameConfig>(&text).unwrap(),
+            toml::from_str(&toml::to_string(&config).unwrap()).unwrap(),
+        ] {
+            assert_eq!(back.channel_levels(), [2, 4, 2]);
+            assert_eq!(back.compression(), Compression::Deflate(6));
+            assert_eq!(serde_json::to_string(&back).unwrap(), text);
+        }
+
+        let text = serde_json::to_string(&stats).unwrap();
+        assert!(text.contains("\"payload_bytes\":null"));
+        let back: VideoStats = serde_json::from_str(&text).unwrap();
+        assert_eq!((back.payload_bytes, back.data_frames), (None, Some(10)));
+        assert_eq!(serde_json::to_string(&back).unwrap(), text);
+        let back: VideoStats = toml::from_str(&toml::to_string(&stats).unwrap()).unwrap();
+        assert_eq!(serde_json::to_string(&back).unwrap(), text);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_deserialize_checks() {
+        let config = FrameConfig::builder().yuv420p(true).build().unwrap();
+        let json = serde_json::to_value(&config).unwrap();
+        let back: FrameConfig = serde_json::from_value(json.clone()).unwrap();
+        assert_eq!(back.channel_levels(), config.channel_levels());
+        assert_eq!(serde_json::to_value(&back).unwrap(), json);
+
+        // Settings the builder refuses are refused here too
+        for (field, value) in [
+            ("block_size", serde_json::json!(0)),
+            ("block_size", serde_json::json!(3)),
+            ("levels", serde_json::json!(3)),
+            ("levels", serde_json::json!(8)),
+            ("channel_levels", serde_json::json!([2, 4, 2])),
+            ("header_copies", serde_json::json!(200)),
+            ("metadata_levels", serde_json::json!(16)),
+        ] {
+            let mut bad = json.clone();
+            bad[field] = value;
+            let e = serde_json::from_value::<FrameConfig>(bad).unwrap_err();
+            assert!(e.is_data(), "{field}: {e}");
+        }
+    }
+
     #[test]
     fn test_invalid_config() {
         assert!(FrameConfig::new(0, 4, 32, 30, 18).is_err());
@@ -114,4 +1300,191 @@ mod tests {
         assert!(FrameConfig::new(2, 4, 0, 30, 18).is_err());
         assert!(FrameConfig::new(7, 4, 32, 30, 18).is_err()); // 3840 not divisible by 7
     }
+
+    #[test]
+    fn test_new_checks_like_builder() {
+        // 240-pixel blocks leave 2 header rows 12 bytes, and no data area
+        let built = FrameConfig::builder()
+            .block_size(240)
+            .levels(2)
+            .ecc_len(32)
+            .build();
+        let new = FrameConfig::new(240, 2, 32, 30, 18);
+        assert!(built.is_err());
+        assert_eq!(new.unwrap_err().to_string(), built.unwrap_err().to_string());
+    }
+
+    #[test]
+    fn test_preset_overrides() {
+        let explicit = FrameSettings {
+            ecc_len: Some(96),
+            ..Default::default()
+        };
+        let config = explicit.or(Preset::Youtube.settings()).resolve().unwrap();
+        assert_eq!(
+            (config.block_size, config.levels, config.ecc_len),
+            (4, 4, 96)
+        );
+        assert!(config.yuv420p);
+
+        for preset in Preset::ALL {
+            assert_eq!(preset.to_string().parse::<Preset>().unwrap(), preset);
+            preset.settings().resolve().unwrap();
+        }
+        let standard = FrameSettings::default().resolve().unwrap();
+        assert_eq!(
+            (standard.block_size, standard.ecc_len, standard.fps),
+            (8, 64, 30)
+        );
+    }
+
+    #[test]
+    fn test_builder() {
+        let config = FrameConfig::builder()
+            .levels(4)
+            .block_size(4)
+            .resolution(1920, 1080)
+            .build()
+            .unwrap();
+        assert_eq!((config.width, config.height), (1920, 1080));
+        assert_eq!((config.levels, config.ecc_len, config.fps), (4, 64, 30));
+        assert!(config
+            .to_string()
+            .starts_with("1920x1080, 4x4 blocks, 4 levels, RS(255,191): "));
+
+        // Explicit settings win over the preset, whichever comes first
+        let config = FrameConfig::builder()
+            .ecc_len(96)
+            .preset(Preset::Youtube)
+            .build()
+            .unwrap();
+        assert_eq!((config.block_size, config.ecc_len), (4, 96));
+        assert_eq!(
+            config.to_string().split(':').next(),
+            Some("3840x2160, 4x4 blocks, 2/4/2 levels, RS(255,159)")
+        );
+
+        // A block that does not divide the frame, a header that does not fit
+        // unless given more rows, and a frame too small for one RS block
+        assert!(FrameConfig::builder().block_size(7).build().is_err());
+        let small = FrameConfig::builder().resolution(640, 360);
+        assert!(small.clone().build().is_err());
+        small.header_redundancy(5, 1).build().unwrap();
+        let thin = FrameConfig::builder().block_size(4).resolution(1920, 12);
+        assert!(thin
+            .build()
+            .unwrap_err()
+            .to_string()
+            .contains("no room for data"));
+
+        // Two rows of 16x16 blocks at two levels hold only two of the three
+        // copies a decode reads
+        let coarse = FrameConfig::builder().block_size(16).levels(2);
+        let err = coarse.clone().build().unwrap_err().to_string();
+        assert!(err.contains("3 header copies need 360 bytes"), "{err}");
+        coarse.header_redundancy(5, 3).build().unwrap();
+        let mut config = FrameConfig::new(16, 4, 32, 30, 18).unwrap();
+        assert!(config.set_channel_levels([2, 2, 2]).is_err());
+        assert_eq!(config.levels(), 4);
+    }
+
+    #[test]
+    fn test_builder_layout_settings() {
+        let config = FrameConfig::builder()
+            .crc_map(true)
+            .bottom_header(true)
+            .banner(true)
+            .barcode(true)
+            .pilots(true)
+            .bin_centers(true)
+            .intro(true)
+            .seed(vec![7; 4])
+            .target_bitrate(8000)
+            .channel_levels([4, 2, 2])
+            .build()
+            .unwrap();
+        assert!(config.crc_map() && config.bottom_header() && config.banner());
+        assert!(config.barcode() && config.pilots() && config.bin_centers() && config.intro());
+        assert_eq!(config.seed(), Some(&[7u8; 4][..]));
+        assert_eq!(config.target_bitrate(), Some(8000));
+        assert_eq!((config.levels(), config.channel_levels()), (4, [4, 2, 2]));
+
+        assert!(FrameConfig::builder().target_bitrate(0).build().is_err());
+        assert!(FrameConfig::builder().seed(Vec::new()).build().is_err());
+        let yuv = FrameConfig::builder().block_size(4).levels(4).yuv420p(true);
+        assert!(yuv.channel_levels([8, 4, 4]).build().is_err());
+        // Settings 4:2:0 cannot carry are fitted to it rather than refused
+        for (block_size, levels, fitted) in [(1, 8, (2, 4)), (3, 4, (4, 4)), (2, 2, (2, 2))] {
+            let config = FrameConfig::builder()
+                .block_size(block_size)
+                .levels(levels)
+                .yuv420p(true)
+                .build()
+                .unwrap();
+            assert_eq!((config.block_size(), config.levels()), fitted);
+            assert_eq!(config.pix_fmt(), "yuv420p");
+        }
+        // The banner and the bottom header take every row the header leaves
+        let low = FrameConfig::builder()
+            .resolution(3840, 80)
+            .header_redundancy(4, 1)
+            .bottom_header(true);
+        low.clone().build().unwrap();
+        let err = low.banner(true).build().unwrap_err();
+        assert!(err.to_string().contains("no room for data"));
+    }
+
+    #[test]
+    fn test_yuv420p_constraints() {
+        let mut config = FrameConfig::new(1, 2, 32, 30, 18).unwrap();
+        assert!(config.enable_yuv420p().is_err());
+        let mut config = FrameConfig::new(2, 8, 32, 30, 18).unwrap();
+        assert!(config.enable_yuv420p().is_err());
+        let mut config = FrameConfig::new(4, 4, 32, 30, 18).unwrap();
+        config.enable_yuv420p().unwrap();
+        assert_eq!(config.pix_fmt(), "yuv420p");
+        assert!(config.set_channel_levels([8, 4, 4]).is_err());
+    }
+
+    #[test]
+    fn test_chroma_aware_levels() {
+        let shares = [0, 1, 2].map(luma_share);
+        assert!(shares[1] > 0.5 && shares[0] < 0.5 && shares[2] < shares[0]);
+        assert_eq!(chroma_aware_levels(4), [2, 4, 2]);
+        assert_eq!(chroma_aware_levels(2), [2; 3]);
+
+        let youtube = Preset::Youtube.settings().resolve().unwrap();
+        assert_eq!(youtube.levels, 4);
+        assert_eq!(youtube.channel_levels(), [2, 4, 2]);
+        let archival = Preset::Archival.settings().resolve().unwrap();
+        assert_eq!(archival.channel_levels, None);
+    }
+
+    #[test]
+    fn test_channel_levels() {
+        let mut config = FrameConfig::new(2, 4, 32, 30, 18).unwrap();
+        config.set_metadata_levels(2).unwrap();
+        config.set_channel_levels([8, 4, 4]).unwrap();
+        assert_eq!(config.levels, 8);
+        assert_eq!(config.bits_per_pixel(), 7);
+        assert_eq!(config.channel_levels(), [8, 4, 4]);
+        assert_eq!(config.metadata_levels, Some(2));
+        assert_eq!(config.with_levels(4).channel_levels(), [4, 4, 4]);
+        assert_eq!(config.with_levels(4).channel_levels, None);
+
+        config.set_channel_levels([4, 4, 4]).unwrap();
+        assert_eq!((config.levels, config.channel_levels), (4, None));
+        assert!(config.set_channel_levels([8, 3, 4]).is_err());
+    }
+
+    #[test]
+    fn test_metadata_levels_need_room() {
+        // At 2 levels, RS(255,1) frames of 30x30 blocks carry less than a GCM tag
+        let builder = FrameConfig::builder()
+            .block_size(30)
+            .levels(16)
+            .ecc_len(254);
+        assert!(builder.clone().build().unwrap().max_plain_per_frame(true) > 0);
+        assert!(builder.metadata_levels(2).build().is_err());
+    }
 }
@@ -0,0 +1,385 @@
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+use crate::config::{FrameSettings, Intermediate, Preset};
+use crate::crypto;
+use crate::error::{Result, VstorageError};
+
+/// Where to read the password from when `-p` is not given.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PasswordSource {
+    /// First line of a file
+    File(PathBuf),
+    /// An environment variable
+    Env(String),
+    /// Standard output of a shell command (e.g. `pass show vstorage`)
+    Command(String),
+}
+
+impl PasswordSource {
+    pub fn read(&self) -> Result<String> {
+        let raw = match self {
+            PasswordSource::File(path) => std::fs::read_to_string(path)?,
+            PasswordSource::Env(var) => std::env::var(var).map_err(|_| {
+                VstorageError::Config(format!("password variable ${var} is not set"))
+            })?,
+            PasswordSource::Command(cmd) => {
+                let output = Command::new("sh").args(["-c", cmd]).output()?;
+                if !output.status.success() {
+                    return Err(VstorageError::Config(format!(
+                        "command '{cmd}' exited with {}",
+                        output.status
+                    )));
+                }
+                String::from_utf8(output.stdout).map_err(|_| {
+                    VstorageError::Config(format!("command '{cmd}' printed invalid UTF-8"))
+                })?
+            }
+        };
+        Ok(raw.lines().next().unwrap_or("").to_string())
+    }
+}
+
+/// A shell command standing in for a hardware token: it is run with the
+/// challenge (a payload's salt) in hex in `$VSTORAGE_CHALLENGE`, and the
+/// first line it prints is the response, e.g.
+/// `ykchalresp -2 -x $VSTORAGE_CHALLENGE`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TokenCommand(pub String);
+
+impl crypto::Token for TokenCommand {
+    fn respond(&self, challenge: &[u8; 16]) -> Result<Vec<u8>> {
+        let hex: String = challenge.iter().map(|b| format!("{b:02x}")).collect();
+        let output = Command::new("sh")
+            .args(["-c", &self.0])
+            .env("VSTORAGE_CHALLENGE", hex)
+            .output()?;
+        if !output.status.success() {
+            return Err(VstorageError::Config(format!(
+                "token command '{}' exited with {}",
+                self.0, output.status
+            )));
+        }
+        let response = output.stdout.split(|&b| b == b'\n').next().unwrap_or(&[]);
+        match response.trim_ascii() {
+            [] => Err(VstorageError::Config(format!(
+                "token command '{}' printed nothing",
+                self.0
+            ))),
+            response => Ok(response.to_vec()),
+        }
+    }
+}
+
+/// Defaults read from `~/.config/vstorage/config.toml` (or `--config`).
+/// Command-line flags override everything here.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ConfigFile {
+    pub settings: FrameSettings,
+    /// Preset filling whatever `settings` leaves unset
+    pub preset: Option<Preset>,
+    pub password: Option<PasswordSource>,
+    /// Hardware token whose response to a payload's salt goes into its key
+    pub token: Option<TokenCommand>,
+    /// Encrypt with the token alone, without a password
+    pub token_only: bool,
+    /// Format of the temporary frames exchanged with ffmpeg
+    pub intermediate: Option<Intermediate>,
+    /// Cap on worker threads (see [`crate::config::FrameConfig::threads`])
+    pub threads: Option<usize>,
+}
+
+impl ConfigFile {
+    /// `$XDG_CONFIG_HOME/vstorage/config.toml`, else `~/.config/vstorage/config.toml`.
+    pub fn default_path() -> Option<PathBuf> {
+        let base = match std::env::var_os("XDG_CONFIG_HOME") {
+            Some(dir) if !dir.is_empty() => PathBuf::from(dir),
+            _ => PathBuf::from(std::env::var_os("HOME")?).join(".config"),
+        };
+        Some(base.join("vstorage").join("config.toml"))
+    }
+
+    pub fn load(path: &Path) -> Result<Self> {
+        let text = std::fs::read_to_string(path)?;
+        Self::parse(&text).map_err(|e| match e {
+            VstorageError::Config(msg) => {
+                VstorageError::Config(format!("{}: {msg}", path.display()))
+            }
+            e => e,
+        })
+    }
+
+    /// Parse the supported TOML subset: top-level `key = value` lines with
+    /// strings, integers, booleans and arrays of strings.
+    pub fn parse(text: &str) -> Result<Self> {
+        let mut file = ConfigFile::default();
+        for (n, line) in text.lines().enumerate() {
+            let bad = |msg: String| VstorageError::Config(format!("line {}: {msg}", n + 1));
+            let line = strip_comment(line).trim();
+            if line.is_empty() {
+                continue;
+            }
+            if line.starts_with('[') {
+                return Err(bad("tables are not supported".into()));
+            }
+            let (key, value) = line
+                .split_once('=')
+                .ok_or_else(|| bad("expected key = value".into()))?;
+            let (key, value) = (key.trim(), parse_value(value.trim()).map_err(&bad)?);
+            let s = &mut file.settings;
+            match key {
+                "block_size" => s.block_size = Some(value.int(key).map_err(&bad)?),
+                "levels" => s.levels = Some(value.int(key).map_err(&bad)?),
+                "ecc" => s.ecc_len = Some(value.int(key).map_err(&bad)?),
+                "fps" => s.fps = Some(value.int(key).map_err(&bad)?),
+                "crf" => s.crf = Some(value.int(key).map_err(&bad)?),
+                "codec" => s.codec = Some(value.str(key).map_err(&bad)?.parse()?),
+                "yuv420p" => s.yuv420p = Some(value.bool(key).map_err(&bad)?),
+                "ffmpeg_args" => s.ffmpeg_args = Some(value.strings(key).map_err(&bad)?),
+                "kdf" => s.kdf = Some(value.str(key).map_err(&bad)?.parse()?),
+                "compression" => s.compression = Some(value.str(key).map_err(&bad)?.parse()?),
+                "layout" => s.layout = Some(value.str(key).map_err(&bad)?.parse()?),
+                "intermediate" => file.intermediate = Some(value.str(key).map_err(&bad)?.parse()?),
+                "threads" => match value.int(key).map_err(&bad)? {
+                    0 => return Err(bad("threads must be at least 1".into())),
+                    n => file.threads = Some(n),
+                },
+                "preset" => file.preset = Some(value.str(key).map_err(&bad)?.parse()?),
+                "password_file" => {
+                    let path = value.str(key).map_err(&bad)?;
+                    file.password = Some(PasswordSource::File(expand_home(&path)));
+                }
+                "password_env" => {
+                    file.password = Some(PasswordSource::Env(value.str(key).map_err(&bad)?))
+                }
+                "password_command" => {
+                    file.password = Some(PasswordSource::Command(value.str(key).map_err(&bad)?))
+                }
+                "token_command" => file.token = Some(TokenCommand(value.str(key).map_err(&bad)?)),
+                "token_only" => file.token_only = value.bool(key).map_err(&bad)?,
+                other => return Err(bad(format!("unknown key '{other}'"))),
+            }
+        }
+        Ok(file)
+    }
+}
+
+enum Value {
+    Str(String),
+    Int(i64),
+    Bool(bool),
+    Array(Vec<Value>),
+}
+
+impl Value {
+    fn int<T: TryFrom<i64>>(self, key: &str) -> std::result::Result<T, String> {
+        match self {
+            Value::Int(n) => T::try_from(n).map_err(|_| format!("{key} = {n} is out of range")),
+            _ => Err(format!("{key} must be an integer")),
+        }
+    }
+
+    fn str(self, key: &str) -> std::result::Result<String, String> {
+        match self {
+            Value::Str(s) => Ok(s),
+            _ => Err(format!("{key} must be a string")),
+        }
+    }
+
+    fn bool(self, key: &str) -> std::result::Result<bool, String> {
+        match self {
+            Value::Bool(b) => Ok(b),
+            _ => Err(format!("{key} must be true or false")),
+        }
+    }
+
+    fn strings(self, key: &str) -> std::result::Result<Vec<String>, String> {
+        match self {
+            Value::Array(items) => items.into_iter().map(|v| v.str(key)).collect(),
+            _ => Err(format!("{key} must be an array of strings")),
+        }
+    }
+}
+
+/// Drop a `#` comment that is not inside a string.
+fn strip_comment(line: &str) -> &str {
+    let mut quote = None;
+    let mut escaped = false;
+    for (i, c) in line.char_indices() {
+        match (quote, c) {
+            (Some('"'), '\\') if !escaped => {
+                escaped = true;
+                continue;
+            }
+            (Some(q), c) if c == q && !escaped => quote = None,
+            (None, '"' | '\'') => quote = Some(c),
+            (None, '#') => return &line[..i],
+            _ => {}
+        }
+        escaped = false;
+    }
+    line
+}
+
+fn parse_value(s: &str) -> std::result::Result<Value, String> {
+    let (value, rest) = parse_one(s)?;
+    if !rest.trim().is_empty() {
+        return Err(format!("unexpected '{}' after value", rest.trim()));
+    }
+    Ok(value)
+}
+
+/// Parse one value from the start of `s`, returning it and the rest.
+fn parse_one(s: &str) -> std::result::Result<(Value, &str), String> {
+    let s = s.trim_start();
+    if let Some(body) = s.strip_prefix('"') {
+        let mut out = String::new();
+        let mut chars = body.char_indices();
+        while let Some((i, c)) = chars.next() {
+            match c {
+                '"' => return Ok((Value::Str(out), &body[i + 1..])),
+                '\\' => match chars.next().map(|(_, c)| c) {
+                    Some('n') => out.push('\n'),
+                    Some('t') => out.push('\t'),
+                    Some(c @ ('"' | '\\')) => out.push(c),
+                    other => return Err(format!("unsupported escape \\{}", other.unwrap_or(' '))),
+                },
+                c => out.push(c),
+            }
+        }
+        return Err("unterminated string".into());
+    }
+    if let Some(body) = s.strip_prefix('\'') {
+        let end = body.find('\'').ok_or("unterminated string")?;
+        return Ok((Value::Str(body[..end].to_string()), &body[end + 1..]));
+    }
+    if let Some(mut body) = s.strip_prefix('[') {
+        let mut items = Vec::new();
+        loop {
+            body = body.trim_start();
+            if let Some(rest) = body.strip_prefix(']') {
+                return Ok((Value::Array(items), rest));
+            }
+            let (item, rest) = parse_one(body)?;
+            items.push(item);
+            body = rest.trim_start();
+            body = body.strip_prefix(',').unwrap_or(body);
+            if body.is_empty() {
+                return Err("unterminated array (arrays must fit on one line)".into());
+            }
+        }
+    }
+    let end = s.find([',', ']', ' ']).unwrap_or(s.len());
+    let (word, rest) = s.split_at(end);
+    let value = match word {
+        "true" => Value::Bool(true),
+        "false" => Value::Bool(false),
+        _ => Value::Int(
+            word.replace('_', "")
+                .parse()
+                .map_err(|_| format!("invalid value '{word}'"))?,
+        ),
+    };
+    Ok((value, rest))
+}
+
+fn expand_home(path: &str) -> PathBuf {
+    match (path.strip_prefix("~/"), std::env::var_os("HOME")) {
+        (Some(rest), Some(home)) => PathBuf::from(home).join(rest),
+        _ => PathBuf::from(path),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::VideoCodec;
+
+    #[test]
+    fn test_parse_config_file() {
+        let file = ConfigFile::parse(
+            r#"
+# defaults for local archives
+preset = "youtube"
+block_size = 2   # smaller blocks
+ecc = 32
+codec = 'vp9'
+ffmpeg_args = ["-threads", "4", "-metadata", "note=a # b"]
+kdf = "scrypt"
+compression = "deflate:9"
+layout = "hilbert"
+intermediate = "ppm"
+threads = 2
+password_env = "VSTORAGE_PW"
+token_command = "ykchalresp -2 -x $VSTORAGE_CHALLENGE"
+token_only = true
+"#,
+        )
+        .unwrap();
+        assert_eq!(file.preset, Some(Preset::Youtube));
+        assert_eq!(file.settings.block_size, Some(2));
+        assert_eq!(file.settings.ecc_len, Some(32));
+        assert_eq!(file.settings.levels, None);
+        assert_eq!(file.settings.codec, Some(VideoCodec::Vp9));
+        assert_eq!(file.settings.kdf, Some(crate::crypto::Kdf::Scrypt));
+        assert_eq!(
+            file.settings.compression,
+            Some(crate::compress::Compression::Deflate(9))
+        );
+        assert_eq!(file.settings.layout, Some(crate::layout::Layout::Hilbert));
+        assert_eq!(file.intermediate, Some(Intermediate::Ppm));
+        assert_eq!(file.threads, Some(2));
+        assert_eq!(
+            file.settings.ffmpeg_args.as_deref(),
+            Some(&["-threads", "4", "-metadata", "note=a # b"].map(String::from)[..])
+        );
+        assert_eq!(
+            file.password,
+            Some(PasswordSource::Env("VSTORAGE_PW".into()))
+        );
+        assert_eq!(
+            file.token,
+            Some(TokenCommand("ykchalresp -2 -x $VSTORAGE_CHALLENGE".into()))
+        );
+        assert!(file.token_only);
+    }
+
+    #[test]
+    fn test_config_file_errors() {
+        for (text, expected) in [
+            ("levels = \"four\"", "line 1: levels must be an integer"),
+            ("\nblock = 2", "line 2: unknown key 'block'"),
+            ("[encode]", "line 1: tables are not supported"),
+            ("crf = 300", "line 1: crf = 300 is out of range"),
+        ] {
+            let err = ConfigFile::parse(text).unwrap_err().to_string();
+            assert!(err.ends_with(expected), "{err}");
+        }
+    }
+
+    #[test]
+    fn test_password_sources() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("pw");
+        std::fs::write(&path, "s3cret\nignored\n").unwrap();
+        assert_eq!(PasswordSource::File(path).read().unwrap(), "s3cret");
+        assert_eq!(
+            PasswordSource::Command("echo hi".into()).read().unwrap(),
+            "hi"
+        );
+        // A token answers the challenge it is given
+        let token = TokenCommand("echo \"r$VSTORAGE_CHALLENGE\"; echo ignored".into());
+        let challenge: [u8; 16] = std::array::from_fn(|i| i as u8 * 17);
+        assert_eq!(
+            crypto::Token::respond(&token, &challenge).unwrap(),
+            b"r00112233445566778899aabbccddeeff"
+        );
+        assert!(crypto::Token::respond(&TokenCommand("true".into()), &challenge).is_err());
+        let err = PasswordSource::Env("VSTORAGE_TEST_UNSET_VAR".into())
+            .read()
+            .unwrap_err();
+        assert!(err
+            .to_string()
+            .contains("$VSTORAGE_TEST_UNSET_VAR is not set"));
+    }
+}
@@ -0,0 +1,226 @@
+use std::fmt;
+
+pub use crate::codec::crc16;
+use crate::json::Json;
+
+/// Side of a tile, in logical blocks.
+pub const TILE_BLOCKS: usize = 64;
+
+/// Copies of each tile's CRC in the map, voted on when read.
+const MAP_COPIES: usize = 3;
+
+/// Tiles across and down a data area `width` blocks wide and `rows` high;
+/// the last column and row of tiles may be narrower.
+pub fn tile_grid(width: usize, rows: usize) -> (usize, usize) {
+    (width.div_ceil(TILE_BLOCKS), rows.div_ceil(TILE_BLOCKS))
+}
+
+/// Logical rows the map takes below a data area of `width` x `rows` blocks,
+/// drawn with 2 levels (3 bits per block).
+pub fn map_rows(width: usize, rows: usize) -> usize {
+    let (tw, th) = tile_grid(width, rows);
+    (tw * th * 2 * MAP_COPIES * 8).div_ceil(width * 3)
+}
+
+/// CRC of each tile, in raster order of the tiles, over the quantized values
+/// of its blocks (`values` holds three per block, in raster order).
+pub fn tile_crcs(values: &[u8], width: usize, rows: usize) -> Vec<u16> {
+    let (tw, th) = tile_grid(width, rows);
+    let mut tile = Vec::with_capacity(TILE_BLOCKS * TILE_BLOCKS * 3);
+    let mut crcs = Vec::with_capacity(tw * th);
+    for ty in 0..th {
+        for tx in 0..tw {
+            tile.clear();
+            let x = tx * TILE_BLOCKS * 3..((tx + 1) * TILE_BLOCKS).min(width) * 3;
+            for y in ty * TILE_BLOCKS..((ty + 1) * TILE_BLOCKS).min(rows) {
+                tile.extend_from_slice(&values[y * width * 3..][x.clone()]);
+            }
+            crcs.push(crc16(&tile));
+        }
+    }
+    crcs
+}
+
+/// Bytes of the map: the CRCs, big-endian, `MAP_COPIES` times over.
+pub fn encode_map(crcs: &[u16]) -> Vec<u8> {
+    let once: Vec<u8> = crcs.iter().flat_map(|c| c.to_be_bytes()).collect();
+    once.repeat(MAP_COPIES)
+}
+
+/// The `tiles` CRCs of a map read back; `None` where no two copies agree.
+pub fn decode_map(map: &[u8], tiles: usize) -> Vec<Option<u16>> {
+    let crc = |copy: usize, i: usize| {
+        let at = (copy * tiles + i) * 2;
+        map.get(at..at + 2)
+            .map(|b| u16::from_be_bytes([b[0], b[1]]))
+    };
+    (0..tiles)
+        .map(|i| {
+            let [a, b, c] = [0, 1, 2].map(|copy| crc(copy, i));
+            if a == b || a == c {
+                a
+            } else if b == c {
+                b
+            } else {
+                None
+            }
+        })
+        .collect()
+}
+
+/// Erasure positions of each RS codeword of a data area: every byte that
+/// carries bits of a block in a damaged tile. `stream` lists the blocks in
+/// the order their bits were written (raster indices), `bits` is the bits per
+/// block, and `locate` maps a byte of the stream to its codeword and position.
+pub fn erasures(
+    damaged: &[bool],
+    width: usize,
+    stream: impl Iterator<Item = usize>,
+    bits: usize,
+    codewords: usize,
+    locate: impl Fn(usize) -> Option<(usize, usize)>,
+) -> Vec<Vec<u8>> {
+    let tw = width.div_ceil(TILE_BLOCKS);
+    let mut out = vec![Vec::new(); codewords];
+    for (s, block) in stream.enumerate() {
+        let (x, y) = (block % width, block / width);
+        if !damaged[(y / TILE_BLOCKS) * tw + x / TILE_BLOCKS] {
+            continue;
+        }
+        for byte in s * bits / 8..=((s + 1) * bits - 1) / 8 {
+            let Some((word, pos)) = locate(byte) else {
+                continue;
+            };
+            let positions: &mut Vec<u8> = &mut out[word];
+            if positions.last() != Some(&(pos as u8)) {
+                positions.push(pos as u8);
+            }
+        }
+    }
+    out
+}
+
+/// How often each tile of the frames failed its CRC.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct TileDamage {
+    pub tiles_wide: usize,
+    pub tiles_high: usize,
+    /// Frames whose map was checked
+    pub frames: usize,
+    /// Frames in which each tile (raster order) was damaged
+    pub counts: Vec<usize>,
+}
+
+impl TileDamage {
+    pub fn new(tiles_wide: usize, tiles_high: usize) -> Self {
+        Self {
+            tiles_wide,
+            tiles_high,
+            frames: 0,
+            counts: vec![0; tiles_wide * tiles_high],
+        }
+    }
+
+    /// Count the damaged tiles of one more frame.
+    pub fn add(&mut self, damaged: &[bool]) {
+        self.frames += 1;
+        for (count, _) in self.counts.iter_mut().zip(damaged).filter(|(_, &d)| d) {
+            *count += 1;
+        }
+    }
+
+    pub fn any(&self) -> bool {
+        self.counts.iter().any(|&c| c > 0)
+    }
+
+    pub fn to_json(&self) -> Json {
+        Json::object([
+            ("tiles_wide", self.tiles_wide.into()),
+            ("tiles_high", self.tiles_high.into()),
+            ("frames", self.frames.into()),
+            ("counts", self.counts.clone().into()),
+        ])
+    }
+}
+
+/// A map of the frame, one character per tile: `.` intact, `+` damaged in
+/// some frames, `#` in all of them.
+impl fmt::Display for TileDamage {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "Damaged tiles ({TILE_BLOCKS}x{TILE_BLOCKS} blocks, {} frames):",
+            self.frames
+        )?;
+        for row in self.counts.chunks(self.tiles_wide.max(1)) {
+            let line: String = row
+                .iter()
+                .map(|&c| match c {
+                    0 => '.',
+                    c if c >= self.frames => '#',
+                    _ => '+',
+                })
+                .collect();
+            write!(f, "\n  {line}")?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_crc16() {
+        // The CCITT-FALSE check value
+        assert_eq!(crc16(b"123456789"), 0x29B1);
+    }
+
+    #[test]
+    fn test_map_votes_and_flags_tiles() {
+        // 2x2 tiles, one of them a partial column
+        let (width, rows) = (100, 128);
+        let mut values = vec![7u8; width * rows * 3];
+        let crcs = tile_crcs(&values, width, rows);
+        assert_eq!(crcs.len(), 4);
+
+        let mut map = encode_map(&crcs);
+        map[2] ^= 0xFF; // One copy of tile 1's CRC
+        assert_eq!(
+            decode_map(&map, 4),
+            crcs.iter().map(|&c| Some(c)).collect::<Vec<_>>()
+        );
+
+        // A block in the bottom right tile
+        values[(100 * width + 90) * 3] = 0;
+        let reread = tile_crcs(&values, width, rows);
+        let damaged: Vec<bool> = reread.iter().zip(&crcs).map(|(a, b)| a != b).collect();
+        assert_eq!(damaged, [false, false, false, true]);
+
+        let mut heat = TileDamage::new(2, 2);
+        heat.add(&damaged);
+        heat.add(&[true, false, false, true]);
+        assert_eq!(
+            heat.to_string(),
+            "Damaged tiles (64x64 blocks, 2 frames):\n  +.\n  .#"
+        );
+    }
+
+    #[test]
+    fn test_erasures_cover_damaged_blocks() {
+        // 4 bits per block in a 128x64 area: tile 1 is bytes 32..64 of each row
+        let (width, rows) = (128, 64);
+        let damaged = [false, true];
+        let codewords = width * rows * 4 / 8 / 255;
+        let erased = erasures(&damaged, width, 0..width * rows, 4, codewords, |b| {
+            (b / 255 < codewords).then_some((b / 255, b % 255))
+        });
+        // 63 whole rows and the first 48 bytes of the last fill the codewords
+        let total: usize = erased.iter().map(Vec::len).sum();
+        assert_eq!(total, 63 * 32 + 16);
+        assert_eq!(&erased[0][..3], &[32, 33, 34]);
+        assert!(!erased[0].contains(&31));
+    }
+}
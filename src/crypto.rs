@@ -1,14 +1,102 @@
-use aes_gcm::aead::{Aead, KeyInit};
+use aes_gcm::aead::{Aead, KeyInit, Payload};
 use aes_gcm::{Aes256Gcm, Nonce};
 use argon2::Argon2;
+use zeroize::Zeroizing;
 
 use crate::error::{Result, VstorageError};
 
+/// AES-GCM authentication tag appended to every encrypted chunk.
+pub const TAG_LEN: usize = 16;
+
+/// Plaintext bytes per segment of [`seal_stream`].
+pub const SEGMENT_LEN: usize = 64 << 10;
+
+/// XORed into nonce byte 7 of the last segment of a stream.
+const LAST_SEGMENT: u8 = 0x01;
+
+pub use crate::codec::{Kdf, TokenMode};
+
+impl Kdf {
+    /// Derive a 256-bit key from password + salt.
+    pub fn derive_key(self, password: &str, salt: &[u8; 16]) -> Zeroizing<[u8; 32]> {
+        match self {
+            Self::Argon2id => derive_key(password, salt),
+            Self::Scrypt => {
+                let mut key = Zeroizing::new([0u8; 32]);
+                let params = scrypt::Params::new(15, 8, 1, 32).expect("valid scrypt parameters");
+                scrypt::scrypt(password.as_bytes(), salt, &params, &mut *key)
+                    .expect("a 32-byte key is a valid output length");
+                key
+            }
+        }
+    }
+}
+
+impl std::str::FromStr for Kdf {
+    type Err = VstorageError;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s.to_ascii_lowercase().as_str() {
+            "argon2id" | "argon2" => Ok(Self::Argon2id),
+            "scrypt" => Ok(Self::Scrypt),
+            other => Err(VstorageError::Config(format!(
+                "unknown kdf '{other}' (expected argon2id or scrypt)"
+            ))),
+        }
+    }
+}
+
+impl std::fmt::Display for Kdf {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            Self::Argon2id => "argon2id",
+            Self::Scrypt => "scrypt",
+        })
+    }
+}
+
+/// A hardware token (e.g. a YubiKey in HMAC challenge-response mode) whose
+/// answer to a payload's salt goes into its key, for payloads whose
+/// [`TokenMode`] asks for one.
+pub trait Token: Send + Sync {
+    fn respond(&self, challenge: &[u8; 16]) -> Result<Vec<u8>>;
+}
+
+impl std::fmt::Debug for dyn Token {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("Token")
+    }
+}
+
+/// What a key is derived from under `mode`: `password`, then a NUL and the
+/// hex of `token`'s response to `salt`; the response alone under
+/// [`TokenMode::Only`].
+fn key_secret(
+    password: &str,
+    mode: TokenMode,
+    salt: &[u8; 16],
+    token: Option<&dyn Token>,
+) -> Result<Zeroizing<String>> {
+    if mode == TokenMode::Off {
+        return Ok(Zeroizing::new(password.to_string()));
+    }
+    let response = Zeroizing::new(token.ok_or(VstorageError::TokenRequired)?.respond(salt)?);
+    let mut secret = Zeroizing::new(match mode {
+        TokenMode::Only => String::new(),
+        _ => password.to_string(),
+    });
+    secret.push('\0');
+    for b in response.iter() {
+        secret.push_str(&format!("{b:02x}"));
+    }
+    Ok(secret)
+}
+
 /// Derive a 256-bit key from password + salt using Argon2id.
-pub fn derive_key(password: &str, salt: &[u8; 16]) -> [u8; 32] {
-    let mut key = [0u8; 32];
+pub fn derive_key(password: &str, salt: &[u8; 16]) -> Zeroizing<[u8; 32]> {
+    let mut key = Zeroizing::new([0u8; 32]);
     Argon2::default()
-        .hash_password_into(password.as_bytes(), salt, &mut key)
+        .hash_password_into(password.as_bytes(), salt, &mut *key)
         .expect("Argon2 key derivation failed");
     key
 }
@@ -23,7 +111,7 @@ pub fn encrypt(data: &[u8], password: &str) -> Result<(Vec<u8>, [u8; 12], [u8; 1
 
     let key = derive_key(password, &salt);
     let cipher =
-        Aes256Gcm::new_from_slice(&key).map_err(|e| VstorageError::Crypto(e.to_string()))?;
+        Aes256Gcm::new_from_slice(&*key).map_err(|e| VstorageError::Crypto(e.to_string()))?;
     let nonce = Nonce::from_slice(&nonce_bytes);
 
     let ciphertext = cipher
@@ -39,17 +127,207 @@ pub fn decrypt(
     password: &str,
     nonce_bytes: &[u8; 12],
     salt: &[u8; 16],
-) -> Result<Vec<u8>> {
+) -> Result<Zeroizing<Vec<u8>>> {
     let key = derive_key(password, salt);
     let cipher =
-        Aes256Gcm::new_from_slice(&key).map_err(|e| VstorageError::Crypto(e.to_string()))?;
+        Aes256Gcm::new_from_slice(&*key).map_err(|e| VstorageError::Crypto(e.to_string()))?;
     let nonce = Nonce::from_slice(nonce_bytes);
 
     cipher
         .decrypt(nonce, ciphertext)
+        .map(Zeroizing::new)
         .map_err(|e| VstorageError::Crypto(e.to_string()))
 }
 
+/// Nonce for one frame: the base nonce with the frame number XORed into its
+/// last four bytes, so every frame's chunk gets a distinct nonce.
+pub fn frame_nonce(base: &[u8; 12], frame_number: u32) -> [u8; 12] {
+    let mut nonce = *base;
+    for (n, b) in nonce[8..].iter_mut().zip(frame_number.to_be_bytes()) {
+        *n ^= b;
+    }
+    nonce
+}
+
+/// Nonce for segment `index` of a stream: the frame nonce, marked when the
+/// segment is the last one so a truncated stream fails to authenticate.
+fn segment_nonce(base: &[u8; 12], index: u32, last: bool) -> [u8; 12] {
+    let mut nonce = frame_nonce(base, index);
+    if last {
+        nonce[7] ^= LAST_SEGMENT;
+    }
+    nonce
+}
+
+/// AES-256-GCM cipher sealing each frame's chunk independently, so any frame
+/// can be decrypted without the others.
+pub struct FrameCipher {
+    cipher: Aes256Gcm,
+    base_nonce: [u8; 12],
+    /// Associated data of every frame's chunk
+    context: Vec<u8>,
+}
+
+impl FrameCipher {
+    /// Derive the key from password + salt with `kdf`, and the `hardware`
+    /// token's response to the salt as `token` asks, for a known base nonce.
+    pub fn new(
+        password: &str,
+        kdf: Kdf,
+        token: TokenMode,
+        hardware: Option<&dyn Token>,
+        salt: &[u8; 16],
+        base_nonce: [u8; 12],
+    ) -> Result<Self> {
+        let secret = key_secret(password, token, salt, hardware)?;
+        Self::from_key(&kdf.derive_key(&secret, salt), base_nonce)
+    }
+
+    /// Cipher for a key that is already known, e.g. a file key unwrapped
+    /// with [`crate::recipient::unwrap_key`].
+    pub fn from_key(key: &[u8; 32], base_nonce: [u8; 12]) -> Result<Self> {
+        let cipher =
+            Aes256Gcm::new_from_slice(key).map_err(|e| VstorageError::Crypto(e.to_string()))?;
+        Ok(Self {
+            cipher,
+            base_nonce,
+            context: Vec::new(),
+        })
+    }
+
+    /// This cipher sealing and opening frame chunks with `context` as
+    /// associated data (see [`crate::header::FrameHeader::cipher_context`]),
+    /// so a chunk only authenticates next to the header fields it was
+    /// encrypted with.
+    pub fn with_context(self, context: Vec<u8>) -> Self {
+        Self { context, ..self }
+    }
+
+    /// Create a cipher with a fresh random salt and base nonce.
+    /// Returns (cipher, base_nonce, salt).
+    pub fn generate(
+        password: &str,
+        kdf: Kdf,
+        token: TokenMode,
+        hardware: Option<&dyn Token>,
+    ) -> Result<(Self, [u8; 12], [u8; 16])> {
+        let mut salt = [0u8; 16];
+        let mut nonce = [0u8; 12];
+        rand::fill(&mut salt);
+        rand::fill(&mut nonce);
+        let cipher = Self::new(password, kdf, token, hardware, &salt, nonce)?;
+        Ok((cipher, nonce, salt))
+    }
+
+    /// Encrypt one frame's chunk. Output is `data.len() + TAG_LEN` bytes.
+    pub fn encrypt_frame(&self, frame_number: u32, data: &[u8]) -> Result<Vec<u8>> {
+        self.encrypt_segment(frame_number, false, data, &self.context)
+    }
+
+    /// Encrypt one frame's chunk of a streamed payload, the `last` one marked
+    /// as [`seal_stream`] marks its last segment, so a stream cut short and
+    /// given a new last frame does not authenticate.
+    pub fn encrypt_stream_frame(
+        &self,
+        frame_number: u32,
+        last: bool,
+        data: &[u8],
+    ) -> Result<Vec<u8>> {
+        self.encrypt_segment(frame_number, last, data, &self.context)
+    }
+
+    fn encrypt_segment(&self, index: u32, last: bool, msg: &[u8], aad: &[u8]) -> Result<Vec<u8>> {
+        let nonce = segment_nonce(&self.base_nonce, index, last);
+        self.cipher
+            .encrypt(Nonce::from_slice(&nonce), Payload { msg, aad })
+            .map_err(|e| VstorageError::Crypto(e.to_string()))
+    }
+
+    /// Tag over an empty message under a reserved frame number, without
+    /// the context; lets a later run check it derived the same key without
+    /// storing the key itself.
+    pub fn key_check(&self) -> Result<[u8; 16]> {
+        let tag = self.encrypt_segment(u32::MAX, false, &[], &[])?;
+        Ok(tag.try_into().unwrap())
+    }
+
+    /// Decrypt and authenticate one frame's chunk.
+    pub fn decrypt_frame(
+        &self,
+        frame_number: u32,
+        ciphertext: &[u8],
+    ) -> Result<Zeroizing<Vec<u8>>> {
+        self.decrypt_segment(frame_number, false, ciphertext, &self.context)
+    }
+
+    /// Decrypt and authenticate one frame's chunk of a streamed payload,
+    /// which must be marked `last` exactly when it is the last frame (see
+    /// [`FrameCipher::encrypt_stream_frame`]).
+    pub fn decrypt_stream_frame(
+        &self,
+        frame_number: u32,
+        last: bool,
+        ciphertext: &[u8],
+    ) -> Result<Zeroizing<Vec<u8>>> {
+        self.decrypt_segment(frame_number, last, ciphertext, &self.context)
+    }
+
+    /// Decrypt a payload sealed as one message under the base nonce, as
+    /// protocol version 1 stored it: what [`decrypt`] does with the password.
+    pub fn decrypt_message(&self, ciphertext: &[u8]) -> Result<Zeroizing<Vec<u8>>> {
+        self.cipher
+            .decrypt(Nonce::from_slice(&self.base_nonce), ciphertext)
+            .map(Zeroizing::new)
+            .map_err(|_| VstorageError::WrongPassword)
+    }
+
+    fn decrypt_segment(
+        &self,
+        index: u32,
+        last: bool,
+        msg: &[u8],
+        aad: &[u8],
+    ) -> Result<Zeroizing<Vec<u8>>> {
+        let nonce = segment_nonce(&self.base_nonce, index, last);
+        self.cipher
+            .decrypt(Nonce::from_slice(&nonce), Payload { msg, aad })
+            .map(Zeroizing::new)
+            .map_err(|_| VstorageError::AuthFailed {
+                frame: index as usize,
+            })
+    }
+}
+
+/// Encrypt `data` as a STREAM of [`SEGMENT_LEN`]-byte segments, each sealed
+/// on its own and the last one marked, instead of as one GCM message: it can
+/// be opened a segment at a time, and dropping or reordering segments fails.
+pub fn seal_stream(cipher: &FrameCipher, data: &[u8]) -> Result<Vec<u8>> {
+    let mut segments: Vec<&[u8]> = data.chunks(SEGMENT_LEN).collect();
+    if segments.is_empty() {
+        segments.push(&[]);
+    }
+    let mut out = Vec::with_capacity(data.len() + segments.len() * TAG_LEN);
+    for (i, segment) in segments.iter().enumerate() {
+        let last = i + 1 == segments.len();
+        out.extend(cipher.encrypt_segment(i as u32, last, segment, &cipher.context)?);
+    }
+    Ok(out)
+}
+
+/// Decrypt a stream made by [`seal_stream`].
+pub fn open_stream(cipher: &FrameCipher, ciphertext: &[u8]) -> Result<Zeroizing<Vec<u8>>> {
+    let segments: Vec<&[u8]> = ciphertext.chunks(SEGMENT_LEN + TAG_LEN).collect();
+    if segments.is_empty() {
+        return Err(VstorageError::Crypto("encrypted stream is empty".into()));
+    }
+    let mut out = Zeroizing::new(Vec::with_capacity(ciphertext.len()));
+    for (i, segment) in segments.iter().enumerate() {
+        let last = i + 1 == segments.len();
+        out.extend_from_slice(&cipher.decrypt_segment(i as u32, last, segment, &cipher.context)?);
+    }
+    Ok(out)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -63,7 +341,7 @@ mod tests {
         assert_ne!(&ciphertext[..], &plaintext[..]);
 
         let decrypted = decrypt(&ciphertext, password, &nonce, &salt).unwrap();
-        assert_eq!(&decrypted, plaintext);
+        assert_eq!(*decrypted, plaintext);
     }
 
     #[test]
@@ -78,6 +356,104 @@ mod tests {
         let salt = [42u8; 16];
         let k1 = derive_key("password", &salt);
         let k2 = derive_key("password", &salt);
-        assert_eq!(k1, k2);
+        assert_eq!(*k1, *k2);
+
+        let scrypt = Kdf::Scrypt.derive_key("password", &salt);
+        assert_eq!(*scrypt, *Kdf::Scrypt.derive_key("password", &salt));
+        // Keys derived before still open their videos
+        assert_eq!(
+            crate::recipient::to_hex(&*scrypt),
+            "473206cf20ed387bfa46a94eb94ba5c47e14906ba23135b2c37c6651f4406338"
+        );
+        assert_ne!(*scrypt, *k1);
+        for kdf in [Kdf::Argon2id, Kdf::Scrypt] {
+            assert_eq!(Kdf::from_id(kdf.id()).unwrap(), kdf);
+            assert_eq!(kdf.to_string().parse::<Kdf>().unwrap(), kdf);
+        }
+        assert!(Kdf::from_id(9).is_err());
+    }
+
+    /// Answers with the challenge reversed.
+    struct Reverse;
+
+    impl Token for Reverse {
+        fn respond(&self, challenge: &[u8; 16]) -> Result<Vec<u8>> {
+            Ok(challenge.iter().rev().copied().collect())
+        }
+    }
+
+    #[test]
+    fn test_key_secret_with_token() {
+        let salt: [u8; 16] = std::array::from_fn(|i| i as u8);
+        let secret = |password, mode| key_secret(password, mode, &salt, Some(&Reverse)).unwrap();
+        assert_eq!(*secret("pw", TokenMode::Off), "pw");
+        assert_eq!(
+            secret("pw", TokenMode::WithPassword).split_once('\0'),
+            Some(("pw", "0f0e0d0c0b0a09080706050403020100"))
+        );
+        // Only the token counts, and it answers each salt differently
+        assert_eq!(*secret("pw", TokenMode::Only), *secret("", TokenMode::Only));
+        let other = key_secret("pw", TokenMode::WithPassword, &[7; 16], Some(&Reverse)).unwrap();
+        assert_ne!(*other, *secret("pw", TokenMode::WithPassword));
+        assert_ne!(
+            *derive_key(&secret("pw", TokenMode::WithPassword), &salt),
+            *derive_key("pw", &salt)
+        );
+
+        // No token installed: only the password-alone mode derives a key
+        assert!(key_secret("pw", TokenMode::Off, &salt, None).is_ok());
+        for mode in [TokenMode::WithPassword, TokenMode::Only] {
+            assert!(matches!(
+                key_secret("pw", mode, &salt, None),
+                Err(VstorageError::TokenRequired)
+            ));
+        }
+    }
+
+    #[test]
+    fn test_frame_cipher_independent_frames() {
+        let (enc, nonce, salt) =
+            FrameCipher::generate("pw", Kdf::Argon2id, TokenMode::Off, None).unwrap();
+        let c0 = enc.encrypt_frame(0, b"first").unwrap();
+        let c1 = enc.encrypt_frame(1, b"second").unwrap();
+        assert_eq!(c0.len(), 5 + TAG_LEN);
+
+        let dec =
+            FrameCipher::new("pw", Kdf::Argon2id, TokenMode::Off, None, &salt, nonce).unwrap();
+        assert_eq!(*dec.decrypt_frame(1, &c1).unwrap(), b"second");
+        assert_eq!(*dec.decrypt_frame(0, &c0).unwrap(), b"first");
+        // A chunk only authenticates under its own frame number
+        assert!(dec.decrypt_frame(1, &c0).is_err());
+
+        // ...and next to the context it was sealed with
+        let enc = enc.with_context(b"file size 5".to_vec());
+        let c0 = enc.encrypt_frame(0, b"first").unwrap();
+        assert!(dec.decrypt_frame(0, &c0).is_err());
+        let dec = dec.with_context(b"file size 6".to_vec());
+        assert!(dec.decrypt_frame(0, &c0).is_err());
+        let dec = dec.with_context(b"file size 5".to_vec());
+        assert_eq!(*dec.decrypt_frame(0, &c0).unwrap(), b"first");
+        assert_eq!(dec.key_check().unwrap(), enc.key_check().unwrap());
+    }
+
+    #[test]
+    fn test_stream_segments() {
+        let (cipher, _, _) =
+            FrameCipher::generate("pw", Kdf::Argon2id, TokenMode::Off, None).unwrap();
+        let data: Vec<u8> = (0..SEGMENT_LEN * 2 + 9).map(|i| i as u8).collect();
+        let sealed = seal_stream(&cipher, &data).unwrap();
+        assert_eq!(sealed.len(), data.len() + 3 * TAG_LEN);
+        assert_eq!(*open_stream(&cipher, &sealed).unwrap(), data);
+        assert_eq!(
+            *open_stream(&cipher, &seal_stream(&cipher, b"").unwrap()).unwrap(),
+            b""
+        );
+
+        // Cutting the stream at a segment boundary leaves no last segment
+        let truncated = &sealed[..2 * (SEGMENT_LEN + TAG_LEN)];
+        assert!(matches!(
+            open_stream(&cipher, truncated),
+            Err(VstorageError::AuthFailed { frame: 1 })
+        ));
     }
 }
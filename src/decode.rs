@@ -1,190 +1,908 @@
+use std::io::Write;
 use std::path::{Path, PathBuf};
+use std::sync::Arc;
 
-use indicatif::{ProgressBar, ProgressStyle};
+use sha2::{Digest, Sha256};
+use zeroize::Zeroizing;
 
-use crate::config::FrameConfig;
+use crate::cancel::CancelToken;
+use crate::collect::{
+    self, assemble_or_hidden, choose_config, detect_config_from_frame, expected_chunk_len,
+    hash_matches, is_encrypted, locate_damage, Collected, FrameCollector, DETECT_FRAMES,
+};
+use crate::compress::Compression;
+use crate::config::{FrameConfig, Intermediate, Workspace};
+use crate::crypto::Token;
 use crate::error::{Result, VstorageError};
 use crate::header::FrameHeader;
-use crate::{crypto, ecc, frame, header, video};
+use crate::json::Json;
+use crate::progress::{NoProgress, ProgressSink, Stage, Tracker};
+use crate::recipient::Identity;
+use crate::recovery::{GapKind, GapReport};
+use crate::signature::VerifyKey;
+use crate::{audio, crypto, ecc, frame, header, preflight, video};
+
+pub use crate::collect::{EccStats, FrameCheck};
+
+/// Optional decode behaviour; `Default` decodes payload 0 strictly.
+#[derive(Debug, Clone, Default)]
+pub struct DecodeOptions {
+    /// Payload of an appended-to video (see [`crate::append::append`]); 0 is
+    /// the originally encoded file
+    pub payload: u16,
+    /// Also decode the audio side channel of the first input into this file
+    pub audio_output: Option<PathBuf>,
+    /// Skip auto-detection and read every frame with this block size,
+    /// levels and ECC
+    pub forced: Option<FrameConfig>,
+    /// Zero-fill unrecoverable frames instead of failing, and write a
+    /// [`GapReport`]
+    pub partial: bool,
+    /// Where to write the gap report (default `<output>.gaps.json`; when
+    /// writing to stdout, nowhere but [`DecodeSummary::gaps`])
+    pub gap_report: Option<PathBuf>,
+    /// Private key for a payload encrypted to recipients (see
+    /// [`crate::recipient`]); `password` is ignored for those
+    pub identity: Option<Identity>,
+    /// Fail unless the file is signed by this key (see [`crate::signature`])
+    pub verify_key: Option<VerifyKey>,
+    /// Cap on worker threads, ffmpeg's included (see [`FrameConfig::threads`])
+    pub threads: Option<usize>,
+    /// Where the frames are extracted, and in what format
+    pub workspace: Workspace,
+    /// Token answering for a key that needs one (see
+    /// [`FrameConfig::hardware_token`])
+    pub hardware_token: Option<Arc<dyn Token>>,
+}
 
 /// Run the full decoding pipeline: MP4 → PNGs → frames → decrypt → file.
-pub fn decode(input_path: &Path, output_path: &Path, password: Option<&str>) -> Result<()> {
+///
+/// `input_paths` may list several videos holding consecutive parts of one
+/// encoding; frames are reassembled by the frame number in their headers.
+/// An `output_path` of `-` writes the decoded file to stdout.
+///
+/// Cancelling `cancel` stops between frames (or kills the running ffmpeg);
+/// the extracted frames are removed and no output is written.
+pub fn decode(
+    input_paths: &[&Path],
+    output_path: &Path,
+    password: Option<&str>,
+    options: &DecodeOptions,
+    progress: &dyn ProgressSink,
+    cancel: &CancelToken,
+) -> Result<DecodeSummary> {
     video::check_ffmpeg()?;
+    let temp_dir = video::temp_dir(&options.workspace)?;
+    let Recovered {
+        collected,
+        cipher,
+        plaintext,
+        hidden,
+        report,
+        stats,
+    } = recover(
+        input_paths,
+        output_path,
+        password,
+        options,
+        temp_dir.path(),
+        progress,
+        cancel,
+    )?;
 
-    // 1. Extract PNGs from video
-    let temp_dir = tempfile::tempdir()?;
-    let pb = ProgressBar::new_spinner();
-    pb.set_style(
-        ProgressStyle::default_spinner()
-            .template("{spinner:.cyan} {msg}")
-            .unwrap(),
-    );
-    pb.set_message(format!(
-        "Extracting frames from {}...",
-        input_path.display()
-    ));
-    pb.enable_steady_tick(std::time::Duration::from_millis(80));
-    video::mp4_to_pngs(input_path, temp_dir.path())?;
-    pb.finish_and_clear();
+    // 9. Write the file
+    write_output(output_path, &plaintext)?;
 
-    // 2. List extracted frames
-    let frame_paths = list_frame_paths(temp_dir.path())?;
-    if frame_paths.is_empty() {
-        return Err(VstorageError::Ffmpeg("no frames extracted".into()));
+    if options.partial {
+        write_gap_report(&report, output_path, options.gap_report.as_deref())?;
     }
 
-    // 3. Read first frame to detect config
-    let first_img = load_png(&frame_paths[0])?;
-    let (first_header, config) = detect_config_from_frame(&first_img)?;
-    let total_frames = first_header.total_frames as usize;
-    let file_size = first_header.file_size;
-    let nonce = first_header.nonce;
-    let salt = first_header.salt;
+    // 10. Optional audio side channel (that of the outer payload, so not
+    //     for a hidden one)
+    if let Some(audio_path) = options.audio_output.as_ref().filter(|_| !hidden) {
+        let wav = temp_dir.path().join("audio.wav");
+        video::extract_audio(input_paths[0], &wav, cancel)?;
+        let side = audio::decode_side_channel(
+            &audio::read_wav(&wav)?,
+            password,
+            options.hardware_token.as_deref(),
+        )?;
+        std::fs::write(audio_path, &side[..])?;
+        tracing::info!(
+            "Wrote {} bytes of audio side channel to {}",
+            side.len(),
+            audio_path.display()
+        );
+    }
 
-    eprintln!(
-        "Detected: {} frames, block_size={}, levels={}, ecc={}, file_size={}",
-        total_frames, config.block_size, config.levels, config.ecc_len, file_size
-    );
+    Ok(DecodeSummary {
+        payload: options.payload,
+        config: collected.config,
+        file_size: plaintext.len() as u64,
+        sha256: Sha256::digest(&plaintext[..]).into(),
+        encrypted: cipher.is_some() || hidden,
+        checks: collected.checks,
+        stats,
+        gaps: options.partial.then_some(report),
+    })
+}
 
-    // 4. Decode all frames
-    let mut ciphertext = Vec::new();
+/// A payload decoded into memory by [`recover`].
+pub(crate) struct Recovered {
+    pub collected: Collected,
+    pub cipher: Option<crypto::FrameCipher>,
+    /// The file, decompressed
+    pub plaintext: Zeroizing<Vec<u8>>,
+    /// Whether `plaintext` is the file hidden in the padding frames
+    pub hidden: bool,
+    pub report: GapReport,
+    pub stats: EccStats,
+}
 
-    let pb = ProgressBar::new(total_frames as u64);
-    pb.set_style(
-        ProgressStyle::default_bar()
-            .template("{spinner:.green} [{bar:40.cyan/blue}] {pos}/{len} frames ({eta} remaining)")
-            .unwrap()
-            .progress_chars("=>-"),
-    );
+/// [`decode`] up to the point of writing the file: extract, RS-decode,
+/// decrypt and check the payload, holding it in memory. Frames are
+/// extracted into `temp_dir`; `output_path` is only checked for room.
+pub(crate) fn recover(
+    input_paths: &[&Path],
+    output_path: &Path,
+    password: Option<&str>,
+    options: &DecodeOptions,
+    temp_dir: &Path,
+    progress: &dyn ProgressSink,
+    cancel: &CancelToken,
+) -> Result<Recovered> {
+    // 0. Fail early on parts of different encodings, missing parts or too
+    //    little space
+    let (first, frame_bytes) = check_inputs(
+        input_paths,
+        options.forced.as_ref(),
+        temp_dir,
+        options.partial,
+        options.workspace.intermediate,
+        options.threads,
+        cancel,
+    )?;
+    if let Some(hdr) = first {
+        check_space(&hdr, frame_bytes, options.payload, temp_dir, output_path)?;
+    }
+
+    // 1.-4. Extract and RS-decode the frames of the payload
+    let mut collected = collect_frames(
+        input_paths,
+        options.payload,
+        temp_dir,
+        options.partial,
+        options.forced.as_ref(),
+        options.workspace.intermediate,
+        options.threads,
+        progress,
+        cancel,
+    )?;
+    if !options.partial {
+        collected.ensure_complete()?;
+    }
+
+    let stats = EccStats::new(&collected.checks, collected.config.ecc_len);
+    tracing::info!("{stats}");
+
+    // 5. Decrypt each frame (or pass through if no encryption); in partial
+    //    mode, damaged frames are zero-filled and reported
+    let cipher = match collect::is_public_key(&collected.first_header) {
+        true => Some(collected.recipient_cipher(options.identity.as_ref())?),
+        false => open_cipher(
+            &collected.first_header,
+            password,
+            options.hardware_token.as_deref(),
+            progress,
+        )?,
+    };
+    let (plaintext, report) =
+        assemble_or_hidden(&mut collected, cipher.as_ref(), password, options.partial)?;
+    let hidden = report.is_none();
+    let mut report = report.unwrap_or_else(|| GapReport::new(plaintext.len() as u64));
+
+    // 6. Check the pieces, if stored: in partial mode they tell which
+    //    regions are intact
+    if !hidden {
+        report.pieces = collected.check_pieces(cipher.as_ref(), &plaintext);
+    }
+    if let Some(pieces) = &report.pieces {
+        if !options.partial && !pieces.damaged.is_empty() {
+            return Err(VstorageError::PieceMismatch(pieces.damaged.clone()));
+        }
+        if !options.partial && pieces.file_ok == Some(false) {
+            return Err(VstorageError::FileHashMismatch("the decoded file".into()));
+        }
+        tracing::info!(
+            "{} of {} pieces verified",
+            pieces.total - pieces.damaged.len(),
+            pieces.total
+        );
+    }
+
+    // 7. Check the signature (a hidden payload has none of its own)
+    if !hidden {
+        collected.check_signature(cipher.as_ref(), &plaintext, options.verify_key.as_ref())?;
+    } else if options.verify_key.is_some() {
+        return Err(VstorageError::Signature(
+            "a hidden payload is not signed".into(),
+        ));
+    }
 
-    let max_raw = config.max_raw_per_frame();
+    // 8. Undo the compression recorded in the headers (a damaged compressed
+    //    file cannot be recovered in part)
+    let compression = collected.first_header.compression;
+    let plaintext = match compression {
+        Compression::None => plaintext,
+        _ if hidden => plaintext,
+        _ => Zeroizing::new(compression.decompress(&plaintext)?),
+    };
+    Ok(Recovered {
+        collected,
+        cipher,
+        plaintext,
+        hidden,
+        report,
+        stats,
+    })
+}
 
-    for (i, frame_path) in frame_paths.iter().enumerate() {
-        if i >= total_frames {
-            break;
+/// What [`decode`] recovered.
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct DecodeSummary {
+    pub payload: u16,
+    pub config: FrameConfig,
+    pub file_size: u64,
+    /// SHA-256 of the written file
+    pub sha256: [u8; 32],
+    pub encrypted: bool,
+    pub checks: Vec<Option<FrameCheck>>,
+    pub stats: EccStats,
+    /// Damaged ranges, with `--partial`
+    pub gaps: Option<GapReport>,
+}
+
+impl DecodeSummary {
+    pub fn to_json(&self) -> Json {
+        let mut json = Json::object([
+            ("command", "decode".into()),
+            ("payload", self.payload.into()),
+            ("settings", layout_json(&self.config)),
+            ("file_size", self.file_size.into()),
+            ("sha256", Json::hex(&self.sha256)),
+            ("encrypted", self.encrypted.into()),
+            ("ecc", self.stats.to_json()),
+            ("frames", frames_json(&self.checks)),
+        ]);
+        if let Some(gaps) = &self.gaps {
+            json.push("gaps", gaps.to_json());
         }
+        json
+    }
+}
 
-        let img = load_png(frame_path)?;
+/// Frame layout parameters as JSON.
+pub(crate) fn layout_json(config: &FrameConfig) -> Json {
+    Json::object([
+        ("block_size", config.block_size.into()),
+        ("levels", config.levels.into()),
+        ("ecc", config.ecc_len.into()),
+    ])
+}
 
-        // Try to read per-frame header; fall back to max capacity
-        let header_bytes = frame::decode_header_area(&img, config.block_size, config.levels);
-        let data_len = match header::decode_header_triple(&header_bytes) {
-            Ok(fh) => fh.data_length as usize,
-            Err(e) => {
-                eprintln!(
-                    "  frame {}: header unreadable ({e}), using max capacity",
-                    i + 1
-                );
-                max_raw
-            }
+/// Per-frame results as a JSON array; undecoded frames have only `frame`.
+pub(crate) fn frames_json(checks: &[Option<FrameCheck>]) -> Json {
+    Json::Array(
+        checks
+            .iter()
+            .enumerate()
+            .map(|(i, c)| {
+                let mut json = Json::object([("frame", (i + 1).into())]);
+                if let Some(c) = c {
+                    json.push("corrected", c.corrected);
+                    json.push("blocks", c.blocks);
+                    json.push("worst_block", c.worst_block);
+                    json.push("hash_ok", c.hash_ok);
+                }
+                json
+            })
+            .collect(),
+    )
+}
+
+/// Write `report` as JSON to `path` or `<output>.gaps.json`; not when the
+/// output went to stdout, which leaves it to the caller, in the summary.
+fn write_gap_report(report: &GapReport, output_path: &Path, path: Option<&Path>) -> Result<()> {
+    let missing = report.bytes(GapKind::Missing);
+    let suspect = report.bytes(GapKind::Suspect);
+    if report.gaps.is_empty() {
+        tracing::info!("All bytes recovered");
+    } else {
+        tracing::warn!(
+            "Partial recovery: {missing} bytes missing (zero-filled), {suspect} bytes suspect"
+        );
+    }
+    let path = match path {
+        Some(p) => p.to_path_buf(),
+        None if output_path == Path::new(crate::encode::STDIO_PATH) => return Ok(()),
+        None => {
+            let mut name = output_path.as_os_str().to_owned();
+            name.push(".gaps.json");
+            PathBuf::from(name)
+        }
+    };
+    std::fs::write(&path, format!("{}\n", report.to_json()))?;
+    tracing::info!("Gap report written to {}", path.display());
+    Ok(())
+}
+
+/// Extract every input video into `work_dir` as frames of `format` and
+/// RS-decode the frames of `payload`, placing each at the index from its
+/// header, on up to `threads`
+/// threads. The payload's global parameters come from its first readable
+/// header.
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn collect_frames(
+    input_paths: &[&Path],
+    payload: u16,
+    work_dir: &Path,
+    check_hashes: bool,
+    forced: Option<&FrameConfig>,
+    format: Intermediate,
+    threads: Option<usize>,
+    progress: &dyn ProgressSink,
+    cancel: &CancelToken,
+) -> Result<Collected> {
+    if input_paths.is_empty() {
+        return Err(VstorageError::Config("no input videos given".into()));
+    }
+
+    // 1. Extract PNGs from every input video
+    let mut frame_paths = Vec::new();
+    for (part, input_path) in input_paths.iter().enumerate() {
+        let part_dir = work_dir.join(format!("part_{part:03}"));
+        std::fs::create_dir(&part_dir)?;
+
+        let pb = Tracker::spinner(
+            progress,
+            Stage::Extract,
+            format!("Extracting frames from {}...", input_path.display()),
+        );
+        video::mp4_to_frames(input_path, &part_dir, format, threads, cancel)?;
+        pb.finish_and_clear();
+
+        // 2. List extracted frames
+        let paths = list_frame_paths(&part_dir, format)?;
+        if paths.is_empty() {
+            return Err(VstorageError::Ffmpeg(format!(
+                "no frames extracted from {}",
+                input_path.display()
+            )));
+        }
+        frame_paths.extend(paths.into_iter().map(|p| (part, p)));
+    }
+
+    // 3. Read the first frames to detect config (unless forced), or any
+    //    later one if none of them has a readable header
+    let first_images: Vec<image::RgbImage> = frame_paths
+        .iter()
+        .take(DETECT_FRAMES)
+        .map(|(_, p)| video::load_frame(p))
+        .collect::<Result<_>>()?;
+    let later = frame_paths
+        .iter()
+        .skip(DETECT_FRAMES)
+        .map(|(_, p)| video::load_frame(p));
+    let (config, preprocess) = choose_config(forced, &first_images, later, threads)?;
+    drop(first_images);
+
+    // 4. Decode the frames of the selected payload
+    let mut collector = FrameCollector::new(payload, config, preprocess, check_hashes);
+    let mut pb = Tracker::frames(progress, Stage::DecodeFrames, frame_paths.len() as u64);
+    for (part, frame_path) in &frame_paths {
+        cancel.check()?;
+        pb.inc(0);
+        let decoded = collector.add(
+            &input_paths[*part].display(),
+            &video::load_frame(frame_path)?,
+        )?;
+        pb.add_bytes(decoded as u64);
+    }
+    pb.finish_and_clear();
+    collector.finish()
+}
+
+/// Read the first frame of every input and check that they are parts of one
+/// encoding and, unless `partial`, that no part is missing, before extracting
+/// all frames. Inputs whose first header is unreadable are left to the full
+/// decode. Returns the first data frame header found and the size of the
+/// largest first frame as an intermediate image of `format`.
+fn check_inputs(
+    input_paths: &[&Path],
+    forced: Option<&FrameConfig>,
+    work_dir: &Path,
+    partial: bool,
+    format: Intermediate,
+    threads: Option<usize>,
+    cancel: &CancelToken,
+) -> Result<(Option<FrameHeader>, u64)> {
+    let (mut firsts, mut part_one, mut frame_bytes) = (Vec::new(), None, 0);
+    for (i, input_path) in input_paths.iter().enumerate() {
+        let png = work_dir.join(format!("first_{i:03}.png"));
+        video::extract_frame(input_path, 0, &png, cancel)?;
+        let img = video::load_frame(&png)?;
+        let bytes = match format {
+            Intermediate::Png => std::fs::metadata(&png)?.len(),
+            _ => u64::from(img.width()) * u64::from(img.height()) * 3,
+        };
+        frame_bytes = frame_bytes.max(bytes);
+        std::fs::remove_file(&png)?;
+        let hdr = match forced {
+            Some(config) => header::decode_header_triple(&frame::decode_header_area(
+                &img,
+                config.block_size,
+                config.levels,
+            ))
+            .ok(),
+            None => detect_config_from_frame(&img, threads)
+                .ok()
+                .map(|(hdr, _)| hdr),
         };
+        // The last part may hold only piece frames
+        if let Some(hdr) = hdr.filter(|h| h.kind != header::KIND_INDEX) {
+            if hdr.part == 0 {
+                part_one.get_or_insert(*input_path);
+            }
+            firsts.push((input_path.display().to_string(), hdr));
+        }
+    }
+    let missing_parts = check_parts(&firsts)?;
+    let data = |(_, h): &&(String, FrameHeader)| h.kind == header::KIND_DATA;
+    let found = firsts.iter().find(data).map(|(_, h)| h.clone());
+    if partial || missing_parts.is_empty() {
+        return Ok((found, frame_bytes));
+    }
+
+    // Frames per part, from where a later part starts or the length of part 1
+    let Some((_, first)) = firsts.iter().find(data) else {
+        return Ok((found, frame_bytes));
+    };
+    let total = first.total_frames as usize;
+    // An intro frame starts part 1, so video frame `v` is data frame
+    // `v - intro`
+    let intro = usize::from(firsts.iter().any(|(_, h)| h.kind == header::KIND_INTRO));
+    let frames_per_part = match firsts.iter().filter(data).find(|(_, h)| h.part > 0) {
+        Some((_, h)) => (h.frame_number as usize + intro) / h.part as usize,
+        None => video::probe_video(part_one.expect("only part 1 was given"))?.frames,
+    };
+    Err(VstorageError::MissingFrames {
+        missing: missing_parts
+            .iter()
+            .flat_map(|&p| {
+                let start = (p as usize * frames_per_part).saturating_sub(intro);
+                start..((p as usize + 1) * frames_per_part - intro).min(total)
+            })
+            .collect(),
+        total,
+        missing_parts,
+        total_parts: first.total_parts,
+    })
+}
+
+/// Fail fast unless the frames of `hdr`'s payload, extracted at about
+/// `frame_bytes` each, fit in `work_dir`, and, when decoding that payload,
+/// the file fits in memory and at `output_path`. Only lower bounds are
+/// checked: other payloads' frames and the decoder's copies are left out,
+/// and a streamed payload, whose size only its last frame records, is not
+/// checked.
+fn check_space(
+    hdr: &FrameHeader,
+    frame_bytes: u64,
+    payload: u16,
+    work_dir: &Path,
+    output_path: &Path,
+) -> Result<()> {
+    if hdr.streamed {
+        return Ok(());
+    }
+    let mut needs = vec![preflight::Need {
+        what: "extracted frames",
+        dir: work_dir.to_path_buf(),
+        bytes: u64::from(hdr.total_frames) * frame_bytes,
+    }];
+    if hdr.payload == payload {
+        preflight::check_memory("decoding the file", hdr.file_size)?;
+        if output_path != Path::new(crate::encode::STDIO_PATH)
+            && crate::s3::S3Url::parse(output_path).is_none()
+        {
+            needs.push(preflight::Need {
+                what: "output file",
+                dir: output_path.parent().unwrap_or(Path::new(".")).to_path_buf(),
+                bytes: hdr.file_size,
+            });
+        }
+    }
+    preflight::check_space(&needs)
+}
+
+/// Check the first headers of every input (named) before decoding: they must
+/// come from one encoding. Returns the parts (0-based) none of them carries.
+pub(crate) fn check_parts(firsts: &[(String, FrameHeader)]) -> Result<Vec<u16>> {
+    let Some((_, first)) = firsts.first() else {
+        return Ok(Vec::new());
+    };
+    let mut seen = std::collections::BTreeSet::new();
+    for (name, hdr) in firsts {
+        if hdr.set_id != first.set_id || hdr.total_parts != first.total_parts {
+            return Err(collect::mixed_sets(name, hdr, first));
+        }
+        seen.insert(hdr.part);
+    }
+    Ok((0..first.total_parts)
+        .filter(|p| !seen.contains(p))
+        .collect())
+}
+
+/// Decode only the bytes `offset..offset + len` of the stored file, extracting
+/// and decrypting just the frames that cover them, and the first frames for
+/// their global parameters (several, so a damaged one is outvoted or
+/// skipped); `cancel` kills the running ffmpeg. Of `options`, only the forced
+/// layout, threads, workspace and hardware token apply to a range.
+pub fn decode_range(
+    input_path: &Path,
+    offset: u64,
+    len: u64,
+    password: Option<&str>,
+    options: &DecodeOptions,
+    cancel: &CancelToken,
+) -> Result<Vec<u8>> {
+    video::check_ffmpeg()?;
+    let workspace = &options.workspace;
+    let temp_dir = video::temp_dir(workspace)?;
+
+    // The first frames carry the global parameters
+    let sample_dir = temp_dir.path().join("first");
+    std::fs::create_dir(&sample_dir)?;
+    video::extract_frame_range(
+        input_path,
+        0,
+        DETECT_FRAMES,
+        &sample_dir,
+        workspace.intermediate,
+        options.threads,
+        cancel,
+    )?;
+    let samples = list_frame_paths(&sample_dir, workspace.intermediate)?
+        .iter()
+        .map(|p| video::load_frame(p))
+        .collect::<Result<Vec<_>>>()?;
+    let (first_header, config, preprocess, intro) =
+        range_globals(&input_path.display(), samples, options)?;
+    if first_header.compression != Compression::None {
+        return Err(VstorageError::Config(format!(
+            "the file is stored with {} compression; decode all of it instead of a range",
+            first_header.compression
+        )));
+    }
+    if first_header.version == 1 && is_encrypted(&first_header) {
+        return Err(VstorageError::Config(
+            "a version 1 video is encrypted as one message; decode all of it instead of a range"
+                .into(),
+        ));
+    }
+    if first_header.streamed {
+        return Err(VstorageError::Config(
+            "the file was encoded from a stream, so its size is only in its last frame; \
+             decode all of it instead of a range"
+                .into(),
+        ));
+    }
+    let file_size = first_header.file_size;
+    if offset.checked_add(len).is_none_or(|end| end > file_size) {
+        return Err(VstorageError::Config(format!(
+            "range {offset}:{len} is outside the stored file ({file_size} bytes)"
+        )));
+    }
+    if len == 0 {
+        return Ok(Vec::new());
+    }
 
-        // Decode data area
-        let data_bytes = frame::decode_data_area(&img, &config);
+    let cipher = open_cipher(
+        &first_header,
+        password,
+        options.hardware_token.as_deref(),
+        &NoProgress,
+    )?;
+    let per_frame = config.max_plain_per_frame(cipher.is_some());
+    let (first, last) = frame_span(offset, len, per_frame as u64);
+    let count = last - first + 1;
+    tracing::info!(
+        "Range {offset}:{len} spans frames {}..={} of {}",
+        first + 1,
+        last + 1,
+        first_header.total_frames
+    );
+
+    let range_dir = temp_dir.path().join("range");
+    std::fs::create_dir(&range_dir)?;
+    // Data frame `first` follows the intro frame, if there is one
+    video::extract_frame_range(
+        input_path,
+        first + intro,
+        count,
+        &range_dir,
+        workspace.intermediate,
+        options.threads,
+        cancel,
+    )?;
+    let frame_paths = list_frame_paths(&range_dir, workspace.intermediate)?;
+    if frame_paths.len() < count {
+        return Err(VstorageError::Ffmpeg(format!(
+            "expected {count} frames from the range, extracted {}",
+            frame_paths.len()
+        )));
+    }
 
-        // RS decode
-        let rs_decoded = ecc::rs_decode(
+    let mut plaintext = Zeroizing::new(Vec::with_capacity(count * per_frame));
+    for (k, frame_path) in frame_paths.iter().take(count).enumerate() {
+        let index = first + k;
+        let img = preprocess.apply(video::load_frame(frame_path)?);
+
+        let header_bytes = frame::decode_header_area(&img, config.block_size, config.levels);
+        let header = header::decode_header_triple(&header_bytes).ok();
+        if let Some(fh) = &header {
+            if fh.frame_number as usize != index {
+                return Err(VstorageError::Header(format!(
+                    "expected frame {}, found frame {}",
+                    index + 1,
+                    fh.frame_number + 1
+                )));
+            }
+        }
+        let data_len = expected_chunk_len(&config, file_size, cipher.is_some(), index);
+
+        let levels = header
+            .as_ref()
+            .map_or(config.channel_levels(), |h| h.area_levels());
+        let data_bytes = frame::decode_data_area(&img, &config, levels, index as u32);
+        let chunk = ecc::rs_decode(
             &data_bytes,
             config.ecc_len as usize,
             config.rs_data_len(),
             data_len,
-        )?;
+            config.worker_threads(),
+        )
+        .map_err(|e| locate_damage(e, &config, file_size, cipher.is_some(), index))?;
+        match &cipher {
+            Some(c) => plaintext.extend_from_slice(&c.decrypt_frame(index as u32, &chunk)?),
+            None if header.is_some_and(|fh| !hash_matches(&chunk, &config, &fh.data_sha256)) => {
+                return Err(VstorageError::HashMismatch { frame: index });
+            }
+            None => plaintext.extend_from_slice(&chunk),
+        }
+    }
 
-        ciphertext.extend_from_slice(&rs_decoded);
-        pb.inc(1);
-    }
-    pb.finish_with_message(format!("{total_frames} frames decoded"));
-
-    // 5. Decrypt (or pass through if no encryption)
-    let encrypted = nonce != [0u8; 12] || salt != [0u8; 16];
-    let plaintext = if encrypted {
-        let pw = password.ok_or_else(|| {
-            VstorageError::Crypto("this video is encrypted — provide -p <PASSWORD>".into())
-        })?;
-        let pb = ProgressBar::new_spinner();
-        pb.set_style(
-            ProgressStyle::default_spinner()
-                .template("{spinner:.cyan} {msg}")
-                .unwrap(),
-        );
-        pb.set_message("Decrypting...");
-        pb.enable_steady_tick(std::time::Duration::from_millis(80));
-        let pt = crypto::decrypt(&ciphertext, pw, &nonce, &salt)?;
-        pb.finish_and_clear();
-        pt
-    } else {
-        eprintln!("No encryption detected — skipping decryption");
-        ciphertext
-    };
+    let start = (offset - first as u64 * per_frame as u64) as usize;
+    Ok(plaintext[start..start + len as usize].to_vec())
+}
 
-    // 6. Truncate to original file size and write
-    let output_data = &plaintext[..file_size as usize];
-    std::fs::write(output_path, output_data)?;
-    eprintln!(
-        "Wrote {} bytes to {}",
-        output_data.len(),
-        output_path.display()
-    );
+/// Global parameters of a range decode from `samples`, the first frames of
+/// `source`: the header most of their data frames agree on (as a full
+/// decode votes), the layout and its correction, and the number of frames
+/// before data frame 0. A damaged first frame is outvoted or skipped.
+fn range_globals(
+    source: &dyn std::fmt::Display,
+    samples: Vec<image::RgbImage>,
+    options: &DecodeOptions,
+) -> Result<(FrameHeader, FrameConfig, frame::Preprocess, usize)> {
+    let (config, preprocess) = choose_config(
+        options.forced.as_ref(),
+        &samples,
+        std::iter::empty(),
+        options.threads,
+    )?;
+    let mut intro = None;
+    for (position, img) in samples.iter().enumerate() {
+        let img = preprocess.apply_ref(img);
+        let header_bytes = frame::decode_header_area(&img, config.block_size, config.levels);
+        match header::decode_header_triple(&header_bytes) {
+            Ok(fh) if fh.kind == header::KIND_INDEX => {
+                return Err(VstorageError::Config(
+                    "the video starts with an index frame; read ranges of its files with \
+                     mount or extract"
+                        .into(),
+                ))
+            }
+            Ok(fh) if fh.kind == header::KIND_DATA && fh.payload == 0 => {
+                intro.get_or_insert(position.saturating_sub(fh.frame_number as usize));
+            }
+            _ => {}
+        }
+    }
+    let mut collector = FrameCollector::new(0, config, preprocess, false);
+    for img in &samples {
+        collector.add(source, img)?;
+    }
+    let collected = collector.finish()?;
+    Ok((
+        collected.first_header,
+        collected.config,
+        preprocess,
+        intro.unwrap_or(0),
+    ))
+}
+
+/// Write decoded bytes to `path`, or to stdout if it is `-`.
+pub fn write_output(path: &Path, data: &[u8]) -> Result<()> {
+    write_output_to(path, data, &mut std::io::stdout().lock())
+}
 
+/// [`write_output`], with `stdout` standing in for stdout.
+pub(crate) fn write_output_to(path: &Path, data: &[u8], stdout: &mut dyn Write) -> Result<()> {
+    if path == Path::new(crate::encode::STDIO_PATH) {
+        stdout.write_all(data)?;
+        stdout.flush()?;
+        tracing::info!("Wrote {} bytes to stdout", data.len());
+    } else {
+        std::fs::write(path, data)?;
+        tracing::info!("Wrote {} bytes to {}", data.len(), path.display());
+    }
     Ok(())
 }
 
-fn load_png(path: &Path) -> Result<image::RgbImage> {
-    let file = std::fs::File::open(path)?;
-    let reader = std::io::BufReader::new(file);
-    let decoder = image::codecs::png::PngDecoder::new(reader)?;
-    let img = image::DynamicImage::from_decoder(decoder)?;
-    Ok(img.to_rgb8())
+/// First and last (0-based, inclusive) frame holding bytes `offset..offset + len`.
+pub fn frame_span(offset: u64, len: u64, per_frame: u64) -> (usize, usize) {
+    let first = offset / per_frame;
+    let last = (offset + len.max(1) - 1) / per_frame;
+    (first as usize, last as usize)
 }
 
-fn list_frame_paths(dir: &Path) -> Result<Vec<PathBuf>> {
+/// Derive the frame cipher for an encrypted video, with the `hardware`
+/// token if its key needs one; `None` if unencrypted.
+pub(crate) fn open_cipher(
+    hdr: &FrameHeader,
+    password: Option<&str>,
+    hardware: Option<&dyn Token>,
+    progress: &dyn ProgressSink,
+) -> Result<Option<crypto::FrameCipher>> {
+    if !is_encrypted(hdr) {
+        tracing::info!("No encryption detected — skipping decryption");
+        return Ok(None);
+    }
+    let pb = Tracker::spinner(
+        progress,
+        Stage::DeriveKey,
+        format!("Deriving key ({})...", hdr.kdf),
+    );
+    let cipher = collect::cipher_for(hdr, password, hardware)?;
+    pb.finish_and_clear();
+    Ok(cipher)
+}
+
+fn list_frame_paths(dir: &Path, format: Intermediate) -> Result<Vec<PathBuf>> {
     let mut paths: Vec<PathBuf> = std::fs::read_dir(dir)?
         .filter_map(|e| e.ok())
         .map(|e| e.path())
-        .filter(|p| p.extension().map_or(false, |ext| ext == "png"))
+        .filter(|p| p.extension().is_some_and(|ext| ext == format.extension()))
         .collect();
     paths.sort();
     Ok(paths)
 }
 
-/// Try combinations of block_size and levels to find a valid header.
-fn detect_config_from_frame(img: &image::RgbImage) -> Result<(FrameHeader, FrameConfig)> {
-    let width = img.width();
-    let height = img.height();
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::frame::Preprocess;
 
-    for &block_size in &[1u8, 2, 4, 8, 16] {
-        if width % block_size as u32 != 0 || height % block_size as u32 != 0 {
-            continue;
-        }
-        for &levels in &[2u8, 4, 8, 16] {
-            let header_bytes = frame::decode_header_area(img, block_size, levels);
-            if let Ok(hdr) = header::decode_header_triple(&header_bytes) {
-                if hdr.block_size == block_size && hdr.levels == levels {
-                    let config = FrameConfig {
-                        width,
-                        height,
-                        block_size,
-                        levels,
-                        ecc_len: hdr.ecc_len,
-                        fps: 30,
-                        crf: 18,
-                    };
-                    return Ok((hdr, config));
-                }
+    #[test]
+    fn test_frame_span() {
+        assert_eq!(frame_span(0, 10, 100), (0, 0));
+        assert_eq!(frame_span(95, 10, 100), (0, 1));
+        assert_eq!(frame_span(100, 100, 100), (1, 1));
+        assert_eq!(frame_span(250, 1, 100), (2, 2));
+    }
+
+    #[test]
+    fn test_check_parts() {
+        let config = FrameConfig::new(4, 4, 32, 30, 18).unwrap();
+        let part = |part: u16, set_id: [u8; 16]| {
+            let hdr = FrameHeader {
+                part,
+                total_parts: 3,
+                set_id,
+                ..crate::capacity::sample_header(&config, 0, 9)
+            };
+            (format!("part{}.mp4", part + 1), hdr)
+        };
+        assert_eq!(
+            check_parts(&[part(0, [1; 16]), part(2, [1; 16])]).unwrap(),
+            [1]
+        );
+        assert!(
+            check_parts(&[part(0, [1; 16]), part(1, [1; 16]), part(2, [1; 16])])
+                .unwrap()
+                .is_empty()
+        );
+        let e = check_parts(&[part(0, [1; 16]), part(1, [2; 16])]).unwrap_err();
+        assert!(e.to_string().contains("part2.mp4 belongs to set 02020202-"));
+    }
+
+    /// Frames of `data` encoded unencrypted with `config`.
+    fn frames_of(data: &[u8], config: &FrameConfig) -> Vec<image::RgbImage> {
+        let options = crate::memory::EncodeOptions::new(config.clone());
+        crate::memory::encode_bytes(data, &options).unwrap()
+    }
+
+    #[test]
+    fn test_frames_of_several_inputs() {
+        let config = FrameConfig::new(8, 2, 32, 30, 18).unwrap();
+        let data = crate::testutil::pattern(100_000, 17, 253);
+        let frames = frames_of(&data, &config);
+        assert!(frames.len() >= 3);
+
+        // Two inputs given in the wrong order still reassemble by frame number
+        let (part1, part2) = frames.split_at(frames.len() / 2);
+        let mut collector = FrameCollector::new(0, config.clone(), Preprocess::None, true);
+        for (source, part) in [("part2.mp4", part2), ("part1.mp4", part1)] {
+            for img in part {
+                collector.add(&source, img).unwrap();
             }
         }
+        let mut collected = collector.finish().unwrap();
+        collected.ensure_complete().unwrap();
+        assert_eq!(
+            *collect::assemble(&mut collected, None, false).unwrap().0,
+            data
+        );
+
+        // An input of another video is refused by its frame count
+        let other = frames_of(&data[..100], &config);
+        let mut collector = FrameCollector::new(0, config, Preprocess::None, true);
+        collector.add(&"part1.mp4", &frames[0]).unwrap();
+        let e = collector.add(&"other.mp4", &other[0]).unwrap_err();
+        assert!(e.to_string().contains("other.mp4 reports 1 total frames"));
     }
 
-    // Debug: print first few pixel values to help diagnose
-    eprintln!("Header detection failed. First frame: {}x{}", width, height);
-    eprintln!("First 8 pixel RGB values:");
-    for x in 0..8u32.min(width) {
-        let p = img.get_pixel(x, 0);
-        eprint!("  ({},{},{}) ", p[0], p[1], p[2]);
+    #[test]
+    fn test_range_globals_outvote_first_frame() {
+        let config = FrameConfig::new(8, 2, 32, 30, 18).unwrap();
+        let data = crate::testutil::pattern(100_000, 7, 251);
+        let frames = frames_of(&data, &config);
+        let options = DecodeOptions::default();
+
+        // A first frame whose header claims another size is outvoted
+        let bytes = frame::decode_header_area(&frames[0], config.block_size, config.levels);
+        let mut wrong = header::decode_header_triple(&bytes).unwrap();
+        wrong.file_size += 1;
+        let mut samples = frames[..3].to_vec();
+        samples[0] = crate::memory::render_frame(&config, wrong, &[]);
+        let (hdr, _, _, intro) = range_globals(&"input", samples, &options).unwrap();
+        assert_eq!((hdr.file_size, intro), (data.len() as u64, 0));
+
+        // One whose header is unreadable is skipped
+        let mut samples = frames[..3].to_vec();
+        let header_len = config.header_rows * config.block_size as usize * config.width as usize;
+        let buf: &mut [u8] = &mut samples[0];
+        buf[..header_len * 3].fill(0);
+        let (hdr, found, _, intro) = range_globals(&"input", samples, &options).unwrap();
+        assert_eq!((hdr.file_size, intro), (data.len() as u64, 0));
+        assert_eq!(found.block_size, config.block_size);
     }
-    eprintln!();
 
-    Err(VstorageError::Header(
-        "could not detect frame configuration from video".into(),
-    ))
+    #[test]
+    fn test_gap_report_destination() {
+        let dir = tempfile::tempdir().unwrap();
+        let mut report = GapReport::new(300);
+        report.add_frame(1, 100, GapKind::Missing, "not decoded");
+        let output = dir.path().join("out.bin");
+        write_gap_report(&report, &output, None).unwrap();
+        let written = std::fs::read_to_string(dir.path().join("out.bin.gaps.json")).unwrap();
+        assert_eq!(written.trim_end(), report.to_json().to_string());
+
+        // Next to stdout it is left to the caller, in the summary
+        let before = std::fs::read_dir(dir.path()).unwrap().count();
+        write_gap_report(&report, Path::new(crate::encode::STDIO_PATH), None).unwrap();
+        assert_eq!(std::fs::read_dir(dir.path()).unwrap().count(), before);
+    }
 }
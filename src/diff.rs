@@ -0,0 +1,257 @@
+use std::fmt;
+use std::path::Path;
+
+use sha2::{Digest, Sha256};
+use zeroize::Zeroizing;
+
+use crate::cancel::CancelToken;
+use crate::collect::{self, is_encrypted, Collected};
+use crate::compress::Compression;
+use crate::config::Workspace;
+use crate::crypto::Token;
+use crate::decode;
+use crate::error::{Result, VstorageError};
+use crate::json::Json;
+use crate::progress::NoProgress;
+use crate::video;
+
+/// Result of [`diff`]: whether two videos store the same data.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct DiffReport {
+    pub payload: u16,
+    /// Data frames of each video
+    pub frames: (usize, usize),
+    /// Recorded file size of each video
+    pub sizes: (u64, u64),
+    /// Whether the frames had to be decrypted to compare them; not when
+    /// neither video is encrypted, or both were encrypted under the same key
+    /// and nonce
+    pub decrypted: bool,
+    /// Frames (0-based) whose data differs or did not decode in one of the
+    /// videos; `None` when the videos split the data into frames differently
+    /// and only the whole payloads were compared
+    pub differing: Option<Vec<usize>>,
+    pub identical: bool,
+}
+
+impl DiffReport {
+    pub fn to_json(&self) -> Json {
+        let mut json = Json::object([
+            ("command", "diff".into()),
+            ("payload", self.payload.into()),
+            ("identical", self.identical.into()),
+            ("decrypted", self.decrypted.into()),
+            ("frames", vec![self.frames.0, self.frames.1].into()),
+            ("sizes", vec![self.sizes.0, self.sizes.1].into()),
+        ]);
+        if let Some(differing) = &self.differing {
+            let numbers: Vec<usize> = differing.iter().map(|i| i + 1).collect();
+            json.push("differing", numbers);
+        }
+        json
+    }
+}
+
+impl fmt::Display for DiffReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "payload {}: {} and {} frames, {} and {} bytes",
+            self.payload, self.frames.0, self.frames.1, self.sizes.0, self.sizes.1
+        )?;
+        if self.decrypted {
+            write!(f, ", compared decrypted")?;
+        }
+        writeln!(f)?;
+        match &self.differing {
+            Some(differing) if !differing.is_empty() => {
+                let shown: Vec<String> = differing
+                    .iter()
+                    .take(20)
+                    .map(|i| (i + 1).to_string())
+                    .collect();
+                let more = match differing.len() > shown.len() {
+                    true => format!(" and {} more", differing.len() - shown.len()),
+                    false => String::new(),
+                };
+                writeln!(f, "frames that differ: {}{more}", shown.join(", "))?;
+            }
+            Some(_) => {}
+            None => writeln!(f, "frame layouts differ; compared the whole payloads")?,
+        }
+        let verdict = match self.identical {
+            true => "IDENTICAL",
+            false => "DIFFERENT",
+        };
+        write!(f, "{verdict}")
+    }
+}
+
+/// The data frames of one video, each reduced to a SHA-256.
+struct Side {
+    collected: Collected,
+    digests: Vec<Option<[u8; 32]>>,
+    /// The payload, as stored, when it takes decompressing to compare
+    packed: Zeroizing<Vec<u8>>,
+    /// SHA-256 of the payload as stored, complete only if every frame decoded
+    whole: Sha256,
+}
+
+/// Compare payload `payload` of the videos `a` and `b`. Frames are compared
+/// by the SHA-256 of their stored bytes, which are decrypted (with
+/// `passwords`, and `hardware` for keys that need a token) only when the
+/// videos were not encrypted alike. The frames are extracted in `workspace`.
+pub fn diff(
+    a: &Path,
+    b: &Path,
+    passwords: (Option<&str>, Option<&str>),
+    hardware: Option<&dyn Token>,
+    payload: u16,
+    workspace: &Workspace,
+) -> Result<DiffReport> {
+    video::check_ffmpeg()?;
+    let collect = |path: &Path| {
+        let temp_dir = video::temp_dir(workspace)?;
+        decode::collect_frames(
+            &[path],
+            payload,
+            temp_dir.path(),
+            true,
+            None,
+            workspace.intermediate,
+            None,
+            &NoProgress,
+            &CancelToken::new(),
+        )
+    };
+    let (ca, cb) = (collect(a)?, collect(b)?);
+    let (ha, hb) = (&ca.first_header, &cb.first_header);
+    let same_layout = ca.config.max_plain_per_frame(is_encrypted(ha))
+        == cb.config.max_plain_per_frame(is_encrypted(hb));
+    let decompress =
+        ha.compression != hb.compression || !same_layout && ha.compression != Compression::None;
+    // Frames encrypted under the same key and nonce are equal exactly when
+    // their plaintexts are, as long as they line up
+    let decrypted = (is_encrypted(ha) || is_encrypted(hb))
+        && ((ha.nonce, ha.salt, ha.kdf) != (hb.nonce, hb.salt, hb.kdf)
+            || !same_layout
+            || decompress);
+
+    let side = |collected: Collected, password: Option<&str>| -> Result<Side> {
+        let cipher = match decrypted {
+            true => decode::open_cipher(&collected.first_header, password, hardware, &NoProgress)?,
+            false => None,
+        };
+        let keep = !same_layout || decompress;
+        let mut side = Side {
+            digests: Vec::with_capacity(collected.chunks.len()),
+            packed: Zeroizing::new(Vec::new()),
+            whole: Sha256::new(),
+            collected,
+        };
+        let header = &side.collected.first_header;
+        let (streamed, total) = (header.streamed, side.collected.chunks.len());
+        for (i, chunk) in side.collected.chunks.iter().enumerate() {
+            let last = collect::is_stream_end(streamed, i, total);
+            let plain = match (chunk, &cipher) {
+                (Some(chunk), Some(c)) => c.decrypt_stream_frame(i as u32, last, chunk).ok(),
+                (Some(chunk), None) => Some(Zeroizing::new(chunk.clone())),
+                (None, _) => None,
+            };
+            side.digests
+                .push(plain.as_ref().map(|p| Sha256::digest(p).into()));
+            match plain {
+                Some(plain) if keep && decompress => side.packed.extend_from_slice(&plain),
+                Some(plain) if keep => side.whole.update(&plain),
+                _ => {}
+            }
+        }
+        Ok(side)
+    };
+    let (sa, sb) = (side(ca, passwords.0)?, side(cb, passwords.1)?);
+    let frames = (sa.digests.len(), sb.digests.len());
+    let sizes = (
+        sa.collected.first_header.file_size,
+        sb.collected.first_header.file_size,
+    );
+
+    if same_layout && !decompress {
+        let differing = differing_frames(&sa.digests, &sb.digests);
+        return Ok(DiffReport {
+            payload,
+            frames,
+            sizes,
+            decrypted,
+            identical: differing.is_empty() && sizes.0 == sizes.1,
+            differing: Some(differing),
+        });
+    }
+
+    let mut whole = Vec::with_capacity(2);
+    for (side, path) in [(sa, a), (sb, b)] {
+        if let Some(i) = side.digests.iter().position(|d| d.is_none()) {
+            return Err(VstorageError::Header(format!(
+                "frame {} of {} did not decode; cannot compare whole payloads",
+                i + 1,
+                path.display()
+            )));
+        }
+        let digest: [u8; 32] = match decompress {
+            true => Sha256::digest(
+                side.collected
+                    .first_header
+                    .compression
+                    .decompress(&side.packed)?,
+            )
+            .into(),
+            false => side.whole.finalize().into(),
+        };
+        whole.push(digest);
+    }
+    Ok(DiffReport {
+        payload,
+        frames,
+        sizes,
+        decrypted,
+        identical: whole[0] == whole[1] && (decompress || sizes.0 == sizes.1),
+        differing: None,
+    })
+}
+
+/// Frames whose digests differ, are missing from either side, or exist in
+/// only one of them.
+fn differing_frames(a: &[Option<[u8; 32]>], b: &[Option<[u8; 32]>]) -> Vec<usize> {
+    (0..a.len().max(b.len()))
+        .filter(|&i| match (a.get(i), b.get(i)) {
+            (Some(Some(x)), Some(Some(y))) => x != y,
+            _ => true,
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_differing_frames() {
+        let (x, y) = (Some([1; 32]), Some([2; 32]));
+        assert!(differing_frames(&[x, y], &[x, y]).is_empty());
+        assert_eq!(differing_frames(&[x, y, x], &[x, x]), [1, 2]);
+        assert_eq!(differing_frames(&[None, y], &[None, y]), [0]);
+
+        let report = DiffReport {
+            payload: 0,
+            frames: (3, 2),
+            sizes: (300, 200),
+            decrypted: false,
+            differing: Some(vec![1, 2]),
+            identical: false,
+        };
+        let text = report.to_string();
+        assert!(text.contains("frames that differ: 2, 3"));
+        assert!(text.ends_with("DIFFERENT"));
+        assert!(report.to_json().to_string().contains("\"differing\":[2,3]"));
+    }
+}
@@ -0,0 +1,110 @@
+use std::ffi::OsString;
+use std::path::{Path, PathBuf};
+use std::process::{Command, Stdio};
+
+use crate::cancel::CancelToken;
+use crate::config::Workspace;
+use crate::error::{Result, VstorageError};
+use crate::progress::{ProgressSink, Stage, Tracker};
+use crate::video;
+
+/// yt-dlp `--format-sort`: the stream closest to the uploaded frames first —
+/// highest resolution, then frame rate, then video bitrate.
+const FORMAT_SORT: &str = "res,fps,vbr,br";
+
+/// A video downloaded into a temporary directory, removed when dropped.
+pub struct Downloaded {
+    pub path: PathBuf,
+    _dir: tempfile::TempDir,
+}
+
+/// Check that yt-dlp is available on PATH.
+pub fn check_ytdlp() -> Result<()> {
+    Command::new("yt-dlp")
+        .arg("--version")
+        .stdout(Stdio::null())
+        .stderr(Stdio::null())
+        .status()
+        .map_err(|_| {
+            VstorageError::Download(
+                "yt-dlp not found — install yt-dlp and make sure it is in your PATH".into(),
+            )
+        })?;
+    Ok(())
+}
+
+/// Download the highest-fidelity video stream of `url` with yt-dlp into a
+/// temporary directory of `workspace`, merged with the best audio stream
+/// when `with_audio` is set (for the audio side channel).
+pub fn download(
+    url: &str,
+    with_audio: bool,
+    workspace: &Workspace,
+    progress: &dyn ProgressSink,
+    cancel: &CancelToken,
+) -> Result<Downloaded> {
+    check_ytdlp()?;
+    let dir = video::temp_dir(workspace)?;
+
+    let pb = Tracker::spinner(progress, Stage::Download, format!("Downloading {url}..."));
+    video::run_tool(
+        "yt-dlp",
+        &ytdlp_args(url, dir.path(), with_audio),
+        VstorageError::Download,
+        cancel,
+    )?;
+    pb.finish_and_clear();
+
+    let path = std::fs::read_dir(dir.path())?
+        .filter_map(|e| e.ok())
+        .map(|e| e.path())
+        .find(|p| p.file_stem().is_some_and(|s| s == "download"))
+        .ok_or_else(|| VstorageError::Download(format!("yt-dlp saved no video for {url}")))?;
+    tracing::info!(
+        "Downloaded {} ({} bytes)",
+        url,
+        std::fs::metadata(&path)?.len()
+    );
+    Ok(Downloaded { path, _dir: dir })
+}
+
+fn ytdlp_args(url: &str, dir: &Path, with_audio: bool) -> Vec<OsString> {
+    let format = if with_audio { "bv*+ba/b" } else { "bv*/b" };
+    let mut args: Vec<OsString> = [
+        "--no-playlist",
+        "--quiet",
+        "--no-warnings",
+        "-f",
+        format,
+        "-S",
+        FORMAT_SORT,
+        "--merge-output-format",
+        "mkv",
+        "-o",
+    ]
+    .map(Into::into)
+    .into();
+    args.push(dir.join("download.%(ext)s").into());
+    args.push("--".into());
+    args.push(url.into());
+    args
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_ytdlp_args() {
+        let args = ytdlp_args("https://youtu.be/x", Path::new("/tmp/d"), false);
+        let args: Vec<&str> = args.iter().map(|a| a.to_str().unwrap()).collect();
+        assert!(args.windows(2).any(|w| w == ["-f", "bv*/b"]));
+        assert!(args.windows(2).any(|w| w == ["-S", FORMAT_SORT]));
+        assert_eq!(
+            &args[args.len() - 3..],
+            ["/tmp/d/download.%(ext)s", "--", "https://youtu.be/x"]
+        );
+        let with_audio = ytdlp_args("u", Path::new("/tmp/d"), true);
+        assert!(with_audio.iter().any(|a| a == "bv*+ba/b"));
+    }
+}
@@ -1,30 +1,28 @@
-use reed_solomon::{Decoder, Encoder};
+use reed_solomon::Decoder;
 
+use crate::codec::{rs_decode_block, rs_encode_blocks};
 use crate::error::{Result, VstorageError};
 
+/// Frames with fewer RS blocks than this per core are coded on fewer threads.
+const MIN_BLOCKS_PER_THREAD: usize = 64;
+
 /// Reed-Solomon encode data.
 /// Splits `data` into chunks of `rs_data_len`, pads the last chunk with zeros,
 /// and encodes each chunk into a 255-byte RS block (rs_data_len + ecc_len).
-pub fn rs_encode(data: &[u8], ecc_len: usize, rs_data_len: usize) -> Vec<u8> {
-    let enc = Encoder::new(ecc_len);
-    let mut result = Vec::new();
-
-    for chunk in data.chunks(rs_data_len) {
-        let input: Vec<u8> = if chunk.len() < rs_data_len {
-            let mut padded = vec![0u8; rs_data_len];
-            padded[..chunk.len()].copy_from_slice(chunk);
-            padded
-        } else {
-            chunk.to_vec()
-        };
-
-        let encoded = enc.encode(&input);
-        // encoded is a Buffer of length rs_data_len + ecc_len = 255
-        for i in 0..rs_data_len + ecc_len {
-            result.push(encoded[i]);
-        }
-    }
-
+/// Runs of blocks are encoded on up to `threads` threads (see
+/// [`crate::config::FrameConfig::worker_threads`]).
+pub fn rs_encode(data: &[u8], ecc_len: usize, rs_data_len: usize, threads: usize) -> Vec<u8> {
+    let block_len = rs_data_len + ecc_len;
+    let num_blocks = data.len().div_ceil(rs_data_len);
+    let per_thread = blocks_per_thread(num_blocks, threads);
+    let mut result = vec![0u8; num_blocks * block_len];
+    let runs = data
+        .chunks(per_thread * rs_data_len)
+        .zip(result.chunks_mut(per_thread * block_len))
+        .collect();
+    run_all(runs, |(data, out)| {
+        rs_encode_blocks(data, out, ecc_len, rs_data_len)
+    });
     result
 }
 
@@ -36,47 +34,93 @@ pub fn rs_decode(
     ecc_len: usize,
     rs_data_len: usize,
     expected_data_len: usize,
+    threads: usize,
 ) -> Result<Vec<u8>> {
-    let dec = Decoder::new(ecc_len);
-    let block_len = rs_data_len + ecc_len; // 255
-    let num_blocks = (expected_data_len + rs_data_len - 1) / rs_data_len;
-    let mut result = Vec::new();
-
-    for i in 0..num_blocks {
-        let start = i * block_len;
-        let end = start + block_len;
-        if end > data.len() {
-            return Err(VstorageError::Ecc(format!(
-                "insufficient data for RS block {i}: need {end} bytes, have {}",
-                data.len()
-            )));
-        }
+    rs_decode_counted(data, ecc_len, rs_data_len, expected_data_len, threads).map(|(d, _)| d)
+}
 
-        // We need to encode dummy data to get a Buffer of the right size,
-        // then overwrite it with our received data.
-        let enc = Encoder::new(ecc_len);
-        let dummy = vec![0u8; rs_data_len];
-        let mut buf = enc.encode(&dummy);
+/// Like [`rs_decode`], also returning the number of corrected symbols in
+/// each RS block.
+pub fn rs_decode_counted(
+    data: &[u8],
+    ecc_len: usize,
+    rs_data_len: usize,
+    expected_data_len: usize,
+    threads: usize,
+) -> Result<(Vec<u8>, Vec<usize>)> {
+    rs_decode_erased(data, ecc_len, rs_data_len, expected_data_len, &[], threads)
+}
 
-        // Copy received data into the buffer
-        for j in 0..block_len {
-            buf[j] = data[start + j];
-        }
+/// Like [`rs_decode_counted`], with positions in each RS block known to be
+/// suspect (see [`crate::crcmap`]). Erasures cost half what unknown errors
+/// do; a block with more than `ecc_len` of them, or that fails with them, is
+/// decoded without.
+pub fn rs_decode_erased(
+    data: &[u8],
+    ecc_len: usize,
+    rs_data_len: usize,
+    expected_data_len: usize,
+    erasures: &[Vec<u8>],
+    threads: usize,
+) -> Result<(Vec<u8>, Vec<usize>)> {
+    let block_len = rs_data_len + ecc_len; // 255
+    let num_blocks = expected_data_len.div_ceil(rs_data_len);
+    if num_blocks * block_len > data.len() {
+        let i = data.len() / block_len;
+        return Err(VstorageError::Ecc(format!(
+            "insufficient data for RS block {i}: need {} bytes, have {}",
+            (i + 1) * block_len,
+            data.len()
+        )));
+    }
 
-        match dec.correct(&mut buf, None) {
-            Ok(corrected) => {
-                result.extend_from_slice(corrected.data());
-            }
-            Err(e) => {
-                return Err(VstorageError::Ecc(format!(
-                    "RS correction failed on block {i}: {e:?}"
-                )));
-            }
+    // Corrected symbols per block, `None` where it was beyond repair
+    let per_thread = blocks_per_thread(num_blocks, threads);
+    let mut result = vec![0u8; num_blocks * rs_data_len];
+    let mut counts = vec![None; num_blocks];
+    let runs = data[..num_blocks * block_len]
+        .chunks(per_thread * block_len)
+        .zip(result.chunks_mut(per_thread * rs_data_len))
+        .zip(counts.chunks_mut(per_thread))
+        .enumerate()
+        .collect();
+    run_all(runs, |(run, ((data, out), counts))| {
+        let dec = Decoder::new(ecc_len);
+        let blocks = data.chunks(block_len).zip(out.chunks_mut(rs_data_len));
+        for (i, ((block, out), count)) in blocks.zip(counts).enumerate() {
+            let erased = erasures
+                .get(run * per_thread + i)
+                .filter(|e| !e.is_empty() && e.len() <= ecc_len);
+            // Keep going past a failed block so the error names every one
+            *count = rs_decode_block(&dec, block, erased.map(|e| e.as_slice()), out);
         }
+    });
+    let failed: Vec<usize> = (0..num_blocks).filter(|&i| counts[i].is_none()).collect();
+    if !failed.is_empty() {
+        return Err(VstorageError::EccBlocks(failed));
     }
 
     result.truncate(expected_data_len);
-    Ok(result)
+    Ok((result, counts.into_iter().flatten().collect()))
+}
+
+/// Blocks per thread for coding `blocks` RS blocks on `threads` threads.
+fn blocks_per_thread(blocks: usize, threads: usize) -> usize {
+    blocks.div_ceil(threads.max(1)).max(MIN_BLOCKS_PER_THREAD)
+}
+
+/// Call `f` on every run, each on its own thread when there are several.
+fn run_all<T: Send>(runs: Vec<T>, f: impl Fn(T) + Sync) {
+    if runs.len() <= 1 {
+        runs.into_iter().for_each(f);
+        return;
+    }
+    let f = &f;
+    std::thread::scope(|s| {
+        for run in runs {
+            s.spawn(move || f(run));
+        }
+    });
 }
 
 #[cfg(test)]
@@ -89,10 +133,10 @@ mod tests {
         let rs_data_len = 223;
         let data = b"Hello, Reed-Solomon!";
 
-        let encoded = rs_encode(data, ecc_len, rs_data_len);
+        let encoded = rs_encode(data, ecc_len, rs_data_len, 4);
         assert_eq!(encoded.len(), 255); // one block, padded
 
-        let decoded = rs_decode(&encoded, ecc_len, rs_data_len, data.len()).unwrap();
+        let decoded = rs_decode(&encoded, ecc_len, rs_data_len, data.len(), 4).unwrap();
         assert_eq!(&decoded, data);
     }
 
@@ -102,15 +146,90 @@ mod tests {
         let rs_data_len = 223;
         let data = b"Error correction test data!!!!!";
 
-        let mut encoded = rs_encode(data, ecc_len, rs_data_len);
+        let mut encoded = rs_encode(data, ecc_len, rs_data_len, 4);
 
         // Corrupt up to ecc_len/2 = 16 bytes (maximum correctable)
-        for i in 0..15 {
-            encoded[i] = encoded[i].wrapping_add(1);
+        for byte in encoded.iter_mut().take(15) {
+            *byte = byte.wrapping_add(1);
         }
 
-        let decoded = rs_decode(&encoded, ecc_len, rs_data_len, data.len()).unwrap();
+        let (decoded, fixed) =
+            rs_decode_counted(&encoded, ecc_len, rs_data_len, data.len(), 4).unwrap();
         assert_eq!(&decoded, data);
+        assert_eq!(fixed, vec![15]);
+    }
+
+    #[test]
+    fn test_rs_failed_blocks_listed() {
+        let ecc_len = 32;
+        let rs_data_len = 223;
+        let data = crate::testutil::pattern(700, 1, 256);
+
+        let mut encoded = rs_encode(&data, ecc_len, rs_data_len, 4);
+        for block in [1, 3] {
+            for byte in &mut encoded[block * 255..block * 255 + 40] {
+                *byte ^= 0x5a;
+            }
+        }
+
+        match rs_decode(&encoded, ecc_len, rs_data_len, data.len(), 4) {
+            Err(VstorageError::EccBlocks(blocks)) => assert_eq!(blocks, vec![1, 3]),
+            other => panic!("expected EccBlocks, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn test_rs_erasures_double_capacity() {
+        let (ecc_len, rs_data_len) = (32, 223);
+        let data = crate::testutil::pattern(446, 7, 256);
+        let mut encoded = rs_encode(&data, ecc_len, rs_data_len, 4);
+        // 30 damaged bytes in block 1 are too many unless they are known
+        for byte in &mut encoded[255 + 10..255 + 40] {
+            *byte ^= 0x33;
+        }
+        assert!(rs_decode(&encoded, ecc_len, rs_data_len, data.len(), 4).is_err());
+
+        let erasures = vec![vec![], (10..40).collect()];
+        let (decoded, _) =
+            rs_decode_erased(&encoded, ecc_len, rs_data_len, data.len(), &erasures, 4).unwrap();
+        assert_eq!(decoded, data);
+
+        // Erasures in the wrong place fall back to plain decoding
+        let mut encoded = rs_encode(&data, ecc_len, rs_data_len, 4);
+        encoded[255 + 100] ^= 1;
+        encoded[255 + 101] ^= 1;
+        let wrong = vec![vec![], (200..230).collect()];
+        assert!(rs_decode_erased(&encoded, ecc_len, rs_data_len, data.len(), &wrong, 4).is_ok());
+    }
+
+    #[test]
+    fn test_rs_many_blocks_in_order() {
+        let (ecc_len, rs_data_len) = (32, 223);
+        // Enough blocks to be split across threads on a multi-core machine
+        let data = crate::testutil::pattern(223 * 1000 + 7, 31, 256);
+        let mut encoded = rs_encode(&data, ecc_len, rs_data_len, 4);
+        assert_eq!(encoded.len(), 255 * 1001);
+        // One thread codes the same blocks as several
+        assert_eq!(rs_encode(&data, ecc_len, rs_data_len, 1), encoded);
+        for (block, errors) in [(0, 3), (500, 16), (1000, 1)] {
+            for byte in &mut encoded[block * 255..block * 255 + errors] {
+                *byte ^= 0xff;
+            }
+        }
+        let (decoded, fixed) =
+            rs_decode_counted(&encoded, ecc_len, rs_data_len, data.len(), 4).unwrap();
+        assert_eq!(decoded, data);
+        assert_eq!((fixed[0], fixed[500], fixed[1000]), (3, 16, 1));
+        assert_eq!(fixed.iter().sum::<usize>(), 20);
+        let one = rs_decode_counted(&encoded, ecc_len, rs_data_len, data.len(), 1).unwrap();
+        assert_eq!(one, (decoded, fixed));
+        assert!(matches!(
+            rs_decode(&encoded[..255 * 1000], ecc_len, rs_data_len, data.len(), 4),
+            Err(VstorageError::Ecc(_))
+        ));
+        // A thread cap gives each thread more blocks
+        assert_eq!(blocks_per_thread(1001, 4), 251);
+        assert_eq!(blocks_per_thread(1001, 1), 1001);
     }
 
     #[test]
@@ -118,12 +237,12 @@ mod tests {
         let ecc_len = 32;
         let rs_data_len = 223;
         // Create data that spans 3 blocks
-        let data: Vec<u8> = (0..500).map(|i| (i % 256) as u8).collect();
+        let data = crate::testutil::pattern(500, 1, 256);
 
-        let encoded = rs_encode(&data, ecc_len, rs_data_len);
+        let encoded = rs_encode(&data, ecc_len, rs_data_len, 4);
         assert_eq!(encoded.len(), 255 * 3); // 3 padded blocks
 
-        let decoded = rs_decode(&encoded, ecc_len, rs_data_len, data.len()).unwrap();
+        let decoded = rs_decode(&encoded, ecc_len, rs_data_len, data.len(), 4).unwrap();
         assert_eq!(decoded, data);
     }
 }
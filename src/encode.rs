@@ -1,53 +1,453 @@
-use std::path::Path;
+use std::path::{Path, PathBuf};
 
-use indicatif::{ProgressBar, ProgressStyle};
 use sha2::{Digest, Sha256};
+use zeroize::Zeroizing;
 
-use crate::config::{FrameConfig, PROTOCOL_VERSION};
-use crate::error::Result;
-use crate::{crypto, ecc, frame, header, video};
+use crate::cancel::CancelToken;
+use crate::checkpoint::Checkpoint;
+use crate::compress::Compression;
+use crate::config::{FrameConfig, Intermediate, Workspace};
+use crate::error::{Result, VstorageError};
+use crate::json::Json;
+use crate::memory::{self, FramePlan};
+use crate::mmap::FileData;
+use crate::progress::{ProgressSink, Stage, Tracker};
+use crate::recipient::{self, Recipient};
+use crate::s3::S3Url;
+use crate::signature::SigningKey;
+use crate::{audio, crypto, ecc, frame, header, preflight, video};
 
-/// Run the full encoding pipeline: file → encrypt → frames → PNGs → MP4.
+/// Frames handed to each ffmpeg run; their images are deleted once the
+/// segment is encoded, and each finished segment is a resume point.
+/// [`Workspace::max_temp`] can lower it.
+pub const SEGMENT_FRAMES: usize = 300;
+
+/// Frames per ffmpeg segment for `config` under the `max_temp` cap of its
+/// workspace.
+pub fn segment_frames(config: &FrameConfig) -> Result<usize> {
+    match config.workspace.max_temp {
+        None => Ok(SEGMENT_FRAMES),
+        Some(cap) => frames_within(cap, config),
+    }
+}
+
+/// Frames of `config` whose images fit in `cap` bytes, at most
+/// [`SEGMENT_FRAMES`].
+fn frames_within(cap: u64, config: &FrameConfig) -> Result<usize> {
+    // A raw RGB frame, plus headroom for PNG filter and block overhead
+    let raw = config.width as u64 * config.height as u64 * 3;
+    let frame_bytes = raw + raw / 1024 + 4096;
+    match cap / frame_bytes {
+        0 => Err(VstorageError::Config(format!(
+            "--max-temp {cap} is smaller than one {}x{} frame (~{frame_bytes} bytes)",
+            config.width, config.height
+        ))),
+        n => Ok(n.min(SEGMENT_FRAMES as u64) as usize),
+    }
+}
+
+/// Share of `max_bytes` planned for video data, leaving room for the
+/// variation between the calibration segment and the rest.
+const SIZE_MARGIN: f64 = 0.95;
+
+/// Caps on every output video; exceeding them splits the output into parts.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct SplitLimits {
+    pub max_bytes: Option<u64>,
+    pub max_secs: Option<u64>,
+}
+
+impl SplitLimits {
+    pub fn is_set(&self) -> bool {
+        self.max_bytes.is_some() || self.max_secs.is_some()
+    }
+}
+
+/// Input or output path meaning stdin/stdout.
+pub const STDIO_PATH: &str = "-";
+
+/// The data of `input_path` with its canonical path and mtime, or all of
+/// `stdin` for [`STDIO_PATH`], which has neither and so cannot be resumed.
+/// The file is memory-mapped if `map` is set, and read otherwise.
+///
+/// # Safety
+///
+/// With `map`, the file must stay unchanged while the data is in use (see
+/// [`FileData::open`]).
+unsafe fn read_input(
+    input_path: &Path,
+    resume: bool,
+    map: bool,
+    stdin: &mut dyn std::io::Read,
+) -> Result<(FileData, PathBuf, u64)> {
+    if input_path == Path::new(STDIO_PATH) {
+        if resume {
+            return Err(VstorageError::Config(
+                "--resume needs an input file, not stdin".into(),
+            ));
+        }
+        let mut data = Vec::new();
+        stdin.read_to_end(&mut data)?;
+        return Ok((FileData::from(data), PathBuf::from(STDIO_PATH), 0));
+    }
+    let data = match map {
+        // SAFETY: passed on to the caller
+        true => unsafe { FileData::open(input_path)? },
+        false => FileData::from(std::fs::read(input_path)?),
+    };
+    let input_path = std::fs::canonicalize(input_path)?;
+    let input_mtime = std::fs::metadata(&input_path)?
+        .modified()?
+        .duration_since(std::time::UNIX_EPOCH)
+        .map_or(0, |d| d.as_secs());
+    Ok((data, input_path, input_mtime))
+}
+
+/// Run the full encoding pipeline: file → encrypted frames → PNGs → MP4.
+///
+/// The input file is read into memory (see [`encode_mapped`] to map it
+/// instead); an `input_path` of `-` reads the data from stdin.
+///
+/// Each frame's chunk is encrypted on its own (see [`crypto::FrameCipher`]),
+/// so frames can later be decrypted independently.
+///
+/// Intermediates live in `<output>.vstorage-work/` together with a
+/// checkpoint; after an interruption, `resume` continues from the last
+/// written frame and the ffmpeg segments already produced.
+///
+/// With `recipients`, the frames are encrypted with a random file key
+/// instead, wrapped for each public key in a key frame after the data frames
+/// (see [`crate::recipient`]); only their private keys can decode it.
+///
+/// If `audio_data` is given, that file is additionally stored in the audio
+/// track as an FSK side channel (encrypted with the same password, if any).
+/// `padding` (from [`crate::hidden::padding`], or empty) fills padding
+/// frames after the file, and `sign` adds a signature frame by that key (see
+/// [`crate::signature`]).
+///
+/// When `limits` caps the size or duration of a video, the output is split
+/// into `<stem>.part001.<ext>`, `<stem>.part002.<ext>`, ... (see
+/// [`part_path`]); the audio track goes into the first part.
+///
+/// `progress` receives an event per frame and at the start and end of each
+/// stage. Cancelling `cancel` stops the frame loop and kills a running
+/// ffmpeg; the half-written segment or video is removed, while the
+/// checkpoint and finished segments stay for `resume`.
+#[allow(clippy::too_many_arguments)]
 pub fn encode(
     input_path: &Path,
     output_path: &Path,
     password: Option<&str>,
+    recipients: &[Recipient],
     config: &FrameConfig,
-) -> Result<()> {
+    audio_data: Option<&Path>,
+    padding: &[u8],
+    sign: Option<&SigningKey>,
+    limits: &SplitLimits,
+    resume: bool,
+    progress: &dyn ProgressSink,
+    cancel: &CancelToken,
+) -> Result<EncodeSummary> {
+    // SAFETY: nothing is mapped with `map` unset
+    unsafe {
+        encode_input(
+            input_path,
+            output_path,
+            password,
+            recipients,
+            config,
+            audio_data,
+            padding,
+            sign,
+            limits,
+            resume,
+            false,
+            progress,
+            cancel,
+        )
+    }
+}
+
+/// [`encode`], but with the input file memory-mapped instead of read, so the
+/// page cache holds it and the encode starts without reading it up front.
+///
+/// # Safety
+///
+/// The input file must not be truncated or written, by this process or any
+/// other, until this returns (see [`FileData::open`]): a shorter file faults
+/// the process, and a changed one is encoded with the changes.
+#[allow(clippy::too_many_arguments)]
+pub unsafe fn encode_mapped(
+    input_path: &Path,
+    output_path: &Path,
+    password: Option<&str>,
+    recipients: &[Recipient],
+    config: &FrameConfig,
+    audio_data: Option<&Path>,
+    padding: &[u8],
+    sign: Option<&SigningKey>,
+    limits: &SplitLimits,
+    resume: bool,
+    progress: &dyn ProgressSink,
+    cancel: &CancelToken,
+) -> Result<EncodeSummary> {
+    // SAFETY: passed on to the caller
+    unsafe {
+        encode_input(
+            input_path,
+            output_path,
+            password,
+            recipients,
+            config,
+            audio_data,
+            padding,
+            sign,
+            limits,
+            resume,
+            true,
+            progress,
+            cancel,
+        )
+    }
+}
+
+/// [`encode`] of an input file mapped if `map` is set.
+///
+/// # Safety
+///
+/// See [`encode_mapped`] if `map` is set.
+#[allow(clippy::too_many_arguments)]
+unsafe fn encode_input(
+    input_path: &Path,
+    output_path: &Path,
+    password: Option<&str>,
+    recipients: &[Recipient],
+    config: &FrameConfig,
+    audio_data: Option<&Path>,
+    padding: &[u8],
+    sign: Option<&SigningKey>,
+    limits: &SplitLimits,
+    resume: bool,
+    map: bool,
+    progress: &dyn ProgressSink,
+    cancel: &CancelToken,
+) -> Result<EncodeSummary> {
     video::check_ffmpeg()?;
 
-    // 1. Read file
-    let data = std::fs::read(input_path)?;
+    // 1. Read file (or all of stdin for "-")
+    // SAFETY: passed on to the caller
+    let (data, input_path, input_mtime) =
+        unsafe { read_input(input_path, resume, map, &mut std::io::stdin().lock())? };
+    let audio = match audio_data {
+        Some(path) => {
+            tracing::info!("Audio side channel from {}", path.display());
+            Some(Zeroizing::new(std::fs::read(path)?))
+        }
+        None => None,
+    };
+    let input = Input {
+        data,
+        path: input_path,
+        mtime: input_mtime,
+        audio,
+        signature: None,
+    };
+    encode_data(
+        input,
+        output_path,
+        password,
+        recipients,
+        config,
+        padding,
+        sign,
+        limits,
+        resume,
+        progress,
+        cancel,
+    )
+}
+
+/// The file an [`encode_data`] stores, already read.
+pub(crate) struct Input {
+    pub data: FileData,
+    /// Recorded in the checkpoint: the canonical path, or [`STDIO_PATH`]
+    pub path: PathBuf,
+    pub mtime: u64,
+    /// Contents of the audio side channel
+    pub audio: Option<Zeroizing<Vec<u8>>>,
+    /// Contents of a signature frame to keep as they are, for want of the
+    /// key that made them (see [`FramePlan::with_signature_frame`])
+    pub signature: Option<Vec<u8>>,
+}
+
+/// [`encode`] from step 2 on, of a file held in `input`.
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn encode_data(
+    input: Input,
+    output_path: &Path,
+    password: Option<&str>,
+    recipients: &[Recipient],
+    config: &FrameConfig,
+    padding: &[u8],
+    sign: Option<&SigningKey>,
+    limits: &SplitLimits,
+    resume: bool,
+    progress: &dyn ProgressSink,
+    cancel: &CancelToken,
+) -> Result<EncodeSummary> {
+    if password.is_some() && !recipients.is_empty() {
+        return Err(VstorageError::Config(
+            "give a password or recipients, not both".into(),
+        ));
+    }
+    if config.seed.is_some() {
+        memory::check_seeded(recipients, padding)?;
+    }
+    let Input {
+        data,
+        path: input_path,
+        mtime: input_mtime,
+        audio,
+        signature,
+    } = input;
     let file_size = data.len() as u64;
-    eprintln!("Read {} bytes from {}", data.len(), input_path.display());
-
-    // 2. Encrypt (or pass through)
-    let (payload, nonce, salt) = if let Some(pw) = password {
-        let pb = ProgressBar::new_spinner();
-        pb.set_style(
-            ProgressStyle::default_spinner()
-                .template("{spinner:.cyan} {msg}")
-                .unwrap(),
+    let sha256: [u8; 32] = Sha256::digest(&data[..]).into();
+    tracing::info!("Read {} bytes from {}", data.len(), input_path.display());
+    let hardware = config.hardware_token();
+    let data = match config.compression {
+        Compression::None => data,
+        compression => {
+            preflight::check_memory("compressing the file", file_size)?;
+            let packed = compression.compress(&data)?;
+            tracing::info!("Compressed to {} bytes ({compression})", packed.len());
+            FileData::from(packed)
+        }
+    };
+
+    // 2. Work dir, resuming from its checkpoint if asked to
+    let work_dir = work_dir_for(output_path, &config.workspace);
+    let checkpoint_path = work_dir.join("checkpoint");
+    let previous = if resume && checkpoint_path.exists() {
+        let cp = Checkpoint::load(&checkpoint_path)?;
+        if !cp.matches(&input_path, file_size, input_mtime, config, limits) {
+            return Err(VstorageError::Config(
+                "checkpoint was written for a different input or settings — \
+                 rerun without --resume to start over"
+                    .into(),
+            ));
+        }
+        Some(cp)
+    } else {
+        if resume {
+            tracing::info!("No checkpoint in {} — starting fresh", work_dir.display());
+        }
+        if work_dir.exists() {
+            std::fs::remove_dir_all(&work_dir)?;
+        }
+        None
+    };
+    let seeded = config
+        .seed
+        .as_ref()
+        .map(|seed| memory::seeded_parameters(seed, &data, config, sign));
+    if let (Some(cp), Some((_, _, set_id))) = (&previous, seeded) {
+        if cp.set_id != set_id {
+            return Err(VstorageError::Config(
+                "checkpoint was written with a different seed — rerun without --resume to \
+             start over"
+                    .into(),
+            ));
+        }
+    }
+    let frames_dir = work_dir.join("frames");
+    std::fs::create_dir_all(&frames_dir)?;
+
+    // 3. Derive the key (or pass through); a file key wrapped for
+    //    recipients is not kept in the checkpoint, so that encode cannot
+    //    resume
+    if previous
+        .as_ref()
+        .is_some_and(|cp| !recipients.is_empty() || is_public_key(cp))
+    {
+        return Err(VstorageError::Config(
+            "an encode to recipients cannot be resumed — rerun without --resume".into(),
+        ));
+    }
+    if previous.is_some() && !padding.is_empty() {
+        return Err(VstorageError::Config(
+            "an encode with padding cannot be resumed — rerun without --resume".into(),
+        ));
+    }
+    let (cipher, nonce, salt, keys) = if !recipients.is_empty() {
+        let (cipher, nonce, keys) = recipient::seal(recipients)?;
+        tracing::info!(
+            "Encrypting frames with AES-256-GCM to {} recipient(s)",
+            recipients.len()
+        );
+        (Some(cipher), nonce, [0u8; 16], keys)
+    } else if let Some(pw) = password {
+        let pb = Tracker::spinner(
+            progress,
+            Stage::DeriveKey,
+            format!("Deriving key ({})...", config.kdf),
         );
-        pb.set_message("Encrypting (Argon2 + AES-256-GCM)...");
-        pb.enable_steady_tick(std::time::Duration::from_millis(80));
-        let (ct, n, s) = crypto::encrypt(&data, &pw)?;
-        pb.finish_with_message(format!("Encrypted: {} bytes", ct.len()));
-        (ct, n, s)
+        let (cipher, n, s) = match &previous {
+            Some(cp) if cp.salt == [0u8; 16] => {
+                return Err(VstorageError::Crypto(
+                    "the interrupted encode was not encrypted — resume without -p".into(),
+                ));
+            }
+            Some(cp) => {
+                let cipher = crypto::FrameCipher::new(
+                    pw,
+                    config.kdf,
+                    config.token,
+                    hardware,
+                    &cp.salt,
+                    cp.nonce,
+                )?;
+                if cipher.key_check()? != cp.key_check {
+                    return Err(VstorageError::Crypto(
+                        "password differs from the one used by the interrupted encode".into(),
+                    ));
+                }
+                (cipher, cp.nonce, cp.salt)
+            }
+            None => match seeded {
+                Some((salt, nonce, _)) => (
+                    crypto::FrameCipher::new(pw, config.kdf, config.token, hardware, &salt, nonce)?,
+                    nonce,
+                    salt,
+                ),
+                None => crypto::FrameCipher::generate(pw, config.kdf, config.token, hardware)?,
+            },
+        };
+        pb.finish_with_message("Encrypting frames with AES-256-GCM");
+        (Some(cipher), n, s, Vec::new())
     } else {
-        eprintln!("No password — skipping encryption");
-        (data, [0u8; 12], [0u8; 16])
+        if previous.as_ref().is_some_and(|cp| cp.salt != [0u8; 16]) {
+            return Err(VstorageError::PasswordRequired);
+        }
+        tracing::info!("No password — skipping encryption");
+        (None, [0u8; 12], [0u8; 16], Vec::new())
     };
 
-    // 3. Calculate frame count
+    // 4. Calculate frame count
     let max_raw = config.max_raw_per_frame();
-    if max_raw == 0 {
-        return Err(crate::error::VstorageError::Config(
-            "frame capacity is zero — check block_size/levels/ecc settings".into(),
-        ));
+    let mut plan = FramePlan::new(config, &data, cipher, nonce, salt, keys)?;
+    if let Some(key) = sign {
+        plan = plan.with_signature(key);
+    } else if let Some(frame) = signature {
+        plan = plan.with_signature_frame(frame);
+    }
+    let mut plan = plan.with_padding(padding);
+    if let Some(cp) = &previous {
+        plan.set_id = cp.set_id;
+    } else if let Some((_, _, set_id)) = seeded {
+        plan.set_id = set_id;
     }
-    let num_frames = (payload.len() + max_raw - 1) / max_raw;
-    eprintln!(
+    let (per_frame, num_frames) = (plan.per_frame, plan.num_frames);
+    tracing::info!(
         "Encoding into {} frames ({} bytes/frame, RS({},{}), ecc={})",
         num_frames,
         max_raw,
@@ -56,66 +456,625 @@ pub fn encode(
         config.ecc_len
     );
 
-    // 4. Create temp dir for PNGs
-    let temp_dir = tempfile::tempdir()?;
-
-    // 5. Encode each frame
-    let pb = ProgressBar::new(num_frames as u64);
-    pb.set_style(
-        ProgressStyle::default_bar()
-            .template("{spinner:.green} [{bar:40.cyan/blue}] {pos}/{len} frames ({eta} remaining)")
-            .unwrap()
-            .progress_chars("=>-"),
-    );
-
-    for i in 0..num_frames {
-        let start = i * max_raw;
-        let end = std::cmp::min(start + max_raw, payload.len());
-        let frame_data = &payload[start..end];
-
-        // RS encode (pads last chunk to full block)
-        let rs_encoded = ecc::rs_encode(frame_data, config.ecc_len as usize, config.rs_data_len());
-
-        // SHA-256 of the RS-encoded data
-        let data_hash: [u8; 32] = Sha256::digest(&rs_encoded).into();
-
-        // Build header
-        let hdr = header::FrameHeader {
-            version: PROTOCOL_VERSION,
-            frame_number: i as u32,
-            total_frames: num_frames as u32,
+    let mut cp = match previous {
+        Some(mut cp) => {
+            // Frames of the pending segment in another format would be
+            // missed by ffmpeg, so render them again
+            let intermediate = config.workspace.intermediate.to_string();
+            if cp.intermediate != intermediate {
+                cp.frames_done = cp.segments_done * cp.segment_frames;
+                cp.intermediate = intermediate;
+            }
+            tracing::info!(
+                "Resuming at frame {} ({} of {} segments done)",
+                cp.frames_done + 1,
+                cp.segments_done,
+                num_frames.div_ceil(cp.segment_frames)
+            );
+            cp
+        }
+        None => Checkpoint {
+            input: input_path.clone(),
+            input_size: file_size,
+            input_mtime,
             block_size: config.block_size,
             levels: config.levels,
-            file_size,
-            data_length: frame_data.len() as u32,
             ecc_len: config.ecc_len,
-            rs_data_len: config.rs_data_len() as u16,
+            fps: config.fps,
+            crf: config.crf,
+            codec: config.codec.to_string(),
+            target_bitrate: config.target_bitrate,
+            yuv420p: config.yuv420p,
+            kdf: config.kdf.to_string(),
+            compression: config.compression.to_string(),
+            layout: config.layout.to_string(),
+            crc_map: config.crc_map,
+            bottom_header: config.bottom_header,
+            banner: config.banner,
+            barcode: config.barcode,
+            pilots: config.pilots,
+            bin_centers: config.bin_centers,
+            header_rows: config.header_rows,
+            header_copies: config.header_copies,
+            intro: config.intro,
+            metadata_levels: config.metadata_levels,
+            channel_levels: config.channel_levels(),
+            max_output_size: limits.max_bytes,
+            max_duration: limits.max_secs,
+            intermediate: config.workspace.intermediate.to_string(),
+            segment_frames: segment_frames(config)?,
             nonce,
             salt,
-            data_sha256: data_hash,
+            key_check: match &plan.cipher {
+                Some(c) => c.key_check()?,
+                None => [0u8; 16],
+            },
+            set_id: plan.set_id,
+            total_frames: num_frames,
+            frames_done: 0,
+            segments_done: 0,
+            frames_per_part: 0,
+        },
+    };
+    cp.save(&checkpoint_path)?;
+    // A resumed encode keeps the segment length its finished segments have
+    let segment_frames = cp.segment_frames;
+    let num_segments = num_frames.div_ceil(segment_frames);
+
+    // 5. Optional audio side channel
+    let (wav_path, wav_samples) = match audio {
+        Some(side) => {
+            let samples =
+                audio::encode_side_channel(&side, password, config.kdf, config.token, hardware)?;
+            let wav = work_dir.join("audio.wav");
+            audio::write_wav(&wav, &samples)?;
+            tracing::info!(
+                "Audio side channel: {} bytes ({:.1}s)",
+                side.len(),
+                samples.len() as f64 / audio::SAMPLE_RATE as f64
+            );
+            (Some(wav), Some(samples.len()))
+        }
+        None => (None, None),
+    };
+
+    // 6. Decide how many frames go into each output video
+    let mut measured = None;
+    if cp.frames_per_part == 0 {
+        let bytes_per_frame = match limits.max_bytes {
+            Some(_) => calibrate(
+                &work_dir,
+                config,
+                segment_frames.min(num_frames),
+                |i| plan.render(i, 0, 0),
+                progress,
+                cancel,
+            )?,
+            None => 0.0,
         };
+        measured = (bytes_per_frame > 0.0).then_some(bytes_per_frame as u64);
+        let reserved = wav_samples.map_or(0, |n| {
+            n as u64 * AUDIO_BITS_PER_SEC / 8 / audio::SAMPLE_RATE as u64
+        });
+        cp.frames_per_part = frames_per_part(
+            limits,
+            config.fps,
+            bytes_per_frame,
+            reserved,
+            segment_frames,
+        )?
+        .min(num_segments * segment_frames);
+        cp.save(&checkpoint_path)?;
+    }
+    let frames_per_part = cp.frames_per_part;
+    let total_parts = num_frames.div_ceil(frames_per_part);
+    if total_parts > u16::MAX as usize {
+        return Err(VstorageError::Config(format!(
+            "{total_parts} output parts needed — raise --max-output-size/--max-duration"
+        )));
+    }
+    if total_parts > 1 {
+        tracing::info!(
+            "Splitting into {total_parts} videos of up to {frames_per_part} frames ({:.0}s)",
+            frames_per_part as f64 / config.fps as f64
+        );
+    }
+
+    // 7. Check that the rest of the encode fits on disk, then encode each
+    //    frame, handing every segment_frames images to ffmpeg
+    let remaining = num_frames - cp.frames_done;
+    check_space(
+        output_path,
+        &work_dir,
+        frame_image_bytes(&plan, &work_dir, config.workspace.intermediate)?,
+        measured.unwrap_or(0).max(max_raw as u64),
+        remaining,
+        num_frames,
+        match config.workspace.keep_frames {
+            true => remaining,
+            false => remaining.min(segment_frames),
+        },
+    )?;
+    let mut pb = Tracker::frames(progress, Stage::EncodeFrames, num_frames as u64);
+    pb.set_position(cp.frames_done as u64);
+
+    let mut img = image::RgbImage::new(0, 0);
+    for i in cp.frames_done..num_frames {
+        cancel.check()?;
+        plan.render_into(i, i / frames_per_part, total_parts, &mut img)?;
 
-        let header_bytes = header::encode_header_triple(&hdr);
-        let img = frame::encode_frame_to_image(&header_bytes, &rs_encoded, config);
+        video::save_frame(
+            &img,
+            &video::frame_path(&frames_dir, i + 1, config.workspace.intermediate),
+        )?;
+        cp.frames_done = i + 1;
 
-        let png_path = temp_dir.path().join(format!("frame_{:06}.png", i + 1));
-        img.save(&png_path)?;
+        if cp.frames_done % segment_frames == 0 || cp.frames_done == num_frames {
+            pb.set_message(format!(
+                "ffmpeg segment {}/{num_segments}",
+                cp.segments_done + 1
+            ));
+            let segment = cp.segments_done;
+            if let Err(e) = encode_segment(
+                &work_dir,
+                segment,
+                segment_frames,
+                num_frames,
+                config,
+                cancel,
+            ) {
+                let _ = std::fs::remove_file(segment_path(&work_dir, cp.segments_done));
+                return Err(e);
+            }
+            cp.segments_done += 1;
+            pb.set_message("");
+        }
+        cp.save(&checkpoint_path)?;
 
-        pb.inc(1);
+        pb.inc(
+            data.len()
+                .min((i + 1) * per_frame)
+                .saturating_sub(i * per_frame) as u64,
+        );
     }
     pb.finish_with_message(format!("{num_frames} frames encoded"));
 
-    // 6. FFmpeg: PNGs → MP4
-    let pb = ProgressBar::new_spinner();
-    pb.set_style(
-        ProgressStyle::default_spinner()
-            .template("{spinner:.cyan} {msg}")
-            .unwrap(),
-    );
-    pb.set_message(format!("FFmpeg: producing {}...", output_path.display()));
-    pb.enable_steady_tick(std::time::Duration::from_millis(80));
-    video::pngs_to_mp4(temp_dir.path(), output_path, config)?;
-    pb.finish_with_message("Done.");
+    // 8. FFmpeg: segments → MP4 (one per part)
+    let segments_per_part = frames_per_part / segment_frames;
+    let mut outputs = Vec::with_capacity(total_parts);
+    for part in 0..total_parts {
+        let out = if total_parts == 1 {
+            output_path.to_path_buf()
+        } else {
+            part_path(output_path, part)
+        };
+        let pb = Tracker::spinner(
+            progress,
+            Stage::Mux,
+            format!("FFmpeg: producing {}...", out.display()),
+        );
+        let first_segment = part * segments_per_part;
+        let segments: Vec<PathBuf> = (first_segment
+            ..num_segments.min(first_segment + segments_per_part))
+            .map(|s| segment_path(&work_dir, s))
+            .collect();
+        let part_frames = frames_per_part.min(num_frames - part * frames_per_part);
+        if let Err(e) = video::concat_segments(
+            &segments,
+            &out,
+            config,
+            part_frames,
+            if part == 0 { wav_path.as_deref() } else { None },
+            cancel,
+        ) {
+            let _ = std::fs::remove_file(&out);
+            return Err(e);
+        }
+        pb.finish_with_message(format!("Wrote {}", out.display()));
+
+        // 4:2:0 output: make sure the chosen parameters survive the codec
+        if config.yuv420p {
+            self_check(&out, &work_dir, config, part_frames, cancel)?;
+            tracing::info!("yuv420p self-check passed");
+        }
+
+        if let (Some(max), Ok(meta)) = (limits.max_bytes, std::fs::metadata(&out)) {
+            let size = meta.len();
+            if size > max {
+                tracing::warn!(
+                    "{} is {size} bytes, over --max-output-size {max}",
+                    out.display()
+                );
+            }
+        }
+        outputs.push(out);
+    }
+
+    // 9. Finished: the checkpoint and intermediates are no longer needed
+    video::remove_work_dir(&work_dir, &config.workspace)?;
+
+    Ok(EncodeSummary {
+        config: config.clone(),
+        file_size,
+        sha256,
+        frames: num_frames,
+        encrypted: plan.cipher.is_some(),
+        outputs,
+    })
+}
+
+/// Whether `cp` was written by an encode to recipients.
+fn is_public_key(cp: &Checkpoint) -> bool {
+    cp.nonce != [0u8; 12] && cp.salt == [0u8; 16]
+}
+
+/// What [`encode`] produced.
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct EncodeSummary {
+    pub config: FrameConfig,
+    pub file_size: u64,
+    /// SHA-256 of the input file
+    pub sha256: [u8; 32],
+    pub frames: usize,
+    pub encrypted: bool,
+    /// Output videos, in part order
+    pub outputs: Vec<PathBuf>,
+}
+
+impl EncodeSummary {
+    pub fn to_json(&self) -> Json {
+        let c = &self.config;
+        Json::object([
+            ("command", "encode".into()),
+            (
+                "settings",
+                Json::object([
+                    ("block_size", c.block_size.into()),
+                    ("levels", c.levels.into()),
+                    ("ecc", c.ecc_len.into()),
+                    ("fps", c.fps.into()),
+                    ("crf", c.crf.into()),
+                    ("codec", c.codec.to_string().into()),
+                    ("target_bitrate", c.target_bitrate.into()),
+                    ("yuv420p", c.yuv420p.into()),
+                ]),
+            ),
+            ("file_size", self.file_size.into()),
+            ("sha256", Json::hex(&self.sha256)),
+            ("frames", self.frames.into()),
+            ("encrypted", self.encrypted.into()),
+            (
+                "outputs",
+                self.outputs
+                    .iter()
+                    .map(|p| p.display().to_string())
+                    .collect::<Vec<_>>()
+                    .into(),
+            ),
+        ])
+    }
+}
+
+/// Bitrate of the AAC track written by [`video::concat_segments`].
+const AUDIO_BITS_PER_SEC: u64 = 256_000;
+
+/// Path of output part `part` (0-based): `out.mp4` → `out.part001.mp4`.
+pub fn part_path(output_path: &Path, part: usize) -> PathBuf {
+    let stem = output_path
+        .file_stem()
+        .map_or(String::new(), |s| s.to_string_lossy().into_owned());
+    let name = match output_path.extension() {
+        Some(ext) => format!("{stem}.part{:03}.{}", part + 1, ext.to_string_lossy()),
+        None => format!("{stem}.part{:03}", part + 1),
+    };
+    output_path.with_file_name(name)
+}
+
+/// Frames per output video under `limits`, a whole number of segments of
+/// `segment_frames`.
+///
+/// `bytes_per_frame` is the measured video size per frame (only needed for
+/// `max_bytes`) and `reserved` the bytes kept free for the audio track.
+pub fn frames_per_part(
+    limits: &SplitLimits,
+    fps: u32,
+    bytes_per_frame: f64,
+    reserved: u64,
+    segment_frames: usize,
+) -> Result<usize> {
+    let mut frames = usize::MAX;
+    if let Some(secs) = limits.max_secs {
+        frames = frames.min((secs * fps as u64) as usize);
+    }
+    if let Some(max) = limits.max_bytes {
+        let budget = (max as f64 * SIZE_MARGIN - reserved as f64).max(0.0);
+        frames = frames.min((budget / bytes_per_frame.max(1.0)) as usize);
+    }
+    if frames == usize::MAX {
+        return Ok(frames);
+    }
+    let frames = frames / segment_frames * segment_frames;
+    if frames == 0 {
+        return Err(VstorageError::Config(format!(
+            "--max-output-size/--max-duration too small: each video must hold at least \
+             {segment_frames} frames ({:.0}s{})",
+            segment_frames as f64 / fps as f64,
+            if limits.max_bytes.is_some() {
+                format!(
+                    ", ~{} bytes",
+                    (bytes_per_frame * segment_frames as f64) as u64
+                )
+            } else {
+                String::new()
+            }
+        )));
+    }
+    Ok(frames)
+}
+
+/// Encode `count` frames from `render` once to measure the video bytes per
+/// frame at the chosen settings.
+pub(crate) fn calibrate(
+    work_dir: &Path,
+    config: &FrameConfig,
+    count: usize,
+    render: impl Fn(usize) -> Result<image::RgbImage>,
+    progress: &dyn ProgressSink,
+    cancel: &CancelToken,
+) -> Result<f64> {
+    let dir = work_dir.join("calibration");
+    std::fs::create_dir_all(&dir)?;
+    tracing::info!("Measuring output size over {count} frames...");
+    let mut pb = Tracker::frames(progress, Stage::Calibrate, count as u64);
+    for i in 0..count {
+        let path = video::frame_path(&dir, i + 1, config.workspace.intermediate);
+        video::save_frame(&render(i)?, &path)?;
+        pb.inc(0);
+    }
+    let segment = dir.join("calibration.mp4");
+    video::frames_to_segment(&dir, 1, count, &segment, config, cancel)?;
+    pb.finish_and_clear();
+    let size = std::fs::metadata(&segment)?.len();
+    std::fs::remove_dir_all(&dir)?;
+    Ok(size as f64 / count as f64)
+}
+
+/// Size of the image of frame 1 as written to `work_dir` in `format`, as an
+/// estimate for every frame.
+fn frame_image_bytes(plan: &FramePlan, work_dir: &Path, format: Intermediate) -> Result<u64> {
+    let path = work_dir.join(format!("preflight.{}", format.extension()));
+    video::save_frame(&plan.render(0, 0, 0)?, &path)?;
+    let bytes = std::fs::metadata(&path)?.len();
+    std::fs::remove_file(&path)?;
+    Ok(bytes)
+}
+
+/// Fail fast unless the work dir and output location have room for the
+/// `remaining` of `num_frames` frames: `waiting` images of `image_bytes`
+/// each on disk at once, and the segments and output video at
+/// `video_bytes` per frame, a lower bound unless measured.
+fn check_space(
+    output_path: &Path,
+    work_dir: &Path,
+    image_bytes: u64,
+    video_bytes: u64,
+    remaining: usize,
+    num_frames: usize,
+    waiting: usize,
+) -> Result<()> {
+    let mut needs = vec![
+        preflight::Need {
+            what: "frame images",
+            dir: work_dir.to_path_buf(),
+            bytes: waiting as u64 * image_bytes,
+        },
+        preflight::Need {
+            what: "video segments",
+            dir: work_dir.to_path_buf(),
+            bytes: remaining as u64 * video_bytes,
+        },
+    ];
+    if S3Url::parse(output_path).is_none() {
+        needs.push(preflight::Need {
+            what: "output video",
+            dir: output_path.parent().unwrap_or(Path::new(".")).to_path_buf(),
+            bytes: num_frames as u64 * video_bytes,
+        });
+    }
+    preflight::check_space(&needs)
+}
+
+/// Directory holding intermediates and the checkpoint for `output_path`:
+/// in the workspace's directory if it has one, otherwise next to the output
+/// (for an `s3://` output, in the current directory).
+pub fn work_dir_for(output_path: &Path, workspace: &Workspace) -> PathBuf {
+    let mut name = match S3Url::parse(output_path) {
+        Some(url) => url.file_name().into(),
+        None => output_path.as_os_str().to_owned(),
+    };
+    name.push(".vstorage-work");
+    match &workspace.dir {
+        Some(root) => root.join(Path::new(&name).file_name().unwrap_or(&name)),
+        None => PathBuf::from(name),
+    }
+}
+
+pub(crate) fn segment_path(work_dir: &Path, segment: usize) -> PathBuf {
+    work_dir.join(format!("segment_{segment:05}.mp4"))
+}
+
+/// Run ffmpeg over the images of segment `segment` (of `segment_frames`
+/// frames each), then delete them unless frames are kept.
+pub(crate) fn encode_segment(
+    work_dir: &Path,
+    segment: usize,
+    segment_frames: usize,
+    num_frames: usize,
+    config: &FrameConfig,
+    cancel: &CancelToken,
+) -> Result<()> {
+    let frames_dir = work_dir.join("frames");
+    let first = segment * segment_frames;
+    let count = segment_frames.min(num_frames - first);
+    video::frames_to_segment(
+        &frames_dir,
+        first + 1,
+        count,
+        &segment_path(work_dir, segment),
+        config,
+        cancel,
+    )?;
+    if !config.workspace.keep_frames {
+        for i in first..first + count {
+            let path = video::frame_path(&frames_dir, i + 1, config.workspace.intermediate);
+            std::fs::remove_file(path)?;
+        }
+    }
+    Ok(())
+}
+
+/// Decode the first and last frame of the produced video and check that the
+/// RS-corrected data reproduces the hash recorded in each frame header.
+fn self_check(
+    video_path: &Path,
+    work_dir: &Path,
+    config: &FrameConfig,
+    num_frames: usize,
+    cancel: &CancelToken,
+) -> Result<()> {
+    // The first data frame, after the intro frame if there is one
+    let mut indices = vec![usize::from(config.intro), num_frames - 1];
+    indices.dedup();
+
+    for index in indices {
+        let png = work_dir.join(format!("check_{index:06}.png"));
+        video::extract_frame(video_path, index, &png, cancel)?;
+        let img = video::load_frame(&png)?;
+
+        let fail = |reason: String| {
+            VstorageError::Config(format!(
+                "yuv420p self-check failed on frame {}: {reason} — \
+                 try a larger --block-size, fewer --levels, or more --ecc",
+                index + 1
+            ))
+        };
+
+        let header_bytes = frame::decode_header_area(&img, config.block_size, config.levels);
+        let hdr = header::decode_header_triple(&header_bytes).map_err(|e| fail(e.to_string()))?;
+        let data_bytes = frame::decode_data_area(&img, config, hdr.area_levels(), hdr.frame_number);
+        let rs_decoded = ecc::rs_decode(
+            &data_bytes,
+            config.ecc_len as usize,
+            config.rs_data_len(),
+            hdr.data_length as usize,
+            config.worker_threads(),
+        )
+        .map_err(|e| fail(e.to_string()))?;
+
+        let reencoded = ecc::rs_encode(
+            &rs_decoded,
+            config.ecc_len as usize,
+            config.rs_data_len(),
+            config.worker_threads(),
+        );
+        let hash: [u8; 32] = Sha256::digest(&reencoded).into();
+        if hash != hdr.data_sha256 {
+            return Err(fail("data hash mismatch".into()));
+        }
+    }
 
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_path() {
+        assert_eq!(
+            part_path(Path::new("/videos/out.mp4"), 0),
+            PathBuf::from("/videos/out.part001.mp4")
+        );
+        assert_eq!(
+            part_path(Path::new("out"), 11),
+            PathBuf::from("out.part012")
+        );
+        assert_eq!(
+            part_path(Path::new("s3://bucket/videos/out.mp4"), 1),
+            PathBuf::from("s3://bucket/videos/out.part002.mp4")
+        );
+        assert_eq!(
+            work_dir_for(
+                Path::new("s3://bucket/videos/out.mp4"),
+                &Workspace::default()
+            ),
+            PathBuf::from("out.mp4.vstorage-work")
+        );
+    }
+
+    #[test]
+    fn test_stdio_streaming() {
+        let stdio = Path::new(STDIO_PATH);
+        let (data, path, mtime) =
+            unsafe { read_input(stdio, false, false, &mut &b"piped"[..]) }.unwrap();
+        assert_eq!((&*data, path.as_path(), mtime), (&b"piped"[..], stdio, 0));
+        let resumed = unsafe { read_input(stdio, true, false, &mut &b"piped"[..]) };
+        assert!(matches!(resumed, Err(VstorageError::Config(_))));
+
+        let mut stdout = Vec::new();
+        crate::decode::write_output_to(stdio, b"decoded", &mut stdout).unwrap();
+        assert_eq!(stdout, b"decoded");
+        assert!(!stdio.exists());
+        let dir = tempfile::tempdir().unwrap();
+        let file = dir.path().join("out.bin");
+        crate::decode::write_output_to(&file, b"decoded", &mut stdout).unwrap();
+        assert_eq!(std::fs::read(&file).unwrap(), b"decoded");
+        assert_eq!(stdout, b"decoded");
+    }
+
+    #[test]
+    fn test_segment_frames_under_cap() {
+        let mut config = FrameConfig::new(2, 4, 32, 30, 18).unwrap();
+        assert_eq!(segment_frames(&config).unwrap(), SEGMENT_FRAMES);
+        let frame = config.width as u64 * config.height as u64 * 3;
+        config.workspace.max_temp = Some(frame * 50);
+        assert_eq!(segment_frames(&config).unwrap(), 49);
+        assert_eq!(frames_within(u64::MAX, &config).unwrap(), SEGMENT_FRAMES);
+        assert!(frames_within(frame / 2, &config).is_err());
+    }
+
+    #[test]
+    fn test_frames_per_part() {
+        let none = SplitLimits::default();
+        assert_eq!(frames_per_part(&none, 30, 0.0, 0, 300).unwrap(), usize::MAX);
+
+        // 15 minutes at 30 fps
+        let duration = SplitLimits {
+            max_bytes: None,
+            max_secs: Some(900),
+        };
+        assert_eq!(frames_per_part(&duration, 30, 0.0, 0, 300).unwrap(), 27_000);
+
+        // 1 MB per frame under a 2 GB cap: 1900 frames, rounded down to segments
+        let size = SplitLimits {
+            max_bytes: Some(2_000_000_000),
+            max_secs: Some(900),
+        };
+        assert_eq!(
+            frames_per_part(&size, 30, 1_000_000.0, 0, 300).unwrap(),
+            1_800
+        );
+        assert_eq!(
+            frames_per_part(&size, 30, 1_000_000.0, 0, 40).unwrap(),
+            1_880
+        );
+
+        let tiny = SplitLimits {
+            max_bytes: None,
+            max_secs: Some(5),
+        };
+        assert!(frames_per_part(&tiny, 30, 0.0, 0, 300).is_err());
+    }
+}
@@ -1,5 +1,9 @@
+use std::fmt;
+
 use thiserror::Error;
 
+use crate::codec::HeaderError;
+
 #[derive(Error, Debug)]
 pub enum VstorageError {
     #[error("IO error: {0}")]
@@ -8,20 +12,219 @@ pub enum VstorageError {
     #[error("Encryption error: {0}")]
     Crypto(String),
 
+    #[error("Encryption error: the data is encrypted — provide -p <PASSWORD>")]
+    PasswordRequired,
+
+    /// No frame authenticated under the given password
+    #[error("Encryption error: wrong password")]
+    WrongPassword,
+
+    #[error("Encryption error: the data is encrypted to public keys — provide --identity <FILE>")]
+    IdentityRequired,
+
+    /// The key needs a hardware token's response and none is installed
+    #[error(
+        "Encryption error: the data needs a hardware token — provide --token-command <COMMAND>"
+    )]
+    TokenRequired,
+
+    /// No key of the payload is wrapped for the given identity
+    #[error("Encryption error: the data is not encrypted to this identity")]
+    WrongIdentity,
+
+    /// One frame's tag did not verify (while others did, or alone when
+    /// decrypting a single frame)
+    #[error("Encryption error: frame {}: authentication failed (corrupted data or wrong password)", .frame + 1)]
+    AuthFailed { frame: usize },
+
     #[error("Reed-Solomon error: {0}")]
     Ecc(String),
 
+    /// Blocks (0-based, within one RS decode) with more errors than the ECC
+    /// can correct
+    #[error("Reed-Solomon error: correction failed on block(s) {}", join(.0))]
+    EccBlocks(Vec<usize>),
+
+    /// Uncorrectable RS blocks located in the original file
+    #[error("Reed-Solomon error: {} uncorrectable block(s): {}", .0.len(), join(.0))]
+    Uncorrectable(Vec<DamagedBlock>),
+
+    /// A frame RS-decoded to data that fails the hash in its header: more
+    /// errors than the ECC can correct, miscorrected
+    #[error("Verification error: frame {}: data hash mismatch (corrupted beyond the ECC)", .frame + 1)]
+    HashMismatch { frame: usize },
+
+    /// Pieces (0-based) of the decoded file that fail their stored hash
+    #[error("Verification error: {} piece(s) fail their hash: {}", .0.len(), join(&.0.iter().map(|p| p + 1).collect::<Vec<_>>()))]
+    PieceMismatch(Vec<usize>),
+
+    /// A file does not hash to the SHA-256 or BLAKE3 recorded for it
+    #[error("Verification error: {0} does not match its recorded hash")]
+    FileHashMismatch(String),
+
+    /// The payload's signature is missing, by another key, or does not match
+    #[error("Signature error: {0}")]
+    Signature(String),
+
     #[error("Invalid header: {0}")]
     Header(String),
 
+    #[error("Invalid header: could not detect frame configuration from video")]
+    DetectFailed,
+
+    /// Frames (0-based) never decoded, and parts (0-based) never seen
+    #[error("{}", missing_text(.missing, .total, .missing_parts, *.total_parts))]
+    MissingFrames {
+        missing: Vec<usize>,
+        total: usize,
+        missing_parts: Vec<u16>,
+        total_parts: u16,
+    },
+
     #[error("FFmpeg error: {0}")]
     Ffmpeg(String),
 
+    #[error("FFmpeg error: ffmpeg not found — install FFmpeg and make sure it is in your PATH")]
+    FfmpegNotFound,
+
+    /// yt-dlp missing or the download failed
+    #[error("Download error: {0}")]
+    Download(String),
+
+    /// aws CLI missing or an object storage transfer failed
+    #[error("Object storage error: {0}")]
+    Storage(String),
+
     #[error("Invalid configuration: {0}")]
     Config(String),
 
+    /// Too little disk space or memory for the command, found before starting
+    #[error("Not enough space: {0}")]
+    NoSpace(String),
+
+    #[error("Audio error: {0}")]
+    Audio(String),
+
+    #[error("Compression error: {0}")]
+    Compression(String),
+
     #[error("Image error: {0}")]
     Image(#[from] image::ImageError),
+
+    #[error("cancelled")]
+    Cancelled,
+}
+
+impl From<HeaderError> for VstorageError {
+    fn from(e: HeaderError) -> Self {
+        VstorageError::Header(e.to_string())
+    }
+}
+
+impl VstorageError {
+    /// Process exit status for this error (2 is taken by a failed verify).
+    pub fn exit_code(&self) -> i32 {
+        match self {
+            VstorageError::Config(_) => 3,
+            VstorageError::Io(_)
+            | VstorageError::Image(_)
+            | VstorageError::Storage(_)
+            | VstorageError::NoSpace(_) => 4,
+            VstorageError::Ffmpeg(_)
+            | VstorageError::FfmpegNotFound
+            | VstorageError::Download(_) => 5,
+            VstorageError::Header(_) | VstorageError::DetectFailed => 6,
+            VstorageError::MissingFrames { .. } => 7,
+            VstorageError::Ecc(_)
+            | VstorageError::EccBlocks(_)
+            | VstorageError::Uncorrectable(_)
+            | VstorageError::HashMismatch { .. }
+            | VstorageError::FileHashMismatch(_)
+            | VstorageError::PieceMismatch(_) => 8,
+            VstorageError::PasswordRequired
+            | VstorageError::WrongPassword
+            | VstorageError::IdentityRequired
+            | VstorageError::TokenRequired
+            | VstorageError::WrongIdentity => 9,
+            VstorageError::AuthFailed { .. } => 10,
+            VstorageError::Signature(_) => 11,
+            VstorageError::Crypto(_) | VstorageError::Audio(_) | VstorageError::Compression(_) => 1,
+            // Killed by SIGINT, by shell convention
+            VstorageError::Cancelled => 130,
+        }
+    }
+}
+
+/// An RS block that could not be corrected, and the bytes of the original
+/// file it carried.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DamagedBlock {
+    /// 0-based frame index
+    pub frame: usize,
+    /// 0-based RS block within the frame
+    pub block: usize,
+    /// File byte offset of the block's data
+    pub offset: u64,
+    pub len: u64,
+}
+
+impl fmt::Display for DamagedBlock {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "frame {} block {} (file bytes {}..{})",
+            self.frame + 1,
+            self.block,
+            self.offset,
+            self.offset + self.len
+        )
+    }
+}
+
+/// Comma-separated list, cut off after 10 items.
+fn join<T: fmt::Display>(items: &[T]) -> String {
+    let mut shown: Vec<String> = items.iter().take(10).map(ToString::to_string).collect();
+    if items.len() > shown.len() {
+        shown.push("...".into());
+    }
+    shown.join(", ")
+}
+
+fn missing_text(missing: &[usize], total: &usize, parts: &[u16], total_parts: u16) -> String {
+    let frames: Vec<usize> = missing.iter().map(|i| i + 1).collect();
+    let hint = if parts.is_empty() {
+        "is a part missing?".to_string()
+    } else {
+        let parts: Vec<u16> = parts.iter().map(|p| p + 1).collect();
+        format!("missing part(s) {} of {total_parts}", join(&parts))
+    };
+    format!(
+        "Invalid header: missing {} of {total} frames ({}) — {hint}",
+        missing.len(),
+        join(&frames)
+    )
 }
 
 pub type Result<T> = std::result::Result<T, VstorageError>;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_missing_frames_message() {
+        let e = VstorageError::MissingFrames {
+            missing: (3..15).collect(),
+            total: 40,
+            missing_parts: vec![1],
+            total_parts: 2,
+        };
+        assert_eq!(
+            e.to_string(),
+            "Invalid header: missing 12 of 40 frames (4, 5, 6, 7, 8, 9, 10, 11, 12, 13, ...) \
+             — missing part(s) 2 of 2"
+        );
+        assert_eq!(e.exit_code(), 7);
+        assert_eq!(VstorageError::WrongPassword.exit_code(), 9);
+    }
+}
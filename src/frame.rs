@@ -1,271 +1,868 @@
+use std::borrow::Cow;
+use std::ops::Range;
+
 use image::{Rgb, RgbImage};
 
-use crate::config::{FrameConfig, HEADER_ROWS};
+use crate::codec::{bits_for, dequantize_table, quantize_table};
+pub use crate::codec::{
+    dequantize, dequantize_centered, quantize, quantize_centered, BitReader, BitWriter,
+};
+use crate::config::{FrameConfig, HEADER_COPIES, HEADER_ROWS};
+use crate::crcmap;
+use crate::header;
+use crate::layout::{self, Layout};
+use crate::pilot;
+
+/// Blocks at the start of a header area holding its preamble, 3 bits each.
+pub const PREAMBLE_BLOCKS: usize = header::PREAMBLE_LEN * 8 / 3;
+
+// ── Block painting / reading ────────────────────────────────────────────────
 
-/// Map a quantization level (0..levels-1) to a pixel channel value (0..255)
-pub fn quantize(value: u8, levels: u8) -> u8 {
-    if levels <= 1 {
-        return 0;
+/// Quantize tables of a data area whose channels have `levels` levels: at
+/// bin centres with [`FrameConfig::bin_centers`].
+fn data_quantize_tables(config: &FrameConfig, levels: [u8; 3]) -> [[u8; 256]; 3] {
+    if !config.bin_centers {
+        return levels.map(quantize_table);
     }
-    (value as u16 * 255 / (levels as u16 - 1)) as u8
+    levels.map(|l| std::array::from_fn(|v| quantize_centered(v as u8, l)))
 }
 
-/// Map a pixel channel value (0..255) to the nearest quantization level (0..levels-1)
-pub fn dequantize(pixel: u8, levels: u8) -> u8 {
-    if levels <= 1 {
-        return 0;
+/// Dequantize tables matching [`data_quantize_tables`].
+fn data_dequantize_tables(config: &FrameConfig, levels: [u8; 3]) -> [[u8; 256]; 3] {
+    if !config.bin_centers {
+        return levels.map(dequantize_table);
     }
-    let step = 255.0 / (levels as f64 - 1.0);
-    ((pixel as f64 / step).round() as u8).min(levels - 1)
+    levels.map(|l| std::array::from_fn(|p| dequantize_centered(p as u8, l)))
 }
 
-// ── Bit stream helpers ──────────────────────────────────────────────────────
+/// The first `n` channel values of the bits of `data`, as read one at a time
+/// with a [`BitReader`]: channel `c` takes `bits[c]` bits, mapped through
+/// `tables[c]`. When the channels are alike, every byte is expanded at once
+/// through a table of its values.
+fn quantize_values(data: &[u8], n: usize, tables: &[[u8; 256]; 3], bits: [u8; 3]) -> Vec<u8> {
+    let alike = bits == [bits[0]; 3] && tables.iter().all(|t| t == &tables[0]);
+    match bits[0] {
+        1 if alike => expand::<8>(data, n, &tables[0]),
+        2 if alike => expand::<4>(data, n, &tables[0]),
+        4 if alike => expand::<2>(data, n, &tables[0]),
+        _ => {
+            let mut reader = BitReader::new(data);
+            let mut values = vec![0u8; n];
+            for rgb in values.chunks_mut(3) {
+                for (c, v) in rgb.iter_mut().enumerate() {
+                    *v = tables[c][reader.read_bits(bits[c]) as usize];
+                }
+            }
+            values
+        }
+    }
+}
 
-pub struct BitWriter {
-    bytes: Vec<u8>,
-    current: u8,
-    count: u8,
+/// [`quantize_values`] of channels of `8 / PER` bits.
+fn expand<const PER: usize>(data: &[u8], n: usize, table: &[u8; 256]) -> Vec<u8> {
+    let bits = 8 / PER;
+    let lanes: Vec<[u8; PER]> = (0..256)
+        .map(|byte| {
+            std::array::from_fn(|k| table[byte >> (8 - bits * (k + 1)) & ((1 << bits) - 1)])
+        })
+        .collect();
+    // Past the end of `data` the bits read as 0
+    let mut values = vec![table[0]; n.div_ceil(PER) * PER];
+    for (chunk, &byte) in values.chunks_exact_mut(PER).zip(data) {
+        chunk.copy_from_slice(&lanes[byte as usize]);
+    }
+    values.truncate(n);
+    values
 }
 
-impl BitWriter {
-    pub fn new() -> Self {
-        Self {
-            bytes: Vec::new(),
-            current: 0,
-            count: 0,
+/// Bits of channel values `values`, as written one at a time with a
+/// [`BitWriter`]: channel `c` is mapped through `tables[c]` to `bits[c]`
+/// bits. When the channels are alike, every byte is packed at once.
+fn dequantize_values(values: &[u8], tables: &[[u8; 256]; 3], bits: [u8; 3]) -> Vec<u8> {
+    let alike = bits == [bits[0]; 3] && tables.iter().all(|t| t == &tables[0]);
+    match bits[0] {
+        1 if alike => pack::<8>(values, &tables[0]),
+        2 if alike => pack::<4>(values, &tables[0]),
+        4 if alike => pack::<2>(values, &tables[0]),
+        _ => {
+            let mut writer = BitWriter::new();
+            for rgb in values.chunks(3) {
+                for (c, &v) in rgb.iter().enumerate() {
+                    writer.write_bits(tables[c][v as usize], bits[c]);
+                }
+            }
+            writer.finish()
         }
     }
+}
 
-    /// Write `num_bits` from `value` (MSB first). num_bits must be <= 8.
-    pub fn write_bits(&mut self, value: u8, num_bits: u8) {
-        for i in (0..num_bits).rev() {
-            let bit = (value >> i) & 1;
-            self.current = (self.current << 1) | bit;
-            self.count += 1;
-            if self.count == 8 {
-                self.bytes.push(self.current);
-                self.current = 0;
-                self.count = 0;
-            }
+/// [`dequantize_values`] of channels of `8 / PER` bits.
+fn pack<const PER: usize>(values: &[u8], table: &[u8; 256]) -> Vec<u8> {
+    let bits = 8 / PER;
+    let mask = (1u8 << bits) - 1;
+    let byte = |chunk: &[u8]| {
+        chunk.iter().enumerate().fold(0u8, |b, (k, &v)| {
+            b | (table[v as usize] & mask) << (8 - bits * (k + 1))
+        })
+    };
+    let chunks = values.chunks_exact(PER);
+    let tail = chunks.remainder();
+    let mut bytes: Vec<u8> = chunks.map(byte).collect();
+    if !tail.is_empty() {
+        bytes.push(byte(tail));
+    }
+    bytes
+}
+
+/// Paint logical row `ly` from `values`, three channel values per block:
+/// the first pixel row is filled block by block, then copied down. Pixels
+/// right of the last block are cleared.
+fn paint_row(img: &mut RgbImage, ly: usize, block_size: u32, values: &[u8]) {
+    let bs = block_size as usize;
+    let stride = img.width() as usize * 3;
+    let buf: &mut [u8] = img;
+    let (first, rest) = buf[ly * bs * stride..(ly + 1) * bs * stride].split_at_mut(stride);
+    first[values.len() * bs..].fill(0);
+    if bs == 1 {
+        first[..values.len()].copy_from_slice(values);
+        return;
+    }
+    for (pixels, rgb) in first.chunks_exact_mut(bs * 3).zip(values.chunks_exact(3)) {
+        for pixel in pixels.chunks_exact_mut(3) {
+            pixel.copy_from_slice(rgb);
         }
     }
+    for row in rest.chunks_exact_mut(stride) {
+        row.copy_from_slice(first);
+    }
+}
+
+/// Blocks of up to this many pixels are sorted in stack arrays; larger ones
+/// are counted into per-channel histograms.
+const SORTED_BLOCK_PIXELS: usize = 256;
 
-    pub fn finish(mut self) -> Vec<u8> {
-        if self.count > 0 {
-            self.current <<= 8 - self.count;
-            self.bytes.push(self.current);
+/// Per-channel medians of the first `blocks` BxB blocks of logical row `ly`,
+/// passed to `emit` three per block. Nothing is allocated.
+fn row_medians(
+    img: &RgbImage,
+    ly: usize,
+    block_size: u32,
+    blocks: usize,
+    mut emit: impl FnMut(u8),
+) {
+    let bs = block_size as usize;
+    let stride = img.width() as usize * 3;
+    let rows = &img.as_raw()[ly * bs * stride..(ly + 1) * bs * stride];
+    let n = bs * bs;
+    if bs == 1 {
+        rows[..blocks * 3].iter().copied().for_each(emit);
+    } else if n <= SORTED_BLOCK_PIXELS {
+        let mut channels = [[0u8; SORTED_BLOCK_PIXELS]; 3];
+        for lx in 0..blocks {
+            let mut k = 0;
+            for row in rows.chunks_exact(stride) {
+                for p in row[lx * bs * 3..(lx + 1) * bs * 3].chunks_exact(3) {
+                    channels[0][k] = p[0];
+                    channels[1][k] = p[1];
+                    channels[2][k] = p[2];
+                    k += 1;
+                }
+            }
+            for channel in &mut channels {
+                channel[..n].sort_unstable();
+                emit(channel[n / 2]);
+            }
+        }
+    } else {
+        for lx in 0..blocks {
+            let mut hist = [[0u32; 256]; 3];
+            for row in rows.chunks_exact(stride) {
+                for p in row[lx * bs * 3..(lx + 1) * bs * 3].chunks_exact(3) {
+                    hist[0][p[0] as usize] += 1;
+                    hist[1][p[1] as usize] += 1;
+                    hist[2][p[2] as usize] += 1;
+                }
+            }
+            for counts in &hist {
+                // The value at sorted position n / 2
+                let mut seen = 0;
+                let median = counts.iter().position(|&c| {
+                    seen += c as usize;
+                    seen > n / 2
+                });
+                emit(median.unwrap_or(255) as u8);
+            }
         }
-        self.bytes
     }
 }
 
-pub struct BitReader<'a> {
-    data: &'a [u8],
-    byte_pos: usize,
-    bit_pos: u8,
+/// Paint logical rows `rows`, `blocks` blocks wide, with the bits of `data`,
+/// each block's red, green and blue at their `levels`.
+fn paint_area(
+    img: &mut RgbImage,
+    rows: Range<usize>,
+    blocks: usize,
+    data: &[u8],
+    config: &FrameConfig,
+    levels: [u8; 3],
+) {
+    let tables = levels.map(quantize_table);
+    let values = quantize_values(data, rows.len() * blocks * 3, &tables, levels.map(bits_for));
+    for (ly, row) in rows.zip(values.chunks_exact(blocks * 3)) {
+        paint_row(img, ly, config.block_size as u32, row);
+    }
+}
+
+/// Read the bits of logical rows `rows`, `blocks` blocks wide, each block's
+/// red, green and blue at their `levels`.
+fn read_area(
+    img: &RgbImage,
+    rows: Range<usize>,
+    blocks: usize,
+    block_size: u8,
+    levels: [u8; 3],
+) -> Vec<u8> {
+    let mut medians = Vec::with_capacity(rows.len() * blocks * 3);
+    for ly in rows {
+        row_medians(img, ly, block_size as u32, blocks, |v| medians.push(v));
+    }
+    dequantize_values(
+        &medians,
+        &levels.map(dequantize_table),
+        levels.map(bits_for),
+    )
+}
+
+/// Whole RS codewords in a data area whose channels have `levels` levels.
+fn area_codewords(config: &FrameConfig, levels: [u8; 3]) -> usize {
+    let bits: usize = levels.map(bits_for).iter().map(|&b| b as usize).sum();
+    config.data_area_pixels() * bits / 8 / 255
 }
 
-impl<'a> BitReader<'a> {
-    pub fn new(data: &'a [u8]) -> Self {
-        Self {
-            data,
-            byte_pos: 0,
-            bit_pos: 0,
+/// Raster indices of the pilot blocks of frame `frame` (none without
+/// [`FrameConfig::pilots`]).
+fn pilot_blocks(config: &FrameConfig, frame: u32) -> Vec<usize> {
+    if !config.pilots {
+        return Vec::new();
+    }
+    pilot::positions(config.data_width(), config.data_rows().len(), frame)
+}
+
+/// Raster indices of the data area's blocks in the order the layout lays
+/// bits on them, leaving out `pilots`.
+fn stream_order(config: &FrameConfig, pilots: &[usize]) -> Vec<usize> {
+    let (lw, lh) = (config.data_width(), config.data_rows().len());
+    let mut is_pilot = vec![false; lw * lh];
+    pilots.iter().for_each(|&b| is_pilot[b] = true);
+    let order: Box<dyn Iterator<Item = usize>> = match config.layout {
+        Layout::Raster => Box::new(0..lw * lh),
+        Layout::Hilbert => {
+            let order = config.curve.order(lw, lh);
+            Box::new((0..order.len()).map(move |i| order[i] as usize))
+        }
+    };
+    order.filter(|&b| !is_pilot[b]).collect()
+}
+
+/// Paint the data area block by block along [`stream_order`], `rs_data`
+/// interleaved first for [`Layout::Hilbert`], with the pilots of frame
+/// `frame` at their levels.
+fn paint_blocks(
+    img: &mut RgbImage,
+    rs_data: &[u8],
+    config: &FrameConfig,
+    levels: [u8; 3],
+    frame: u32,
+) {
+    let (lw, lh) = (config.data_width(), config.data_rows().len());
+    let interleaved;
+    let data = match config.layout {
+        Layout::Raster => rs_data,
+        Layout::Hilbert => {
+            interleaved = layout::interleave(rs_data, area_codewords(config, levels));
+            &interleaved[..]
+        }
+    };
+    let tables = data_quantize_tables(config, levels);
+    let bits = levels.map(bits_for);
+    let mut values = vec![0u8; lw * lh * 3];
+    let pilots = pilot_blocks(config, frame);
+    for (k, &block) in pilots.iter().enumerate() {
+        for c in 0..3 {
+            values[block * 3 + c] = tables[c][pilot::level(k, c, levels[c]) as usize];
         }
     }
+    let order = stream_order(config, &pilots);
+    let stream = quantize_values(data, order.len() * 3, &tables, bits);
+    for (&block, rgb) in order.iter().zip(stream.chunks_exact(3)) {
+        values[block * 3..][..3].copy_from_slice(rgb);
+    }
+    for (ly, row) in config.data_rows().zip(values.chunks_exact(lw * 3)) {
+        paint_row(img, ly, config.block_size as u32, row);
+    }
+}
 
-    /// Read `num_bits` (MSB first). Pads with 0 if past end of data.
-    pub fn read_bits(&mut self, num_bits: u8) -> u8 {
-        let mut value: u8 = 0;
-        for _ in 0..num_bits {
-            value <<= 1;
-            if self.byte_pos < self.data.len() {
-                let bit = (self.data[self.byte_pos] >> (7 - self.bit_pos)) & 1;
-                value |= bit;
-                self.bit_pos += 1;
-                if self.bit_pos == 8 {
-                    self.bit_pos = 0;
-                    self.byte_pos += 1;
-                }
-            }
+/// Read a data area painted by [`paint_blocks`].
+fn read_blocks(img: &RgbImage, config: &FrameConfig, levels: [u8; 3], frame: u32) -> Vec<u8> {
+    let (lw, lh) = (config.data_width(), config.data_rows().len());
+    let medians = data_medians(img, config);
+    let pilots = pilot_blocks(config, frame);
+    let tables = area_tables(&medians, config, levels, &pilots);
+    let bits = levels.map(bits_for);
+    let mut writer = BitWriter::new();
+    for block in stream_order(config, &pilots) {
+        let (rgb, table) = (
+            &medians[block * 3..][..3],
+            &tables[pilot::region(block, lw, lh)],
+        );
+        for c in 0..3 {
+            writer.write_bits(table[c][rgb[c] as usize], bits[c]);
         }
-        value
+    }
+    match config.layout {
+        Layout::Raster => writer.finish(),
+        Layout::Hilbert => layout::deinterleave(&writer.finish(), area_codewords(config, levels)),
     }
 }
 
-// ── Block painting / reading ────────────────────────────────────────────────
+/// Dequantize tables of each [`pilot::region`] of a data area whose block
+/// medians are `medians`: fitted to the region's `pilots`, else to all of
+/// them, else the fixed ones.
+fn area_tables(
+    medians: &[u8],
+    config: &FrameConfig,
+    levels: [u8; 3],
+    pilots: &[usize],
+) -> Vec<[[u8; 256]; 3]> {
+    let (lw, lh) = (config.data_width(), config.data_rows().len());
+    let fit = |c: usize, region: Option<usize>| {
+        let samples: Vec<(u8, u8)> = pilots
+            .iter()
+            .enumerate()
+            .filter(|&(_, &b)| region.is_none_or(|r| pilot::region(b, lw, lh) == r))
+            .map(|(k, &b)| (pilot::level(k, c, levels[c]), medians[b * 3 + c]))
+            .collect();
+        pilot::fit(&samples, levels[c])
+    };
+    let fixed = data_dequantize_tables(config, levels);
+    let whole: [[u8; 256]; 3] = std::array::from_fn(|c| fit(c, None).unwrap_or(fixed[c]));
+    (0..pilot::REGIONS * pilot::REGIONS)
+        .map(|r| std::array::from_fn(|c| fit(c, Some(r)).unwrap_or(whole[c])))
+        .collect()
+}
 
-/// Paint a BxB block of pixels at logical position (lx, ly) with the given RGB values.
-fn paint_block(img: &mut RgbImage, lx: usize, ly: usize, block_size: u32, r: u8, g: u8, b: u8) {
-    let px = lx as u32 * block_size;
-    let py = ly as u32 * block_size;
-    for dy in 0..block_size {
-        for dx in 0..block_size {
-            img.put_pixel(px + dx, py + dy, Rgb([r, g, b]));
-        }
+/// Channel medians of every data area block, three per block in raster
+/// order.
+fn data_medians(img: &RgbImage, config: &FrameConfig) -> Vec<u8> {
+    let lw = config.data_width();
+    let mut medians = Vec::with_capacity(lw * config.data_rows().len() * 3);
+    for ly in config.data_rows() {
+        row_medians(img, ly, config.block_size as u32, lw, |v| medians.push(v));
+    }
+    medians
+}
+
+/// Paint the CRC map of the data area just painted, whose blocks still hold
+/// their exact values in their top-left pixels.
+fn paint_crc_map(img: &mut RgbImage, config: &FrameConfig) {
+    let (lw, rows) = (config.data_width(), config.data_rows());
+    let (bs, stride) = (config.block_size as usize, config.width as usize * 3);
+    let mut values = Vec::with_capacity(lw * rows.len() * 3);
+    for ly in rows.clone() {
+        let row = &img.as_raw()[ly * bs * stride..][..lw * bs * 3];
+        row.chunks_exact(bs * 3)
+            .for_each(|block| values.extend_from_slice(&block[..3]));
+    }
+    let map = crcmap::encode_map(&crcmap::tile_crcs(&values, lw, rows.len()));
+    let map_rows = rows.end..rows.end + config.map_rows();
+    paint_area(img, map_rows, config.logical_width(), &map, config, [2; 3]);
+}
+
+/// Draw the banner line `text` in its rows, above the bottom header copy.
+pub fn paint_banner(img: &mut RgbImage, config: &FrameConfig, text: &str) {
+    let end = config.logical_height() - config.bottom_header_rows();
+    crate::banner::paint(
+        img,
+        end - config.banner_rows()..end,
+        config.block_size,
+        text,
+    );
+}
+
+/// Draw the barcode of a frame's number and kind (see [`crate::barcode`])
+/// right of its data area.
+pub fn paint_barcode(img: &mut RgbImage, config: &FrameConfig, frame: (u32, u8)) {
+    let columns = config.data_width()..config.logical_width();
+    crate::barcode::paint(img, config.data_rows(), columns, config.block_size, frame);
+}
+
+/// The frame number and kind in the barcode, if the frames have one and it
+/// reads.
+pub fn read_barcode(img: &RgbImage, config: &FrameConfig) -> Option<(u32, u8)> {
+    if !config.barcode {
+        return None;
     }
+    let columns = config.data_width()..config.logical_width();
+    crate::barcode::read(img, config.data_rows(), columns, config.block_size)
 }
 
-/// Read a BxB block at logical (lx, ly) and return the median-dequantized (r, g, b) level values.
-fn read_block(img: &RgbImage, lx: usize, ly: usize, block_size: u32, levels: u8) -> (u8, u8, u8) {
-    let px = lx as u32 * block_size;
-    let py = ly as u32 * block_size;
-    let mut rs: Vec<u8> = Vec::new();
-    let mut gs: Vec<u8> = Vec::new();
-    let mut bs: Vec<u8> = Vec::new();
-    for dy in 0..block_size {
-        for dx in 0..block_size {
-            let p = img.get_pixel(px + dx, py + dy);
-            rs.push(p[0]);
-            gs.push(p[1]);
-            bs.push(p[2]);
+/// Which tiles of the data area (see [`crate::crcmap`]) no longer match
+/// the CRC map of frame `frame`, reading the blocks with `levels`; `None`
+/// without a map. Tiles whose map entry is unreadable count as intact.
+pub fn damaged_tiles(
+    img: &RgbImage,
+    config: &FrameConfig,
+    levels: [u8; 3],
+    frame: u32,
+) -> Option<Vec<bool>> {
+    if !config.crc_map {
+        return None;
+    }
+    let (lw, rows) = (config.data_width(), config.data_rows());
+    let (tw, th) = crcmap::tile_grid(lw, rows.len());
+    let map = read_area(
+        img,
+        rows.end..rows.end + config.map_rows(),
+        config.logical_width(),
+        config.block_size,
+        [2; 3],
+    );
+    let stored = crcmap::decode_map(&map, tw * th);
+
+    // Snap each median to its level, as painted
+    let mut values = data_medians(img, config);
+    let tables = area_tables(&values, config, levels, &pilot_blocks(config, frame));
+    let quantized = data_quantize_tables(config, levels);
+    for (block, rgb) in values.chunks_exact_mut(3).enumerate() {
+        let table = &tables[pilot::region(block, lw, rows.len())];
+        for c in 0..3 {
+            rgb[c] = quantized[c][table[c][rgb[c] as usize] as usize];
         }
     }
-    rs.sort_unstable();
-    gs.sort_unstable();
-    bs.sort_unstable();
-    let mid = rs.len() / 2;
-    (
-        dequantize(rs[mid], levels),
-        dequantize(gs[mid], levels),
-        dequantize(bs[mid], levels),
+    let crcs = crcmap::tile_crcs(&values, lw, rows.len());
+    Some(
+        crcs.iter()
+            .zip(stored)
+            .map(|(&crc, stored)| stored.is_some_and(|s| s != crc))
+            .collect(),
     )
 }
 
-// ── Frame encoding / decoding ───────────────────────────────────────────────
+/// Erasure positions in each RS codeword of the data area of frame `frame`
+/// read with `levels` (as returned by [`decode_data_area`]): the bytes of
+/// its `damaged` tiles.
+pub fn tile_erasures(
+    config: &FrameConfig,
+    levels: [u8; 3],
+    damaged: &[bool],
+    frame: u32,
+) -> Vec<Vec<u8>> {
+    let bits = levels.map(bits_for).iter().map(|&b| b as usize).sum();
+    let lw = config.data_width();
+    let codewords = area_codewords(config, levels);
+    let stream = || stream_order(config, &pilot_blocks(config, frame)).into_iter();
+    match config.layout {
+        Layout::Raster => crcmap::erasures(damaged, lw, stream(), bits, codewords, |b| {
+            (b / 255 < codewords).then_some((b / 255, b % 255))
+        }),
+        Layout::Hilbert => crcmap::erasures(damaged, lw, stream(), bits, codewords, |p| {
+            (p < codewords * 255).then_some((p % codewords, p / codewords))
+        }),
+    }
+}
 
-/// Encode header bytes and RS-encoded data into a 4K RGB image.
-pub fn encode_frame_to_image(header_data: &[u8], rs_data: &[u8], config: &FrameConfig) -> RgbImage {
-    let lw = config.logical_width();
-    let lh = config.logical_height();
-    let bpc = config.bits_per_channel();
+/// How far block medians in the data area drifted from the level they were
+/// read as.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct LevelNoise {
+    /// Mean distance, in pixel values
+    pub mean: f64,
+    /// Largest distance, in pixel values
+    pub max: u8,
+    /// Distance at which a value is read as the neighbouring level
+    pub threshold: f64,
+}
+
+impl LevelNoise {
+    /// Fraction of the threshold the worst block still had left (0 = a block
+    /// sat on the decision boundary).
+    pub fn margin(&self) -> f64 {
+        (1.0 - self.max as f64 / self.threshold).max(0.0)
+    }
+}
+
+/// Measure [`LevelNoise`] over the data area of a decoded frame. With
+/// [`FrameConfig::channel_levels`] the threshold is that of the channel with
+/// the most levels.
+pub fn level_noise(img: &RgbImage, config: &FrameConfig) -> LevelNoise {
     let bs = config.block_size as u32;
-    let levels = config.levels;
+    let levels = config.channel_levels();
+    let (quantized, dequantized) = (
+        data_quantize_tables(config, levels),
+        data_dequantize_tables(config, levels),
+    );
+    let (mut sum, mut count, mut max) = (0u64, 0u64, 0u8);
+    for ly in config.data_rows() {
+        row_medians(img, ly, bs, config.data_width(), |v| {
+            let c = (count % 3) as usize;
+            let d = v.abs_diff(quantized[c][dequantized[c][v as usize] as usize]);
+            sum += d as u64;
+            count += 1;
+            max = max.max(d);
+        });
+    }
+    LevelNoise {
+        mean: sum as f64 / count.max(1) as f64,
+        max,
+        threshold: if config.bin_centers {
+            128.0 / config.levels as f64
+        } else {
+            255.0 / (config.levels as f64 - 1.0) / 2.0
+        },
+    }
+}
+
+// ── Frame encoding / decoding ───────────────────────────────────────────────
 
+/// Encode header bytes and RS-encoded data into a 4K RGB image, with the
+/// pilots of frame 0.
+pub fn encode_frame_to_image(header_data: &[u8], rs_data: &[u8], config: &FrameConfig) -> RgbImage {
     let mut img = RgbImage::new(config.width, config.height);
+    paint_frame(
+        &mut img,
+        header_data,
+        rs_data,
+        config,
+        config.channel_levels(),
+        0,
+    );
+    img
+}
 
-    // Header area: first HEADER_ROWS logical rows
+/// [`encode_frame_to_image`] into `img`, reusing its buffer when it already
+/// has the frame's dimensions, so encoding many frames allocates one image.
+/// The data area's channels are drawn with `data_levels` levels, the header
+/// area with `config.levels`; `frame` places the pilots.
+pub fn paint_frame(
+    img: &mut RgbImage,
+    header_data: &[u8],
+    rs_data: &[u8],
+    config: &FrameConfig,
+    data_levels: [u8; 3],
+    frame: u32,
+) {
+    if img.dimensions() != (config.width, config.height) {
+        *img = RgbImage::new(config.width, config.height);
+    }
+    // Header area: first header_rows logical rows
+    paint_header(img, 0, header_data, config);
+    // Data area: remaining logical rows, less the CRC map's
+    if config.layout == Layout::Raster && !config.pilots && !config.bin_centers {
+        let rows = config.data_rows();
+        paint_area(img, rows, config.data_width(), rs_data, config, data_levels);
+    } else {
+        paint_blocks(img, rs_data, config, data_levels, frame);
+    }
+    if config.crc_map {
+        paint_crc_map(img, config);
+    }
+    let lh = config.logical_height();
+    // Header copy in the last header_rows logical rows
+    if config.bottom_header {
+        paint_header(img, lh - config.header_rows, header_data, config);
+    }
+    // Rows below the last block
+    let painted = lh * config.block_size as usize * config.width as usize * 3;
+    let buf: &mut [u8] = img;
+    buf[painted..].fill(0);
+}
+
+/// Paint a header area from logical row `top`: the preamble if the frames
+/// have one ([`FrameConfig::has_preamble`]), then `header_data`.
+fn paint_header(img: &mut RgbImage, top: usize, header_data: &[u8], config: &FrameConfig) {
+    let (lw, rows) = (config.logical_width(), top..top + config.header_rows);
+    if !config.has_preamble() {
+        paint_area(img, rows, lw, header_data, config, [config.levels; 3]);
+        return;
+    }
+    let preamble = header::encode_preamble(config.header_rows, config.header_copies);
+    let mut reader = BitReader::new(&preamble);
+    let binary = quantize_table(2);
+    let mut values: Vec<u8> = (0..PREAMBLE_BLOCKS * 3)
+        .map(|_| binary[reader.read_bits(1) as usize])
+        .collect();
     let mut reader = BitReader::new(header_data);
-    for ly in 0..HEADER_ROWS {
-        for lx in 0..lw {
-            let r = reader.read_bits(bpc);
-            let g = reader.read_bits(bpc);
-            let b = reader.read_bits(bpc);
-            paint_block(
-                &mut img,
-                lx,
-                ly,
-                bs,
-                quantize(r, levels),
-                quantize(g, levels),
-                quantize(b, levels),
-            );
-        }
+    let (table, bits) = (quantize_table(config.levels), bits_for(config.levels));
+    values.resize_with(rows.len() * lw * 3, || {
+        table[reader.read_bits(bits) as usize]
+    });
+    for (ly, row) in rows.zip(values.chunks_exact(lw * 3)) {
+        paint_row(img, ly, config.block_size as u32, row);
     }
+}
 
-    // Data area: remaining logical rows
-    let mut reader = BitReader::new(rs_data);
-    for ly in HEADER_ROWS..lh {
-        for lx in 0..lw {
-            let r = reader.read_bits(bpc);
-            let g = reader.read_bits(bpc);
-            let b = reader.read_bits(bpc);
-            paint_block(
-                &mut img,
-                lx,
-                ly,
-                bs,
-                quantize(r, levels),
-                quantize(g, levels),
-                quantize(b, levels),
-            );
-        }
+/// Rows and copies of the header area starting at logical row `top`, from
+/// its preamble; `None` if it has none.
+fn read_preamble(img: &RgbImage, top: usize, block_size: u8) -> Option<(usize, usize)> {
+    let bs = block_size as u32;
+    if (img.width() / bs) < PREAMBLE_BLOCKS as u32 || (top as u32 + 1) * bs > img.height() {
+        return None;
     }
+    let bytes = read_area(img, top..top + 1, PREAMBLE_BLOCKS, block_size, [2; 3]);
+    header::decode_preamble(&bytes)
+}
 
-    img
+/// Logical rows and header copies of the frames `img` belongs to: those
+/// its top preamble gives, or that of its bottom header copy, or the
+/// defaults.
+pub fn header_layout(img: &RgbImage, block_size: u8) -> (usize, usize) {
+    read_preamble(img, 0, block_size)
+        .or_else(|| bottom_preamble(img, block_size).map(|(_, layout)| layout))
+        .unwrap_or((HEADER_ROWS, HEADER_COPIES))
 }
 
-/// Decode only the header area (first HEADER_ROWS logical rows) from an image.
-pub fn decode_header_area(img: &RgbImage, block_size: u8, levels: u8) -> Vec<u8> {
-    let lw = img.width() as usize / block_size as usize;
-    let bpc = (levels as f64).log2() as u8;
-    let bs = block_size as u32;
+/// First logical row of a bottom header copy that starts with a preamble,
+/// and the rows and copies it gives.
+fn bottom_preamble(img: &RgbImage, block_size: u8) -> Option<(usize, (usize, usize))> {
+    let lh = img.height() as usize / block_size as usize;
+    (1..=header::MAX_HEADER_ROWS.min(lh)).find_map(|r| {
+        read_preamble(img, lh - r, block_size)
+            .filter(|&(rows, _)| rows == r)
+            .map(|layout| (lh - r, layout))
+    })
+}
 
+/// The header area from logical row `top`, in the three-copy form
+/// [`header::decode_header_triple`] takes: with a preamble, its copies are
+/// voted into one, repeated three times.
+fn read_header(img: &RgbImage, top: usize, block_size: u8, levels: u8) -> Vec<u8> {
+    let lw = img.width() as usize / block_size as usize;
+    let Some((rows, copies)) = read_preamble(img, top, block_size) else {
+        return read_area(img, top..top + HEADER_ROWS, lw, block_size, [levels; 3]);
+    };
+    let (table, bits) = (dequantize_table(levels), bits_for(levels));
     let mut writer = BitWriter::new();
-    for ly in 0..HEADER_ROWS {
-        for lx in 0..lw {
-            let (r, g, b) = read_block(img, lx, ly, bs, levels);
-            writer.write_bits(r, bpc);
-            writer.write_bits(g, bpc);
-            writer.write_bits(b, bpc);
-        }
+    let mut skip = PREAMBLE_BLOCKS * 3;
+    let end = (top + rows).min(img.height() as usize / block_size as usize);
+    for ly in top..end {
+        row_medians(img, ly, block_size as u32, lw, |v| match skip {
+            0 => writer.write_bits(table[v as usize], bits),
+            _ => skip -= 1,
+        });
     }
-    writer.finish()
+    header::vote_copies(&writer.finish(), copies).repeat(3)
 }
 
-/// Decode the data area (rows after HEADER_ROWS) from an image.
-pub fn decode_data_area(img: &RgbImage, config: &FrameConfig) -> Vec<u8> {
-    let lw = config.logical_width();
-    let lh = config.logical_height();
-    let bpc = config.bits_per_channel();
-    let bs = config.block_size as u32;
-    let levels = config.levels;
+/// Decode only the header area (the first logical rows, as many as its
+/// preamble gives or [`HEADER_ROWS`]) from an image, or its copy in the last
+/// rows if the first holds no valid header and the copy does (see
+/// [`FrameConfig::bottom_header`]).
+pub fn decode_header_area(img: &RgbImage, block_size: u8, levels: u8) -> Vec<u8> {
+    let lh = img.height() as usize / block_size as usize;
+    let top = read_header(img, 0, block_size, levels);
+    if lh < HEADER_ROWS * 2 || header::decode_header_triple(&top).is_ok() {
+        return top;
+    }
+    // The bottom copy starts with its own preamble, if the frames have one
+    let start = bottom_preamble(img, block_size).map_or(lh - HEADER_ROWS, |(start, _)| start);
+    let bottom = read_header(img, start, block_size, levels);
+    match header::decode_header_triple(&bottom) {
+        Ok(hdr) if hdr.bottom_header => bottom,
+        _ => top,
+    }
+}
 
-    let mut writer = BitWriter::new();
-    for ly in HEADER_ROWS..lh {
-        for lx in 0..lw {
-            let (r, g, b) = read_block(img, lx, ly, bs, levels);
-            writer.write_bits(r, bpc);
-            writer.write_bits(g, bpc);
-            writer.write_bits(b, bpc);
+/// Decode the data area (rows after HEADER_ROWS) of frame `frame` from an
+/// image, its channels drawn with `levels` levels (the frame header's
+/// [`FrameHeader::area_levels`]).
+///
+/// [`FrameHeader::area_levels`]: crate::header::FrameHeader::area_levels
+pub fn decode_data_area(
+    img: &RgbImage,
+    config: &FrameConfig,
+    levels: [u8; 3],
+    frame: u32,
+) -> Vec<u8> {
+    if config.layout == Layout::Hilbert || config.pilots || config.bin_centers {
+        return read_blocks(img, config, levels, frame);
+    }
+    read_area(
+        img,
+        config.data_rows(),
+        config.data_width(),
+        config.block_size,
+        levels,
+    )
+}
+
+/// Phase, in `0..block_size`, of the block edges of `img` across its columns
+/// (or rows, with `rows`): the pixel offset, modulo the block size, at which
+/// neighbours differ most, if it clearly beats 0. Every 31st line is summed.
+fn edge_phase(img: &RgbImage, block_size: u8, rows: bool) -> usize {
+    let bs = block_size as usize;
+    let (w, h) = (img.width() as usize, img.height() as usize);
+    let raw = img.as_raw();
+    let (lines, len, step, pitch) = match rows {
+        false => (h, w, 3, w * 3),
+        true => (w, h, w * 3, 3),
+    };
+    let mut energy = vec![0u64; bs];
+    for line in (0..lines).step_by(31) {
+        let base = line * pitch;
+        for i in 1..len {
+            let (a, b) = (base + (i - 1) * step, base + i * step);
+            energy[i % bs] += (0..3)
+                .map(|c| raw[a + c].abs_diff(raw[b + c]) as u64)
+                .sum::<u64>();
         }
     }
-    writer.finish()
+    let best = (0..bs).max_by_key(|&p| (energy[p], p == 0)).unwrap_or(0);
+    if energy[best] > energy[0] * 2 {
+        best
+    } else {
+        0
+    }
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+/// `img` moved by `(-dx, -dy)` pixels, the uncovered edges repeating the
+/// nearest pixels.
+fn shift_image(img: &RgbImage, dx: i64, dy: i64) -> RgbImage {
+    let (w, h) = img.dimensions();
+    RgbImage::from_fn(w, h, |x, y| {
+        let sx = (x as i64 + dx).clamp(0, w as i64 - 1) as u32;
+        let sy = (y as i64 + dy).clamp(0, h as i64 - 1) as u32;
+        *img.get_pixel(sx, sy)
+    })
+}
 
-    #[test]
-    fn test_quantize_roundtrip() {
-        for levels in [2u8, 4, 8, 16] {
-            for v in 0..levels {
-                let pixel = quantize(v, levels);
-                let recovered = dequantize(pixel, levels);
-                assert_eq!(v, recovered, "levels={levels}, value={v}, pixel={pixel}");
-            }
+/// `img` moved back onto its block grid, when re-encoding shifted it by a
+/// pixel or so: if its header does not decode as it is, the shifts that put
+/// each [`edge_phase`] back at 0 are tried, nearest first, and the first
+/// under which the header decodes is kept. `None` if none is needed or
+/// helps.
+pub fn realign(img: &RgbImage, block_size: u8, levels: u8) -> Option<RgbImage> {
+    let decodes =
+        |img: &RgbImage| header::decode_header_triple(&decode_header_area(img, block_size, levels));
+    if block_size == 1 || decodes(img).is_ok() {
+        return None;
+    }
+    let bs = block_size as i64;
+    let shifts = |phase: usize| {
+        let mut shifts = match phase as i64 {
+            0 => vec![0],
+            p => vec![p, p - bs],
+        };
+        shifts.sort_by_key(|d| d.abs());
+        shifts
+    };
+    let (xs, ys) = (
+        shifts(edge_phase(img, block_size, false)),
+        shifts(edge_phase(img, block_size, true)),
+    );
+    if xs == [0] && ys == [0] {
+        return None;
+    }
+    ys.iter()
+        .flat_map(|&dy| xs.iter().map(move |&dx| (dx, dy)))
+        .map(|(dx, dy)| shift_image(img, dx, dy))
+        .find(|shifted| decodes(shifted).is_ok())
+}
+
+/// Image correction applied before reading blocks, chosen by the decode
+/// fallback ladder when a frame does not decode as-is.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Preprocess {
+    None,
+    /// 3x3 median filter per channel (removes speckle noise)
+    Median,
+    /// Stretch each channel's observed range to 0..255, relaxing the level
+    /// thresholds for washed-out (e.g. limited-range) video
+    Stretch,
+}
+
+impl Preprocess {
+    pub fn apply(self, img: RgbImage) -> RgbImage {
+        match self {
+            Preprocess::None => img,
+            Preprocess::Median => median_filter(&img),
+            Preprocess::Stretch => stretch_levels(&img),
         }
     }
 
-    #[test]
-    fn test_noise_tolerance() {
-        let levels = 4u8;
-        for v in 0..levels {
-            let pixel = quantize(v, levels);
-            // Add noise up to ±30
-            for noise in -30i16..=30 {
-                let noisy = (pixel as i16 + noise).clamp(0, 255) as u8;
-                let recovered = dequantize(noisy, levels);
-                assert_eq!(
-                    v, recovered,
-                    "levels={levels}, value={v}, pixel={pixel}, noise={noise}, noisy={noisy}"
-                );
+    /// [`Preprocess::apply`] to a borrowed frame, copied only if it changes.
+    pub fn apply_ref(self, img: &RgbImage) -> Cow<'_, RgbImage> {
+        match self {
+            Preprocess::None => Cow::Borrowed(img),
+            Preprocess::Median => Cow::Owned(median_filter(img)),
+            Preprocess::Stretch => Cow::Owned(stretch_levels(img)),
+        }
+    }
+}
+
+/// 3x3 median filter per channel; edge pixels use the clamped neighbourhood.
+pub fn median_filter(img: &RgbImage) -> RgbImage {
+    let (w, h) = img.dimensions();
+    RgbImage::from_fn(w, h, |x, y| {
+        let mut out = [0u8; 3];
+        for (c, v) in out.iter_mut().enumerate() {
+            let mut window = [0u8; 9];
+            let mut n = 0;
+            for ny in y.saturating_sub(1)..=(y + 1).min(h - 1) {
+                for nx in x.saturating_sub(1)..=(x + 1).min(w - 1) {
+                    window[n] = img.get_pixel(nx, ny)[c];
+                    n += 1;
+                }
             }
+            window[..n].sort_unstable();
+            *v = window[n / 2];
         }
+        Rgb(out)
+    })
+}
+
+/// Map each channel's 0.5th..99.5th percentile onto 0..255.
+pub fn stretch_levels(img: &RgbImage) -> RgbImage {
+    let total = img.width() as usize * img.height() as usize;
+    let mut ranges = [(0u8, 255u8); 3];
+    for (c, range) in ranges.iter_mut().enumerate() {
+        let mut hist = [0usize; 256];
+        for p in img.pixels() {
+            hist[p[c] as usize] += 1;
+        }
+        let cut = total / 200;
+        let percentile = |from_top: bool| {
+            let mut seen = 0;
+            for i in 0..256 {
+                let v = if from_top { 255 - i } else { i };
+                seen += hist[v];
+                if seen > cut {
+                    return v as u8;
+                }
+            }
+            if from_top {
+                255
+            } else {
+                0
+            }
+        };
+        *range = (percentile(false), percentile(true));
     }
 
-    #[test]
-    fn test_bit_roundtrip() {
-        let data = vec![0xDE, 0xAD, 0xBE, 0xEF];
-        let mut reader = BitReader::new(&data);
-        let mut writer = BitWriter::new();
-        for _ in 0..(data.len() * 8 / 2) {
-            let bits = reader.read_bits(2);
-            writer.write_bits(bits, 2);
+    let mut out = img.clone();
+    for p in out.pixels_mut() {
+        for (c, &(lo, hi)) in ranges.iter().enumerate() {
+            if hi > lo {
+                let v = (p[c].saturating_sub(lo)) as u32 * 255 / (hi - lo) as u32;
+                p[c] = v.min(255) as u8;
+            }
         }
-        assert_eq!(writer.finish(), data);
     }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
 
     #[test]
     fn test_frame_encode_decode_roundtrip() {
@@ -286,11 +883,259 @@ mod tests {
         );
 
         // Decode data
-        let decoded_data = decode_data_area(&img, &config);
+        let decoded_data = decode_data_area(&img, &config, config.channel_levels(), 0);
         assert_eq!(
             &decoded_data[..rs_data.len()],
             &rs_data[..],
             "data roundtrip failed"
         );
     }
+
+    #[test]
+    fn test_values_match_bit_stream() {
+        let data = crate::testutil::pattern(1001, 37, 256);
+        for levels in [[2; 3], [4; 3], [16; 3], [8; 3], [8, 4, 8]] {
+            let tables = levels.map(quantize_table);
+            let bits = levels.map(bits_for);
+            // Past the end of the data, and not a whole byte
+            let n = data.len() * 3 + 5;
+            let mut reader = BitReader::new(&data);
+            let one_by_one: Vec<u8> = (0..n)
+                .map(|i| tables[i % 3][reader.read_bits(bits[i % 3]) as usize])
+                .collect();
+            let values = quantize_values(&data, n, &tables, bits);
+            assert_eq!(values, one_by_one, "{levels:?}");
+
+            let tables = levels.map(dequantize_table);
+            let mut writer = BitWriter::new();
+            for (i, &v) in values.iter().enumerate() {
+                writer.write_bits(tables[i % 3][v as usize], bits[i % 3]);
+            }
+            assert_eq!(dequantize_values(&values, &tables, bits), writer.finish());
+        }
+    }
+
+    #[test]
+    fn test_channel_levels_roundtrip() {
+        let mut config = crate::config::FrameConfig::new(4, 8, 32, 30, 18).unwrap();
+        config.set_channel_levels([8, 4, 2]).unwrap();
+        let rs_data = crate::testutil::pattern(5000, 31, 251);
+        let img = encode_frame_to_image(&[0xAB; 64], &rs_data, &config);
+        // Six bits per block: 3 red, 2 green, 1 blue
+        let top = (HEADER_ROWS * 4) as u32;
+        let first = rs_data[0];
+        assert_eq!(img.get_pixel(0, top)[0], quantize(first >> 5, 8));
+        assert_eq!(img.get_pixel(0, top)[1], quantize(first >> 3 & 3, 4));
+        assert_eq!(img.get_pixel(0, top)[2], quantize(first >> 2 & 1, 2));
+        let decoded = decode_data_area(&img, &config, [8, 4, 2], 0);
+        assert_eq!(&decoded[..rs_data.len()], &rs_data[..]);
+        assert_eq!(&decode_header_area(&img, 4, 8)[..64], &[0xAB; 64]);
+    }
+
+    #[test]
+    fn test_hilbert_layout_roundtrip() {
+        let mut config = crate::config::FrameConfig::new(8, 4, 32, 30, 18).unwrap();
+        config.layout = Layout::Hilbert;
+        let rs_data = crate::testutil::pattern(255 * 40, 31, 251);
+        let mut img = encode_frame_to_image(&[0xAB; 64], &rs_data, &config);
+        assert_eq!(&decode_header_area(&img, 8, 4)[..64], &[0xAB; 64]);
+        assert_eq!(
+            &decode_data_area(&img, &config, [4; 3], 0)[..rs_data.len()],
+            &rs_data[..]
+        );
+
+        // Wipe a 64x64 pixel patch: no codeword loses more than a few bytes
+        let top = (HEADER_ROWS * 8) as u32 + 200;
+        for y in top..top + 64 {
+            for x in 1000..1064 {
+                img.put_pixel(x, y, Rgb([128, 128, 128]));
+            }
+        }
+        let decoded = decode_data_area(&img, &config, [4; 3], 0);
+        let worst = decoded
+            .chunks(255)
+            .zip(rs_data.chunks(255))
+            .map(|(a, b)| a.iter().zip(b).filter(|(x, y)| x != y).count())
+            .max();
+        assert!(worst.unwrap() <= 2, "worst codeword lost {worst:?}");
+    }
+
+    #[test]
+    fn test_pilots_fit_distorted_levels() {
+        let mut config = crate::config::FrameConfig::new(8, 4, 32, 30, 18).unwrap();
+        config.pilots = true;
+        let rs_data = crate::testutil::pattern(255 * 300, 31, 251);
+        let mut img = RgbImage::new(0, 0);
+        paint_frame(&mut img, &[0xAB; 64], &rs_data, &config, [4; 3], 5);
+        assert_eq!(
+            &decode_data_area(&img, &config, [4; 3], 5)[..rs_data.len()],
+            &rs_data[..]
+        );
+
+        // A 2.2 gamma on the left half, contrast halved on the right: fixed
+        // thresholds would misread levels 1 and 2 on the left, 0 and 3 on
+        // the right
+        let top = (HEADER_ROWS * 8) as u32;
+        for y in top..config.height {
+            for x in 0..config.width {
+                let p = img.get_pixel_mut(x, y);
+                for v in p.0.iter_mut() {
+                    *v = if x < config.width / 2 {
+                        (255.0 * (*v as f64 / 255.0).powf(2.2)) as u8
+                    } else {
+                        64 + *v / 2
+                    };
+                }
+            }
+        }
+        assert_eq!(
+            &decode_data_area(&img, &config, [4; 3], 5)[..rs_data.len()],
+            &rs_data[..]
+        );
+    }
+
+    #[test]
+    fn test_bin_centers_survive_contrast() {
+        for levels in [2u8, 4, 8, 16] {
+            for v in 0..levels {
+                let pixel = quantize_centered(v, levels);
+                assert_eq!(dequantize_centered(pixel, levels), v);
+                // Half a bin of margin to either side, even at the ends
+                let half = 128 / levels as u16;
+                assert!(pixel as u16 >= half && pixel as u16 + half <= 256);
+            }
+        }
+        assert_eq!(
+            [0, 1, 2, 3].map(|v| quantize_centered(v, 4)),
+            [32, 96, 160, 224]
+        );
+
+        // Shadows and highlights rolled off to a fifth outside 64..192, as
+        // a limiter would: levels 0 and 3 drift past the fixed thresholds,
+        // their bin centres hold
+        let rs_data = crate::testutil::pattern(255 * 300, 31, 251);
+        for bin_centers in [false, true] {
+            let mut config = crate::config::FrameConfig::new(8, 4, 32, 30, 18).unwrap();
+            config.bin_centers = bin_centers;
+            let mut img = encode_frame_to_image(&[0xAB; 64], &rs_data, &config);
+            for p in img.pixels_mut() {
+                for v in p.0.iter_mut() {
+                    *v = match *v {
+                        0..64 => 64 - (64 - *v) / 5,
+                        193.. => 192 + (*v - 192) / 5,
+                        _ => *v,
+                    };
+                }
+            }
+            let decoded = decode_data_area(&img, &config, [4; 3], 0);
+            assert_eq!(decoded[..rs_data.len()] == rs_data[..], bin_centers);
+        }
+    }
+
+    #[test]
+    fn test_crc_map_marks_erasures() {
+        // 128x173 blocks: 170 data rows of 64 codewords and one map row
+        let mut config = crate::config::FrameConfig::new(8, 4, 64, 30, 18).unwrap();
+        (config.width, config.height) = (1024, 173 * 8);
+        config.layout = Layout::Hilbert;
+        config.crc_map = true;
+        assert_eq!(config.data_rows(), HEADER_ROWS..172);
+        let data = crate::testutil::pattern(191 * 64, 31, 251);
+        let rs_data = crate::ecc::rs_encode(&data, 64, 191, 4);
+        let mut img = encode_frame_to_image(&[0xAB; 64], &rs_data, &config);
+        assert_eq!(
+            damaged_tiles(&img, &config, [4; 3], 0),
+            Some(vec![false; 6])
+        );
+
+        // Scramble the top left tile: too much for the RS blocks alone
+        let top = HEADER_ROWS as u32 * 8;
+        for y in top..top + 512 {
+            for x in 0..512 {
+                let v = (x / 8 * 37 + y / 8 * 11) as u8;
+                img.put_pixel(x, y, Rgb([v, v.wrapping_mul(3), !v]));
+            }
+        }
+        let damaged = damaged_tiles(&img, &config, [4; 3], 0).unwrap();
+        assert_eq!(damaged, [true, false, false, false, false, false]);
+        let area = decode_data_area(&img, &config, [4; 3], 0);
+        assert!(crate::ecc::rs_decode(&area, 64, 191, data.len(), 4).is_err());
+        let erasures = tile_erasures(&config, [4; 3], &damaged, 0);
+        let (decoded, _) =
+            crate::ecc::rs_decode_erased(&area, 64, 191, data.len(), &erasures, 4).unwrap();
+        assert_eq!(decoded, data);
+    }
+
+    #[test]
+    fn test_paint_frame_reuses_buffer() {
+        // 100x70 leaves pixels right of and below the last 8x8 block
+        let mut config = crate::config::FrameConfig::new(8, 2, 32, 30, 18).unwrap();
+        (config.width, config.height) = (100, 70);
+        let mut img = RgbImage::from_pixel(100, 70, Rgb([255, 255, 255]));
+        let ptr = img.as_ptr();
+        paint_frame(&mut img, &[0xAB; 16], &[0x5C; 64], &config, [2; 3], 0);
+        assert_eq!(
+            img,
+            encode_frame_to_image(&[0xAB; 16], &[0x5C; 64], &config)
+        );
+        assert_eq!(img.as_ptr(), ptr);
+        assert_eq!(*img.get_pixel(99, 69), Rgb([0, 0, 0]));
+    }
+
+    #[test]
+    fn test_block_medians() {
+        // Sorted (4x4) and counted (20x20) blocks; a quarter of each block
+        // is an outlier the median ignores
+        for bs in [4u32, 20] {
+            let img = RgbImage::from_fn(bs * 2, bs, |x, y| match (x / bs, y < bs / 4) {
+                (0, true) => Rgb([255, 0, 9]),
+                (0, false) => Rgb([100, 50, 9]),
+                _ => Rgb([7, 200, (x + y) as u8 % 2 * 40]),
+            });
+            let mut medians = Vec::new();
+            row_medians(&img, 0, bs, 2, |v| medians.push(v));
+            assert_eq!(medians, [100, 50, 9, 7, 200, 40], "block size {bs}");
+        }
+    }
+
+    #[test]
+    fn test_level_noise() {
+        let config = crate::config::FrameConfig::new(8, 2, 64, 30, 18).unwrap();
+        let mut img = encode_frame_to_image(&[], &[], &config);
+        assert_eq!(level_noise(&img, &config).max, 0);
+
+        // Brighten one data block from level 0 to 30
+        let top = (HEADER_ROWS * 8) as u32;
+        for y in top..top + 8 {
+            for x in 0..8 {
+                img.put_pixel(x, y, Rgb([30, 0, 0]));
+            }
+        }
+        let noise = level_noise(&img, &config);
+        assert_eq!(noise.max, 30);
+        assert!((noise.margin() - (1.0 - 30.0 / 127.5)).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_preprocess_fallbacks() {
+        // Limited-range video: levels squeezed into 16..235, so 4-level
+        // thresholds are off; stretching restores them
+        let squeezed = RgbImage::from_fn(64, 64, |x, _| {
+            let v = quantize((x % 4) as u8, 4) as u32;
+            Rgb([(16 + v * 219 / 255) as u8; 3])
+        });
+        let stretched = Preprocess::Stretch.apply(squeezed);
+        for x in 0..4 {
+            assert_eq!(
+                dequantize(stretched.get_pixel(x, 0)[0], 4),
+                x as u8,
+                "x={x}"
+            );
+        }
+
+        // A single speckle in a flat area is removed by the median filter
+        let mut speckled = RgbImage::from_pixel(8, 8, Rgb([0, 0, 0]));
+        speckled.put_pixel(4, 4, Rgb([255, 255, 255]));
+        assert_eq!(Preprocess::Median.apply(speckled).get_pixel(4, 4)[0], 0);
+    }
 }
@@ -1,123 +1,40 @@
-use crate::config::PROTOCOL_VERSION;
-use crate::error::{Result, VstorageError};
-
-pub const HEADER_SIZE: usize = 90;
-pub const MAGIC: &[u8; 4] = b"VSTR";
-
-/// Frame header containing metadata for one video frame.
-#[derive(Debug, Clone)]
-pub struct FrameHeader {
-    pub version: u8,
-    pub frame_number: u32,
-    pub total_frames: u32,
-    pub block_size: u8,
-    pub levels: u8,
-    pub file_size: u64,
-    pub data_length: u32,
-    pub ecc_len: u8,
-    pub rs_data_len: u16,
-    pub nonce: [u8; 12],
-    pub salt: [u8; 16],
-    pub data_sha256: [u8; 32],
+// The header layout, its CRC and the vote over its copies live in the
+// no_std codec; the set ids, which need randomness, here
+pub use crate::codec::{
+    cipher_context, decode_header_triple, decode_preamble, encode_header_copies,
+    encode_header_triple, encode_preamble, header_crc, header_size, vote_copies, FrameHeader,
+    HeaderError, HEADER_SIZE, HEADER_SIZE_V1, INDEX_PAYLOAD, KIND_DATA, KIND_INDEX, KIND_INTRO,
+    KIND_KEYS, KIND_PADDING, KIND_PIECES, KIND_SIGNATURE, MAGIC, MAX_HEADER_COPIES,
+    MAX_HEADER_ROWS, PREAMBLE_LEN, STREAMED_SIZE,
+};
+
+/// A fresh random set id (a version 4 UUID).
+pub fn new_set_id() -> [u8; 16] {
+    let mut id = [0u8; 16];
+    rand::fill(&mut id);
+    id[6] = (id[6] & 0x0f) | 0x40;
+    id[8] = (id[8] & 0x3f) | 0x80;
+    id
 }
 
-impl FrameHeader {
-    /// Serialize to HEADER_SIZE bytes (big-endian).
-    pub fn serialize(&self) -> [u8; HEADER_SIZE] {
-        let mut buf = [0u8; HEADER_SIZE];
-        buf[0..4].copy_from_slice(MAGIC);
-        buf[4] = self.version;
-        buf[5..9].copy_from_slice(&self.frame_number.to_be_bytes());
-        buf[9..13].copy_from_slice(&self.total_frames.to_be_bytes());
-        buf[13] = self.block_size;
-        buf[14] = self.levels;
-        buf[15..23].copy_from_slice(&self.file_size.to_be_bytes());
-        buf[23..27].copy_from_slice(&self.data_length.to_be_bytes());
-        buf[27] = self.ecc_len;
-        buf[28..30].copy_from_slice(&self.rs_data_len.to_be_bytes());
-        buf[30..42].copy_from_slice(&self.nonce);
-        buf[42..58].copy_from_slice(&self.salt);
-        buf[58..90].copy_from_slice(&self.data_sha256);
-        buf
-    }
-
-    /// Deserialize from bytes.
-    pub fn deserialize(buf: &[u8]) -> Result<Self> {
-        if buf.len() < HEADER_SIZE {
-            return Err(VstorageError::Header("buffer too short".into()));
-        }
-        if &buf[0..4] != MAGIC {
-            return Err(VstorageError::Header(format!(
-                "invalid magic: {:?}",
-                &buf[0..4]
-            )));
-        }
-        let version = buf[4];
-        if version != PROTOCOL_VERSION {
-            return Err(VstorageError::Header(format!(
-                "unsupported version: {version}"
-            )));
-        }
-        Ok(Self {
-            version,
-            frame_number: u32::from_be_bytes(buf[5..9].try_into().unwrap()),
-            total_frames: u32::from_be_bytes(buf[9..13].try_into().unwrap()),
-            block_size: buf[13],
-            levels: buf[14],
-            file_size: u64::from_be_bytes(buf[15..23].try_into().unwrap()),
-            data_length: u32::from_be_bytes(buf[23..27].try_into().unwrap()),
-            ecc_len: buf[27],
-            rs_data_len: u16::from_be_bytes(buf[28..30].try_into().unwrap()),
-            nonce: buf[30..42].try_into().unwrap(),
-            salt: buf[42..58].try_into().unwrap(),
-            data_sha256: buf[58..90].try_into().unwrap(),
-        })
-    }
-}
-
-/// Encode header with triple redundancy for error resilience.
-pub fn encode_header_triple(header: &FrameHeader) -> Vec<u8> {
-    let serialized = header.serialize();
-    let mut result = Vec::with_capacity(HEADER_SIZE * 3);
-    for _ in 0..3 {
-        result.extend_from_slice(&serialized);
-    }
-    result
-}
-
-/// Decode header from triple-redundant data using byte-level majority vote.
-pub fn decode_header_triple(data: &[u8]) -> Result<FrameHeader> {
-    if data.len() < HEADER_SIZE * 3 {
-        return Err(VstorageError::Header(
-            "header data too short for triple decode".into(),
-        ));
-    }
-
-    let h1 = &data[0..HEADER_SIZE];
-    let h2 = &data[HEADER_SIZE..HEADER_SIZE * 2];
-    let h3 = &data[HEADER_SIZE * 2..HEADER_SIZE * 3];
-
-    let mut voted = vec![0u8; HEADER_SIZE];
-    for i in 0..HEADER_SIZE {
-        voted[i] = majority_vote(h1[i], h2[i], h3[i]);
-    }
-
-    FrameHeader::deserialize(&voted)
-}
-
-fn majority_vote(a: u8, b: u8, c: u8) -> u8 {
-    if a == b || a == c {
-        a
-    } else if b == c {
-        b
-    } else {
-        a // no majority — return first
-    }
+/// `id` in the usual UUID form, `xxxxxxxx-xxxx-xxxx-xxxx-xxxxxxxxxxxx`.
+pub fn format_set_id(id: &[u8; 16]) -> String {
+    let hex: String = id.iter().map(|b| format!("{b:02x}")).collect();
+    format!(
+        "{}-{}-{}-{}-{}",
+        &hex[0..8],
+        &hex[8..12],
+        &hex[12..16],
+        &hex[16..20],
+        &hex[20..32]
+    )
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::codec::{Compression, Kdf, Layout, TokenMode};
+    use crate::config::PROTOCOL_VERSION;
 
     fn sample_header() -> FrameHeader {
         FrameHeader {
@@ -133,6 +50,24 @@ mod tests {
             nonce: [1; 12],
             salt: [2; 16],
             data_sha256: [3; 32],
+            kind: KIND_DATA,
+            payload: 7,
+            part: 1,
+            total_parts: 3,
+            set_id: new_set_id(),
+            kdf: Kdf::Scrypt,
+            token: TokenMode::WithPassword,
+            compression: Compression::Deflate(9),
+            data_levels: 2,
+            channel_levels: [4, 2, 2],
+            layout: Layout::Hilbert,
+            crc_map: true,
+            bottom_header: true,
+            banner: true,
+            barcode: true,
+            pilots: true,
+            bin_centers: true,
+            streamed: false,
         }
     }
 
@@ -148,6 +83,37 @@ mod tests {
         assert_eq!(h.nonce, h2.nonce);
         assert_eq!(h.salt, h2.salt);
         assert_eq!(h.data_sha256, h2.data_sha256);
+        assert_eq!(h.kind, h2.kind);
+        assert_eq!(h.payload, h2.payload);
+        assert_eq!(h.part, h2.part);
+        assert_eq!(h.total_parts, h2.total_parts);
+        assert_eq!(h.set_id, h2.set_id);
+        assert_eq!(h.kdf, h2.kdf);
+        assert_eq!(h2.token, TokenMode::WithPassword);
+        assert_eq!(h.compression, h2.compression);
+        assert_eq!(h2.channel_levels, [4, 2, 2]);
+        assert_eq!(h2.area_levels(), [2, 2, 2]);
+        assert_eq!(h2.layout, Layout::Hilbert);
+        assert!(h2.crc_map && h2.bottom_header && h2.banner && h2.barcode && h2.pilots);
+        assert!(h2.bin_centers && !h2.streamed);
+
+        // A streamed payload's chunks are sealed before its size is known
+        let streamed = FrameHeader {
+            streamed: true,
+            ..sample_header()
+        };
+        let h3 = FrameHeader::deserialize(&streamed.serialize()).unwrap();
+        assert!(h3.streamed);
+        assert_eq!(h3.channel_levels, [4, 2, 2]);
+        assert_eq!(
+            h3.cipher_context(),
+            cipher_context(STREAMED_SIZE, 0, h.payload, h.compression)
+        );
+        assert_ne!(h3.cipher_context(), h2.cipher_context());
+        assert_eq!(
+            format_set_id(&[0xab; 16]),
+            "abababab-abab-abab-abab-abababababab"
+        );
     }
 
     #[test]
@@ -156,9 +122,7 @@ mod tests {
         let mut triple = encode_header_triple(&h);
 
         // Corrupt the second copy
-        for i in HEADER_SIZE..HEADER_SIZE * 2 {
-            triple[i] = 0xFF;
-        }
+        triple[HEADER_SIZE..HEADER_SIZE * 2].fill(0xFF);
 
         let recovered = decode_header_triple(&triple).unwrap();
         assert_eq!(recovered.frame_number, h.frame_number);
@@ -166,10 +130,105 @@ mod tests {
         assert_eq!(recovered.data_sha256, h.data_sha256);
     }
 
+    #[test]
+    fn test_crc_rejects_bad_vote() {
+        let h = sample_header();
+        let clean = encode_header_triple(&h);
+
+        // No two copies agree on byte 50: the vote takes the first copy's,
+        // whose CRC fails, and the intact third copy is used instead
+        let mut triple = clean.clone();
+        triple[50] ^= 0x01;
+        triple[HEADER_SIZE + 50] ^= 0x02;
+        triple[HEADER_SIZE + 60] ^= 0x04;
+        let recovered = decode_header_triple(&triple).unwrap();
+        assert_eq!(recovered.data_sha256, h.data_sha256);
+        assert_eq!(recovered.rs_data_len, 223);
+        assert_eq!(vote_copies(&triple, 3)[..], h.serialize()[..]);
+
+        // Every copy damaged: rejected rather than trusted
+        triple[HEADER_SIZE * 2 + 50] ^= 0x08;
+        let err = decode_header_triple(&triple).unwrap_err();
+        assert!(err.to_string().contains("CRC"));
+        assert!(FrameHeader::deserialize(&vote_copies(&triple, 3)).is_err());
+    }
+
+    #[test]
+    fn test_copies_and_preamble() {
+        let h = sample_header();
+        let mut five = encode_header_copies(&h, 5);
+        // Two copies wrecked, differently
+        five[..HEADER_SIZE].fill(0xFF);
+        five[HEADER_SIZE * 3..HEADER_SIZE * 4].fill(0x11);
+        let voted = vote_copies(&five, 5);
+        assert_eq!(voted[..], h.serialize()[..]);
+        // One copy, cut short
+        assert_eq!(
+            vote_copies(&five[..10], 1),
+            [&[0xFF; 10][..], &[0; 110]].concat()
+        );
+
+        let mut preamble = encode_preamble(6, 7);
+        assert_eq!(decode_preamble(&preamble), Some((6, 7)));
+        preamble[0] ^= 1;
+        assert_eq!(decode_preamble(&preamble), Some((6, 7)));
+        assert_eq!(decode_preamble(&encode_preamble(6, 10)), None);
+        assert_eq!(decode_preamble(&[0; PREAMBLE_LEN]), None);
+    }
+
     #[test]
     fn test_invalid_magic() {
         let mut buf = [0u8; HEADER_SIZE];
         buf[0..4].copy_from_slice(b"XXXX");
         assert!(FrameHeader::deserialize(&buf).is_err());
     }
+
+    #[test]
+    fn test_version_1_header() {
+        // As the first release wrote it: 90 bytes and no CRC
+        let mut v1 = [0u8; HEADER_SIZE_V1];
+        v1[0..4].copy_from_slice(MAGIC);
+        v1[4] = 1;
+        v1[5..9].copy_from_slice(&3u32.to_be_bytes());
+        v1[9..13].copy_from_slice(&5u32.to_be_bytes());
+        (v1[13], v1[14]) = (2, 4);
+        v1[15..23].copy_from_slice(&1_000_000u64.to_be_bytes());
+        v1[23..27].copy_from_slice(&200_000u32.to_be_bytes());
+        v1[27] = 32;
+        v1[28..30].copy_from_slice(&223u16.to_be_bytes());
+        v1[30..42].fill(1);
+        v1[42..58].fill(2);
+        v1[58..90].fill(3);
+        let mut triple = v1.repeat(3);
+        // One damaged copy is outvoted, and the rows end in zeros
+        triple[HEADER_SIZE_V1 + 20] ^= 0xff;
+        triple.resize(HEADER_SIZE * 3, 0);
+        let h = decode_header_triple(&triple).unwrap();
+        assert_eq!((h.version, h.frame_number, h.total_frames), (1, 3, 5));
+        assert_eq!(
+            (h.file_size, h.data_length, h.rs_data_len),
+            (1_000_000, 200_000, 223)
+        );
+        assert_eq!(
+            (h.nonce, h.salt, h.data_sha256),
+            ([1; 12], [2; 16], [3; 32])
+        );
+        assert_eq!((h.kind, h.payload, h.total_parts), (KIND_DATA, 0, 1));
+        assert_eq!(
+            (h.kdf, h.compression, h.layout),
+            (Kdf::Argon2id, Compression::None, Layout::Raster)
+        );
+        assert_eq!((h.data_levels, h.channel_levels), (4, [4; 3]));
+        assert!(!h.crc_map && !h.streamed && h.cipher_context().is_empty());
+        assert_eq!(h.serialize()[..HEADER_SIZE_V1], v1);
+
+        // A version newer than this build
+        let mut h = sample_header();
+        h.version = PROTOCOL_VERSION + 1;
+        assert_eq!(header_size(h.version), None);
+        let err = decode_header_triple(&encode_header_triple(&h)).unwrap_err();
+        assert!(err
+            .to_string()
+            .contains(&format!("unsupported version: {}", h.version)));
+    }
 }
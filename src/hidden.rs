@@ -0,0 +1,89 @@
+use zeroize::Zeroizing;
+
+use crate::crypto::{self, FrameCipher, TAG_LEN};
+use crate::error::{Result, VstorageError};
+
+/// Base nonce of the data stream; the length block uses the zero nonce.
+const STREAM_NONCE: [u8; 12] = [1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0];
+
+/// Salt, then the sealed length of the data.
+const PREFIX_LEN: usize = 16 + 8 + TAG_LEN;
+
+/// Bytes [`padding`] needs to hide `len` bytes.
+pub fn sealed_len(len: usize) -> usize {
+    PREFIX_LEN + len + len.div_ceil(crypto::SEGMENT_LEN).max(1) * TAG_LEN
+}
+
+/// `size` bytes of random padding for the padding frames after a payload,
+/// with `hidden` (data and its own password) sealed at the start when given.
+///
+/// The sealed bytes are salt | length | [`crypto::seal_stream`] data, all
+/// indistinguishable from the random bytes around them, so padding that
+/// holds a second file looks the same as padding that does not: which of
+/// the two a password opens cannot be told from the video. `size` grows to
+/// fit the hidden data.
+pub fn padding(size: usize, hidden: Option<(&[u8], &str)>) -> Result<Vec<u8>> {
+    let mut out = Vec::new();
+    if let Some((data, password)) = hidden {
+        let mut salt = [0u8; 16];
+        rand::fill(&mut salt);
+        let key = crypto::derive_key(password, &salt);
+        out.extend_from_slice(&salt);
+        out.extend(
+            FrameCipher::from_key(&key, [0; 12])?
+                .encrypt_frame(0, &(data.len() as u64).to_be_bytes())?,
+        );
+        out.extend(crypto::seal_stream(
+            &FrameCipher::from_key(&key, STREAM_NONCE)?,
+            data,
+        )?);
+    }
+    let start = out.len();
+    out.resize(start.max(size), 0);
+    rand::fill(&mut out[start..]);
+    Ok(out)
+}
+
+/// The file hidden in `padding` under `password`.
+pub fn open(padding: &[u8], password: &str) -> Result<Zeroizing<Vec<u8>>> {
+    if padding.len() < PREFIX_LEN {
+        return Err(VstorageError::WrongPassword);
+    }
+    let salt: [u8; 16] = padding[..16].try_into().unwrap();
+    let key = crypto::derive_key(password, &salt);
+    let len = FrameCipher::from_key(&key, [0; 12])?
+        .decrypt_frame(0, &padding[16..PREFIX_LEN])
+        .map_err(|_| VstorageError::WrongPassword)?;
+    let len = u64::from_be_bytes(len[..].try_into().expect("sealed as 8 bytes")) as usize;
+    if len > padding.len() || sealed_len(len) > padding.len() {
+        return Err(VstorageError::Header("hidden payload truncated".into()));
+    }
+    crypto::open_stream(
+        &FrameCipher::from_key(&key, STREAM_NONCE)?,
+        &padding[PREFIX_LEN..sealed_len(len)],
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_hidden_in_padding() {
+        let sealed = padding(1000, Some((b"the real file", "inner"))).unwrap();
+        assert_eq!(sealed.len(), 1000);
+        assert_eq!(*open(&sealed, "inner").unwrap(), b"the real file");
+        assert!(matches!(
+            open(&sealed, "outer"),
+            Err(VstorageError::WrongPassword)
+        ));
+
+        let plain = padding(10, None).unwrap();
+        assert_eq!(plain.len(), 10);
+        assert!(open(&plain, "inner").is_err());
+        assert_eq!(
+            padding(0, Some((&[7u8; 100], "pw"))).unwrap().len(),
+            sealed_len(100)
+        );
+    }
+}
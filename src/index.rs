@@ -0,0 +1,218 @@
+use std::fmt;
+
+use crate::error::{Result, VstorageError};
+use crate::json::Json;
+
+/// Index without file hashes, as written before they were added.
+pub const INDEX_MAGIC_V1: &[u8; 4] = b"VIDX";
+pub const INDEX_MAGIC: &[u8; 4] = b"VID2";
+
+/// One payload stored in a video.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PayloadEntry {
+    pub payload: u16,
+    /// Original file name (may be empty when unknown)
+    pub name: String,
+    pub file_size: u64,
+    pub total_frames: u32,
+    /// Position (0-based) of the payload's first frame in the video
+    pub first_frame: u32,
+    /// SHA-256 of the file; only recorded for unencrypted payloads, since the
+    /// index itself is never encrypted
+    pub sha256: Option<[u8; 32]>,
+}
+
+/// Directory of the payloads in a video, written as an index frame after
+/// every append. The index frame with the highest generation is current.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct PayloadIndex {
+    pub entries: Vec<PayloadEntry>,
+}
+
+impl PayloadIndex {
+    pub fn find(&self, payload: u16) -> Option<&PayloadEntry> {
+        self.entries.iter().find(|e| e.payload == payload)
+    }
+
+    /// Next free payload id.
+    pub fn next_payload(&self) -> u16 {
+        self.entries
+            .iter()
+            .map(|e| e.payload + 1)
+            .max()
+            .unwrap_or(0)
+    }
+
+    pub fn to_json(&self) -> Json {
+        Json::Array(
+            self.entries
+                .iter()
+                .map(|e| {
+                    Json::object([
+                        ("payload", e.payload.into()),
+                        ("name", e.name.as_str().into()),
+                        ("size", e.file_size.into()),
+                        (
+                            "sha256",
+                            e.sha256.as_ref().map_or(Json::Null, |h| Json::hex(h)),
+                        ),
+                        ("first_frame", e.first_frame.into()),
+                        ("frames", e.total_frames.into()),
+                    ])
+                })
+                .collect(),
+        )
+    }
+
+    /// Serialize (big-endian): magic | count u16 | entries, each ending with
+    /// a has-hash byte and the hash.
+    pub fn serialize(&self) -> Vec<u8> {
+        let mut buf = Vec::new();
+        buf.extend_from_slice(INDEX_MAGIC);
+        buf.extend_from_slice(&(self.entries.len() as u16).to_be_bytes());
+        for e in &self.entries {
+            buf.extend_from_slice(&e.payload.to_be_bytes());
+            buf.extend_from_slice(&e.file_size.to_be_bytes());
+            buf.extend_from_slice(&e.total_frames.to_be_bytes());
+            buf.extend_from_slice(&e.first_frame.to_be_bytes());
+            buf.extend_from_slice(&(e.name.len() as u16).to_be_bytes());
+            buf.extend_from_slice(e.name.as_bytes());
+            match &e.sha256 {
+                Some(hash) => {
+                    buf.push(1);
+                    buf.extend_from_slice(hash);
+                }
+                None => buf.push(0),
+            }
+        }
+        buf
+    }
+
+    pub fn deserialize(buf: &[u8]) -> Result<Self> {
+        let hashes = buf.get(0..4) == Some(INDEX_MAGIC);
+        if buf.len() < 6 || !(hashes || &buf[0..4] == INDEX_MAGIC_V1) {
+            return Err(VstorageError::Header("invalid index magic".into()));
+        }
+        let count = u16::from_be_bytes([buf[4], buf[5]]) as usize;
+        let mut pos = 6;
+        let mut take = |n: usize| -> Result<&[u8]> {
+            let s = buf
+                .get(pos..pos + n)
+                .ok_or_else(|| VstorageError::Header("index truncated".into()))?;
+            pos += n;
+            Ok(s)
+        };
+
+        let mut entries = Vec::with_capacity(count);
+        for _ in 0..count {
+            let payload = u16::from_be_bytes(take(2)?.try_into().unwrap());
+            let file_size = u64::from_be_bytes(take(8)?.try_into().unwrap());
+            let total_frames = u32::from_be_bytes(take(4)?.try_into().unwrap());
+            let first_frame = u32::from_be_bytes(take(4)?.try_into().unwrap());
+            let name_len = u16::from_be_bytes(take(2)?.try_into().unwrap()) as usize;
+            let name = String::from_utf8_lossy(take(name_len)?).into_owned();
+            let sha256 = match hashes && take(1)?[0] == 1 {
+                true => Some(take(32)?.try_into().unwrap()),
+                false => None,
+            };
+            entries.push(PayloadEntry {
+                payload,
+                name,
+                file_size,
+                total_frames,
+                first_frame,
+                sha256,
+            });
+        }
+        Ok(Self { entries })
+    }
+}
+
+/// One line per payload: id, frame range, size, hash (`-` if not recorded)
+/// and name.
+impl fmt::Display for PayloadIndex {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, e) in self.entries.iter().enumerate() {
+            if i > 0 {
+                writeln!(f)?;
+            }
+            let frames = format!("{}-{}", e.first_frame + 1, e.first_frame + e.total_frames);
+            let hash = e.sha256.as_ref().map_or("-".into(), |h| {
+                h.iter().map(|b| format!("{b:02x}")).collect::<String>()
+            });
+            write!(
+                f,
+                "{:>5}  {frames:>13}  {:>12}  {hash:<64}  {}",
+                e.payload, e.file_size, e.name
+            )?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_index_roundtrip() {
+        let index = PayloadIndex {
+            entries: vec![
+                PayloadEntry {
+                    payload: 0,
+                    name: String::new(),
+                    file_size: 5_000_000,
+                    total_frames: 4,
+                    first_frame: 0,
+                    sha256: None,
+                },
+                PayloadEntry {
+                    payload: 1,
+                    name: "notes.txt".into(),
+                    file_size: 12,
+                    total_frames: 1,
+                    first_frame: 4,
+                    sha256: Some([9; 32]),
+                },
+            ],
+        };
+        let parsed = PayloadIndex::deserialize(&index.serialize()).unwrap();
+        assert_eq!(parsed, index);
+        assert_eq!(parsed.next_payload(), 2);
+        assert_eq!(parsed.find(1).unwrap().name, "notes.txt");
+    }
+
+    #[test]
+    fn test_index_truncated() {
+        let mut buf = PayloadIndex {
+            entries: vec![PayloadEntry {
+                payload: 0,
+                name: "a".into(),
+                file_size: 1,
+                total_frames: 1,
+                first_frame: 0,
+                sha256: None,
+            }],
+        }
+        .serialize();
+        buf.truncate(buf.len() - 1);
+        assert!(PayloadIndex::deserialize(&buf).is_err());
+    }
+
+    #[test]
+    fn test_index_v1_without_hashes() {
+        let mut buf = INDEX_MAGIC_V1.to_vec();
+        buf.extend_from_slice(&1u16.to_be_bytes());
+        buf.extend_from_slice(&3u16.to_be_bytes());
+        buf.extend_from_slice(&12u64.to_be_bytes());
+        buf.extend_from_slice(&1u32.to_be_bytes());
+        buf.extend_from_slice(&7u32.to_be_bytes());
+        buf.extend_from_slice(&1u16.to_be_bytes());
+        buf.push(b'x');
+        let index = PayloadIndex::deserialize(&buf).unwrap();
+        assert_eq!(index.entries[0].payload, 3);
+        assert_eq!(index.entries[0].first_frame, 7);
+        assert_eq!(index.entries[0].sha256, None);
+        assert_eq!(index.to_json().to_string(), "[{\"payload\":3,\"name\":\"x\",\"size\":12,\"sha256\":null,\"first_frame\":7,\"frames\":1}]");
+    }
+}
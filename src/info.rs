@@ -0,0 +1,220 @@
+use std::fmt;
+use std::path::Path;
+use std::time::Instant;
+
+use crate::cancel::CancelToken;
+use crate::config::{FrameConfig, Workspace};
+use crate::error::Result;
+use crate::header::FrameHeader;
+use crate::video::{self, StreamInfo};
+use crate::{collect, ecc, frame};
+
+/// What [`info`] learned about a video from its first frame.
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct VideoInfo {
+    pub header: FrameHeader,
+    pub config: FrameConfig,
+    /// Container stream properties (`None` if ffprobe is unavailable)
+    pub stream: Option<StreamInfo>,
+    /// Measured time to load and decode one frame on this machine
+    pub secs_per_frame: f64,
+}
+
+impl VideoInfo {
+    pub fn encrypted(&self) -> bool {
+        collect::is_encrypted(&self.header)
+    }
+
+    /// Encrypted with a password (rather than to public keys or a hardware
+    /// token alone).
+    pub fn needs_password(&self) -> bool {
+        self.encrypted()
+            && !collect::is_public_key(&self.header)
+            && self.header.token != crate::crypto::TokenMode::Only
+    }
+
+    /// Decode time for all frames of this video at the measured rate.
+    pub fn estimated_decode_secs(&self) -> f64 {
+        let frames = self
+            .stream
+            .as_ref()
+            .map_or(self.header.total_frames as usize, |s| s.frames);
+        self.secs_per_frame * frames as f64
+    }
+}
+
+impl fmt::Display for VideoInfo {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let h = &self.header;
+        writeln!(f, "Protocol version: {}", h.version)?;
+        if h.streamed && h.file_size == crate::header::STREAMED_SIZE {
+            writeln!(
+                f,
+                "Frames:           unknown (streamed; in the last data frame)"
+            )?;
+            writeln!(
+                f,
+                "File size:        unknown (streamed; in the last data frame)"
+            )?;
+        } else {
+            writeln!(f, "Frames:           {}", h.total_frames)?;
+            writeln!(f, "File size:        {} bytes", h.file_size)?;
+        }
+        writeln!(
+            f,
+            "Frame layout:     {}x{}, block_size={}, levels={}, ecc={} (RS({},{}))",
+            self.config.width,
+            self.config.height,
+            h.block_size,
+            match h.channel_levels {
+                [r, g, b] if r != g || g != b => format!("{r}/{g}/{b}"),
+                _ => h.levels.to_string(),
+            },
+            h.ecc_len,
+            h.rs_data_len as usize + h.ecc_len as usize,
+            h.rs_data_len
+        )?;
+        if collect::is_public_key(h) {
+            writeln!(
+                f,
+                "Encryption:       AES-256-GCM per frame (encrypted to public keys)"
+            )?;
+        } else if self.encrypted() {
+            let needs = match h.token {
+                crate::crypto::TokenMode::Off => "password",
+                crate::crypto::TokenMode::WithPassword => "password and hardware token",
+                crate::crypto::TokenMode::Only => "hardware token",
+            };
+            writeln!(
+                f,
+                "Encryption:       AES-256-GCM per frame ({needs} required, {} key)",
+                h.kdf
+            )?;
+        } else {
+            writeln!(f, "Encryption:       none")?;
+        }
+        if h.compression != crate::compress::Compression::None {
+            writeln!(
+                f,
+                "Compression:      {} (the file size above is compressed)",
+                h.compression
+            )?;
+        }
+        if h.total_parts > 1 {
+            writeln!(
+                f,
+                "Part:             {} of {} (set {})",
+                h.part + 1,
+                h.total_parts,
+                crate::header::format_set_id(&h.set_id)
+            )?;
+        }
+        if let Some(s) = &self.stream {
+            writeln!(
+                f,
+                "Stream:           {} {}, {} fps, {} frames",
+                s.codec, s.pix_fmt, s.fps, s.frames
+            )?;
+        }
+        write!(
+            f,
+            "Estimated decode: ~{:.0}s ({:.1} ms/frame here, plus frame extraction)",
+            self.estimated_decode_secs(),
+            self.secs_per_frame * 1000.0
+        )
+    }
+}
+
+/// Read the header of the first frame of `input` (after the intro frame,
+/// if any) and time decoding it. Needs no password: headers are stored in
+/// the clear. The frame is extracted in `workspace`.
+pub fn info(input: &Path, workspace: &Workspace) -> Result<VideoInfo> {
+    video::check_ffmpeg()?;
+    let temp_dir = video::temp_dir(workspace)?;
+    let png = temp_dir.path().join("first.png");
+    let cancel = CancelToken::new();
+    video::extract_frame(input, 0, &png, &cancel)?;
+
+    let mut start = Instant::now();
+    let mut img = video::load_frame(&png)?;
+    let (mut header, mut config) = collect::detect_config_from_frame(&img, None)?;
+    if header.kind == crate::header::KIND_INTRO {
+        video::extract_frame(input, 1, &png, &cancel)?;
+        start = Instant::now();
+        img = video::load_frame(&png)?;
+        (header, config) = collect::detect_config_from_frame(&img, None)?;
+    }
+    let data_bytes =
+        frame::decode_data_area(&img, &config, header.area_levels(), header.frame_number);
+    ecc::rs_decode(
+        &data_bytes,
+        config.ecc_len as usize,
+        config.rs_data_len(),
+        header.data_length as usize,
+        config.worker_threads(),
+    )?;
+    let secs_per_frame = start.elapsed().as_secs_f64();
+
+    Ok(VideoInfo {
+        header,
+        config,
+        stream: video::probe_video(input).ok(),
+        secs_per_frame,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::PROTOCOL_VERSION;
+    use crate::header;
+
+    #[test]
+    fn test_info_display() {
+        let config = FrameConfig::new(2, 4, 32, 30, 18).unwrap();
+        let info = VideoInfo {
+            header: FrameHeader {
+                version: PROTOCOL_VERSION,
+                frame_number: 0,
+                total_frames: 120,
+                block_size: 2,
+                levels: 4,
+                file_size: 1_000_000,
+                data_length: 0,
+                ecc_len: 32,
+                rs_data_len: 223,
+                nonce: [0; 12],
+                salt: [0; 16],
+                data_sha256: [0; 32],
+                kind: header::KIND_DATA,
+                payload: 0,
+                part: 0,
+                total_parts: 1,
+                set_id: [0; 16],
+                kdf: crate::crypto::Kdf::Argon2id,
+                token: crate::crypto::TokenMode::Off,
+                compression: crate::compress::Compression::None,
+                data_levels: 4,
+                channel_levels: [4; 3],
+                layout: crate::layout::Layout::Raster,
+                crc_map: false,
+                bottom_header: false,
+                banner: false,
+                barcode: false,
+                pilots: false,
+                bin_centers: false,
+                streamed: false,
+            },
+            config,
+            stream: None,
+            secs_per_frame: 0.05,
+        };
+        assert!(!info.encrypted());
+        assert!((info.estimated_decode_secs() - 6.0).abs() < 1e-9);
+        let text = info.to_string();
+        assert!(text.contains("Frames:           120"));
+        assert!(text.contains("Encryption:       none"));
+        assert!(!text.contains("Part:"));
+    }
+}
@@ -0,0 +1,74 @@
+use image::{Rgb, RgbImage};
+
+use crate::banner;
+use crate::collect;
+use crate::config::FrameConfig;
+use crate::header::FrameHeader;
+use crate::qr::QrCode;
+
+/// Where the decoder lives, linked by the intro frame's QR code.
+pub const DECODER_URL: &str = "https://github.com/levish0/Vstorage";
+
+/// Lines of instructions on the intro frame of a video whose first data
+/// frame has header `hdr`.
+pub fn lines(hdr: &FrameHeader) -> Vec<String> {
+    let mut lines = vec![
+        format!("VSTORAGE ARCHIVE - PROTOCOL V{}", hdr.version),
+        "THIS VIDEO HOLDS A FILE STORED AS BLOCKS OF COLOR.".into(),
+        format!(
+            "THE FILE IS {} BYTES IN {} FRAMES.",
+            hdr.file_size, hdr.total_frames
+        ),
+        "TO RESTORE IT, GET THE DECODER FROM THE LINK BELOW AND RUN".into(),
+        "    VSTORAGE DECODE VIDEO.MP4 OUTPUT.BIN".into(),
+    ];
+    if collect::is_encrypted(hdr) {
+        lines.push("IT IS ENCRYPTED - THE DECODER ASKS FOR THE PASSWORD.".into());
+    }
+    lines.push(String::new());
+    lines.push("GITHUB.COM/LEVISH0/VSTORAGE".into());
+    lines
+}
+
+/// Draw the instructions and a QR code of [`DECODER_URL`] in black on white
+/// over the data area of `img`, a frame already painted with its header.
+pub fn paint(img: &mut RgbImage, config: &FrameConfig, hdr: &FrameHeader) {
+    let bs = config.block_size as u32;
+    let rows = config.data_rows();
+    let (top, bottom) = (rows.start as u32 * bs, rows.end as u32 * bs);
+    for y in top..bottom {
+        for x in 0..config.width {
+            img.put_pixel(x, y, Rgb([255, 255, 255]));
+        }
+    }
+
+    // About 80 characters across
+    let scale = (config.width / 480).max(1);
+    let black = Rgb([0, 0, 0]);
+    let mut y = top + 6 * scale;
+    for line in lines(hdr) {
+        banner::draw_text(img, (6 * scale, y), scale, &line, black);
+        y += 10 * scale;
+    }
+
+    // Four modules of quiet zone on every side
+    let qr = QrCode::encode(DECODER_URL.as_bytes()).expect("the URL fits a QR code");
+    let module = scale * 2;
+    let (x0, y0) = (6 * scale + 4 * module, y + 4 * module);
+    if y0 + qr.size as u32 * module > bottom {
+        return;
+    }
+    for qy in 0..qr.size {
+        for qx in 0..qr.size {
+            if !qr.get(qx, qy) {
+                continue;
+            }
+            for dy in 0..module {
+                for dx in 0..module {
+                    let (px, py) = (x0 + qx as u32 * module + dx, y0 + qy as u32 * module + dy);
+                    img.put_pixel(px, py, black);
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,205 @@
+use std::sync::{Arc, Mutex};
+
+use crate::error::{Result, VstorageError};
+
+/// Bytes of one RS codeword, the unit [`interleave`] spreads.
+const CODEWORD_LEN: usize = 255;
+
+pub use crate::codec::Layout;
+
+impl std::str::FromStr for Layout {
+    type Err = VstorageError;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s.to_ascii_lowercase().as_str() {
+            "raster" => Ok(Self::Raster),
+            "hilbert" => Ok(Self::Hilbert),
+            other => Err(VstorageError::Config(format!(
+                "unknown layout '{other}' (expected raster or hilbert)"
+            ))),
+        }
+    }
+}
+
+impl std::fmt::Display for Layout {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            Self::Raster => "raster",
+            Self::Hilbert => "hilbert",
+        })
+    }
+}
+
+/// Width, height and block order of a curve.
+type Curve = (usize, usize, Arc<[u32]>);
+
+/// The last Hilbert curve a [`crate::config::FrameConfig`] laid out, shared
+/// by its clones, as the frames of one video all use the same one.
+#[derive(Clone, Default)]
+pub(crate) struct CurveCache(Arc<Mutex<Option<Curve>>>);
+
+impl CurveCache {
+    /// [`hilbert_order`] of a `width` x `height` area, built again only when
+    /// the size changes.
+    pub(crate) fn order(&self, width: usize, height: usize) -> Arc<[u32]> {
+        let mut cached = self.0.lock().unwrap_or_else(|e| e.into_inner());
+        if let Some((w, h, curve)) = &*cached {
+            if (*w, *h) == (width, height) {
+                return curve.clone();
+            }
+        }
+        let order = hilbert_order(width, height);
+        *cached = Some((width, height, order.clone()));
+        order
+    }
+}
+
+impl std::fmt::Debug for CurveCache {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("CurveCache").finish_non_exhaustive()
+    }
+}
+
+/// Raster indices (`y * width + x`) of a `width` x `height` area of blocks,
+/// in the order a generalized Hilbert curve visits them. Any rectangle is
+/// covered, each step moving to a neighbouring block (diagonally at most
+/// once per odd-sized split).
+pub fn hilbert_order(width: usize, height: usize) -> Arc<[u32]> {
+    let mut order = Vec::with_capacity(width * height);
+    let (w, h) = (width as i64, height as i64);
+    if w >= h {
+        curve(&mut order, width, (0, 0), (w, 0), (0, h));
+    } else {
+        curve(&mut order, width, (0, 0), (0, h), (w, 0));
+    }
+    order.into()
+}
+
+/// Visit the rectangle at `(x, y)` spanned by the major axis `a` and the
+/// minor axis `b`, after Červený's gilbert2d.
+fn curve(order: &mut Vec<u32>, width: usize, (x, y): (i64, i64), a: (i64, i64), b: (i64, i64)) {
+    let (w, h) = ((a.0 + a.1).abs(), (b.0 + b.1).abs());
+    let da = (a.0.signum(), a.1.signum());
+    let db = (b.0.signum(), b.1.signum());
+    let mut visit = |(x, y): (i64, i64), d: (i64, i64), n: i64| {
+        for i in 0..n {
+            order.push(((y + i * d.1) as usize * width + (x + i * d.0) as usize) as u32);
+        }
+    };
+    if h == 1 {
+        return visit((x, y), da, w);
+    }
+    if w == 1 {
+        return visit((x, y), db, h);
+    }
+    let mut a2 = (a.0.div_euclid(2), a.1.div_euclid(2));
+    let mut b2 = (b.0.div_euclid(2), b.1.div_euclid(2));
+    let (w2, h2) = ((a2.0 + a2.1).abs(), (b2.0 + b2.1).abs());
+    if 2 * w > 3 * h {
+        // Long and thin: split along the major axis
+        if w2 % 2 == 1 && w > 2 {
+            a2 = (a2.0 + da.0, a2.1 + da.1);
+        }
+        curve(order, width, (x, y), a2, b);
+        curve(
+            order,
+            width,
+            (x + a2.0, y + a2.1),
+            (a.0 - a2.0, a.1 - a2.1),
+            b,
+        );
+    } else {
+        if h2 % 2 == 1 && h > 2 {
+            b2 = (b2.0 + db.0, b2.1 + db.1);
+        }
+        curve(order, width, (x, y), b2, a2);
+        curve(
+            order,
+            width,
+            (x + b2.0, y + b2.1),
+            a,
+            (b.0 - b2.0, b.1 - b2.1),
+        );
+        curve(
+            order,
+            width,
+            (
+                x + (a.0 - da.0) + (b2.0 - db.0),
+                y + (a.1 - da.1) + (b2.1 - db.1),
+            ),
+            (-b2.0, -b2.1),
+            (a2.0 - a.0, a2.1 - a.1),
+        );
+    }
+}
+
+/// Spread `codewords` RS codewords byte by byte: byte `j` of codeword `i`
+/// goes to `j * codewords + i`. Codewords missing from `data` are zeros.
+pub fn interleave(data: &[u8], codewords: usize) -> Vec<u8> {
+    let mut out = vec![0u8; codewords * CODEWORD_LEN];
+    for (i, word) in data.chunks(CODEWORD_LEN).take(codewords).enumerate() {
+        for (j, &byte) in word.iter().enumerate() {
+            out[j * codewords + i] = byte;
+        }
+    }
+    out
+}
+
+/// Undo [`interleave`], returning the `codewords` codewords in order.
+pub fn deinterleave(data: &[u8], codewords: usize) -> Vec<u8> {
+    let mut out = vec![0u8; codewords * CODEWORD_LEN];
+    for (i, word) in out.chunks_exact_mut(CODEWORD_LEN).enumerate() {
+        for (j, byte) in word.iter_mut().enumerate() {
+            *byte = data.get(j * codewords + i).copied().unwrap_or(0);
+        }
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_curve_visits_every_block() {
+        for (w, h) in [(480, 268), (5, 9), (1, 7), (1920, 1078)] {
+            let order = hilbert_order(w, h);
+            let mut seen = vec![false; w * h];
+            for &i in order.iter() {
+                assert!(!seen[i as usize], "{w}x{h}: {i} twice");
+                seen[i as usize] = true;
+            }
+            assert!(seen.iter().all(|&s| s), "{w}x{h}");
+            // Consecutive blocks touch, so a patch is a few runs of the curve
+            for step in order.windows(2) {
+                let (a, b) = (step[0] as usize, step[1] as usize);
+                let (dx, dy) = ((a % w).abs_diff(b % w), (a / w).abs_diff(b / w));
+                assert!(dx <= 1 && dy <= 1, "{w}x{h}: {a} -> {b}");
+            }
+        }
+    }
+
+    #[test]
+    fn test_interleave_spreads_bursts() {
+        let codewords = 10;
+        let data: Vec<u8> = (0..codewords * CODEWORD_LEN)
+            .map(|i| (i / 7) as u8)
+            .collect();
+        let mut spread = interleave(&data, codewords);
+        assert_eq!(deinterleave(&spread, codewords), data);
+
+        // A burst of 50 bytes costs each codeword 5
+        spread[100..150].fill(0xEE);
+        let back = deinterleave(&spread, codewords);
+        for (word, original) in back.chunks(CODEWORD_LEN).zip(data.chunks(CODEWORD_LEN)) {
+            let errors = word.iter().zip(original).filter(|(a, b)| a != b).count();
+            assert!(errors <= 5);
+        }
+
+        // Short input is padded with zero codewords
+        assert_eq!(
+            &deinterleave(&interleave(&data[..300], codewords), codewords)[..300],
+            &data[..300]
+        );
+    }
+}
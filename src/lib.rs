@@ -1,9 +1,117 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+extern crate alloc;
+
+#[cfg(feature = "pipeline")]
+pub mod append;
+#[cfg(feature = "pipeline")]
+pub mod archive;
+#[cfg(feature = "std")]
+pub mod audio;
+#[cfg(feature = "pipeline")]
+pub mod backup;
+#[cfg(feature = "std")]
+pub mod banner;
+#[cfg(feature = "std")]
+pub mod barcode;
+#[cfg(feature = "pipeline")]
+pub mod bench;
+#[cfg(feature = "pipeline")]
+pub mod cancel;
+#[cfg(feature = "pipeline")]
+pub mod capacity;
+#[cfg(feature = "std")]
+pub mod cdc;
+#[cfg(feature = "pipeline")]
+pub mod checkpoint;
+// Only core and alloc, so it can be built for no_std targets
+#[deny(clippy::std_instead_of_core, clippy::std_instead_of_alloc)]
+pub mod codec;
+#[cfg(feature = "std")]
+pub mod collect;
+#[cfg(feature = "std")]
+pub mod compress;
+#[cfg(feature = "std")]
 pub mod config;
+#[cfg(feature = "pipeline")]
+pub mod config_file;
+#[cfg(feature = "std")]
+pub mod crcmap;
+#[cfg(feature = "std")]
 pub mod crypto;
+#[cfg(feature = "pipeline")]
 pub mod decode;
+#[cfg(feature = "pipeline")]
+pub mod diff;
+#[cfg(feature = "pipeline")]
+pub mod download;
+#[cfg(feature = "std")]
 pub mod ecc;
+#[cfg(feature = "pipeline")]
 pub mod encode;
+#[cfg(feature = "std")]
 pub mod error;
+#[cfg(feature = "std")]
 pub mod frame;
+#[cfg(feature = "std")]
 pub mod header;
+#[cfg(feature = "std")]
+pub mod hidden;
+#[cfg(feature = "std")]
+pub mod index;
+#[cfg(feature = "pipeline")]
+pub mod info;
+#[cfg(feature = "std")]
+pub mod intro;
+#[cfg(feature = "std")]
+pub mod json;
+#[cfg(feature = "std")]
+pub mod layout;
+#[cfg(feature = "std")]
+pub mod memory;
+#[cfg(feature = "pipeline")]
+pub(crate) mod mmap;
+#[cfg(all(feature = "pipeline", target_os = "linux"))]
+pub mod mount;
+#[cfg(feature = "pipeline")]
+pub mod pack;
+#[cfg(feature = "std")]
+pub mod pieces;
+#[cfg(feature = "std")]
+pub mod pilot;
+#[cfg(feature = "pipeline")]
+pub mod preflight;
+#[cfg(feature = "pipeline")]
+pub mod progress;
+#[cfg(feature = "pipeline")]
+pub mod prompt;
+#[cfg(feature = "std")]
+pub mod qr;
+#[cfg(feature = "std")]
+pub mod recipient;
+#[cfg(feature = "std")]
+pub mod recovery;
+#[cfg(feature = "pipeline")]
+pub mod rekey;
+#[cfg(feature = "pipeline")]
+pub mod s3;
+#[cfg(feature = "pipeline")]
+pub mod selftest;
+#[cfg(feature = "std")]
+pub mod signature;
+#[cfg(feature = "pipeline")]
+pub mod simulate;
+#[cfg(feature = "pipeline")]
+pub mod stats;
+#[cfg(feature = "pipeline")]
+pub mod stream;
+#[cfg(feature = "pipeline")]
+pub mod task;
+#[cfg(all(test, feature = "std"))]
+mod testutil;
+#[cfg(feature = "pipeline")]
+pub mod tune;
+#[cfg(feature = "pipeline")]
+pub mod verify;
+#[cfg(feature = "pipeline")]
 pub mod video;
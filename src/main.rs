@@ -2,6 +2,11 @@ use std::path::Path;
 use std::process;
 /// sinoka
 use clap::{Parser, Subcommand};
+use tracing_subscriber::filter::LevelFilter;
+use tracing_subscriber::layer::{Layer, SubscriberExt};
+use tracing_subscriber::util::SubscriberInitExt;
+use std::sync::Arc;
+use vstorage::config_file::ConfigFile;
 
 #[derive(Parser)]
 #[command(name = "vstorage")]
@@ -9,89 +14,1735 @@ use clap::{Parser, Subcommand};
 struct Cli {
     #[command(subcommand)]
     command: Commands,
+    /// Print debug diagnostics
+    #[arg(short, long, global = true, conflicts_with = "quiet")]
+    verbose: bool,
+    /// Only print warnings and errors, and no progress bars (-qq: errors only)
+    #[arg(short, long, global = true, action = clap::ArgAction::Count)]
+    quiet: u8,
+    /// Config file with default settings [default: ~/.config/vstorage/config.toml]
+    #[arg(long, global = true, value_name = "FILE")]
+    config: Option<String>,
+    /// Read the password from this environment variable when -p is not
+    /// given
+    #[arg(
+        long,
+        global = true,
+        value_name = "VAR",
+        conflicts_with = "password_file"
+    )]
+    password_env: Option<String>,
+    /// Read the password from the first line of this file (or a descriptor
+    /// such as /dev/fd/3) when -p is not given
+    #[arg(long, global = true, value_name = "FILE")]
+    password_file: Option<std::path::PathBuf>,
+    /// Hardware token: a command answering the video's salt, given in hex in
+    /// $VSTORAGE_CHALLENGE (e.g. 'ykchalresp -2 -x $VSTORAGE_CHALLENGE');
+    /// its answer goes into the key, so decoding also needs the token
+    #[arg(long, global = true, value_name = "COMMAND")]
+    token_command: Option<String>,
+    /// Encrypt with the token of --token-command alone, without a password
+    #[arg(long, global = true)]
+    token_only: bool,
+    /// Format of the temporary frames handed to and read from ffmpeg (png,
+    /// ppm or bmp); ppm and bmp are faster but take more disk space
+    /// [default: png]
+    #[arg(long, global = true, value_name = "FORMAT")]
+    intermediate: Option<vstorage::config::Intermediate>,
+    /// Keep the frame images waiting for ffmpeg under this size (K/M/G/T
+    /// suffixes) by handing it fewer frames at a time
+    #[arg(long, global = true, value_name = "BYTES", value_parser = parse_size)]
+    max_temp: Option<u64>,
+    /// Put work directories and extracted frames here instead of next to
+    /// the output and in the system temp directory
+    #[arg(long, global = true, value_name = "DIR")]
+    work_dir: Option<std::path::PathBuf>,
+    /// Leave the frame images and work directory on disk when done
+    #[arg(long, global = true)]
+    keep_frames: bool,
+    /// Use at most this many threads, for error correction, decryption and
+    /// ffmpeg [default: one per core]
+    #[arg(long, global = true, value_name = "N", value_parser = clap::value_parser!(u16).range(1..))]
+    threads: Option<u16>,
+    /// Write progress, ffmpeg progress and messages as JSON lines to stderr
+    /// (in place of progress bars), or with =PATH to a file or named pipe
+    #[arg(
+        long,
+        global = true,
+        value_name = "PATH",
+        num_args = 0..=1,
+        require_equals = true,
+        default_missing_value = "-"
+    )]
+    progress_json: Option<std::path::PathBuf>,
 }
 
 #[derive(Subcommand)]
 enum Commands {
     /// Encode a file into a video
     Encode {
-        /// Input file path
+        /// Input file path ("-" for stdin)
         #[arg(short, long)]
-        input: String,
+        input: Option<String>,
         /// Output video path (.mp4)
         #[arg(short, long)]
-        output: String,
+        output: Option<String>,
+        /// Input and output paths (alternative to -i/-o)
+        #[arg(value_name = "PATH", num_args = 0..=2)]
+        paths: Vec<String>,
         /// Encryption password (omit for no encryption)
         #[arg(short, long)]
         password: Option<String>,
-        /// Pixel block size
-        #[arg(long, default_value = "8")]
-        block_size: u8,
-        /// Quantization levels per channel (power of 2)
-        #[arg(long, default_value = "2")]
-        levels: u8,
-        /// Video frame rate
-        #[arg(long, default_value = "30")]
-        fps: u32,
-        /// FFmpeg CRF quality (lower = better)
-        #[arg(long, default_value = "18")]
-        crf: u8,
-        /// Reed-Solomon ECC parity bytes
-        #[arg(long, default_value = "64")]
-        ecc: u8,
+        /// Ask for the password on the terminal, with confirmation, instead
+        /// of passing it with -p
+        #[arg(short, long, conflicts_with_all = ["password", "recipient"])]
+        encrypt: bool,
+        /// Encrypt to this public key (from `keygen`) instead; repeat for
+        /// several recipients
+        #[arg(short, long, value_name = "PUBLIC_KEY", conflicts_with = "password")]
+        recipient: Vec<vstorage::recipient::Recipient>,
+        /// Start from a named preset: archival, youtube or camera
+        #[arg(long)]
+        preset: Option<vstorage::config::Preset>,
+        /// Pixel block size [default: 8]
+        #[arg(long)]
+        block_size: Option<u8>,
+        /// Quantization levels per channel (power of 2) [default: 2]
+        #[arg(long)]
+        levels: Option<u8>,
+        /// Video frame rate [default: 30]
+        #[arg(long)]
+        fps: Option<u32>,
+        /// FFmpeg CRF quality (lower = better) [default: 18]
+        #[arg(long)]
+        crf: Option<u8>,
+        /// Reed-Solomon ECC parity bytes [default: 64]
+        #[arg(long)]
+        ecc: Option<u8>,
+        /// Video codec (h264 or vp9) [default: h264]
+        #[arg(long)]
+        codec: Option<vstorage::config::VideoCodec>,
+        /// Target bitrate in kbit/s; enables two-pass encoding instead of CRF
+        #[arg(long)]
+        target_bitrate: Option<u32>,
+        /// Encode as yuv420p (what most platforms force) and self-check the output
+        #[arg(long)]
+        yuv420p: bool,
+        /// Draw the frames holding metadata (piece map, keys, signature) with
+        /// fewer levels than the data frames, so they survive more loss
+        #[arg(long, value_name = "LEVELS")]
+        metadata_levels: Option<u8>,
+        /// Logical rows of the header area [default: 2]
+        #[arg(long, value_name = "ROWS")]
+        header_rows: Option<usize>,
+        /// Copies of the header in the header area [default: 3]
+        #[arg(long, value_name = "COPIES")]
+        header_copies: Option<usize>,
+        /// Separate levels for the red, green and blue channels of the data
+        /// area (e.g. 8/4/4); replaces --levels
+        #[arg(
+            long,
+            value_name = "R/G/B",
+            value_parser = parse_channel_levels,
+            conflicts_with_all = ["levels", "auto"]
+        )]
+        channel_levels: Option<[u8; 3]>,
+        /// Order of the data on the blocks: raster, or hilbert to spread
+        /// damaged patches over many RS blocks [default: raster]
+        #[arg(long)]
+        layout: Option<vstorage::layout::Layout>,
+        /// Add a map of CRCs per 64x64-block tile below the data area, so
+        /// damaged tiles are decoded as erasures and shown by verify
+        #[arg(long)]
+        crc_map: bool,
+        /// Repeat the header in the bottom rows of each frame, so a frame
+        /// cropped or covered at the top still decodes
+        #[arg(long)]
+        bottom_header: bool,
+        /// Draw a line of text in each frame saying what the video is and
+        /// where to find the decoder
+        #[arg(long)]
+        banner: bool,
+        /// Draw the frame number as a barcode down the right edge, so frames
+        /// whose header is unreadable still find their place
+        #[arg(long)]
+        barcode: bool,
+        /// Scatter blocks of known levels over the data area, so decoding
+        /// adapts to level drift, gamma shifts and blur across the frame
+        #[arg(long)]
+        pilots: bool,
+        /// Draw the data levels at the centres of their bins (32, 96, 160
+        /// and 224 at 4 levels), leaving margin for codecs that clip or roll
+        /// off the darkest and brightest values
+        #[arg(long)]
+        bin_centers: bool,
+        /// Start the video with a frame of instructions and a QR code
+        /// linking to the decoder, which decoding skips
+        #[arg(long)]
+        intro: bool,
+        /// Key derivation for the password (argon2id or scrypt) [default: argon2id]
+        #[arg(long)]
+        kdf: Option<vstorage::crypto::Kdf>,
+        /// Compress the file first: lz4, zstd[:1-22], deflate[:0-9] or xz[:0-9] [default: none]
+        #[arg(long, value_name = "ALGO[:LEVEL]")]
+        compress: Option<vstorage::compress::Compression>,
+        /// Extra file to store in the audio track (FSK side channel)
+        #[arg(long)]
+        audio_data: Option<String>,
+        /// Split into several videos of at most this size (e.g. 2G, 500M)
+        #[arg(long, value_name = "BYTES", value_parser = parse_size)]
+        max_output_size: Option<u64>,
+        /// Split into several videos of at most this duration (e.g. 900, 15m, 1h)
+        #[arg(long, value_name = "DURATION", value_parser = parse_duration)]
+        max_duration: Option<u64>,
+        /// Add this many bytes of random padding frames after the file
+        /// (e.g. 10M), hiding its size
+        #[arg(long, value_name = "BYTES", value_parser = parse_size)]
+        pad: Option<u64>,
+        /// Also store this file, hidden in the padding under its own password
+        #[arg(long, value_name = "FILE")]
+        hidden: Option<String>,
+        /// Password of the hidden file (asked on the terminal if not given)
+        #[arg(long, value_name = "PASSWORD", requires = "hidden")]
+        hidden_pass: Option<String>,
+        /// Sign the file with this signing key (from `keygen --signing`)
+        #[arg(long, value_name = "FILE")]
+        sign: Option<String>,
+        /// Derive the salt, nonce and set id from --seed instead of at
+        /// random, so the same file gives bit-identical frames on any machine
+        /// (and anyone can tell two such videos hold the same file)
+        #[arg(
+            long,
+            requires = "seed",
+            conflicts_with_all = ["recipient", "pad", "hidden", "auto"]
+        )]
+        deterministic: bool,
+        /// Seed of a --deterministic encode, in hex
+        #[arg(long, value_name = "HEX", requires = "deterministic")]
+        seed: Option<String>,
+        /// Continue an interrupted encode from its checkpoint
+        #[arg(long)]
+        resume: bool,
+        /// Memory-map the input instead of reading it up front; the file
+        /// must not be truncated or written until the encode finishes
+        #[arg(long)]
+        mmap: bool,
+        /// Encode stdin ("-") as it arrives, without knowing its length or
+        /// holding it in memory; the video can only be decoded in full
+        #[arg(
+            long,
+            conflicts_with_all = [
+                "intro", "compress", "audio_data", "max_output_size", "max_duration",
+                "pad", "hidden", "sign", "deterministic", "resume", "mmap"
+            ]
+        )]
+        stream: bool,
+        /// Probe the codec first and use the densest block size, levels, ECC
+        /// and CRF that survive it
+        #[arg(long, conflicts_with_all = ["resume", "block_size", "levels", "ecc"])]
+        auto: bool,
+        /// Share of each RS block's correction capacity that `--auto` keeps
+        /// unused in its probes
+        #[arg(
+            long,
+            value_name = "FRACTION",
+            default_value_t = 0.5,
+            requires = "auto"
+        )]
+        auto_margin: f64,
+        /// Print the result as JSON on stdout, without progress bars
+        #[arg(long)]
+        json: bool,
     },
     /// Decode a video back into the original file
     Decode {
+        /// Input video path (.mp4), or a directory of its frames as images;
+        /// repeat for videos holding several parts
+        #[arg(short, long)]
+        input: Vec<String>,
+        /// Input videos, in order (alternative to -i); without -o the last
+        /// one is the output path
+        #[arg(conflicts_with = "input")]
+        inputs: Vec<String>,
+        /// Download the video with yt-dlp (best quality stream) and decode it
+        #[arg(long, value_name = "URL", conflicts_with = "input")]
+        from_url: Option<String>,
+        /// Output file path ("-" for stdout)
+        #[arg(short, long)]
+        output: Option<String>,
+        /// Decryption password (omit if not encrypted)
+        #[arg(short, long)]
+        password: Option<String>,
+        /// Identity file (from `keygen`) for a video encrypted to recipients
+        #[arg(long, value_name = "FILE")]
+        identity: Option<String>,
+        /// Fail unless the file is signed by this verify key (vssig1...)
+        #[arg(long, value_name = "KEY", conflicts_with = "range")]
+        verify_key: Option<vstorage::signature::VerifyKey>,
+        /// Write the audio side channel payload to this path
+        #[arg(long)]
+        audio_output: Option<String>,
+        /// Decode only LEN bytes starting at OFFSET of the stored file
+        #[arg(long, value_name = "OFFSET:LEN", value_parser = parse_range)]
+        range: Option<(u64, u64)>,
+        /// Payload to decode from an appended-to video (0 = original file)
+        #[arg(long, default_value = "0", conflicts_with = "range")]
+        payload: u16,
+        /// Pixel block size; with --levels and --ecc, skips auto-detection
+        #[arg(long, requires_all = ["levels", "ecc"])]
+        block_size: Option<u8>,
+        /// Quantization levels per channel (with --block-size)
+        #[arg(long, requires = "block_size")]
+        levels: Option<u8>,
+        /// Reed-Solomon ECC parity bytes (with --block-size)
+        #[arg(long, requires = "block_size")]
+        ecc: Option<u8>,
+        /// Zero-fill unrecoverable frames and write a gap report instead of failing
+        #[arg(long, conflicts_with = "range")]
+        partial: bool,
+        /// Gap report path for --partial (default <OUTPUT>.gaps.json)
+        #[arg(long, requires = "partial")]
+        gap_report: Option<String>,
+        /// Print the result as JSON on stdout, without progress bars
+        #[arg(long, conflicts_with = "range")]
+        json: bool,
+    },
+    /// Generate an identity (private key) for encrypting to recipients
+    Keygen {
+        /// Write the identity to this file instead of stdout
+        #[arg(short, long)]
+        output: Option<String>,
+        /// Generate a signing key for `encode --sign` instead
+        #[arg(long)]
+        signing: bool,
+    },
+    /// Estimate frames, duration and MP4 size for given settings
+    Capacity {
+        /// Start from a named preset: archival, youtube or camera
+        #[arg(long)]
+        preset: Option<vstorage::config::Preset>,
+        /// Pixel block size [default: 8]
+        #[arg(long)]
+        block_size: Option<u8>,
+        /// Quantization levels per channel (power of 2) [default: 2]
+        #[arg(long)]
+        levels: Option<u8>,
+        /// Reed-Solomon ECC parity bytes [default: 64]
+        #[arg(long)]
+        ecc: Option<u8>,
+        /// Video frame rate [default: 30]
+        #[arg(long)]
+        fps: Option<u32>,
+        /// FFmpeg CRF quality used for the MP4 size estimate [default: 18]
+        #[arg(long)]
+        crf: Option<u8>,
+        /// Video codec (h264 or vp9) [default: h264]
+        #[arg(long)]
+        codec: Option<vstorage::config::VideoCodec>,
+        /// Input size to plan for (e.g. 40G)
+        #[arg(long, value_name = "BYTES", value_parser = parse_size, conflicts_with = "input")]
+        size: Option<u64>,
+        /// File whose size to plan for
+        #[arg(short, long)]
+        input: Option<String>,
+        /// Account for per-frame encryption overhead
+        #[arg(long)]
+        encrypted: bool,
+        /// Skip encoding a sample with ffmpeg (no MP4 size estimate)
+        #[arg(long)]
+        no_measure: bool,
+    },
+    /// Measure frame paint, Reed-Solomon, Argon2 and end-to-end throughput
+    Bench {
+        /// Start from a named preset: archival, youtube or camera
+        #[arg(long)]
+        preset: Option<vstorage::config::Preset>,
+        /// Pixel block size [default: 8]
+        #[arg(long)]
+        block_size: Option<u8>,
+        /// Quantization levels per channel (power of 2) [default: 2]
+        #[arg(long)]
+        levels: Option<u8>,
+        /// Reed-Solomon ECC parity bytes [default: 64]
+        #[arg(long)]
+        ecc: Option<u8>,
+        /// FFmpeg CRF quality for the end-to-end run [default: 18]
+        #[arg(long)]
+        crf: Option<u8>,
+        /// Video codec (h264 or vp9) [default: h264]
+        #[arg(long)]
+        codec: Option<vstorage::config::VideoCodec>,
+        /// Frames of random data to measure with
+        #[arg(long, default_value_t = 10)]
+        frames: usize,
+        /// Skip the encode/decode run through ffmpeg
+        #[arg(long)]
+        no_end_to_end: bool,
+    },
+    /// Round-trip a random payload through ffmpeg to check the settings
+    Selftest {
+        /// Start from a named preset: archival, youtube or camera
+        #[arg(long)]
+        preset: Option<vstorage::config::Preset>,
+        /// Pixel block size [default: 8]
+        #[arg(long)]
+        block_size: Option<u8>,
+        /// Quantization levels per channel (power of 2) [default: 2]
+        #[arg(long)]
+        levels: Option<u8>,
+        /// Reed-Solomon ECC parity bytes [default: 64]
+        #[arg(long)]
+        ecc: Option<u8>,
+        /// Video frame rate [default: 30]
+        #[arg(long)]
+        fps: Option<u32>,
+        /// FFmpeg CRF quality (0 = lossless) [default: 18]
+        #[arg(long)]
+        crf: Option<u8>,
+        /// Video codec (h264 or vp9) [default: h264]
+        #[arg(long)]
+        codec: Option<vstorage::config::VideoCodec>,
+        /// Encode with 4:2:0 chroma subsampling
+        #[arg(long)]
+        yuv420p: bool,
+        /// Size of the random payload
+        #[arg(long, value_name = "BYTES", value_parser = parse_size, default_value = "256K")]
+        size: u64,
+    },
+    /// Re-encode a sample at a sweep of CRF values and report symbol errors
+    Simulate {
+        /// Start from a named preset: archival, youtube or camera
+        #[arg(long)]
+        preset: Option<vstorage::config::Preset>,
+        /// Pixel block size [default: 8]
+        #[arg(long)]
+        block_size: Option<u8>,
+        /// Quantization levels per channel (power of 2) [default: 2]
+        #[arg(long)]
+        levels: Option<u8>,
+        /// Reed-Solomon ECC parity bytes [default: 64]
+        #[arg(long)]
+        ecc: Option<u8>,
+        /// Video codec of the re-encodes (h264 or vp9) [default: h264]
+        #[arg(long)]
+        codec: Option<vstorage::config::VideoCodec>,
+        /// CRF values to try: N, FIRST:LAST (step 4) or FIRST:LAST:STEP
+        #[arg(long, value_parser = parse_crf_sweep, default_value = "18:40")]
+        crf: Vec<u8>,
+        /// Also try each CRF with 4:2:0 chroma subsampling
+        #[arg(long)]
+        yuv420p: bool,
+        /// Size of the random payload
+        #[arg(long, value_name = "BYTES", value_parser = parse_size, default_value = "256K")]
+        size: u64,
+    },
+    /// Show the parameters stored in a video's frame header
+    Info {
+        /// Input video path (.mp4)
+        input: String,
+    },
+    /// Report the density and overheads of a video
+    Stats {
         /// Input video path (.mp4)
+        input: String,
+    },
+    /// Check that a video decodes cleanly, without writing the output
+    Verify {
+        /// Input videos, in order
+        #[arg(required = true)]
+        inputs: Vec<String>,
+        /// Decryption password (omit if not encrypted)
+        #[arg(short, long)]
+        password: Option<String>,
+        /// Payload to verify (0 = original file)
+        #[arg(long, default_value = "0")]
+        payload: u16,
+        /// Also compare the decoded data with this file, reporting the first
+        /// byte that differs
+        #[arg(long, value_name = "FILE")]
+        against: Option<std::path::PathBuf>,
+        /// Print the report as JSON on stdout, without progress bars
+        #[arg(long)]
+        json: bool,
+    },
+    /// Compare the data stored in two videos, frame by frame
+    Diff {
+        /// First video
+        a: String,
+        /// Second video
+        b: String,
+        /// Decryption password, for both videos unless --password-b is given
         #[arg(short, long)]
+        password: Option<String>,
+        /// Decryption password of the second video
+        #[arg(long)]
+        password_b: Option<String>,
+        /// Payload to compare (0 = original file)
+        #[arg(long, default_value = "0")]
+        payload: u16,
+        /// Print the report as JSON on stdout, without progress bars
+        #[arg(long)]
+        json: bool,
+    },
+    /// Append a file as a new payload to an existing vstorage video
+    Append {
+        /// Existing vstorage video (.mp4), updated in place
+        video: String,
+        /// File to append
         input: String,
-        /// Output file path
+        /// Encryption password for the new payload (omit for no encryption)
+        #[arg(short, long)]
+        password: Option<String>,
+        /// Ask for the password on the terminal, with confirmation, instead
+        /// of passing it with -p
+        #[arg(short, long, conflicts_with = "password")]
+        encrypt: bool,
+        /// FFmpeg CRF quality for the new frames (lower = better)
+        #[arg(long, default_value = "18")]
+        crf: u8,
+    },
+    /// Store several files in one video, with an index frame for random access
+    Pack {
+        /// Files to store, under their relative paths as given
+        #[arg(required = true)]
+        inputs: Vec<String>,
+        /// Output video path (.mp4)
+        #[arg(short, long)]
+        output: String,
+        /// Encryption password (omit for no encryption)
+        #[arg(short, long)]
+        password: Option<String>,
+        /// Ask for the password on the terminal, with confirmation, instead
+        /// of passing it with -p
+        #[arg(short, long, conflicts_with = "password")]
+        encrypt: bool,
+        /// Start from a named preset: archival, youtube or camera
+        #[arg(long)]
+        preset: Option<vstorage::config::Preset>,
+        /// Pixel block size [default: 8]
+        #[arg(long)]
+        block_size: Option<u8>,
+        /// Quantization levels per channel (power of 2) [default: 2]
+        #[arg(long)]
+        levels: Option<u8>,
+        /// FFmpeg CRF quality (lower = better) [default: 18]
+        #[arg(long)]
+        crf: Option<u8>,
+        /// Reed-Solomon ECC parity bytes [default: 64]
+        #[arg(long)]
+        ecc: Option<u8>,
+        /// Video codec (h264 or vp9) [default: h264]
+        #[arg(long)]
+        codec: Option<vstorage::config::VideoCodec>,
+    },
+    /// List the files stored in a video from its index, without decoding them
+    Ls {
+        /// Vstorage video (.mp4)
+        video: String,
+        /// Print the entries as JSON on stdout
+        #[arg(long)]
+        json: bool,
+    },
+    /// Extract one stored file, decoding only its frames
+    Extract {
+        /// Video made by pack or append (.mp4)
+        video: String,
+        /// Full stored path of the file, as ls lists it
+        name: String,
+        /// Output file path ("-" for stdout) [default: NAME]
+        #[arg(short, long)]
+        output: Option<String>,
+        /// Decryption password (omit if not encrypted)
+        #[arg(short, long)]
+        password: Option<String>,
+    },
+    /// Write a video's file to stdout as it decodes, for piping into another
+    /// tool
+    Cat {
+        /// Vstorage video (.mp4)
+        video: String,
+        /// Decryption password (omit if not encrypted)
+        #[arg(short, long)]
+        password: Option<String>,
+        /// Payload to write from an appended-to video (0 = original file)
+        #[arg(long, default_value = "0")]
+        payload: u16,
+    },
+    /// Back up a directory; with --base, only chunks changed since that backup
+    /// are encoded
+    Backup {
+        /// Directory to back up
+        dir: String,
+        /// Output prefix: writes PREFIX.manifest and PREFIX.mp4
+        #[arg(short, long)]
+        output: String,
+        /// Manifest of an earlier backup whose videos hold unchanged chunks
+        #[arg(long, value_name = "MANIFEST")]
+        base: Option<String>,
+        /// Encryption password (omit for no encryption)
         #[arg(short, long)]
+        password: Option<String>,
+        /// Ask for the password on the terminal, with confirmation, instead
+        /// of passing it with -p
+        #[arg(short, long, conflicts_with = "password")]
+        encrypt: bool,
+        /// Start from a named preset: archival, youtube or camera
+        #[arg(long)]
+        preset: Option<vstorage::config::Preset>,
+        /// Pixel block size [default: 8]
+        #[arg(long)]
+        block_size: Option<u8>,
+        /// Quantization levels per channel (power of 2) [default: 2]
+        #[arg(long)]
+        levels: Option<u8>,
+        /// FFmpeg CRF quality (lower = better) [default: 18]
+        #[arg(long)]
+        crf: Option<u8>,
+        /// Reed-Solomon ECC parity bytes [default: 64]
+        #[arg(long)]
+        ecc: Option<u8>,
+        /// Video codec (h264 or vp9) [default: h264]
+        #[arg(long)]
+        codec: Option<vstorage::config::VideoCodec>,
+    },
+    /// Re-encrypt a video under a new password (decodes and encodes it again)
+    Rekey {
+        /// Encrypted vstorage video (.mp4)
+        input: String,
+        /// Output video path (.mp4)
         output: String,
+        /// Current password (asked on the terminal if needed and not given)
+        #[arg(long)]
+        old_pass: Option<String>,
+        /// New password (asked on the terminal, with confirmation, if not given)
+        #[arg(long)]
+        new_pass: Option<String>,
+        /// FFmpeg CRF quality for the new video (lower = better)
+        #[arg(long, default_value = "18")]
+        crf: u8,
+    },
+    /// Restore a backup from its manifest and the videos next to it
+    Restore {
+        /// Backup manifest (.manifest)
+        manifest: String,
+        /// Directory to restore into
+        dir: String,
+        /// Decryption password (omit if not encrypted)
+        #[arg(short, long)]
+        password: Option<String>,
+    },
+    /// Mount a video read-only as a directory of its stored files (FUSE)
+    #[cfg(target_os = "linux")]
+    Mount {
+        /// Vstorage video (.mp4)
+        video: String,
+        /// Directory to mount it on; unmount with Ctrl-C or `fusermount -u`
+        mountpoint: String,
         /// Decryption password (omit if not encrypted)
         #[arg(short, long)]
         password: Option<String>,
+        /// Decoded frames kept in memory
+        #[arg(long, default_value = "32")]
+        cache_frames: usize,
     },
 }
 
+fn parse_range(s: &str) -> Result<(u64, u64), String> {
+    let (offset, len) = s
+        .split_once(':')
+        .ok_or_else(|| "expected OFFSET:LEN".to_string())?;
+    let offset = offset.parse().map_err(|e| format!("bad offset: {e}"))?;
+    let len = len.parse().map_err(|e| format!("bad length: {e}"))?;
+    Ok((offset, len))
+}
+
+fn parse_size(s: &str) -> Result<u64, String> {
+    let (digits, unit) = s.split_at(s.find(|c: char| c.is_ascii_alphabetic()).unwrap_or(s.len()));
+    let scale: u64 = match unit.to_ascii_uppercase().trim_end_matches('B') {
+        "" => 1,
+        "K" => 1_000,
+        "M" => 1_000_000,
+        "G" => 1_000_000_000,
+        "T" => 1_000_000_000_000,
+        _ => return Err(format!("unknown size unit '{unit}'")),
+    };
+    let n: u64 = digits.parse().map_err(|e| format!("bad size: {e}"))?;
+    match n.checked_mul(scale) {
+        Some(0) => Err("size must be > 0".into()),
+        Some(v) => Ok(v),
+        None => Err("size too large".into()),
+    }
+}
+
+fn parse_crf_sweep(s: &str) -> Result<Vec<u8>, String> {
+    let parts: Vec<u8> = s
+        .split(':')
+        .map(|p| p.parse().map_err(|e| format!("bad CRF '{p}': {e}")))
+        .collect::<Result<_, _>>()?;
+    let (first, last, step) = match parts[..] {
+        [crf] => (crf, crf, 1),
+        [first, last] => (first, last, 4),
+        [first, last, step] => (first, last, step),
+        _ => return Err("expected N, FIRST:LAST or FIRST:LAST:STEP".into()),
+    };
+    if first > last || step == 0 {
+        return Err("expected FIRST <= LAST and STEP > 0".into());
+    }
+    let mut crfs: Vec<u8> = (first..=last).step_by(step as usize).collect();
+    if crfs.last() != Some(&last) {
+        crfs.push(last);
+    }
+    Ok(crfs)
+}
+
+fn parse_channel_levels(s: &str) -> Result<[u8; 3], String> {
+    let parts: Vec<u8> = s
+        .split('/')
+        .map(|p| p.parse().map_err(|e| format!("bad levels '{p}': {e}")))
+        .collect::<Result<_, _>>()?;
+    parts.try_into().map_err(|_| "expected R/G/B".into())
+}
+
+fn parse_duration(s: &str) -> Result<u64, String> {
+    let (digits, scale) = match s.strip_suffix('h') {
+        Some(d) => (d, 3600),
+        None => match s.strip_suffix('m') {
+            Some(d) => (d, 60),
+            None => (s.strip_suffix('s').unwrap_or(s), 1),
+        },
+    };
+    let n: u64 = digits.parse().map_err(|e| format!("bad duration: {e}"))?;
+    match n.checked_mul(scale) {
+        Some(0) => Err("duration must be > 0".into()),
+        Some(v) => Ok(v),
+        None => Err("duration too long".into()),
+    }
+}
+
+/// Frame config from explicit flags layered over `preset`, then the config
+/// file's settings and preset, writing frames in `workspace`; exits on
+/// invalid settings.
+fn resolve_config(
+    settings: vstorage::config::FrameSettings,
+    preset: Option<vstorage::config::Preset>,
+    file: &ConfigFile,
+    workspace: &vstorage::config::Workspace,
+) -> vstorage::config::FrameConfig {
+    build_config(
+        layer_settings(settings, preset, file)
+            .builder()
+            .token(token_mode(file))
+            .hardware_token(hardware_token(file))
+            .threads(file.threads)
+            .workspace(workspace.clone()),
+    )
+}
+
+/// `settings` over `preset`, over the config file's settings and preset.
+fn layer_settings(
+    settings: vstorage::config::FrameSettings,
+    preset: Option<vstorage::config::Preset>,
+    file: &ConfigFile,
+) -> vstorage::config::FrameSettings {
+    let preset_settings =
+        |p: Option<vstorage::config::Preset>| p.map(|p| p.settings()).unwrap_or_default();
+    settings
+        .or(preset_settings(preset))
+        .or(file.settings.clone())
+        .or(preset_settings(file.preset))
+}
+
+fn build_config(builder: vstorage::config::FrameConfigBuilder) -> vstorage::config::FrameConfig {
+    builder.build().unwrap_or_else(|e| {
+        eprintln!("Error: {e}");
+        process::exit(e.exit_code());
+    })
+}
+
+/// Load `--config`, or the default config file if it exists; exits if the
+/// file cannot be read or parsed.
+fn load_config_file(path: Option<&str>) -> ConfigFile {
+    let result = match path {
+        Some(path) => ConfigFile::load(Path::new(path)),
+        None => match ConfigFile::default_path() {
+            Some(path) if path.exists() => ConfigFile::load(&path),
+            _ => Ok(ConfigFile::default()),
+        },
+    };
+    result.unwrap_or_else(|e| {
+        eprintln!("Error: {e}");
+        process::exit(e.exit_code());
+    })
+}
+
+fn is_mount(command: &Commands) -> bool {
+    #[cfg(target_os = "linux")]
+    if let Commands::Mount { .. } = command {
+        return true;
+    }
+    let _ = command;
+    false
+}
+
+/// `-p` if given, else `--password-env`/`--password-file`, else the config
+/// file's password source.
+fn password_or_file(password: Option<String>, file: &ConfigFile) -> Option<String> {
+    password.or_else(|| {
+        file.password.as_ref().map(|source| {
+            source.read().unwrap_or_else(|e| {
+                eprintln!("Error: {e}");
+                process::exit(e.exit_code());
+            })
+        })
+    })
+}
+
+/// How keys use the token of `--token-command`: not at all without one,
+/// instead of the password with `--token-only`.
+fn token_mode(file: &ConfigFile) -> vstorage::crypto::TokenMode {
+    match (&file.token, file.token_only) {
+        (None, _) => vstorage::crypto::TokenMode::Off,
+        (Some(_), false) => vstorage::crypto::TokenMode::WithPassword,
+        (Some(_), true) => vstorage::crypto::TokenMode::Only,
+    }
+}
+
+/// The token of `--token-command`, to answer for keys that need it.
+fn hardware_token(file: &ConfigFile) -> Option<Arc<dyn vstorage::crypto::Token>> {
+    let token = file.token.clone()?;
+    Some(Arc::new(token))
+}
+
+/// The password to encrypt with: from `-p` or the config file, else asked
+/// on the terminal when `--encrypt` is given; with `--token-only`, none but
+/// the token's.
+fn new_password(password: Option<String>, encrypt: bool, file: &ConfigFile) -> Option<String> {
+    match password_or_file(password, file) {
+        None if file.token_only => Some(String::new()),
+        None if encrypt => Some(
+            vstorage::prompt::new_password("Password").unwrap_or_else(|e| {
+                eprintln!("Error: {e}");
+                process::exit(e.exit_code());
+            }),
+        ),
+        password => password,
+    }
+}
+
+/// Contents of `--pad` padding frames, with the `--hidden` file sealed in
+/// them; exits if the hidden file cannot be read or shares `password`.
+fn padding_for(
+    pad: Option<u64>,
+    hidden: Option<String>,
+    hidden_pass: Option<String>,
+    password: Option<&str>,
+) -> Vec<u8> {
+    let exit = |e: vstorage::error::VstorageError| -> ! {
+        eprintln!("Error: {e}");
+        process::exit(e.exit_code());
+    };
+    let hidden = hidden.map(|path| {
+        let data = std::fs::read(&path).unwrap_or_else(|e| exit(e.into()));
+        let pass = hidden_pass.unwrap_or_else(|| {
+            vstorage::prompt::new_password("Hidden file password").unwrap_or_else(|e| exit(e))
+        });
+        (data, pass)
+    });
+    if hidden
+        .as_ref()
+        .is_some_and(|(_, p)| Some(p.as_str()) == password)
+    {
+        exit(vstorage::error::VstorageError::Config(
+            "the hidden file needs a password of its own".into(),
+        ));
+    }
+    let hidden = hidden
+        .as_ref()
+        .map(|(data, pass)| (&data[..], pass.as_str()));
+    vstorage::hidden::padding(pad.unwrap_or(0) as usize, hidden).unwrap_or_else(|e| exit(e))
+}
+
+/// `password`, or asked on the terminal when none was given and the first
+/// frame of `video` (extracted in `workspace`) shows it is
+/// password-encrypted.
+fn password_for_video(
+    password: Option<String>,
+    video: &Path,
+    workspace: &vstorage::config::Workspace,
+) -> Option<String> {
+    let password = match password {
+        None if vstorage::prompt::can_prompt() => match vstorage::info::info(video, workspace) {
+            Ok(info) if info.needs_password() => Some(
+                vstorage::prompt::read_password("Password: ").unwrap_or_else(|e| {
+                    eprintln!("Error: {e}");
+                    process::exit(e.exit_code());
+                }),
+            ),
+            _ => None,
+        },
+        password => password,
+    };
+    password
+}
+
+/// Run `op`, and once more with a password asked on the terminal if it
+/// turns out to need one that was not given.
+fn retry_with_password<T>(
+    password: Option<String>,
+    mut op: impl FnMut(Option<&str>) -> vstorage::error::Result<T>,
+) -> vstorage::error::Result<T> {
+    let given = password;
+    match op(given.as_deref()) {
+        Err(vstorage::error::VstorageError::PasswordRequired)
+            if given.is_none() && vstorage::prompt::can_prompt() =>
+        {
+            let password = vstorage::prompt::read_password("Password: ")?;
+            op(Some(&password))
+        }
+        result => result,
+    }
+}
+
 fn main() {
     let cli = Cli::parse();
+    let mut file = load_config_file(cli.config.as_deref());
+    // The flags take the place of the config file's password source
+    if let Some(source) = cli
+        .password_env
+        .map(vstorage::config_file::PasswordSource::Env)
+        .or(cli
+            .password_file
+            .map(vstorage::config_file::PasswordSource::File))
+    {
+        file.password = Some(source);
+    }
+    if let Some(command) = cli.token_command {
+        file.token = Some(vstorage::config_file::TokenCommand(command));
+    }
+    file.token_only |= cli.token_only;
+    let hardware_token = hardware_token(&file);
+    if hardware_token.is_none() && file.token_only {
+        usage_error("--token-only needs a token (--token-command or token_command)");
+    }
+    let json = matches!(
+        cli.command,
+        Commands::Encode { json: true, .. }
+            | Commands::Decode { json: true, .. }
+            | Commands::Verify { json: true, .. }
+            | Commands::Diff { json: true, .. }
+            | Commands::Ls { json: true, .. }
+    );
+    if let Some(path) = &cli.progress_json {
+        vstorage::progress::send_events_to(path).unwrap_or_else(|e| {
+            eprintln!("Error: {}: {e}", path.display());
+            process::exit(e.exit_code());
+        });
+    }
+    vstorage::progress::set_hidden(json || cli.quiet > 0 || vstorage::progress::events_on_stderr());
+    let progress: &dyn vstorage::progress::ProgressSink = match cli.progress_json {
+        Some(_) => &vstorage::progress::JsonProgress,
+        None => &vstorage::progress::NoProgress,
+    };
+    let workspace = vstorage::config::Workspace {
+        intermediate: cli.intermediate.or(file.intermediate).unwrap_or_default(),
+        dir: cli.work_dir,
+        keep_frames: cli.keep_frames,
+        max_temp: cli.max_temp,
+    };
+    file.threads = cli.threads.map(usize::from).or(file.threads);
+    let cancel = vstorage::cancel::CancelToken::new();
+    if matches!(
+        cli.command,
+        Commands::Encode { .. }
+            | Commands::Decode { .. }
+            | Commands::Bench { .. }
+            | Commands::Selftest { .. }
+            | Commands::Simulate { .. }
+            | Commands::Pack { .. }
+            | Commands::Extract { .. }
+            | Commands::Cat { .. }
+            | Commands::Backup { .. }
+            | Commands::Restore { .. }
+            | Commands::Rekey { .. }
+    ) || is_mount(&cli.command)
+    {
+        vstorage::cancel::cancel_on_ctrl_c(&cancel);
+    }
+    let level = match (cli.verbose, cli.quiet) {
+        (true, _) => LevelFilter::DEBUG,
+        (false, 0) => LevelFilter::INFO,
+        (false, 1) => LevelFilter::WARN,
+        (false, _) => LevelFilter::ERROR,
+    };
+    tracing_subscriber::registry()
+        .with(vstorage::progress::LogLayer.with_filter(level))
+        .init();
 
     let result = match cli.command {
         Commands::Encode {
             input,
             output,
+            paths,
             password,
+            encrypt,
+            recipient,
+            preset,
             block_size,
             levels,
             fps,
             crf,
             ecc,
+            codec,
+            target_bitrate,
+            yuv420p,
+            metadata_levels,
+            header_rows,
+            header_copies,
+            channel_levels,
+            layout,
+            crc_map,
+            bottom_header,
+            banner,
+            barcode,
+            pilots,
+            bin_centers,
+            intro,
+            kdf,
+            compress,
+            audio_data,
+            max_output_size,
+            max_duration,
+            pad,
+            hidden,
+            hidden_pass,
+            sign,
+            deterministic: _,
+            seed,
+            resume,
+            mmap,
+            stream,
+            auto,
+            auto_margin,
+            json,
         } => {
-            let config = match vstorage::config::FrameConfig::new(block_size, levels, ecc, fps, crf)
-            {
-                Ok(c) => c,
-                Err(e) => {
+            let password = new_password(password, encrypt, &file);
+            let sign = sign.map(|path| {
+                std::fs::read_to_string(&path)
+                    .map_err(Into::into)
+                    .and_then(|text| vstorage::signature::SigningKey::parse(&text))
+                    .unwrap_or_else(|e| {
+                        eprintln!("Error: {path}: {e}");
+                        process::exit(e.exit_code());
+                    })
+            });
+            let padding = padding_for(pad, hidden, hidden_pass, password.as_deref());
+            let mut paths = paths.into_iter();
+            let (Some(input), Some(output)) = (
+                input.or_else(|| paths.next()),
+                output.or_else(|| paths.next()),
+            ) else {
+                usage_error("give an input file and an output video (or -i/-o)");
+            };
+            if paths.next().is_some() {
+                usage_error("too many paths");
+            }
+            let settings = vstorage::config::FrameSettings {
+                block_size,
+                levels,
+                ecc_len: ecc,
+                fps,
+                crf,
+                codec,
+                yuv420p: yuv420p.then_some(true),
+                ffmpeg_args: None,
+                kdf,
+                compression: compress,
+                layout,
+            };
+            let mut builder = layer_settings(settings, preset, &file)
+                .builder()
+                .crc_map(crc_map)
+                .bottom_header(bottom_header)
+                .banner(banner)
+                .barcode(barcode)
+                .pilots(pilots)
+                .bin_centers(bin_centers)
+                .intro(intro)
+                .token(token_mode(&file))
+                .hardware_token(hardware_token.clone())
+                .threads(file.threads)
+                .workspace(workspace.clone());
+            if let Some(kbps) = target_bitrate {
+                builder = builder.target_bitrate(kbps);
+            }
+            if let Some(hex) = seed {
+                builder = builder.seed(vstorage::memory::parse_seed(&hex).unwrap_or_else(|e| {
                     eprintln!("Error: {e}");
-                    process::exit(1);
+                    process::exit(e.exit_code());
+                }));
+            }
+            if header_rows.is_some() || header_copies.is_some() {
+                builder = builder.header_redundancy(
+                    header_rows.unwrap_or(vstorage::config::HEADER_ROWS),
+                    header_copies.unwrap_or(vstorage::config::HEADER_COPIES),
+                );
+            }
+            if auto {
+                let base = build_config(builder.clone());
+                let chosen = vstorage::tune::auto_tune(&base, auto_margin, &cancel)
+                    .unwrap_or_else(|e| {
+                        eprintln!("Error: {e}");
+                        process::exit(e.exit_code());
+                    })
+                    .chosen;
+                builder = builder
+                    .block_size(chosen.block_size())
+                    .levels(chosen.levels())
+                    .ecc_len(chosen.ecc_len())
+                    .crf(chosen.crf());
+            }
+            if let Some(levels) = channel_levels {
+                builder = builder.channel_levels(levels);
+            }
+            if let Some(levels) = metadata_levels {
+                builder = builder.metadata_levels(levels);
+            }
+            let config = build_config(builder);
+            if stream && input != vstorage::encode::STDIO_PATH {
+                usage_error("--stream reads stdin; give \"-\" as the input");
+            }
+            let limits = vstorage::encode::SplitLimits {
+                max_bytes: max_output_size,
+                max_secs: max_duration,
+            };
+            let summary = match stream {
+                true => vstorage::stream::encode_stream(
+                    &mut std::io::stdin().lock(),
+                    Path::new(&output),
+                    password.as_deref(),
+                    &recipient,
+                    &config,
+                    progress,
+                    &cancel,
+                ),
+                // SAFETY: --mmap asks that the input be left alone until the
+                // encode finishes, as its help says
+                false if mmap => unsafe {
+                    vstorage::encode::encode_mapped(
+                        Path::new(&input),
+                        Path::new(&output),
+                        password.as_deref(),
+                        &recipient,
+                        &config,
+                        audio_data.as_deref().map(Path::new),
+                        &padding,
+                        sign.as_ref(),
+                        &limits,
+                        resume,
+                        progress,
+                        &cancel,
+                    )
+                },
+                false => vstorage::encode::encode(
+                    Path::new(&input),
+                    Path::new(&output),
+                    password.as_deref(),
+                    &recipient,
+                    &config,
+                    audio_data.as_deref().map(Path::new),
+                    &padding,
+                    sign.as_ref(),
+                    &limits,
+                    resume,
+                    progress,
+                    &cancel,
+                ),
+            };
+            summary
+                .inspect_err(|e| {
+                    if !stream && matches!(e, vstorage::error::VstorageError::Cancelled) {
+                        eprintln!(
+                            "Interrupted — finished segments are kept in {}; rerun with --resume to continue",
+                            vstorage::encode::work_dir_for(Path::new(&output), &workspace).display()
+                        );
+                    }
+                })
+                .map(|summary| {
+                    if json {
+                        println!("{}", summary.to_json());
+                    }
+                })
+        }
+        Commands::Decode {
+            input,
+            inputs,
+            from_url,
+            output,
+            password,
+            identity,
+            verify_key,
+            audio_output,
+            range,
+            payload,
+            block_size,
+            levels,
+            ecc,
+            partial,
+            gap_report,
+            json,
+        } => {
+            let password = password_or_file(password, &file);
+            let identity = identity.map(|path| {
+                std::fs::read_to_string(&path)
+                    .map_err(Into::into)
+                    .and_then(|text| vstorage::recipient::Identity::parse(&text))
+                    .unwrap_or_else(|e| {
+                        eprintln!("Error: {path}: {e}");
+                        process::exit(e.exit_code());
+                    })
+            });
+            let forced = match (block_size, levels, ecc) {
+                (Some(b), Some(l), Some(e)) => {
+                    match vstorage::config::FrameConfig::new(b, l, e, 30, 18) {
+                        Ok(c) => Some(c),
+                        Err(e) => {
+                            eprintln!("Error: {e}");
+                            process::exit(e.exit_code());
+                        }
+                    }
+                }
+                _ => None,
+            };
+            let mut inputs = inputs;
+            let output = match output {
+                Some(o) => o,
+                None if inputs.len() >= 2 || (from_url.is_some() && inputs.len() == 1) => {
+                    inputs.pop().unwrap()
+                }
+                None => {
+                    usage_error("no output path given (use -o <FILE> or -o - for stdout)");
+                }
+            };
+            if json && output == vstorage::encode::STDIO_PATH {
+                usage_error("--json prints to stdout; write the output to a file");
+            }
+            if from_url.is_some() && !inputs.is_empty() {
+                usage_error("--from-url replaces the input videos");
+            }
+            let downloaded = from_url.map(|url| {
+                vstorage::download::download(
+                    &url,
+                    audio_output.is_some(),
+                    &workspace,
+                    progress,
+                    &cancel,
+                )
+                .unwrap_or_else(|e| exit_with(&e, json))
+            });
+            let inputs: Vec<&Path> = match &downloaded {
+                Some(d) => vec![d.path.as_path()],
+                None => input.iter().chain(&inputs).map(Path::new).collect(),
+            };
+            if inputs.is_empty() {
+                usage_error("no input video given (use -i <VIDEO> or list videos)");
+            }
+            let password = password_for_video(password, inputs[0], &workspace);
+            let gaps_to_stderr = gap_report.is_none() && output == vstorage::encode::STDIO_PATH;
+            let options = vstorage::decode::DecodeOptions {
+                payload,
+                audio_output: audio_output.map(Into::into),
+                forced,
+                partial,
+                gap_report: gap_report.map(Into::into),
+                identity,
+                verify_key,
+                threads: file.threads,
+                workspace,
+                hardware_token,
+            };
+            if let Some((offset, len)) = range {
+                if inputs.len() > 1 {
+                    usage_error("--range supports a single input video");
+                }
+                vstorage::decode::decode_range(
+                    inputs[0],
+                    offset,
+                    len,
+                    password.as_deref(),
+                    &options,
+                    &cancel,
+                )
+                .and_then(|bytes| vstorage::decode::write_output(Path::new(&output), &bytes))
+            } else {
+                vstorage::decode::decode(
+                    &inputs,
+                    Path::new(&output),
+                    password.as_deref(),
+                    &options,
+                    progress,
+                    &cancel,
+                )
+                .map(|summary| {
+                    if json {
+                        println!("{}", summary.to_json());
+                    } else if let Some(gaps) = summary.gaps.filter(|_| gaps_to_stderr) {
+                        // The file went to stdout, so its gap report has no
+                        // file next to it
+                        eprintln!("{}", gaps.to_json());
+                    }
+                })
+            }
+        }
+        Commands::Keygen { output, signing } => {
+            let (text, public) = if signing {
+                let key = vstorage::signature::SigningKey::generate();
+                (key.to_file(), format!("Verify key: {}", key.verify_key()))
+            } else {
+                let identity = vstorage::recipient::Identity::generate();
+                (
+                    identity.to_file(),
+                    format!("Public key: {}", identity.recipient()),
+                )
+            };
+            match output {
+                Some(path) => write_identity(Path::new(&path), &text),
+                None => {
+                    print!("{text}");
+                    Ok(())
+                }
+            }
+            .map(|()| eprintln!("{public}"))
+        }
+        Commands::Capacity {
+            preset,
+            block_size,
+            levels,
+            ecc,
+            fps,
+            crf,
+            codec,
+            size,
+            input,
+            encrypted,
+            no_measure,
+        } => {
+            let settings = vstorage::config::FrameSettings {
+                block_size,
+                levels,
+                ecc_len: ecc,
+                fps,
+                crf,
+                codec,
+                yuv420p: None,
+                ffmpeg_args: None,
+                kdf: None,
+                compression: None,
+                layout: None,
+            };
+            let config = resolve_config(settings, preset, &file, &workspace);
+            let size = match input {
+                Some(path) => match std::fs::metadata(&path) {
+                    Ok(m) => Some(m.len()),
+                    Err(e) => {
+                        eprintln!("Error: {path}: {e}");
+                        process::exit(vstorage::error::VstorageError::from(e).exit_code());
+                    }
+                },
+                None => size,
+            };
+            let mut estimate = vstorage::capacity::CapacityEstimate::new(&config, encrypted, size);
+            if !no_measure {
+                match vstorage::capacity::measure_mp4_bytes_per_frame(&config) {
+                    Ok(bytes) => estimate.mp4_bytes_per_frame = Some(bytes),
+                    Err(e) => eprintln!("Skipping MP4 size estimate: {e}"),
                 }
+            }
+            println!("{estimate}");
+            Ok(())
+        }
+        Commands::Bench {
+            preset,
+            block_size,
+            levels,
+            ecc,
+            crf,
+            codec,
+            frames,
+            no_end_to_end,
+        } => {
+            let settings = vstorage::config::FrameSettings {
+                block_size,
+                levels,
+                ecc_len: ecc,
+                crf,
+                codec,
+                ..Default::default()
+            };
+            let config = resolve_config(settings, preset, &file, &workspace);
+            vstorage::bench::bench(&config, frames, !no_end_to_end, &cancel)
+                .map(|report| println!("{report}"))
+        }
+        Commands::Selftest {
+            preset,
+            block_size,
+            levels,
+            ecc,
+            fps,
+            crf,
+            codec,
+            yuv420p,
+            size,
+        } => {
+            let settings = vstorage::config::FrameSettings {
+                block_size,
+                levels,
+                ecc_len: ecc,
+                fps,
+                crf,
+                codec,
+                yuv420p: yuv420p.then_some(true),
+                ffmpeg_args: None,
+                kdf: None,
+                compression: None,
+                layout: None,
+            };
+            let config = resolve_config(settings, preset, &file, &workspace);
+            vstorage::selftest::selftest(&config, size as usize, &cancel).map(|report| {
+                println!("{report}");
+                if !report.passed() {
+                    process::exit(2);
+                }
+            })
+        }
+        Commands::Simulate {
+            preset,
+            block_size,
+            levels,
+            ecc,
+            codec,
+            crf,
+            yuv420p,
+            size,
+        } => {
+            let settings = vstorage::config::FrameSettings {
+                block_size,
+                levels,
+                ecc_len: ecc,
+                codec,
+                ..Default::default()
             };
-            vstorage::encode::encode(
+            let config = resolve_config(settings, preset, &file, &workspace);
+            vstorage::simulate::simulate(&config, &crf, yuv420p, size as usize, &cancel)
+                .map(|report| println!("{report}"))
+        }
+        Commands::Info { input } => {
+            vstorage::info::info(Path::new(&input), &workspace).map(|info| println!("{info}"))
+        }
+        Commands::Stats { input } => {
+            vstorage::stats::stats(Path::new(&input), &workspace).map(|stats| println!("{stats}"))
+        }
+        Commands::Verify {
+            inputs,
+            password,
+            payload,
+            against,
+            json,
+        } => {
+            let password = password_or_file(password, &file);
+            let inputs: Vec<&Path> = inputs.iter().map(Path::new).collect();
+            let password = password_for_video(password, inputs[0], &workspace);
+            vstorage::verify::verify(
+                &inputs,
+                password.as_deref(),
+                hardware_token.as_deref(),
+                payload,
+                against.as_deref(),
+                file.threads,
+                &workspace,
+            )
+            .map(|report| {
+                if json {
+                    println!("{}", report.to_json());
+                } else {
+                    println!("{report}");
+                }
+                if !report.passed() {
+                    process::exit(2);
+                }
+            })
+        }
+        Commands::Diff {
+            a,
+            b,
+            password,
+            password_b,
+            payload,
+            json,
+        } => {
+            let password = password_or_file(password, &file);
+            let password_b = password_b.or(password.clone());
+            let passwords = (password.as_deref(), password_b.as_deref());
+            vstorage::diff::diff(
+                Path::new(&a),
+                Path::new(&b),
+                passwords,
+                hardware_token.as_deref(),
+                payload,
+                &workspace,
+            )
+            .map(|report| {
+                if json {
+                    println!("{}", report.to_json());
+                } else {
+                    println!("{report}");
+                }
+                if !report.identical {
+                    process::exit(2);
+                }
+            })
+        }
+        Commands::Append {
+            video,
+            input,
+            password,
+            encrypt,
+            crf,
+        } => {
+            let password = new_password(password, encrypt, &file);
+            vstorage::append::append(
+                Path::new(&video),
                 Path::new(&input),
+                password.as_deref(),
+                crf,
+                hardware_token.as_deref(),
+                &workspace,
+            )
+            .map(|payload| eprintln!("Decode it with --payload {payload}"))
+        }
+        Commands::Pack {
+            inputs,
+            output,
+            password,
+            encrypt,
+            preset,
+            block_size,
+            levels,
+            crf,
+            ecc,
+            codec,
+        } => {
+            let password = new_password(password, encrypt, &file);
+            let settings = vstorage::config::FrameSettings {
+                block_size,
+                levels,
+                ecc_len: ecc,
+                crf,
+                codec,
+                ..Default::default()
+            };
+            let config = resolve_config(settings, preset, &file, &workspace);
+            let inputs: Vec<&Path> = inputs.iter().map(Path::new).collect();
+            vstorage::pack::pack(
+                &inputs,
                 Path::new(&output),
                 password.as_deref(),
                 &config,
+                progress,
+                &cancel,
             )
+            .map(|index| eprintln!("Packed {} files into {output}", index.entries.len()))
         }
-        Commands::Decode {
+        Commands::Ls { video, json } => {
+            vstorage::pack::list(Path::new(&video), &workspace).map(|index| match json {
+                true => println!("{}", index.to_json()),
+                false => println!("{index}"),
+            })
+        }
+        Commands::Extract {
+            video,
+            name,
+            output,
+            password,
+        } => {
+            let password = password_or_file(password, &file);
+            retry_with_password(password, |password| {
+                vstorage::pack::extract(
+                    Path::new(&video),
+                    &name,
+                    Path::new(output.as_deref().unwrap_or(&name)),
+                    password,
+                    hardware_token.as_deref(),
+                    &workspace,
+                    &cancel,
+                )
+            })
+            .map(|_| ())
+        }
+        Commands::Cat {
+            video,
+            password,
+            payload,
+        } => {
+            let password = password_or_file(password, &file);
+            retry_with_password(password, |password| {
+                vstorage::pack::cat(
+                    Path::new(&video),
+                    payload,
+                    password,
+                    hardware_token.as_deref(),
+                    &workspace,
+                    &cancel,
+                )
+            })
+            .map(|_| ())
+        }
+        Commands::Backup {
+            dir,
+            output,
+            base,
+            password,
+            encrypt,
+            preset,
+            block_size,
+            levels,
+            crf,
+            ecc,
+            codec,
+        } => {
+            let password = new_password(password, encrypt, &file);
+            let settings = vstorage::config::FrameSettings {
+                block_size,
+                levels,
+                ecc_len: ecc,
+                crf,
+                codec,
+                ..Default::default()
+            };
+            let config = resolve_config(settings, preset, &file, &workspace);
+            vstorage::backup::backup(
+                Path::new(&dir),
+                Path::new(&output),
+                base.as_deref().map(Path::new),
+                password.as_deref(),
+                &config,
+                progress,
+                &cancel,
+            )
+            .map(|summary| eprintln!("{summary}"))
+        }
+        Commands::Rekey {
             input,
             output,
+            old_pass,
+            new_pass,
+            crf,
+        } => {
+            let old = password_or_file(old_pass, &file);
+            let old = password_for_video(old, Path::new(&input), &workspace);
+            // The config file's password source is the old password's
+            let new = new_pass.unwrap_or_else(|| {
+                vstorage::prompt::new_password("New password").unwrap_or_else(|e| {
+                    eprintln!("Error: {e}");
+                    process::exit(e.exit_code());
+                })
+            });
+            vstorage::rekey::rekey(
+                Path::new(&input),
+                Path::new(&output),
+                old.as_deref(),
+                Some(&new),
+                crf,
+                hardware_token,
+                &workspace,
+                progress,
+                &cancel,
+            )
+            .map(|_| eprintln!("Wrote {output} under the new password"))
+        }
+        Commands::Restore {
+            manifest,
+            dir,
+            password,
+        } => {
+            let password = password_or_file(password, &file);
+            retry_with_password(password, |password| {
+                vstorage::backup::restore(
+                    Path::new(&manifest),
+                    Path::new(&dir),
+                    password,
+                    hardware_token.clone(),
+                    &workspace,
+                    progress,
+                    &cancel,
+                )
+            })
+            .map(|_| ())
+        }
+        #[cfg(target_os = "linux")]
+        Commands::Mount {
+            video,
+            mountpoint,
             password,
-        } => vstorage::decode::decode(Path::new(&input), Path::new(&output), password.as_deref()),
+            cache_frames,
+        } => {
+            let password = password_or_file(password, &file);
+            retry_with_password(password, |password| {
+                vstorage::archive::VideoSource::open(Path::new(&video), &workspace, &cancel)
+                    .and_then(|source| {
+                        vstorage::archive::Archive::open(
+                            Box::new(source),
+                            password,
+                            hardware_token.as_deref(),
+                            cache_frames,
+                        )
+                    })
+            })
+            .and_then(|archive| vstorage::mount::mount(archive, Path::new(&mountpoint), &cancel))
+        }
     };
 
     if let Err(e) = result {
+        exit_with(&e, json);
+    }
+}
+
+/// Create `path` holding an identity or signing key, readable only by its
+/// owner; an existing file is never overwritten.
+fn write_identity(path: &Path, text: &str) -> vstorage::error::Result<()> {
+    use std::io::Write;
+    let mut options = std::fs::OpenOptions::new();
+    options.write(true).create_new(true);
+    #[cfg(unix)]
+    std::os::unix::fs::OpenOptionsExt::mode(&mut options, 0o600);
+    options.open(path)?.write_all(text.as_bytes())?;
+    Ok(())
+}
+
+/// Exit for invalid arguments, with the exit code of an invalid
+/// configuration.
+fn usage_error(message: &str) -> ! {
+    eprintln!("Error: {message}");
+    process::exit(vstorage::error::VstorageError::Config(message.into()).exit_code());
+}
+
+/// Report `e` (also as JSON on stdout with `--json`) and exit with its code.
+fn exit_with(e: &vstorage::error::VstorageError, json: bool) -> ! {
+    vstorage::progress::event(vstorage::json::Json::object([
+        ("event", "error".into()),
+        ("message", e.to_string().into()),
+        ("exit_code", (e.exit_code() as u32).into()),
+    ]));
+    if json {
+        println!(
+            "{}",
+            vstorage::json::Json::object([
+                ("error", e.to_string().into()),
+                ("exit_code", (e.exit_code() as u32).into()),
+            ])
+        );
+    }
+    if !vstorage::progress::events_on_stderr() {
         eprintln!("Error: {e}");
-        process::exit(1);
     }
+    process::exit(e.exit_code());
 }
@@ -0,0 +1,1388 @@
+use std::io::Read;
+use std::sync::Arc;
+
+use image::RgbImage;
+use sha2::{Digest, Sha256};
+use zeroize::Zeroizing;
+
+use crate::collect::{self, FrameCollector};
+use crate::compress::Compression;
+use crate::config::{FrameConfig, PROTOCOL_VERSION};
+use crate::crypto::Token;
+use crate::error::{Result, VstorageError};
+use crate::pieces::{PieceHashes, PIECE_NONCE_BASE};
+use crate::recipient::{self, Identity, Recipient};
+use crate::signature::{self, SigningKey, VerifyKey, SIGNATURE_NONCE};
+use crate::{crypto, ecc, frame, header};
+
+/// How a file is cut into frames: chunk size, encryption and the header
+/// fields every frame shares. The data frames are followed by the key frame
+/// (if encrypted to recipients), piece frames holding the file's
+/// [`PieceHashes`], the signature frame (if signed) and padding frames (see
+/// [`crate::hidden`]); with [`FrameConfig::intro`], an intro frame comes
+/// first.
+pub(crate) struct FramePlan<'a> {
+    config: &'a FrameConfig,
+    data: &'a [u8],
+    pub cipher: Option<crypto::FrameCipher>,
+    nonce: [u8; 12],
+    salt: [u8; 16],
+    /// Stamped into every header (see [`header::FrameHeader::set_id`])
+    pub set_id: [u8; 16],
+    /// Key list for the key frame (empty for none)
+    keys: Vec<u8>,
+    /// Serialized piece hashes
+    pieces: Vec<u8>,
+    /// Contents of the signature frame (empty for none)
+    signature: Vec<u8>,
+    /// Contents of the padding frames
+    padding: &'a [u8],
+    /// File bytes per frame
+    pub per_frame: usize,
+    /// Bytes per key, piece or signature frame, drawn with
+    /// [`FrameConfig::metadata_levels`]
+    meta_per_frame: usize,
+    pub data_frames: usize,
+    /// Intro, data, key, piece, signature and padding frames
+    pub num_frames: usize,
+}
+
+impl<'a> FramePlan<'a> {
+    pub fn new(
+        config: &'a FrameConfig,
+        data: &'a [u8],
+        cipher: Option<crypto::FrameCipher>,
+        nonce: [u8; 12],
+        salt: [u8; 16],
+        keys: Vec<u8>,
+    ) -> Result<Self> {
+        let per_frame = config.max_plain_per_frame(cipher.is_some());
+        if per_frame == 0 {
+            return Err(VstorageError::Config(
+                "frame capacity is zero — check block_size/levels/ecc settings".into(),
+            ));
+        }
+        let meta_levels = config.metadata_levels.unwrap_or(config.levels);
+        let meta_per_frame = config
+            .with_levels(meta_levels)
+            .max_plain_per_frame(cipher.is_some());
+        if meta_per_frame == 0 {
+            return Err(VstorageError::Config(
+                "metadata frame capacity is zero — check metadata levels".into(),
+            ));
+        }
+        if keys.len() > meta_per_frame {
+            return Err(VstorageError::Config(
+                "too many recipients for one key frame".into(),
+            ));
+        }
+        let data_frames = data.len().div_ceil(per_frame).max(1);
+        let key_frames = usize::from(!keys.is_empty());
+        let pieces = PieceHashes::compute(data).serialize();
+        // Bound to the data frames' header fields, as decode opens it
+        let context =
+            header::cipher_context(data.len() as u64, data_frames as u32, 0, config.compression);
+        let cipher = cipher.map(|c| c.with_context(context));
+        Ok(Self {
+            config,
+            data,
+            cipher,
+            nonce,
+            salt,
+            set_id: header::new_set_id(),
+            num_frames: usize::from(config.intro)
+                + data_frames
+                + key_frames
+                + pieces.len().div_ceil(meta_per_frame),
+            keys,
+            pieces,
+            signature: Vec::new(),
+            padding: &[],
+            per_frame,
+            meta_per_frame,
+            data_frames,
+        })
+    }
+
+    /// Add a signature frame by `key` (see [`crate::signature`]) after the
+    /// piece frames.
+    pub fn with_signature(self, key: &SigningKey) -> Self {
+        let frame = signature::sign(key, self.data);
+        self.with_signature_frame(frame)
+    }
+
+    /// Add a signature frame holding `frame`, made by [`signature::sign`]
+    /// over the same data, e.g. kept from another encode of it.
+    pub fn with_signature_frame(mut self, frame: Vec<u8>) -> Self {
+        self.signature = frame;
+        self.num_frames += 1;
+        self
+    }
+
+    /// Add padding frames holding `padding` after the piece frames.
+    pub fn with_padding(mut self, padding: &'a [u8]) -> Self {
+        self.num_frames += padding.len().div_ceil(self.per_frame);
+        self.padding = padding;
+        self
+    }
+
+    /// Encrypt (if enabled) and draw frame `i`, stored in `part` of
+    /// `total_parts` output videos.
+    pub fn render(&self, i: usize, part: usize, total_parts: usize) -> Result<image::RgbImage> {
+        let mut img = RgbImage::new(0, 0);
+        self.render_into(i, part, total_parts, &mut img)?;
+        Ok(img)
+    }
+
+    /// [`FramePlan::render`] into `img`, reusing its buffer.
+    pub fn render_into(
+        &self,
+        i: usize,
+        part: usize,
+        total_parts: usize,
+        img: &mut RgbImage,
+    ) -> Result<()> {
+        let i = match (self.config.intro, i) {
+            (false, i) => i,
+            (true, 0) => {
+                let (first, _) = self.frame(0, part, total_parts)?;
+                let hdr = header::FrameHeader {
+                    kind: header::KIND_INTRO,
+                    ..first
+                };
+                render_frame_into(self.config, hdr.clone(), &[], img);
+                crate::intro::paint(img, self.config, &hdr);
+                return Ok(());
+            }
+            (true, i) => i - 1,
+        };
+        let (hdr, frame_data) = self.frame(i, part, total_parts)?;
+        render_frame_into(self.config, hdr, &frame_data, img);
+        Ok(())
+    }
+
+    /// Header and (encrypted) contents of frame `i`.
+    fn frame(
+        &self,
+        i: usize,
+        part: usize,
+        total_parts: usize,
+    ) -> Result<(header::FrameHeader, Vec<u8>)> {
+        // Piece frame k and the signature frame have their own frame numbers
+        // and nonces; the key frame and padding (random already) are not
+        // encrypted
+        let pieces_start = self.data_frames + usize::from(!self.keys.is_empty());
+        let frames = self.num_frames - usize::from(self.config.intro);
+        let padding_start = frames - self.padding.len().div_ceil(self.per_frame);
+        let signature_start = padding_start - usize::from(!self.signature.is_empty());
+        let (kind, number, total, bytes, nonce_number) = if i < self.data_frames {
+            (
+                header::KIND_DATA,
+                i,
+                self.data_frames,
+                self.data,
+                Some(i as u32),
+            )
+        } else if i < pieces_start {
+            (header::KIND_KEYS, 0, 1, &self.keys[..], None)
+        } else if i < signature_start {
+            let k = i - pieces_start;
+            (
+                header::KIND_PIECES,
+                k,
+                signature_start - pieces_start,
+                &self.pieces[..],
+                Some(PIECE_NONCE_BASE + k as u32),
+            )
+        } else if i < padding_start {
+            (
+                header::KIND_SIGNATURE,
+                0,
+                1,
+                &self.signature[..],
+                Some(SIGNATURE_NONCE),
+            )
+        } else {
+            let k = i - padding_start;
+            let total = frames - padding_start;
+            (header::KIND_PADDING, k, total, self.padding, None)
+        };
+        let config = self.config;
+        let (chunk_len, data_levels) = match kind {
+            header::KIND_DATA | header::KIND_PADDING => (self.per_frame, config.levels),
+            _ => (
+                self.meta_per_frame,
+                config.metadata_levels.unwrap_or(config.levels),
+            ),
+        };
+        let start = number * chunk_len;
+        let end = std::cmp::min(start + chunk_len, bytes.len());
+        let frame_data = match (&self.cipher, nonce_number) {
+            (Some(c), Some(n)) => c.encrypt_frame(n, &bytes[start..end])?,
+            _ => bytes[start..end].to_vec(),
+        };
+        let hdr = header::FrameHeader {
+            frame_number: number as u32,
+            total_frames: total as u32,
+            file_size: bytes.len() as u64,
+            kind,
+            part: part as u16,
+            total_parts: total_parts as u16,
+            data_levels,
+            ..base_header(config, self.nonce, self.salt, self.set_id)
+        };
+        Ok((hdr, frame_data))
+    }
+}
+
+/// Header of a single unencrypted data frame of payload 0 with `config`'s
+/// layout, for the plans to fill in.
+fn base_header(
+    config: &FrameConfig,
+    nonce: [u8; 12],
+    salt: [u8; 16],
+    set_id: [u8; 16],
+) -> header::FrameHeader {
+    header::FrameHeader {
+        version: PROTOCOL_VERSION,
+        frame_number: 0,
+        total_frames: 1,
+        block_size: config.block_size,
+        levels: config.levels,
+        file_size: 0,
+        data_length: 0,
+        ecc_len: config.ecc_len,
+        rs_data_len: config.rs_data_len() as u16,
+        nonce,
+        salt,
+        data_sha256: [0; 32],
+        kind: header::KIND_DATA,
+        payload: 0,
+        part: 0,
+        total_parts: 1,
+        set_id,
+        kdf: config.kdf,
+        token: config.token,
+        compression: config.compression,
+        data_levels: config.levels,
+        channel_levels: config.channel_levels(),
+        layout: config.layout,
+        crc_map: config.crc_map,
+        bottom_header: config.bottom_header,
+        banner: config.banner,
+        barcode: config.barcode,
+        pilots: config.pilots,
+        bin_centers: config.bin_centers,
+        streamed: false,
+    }
+}
+
+/// How a file of unknown length read from a stream is cut into frames:
+/// every data frame but the last records [`header::STREAMED_SIZE`] and no
+/// frame count, the last the file's size and frame count (see
+/// [`header::FrameHeader::streamed`]). The key frame, if encrypted to
+/// recipients, follows; there are no piece, signature or padding frames.
+pub(crate) struct StreamPlan<'a> {
+    config: &'a FrameConfig,
+    cipher: Option<crypto::FrameCipher>,
+    nonce: [u8; 12],
+    salt: [u8; 16],
+    pub set_id: [u8; 16],
+    /// Key list for the key frame (empty for none)
+    keys: Vec<u8>,
+    /// File bytes per data frame
+    pub per_frame: usize,
+}
+
+impl<'a> StreamPlan<'a> {
+    pub fn new(
+        config: &'a FrameConfig,
+        cipher: Option<crypto::FrameCipher>,
+        nonce: [u8; 12],
+        salt: [u8; 16],
+        keys: Vec<u8>,
+    ) -> Result<Self> {
+        check_streamable(config, &[], None)?;
+        let per_frame = config.max_plain_per_frame(cipher.is_some());
+        if per_frame == 0 {
+            return Err(VstorageError::Config(
+                "frame capacity is zero — check block_size/levels/ecc settings".into(),
+            ));
+        }
+        let meta_levels = config.metadata_levels.unwrap_or(config.levels);
+        let meta_per_frame = config
+            .with_levels(meta_levels)
+            .max_plain_per_frame(cipher.is_some());
+        if meta_per_frame == 0 {
+            return Err(VstorageError::Config(
+                "metadata frame capacity is zero — check metadata levels".into(),
+            ));
+        }
+        if keys.len() > meta_per_frame {
+            return Err(VstorageError::Config(
+                "too many recipients for one key frame".into(),
+            ));
+        }
+        let context = header::cipher_context(header::STREAMED_SIZE, 0, 0, config.compression);
+        Ok(Self {
+            config,
+            cipher: cipher.map(|c| c.with_context(context)),
+            nonce,
+            salt,
+            set_id: header::new_set_id(),
+            keys,
+            per_frame,
+        })
+    }
+
+    /// Read `reader` to its end, handing each frame to `emit` in order with
+    /// the number of file bytes it carries. Each chunk is held back until the
+    /// next is read, so the last data frame is known when it is drawn; an
+    /// empty stream gives one empty data frame. Returns the file's size and
+    /// SHA-256.
+    pub fn encode(
+        &self,
+        reader: &mut dyn Read,
+        emit: &mut dyn FnMut(RgbImage, usize) -> Result<()>,
+    ) -> Result<(u64, [u8; 32])> {
+        let mut hasher = Sha256::new();
+        let mut chunk = Zeroizing::new(vec![0u8; self.per_frame]);
+        let mut next = Zeroizing::new(vec![0u8; self.per_frame]);
+        let mut len = read_full(reader, &mut chunk)?;
+        let (mut i, mut file_size) = (0usize, 0u64);
+        loop {
+            let next_len = match len {
+                n if n == self.per_frame => read_full(reader, &mut next)?,
+                _ => 0,
+            };
+            hasher.update(&chunk[..len]);
+            file_size += len as u64;
+            let last = next_len == 0;
+            let frame_data = match &self.cipher {
+                Some(c) => c.encrypt_stream_frame(i as u32, last, &chunk[..len])?,
+                None => chunk[..len].to_vec(),
+            };
+            let (file_size, total) = match last {
+                true => (file_size, i as u32 + 1),
+                false => (header::STREAMED_SIZE, 0),
+            };
+            let hdr = header::FrameHeader {
+                frame_number: i as u32,
+                total_frames: total,
+                file_size,
+                streamed: true,
+                ..base_header(self.config, self.nonce, self.salt, self.set_id)
+            };
+            emit(render_frame(self.config, hdr, &frame_data), len)?;
+            if last {
+                break;
+            }
+            std::mem::swap(&mut chunk, &mut next);
+            (len, i) = (next_len, i + 1);
+        }
+        if !self.keys.is_empty() {
+            let config = self.config;
+            let hdr = header::FrameHeader {
+                file_size: self.keys.len() as u64,
+                kind: header::KIND_KEYS,
+                data_levels: config.metadata_levels.unwrap_or(config.levels),
+                streamed: true,
+                ..base_header(config, self.nonce, self.salt, self.set_id)
+            };
+            emit(render_frame(config, hdr, &self.keys), 0)?;
+        }
+        Ok((file_size, hasher.finalize().into()))
+    }
+}
+
+/// Fill `buf` from `reader`, short only at the end of the stream; returns
+/// the bytes read.
+fn read_full(reader: &mut dyn Read, buf: &mut [u8]) -> Result<usize> {
+    let mut filled = 0;
+    while filled < buf.len() {
+        match reader.read(&mut buf[filled..]) {
+            Ok(0) => break,
+            Ok(n) => filled += n,
+            Err(e) if e.kind() == std::io::ErrorKind::Interrupted => {}
+            Err(e) => return Err(e.into()),
+        }
+    }
+    Ok(filled)
+}
+
+/// Refuse what a streamed encode cannot do, since each of these needs the
+/// whole file before its first frame: compression, a deterministic seed, an
+/// intro frame, padding and a signature.
+pub fn check_streamable(
+    config: &FrameConfig,
+    padding: &[u8],
+    sign: Option<&SigningKey>,
+) -> Result<()> {
+    let refused = [
+        (config.compression != Compression::None, "compression"),
+        (config.seed.is_some(), "a seed"),
+        (config.intro, "an intro frame"),
+        (!padding.is_empty(), "padding"),
+        (sign.is_some(), "a signature"),
+    ];
+    match refused.iter().find(|(set, _)| *set) {
+        Some((_, what)) => Err(VstorageError::Config(format!(
+            "a streamed encode cannot use {what}; encode from a file instead"
+        ))),
+        None => Ok(()),
+    }
+}
+
+/// RS-encode `chunk` and draw it with `hdr` (whose `data_length` and
+/// `data_sha256` are filled in here) into a frame image.
+pub(crate) fn render_frame(
+    config: &FrameConfig,
+    hdr: header::FrameHeader,
+    chunk: &[u8],
+) -> image::RgbImage {
+    let mut img = RgbImage::new(0, 0);
+    render_frame_into(config, hdr, chunk, &mut img);
+    img
+}
+
+/// [`render_frame`] into `img`, reusing its buffer. The data area is drawn
+/// with the header's [`header::FrameHeader::area_levels`].
+pub(crate) fn render_frame_into(
+    config: &FrameConfig,
+    mut hdr: header::FrameHeader,
+    chunk: &[u8],
+    img: &mut RgbImage,
+) {
+    // RS encode (pads last chunk to full block)
+    let rs_encoded = ecc::rs_encode(
+        chunk,
+        config.ecc_len as usize,
+        config.rs_data_len(),
+        config.worker_threads(),
+    );
+
+    hdr.data_length = chunk.len() as u32;
+    hdr.data_sha256 = Sha256::digest(&rs_encoded).into();
+
+    let header_bytes = header::encode_header_copies(&hdr, config.header_copies);
+    let (levels, number) = (hdr.area_levels(), hdr.frame_number);
+    frame::paint_frame(img, &header_bytes, &rs_encoded, config, levels, number);
+    if config.banner {
+        frame::paint_banner(img, config, &crate::banner::text(&hdr));
+    }
+    if config.barcode {
+        frame::paint_barcode(img, config, (hdr.frame_number, hdr.kind));
+    }
+}
+
+/// Salt, nonce and set id of an encode of `data` (as framed, after
+/// compression) with `seed`, signed by `sign` if given. The salt comes from
+/// the seed alone; the nonce and set id also from everything the encrypted
+/// frames hold, so a key never meets the same nonce over different chunks.
+pub fn seeded_parameters(
+    seed: &[u8],
+    data: &[u8],
+    config: &FrameConfig,
+    sign: Option<&SigningKey>,
+) -> ([u8; 16], [u8; 12], [u8; 16]) {
+    let seed = Sha256::new()
+        .chain_update(b"vstorage-seed")
+        .chain_update(seed);
+    let meta_levels = config.metadata_levels.unwrap_or(config.levels);
+    let contents = seed
+        .clone()
+        .chain_update(Sha256::digest(data))
+        .chain_update((config.max_plain_per_frame(true) as u64).to_be_bytes())
+        .chain_update(
+            (config.with_levels(meta_levels).max_plain_per_frame(true) as u64).to_be_bytes(),
+        )
+        .chain_update(sign.map_or([0; 32], |k| k.verify_key().public));
+    let salt = seed.chain_update(b"salt").finalize();
+    let nonce = contents.clone().chain_update(b"nonce").finalize();
+    let mut set_id: [u8; 16] = contents.chain_update(b"set id").finalize()[..16]
+        .try_into()
+        .unwrap();
+    set_id[6] = (set_id[6] & 0x0f) | 0x40;
+    set_id[8] = (set_id[8] & 0x3f) | 0x80;
+    (
+        salt[..16].try_into().unwrap(),
+        nonce[..12].try_into().unwrap(),
+        set_id,
+    )
+}
+
+/// The bytes of a seed written in hex.
+pub fn parse_seed(hex: &str) -> Result<Vec<u8>> {
+    if hex.is_empty() || !hex.len().is_multiple_of(2) || !hex.bytes().all(|b| b.is_ascii_hexdigit())
+    {
+        return Err(VstorageError::Config(format!(
+            "invalid seed '{hex}' — expected an even number of hex digits"
+        )));
+    }
+    Ok((0..hex.len())
+        .step_by(2)
+        .map(|i| u8::from_str_radix(&hex[i..i + 2], 16).expect("hex digits checked"))
+        .collect())
+}
+
+/// Refuse what a seeded encode cannot make reproducible: a file key sealed
+/// to recipients and padding, both random. Otherwise warn that the frames
+/// give the file away.
+pub fn check_seeded(recipients: &[Recipient], padding: &[u8]) -> Result<()> {
+    if !recipients.is_empty() || !padding.is_empty() {
+        return Err(VstorageError::Config(
+            "a deterministic encode cannot use recipients or padding".into(),
+        ));
+    }
+    tracing::warn!(
+        "Deterministic encode: the same file, seed and password always give the \
+         same frames, so anyone can tell two such videos hold the same file"
+    );
+    Ok(())
+}
+
+/// Settings for [`encode_bytes`].
+#[derive(Debug, Clone)]
+pub struct EncodeOptions {
+    pub config: FrameConfig,
+    /// Encrypt each frame with a key derived from this password
+    pub password: Option<String>,
+    /// Or encrypt to these public keys instead (see [`crate::recipient`])
+    pub recipients: Vec<Recipient>,
+    /// Contents of padding frames after the file, from
+    /// [`crate::hidden::padding`] (empty for none)
+    pub padding: Vec<u8>,
+    /// Sign the file with this key
+    pub sign: Option<SigningKey>,
+}
+
+impl EncodeOptions {
+    /// Unencrypted, unsigned and without padding.
+    pub fn new(config: FrameConfig) -> Self {
+        Self {
+            config,
+            password: None,
+            recipients: Vec::new(),
+            padding: Vec::new(),
+            sign: None,
+        }
+    }
+}
+
+/// Settings for [`decode_frames`]; `Default` detects the layout and reads an
+/// unencrypted payload 0.
+#[derive(Debug, Clone, Default)]
+pub struct DecodeOptions {
+    pub password: Option<String>,
+    /// Private key for a payload encrypted to recipients
+    pub identity: Option<Identity>,
+    /// Payload of an appended-to video; 0 is the originally encoded file
+    pub payload: u16,
+    /// Skip auto-detection and read every frame with this layout
+    pub forced: Option<FrameConfig>,
+    /// Fail unless the file is signed by this key
+    pub verify_key: Option<VerifyKey>,
+    /// Cap on worker threads (see [`FrameConfig::threads`])
+    pub threads: Option<usize>,
+    /// Token answering for a key that needs one (see
+    /// [`FrameConfig::hardware_token`])
+    pub hardware_token: Option<Arc<dyn Token>>,
+}
+
+/// Encode `data` into frame images, in order, exactly as `encode` would
+/// before handing them to ffmpeg. No files are read or written.
+pub fn encode_bytes(data: &[u8], options: &EncodeOptions) -> Result<Vec<RgbImage>> {
+    let compressed;
+    let data = match options.config.compression {
+        Compression::None => data,
+        compression => {
+            compressed = Zeroizing::new(compression.compress(data)?);
+            &compressed[..]
+        }
+    };
+    let seeded = match &options.config.seed {
+        Some(seed) => {
+            check_seeded(&options.recipients, &options.padding)?;
+            Some(seeded_parameters(
+                seed,
+                data,
+                &options.config,
+                options.sign.as_ref(),
+            ))
+        }
+        None => None,
+    };
+    let (cipher, nonce, salt, keys) = frame_cipher(options, seeded)?;
+    let mut plan = FramePlan::new(&options.config, data, cipher, nonce, salt, keys)?;
+    if let Some(key) = &options.sign {
+        plan = plan.with_signature(key);
+    }
+    let mut plan = plan.with_padding(&options.padding);
+    if let Some((_, _, set_id)) = seeded {
+        plan.set_id = set_id;
+    }
+    (0..plan.num_frames).map(|i| plan.render(i, 0, 1)).collect()
+}
+
+/// Encode everything `reader` yields, its length unknown until it ends, into
+/// frame images as a streamed encode does (see [`StreamPlan`]). The options
+/// must pass [`check_streamable`].
+pub fn encode_reader(reader: &mut dyn Read, options: &EncodeOptions) -> Result<Vec<RgbImage>> {
+    check_streamable(&options.config, &options.padding, options.sign.as_ref())?;
+    let (cipher, nonce, salt, keys) = frame_cipher(options, None)?;
+    let plan = StreamPlan::new(&options.config, cipher, nonce, salt, keys)?;
+    let mut frames = Vec::new();
+    plan.encode(reader, &mut |img, _| {
+        frames.push(img);
+        Ok(())
+    })?;
+    Ok(frames)
+}
+
+/// Frame cipher, nonce, salt and key list of an encode.
+type Encryption = (Option<crypto::FrameCipher>, [u8; 12], [u8; 16], Vec<u8>);
+
+/// The [`Encryption`] `options` ask for, with the salt and nonce of `seeded`
+/// if given.
+fn frame_cipher(
+    options: &EncodeOptions,
+    seeded: Option<([u8; 16], [u8; 12], [u8; 16])>,
+) -> Result<Encryption> {
+    Ok(match (&options.password, &options.recipients[..]) {
+        (Some(_), [_, ..]) => {
+            return Err(VstorageError::Config(
+                "give a password or recipients, not both".into(),
+            ));
+        }
+        (None, recipients @ [_, ..]) => {
+            let (cipher, nonce, keys) = recipient::seal(recipients)?;
+            (Some(cipher), nonce, [0u8; 16], keys)
+        }
+        (Some(pw), []) => {
+            let (cipher, nonce, salt) = match seeded {
+                Some((salt, nonce, _)) => (
+                    crypto::FrameCipher::new(
+                        pw,
+                        options.config.kdf,
+                        options.config.token,
+                        options.config.hardware_token(),
+                        &salt,
+                        nonce,
+                    )?,
+                    nonce,
+                    salt,
+                ),
+                None => crypto::FrameCipher::generate(
+                    pw,
+                    options.config.kdf,
+                    options.config.token,
+                    options.config.hardware_token(),
+                )?,
+            };
+            (Some(cipher), nonce, salt, Vec::new())
+        }
+        (None, []) => (None, [0u8; 12], [0u8; 16], Vec::new()),
+    })
+}
+
+/// Decode frame images (in any order, duplicates allowed) back into the
+/// stored file. Every frame must be recoverable; errors match `decode`.
+pub fn decode_frames(frames: &[RgbImage], options: &DecodeOptions) -> Result<Vec<u8>> {
+    if frames.is_empty() {
+        return Err(VstorageError::Config("no frames given".into()));
+    }
+    let (first, later) = frames.split_at(frames.len().min(collect::DETECT_FRAMES));
+    let later = later.iter().cloned().map(Ok);
+    let (config, preprocess) =
+        collect::choose_config(options.forced.as_ref(), first, later, options.threads)?;
+    let mut collector = FrameCollector::new(options.payload, config, preprocess, false);
+    for img in frames {
+        collector.add(&"input", img)?;
+    }
+    let mut collected = collector.finish()?;
+    collected.ensure_complete()?;
+    let cipher = match collect::is_public_key(&collected.first_header) {
+        true => Some(collected.recipient_cipher(options.identity.as_ref())?),
+        false => collect::cipher_for(
+            &collected.first_header,
+            options.password.as_deref(),
+            options.hardware_token.as_deref(),
+        )?,
+    };
+    let password = options.password.as_deref();
+    let (mut data, report) =
+        collect::assemble_or_hidden(&mut collected, cipher.as_ref(), password, false)?;
+    if report.is_none() {
+        // A hidden payload has no piece or signature frames of its own
+        return match options.verify_key {
+            Some(_) => Err(VstorageError::Signature(
+                "a hidden payload is not signed".into(),
+            )),
+            // Handed over to the caller, not copied
+            None => Ok(std::mem::take(&mut *data)),
+        };
+    }
+    if let Some(pieces) = collected.check_pieces(cipher.as_ref(), &data) {
+        if !pieces.damaged.is_empty() {
+            return Err(VstorageError::PieceMismatch(pieces.damaged));
+        }
+        if pieces.file_ok == Some(false) {
+            return Err(VstorageError::FileHashMismatch("the decoded file".into()));
+        }
+    }
+    collected.check_signature(cipher.as_ref(), &data, options.verify_key.as_ref())?;
+    match collected.first_header.compression {
+        Compression::None => Ok(std::mem::take(&mut *data)),
+        compression => compression.decompress(&data),
+    }
+}
+
+/// Convert RGBA pixels (e.g. a canvas `ImageData` of a video frame) into a
+/// frame image for [`decode_frames`]; alpha is ignored.
+pub fn frame_from_rgba(width: u32, height: u32, rgba: &[u8]) -> Result<RgbImage> {
+    if rgba.len() != width as usize * height as usize * 4 {
+        return Err(VstorageError::Config(format!(
+            "{} RGBA bytes do not make a {width}x{height} frame",
+            rgba.len()
+        )));
+    }
+    let rgb = rgba
+        .chunks_exact(4)
+        .flat_map(|p| [p[0], p[1], p[2]])
+        .collect();
+    Ok(RgbImage::from_raw(width, height, rgb).expect("length checked"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_bytes_roundtrip() {
+        let mut config = FrameConfig::new(8, 2, 32, 30, 18).unwrap();
+        // Decoding picks the KDF up from the headers
+        config.kdf = crate::crypto::Kdf::Scrypt;
+        let data: Vec<u8> =
+            crate::testutil::pattern(config.max_plain_per_frame(true) + 100, 7, 256);
+        let options = EncodeOptions {
+            password: Some("hunter2".into()),
+            ..EncodeOptions::new(config)
+        };
+        let mut frames = encode_bytes(&data, &options).unwrap();
+        // Two data frames and the piece frame
+        assert_eq!(frames.len(), 3);
+
+        frames.reverse();
+        let rgba: Vec<u8> = frames[0]
+            .pixels()
+            .flat_map(|p| [p[0], p[1], p[2], 255])
+            .collect();
+        frames[0] = frame_from_rgba(frames[0].width(), frames[0].height(), &rgba).unwrap();
+        let mut decode_options = DecodeOptions {
+            password: Some("hunter2".into()),
+            ..Default::default()
+        };
+        assert_eq!(decode_frames(&frames, &decode_options).unwrap(), data);
+
+        decode_options.password = Some("wrong".into());
+        assert!(matches!(
+            decode_frames(&frames, &decode_options),
+            Err(VstorageError::WrongPassword)
+        ));
+    }
+
+    /// A frame as the first release painted it: three 90-byte headers in the
+    /// two top rows of blocks, then the RS blocks, in raster order.
+    fn version_1_frame(header: &[u8], rs_data: &[u8], block_size: u32, levels: u8) -> RgbImage {
+        let (lw, lh) = (3840 / block_size, 2160 / block_size);
+        let bits = levels.trailing_zeros() as u8;
+        let mut img = RgbImage::new(3840, 2160);
+        let mut paint = |rows: std::ops::Range<u32>, data: &[u8]| {
+            let mut reader = frame::BitReader::new(data);
+            for ly in rows {
+                for lx in 0..lw {
+                    let rgb = [(); 3].map(|_| frame::quantize(reader.read_bits(bits), levels));
+                    for (dx, dy) in
+                        (0..block_size).flat_map(|x| (0..block_size).map(move |y| (x, y)))
+                    {
+                        img.put_pixel(lx * block_size + dx, ly * block_size + dy, image::Rgb(rgb));
+                    }
+                }
+            }
+        };
+        paint(0..2, header);
+        paint(2..lh, rs_data);
+        img
+    }
+
+    #[test]
+    fn test_version_1_frames() {
+        let data = crate::testutil::pattern(100_000, 11, 251);
+        // Encrypted as one message, then cut into frames of whole RS blocks
+        let (sealed, nonce, salt) = crypto::encrypt(&data, "hunter2").unwrap();
+        let max_raw = 480 * 268 * 6 / 8 / 255 * 223;
+        let config = FrameConfig::new(8, 4, 32, 30, 18).unwrap();
+        let frames: Vec<RgbImage> = sealed
+            .chunks(max_raw)
+            .enumerate()
+            .map(|(i, chunk)| {
+                let rs = ecc::rs_encode(chunk, 32, 223, 4);
+                let hdr = header::FrameHeader {
+                    version: 1,
+                    frame_number: i as u32,
+                    total_frames: sealed.len().div_ceil(max_raw) as u32,
+                    file_size: data.len() as u64,
+                    data_length: chunk.len() as u32,
+                    data_sha256: Sha256::digest(&rs).into(),
+                    ..base_header(&config, nonce, salt, [0; 16])
+                };
+                let v1 = &hdr.serialize()[..header::HEADER_SIZE_V1];
+                version_1_frame(&v1.repeat(3), &rs, 8, 4)
+            })
+            .collect();
+        assert_eq!(frames.len(), 2);
+
+        let options = DecodeOptions {
+            password: Some("hunter2".into()),
+            ..Default::default()
+        };
+        assert_eq!(decode_frames(&frames, &options).unwrap(), data);
+        let options = DecodeOptions {
+            password: Some("wrong".into()),
+            ..Default::default()
+        };
+        assert!(matches!(
+            decode_frames(&frames, &options),
+            Err(VstorageError::WrongPassword)
+        ));
+        // Every frame is needed to open the one message
+        assert!(matches!(
+            decode_frames(&frames[..1], &options),
+            Err(VstorageError::MissingFrames { .. })
+        ));
+    }
+
+    #[test]
+    fn test_streamed_roundtrip() {
+        let mut config = FrameConfig::new(8, 2, 32, 30, 18).unwrap();
+        config.kdf = crate::crypto::Kdf::Scrypt;
+        let per_frame = config.max_plain_per_frame(true);
+        let data = crate::testutil::pattern(per_frame * 5 / 2, 13, 256);
+        let mut options = EncodeOptions {
+            password: Some("hunter2".into()),
+            ..EncodeOptions::new(config)
+        };
+        // Read a few bytes at a time, as from a pipe
+        let mut reader = std::io::Read::chain(&data[..100], &data[100..]);
+        let mut frames = encode_reader(&mut reader, &options).unwrap();
+        assert_eq!(frames.len(), 3);
+        frames.reverse();
+        let decode_options = DecodeOptions {
+            password: Some("hunter2".into()),
+            ..Default::default()
+        };
+        assert_eq!(decode_frames(&frames, &decode_options).unwrap(), data);
+        // Without the last frame the size is unknown
+        assert!(matches!(
+            decode_frames(&frames[1..], &decode_options),
+            Err(VstorageError::Header(_))
+        ));
+
+        // An exact number of frames, and an empty stream
+        options.password = None;
+        let per_frame = options.config.max_plain_per_frame(false);
+        let frames = encode_reader(&mut &data[..per_frame * 2], &options).unwrap();
+        assert_eq!(frames.len(), 2);
+        let decoded = decode_frames(&frames, &DecodeOptions::default()).unwrap();
+        assert_eq!(decoded, data[..per_frame * 2]);
+        let frames = encode_reader(&mut &[][..], &options).unwrap();
+        assert_eq!(frames.len(), 1);
+        assert!(decode_frames(&frames, &DecodeOptions::default())
+            .unwrap()
+            .is_empty());
+
+        options.config.compression = Compression::Lz4;
+        assert!(encode_reader(&mut &data[..], &options).is_err());
+    }
+
+    #[test]
+    fn test_truncated_stream_fails() {
+        let mut config = FrameConfig::new(8, 2, 32, 30, 18).unwrap();
+        config.kdf = crate::crypto::Kdf::Scrypt;
+        let per_frame = config.max_plain_per_frame(true);
+        let data = crate::testutil::pattern(per_frame * 5 / 2, 7, 256);
+        let (nonce, salt) = ([3; 12], [5; 16]);
+        let cipher =
+            || crypto::FrameCipher::new("pw", config.kdf, config.token, None, &salt, nonce);
+        let plan = StreamPlan::new(&config, Some(cipher().unwrap()), nonce, salt, Vec::new());
+        let plan = plan.unwrap();
+        let mut frames = Vec::new();
+        plan.encode(&mut &data[..], &mut |img, _| {
+            frames.push(img);
+            Ok(())
+        })
+        .unwrap();
+        assert_eq!(frames.len(), 3);
+
+        // Drop the last frame and relabel the second, its sealed chunk
+        // copied as it is, as the end of a shorter file
+        let context = header::cipher_context(header::STREAMED_SIZE, 0, 0, config.compression);
+        let sealed = cipher()
+            .unwrap()
+            .with_context(context)
+            .encrypt_frame(1, &data[per_frame..per_frame * 2])
+            .unwrap();
+        let hdr = header::FrameHeader {
+            frame_number: 1,
+            total_frames: 2,
+            file_size: per_frame as u64 * 2,
+            streamed: true,
+            ..base_header(&config, nonce, salt, plan.set_id)
+        };
+        frames.truncate(1);
+        frames.push(render_frame(&config, hdr, &sealed));
+        let options = DecodeOptions {
+            password: Some("pw".into()),
+            ..Default::default()
+        };
+        assert!(matches!(
+            decode_frames(&frames, &options),
+            Err(VstorageError::AuthFailed { frame: 1 })
+        ));
+    }
+
+    #[test]
+    fn test_seeded_encode_repeats() {
+        let mut config = FrameConfig::new(8, 2, 32, 30, 18).unwrap();
+        config.kdf = crate::crypto::Kdf::Scrypt;
+        config.seed = Some(parse_seed("00c0ffee").unwrap());
+        let data = crate::testutil::pattern(5000, 11, 256);
+        let mut options = EncodeOptions {
+            password: Some("hunter2".into()),
+            ..EncodeOptions::new(config)
+        };
+        let frames = encode_bytes(&data, &options).unwrap();
+        assert_eq!(encode_bytes(&data, &options).unwrap(), frames);
+        let decode_options = DecodeOptions {
+            password: Some("hunter2".into()),
+            ..Default::default()
+        };
+        assert_eq!(decode_frames(&frames, &decode_options).unwrap(), data);
+
+        // Other data changes the nonce; no seed, a random salt and nonce
+        assert_ne!(encode_bytes(&data[1..], &options).unwrap()[0], frames[0]);
+        options.config.seed = None;
+        assert_ne!(encode_bytes(&data, &options).unwrap(), frames);
+
+        options.config.seed = Some(vec![0]);
+        options.padding = vec![0; 10];
+        assert!(matches!(
+            encode_bytes(&data, &options),
+            Err(VstorageError::Config(_))
+        ));
+        assert!(parse_seed("abc").is_err() && parse_seed("xy").is_err());
+    }
+
+    #[test]
+    fn test_one_thread_same_frames() {
+        // Small blocks give each frame enough RS blocks to split
+        let seeded = |threads| {
+            FrameConfig::builder()
+                .block_size(2)
+                .levels(4)
+                .seed(vec![7])
+                .threads(threads)
+                .build()
+                .unwrap()
+        };
+        let data = crate::testutil::pattern(300_000, 13, 251);
+        let encode = |threads| {
+            let options = EncodeOptions {
+                password: Some("hunter2".into()),
+                ..EncodeOptions::new(seeded(threads))
+            };
+            encode_bytes(&data, &options).unwrap()
+        };
+        let frames = encode(None);
+        assert_eq!(encode(Some(1)), frames);
+        assert_eq!(encode(Some(4)), frames);
+        for threads in [Some(1), None] {
+            let options = DecodeOptions {
+                password: Some("hunter2".into()),
+                threads,
+                ..Default::default()
+            };
+            assert_eq!(decode_frames(&frames, &options).unwrap(), data);
+        }
+    }
+
+    #[test]
+    fn test_bytes_roundtrip_to_recipient() {
+        let config = FrameConfig::new(8, 2, 32, 30, 18).unwrap();
+        let data = b"only the offline key can read this".to_vec();
+        let identity = Identity::generate();
+        let options = EncodeOptions {
+            recipients: vec![Identity::generate().recipient(), identity.recipient()],
+            ..EncodeOptions::new(config)
+        };
+        // Data, key and piece frame
+        let frames = encode_bytes(&data, &options).unwrap();
+        assert_eq!(frames.len(), 3);
+
+        let mut decode_options = DecodeOptions::default();
+        assert!(matches!(
+            decode_frames(&frames, &decode_options),
+            Err(VstorageError::IdentityRequired)
+        ));
+        decode_options.identity = Some(identity);
+        assert_eq!(decode_frames(&frames, &decode_options).unwrap(), data);
+        decode_options.identity = Some(Identity::generate());
+        assert!(matches!(
+            decode_frames(&frames, &decode_options),
+            Err(VstorageError::WrongIdentity)
+        ));
+    }
+
+    #[test]
+    fn test_unencrypted_frame_hash_checked() {
+        let config = FrameConfig::new(8, 2, 32, 30, 18).unwrap();
+        let options = EncodeOptions::new(config.clone());
+        let mut frames = encode_bytes(b"plaintext archive", &options).unwrap();
+        assert_eq!(
+            decode_frames(&frames, &DecodeOptions::default()).unwrap(),
+            b"plaintext archive"
+        );
+
+        // Data that RS-decodes cleanly but is not what the header hashed, as
+        // after a miscorrection
+        let hdr = header::decode_header_triple(&frame::decode_header_area(
+            &frames[0],
+            config.block_size,
+            config.levels,
+        ))
+        .unwrap();
+        let rotten = ecc::rs_encode(
+            b"plaintext arch1ve",
+            config.ecc_len as usize,
+            config.rs_data_len(),
+            config.worker_threads(),
+        );
+        frames[0] =
+            frame::encode_frame_to_image(&header::encode_header_triple(&hdr), &rotten, &config);
+        assert!(matches!(
+            decode_frames(&frames, &DecodeOptions::default()),
+            Err(VstorageError::HashMismatch { frame: 0 })
+        ));
+    }
+
+    #[test]
+    fn test_compressed_roundtrip() {
+        let mut config = FrameConfig::new(8, 2, 32, 30, 18).unwrap();
+        config.compression = Compression::Lz4;
+        let data = b"highly repetitive ".repeat(config.max_plain_per_frame(true) / 4);
+        let options = EncodeOptions {
+            password: Some("hunter2".into()),
+            ..EncodeOptions::new(config)
+        };
+        // Five frames of data fit in one once compressed
+        let mut frames = encode_bytes(&data, &options).unwrap();
+        assert_eq!(frames.len(), 2);
+        let decode_options = DecodeOptions {
+            password: Some("hunter2".into()),
+            ..Default::default()
+        };
+        assert_eq!(decode_frames(&frames, &decode_options).unwrap(), data);
+
+        // A header claiming no compression would hand back the compressed
+        // bytes as the file; the chunk no longer authenticates instead (and
+        // with every data frame failing, that reads as a wrong password)
+        let config = &options.config;
+        let mut hdr = header::decode_header_triple(&frame::decode_header_area(
+            &frames[0],
+            config.block_size,
+            config.levels,
+        ))
+        .unwrap();
+        hdr.compression = Compression::None;
+        let rs_data = frame::decode_data_area(&frames[0], config, hdr.area_levels(), 0);
+        frames[0] =
+            frame::encode_frame_to_image(&header::encode_header_triple(&hdr), &rs_data, config);
+        let err = decode_frames(&frames, &decode_options).unwrap_err();
+        assert!(matches!(err, VstorageError::WrongPassword), "{err}");
+    }
+
+    #[test]
+    fn test_levels_per_frame_and_channel() {
+        let mut config = FrameConfig::new(8, 4, 32, 30, 18).unwrap();
+        config.set_channel_levels([4, 4, 2]).unwrap();
+        config.set_metadata_levels(2).unwrap();
+        config.layout = crate::layout::Layout::Hilbert;
+        let data = vec![0x5Au8; config.max_plain_per_frame(true) + 10];
+        let options = EncodeOptions {
+            password: Some("hunter2".into()),
+            ..EncodeOptions::new(config.clone())
+        };
+        let frames = encode_bytes(&data, &options).unwrap();
+        assert_eq!(frames.len(), 3);
+        let levels: Vec<[u8; 3]> = frames
+            .iter()
+            .map(|img| {
+                let area = frame::decode_header_area(img, config.block_size, config.levels);
+                header::decode_header_triple(&area).unwrap().area_levels()
+            })
+            .collect();
+        assert_eq!(levels, [[4, 4, 2], [4, 4, 2], [2, 2, 2]]);
+
+        let decode_options = DecodeOptions {
+            password: Some("hunter2".into()),
+            ..Default::default()
+        };
+        assert_eq!(decode_frames(&frames, &decode_options).unwrap(), data);
+    }
+
+    #[test]
+    fn test_bottom_header_survives_cropped_top() {
+        let mut config = FrameConfig::new(8, 2, 32, 30, 18).unwrap();
+        config.bottom_header = true;
+        config.crc_map = true;
+        config.banner = true;
+        let data = vec![0xC3u8; config.max_plain_per_frame(false) + 10];
+        let options = EncodeOptions::new(config.clone());
+        let mut frames = encode_bytes(&data, &options).unwrap();
+        // The banner sits right above the bottom header copy
+        let banner_top = (config.logical_height() - 4) as u32 * 8;
+        let lit = (banner_top..banner_top + 16)
+            .flat_map(|y| (0..200).map(move |x| (x, y)))
+            .filter(|&(x, y)| frames[0].get_pixel(x, y)[0] == 255);
+        assert!(lit.count() > 100);
+
+        // A banner burned over the top of the video
+        for img in &mut frames {
+            for y in 0..16 {
+                for x in 0..img.width() {
+                    img.put_pixel(x, y, image::Rgb([255, 255, 255]));
+                }
+            }
+        }
+        let area = frame::decode_header_area(&frames[1], 8, 2);
+        assert_eq!(header::decode_header_triple(&area).unwrap().frame_number, 1);
+        assert_eq!(
+            decode_frames(&frames, &DecodeOptions::default()).unwrap(),
+            data
+        );
+    }
+
+    #[test]
+    fn test_header_redundancy_roundtrip() {
+        let data = crate::testutil::pattern(5000, 13, 256);
+        let encode = |config: FrameConfig| {
+            let options = EncodeOptions::new(config);
+            encode_bytes(&data, &options).unwrap()
+        };
+        for (rows, copies) in [(1, 1), (4, 7)] {
+            let mut config = FrameConfig::new(8, 4, 32, 30, 18).unwrap();
+            config.set_header_redundancy(rows, copies).unwrap();
+            config.bottom_header = true;
+            let mut frames = encode(config);
+            assert_eq!(frame::header_layout(&frames[0], 8), (rows, copies));
+            assert_eq!(
+                decode_frames(&frames, &DecodeOptions::default()).unwrap(),
+                data
+            );
+
+            // The top header area wiped: the bottom copy's preamble places it
+            for img in &mut frames {
+                for y in 0..rows as u32 * 8 {
+                    for x in 0..img.width() {
+                        img.put_pixel(x, y, image::Rgb([0, 0, 0]));
+                    }
+                }
+            }
+            assert_eq!(
+                decode_frames(&frames, &DecodeOptions::default()).unwrap(),
+                data
+            );
+        }
+
+        // Five rows of 16x16 blocks at two levels for the three copies that
+        // two rows cannot hold
+        let config = FrameConfig::builder()
+            .block_size(16)
+            .levels(2)
+            .header_redundancy(5, 3)
+            .build()
+            .unwrap();
+        let frames = encode(config);
+        assert_eq!(
+            decode_frames(&frames, &DecodeOptions::default()).unwrap(),
+            data
+        );
+
+        // One row at two levels holds a single copy beside the preamble
+        let mut config = FrameConfig::new(8, 2, 32, 30, 18).unwrap();
+        assert!(config.set_header_redundancy(1, 3).is_err());
+        assert!(config.set_header_redundancy(0, 1).is_err());
+        // Frames with the default header area have no preamble to misread
+        for levels in [2, 4, 8, 16] {
+            let frames = encode(FrameConfig::new(8, levels, 32, 30, 18).unwrap());
+            assert_eq!(frame::header_layout(&frames[0], 8), (2, 3));
+        }
+    }
+
+    #[test]
+    fn test_barcode_places_headerless_frame() {
+        let mut config = FrameConfig::new(8, 2, 32, 30, 18).unwrap();
+        config.barcode = true;
+        let data: Vec<u8> =
+            crate::testutil::pattern(config.max_plain_per_frame(false) * 2 + 10, 7, 251);
+        let options = EncodeOptions::new(config);
+        let mut frames = encode_bytes(&data, &options).unwrap();
+        // The last data frame's header wiped out, and the frame moved ahead
+        // of the one it follows
+        for y in 0..16 {
+            for x in 0..frames[2].width() {
+                frames[2].put_pixel(x, y, image::Rgb([0, 0, 0]));
+            }
+        }
+        frames.swap(1, 2);
+        assert_eq!(
+            decode_frames(&frames, &DecodeOptions::default()).unwrap(),
+            data
+        );
+    }
+
+    #[test]
+    fn test_damaged_first_header_outvoted() {
+        let config = FrameConfig::new(8, 2, 32, 30, 18).unwrap();
+        let data: Vec<u8> =
+            crate::testutil::pattern(config.max_plain_per_frame(false) * 2 + 10, 5, 256);
+        let options = EncodeOptions::new(config.clone());
+        let frames = encode_bytes(&data, &options).unwrap();
+        let options = DecodeOptions::default();
+
+        // A first header with a wrong file size (and a valid CRC) is outvoted
+        let area = frame::decode_header_area(&frames[0], 8, 2);
+        let mut hdr = header::decode_header_triple(&area).unwrap();
+        hdr.file_size += 1;
+        let rs = frame::decode_data_area(&frames[0], &config, hdr.area_levels(), 0);
+        let mut frames_in = frames.clone();
+        frames_in[0] =
+            frame::encode_frame_to_image(&header::encode_header_triple(&hdr), &rs, &config);
+        assert_eq!(decode_frames(&frames_in, &options).unwrap(), data);
+    }
+
+    #[test]
+    fn test_shifted_frames_realigned() {
+        let options = EncodeOptions::new(FrameConfig::new(2, 2, 32, 30, 18).unwrap());
+        let data = crate::testutil::pattern(3000, 13, 256);
+        let frames = encode_bytes(&data, &options).unwrap();
+        // Moved a pixel right and down, or left, by a re-encode
+        for (dx, dy) in [(1i64, 1i64), (-1, 0)] {
+            let shifted: Vec<RgbImage> = frames
+                .iter()
+                .map(|img| {
+                    let (w, h) = img.dimensions();
+                    RgbImage::from_fn(w, h, |x, y| {
+                        let sx = (x as i64 - dx).clamp(0, w as i64 - 1) as u32;
+                        let sy = (y as i64 - dy).clamp(0, h as i64 - 1) as u32;
+                        *img.get_pixel(sx, sy)
+                    })
+                })
+                .collect();
+            let area = frame::decode_header_area(&shifted[0], 2, 2);
+            assert!(header::decode_header_triple(&area).is_err());
+            let decoded = decode_frames(&shifted, &DecodeOptions::default()).unwrap();
+            assert_eq!(decoded, data);
+        }
+    }
+
+    #[test]
+    fn test_intro_frame_skipped() {
+        let mut config = FrameConfig::new(8, 2, 32, 30, 18).unwrap();
+        config.intro = true;
+        let data = b"for whoever finds this".to_vec();
+        let options = EncodeOptions {
+            password: Some("hunter2".into()),
+            ..EncodeOptions::new(config)
+        };
+        let frames = encode_bytes(&data, &options).unwrap();
+        assert_eq!(frames.len(), 3);
+        let area = frame::decode_header_area(&frames[0], 8, 2);
+        let intro = header::decode_header_triple(&area).unwrap();
+        assert_eq!((intro.kind, intro.file_size), (header::KIND_INTRO, 22));
+        // White page, black text
+        assert_eq!(*frames[0].get_pixel(0, 40), image::Rgb([255, 255, 255]));
+        let decode_options = DecodeOptions {
+            password: Some("hunter2".into()),
+            ..Default::default()
+        };
+        assert_eq!(decode_frames(&frames, &decode_options).unwrap(), data);
+    }
+
+    #[test]
+    fn test_signed_roundtrip() {
+        let config = FrameConfig::new(8, 2, 32, 30, 18).unwrap();
+        let data = b"a file worth vouching for".to_vec();
+        let key = SigningKey::generate();
+        let mut options = EncodeOptions {
+            password: Some("hunter2".into()),
+            sign: Some(key.clone()),
+            ..EncodeOptions::new(config)
+        };
+        // Data, piece and signature frame
+        let frames = encode_bytes(&data, &options).unwrap();
+        assert_eq!(frames.len(), 3);
+
+        let mut decode_options = DecodeOptions {
+            password: Some("hunter2".into()),
+            ..Default::default()
+        };
+        assert_eq!(decode_frames(&frames, &decode_options).unwrap(), data);
+        decode_options.verify_key = Some(key.verify_key());
+        assert_eq!(decode_frames(&frames, &decode_options).unwrap(), data);
+        decode_options.verify_key = Some(SigningKey::generate().verify_key());
+        assert!(matches!(
+            decode_frames(&frames, &decode_options),
+            Err(VstorageError::Signature(_))
+        ));
+
+        options.sign = None;
+        let unsigned = encode_bytes(&data, &options).unwrap();
+        decode_options.verify_key = Some(key.verify_key());
+        assert!(matches!(
+            decode_frames(&unsigned, &decode_options),
+            Err(VstorageError::Signature(_))
+        ));
+    }
+
+    #[test]
+    fn test_hidden_payload_in_padding() {
+        let config = FrameConfig::new(8, 2, 32, 30, 18).unwrap();
+        let hidden = b"the file that is really stored".to_vec();
+        let options = EncodeOptions {
+            password: Some("decoy".into()),
+            padding: crate::hidden::padding(
+                config.max_plain_per_frame(true) * 2,
+                Some((&hidden, "inner")),
+            )
+            .unwrap(),
+            ..EncodeOptions::new(config.clone())
+        };
+        // Data, piece and two padding frames
+        let frames = encode_bytes(b"a decoy", &options).unwrap();
+        assert_eq!(frames.len(), 4);
+
+        let mut decode_options = DecodeOptions {
+            password: Some("decoy".into()),
+            ..Default::default()
+        };
+        assert_eq!(decode_frames(&frames, &decode_options).unwrap(), b"a decoy");
+        decode_options.password = Some("inner".into());
+        assert_eq!(decode_frames(&frames, &decode_options).unwrap(), hidden);
+        decode_options.password = Some("neither".into());
+        assert!(matches!(
+            decode_frames(&frames, &decode_options),
+            Err(VstorageError::WrongPassword)
+        ));
+    }
+}
@@ -0,0 +1,148 @@
+use std::ops::Deref;
+use std::path::Path;
+
+use zeroize::Zeroizing;
+
+use crate::error::Result;
+
+/// Contents of an input file. Regular files are mapped read-only, so the page
+/// cache holds them and nothing is read up front; other data is held in
+/// memory and wiped when dropped.
+pub(crate) enum FileData {
+    #[cfg(unix)]
+    Mapped(Mmap),
+    Owned(Zeroizing<Vec<u8>>),
+}
+
+impl FileData {
+    /// Map the file at `path`, or read it if it cannot be mapped (e.g. it is
+    /// empty or not a regular file).
+    ///
+    /// # Safety
+    ///
+    /// See [`Mmap::open`]: the file must not be truncated or written while
+    /// the value lives.
+    pub unsafe fn open(path: &Path) -> Result<Self> {
+        #[cfg(unix)]
+        // SAFETY: passed on to the caller
+        if let Some(map) = unsafe { Mmap::open(path)? } {
+            return Ok(Self::Mapped(map));
+        }
+        Ok(Self::Owned(Zeroizing::new(std::fs::read(path)?)))
+    }
+}
+
+impl From<Vec<u8>> for FileData {
+    fn from(data: Vec<u8>) -> Self {
+        Self::Owned(Zeroizing::new(data))
+    }
+}
+
+impl Deref for FileData {
+    type Target = [u8];
+
+    fn deref(&self) -> &[u8] {
+        match self {
+            #[cfg(unix)]
+            Self::Mapped(map) => map,
+            Self::Owned(data) => data,
+        }
+    }
+}
+
+/// A read-only, private mapping of a whole file.
+#[cfg(unix)]
+pub(crate) struct Mmap {
+    ptr: *mut libc::c_void,
+    len: usize,
+}
+
+// SAFETY: the mapping is read-only and owned by this value alone
+#[cfg(unix)]
+unsafe impl Send for Mmap {}
+#[cfg(unix)]
+unsafe impl Sync for Mmap {}
+
+#[cfg(unix)]
+impl Mmap {
+    /// Map the file at `path`; `None` for an empty or non-regular file.
+    ///
+    /// # Safety
+    ///
+    /// The file must not be truncated or written, by this process or any
+    /// other, while the mapping lives: the bytes it derefs to would change
+    /// under a shared borrow, or fault past a new end of the file.
+    pub unsafe fn open(path: &Path) -> Result<Option<Self>> {
+        use std::os::unix::io::AsRawFd;
+
+        let file = std::fs::File::open(path)?;
+        let meta = file.metadata()?;
+        if !meta.is_file() || meta.len() == 0 {
+            return Ok(None);
+        }
+        let len = meta.len() as usize;
+        // SAFETY: a fresh read-only mapping of an open file; the fd may be
+        // closed once it exists
+        let ptr = unsafe {
+            libc::mmap(
+                std::ptr::null_mut(),
+                len,
+                libc::PROT_READ,
+                libc::MAP_PRIVATE,
+                file.as_raw_fd(),
+                0,
+            )
+        };
+        if ptr == libc::MAP_FAILED {
+            return Err(std::io::Error::last_os_error().into());
+        }
+        // The file is read front to back; a failed hint is harmless
+        // SAFETY: `ptr..ptr + len` is the mapping just made
+        unsafe { libc::madvise(ptr, len, libc::MADV_SEQUENTIAL) };
+        Ok(Some(Self { ptr, len }))
+    }
+}
+
+#[cfg(unix)]
+impl Deref for Mmap {
+    type Target = [u8];
+
+    fn deref(&self) -> &[u8] {
+        // SAFETY: the mapping is `len` readable bytes until dropped
+        unsafe { std::slice::from_raw_parts(self.ptr as *const u8, self.len) }
+    }
+}
+
+#[cfg(unix)]
+impl Drop for Mmap {
+    fn drop(&mut self) {
+        // SAFETY: unmaps the mapping made in `open`, which nothing borrows
+        unsafe { libc::munmap(self.ptr, self.len) };
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_open() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("input.bin");
+        let data = crate::testutil::pattern(100_000, 1, 251);
+        std::fs::write(&path, &data).unwrap();
+        // SAFETY: nothing writes the test's files while they are mapped
+        let mapped = unsafe { FileData::open(&path) }.unwrap();
+        #[cfg(unix)]
+        assert!(matches!(mapped, FileData::Mapped(_)));
+        assert_eq!(&mapped[..], &data[..]);
+
+        let empty = dir.path().join("empty.bin");
+        std::fs::write(&empty, b"").unwrap();
+        // SAFETY: as above
+        unsafe {
+            assert!(FileData::open(&empty).unwrap().is_empty());
+            assert!(FileData::open(&dir.path().join("missing")).is_err());
+        }
+    }
+}
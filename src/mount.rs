@@ -0,0 +1,576 @@
+use std::ffi::CString;
+use std::fs::File;
+use std::io::{self, Read, Write};
+use std::os::fd::{FromRawFd, RawFd};
+use std::os::unix::ffi::OsStrExt;
+use std::path::Path;
+use std::process::Command;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use crate::archive::Archive;
+use crate::cancel::CancelToken;
+use crate::error::{Result, VstorageError};
+
+// FUSE kernel protocol 7.31 (linux/fuse.h); only what a read-only
+// filesystem with one flat directory needs
+const KERNEL_VERSION: u32 = 7;
+const KERNEL_MINOR_VERSION: u32 = 31;
+
+const LOOKUP: u32 = 1;
+const FORGET: u32 = 2;
+const GETATTR: u32 = 3;
+const OPEN: u32 = 14;
+const READ: u32 = 15;
+const STATFS: u32 = 17;
+const RELEASE: u32 = 18;
+const FLUSH: u32 = 25;
+const INIT: u32 = 26;
+const OPENDIR: u32 = 27;
+const READDIR: u32 = 28;
+const RELEASEDIR: u32 = 29;
+const ACCESS: u32 = 34;
+const INTERRUPT: u32 = 36;
+const DESTROY: u32 = 38;
+const BATCH_FORGET: u32 = 42;
+
+/// Keep file contents in the page cache across opens; the archive never
+/// changes while mounted
+const FOPEN_KEEP_CACHE: u32 = 1 << 1;
+
+const ROOT_ID: u64 = 1;
+/// How long the kernel may cache names and attributes
+const TTL_SECS: u64 = 3600;
+const IN_HEADER_LEN: usize = 40;
+const MAX_WRITE: u32 = 4096;
+/// Requests are small (no writes); the kernel wants room for `MAX_WRITE`
+const BUFFER_LEN: usize = 64 * 1024;
+
+struct Request<'a> {
+    opcode: u32,
+    unique: u64,
+    nodeid: u64,
+    body: &'a [u8],
+}
+
+impl<'a> Request<'a> {
+    fn parse(buf: &'a [u8]) -> Option<Self> {
+        if buf.len() < IN_HEADER_LEN {
+            return None;
+        }
+        Some(Self {
+            opcode: u32_at(buf, 4),
+            unique: u64_at(buf, 8),
+            nodeid: u64_at(buf, 16),
+            body: &buf[IN_HEADER_LEN..],
+        })
+    }
+}
+
+fn u32_at(buf: &[u8], pos: usize) -> u32 {
+    buf.get(pos..pos + 4)
+        .map_or(0, |b| u32::from_ne_bytes(b.try_into().unwrap()))
+}
+
+fn u64_at(buf: &[u8], pos: usize) -> u64 {
+    buf.get(pos..pos + 8)
+        .map_or(0, |b| u64::from_ne_bytes(b.try_into().unwrap()))
+}
+
+/// Reply body, in native byte order like the kernel's structs.
+#[derive(Default)]
+struct Out(Vec<u8>);
+
+impl Out {
+    fn u16(mut self, v: u16) -> Self {
+        self.0.extend_from_slice(&v.to_ne_bytes());
+        self
+    }
+
+    fn u32(mut self, v: u32) -> Self {
+        self.0.extend_from_slice(&v.to_ne_bytes());
+        self
+    }
+
+    fn u64(mut self, v: u64) -> Self {
+        self.0.extend_from_slice(&v.to_ne_bytes());
+        self
+    }
+}
+
+/// Serves an [`Archive`] as a read-only directory: inode 1 is the root and
+/// inode `2 + i` is `archive.files[i]`, named `names[i]`.
+struct Filesystem {
+    archive: Archive,
+    names: Vec<String>,
+    uid: u32,
+    gid: u32,
+    time: u64,
+}
+
+/// Directory entry names of `archive`'s files, all in the one directory: a
+/// stored path with `_` for `/`, or `payload_<id>` if that name is taken.
+fn entry_names(archive: &Archive) -> Vec<String> {
+    let mut names: Vec<String> = Vec::new();
+    for file in &archive.files {
+        let mut name = file.name.replace('/', "_");
+        if names.contains(&name) {
+            name = format!("payload_{}", file.payload);
+        }
+        names.push(name);
+    }
+    names
+}
+
+impl Filesystem {
+    /// Reply to one request (`None` for requests that take no reply).
+    fn handle(&mut self, req: &Request) -> Option<Vec<u8>> {
+        let result = match req.opcode {
+            FORGET | BATCH_FORGET | INTERRUPT => return None,
+            INIT => self.init(req.body),
+            LOOKUP => self.lookup(req.nodeid, req.body),
+            GETATTR => self.attr(req.nodeid).map(|attr| {
+                let out = Out::default().u64(TTL_SECS).u32(0).u32(0);
+                Out([out.0, attr.0].concat())
+            }),
+            OPEN => self.open(req.nodeid, u32_at(req.body, 0)),
+            READ => self.read(req.nodeid, u64_at(req.body, 8), u32_at(req.body, 16)),
+            OPENDIR if req.nodeid == ROOT_ID => Ok(Out::default().u64(0).u32(0).u32(0)),
+            OPENDIR => Err(libc::ENOTDIR),
+            READDIR => self.readdir(req.nodeid, u64_at(req.body, 8), u32_at(req.body, 16)),
+            STATFS => Ok(self.statfs()),
+            ACCESS if u32_at(req.body, 0) & libc::W_OK as u32 != 0 => Err(libc::EROFS),
+            ACCESS | RELEASE | RELEASEDIR | FLUSH | DESTROY => Ok(Out::default()),
+            _ => Err(libc::ENOSYS),
+        };
+        let (error, body) = match result {
+            Ok(out) => (0, out.0),
+            Err(errno) => (-errno, Vec::new()),
+        };
+        let header = Out::default()
+            .u32((16 + body.len()) as u32)
+            .u32(error as u32)
+            .u64(req.unique);
+        Some([header.0, body].concat())
+    }
+
+    fn init(&self, body: &[u8]) -> std::result::Result<Out, i32> {
+        if u32_at(body, 0) < KERNEL_VERSION {
+            return Err(libc::EPROTO);
+        }
+        Ok(Out::default()
+            .u32(KERNEL_VERSION)
+            .u32(KERNEL_MINOR_VERSION)
+            .u32(u32_at(body, 8)) // max_readahead as offered
+            .u32(0) // flags
+            .u16(16) // max_background
+            .u16(12) // congestion_threshold
+            .u32(MAX_WRITE)
+            .u32(1) // time_gran
+            .u16(0) // max_pages (kernel default)
+            .u16(0)
+            .u32(0)
+            .u64(0)
+            .u64(0)
+            .u64(0)
+            .u32(0))
+    }
+
+    fn file(&self, nodeid: u64) -> Option<usize> {
+        let index = nodeid.checked_sub(2)? as usize;
+        (index < self.archive.files.len()).then_some(index)
+    }
+
+    /// `struct fuse_attr` of an inode.
+    fn attr(&self, nodeid: u64) -> std::result::Result<Out, i32> {
+        let (size, mode, nlink) = if nodeid == ROOT_ID {
+            (0, libc::S_IFDIR | 0o555, 2)
+        } else {
+            let file = self.file(nodeid).ok_or(libc::ENOENT)?;
+            (self.archive.files[file].size, libc::S_IFREG | 0o444, 1)
+        };
+        let mut out = Out::default().u64(nodeid).u64(size).u64(size.div_ceil(512));
+        for _ in 0..3 {
+            out = out.u64(self.time);
+        }
+        Ok(out
+            .u32(0)
+            .u32(0)
+            .u32(0)
+            .u32(mode)
+            .u32(nlink)
+            .u32(self.uid)
+            .u32(self.gid)
+            .u32(0) // rdev
+            .u32(4096) // blksize
+            .u32(0))
+    }
+
+    fn lookup(&self, parent: u64, body: &[u8]) -> std::result::Result<Out, i32> {
+        let name = body.split(|&b| b == 0).next().unwrap_or_default();
+        let file = self
+            .names
+            .iter()
+            .position(|n| n.as_bytes() == name)
+            .filter(|_| parent == ROOT_ID)
+            .ok_or(libc::ENOENT)?;
+        let nodeid = file as u64 + 2;
+        let out = Out::default()
+            .u64(nodeid)
+            .u64(0) // generation
+            .u64(TTL_SECS)
+            .u64(TTL_SECS)
+            .u32(0)
+            .u32(0);
+        Ok(Out([out.0, self.attr(nodeid)?.0].concat()))
+    }
+
+    fn open(&self, nodeid: u64, flags: u32) -> std::result::Result<Out, i32> {
+        self.file(nodeid).ok_or(libc::EISDIR)?;
+        if flags as i32 & libc::O_ACCMODE != libc::O_RDONLY {
+            return Err(libc::EROFS);
+        }
+        Ok(Out::default().u64(0).u32(FOPEN_KEEP_CACHE).u32(0))
+    }
+
+    fn read(&mut self, nodeid: u64, offset: u64, size: u32) -> std::result::Result<Out, i32> {
+        let file = self.file(nodeid).ok_or(libc::EISDIR)?;
+        match self.archive.read(file, offset, size as usize) {
+            Ok(data) => Ok(Out(data)),
+            Err(e) => {
+                tracing::warn!(
+                    "{}: read at {offset} failed: {e}",
+                    self.archive.files[file].name
+                );
+                Err(libc::EIO)
+            }
+        }
+    }
+
+    /// `struct fuse_dirent`s from entry `offset` on, as many as fit in `size`.
+    fn readdir(&self, nodeid: u64, offset: u64, size: u32) -> std::result::Result<Out, i32> {
+        if nodeid != ROOT_ID {
+            return Err(libc::ENOTDIR);
+        }
+        let entries = [(ROOT_ID, ".", libc::DT_DIR), (ROOT_ID, "..", libc::DT_DIR)]
+            .into_iter()
+            .chain(
+                self.names
+                    .iter()
+                    .enumerate()
+                    .map(|(i, n)| (i as u64 + 2, n.as_str(), libc::DT_REG)),
+            );
+        let mut out = Out::default();
+        for (k, (ino, name, kind)) in entries.enumerate().skip(offset as usize) {
+            let len = (24 + name.len()).next_multiple_of(8);
+            if out.0.len() + len > size as usize {
+                break;
+            }
+            out = out
+                .u64(ino)
+                .u64(k as u64 + 1)
+                .u32(name.len() as u32)
+                .u32(kind as u32);
+            out.0.extend_from_slice(name.as_bytes());
+            out.0.resize(out.0.len().next_multiple_of(8), 0);
+        }
+        Ok(out)
+    }
+
+    fn statfs(&self) -> Out {
+        let total: u64 = self.archive.files.iter().map(|f| f.size).sum();
+        let files = self.archive.files.len() as u64 + 1;
+        let out = Out::default()
+            .u64(total.div_ceil(512))
+            .u64(0)
+            .u64(0)
+            .u64(files)
+            .u64(0)
+            .u32(512) // bsize
+            .u32(255) // namelen
+            .u32(512) // frsize
+            .u32(0);
+        (0..6).fold(out, |out, _| out.u32(0))
+    }
+}
+
+/// Mount `archive` read-only at `mountpoint` and serve it until the
+/// filesystem is unmounted or `cancel` is cancelled (which unmounts it).
+/// As root the kernel is asked directly; otherwise `fusermount3` (or
+/// `fusermount`) mounts on our behalf.
+pub fn mount(archive: Archive, mountpoint: &Path, cancel: &CancelToken) -> Result<()> {
+    if !mountpoint.is_dir() {
+        return Err(VstorageError::Config(format!(
+            "mount point {} is not a directory",
+            mountpoint.display()
+        )));
+    }
+    let mut device = open_mount(mountpoint)?;
+    tracing::info!(
+        "Mounted {} file(s) at {}; press Ctrl-C or run `fusermount -u {}` to unmount",
+        archive.files.len(),
+        mountpoint.display(),
+        mountpoint.display()
+    );
+
+    let done = CancelToken::new();
+    let watcher = {
+        let (done, cancel, mountpoint) = (done.clone(), cancel.clone(), mountpoint.to_path_buf());
+        std::thread::spawn(move || {
+            while !done.is_cancelled() {
+                if cancel.is_cancelled() {
+                    if let Err(e) = unmount(&mountpoint) {
+                        tracing::warn!("Unmounting {} failed: {e}", mountpoint.display());
+                    }
+                    return;
+                }
+                std::thread::sleep(Duration::from_millis(100));
+            }
+        })
+    };
+
+    let mut fs = Filesystem {
+        names: entry_names(&archive),
+        archive,
+        // SAFETY: getuid cannot fail and touches no memory
+        uid: unsafe { libc::getuid() },
+        // SAFETY: getgid cannot fail and touches no memory
+        gid: unsafe { libc::getgid() },
+        time: SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_or(0, |d| d.as_secs()),
+    };
+    let served = serve(&mut device, &mut fs);
+    done.cancel();
+    if served.is_err() && !cancel.is_cancelled() {
+        let _ = unmount(mountpoint);
+    }
+    let _ = watcher.join();
+    tracing::info!("Unmounted {}", mountpoint.display());
+    served
+}
+
+/// Answer requests from the kernel until the filesystem goes away.
+fn serve(device: &mut File, fs: &mut Filesystem) -> Result<()> {
+    let mut buf = vec![0u8; BUFFER_LEN];
+    loop {
+        let n = match device.read(&mut buf) {
+            Ok(n) => n,
+            // ENOENT: the request was interrupted before we read it
+            Err(e) if matches!(e.raw_os_error(), Some(libc::EINTR | libc::ENOENT)) => continue,
+            Err(e) if e.raw_os_error() == Some(libc::ENODEV) => return Ok(()),
+            Err(e) => return Err(e.into()),
+        };
+        let Some(req) = Request::parse(&buf[..n]) else {
+            continue;
+        };
+        if let Some(reply) = fs.handle(&req) {
+            // ENOENT again means the caller gave up on the request
+            if let Err(e) = device.write(&reply) {
+                if e.raw_os_error() != Some(libc::ENOENT) {
+                    return Err(e.into());
+                }
+            }
+        }
+        if req.opcode == DESTROY {
+            return Ok(());
+        }
+    }
+}
+
+fn mount_error(mountpoint: &Path, e: impl std::fmt::Display) -> VstorageError {
+    VstorageError::Io(io::Error::other(format!(
+        "cannot mount {}: {e}",
+        mountpoint.display()
+    )))
+}
+
+/// Mount the filesystem and return the open `/dev/fuse` connection.
+fn open_mount(mountpoint: &Path) -> Result<File> {
+    // SAFETY: geteuid cannot fail and touches no memory
+    if unsafe { libc::geteuid() } != 0 {
+        return fusermount(mountpoint);
+    }
+    let device = File::options().read(true).write(true).open("/dev/fuse")?;
+    let target =
+        CString::new(mountpoint.as_os_str().as_bytes()).map_err(|e| mount_error(mountpoint, e))?;
+    let options = format!(
+        "fd={},rootmode=40000,user_id=0,group_id=0",
+        std::os::fd::AsRawFd::as_raw_fd(&device)
+    );
+    let options = CString::new(options).expect("no NUL in options");
+    // SAFETY: every pointer is to a NUL-terminated string that outlives the call
+    let ret = unsafe {
+        libc::mount(
+            c"vstorage".as_ptr(),
+            target.as_ptr(),
+            c"fuse.vstorage".as_ptr(),
+            libc::MS_RDONLY | libc::MS_NOSUID | libc::MS_NODEV,
+            options.as_ptr().cast(),
+        )
+    };
+    if ret != 0 {
+        return Err(mount_error(mountpoint, io::Error::last_os_error()));
+    }
+    Ok(device)
+}
+
+/// Have fusermount mount the filesystem; it sends back the `/dev/fuse`
+/// descriptor over a socket named by `_FUSE_COMMFD`.
+fn fusermount(mountpoint: &Path) -> Result<File> {
+    let mut fds = [0 as RawFd; 2];
+    // SAFETY: socketpair writes two descriptors into `fds`, which holds two
+    if unsafe { libc::socketpair(libc::AF_UNIX, libc::SOCK_STREAM, 0, fds.as_mut_ptr()) } != 0 {
+        return Err(io::Error::last_os_error().into());
+    }
+    // SAFETY: both descriptors are fresh from socketpair and owned by nothing else
+    let (ours, theirs) = unsafe { (File::from_raw_fd(fds[0]), File::from_raw_fd(fds[1])) };
+    let status = run_fusermount(|cmd| {
+        cmd.args([
+            "-o",
+            "ro,nosuid,nodev,fsname=vstorage,subtype=vstorage",
+            "--",
+        ])
+        .arg(mountpoint)
+        .env("_FUSE_COMMFD", fds[1].to_string())
+    })
+    .map_err(|e| mount_error(mountpoint, e))?;
+    drop(theirs);
+    if !status.success() {
+        return Err(mount_error(mountpoint, format!("fusermount {status}")));
+    }
+    receive_fd(&ours).map_err(|e| mount_error(mountpoint, e))
+}
+
+/// Run `fusermount3`, or `fusermount` where only FUSE 2 is installed.
+fn run_fusermount(
+    args: impl Fn(&mut Command) -> &mut Command,
+) -> io::Result<std::process::ExitStatus> {
+    match args(&mut Command::new("fusermount3")).status() {
+        Err(e) if e.kind() == io::ErrorKind::NotFound => args(&mut Command::new("fusermount"))
+            .status()
+            .map_err(|e| match e.kind() {
+                io::ErrorKind::NotFound => {
+                    io::Error::other("fusermount not found — install fuse3 (or run as root)")
+                }
+                _ => e,
+            }),
+        result => result,
+    }
+}
+
+/// Receive one file descriptor (SCM_RIGHTS) from a unix socket.
+fn receive_fd(socket: &File) -> io::Result<File> {
+    let mut byte = [0u8; 1];
+    let mut iov = libc::iovec {
+        iov_base: byte.as_mut_ptr().cast(),
+        iov_len: 1,
+    };
+    // SAFETY: CMSG_SPACE only computes a length
+    let space = unsafe { libc::CMSG_SPACE(std::mem::size_of::<RawFd>() as u32) } as usize;
+    let mut control = vec![0u8; space];
+    // SAFETY: msghdr is plain data, for which all zeroes is valid (null pointers)
+    let mut msg: libc::msghdr = unsafe { std::mem::zeroed() };
+    msg.msg_iov = &mut iov;
+    msg.msg_iovlen = 1;
+    msg.msg_control = control.as_mut_ptr().cast();
+    msg.msg_controllen = space as _;
+
+    let socket = std::os::fd::AsRawFd::as_raw_fd(socket);
+    // SAFETY: `msg` points at `iov` and `control`, both live and of the lengths
+    // it gives
+    if unsafe { libc::recvmsg(socket, &mut msg, 0) } < 0 {
+        return Err(io::Error::last_os_error());
+    }
+    // SAFETY: `msg` was filled in by recvmsg, its control buffer still alive
+    let cmsg = unsafe { libc::CMSG_FIRSTHDR(&msg) };
+    // SAFETY: a non-null header lies within `control`
+    if cmsg.is_null() || unsafe { (*cmsg).cmsg_type } != libc::SCM_RIGHTS {
+        return Err(io::Error::other("fusermount sent no descriptor"));
+    }
+    // SAFETY: an SCM_RIGHTS message carries a descriptor after its header, which
+    // may be unaligned in the buffer
+    let fd = unsafe { std::ptr::read_unaligned(libc::CMSG_DATA(cmsg).cast::<RawFd>()) };
+    // SAFETY: the kernel installed the descriptor for us alone
+    Ok(unsafe { File::from_raw_fd(fd) })
+}
+
+/// Lazily unmount, so open files do not keep the mount busy.
+fn unmount(mountpoint: &Path) -> Result<()> {
+    // SAFETY: geteuid cannot fail and touches no memory
+    if unsafe { libc::geteuid() } == 0 {
+        let target = CString::new(mountpoint.as_os_str().as_bytes())
+            .map_err(|e| mount_error(mountpoint, e))?;
+        // SAFETY: `target` is a NUL-terminated string that outlives the call
+        if unsafe { libc::umount2(target.as_ptr(), libc::MNT_DETACH) } != 0 {
+            return Err(io::Error::last_os_error().into());
+        }
+        return Ok(());
+    }
+    let status = run_fusermount(|cmd| cmd.args(["-u", "-z", "--"]).arg(mountpoint))?;
+    if !status.success() {
+        return Err(VstorageError::Io(io::Error::other(format!(
+            "fusermount -u {status}"
+        ))));
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::FrameConfig;
+    use crate::memory::{encode_bytes, EncodeOptions};
+
+    fn request(opcode: u32, nodeid: u64, body: &[u8]) -> Vec<u8> {
+        let header = Out::default()
+            .u32((IN_HEADER_LEN + body.len()) as u32)
+            .u32(opcode)
+            .u64(7)
+            .u64(nodeid)
+            .u64(0)
+            .u64(0);
+        [header.0, body.to_vec()].concat()
+    }
+
+    #[test]
+    fn test_lookup_read_readdir() {
+        let config = FrameConfig::new(8, 2, 32, 30, 18).unwrap();
+        let data = crate::testutil::pattern(3000, 1, 199);
+        let options = EncodeOptions::new(config);
+        let frames = encode_bytes(&data, &options).unwrap();
+        let archive = Archive::open(Box::new(frames), None, None, 4).unwrap();
+        let mut fs = Filesystem {
+            names: entry_names(&archive),
+            archive,
+            uid: 0,
+            gid: 0,
+            time: 0,
+        };
+        let mut send = |opcode, nodeid, body: &[u8]| {
+            let buf = request(opcode, nodeid, body);
+            fs.handle(&Request::parse(&buf).unwrap()).unwrap()
+        };
+
+        let reply = send(LOOKUP, ROOT_ID, b"payload_0\0");
+        assert_eq!(u32_at(&reply, 4), 0);
+        assert_eq!(u64_at(&reply, 16), 2); // nodeid
+        assert_eq!(u64_at(&reply, 16 + 40 + 8), 3000); // attr.size
+        let reply = send(LOOKUP, ROOT_ID, b"missing\0");
+        assert_eq!(u32_at(&reply, 4) as i32, -libc::ENOENT);
+
+        let read_in = Out::default().u64(0).u64(1000).u32(100).u32(0);
+        let reply = send(READ, 2, &read_in.0);
+        assert_eq!(&reply[16..], &data[1000..1100]);
+
+        let write_open = Out::default().u32(libc::O_WRONLY as u32).u32(0);
+        let reply = send(OPEN, 2, &write_open.0);
+        assert_eq!(u32_at(&reply, 4) as i32, -libc::EROFS);
+
+        let readdir_in = Out::default().u64(0).u64(2).u32(4096).u32(0);
+        let reply = send(READDIR, ROOT_ID, &readdir_in.0);
+        let entry = &reply[16..];
+        assert_eq!(u64_at(entry, 0), 2);
+        assert_eq!(&entry[24..24 + u32_at(entry, 16) as usize], b"payload_0");
+        assert_eq!(entry.len(), (24 + 9usize).next_multiple_of(8));
+    }
+}
@@ -0,0 +1,500 @@
+use std::io::{Read, Write};
+use std::path::{Component, Path, PathBuf};
+
+use image::RgbImage;
+use sha2::{Digest, Sha256};
+
+use crate::append::{current_index, data_header, index_header};
+use crate::archive::{Archive, FrameSource, VideoSource, READAHEAD_FRAMES};
+use crate::cancel::CancelToken;
+use crate::config::{FrameConfig, Workspace};
+use crate::crypto::Token;
+use crate::encode::{self, encode_segment, segment_path, work_dir_for, STDIO_PATH};
+use crate::error::{Result, VstorageError};
+use crate::index::{PayloadEntry, PayloadIndex};
+use crate::memory::render_frame;
+use crate::progress::{ProgressSink, Stage, Tracker};
+use crate::{collect, crypto, header, video};
+
+/// Bytes read from the archive per [`Archive::read`] while extracting.
+const EXTRACT_BUF: usize = 1 << 20;
+
+/// Store several files in one video: an index frame first, listing each
+/// file's path (relative, as given), size and frame range, then every file
+/// as its own payload starting on a frame boundary. `extract` reads the
+/// index and then only the frames of the file it wants.
+///
+/// With a password, each file gets its own key (fresh salt and nonce).
+/// Returns the index written to the first frame.
+pub fn pack(
+    inputs: &[&Path],
+    output_path: &Path,
+    password: Option<&str>,
+    config: &FrameConfig,
+    progress: &dyn ProgressSink,
+    cancel: &CancelToken,
+) -> Result<PayloadIndex> {
+    video::check_ffmpeg()?;
+    let per_frame = config.max_plain_per_frame(password.is_some());
+    if per_frame == 0 {
+        return Err(VstorageError::Config(
+            "frame capacity is zero — check block_size/levels/ecc settings".into(),
+        ));
+    }
+    let index = plan_index(inputs, Path::new("."), per_frame)?;
+    let index_bytes = index.serialize();
+    if index_bytes.len() > config.max_raw_per_frame() {
+        return Err(VstorageError::Config(format!(
+            "the index of {} files does not fit in one frame; use a larger frame or fewer files",
+            index.entries.len()
+        )));
+    }
+    let num_frames = index
+        .entries
+        .last()
+        .map_or(1, |e| (e.first_frame + e.total_frames) as usize);
+    tracing::info!(
+        "Packing {} files into {num_frames} frames",
+        index.entries.len()
+    );
+
+    let work_dir = work_dir_for(output_path, &config.workspace);
+    if work_dir.exists() {
+        std::fs::remove_dir_all(&work_dir)?;
+    }
+    let frames_dir = work_dir.join("frames");
+    std::fs::create_dir_all(&frames_dir)?;
+
+    let mut pb = Tracker::frames(progress, Stage::EncodeFrames, num_frames as u64);
+    let segment_frames = encode::segment_frames(config)?;
+    let mut segments_done = 0;
+    {
+        let mut position = 0;
+        let mut emit = |img: RgbImage, bytes: usize| -> Result<()> {
+            cancel.check()?;
+            let path = video::frame_path(&frames_dir, position + 1, config.workspace.intermediate);
+            video::save_frame(&img, &path)?;
+            position += 1;
+            if position % segment_frames == 0 || position == num_frames {
+                encode_segment(
+                    &work_dir,
+                    segments_done,
+                    segment_frames,
+                    num_frames,
+                    config,
+                    cancel,
+                )?;
+                segments_done += 1;
+            }
+            pb.inc(bytes as u64);
+            Ok(())
+        };
+        emit(
+            render_frame(config, index_header(config, 0), &index_bytes),
+            0,
+        )?;
+        for (entry, input) in index.entries.iter().zip(inputs) {
+            let cipher = password
+                .map(|pw| {
+                    crypto::FrameCipher::generate(
+                        pw,
+                        config.kdf,
+                        config.token,
+                        config.hardware_token(),
+                    )
+                })
+                .transpose()?;
+            let reader = std::io::BufReader::new(std::fs::File::open(input)?);
+            render_payload(config, entry, reader, cipher, &mut emit)?;
+        }
+    }
+    pb.finish_with_message(format!("{num_frames} frames encoded"));
+
+    let pb = Tracker::spinner(
+        progress,
+        Stage::Mux,
+        format!("FFmpeg: producing {}...", output_path.display()),
+    );
+    let segments: Vec<PathBuf> = (0..segments_done)
+        .map(|s| segment_path(&work_dir, s))
+        .collect();
+    video::concat_segments(&segments, output_path, config, num_frames, None, cancel)?;
+    pb.finish_with_message(format!("Wrote {}", output_path.display()));
+    video::remove_work_dir(&work_dir, &config.workspace)?;
+    Ok(index)
+}
+
+/// Payload ids, names and frame ranges for `inputs`, relative to `base`,
+/// after the index frame, and the SHA-256 of each file's plaintext.
+fn plan_index(inputs: &[&Path], base: &Path, per_frame: usize) -> Result<PayloadIndex> {
+    if inputs.len() >= header::INDEX_PAYLOAD as usize {
+        return Err(VstorageError::Config("too many files for one video".into()));
+    }
+    let mut index = PayloadIndex::default();
+    let mut next_frame = 1;
+    for (payload, input) in inputs.iter().enumerate() {
+        let path = base.join(input);
+        let meta = std::fs::metadata(&path)?;
+        if !meta.is_file() {
+            return Err(VstorageError::Config(format!(
+                "{} is not a regular file",
+                input.display()
+            )));
+        }
+        let name = entry_name(input)?;
+        if index.entries.iter().any(|e| e.name == name) {
+            return Err(VstorageError::Config(format!(
+                "{name} is given twice; paths must be unique"
+            )));
+        }
+        let mut hasher = Sha256::new();
+        std::io::copy(&mut std::fs::File::open(&path)?, &mut hasher)?;
+        let total_frames = (meta.len().div_ceil(per_frame as u64)).max(1) as u32;
+        index.entries.push(PayloadEntry {
+            payload: payload as u16,
+            name,
+            file_size: meta.len(),
+            total_frames,
+            first_frame: next_frame,
+            sha256: Some(hasher.finalize().into()),
+        });
+        next_frame += total_frames;
+    }
+    Ok(index)
+}
+
+/// Name of `input` in a pack's index: its path with `/` between components
+/// and `.` left out. Absolute paths and `..` are refused, so extracting a
+/// file to its stored name stays under the current directory.
+fn entry_name(input: &Path) -> Result<String> {
+    let mut parts = Vec::new();
+    for component in input.components() {
+        match component {
+            Component::Normal(part) => parts.push(part.to_string_lossy()),
+            Component::CurDir => {}
+            _ => {
+                return Err(VstorageError::Config(format!(
+                    "{} must be a relative path without `..`",
+                    input.display()
+                )))
+            }
+        }
+    }
+    Ok(parts.join("/"))
+}
+
+/// Render the frames of one payload read from `reader`, handing each to
+/// `emit` with the number of file bytes it carries.
+fn render_payload(
+    config: &FrameConfig,
+    entry: &PayloadEntry,
+    mut reader: impl Read,
+    cipher: Option<(crypto::FrameCipher, [u8; 12], [u8; 16])>,
+    emit: &mut impl FnMut(RgbImage, usize) -> Result<()>,
+) -> Result<()> {
+    let (cipher, nonce, salt) = match cipher {
+        Some((cipher, nonce, salt)) => (Some(cipher), nonce, salt),
+        None => (None, [0u8; 12], [0u8; 16]),
+    };
+    let total = entry.total_frames as usize;
+    let per_frame = config.max_plain_per_frame(cipher.is_some());
+    let context = data_header(
+        config,
+        entry.payload,
+        0,
+        total,
+        entry.file_size,
+        nonce,
+        salt,
+    )
+    .cipher_context();
+    let cipher = cipher.map(|c| c.with_context(context));
+    let mut buf = vec![0u8; per_frame];
+    for i in 0..total {
+        let len = (entry.file_size - (i * per_frame) as u64).min(per_frame as u64) as usize;
+        reader.read_exact(&mut buf[..len])?;
+        let chunk = match &cipher {
+            Some(c) => c.encrypt_frame(i as u32, &buf[..len])?,
+            None => buf[..len].to_vec(),
+        };
+        let hdr = data_header(
+            config,
+            entry.payload,
+            i,
+            total,
+            entry.file_size,
+            nonce,
+            salt,
+        );
+        emit(render_frame(config, hdr, &chunk), len)?;
+    }
+    Ok(())
+}
+
+/// The payload index of a video, read from its first and last frame only:
+/// no payload data is decoded or decrypted. The frames are extracted in
+/// `workspace`.
+pub fn list(video_path: &Path, workspace: &Workspace) -> Result<PayloadIndex> {
+    let input = crate::s3::readable(video_path)?;
+    let mut source = VideoSource::open(&input, workspace, &CancelToken::new())?;
+    let frames = source.frame_count();
+    if frames == 0 {
+        return Err(VstorageError::Config("the video has no frames".into()));
+    }
+    let first_img = source
+        .frames(0, 1)?
+        .pop()
+        .ok_or(VstorageError::DetectFailed)?;
+    let last_img = match frames {
+        1 => first_img.clone(),
+        n => source
+            .frames(n - 1, 1)?
+            .pop()
+            .ok_or_else(|| VstorageError::Ffmpeg(format!("could not read frame {n}")))?,
+    };
+    index_of(&first_img, &last_img)
+}
+
+/// The index in effect in a video whose first and last frames are
+/// `first_img` and `last_img`; no other frame is needed.
+fn index_of(first_img: &RgbImage, last_img: &RgbImage) -> Result<PayloadIndex> {
+    let (first_header, config) = collect::detect_config_from_frame(first_img, None)?;
+    let (index, _) = current_index(&config, first_img, &first_header, last_img)?;
+    Ok(index)
+}
+
+/// Write file `name` (its full stored path) of a video made by [`pack`]
+/// (or appended to) to `output_path` (`-` for stdout), decoding only that
+/// file's frames. Missing parent directories of `output_path` are created.
+/// The data is hashed as it is written and checked against the index's
+/// SHA-256, if it has one. Returns the number of bytes written.
+pub fn extract(
+    video_path: &Path,
+    name: &str,
+    output_path: &Path,
+    password: Option<&str>,
+    hardware: Option<&dyn Token>,
+    workspace: &Workspace,
+    cancel: &CancelToken,
+) -> Result<u64> {
+    let source = VideoSource::open(&crate::s3::readable(video_path)?, workspace, cancel)?;
+    let archive = Archive::open_file(Box::new(source), password, hardware, READAHEAD_FRAMES, name)?;
+    let mut out: Box<dyn Write> = if output_path == Path::new(STDIO_PATH) {
+        Box::new(std::io::stdout().lock())
+    } else {
+        if let Some(parent) = output_path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        Box::new(std::io::BufWriter::new(std::fs::File::create(output_path)?))
+    };
+    let size = write_file(archive, &mut out, cancel)?;
+    tracing::info!("Extracted {name} ({size} bytes)");
+    Ok(size)
+}
+
+/// Write payload `payload` of a video to stdout as its frames decode, with
+/// no output file or extracted video in between. Payloads stored with
+/// compression or split over several videos need `decode` instead. Returns
+/// the number of bytes written.
+pub fn cat(
+    video_path: &Path,
+    payload: u16,
+    password: Option<&str>,
+    hardware: Option<&dyn Token>,
+    workspace: &Workspace,
+    cancel: &CancelToken,
+) -> Result<u64> {
+    let source = VideoSource::open(&crate::s3::readable(video_path)?, workspace, cancel)?;
+    let archive = Archive::open_payload(
+        Box::new(source),
+        password,
+        hardware,
+        READAHEAD_FRAMES,
+        payload,
+    )?;
+    let size = write_file(archive, &mut std::io::stdout().lock(), cancel)?;
+    tracing::info!("Wrote {size} bytes to stdout");
+    Ok(size)
+}
+
+/// Write the one file `archive` was opened with to `out`, hashing it as it
+/// is written and checking the index's SHA-256, if it has one.
+fn write_file(mut archive: Archive, out: &mut dyn Write, cancel: &CancelToken) -> Result<u64> {
+    let (size, expected) = (archive.files[0].size, archive.files[0].sha256);
+    let mut hasher = Sha256::new();
+    let mut offset = 0;
+    while offset < size {
+        cancel.check()?;
+        let data = archive.read(0, offset, EXTRACT_BUF)?;
+        hasher.update(&data);
+        out.write_all(&data)?;
+        offset += data.len() as u64;
+    }
+    out.flush()?;
+    if expected.is_some_and(|h| h != <[u8; 32]>::from(hasher.finalize())) {
+        return Err(VstorageError::FileHashMismatch(
+            archive.files[0].name.clone(),
+        ));
+    }
+    Ok(size)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_plan_index() {
+        let dir = tempfile::tempdir().unwrap();
+        let root = dir.path();
+        std::fs::create_dir_all(root.join("x")).unwrap();
+        std::fs::write(root.join("a.bin"), vec![1u8; 250]).unwrap();
+        std::fs::write(root.join("b.txt"), b"hello").unwrap();
+        std::fs::write(root.join("empty"), b"").unwrap();
+        std::fs::write(root.join("x/b.txt"), b"world").unwrap();
+        let (a, b, c, d) = (
+            Path::new("a.bin"),
+            Path::new("./b.txt"),
+            Path::new("empty"),
+            Path::new("x/b.txt"),
+        );
+        let index = plan_index(&[a, b, c, d], root, 100).unwrap();
+        let ranges: Vec<(u32, u32)> = index
+            .entries
+            .iter()
+            .map(|e| (e.first_frame, e.total_frames))
+            .collect();
+        assert_eq!(ranges, [(1, 3), (4, 1), (5, 1), (6, 1)]);
+        assert_eq!(index.entries[1].name, "b.txt");
+        assert_eq!(index.entries[3].name, "x/b.txt");
+        assert_eq!(
+            index.entries[1].sha256,
+            Some(Sha256::digest(b"hello").into())
+        );
+        assert!(plan_index(&[a, Path::new("./a.bin")], root, 100).is_err());
+        assert!(plan_index(&[&root.join("a.bin")], root, 100).is_err());
+        assert!(plan_index(&[Path::new("x/../a.bin")], root, 100).is_err());
+    }
+
+    /// Two files packed behind an index frame, each encrypted under "pw".
+    fn packed_frames() -> (FrameConfig, [Vec<u8>; 2], Vec<RgbImage>) {
+        let config = FrameConfig::new(8, 2, 32, 30, 18).unwrap();
+        let per_frame = config.max_plain_per_frame(true);
+        let files: [Vec<u8>; 2] = [
+            crate::testutil::pattern(per_frame * 2 + 7, 1, 249),
+            b"second file".to_vec(),
+        ];
+        let mut index = PayloadIndex::default();
+        let mut next_frame = 1;
+        for (i, data) in files.iter().enumerate() {
+            let total_frames = data.len().div_ceil(per_frame) as u32;
+            index.entries.push(PayloadEntry {
+                payload: i as u16,
+                name: format!("{}/x.txt", ["a", "b"][i]),
+                file_size: data.len() as u64,
+                total_frames,
+                first_frame: next_frame,
+                sha256: Some(Sha256::digest(data).into()),
+            });
+            next_frame += total_frames;
+        }
+
+        let mut frames = vec![render_frame(
+            &config,
+            index_header(&config, 0),
+            &index.serialize(),
+        )];
+        for (entry, data) in index.entries.iter().zip(&files) {
+            let cipher =
+                crypto::FrameCipher::generate("pw", config.kdf, config.token, None).unwrap();
+            render_payload(&config, entry, &data[..], Some(cipher), &mut |img, _| {
+                frames.push(img);
+                Ok(())
+            })
+            .unwrap();
+        }
+        (config, files, frames)
+    }
+
+    #[test]
+    fn test_list_from_index_frame() {
+        let (_, files, frames) = packed_frames();
+        let index = index_of(&frames[0], frames.last().unwrap()).unwrap();
+        let listed: Vec<(&str, u64, u32)> = index
+            .entries
+            .iter()
+            .map(|e| (e.name.as_str(), e.file_size, e.first_frame))
+            .collect();
+        assert_eq!(
+            listed,
+            [("a/x.txt", files[0].len() as u64, 1), ("b/x.txt", 11, 4)]
+        );
+        let hash: String = Sha256::digest(&files[1])
+            .iter()
+            .map(|b| format!("{b:02x}"))
+            .collect();
+        assert!(index.to_string().lines().nth(1).unwrap().contains(&hash));
+    }
+
+    #[test]
+    fn test_extract_reads_only_its_frames() {
+        let (config, files, mut frames) = packed_frames();
+        // The frames of a/x.txt are lost; b/x.txt still comes out, hash
+        // checked, by its full path only
+        let blank = RgbImage::new(config.width, config.height);
+        frames[1..4].fill(blank);
+        let archive =
+            Archive::open_file(Box::new(frames.clone()), Some("pw"), None, 4, "b/x.txt").unwrap();
+        let mut out = Vec::new();
+        write_file(archive, &mut out, &CancelToken::new()).unwrap();
+        assert_eq!(out, files[1]);
+        assert!(
+            Archive::open_file(Box::new(frames.clone()), Some("pw"), None, 4, "x.txt").is_err()
+        );
+        assert!(Archive::open_file(Box::new(frames), Some("pw"), None, 4, "a/x.txt").is_err());
+    }
+
+    #[test]
+    fn test_random_access_after_index_frame() {
+        let (config, files, frames) = packed_frames();
+        let per_frame = config.max_plain_per_frame(true);
+        let mut archive =
+            Archive::open_file(Box::new(frames.clone()), Some("pw"), None, 4, "b/x.txt").unwrap();
+        assert_eq!(archive.files.len(), 1);
+        assert_eq!(archive.read(0, 0, 100).unwrap(), files[1]);
+        assert_eq!(
+            archive.files[0].sha256,
+            Some(Sha256::digest(&files[1]).into())
+        );
+        let mut archive = Archive::open(Box::new(frames.clone()), Some("pw"), None, 4).unwrap();
+        let offset = per_frame as u64 + 3;
+        assert_eq!(
+            archive.read(0, offset, per_frame).unwrap(),
+            &files[0][offset as usize..offset as usize + per_frame]
+        );
+        assert!(Archive::open_file(Box::new(frames), Some("pw"), None, 4, "f2").is_err());
+    }
+
+    #[test]
+    fn test_cat_streams_payload() {
+        let config = FrameConfig::new(8, 2, 32, 30, 18).unwrap();
+        let data: Vec<u8> =
+            crate::testutil::pattern(config.max_plain_per_frame(true) * 2 + 9, 1, 241);
+        let options = crate::memory::EncodeOptions {
+            password: Some("pw".into()),
+            ..crate::memory::EncodeOptions::new(config)
+        };
+        let frames = crate::memory::encode_bytes(&data, &options).unwrap();
+        let open =
+            || Archive::open_payload(Box::new(frames.clone()), Some("pw"), None, 2, 0).unwrap();
+
+        let mut out = Vec::new();
+        let size = write_file(open(), &mut out, &CancelToken::new()).unwrap();
+        assert_eq!((size, &out), (data.len() as u64, &data));
+
+        let mut archive = open();
+        archive.files[0].sha256 = Some([0; 32]);
+        let e = write_file(archive, &mut Vec::new(), &CancelToken::new()).unwrap_err();
+        assert!(matches!(e, VstorageError::FileHashMismatch(_)));
+    }
+}
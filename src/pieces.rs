@@ -0,0 +1,183 @@
+use sha2::{Digest, Sha256};
+
+use crate::error::{Result, VstorageError};
+use crate::json::Json;
+
+/// Plaintext bytes covered by each piece hash.
+pub const PIECE_SIZE: usize = 4 << 20;
+
+pub const PIECES_MAGIC: &[u8; 4] = b"VPCS";
+
+/// Piece frames are encrypted under frame numbers from here on, apart from
+/// the data frames' (see [`crate::crypto::FrameCipher::encrypt_frame`]).
+pub const PIECE_NONCE_BASE: u32 = 0x8000_0000;
+
+/// SHA-256 of every `piece_size` bytes of a file, stored in piece frames
+/// after the data frames so a partial recovery can tell which regions are
+/// intact, like BitTorrent piece verification, followed by a BLAKE3 hash of
+/// the whole file.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PieceHashes {
+    pub piece_size: u32,
+    pub hashes: Vec<[u8; 32]>,
+    /// BLAKE3 of the whole file; `None` in piece lists written without it
+    pub blake3: Option<[u8; 32]>,
+}
+
+impl PieceHashes {
+    pub fn compute(data: &[u8]) -> Self {
+        Self {
+            piece_size: PIECE_SIZE as u32,
+            hashes: data
+                .chunks(PIECE_SIZE)
+                .map(|piece| Sha256::digest(piece).into())
+                .collect(),
+            blake3: Some(blake3::hash(data).into()),
+        }
+    }
+
+    /// Pieces (0-based) of `data` that do not match their hash.
+    pub fn damaged(&self, data: &[u8]) -> Vec<usize> {
+        let pieces = data.chunks(self.piece_size as usize);
+        let mut damaged: Vec<usize> = pieces
+            .zip(&self.hashes)
+            .enumerate()
+            .filter(|(_, (piece, hash))| <[u8; 32]>::from(Sha256::digest(piece)) != **hash)
+            .map(|(i, _)| i)
+            .collect();
+        let present = data.len().div_ceil(self.piece_size as usize);
+        damaged.extend(present..self.hashes.len());
+        damaged
+    }
+
+    /// Whether `data` has the stored BLAKE3 hash, if there is one.
+    pub fn file_matches(&self, data: &[u8]) -> Option<bool> {
+        self.blake3
+            .map(|expected| <[u8; 32]>::from(blake3::hash(data)) == expected)
+    }
+
+    /// Serialize (big-endian): magic | piece_size u32 | count u32 | hashes |
+    /// BLAKE3 of the file, if any.
+    pub fn serialize(&self) -> Vec<u8> {
+        let mut buf = Vec::with_capacity(44 + self.hashes.len() * 32);
+        buf.extend_from_slice(PIECES_MAGIC);
+        buf.extend_from_slice(&self.piece_size.to_be_bytes());
+        buf.extend_from_slice(&(self.hashes.len() as u32).to_be_bytes());
+        for hash in &self.hashes {
+            buf.extend_from_slice(hash);
+        }
+        if let Some(hash) = &self.blake3 {
+            buf.extend_from_slice(hash);
+        }
+        buf
+    }
+
+    pub fn deserialize(buf: &[u8]) -> Result<Self> {
+        if buf.len() < 12 || &buf[0..4] != PIECES_MAGIC {
+            return Err(VstorageError::Header("invalid piece list magic".into()));
+        }
+        let piece_size = u32::from_be_bytes(buf[4..8].try_into().unwrap());
+        let count = u32::from_be_bytes(buf[8..12].try_into().unwrap()) as usize;
+        let body = &buf[12..];
+        let len = count.checked_mul(32).filter(|&len| len <= body.len());
+        let (hashes, blake3) = match len {
+            Some(len) if piece_size != 0 => body.split_at(len),
+            _ => return Err(VstorageError::Header("piece list truncated".into())),
+        };
+        let blake3 = match blake3.len() {
+            0 => None,
+            32 => Some(blake3.try_into().unwrap()),
+            _ => return Err(VstorageError::Header("piece list truncated".into())),
+        };
+        Ok(Self {
+            piece_size,
+            hashes: hashes.chunks(32).map(|h| h.try_into().unwrap()).collect(),
+            blake3,
+        })
+    }
+}
+
+/// Result of checking a decoded file against its [`PieceHashes`].
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PieceCheck {
+    pub piece_size: u32,
+    pub total: usize,
+    /// Pieces (0-based) that do not match their hash
+    pub damaged: Vec<usize>,
+    pub file_size: u64,
+    /// Whether the whole file matches its BLAKE3 hash, if one is stored
+    pub file_ok: Option<bool>,
+}
+
+impl PieceCheck {
+    /// File byte range of piece `piece`.
+    pub fn range(&self, piece: usize) -> (u64, u64) {
+        let start = piece as u64 * self.piece_size as u64;
+        (start, (start + self.piece_size as u64).min(self.file_size))
+    }
+
+    /// Report as JSON; piece numbers are 1-based like frame numbers.
+    pub fn to_json(&self) -> Json {
+        let damaged: Vec<Json> = self
+            .damaged
+            .iter()
+            .map(|&p| {
+                let (start, end) = self.range(p);
+                Json::object([
+                    ("piece", (p + 1).into()),
+                    ("offset", start.into()),
+                    ("length", (end - start).into()),
+                ])
+            })
+            .collect();
+        let mut json = Json::object([
+            ("piece_size", self.piece_size.into()),
+            ("total", self.total.into()),
+            ("intact", (self.total - self.damaged.len()).into()),
+            ("damaged", damaged.into()),
+        ]);
+        if let Some(ok) = self.file_ok {
+            json.push("file_ok", ok);
+        }
+        json
+    }
+}
+
+/// Frames the piece list of a `file_size`-byte file takes at `per_frame`
+/// bytes per frame.
+pub fn piece_frames(file_size: u64, per_frame: usize) -> usize {
+    let pieces = file_size.div_ceil(PIECE_SIZE as u64) as usize;
+    (44 + pieces * 32).div_ceil(per_frame.max(1))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_pieces_roundtrip_and_damage() {
+        let mut data = crate::testutil::pattern(PIECE_SIZE * 2 + 100, 1, 251);
+        let pieces = PieceHashes::compute(&data);
+        assert_eq!(pieces.hashes.len(), 3);
+        assert_eq!(
+            PieceHashes::deserialize(&pieces.serialize()).unwrap(),
+            pieces
+        );
+        assert!(pieces.damaged(&data).is_empty());
+        assert_eq!(pieces.file_matches(&data), Some(true));
+
+        // Lists written without the file hash still read
+        let mut old = pieces.serialize();
+        old.truncate(old.len() - 32);
+        let old = PieceHashes::deserialize(&old).unwrap();
+        assert_eq!((old.hashes.len(), old.blake3), (3, None));
+        assert_eq!(old.file_matches(&data), None);
+
+        data[PIECE_SIZE + 7] ^= 0xff;
+        assert_eq!(pieces.damaged(&data), [1]);
+        assert_eq!(pieces.file_matches(&data), Some(false));
+        assert_eq!(pieces.damaged(&data[..PIECE_SIZE]), [1, 2]);
+        assert_eq!(piece_frames(data.len() as u64, 50), 3);
+    }
+}
@@ -0,0 +1,116 @@
+/// Side of the cells of the data area, in blocks; each holds one pilot.
+pub const SPACING: usize = 16;
+
+/// Regions across and down the data area whose levels are fitted apart.
+pub const REGIONS: usize = 4;
+
+/// Pilot blocks in a data area of `width` x `rows` blocks.
+pub fn count(width: usize, rows: usize) -> usize {
+    width.div_ceil(SPACING) * rows.div_ceil(SPACING)
+}
+
+/// Raster indices, ascending, of the pilots of frame `frame`: one at a
+/// pseudo-random place in each cell (the last column and row of cells may
+/// be smaller), so a blemish on the video does not hide the same pilots in
+/// every frame.
+pub fn positions(width: usize, rows: usize, frame: u32) -> Vec<usize> {
+    let mut out = Vec::with_capacity(count(width, rows));
+    for cy in (0..rows).step_by(SPACING) {
+        for cx in (0..width).step_by(SPACING) {
+            let (w, h) = (SPACING.min(width - cx), SPACING.min(rows - cy));
+            let r = mix(frame, out.len()) as usize;
+            out.push((cy + r / w % h) * width + cx + r % w);
+        }
+    }
+    out.sort_unstable();
+    out
+}
+
+/// SplitMix64 of the frame number and a cell index.
+fn mix(frame: u32, cell: usize) -> u64 {
+    let mut z = (u64::from(frame) << 32 | cell as u64).wrapping_add(0x9E37_79B9_7F4A_7C15);
+    z = (z ^ z >> 30).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+    z = (z ^ z >> 27).wrapping_mul(0x94D0_49BB_1331_11EB);
+    z ^ z >> 31
+}
+
+/// Level of the `k`th pilot (in [`positions`] order) on `channel`; the
+/// channels are staggered so each pilot is a different colour.
+pub fn level(k: usize, channel: usize, levels: u8) -> u8 {
+    ((k + channel) % levels as usize) as u8
+}
+
+/// Region, in raster order of the [`REGIONS`] x [`REGIONS`] grid, of block
+/// `block` of a `width` x `rows` data area.
+pub fn region(block: usize, width: usize, rows: usize) -> usize {
+    let (x, y) = (block % width, block / width);
+    y * REGIONS / rows * REGIONS + x * REGIONS / width
+}
+
+/// Level each median reads as, from the medians `samples` of pilots painted
+/// at known levels: thresholds halfway between the mean median of each
+/// level and the next. `None` unless every level was seen and the means
+/// rise with the level.
+pub fn fit(samples: &[(u8, u8)], levels: u8) -> Option<[u8; 256]> {
+    if levels < 2 {
+        return None;
+    }
+    let mut sums = vec![(0u64, 0u64); levels as usize];
+    for &(level, median) in samples {
+        let (sum, n) = &mut sums[level as usize];
+        *sum += median as u64;
+        *n += 1;
+    }
+    if sums.iter().any(|&(_, n)| n == 0) {
+        return None;
+    }
+    let means: Vec<f64> = sums.iter().map(|&(s, n)| s as f64 / n as f64).collect();
+    if means.windows(2).any(|m| m[1] <= m[0]) {
+        return None;
+    }
+    let thresholds: Vec<f64> = means.windows(2).map(|m| (m[0] + m[1]) / 2.0).collect();
+    Some(std::array::from_fn(|p| {
+        thresholds.iter().filter(|&&t| p as f64 > t).count() as u8
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_one_pilot_per_cell() {
+        // 3x2 cells, the last column and row narrower
+        let (width, rows) = (40, 20);
+        let pilots = positions(width, rows, 7);
+        assert_eq!(pilots.len(), count(width, rows));
+        assert_eq!(pilots.len(), 6);
+        let mut cells: Vec<usize> = pilots
+            .iter()
+            .map(|&b| (b / width / SPACING) * 3 + b % width / SPACING)
+            .collect();
+        cells.sort_unstable();
+        assert_eq!(cells, [0, 1, 2, 3, 4, 5]);
+        assert!(pilots.windows(2).all(|p| p[0] < p[1]));
+        assert_eq!(positions(width, rows, 7), pilots);
+        assert_ne!(positions(width, rows, 8), pilots);
+        assert_eq!(region(0, width, rows), 0);
+        assert_eq!(region(width * rows - 1, width, rows), REGIONS * REGIONS - 1);
+    }
+
+    #[test]
+    fn test_fit_follows_gamma() {
+        // 4 levels through a 2.2 gamma: 0, 23, 105, 255
+        let curve = |l: u8| (255.0 * (l as f64 / 3.0).powf(2.2)).round() as u8;
+        let samples: Vec<(u8, u8)> = (0..40).map(|k| (k % 4, curve(k % 4))).collect();
+        let table = fit(&samples, 4).unwrap();
+        for l in 0..4 {
+            assert_eq!(table[curve(l) as usize], l);
+        }
+        assert_eq!(table[60], 1);
+        assert_eq!(table[70], 2);
+        // A level without pilots, or levels out of order
+        assert_eq!(fit(&samples[..2], 4), None);
+        assert_eq!(fit(&[(0, 200), (1, 100)], 2), None);
+    }
+}
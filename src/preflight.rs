@@ -0,0 +1,143 @@
+use std::path::{Path, PathBuf};
+
+use crate::capacity::human_bytes;
+use crate::error::{Result, VstorageError};
+
+/// Disk space a command is about to write.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Need {
+    /// What the space is for, e.g. "frame images"
+    pub what: &'static str,
+    /// Directory written to; it need not exist yet
+    pub dir: PathBuf,
+    pub bytes: u64,
+}
+
+/// Fail before any work is done when the needs falling on one filesystem add
+/// up to more than is free there. Filesystems whose free space cannot be read
+/// are not checked.
+pub fn check_space(needs: &[Need]) -> Result<()> {
+    let mut filesystems: Vec<(u64, u64, Vec<&Need>)> = Vec::new();
+    for need in needs.iter().filter(|n| n.bytes > 0) {
+        let Some((device, free)) = free_space(&need.dir) else {
+            continue;
+        };
+        match filesystems.iter_mut().find(|(d, _, _)| *d == device) {
+            Some((_, _, group)) => group.push(need),
+            None => filesystems.push((device, free, vec![need])),
+        }
+    }
+    for (_, free, group) in filesystems {
+        shortfall(&group, free)?;
+    }
+    Ok(())
+}
+
+/// The error for needs on one filesystem that do not fit in `free` bytes.
+fn shortfall(group: &[&Need], free: u64) -> Result<()> {
+    let needed: u64 = group.iter().map(|n| n.bytes).sum();
+    if needed <= free {
+        return Ok(());
+    }
+    let parts: Vec<String> = group
+        .iter()
+        .map(|n| format!("{} {} in {}", human_bytes(n.bytes), n.what, n.dir.display()))
+        .collect();
+    Err(VstorageError::NoSpace(format!(
+        "about {} of disk space needed ({}), {} free — free some space or choose \
+         another --work-dir or output",
+        human_bytes(needed),
+        parts.join(", "),
+        human_bytes(free)
+    )))
+}
+
+/// Fail when holding `bytes` in memory would exceed the memory and swap
+/// available; unchecked where that cannot be read.
+pub fn check_memory(what: &str, bytes: u64) -> Result<()> {
+    match available_memory() {
+        Some(free) if bytes > free => Err(VstorageError::NoSpace(format!(
+            "{what} holds about {} in memory, {} available",
+            human_bytes(bytes),
+            human_bytes(free)
+        ))),
+        _ => Ok(()),
+    }
+}
+
+/// Device and bytes free to unprivileged users on the filesystem `dir` (or
+/// its nearest existing ancestor) is on.
+#[cfg(unix)]
+fn free_space(dir: &Path) -> Option<(u64, u64)> {
+    use std::os::unix::ffi::OsStrExt;
+    use std::os::unix::fs::MetadataExt;
+
+    let existing = dir
+        .ancestors()
+        .map(|p| match p.as_os_str().is_empty() {
+            true => Path::new("."),
+            false => p,
+        })
+        .find(|p| p.exists())?;
+    let device = std::fs::metadata(existing).ok()?.dev();
+    let path = std::ffi::CString::new(existing.as_os_str().as_bytes()).ok()?;
+    // SAFETY: statvfs only writes the struct it is given
+    let mut stat: libc::statvfs = unsafe { std::mem::zeroed() };
+    if unsafe { libc::statvfs(path.as_ptr(), &mut stat) } != 0 {
+        return None;
+    }
+    // The field types differ between platforms
+    #[allow(clippy::unnecessary_cast)]
+    Some((device, stat.f_bavail as u64 * stat.f_frsize as u64))
+}
+
+#[cfg(not(unix))]
+fn free_space(_dir: &Path) -> Option<(u64, u64)> {
+    None
+}
+
+/// MemAvailable plus SwapFree from /proc/meminfo, in bytes.
+fn available_memory() -> Option<u64> {
+    let meminfo = std::fs::read_to_string("/proc/meminfo").ok()?;
+    let kib = |key: &str| {
+        meminfo
+            .lines()
+            .find_map(|l| l.strip_prefix(key))
+            .and_then(|v| v.trim().trim_end_matches("kB").trim().parse::<u64>().ok())
+    };
+    Some((kib("MemAvailable:")? + kib("SwapFree:").unwrap_or(0)) * 1024)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_shortfall_names_every_need() {
+        let need = |what, bytes| Need {
+            what,
+            dir: PathBuf::from("/data"),
+            bytes,
+        };
+        let (frames, video) = (
+            need("frame images", 3_000_000),
+            need("output video", 2_000_000),
+        );
+        assert!(shortfall(&[&frames, &video], 5_000_000).is_ok());
+        let message = shortfall(&[&frames, &video], 4_000_000)
+            .unwrap_err()
+            .to_string();
+        assert_eq!(
+            message,
+            "Not enough space: about 5.0 MB of disk space needed (3.0 MB frame images in \
+             /data, 2.0 MB output video in /data), 4.0 MB free — free some space or choose \
+             another --work-dir or output"
+        );
+        // Nothing to write, or a directory that does not exist yet
+        assert!(check_space(&[need("output video", 0)]).is_ok());
+        if cfg!(unix) {
+            let missing = std::env::temp_dir().join("vstorage-preflight-missing/a/b");
+            assert!(free_space(&missing).is_some_and(|(_, free)| free > 0));
+        }
+    }
+}
@@ -0,0 +1,467 @@
+use std::io::Write;
+use std::path::Path;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Mutex, OnceLock};
+use std::time::Instant;
+
+#[cfg(feature = "cli")]
+use indicatif::{ProgressBar, ProgressStyle};
+
+use crate::error::Result;
+use crate::json::Json;
+
+static HIDDEN: AtomicBool = AtomicBool::new(false);
+
+/// Where [`event`] writes, and whether that is stderr.
+static EVENTS: OnceLock<(Mutex<Box<dyn Write + Send>>, bool)> = OnceLock::new();
+
+/// Hide every progress bar and spinner created from now on (for `--json`).
+pub fn set_hidden(hidden: bool) {
+    HIDDEN.store(hidden, Ordering::Relaxed);
+}
+
+#[cfg(feature = "cli")]
+fn hidden() -> bool {
+    HIDDEN.load(Ordering::Relaxed)
+}
+
+/// Write [`event`]s, one JSON object per line, to `path` (`-` for stderr),
+/// a file or a named pipe, for the rest of the process (`--progress-json`).
+/// Only the first call takes effect.
+pub fn send_events_to(path: &Path) -> Result<()> {
+    let (out, on_stderr): (Box<dyn Write + Send>, bool) = match path == Path::new("-") {
+        true => (Box::new(std::io::stderr()), true),
+        // Opening a named pipe waits for its reader
+        false => (
+            Box::new(
+                std::fs::OpenOptions::new()
+                    .write(true)
+                    .create(true)
+                    .truncate(true)
+                    .open(path)?,
+            ),
+            false,
+        ),
+    };
+    let _ = EVENTS.set((Mutex::new(out), on_stderr));
+    Ok(())
+}
+
+/// Whether events go to stderr, in place of progress bars and messages.
+pub fn events_on_stderr() -> bool {
+    EVENTS.get().is_some_and(|(_, on_stderr)| *on_stderr)
+}
+
+/// Write `event` as a line of the event stream, if there is one. A reader
+/// that went away does not stop the command.
+pub fn event(event: Json) {
+    if let Some((out, _)) = EVENTS.get() {
+        let mut out = out.lock().unwrap_or_else(|e| e.into_inner());
+        let _ = writeln!(out, "{event}").and_then(|_| out.flush());
+    }
+}
+
+/// Ticking spinner showing `msg`.
+#[cfg(feature = "cli")]
+pub fn spinner(msg: impl Into<String>) -> ProgressBar {
+    if hidden() {
+        return ProgressBar::hidden();
+    }
+    let pb = ProgressBar::new_spinner();
+    pb.set_style(
+        ProgressStyle::default_spinner()
+            .template("{spinner:.cyan} {msg}")
+            .unwrap(),
+    );
+    pb.set_message(msg.into());
+    pb.enable_steady_tick(std::time::Duration::from_millis(80));
+    pb
+}
+
+/// Bar counting `len` frames.
+#[cfg(feature = "cli")]
+pub fn frames(len: u64) -> ProgressBar {
+    if hidden() {
+        return ProgressBar::hidden();
+    }
+    let pb = ProgressBar::new(len);
+    pb.set_style(
+        ProgressStyle::default_bar()
+            .template(
+                "{spinner:.green} [{bar:40.cyan/blue}] {pos}/{len} frames ({eta} remaining) {msg}",
+            )
+            .unwrap()
+            .progress_chars("=>-"),
+    );
+    pb
+}
+
+/// Spinner counting frames whose number is not known in advance.
+#[cfg(feature = "cli")]
+pub fn frame_counter() -> ProgressBar {
+    if hidden() {
+        return ProgressBar::hidden();
+    }
+    let pb = ProgressBar::new_spinner();
+    pb.set_style(
+        ProgressStyle::default_spinner()
+            .template("{spinner:.green} {pos} frames ({per_sec}) {msg}")
+            .unwrap(),
+    );
+    pb.enable_steady_tick(std::time::Duration::from_millis(80));
+    pb
+}
+
+/// Without the `cli` feature there is no terminal to draw on: a bar that
+/// draws nothing, in place of indicatif's.
+#[cfg(not(feature = "cli"))]
+#[derive(Debug, Clone)]
+pub struct ProgressBar;
+
+#[cfg(not(feature = "cli"))]
+impl ProgressBar {
+    pub fn hidden() -> Self {
+        Self
+    }
+
+    pub fn set_position(&self, _pos: u64) {}
+
+    pub fn inc(&self, _delta: u64) {}
+
+    pub fn set_message(&self, _msg: impl Into<String>) {}
+
+    pub fn finish_with_message(&self, _msg: impl Into<String>) {}
+
+    pub fn finish_and_clear(&self) {}
+}
+
+/// [`ProgressBar::hidden`], with no terminal to draw on.
+#[cfg(not(feature = "cli"))]
+pub fn spinner(_msg: impl Into<String>) -> ProgressBar {
+    ProgressBar::hidden()
+}
+
+/// [`ProgressBar::hidden`], with no terminal to draw on.
+#[cfg(not(feature = "cli"))]
+pub fn frames(_len: u64) -> ProgressBar {
+    ProgressBar::hidden()
+}
+
+/// [`ProgressBar::hidden`], with no terminal to draw on.
+#[cfg(not(feature = "cli"))]
+pub fn frame_counter() -> ProgressBar {
+    ProgressBar::hidden()
+}
+
+/// Pipeline step a [`ProgressEvent`] belongs to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Stage {
+    /// Password key derivation (Argon2id or scrypt)
+    DeriveKey,
+    /// Encoding a sample segment to plan split outputs
+    Calibrate,
+    /// Rendering frames and handing them to ffmpeg
+    EncodeFrames,
+    /// Muxing the segments into the output video(s)
+    Mux,
+    /// Downloading an input video with yt-dlp
+    Download,
+    /// Extracting frames from an input video
+    Extract,
+    /// Reading and error-correcting frames
+    DecodeFrames,
+}
+
+impl Stage {
+    /// Name of the stage in JSON events.
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::DeriveKey => "derive_key",
+            Self::Calibrate => "calibrate",
+            Self::EncodeFrames => "encode_frames",
+            Self::Mux => "mux",
+            Self::Download => "download",
+            Self::Extract => "extract",
+            Self::DecodeFrames => "decode_frames",
+        }
+    }
+}
+
+/// What a [`ProgressEvent`] reports.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EventKind {
+    /// The stage started
+    Start,
+    /// One more unit (frame) was done
+    Step,
+    /// The stage finished
+    End,
+}
+
+/// Progress snapshot passed to a [`ProgressSink`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ProgressEvent {
+    pub stage: Stage,
+    pub kind: EventKind,
+    /// Units done and total: frames for the frame stages, otherwise 0 or 1;
+    /// `total` is 0 until the end when the frames are not known in advance
+    /// (a streamed encode)
+    pub done: u64,
+    pub total: u64,
+    /// File bytes processed so far in this stage
+    pub bytes: u64,
+    /// `bytes` per second since the stage started
+    pub bytes_per_sec: f64,
+}
+
+/// Receives progress events from [`crate::encode::encode`] and
+/// [`crate::decode::decode`]; closures taking `&ProgressEvent` implement it.
+pub trait ProgressSink: Send + Sync {
+    fn on_progress(&self, event: &ProgressEvent);
+}
+
+impl<F: Fn(&ProgressEvent) + Send + Sync> ProgressSink for F {
+    fn on_progress(&self, event: &ProgressEvent) {
+        self(event)
+    }
+}
+
+impl ProgressEvent {
+    pub fn to_json(&self) -> Json {
+        let event = match self.kind {
+            EventKind::Start => "stage_start",
+            EventKind::Step => "frame",
+            EventKind::End => "stage_end",
+        };
+        Json::object([
+            ("event", event.into()),
+            ("stage", self.stage.name().into()),
+            ("done", self.done.into()),
+            ("total", self.total.into()),
+            ("bytes", self.bytes.into()),
+            ("bytes_per_sec", self.bytes_per_sec.into()),
+        ])
+    }
+}
+
+/// Sink that ignores all events.
+pub struct NoProgress;
+
+impl ProgressSink for NoProgress {
+    fn on_progress(&self, _: &ProgressEvent) {}
+}
+
+/// Sink writing every event to the event stream (see [`send_events_to`]).
+pub struct JsonProgress;
+
+impl ProgressSink for JsonProgress {
+    fn on_progress(&self, e: &ProgressEvent) {
+        event(e.to_json());
+    }
+}
+
+/// Layer writing each message of the library's `tracing` events to the
+/// event stream as a `log` event, and to stderr, prefixing warnings and
+/// errors, unless the events go there (what the CLI installs).
+#[cfg(feature = "cli")]
+pub struct LogLayer;
+
+#[cfg(feature = "cli")]
+impl<S: tracing::Subscriber> tracing_subscriber::Layer<S> for LogLayer {
+    fn on_event(&self, e: &tracing::Event<'_>, _: tracing_subscriber::layer::Context<'_, S>) {
+        let mut message = Message(String::new());
+        e.record(&mut message);
+        let (level, message) = (*e.metadata().level(), message.0);
+        let level_name = match level {
+            tracing::Level::ERROR => "error",
+            tracing::Level::WARN => "warning",
+            tracing::Level::INFO => "info",
+            tracing::Level::DEBUG => "debug",
+            tracing::Level::TRACE => "trace",
+        };
+        event(Json::object([
+            ("event", "log".into()),
+            ("level", level_name.into()),
+            ("message", message.as_str().into()),
+        ]));
+        if !events_on_stderr() {
+            match level {
+                tracing::Level::ERROR => eprintln!("Error: {message}"),
+                tracing::Level::WARN => eprintln!("Warning: {message}"),
+                _ => eprintln!("{message}"),
+            }
+        }
+    }
+}
+
+/// The `message` field of an event, as formatted by the macro.
+#[cfg(feature = "cli")]
+struct Message(String);
+
+#[cfg(feature = "cli")]
+impl tracing::field::Visit for Message {
+    fn record_debug(&mut self, field: &tracing::field::Field, value: &dyn std::fmt::Debug) {
+        if field.name() == "message" {
+            self.0 = format!("{value:?}");
+        }
+    }
+}
+
+/// One stage's terminal bar plus the events for its sink.
+pub(crate) struct Tracker<'a> {
+    sink: &'a dyn ProgressSink,
+    bar: ProgressBar,
+    stage: Stage,
+    done: u64,
+    total: u64,
+    bytes: u64,
+    start: Instant,
+}
+
+impl<'a> Tracker<'a> {
+    /// Stage counting `total` frames.
+    pub fn frames(sink: &'a dyn ProgressSink, stage: Stage, total: u64) -> Self {
+        Self::start(sink, stage, total, frames(total))
+    }
+
+    /// Stage counting frames whose number is only known when it finishes.
+    pub fn open_frames(sink: &'a dyn ProgressSink, stage: Stage) -> Self {
+        Self::start(sink, stage, 0, frame_counter())
+    }
+
+    /// Single-step stage shown as a spinner with `msg`.
+    pub fn spinner(sink: &'a dyn ProgressSink, stage: Stage, msg: impl Into<String>) -> Self {
+        Self::start(sink, stage, 1, spinner(msg))
+    }
+
+    fn start(sink: &'a dyn ProgressSink, stage: Stage, total: u64, bar: ProgressBar) -> Self {
+        let tracker = Self {
+            sink,
+            bar,
+            stage,
+            done: 0,
+            total,
+            bytes: 0,
+            start: Instant::now(),
+        };
+        tracker.emit(EventKind::Start);
+        tracker
+    }
+
+    fn emit(&self, kind: EventKind) {
+        let secs = self.start.elapsed().as_secs_f64();
+        self.sink.on_progress(&ProgressEvent {
+            stage: self.stage,
+            kind,
+            done: self.done,
+            total: self.total,
+            bytes: self.bytes,
+            bytes_per_sec: if secs > 0.0 {
+                self.bytes as f64 / secs
+            } else {
+                0.0
+            },
+        });
+    }
+
+    /// Skip ahead to `done` units already finished (resume).
+    pub fn set_position(&mut self, done: u64) {
+        self.done = done;
+        self.bar.set_position(done);
+    }
+
+    /// One more unit done, carrying `bytes` of the file.
+    pub fn inc(&mut self, bytes: u64) {
+        self.done += 1;
+        self.bytes += bytes;
+        self.bar.inc(1);
+        self.emit(EventKind::Step);
+    }
+
+    /// Count `bytes` towards the next event without finishing a unit.
+    pub fn add_bytes(&mut self, bytes: u64) {
+        self.bytes += bytes;
+    }
+
+    pub fn set_message(&self, msg: impl Into<String>) {
+        self.bar.set_message(msg.into());
+    }
+
+    pub fn finish_with_message(mut self, msg: impl Into<String>) {
+        self.total = self.total.max(self.done);
+        self.done = self.total;
+        self.bar.finish_with_message(msg.into());
+        self.emit(EventKind::End);
+    }
+
+    pub fn finish_and_clear(mut self) {
+        self.total = self.total.max(self.done);
+        self.done = self.total;
+        self.bar.finish_and_clear();
+        self.emit(EventKind::End);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::Mutex;
+
+    #[test]
+    fn test_tracker_events() {
+        set_hidden(true);
+        let events = Mutex::new(Vec::new());
+        let sink = |e: &ProgressEvent| events.lock().unwrap().push(*e);
+        let mut t = Tracker::frames(&sink, Stage::EncodeFrames, 3);
+        t.set_position(1);
+        t.inc(100);
+        t.finish_and_clear();
+
+        let events = events.into_inner().unwrap();
+        let seen: Vec<(EventKind, u64, u64)> =
+            events.iter().map(|e| (e.kind, e.done, e.bytes)).collect();
+        assert_eq!(
+            seen,
+            vec![
+                (EventKind::Start, 0, 0),
+                (EventKind::Step, 2, 100),
+                (EventKind::End, 3, 100)
+            ]
+        );
+        assert!(events
+            .iter()
+            .all(|e| e.stage == Stage::EncodeFrames && e.total == 3));
+        assert!(events[1].to_json().to_string().starts_with(
+            r#"{"event":"frame","stage":"encode_frames","done":2,"total":3,"bytes":100,"#
+        ));
+    }
+
+    #[cfg(feature = "cli")]
+    #[test]
+    fn test_event_stream() {
+        use tracing_subscriber::layer::SubscriberExt;
+
+        set_hidden(true);
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("events.jsonl");
+        send_events_to(&path).unwrap();
+        assert!(!events_on_stderr());
+
+        let mut t = Tracker::frames(&JsonProgress, Stage::EncodeFrames, 2);
+        t.inc(10);
+        t.finish_and_clear();
+        let subscriber = tracing_subscriber::registry().with(LogLayer);
+        tracing::subscriber::with_default(subscriber, || tracing::warn!("careful"));
+
+        let text = std::fs::read_to_string(&path).unwrap();
+        let events: Vec<&str> = text.lines().collect();
+        assert_eq!(events.len(), 4);
+        assert!(events[0].starts_with(r#"{"event":"stage_start","stage":"encode_frames","#));
+        assert!(events[1].starts_with(r#"{"event":"frame","stage":"encode_frames","done":1,"#));
+        assert!(events[2].starts_with(r#"{"event":"stage_end","#));
+        assert_eq!(
+            events[3],
+            r#"{"event":"log","level":"warning","message":"careful"}"#
+        );
+    }
+}
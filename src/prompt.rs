@@ -0,0 +1,132 @@
+use std::io::{Read, Write};
+
+use zeroize::Zeroizing;
+
+use crate::error::{Result, VstorageError};
+
+/// Ask for a password on the terminal without echoing it.
+#[cfg(unix)]
+pub fn read_password(prompt: &str) -> Result<String> {
+    use std::os::fd::AsRawFd;
+
+    let mut tty = std::fs::OpenOptions::new()
+        .read(true)
+        .write(true)
+        .open("/dev/tty")
+        .map_err(|_| no_terminal())?;
+    write!(tty, "{prompt}")?;
+    tty.flush()?;
+    let line = {
+        let _raw = RawMode::enable(tty.as_raw_fd())?;
+        edit_line(std::io::BufReader::new(&tty).bytes().map_while(|b| b.ok()))
+    };
+    writeln!(tty)?;
+    line
+}
+
+/// Password prompts need a Unix terminal.
+#[cfg(not(unix))]
+pub fn read_password(_prompt: &str) -> Result<String> {
+    Err(no_terminal())
+}
+
+/// Ask for a new password (`label`, like "Password") twice, so a typo does
+/// not lock the data away.
+pub fn new_password(label: &str) -> Result<String> {
+    let password = read_password(&format!("{label}: "))?;
+    if password.is_empty() {
+        return Err(VstorageError::Config("the password is empty".into()));
+    }
+    if read_password(&format!("Confirm {}: ", label.to_lowercase()))? != password {
+        return Err(VstorageError::Config("the passwords do not match".into()));
+    }
+    Ok(password)
+}
+
+/// Whether [`read_password`] has a terminal to ask on.
+pub fn can_prompt() -> bool {
+    cfg!(unix) && std::fs::File::open("/dev/tty").is_ok()
+}
+
+fn no_terminal() -> VstorageError {
+    VstorageError::Config("no terminal to ask for the password on; pass -p".into())
+}
+
+/// One line of keystrokes from a terminal in raw mode: Enter ends it,
+/// Backspace deletes and Ctrl-C cancels.
+fn edit_line(keys: impl Iterator<Item = u8>) -> Result<String> {
+    let mut line = Zeroizing::new(Vec::new());
+    for key in keys {
+        match key {
+            b'\r' | b'\n' => return Ok(String::from_utf8_lossy(&line[..]).into_owned()),
+            0x03 => return Err(VstorageError::Cancelled),
+            0x7f | 0x08 => {
+                // Drop a whole UTF-8 character
+                while let Some(b) = line.pop() {
+                    if b & 0xc0 != 0x80 {
+                        break;
+                    }
+                }
+            }
+            0x15 => line.clear(),
+            b => line.push(b),
+        }
+    }
+    Err(VstorageError::Config("no password entered".into()))
+}
+
+/// Turns off echo, line buffering and signal keys on a terminal until
+/// dropped.
+#[cfg(unix)]
+struct RawMode {
+    fd: i32,
+    saved: libc::termios,
+}
+
+#[cfg(unix)]
+impl RawMode {
+    fn enable(fd: i32) -> Result<Self> {
+        // SAFETY: termios is plain data, filled in by tcgetattr
+        let mut saved: libc::termios = unsafe { std::mem::zeroed() };
+        // SAFETY: fd is an open terminal and the pointers are valid
+        unsafe {
+            if libc::tcgetattr(fd, &mut saved) != 0 {
+                return Err(std::io::Error::last_os_error().into());
+            }
+            let mut raw = saved;
+            raw.c_lflag &= !(libc::ECHO | libc::ICANON | libc::ISIG);
+            raw.c_cc[libc::VMIN] = 1;
+            raw.c_cc[libc::VTIME] = 0;
+            if libc::tcsetattr(fd, libc::TCSAFLUSH, &raw) != 0 {
+                return Err(std::io::Error::last_os_error().into());
+            }
+        }
+        Ok(Self { fd, saved })
+    }
+}
+
+#[cfg(unix)]
+impl Drop for RawMode {
+    fn drop(&mut self) {
+        // SAFETY: restores the settings read in `enable`
+        unsafe {
+            libc::tcsetattr(self.fd, libc::TCSAFLUSH, &self.saved);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_edit_line() {
+        let typed = |s: &[u8]| edit_line(s.iter().copied());
+        assert_eq!(typed(b"hunter2\r").unwrap(), "hunter2");
+        assert_eq!(typed(b"pw\x7f\x7fok\n").unwrap(), "ok");
+        assert_eq!(typed("né\x7fe\r".as_bytes()).unwrap(), "ne");
+        assert_eq!(typed(b"wrong\x15right\r").unwrap(), "right");
+        assert!(matches!(typed(b"pw\x03"), Err(VstorageError::Cancelled)));
+        assert!(typed(b"no enter").is_err());
+    }
+}
@@ -0,0 +1,230 @@
+use crate::error::{Result, VstorageError};
+
+/// Data and ECC codewords of versions 1 to 4 at error correction level L,
+/// each a single RS block.
+const VERSIONS: [(usize, usize); 4] = [(19, 7), (34, 10), (55, 15), (80, 20)];
+
+/// A QR code symbol: `size` x `size` modules, `true` dark.
+#[derive(Debug, Clone, PartialEq)]
+pub struct QrCode {
+    pub size: usize,
+    modules: Vec<bool>,
+}
+
+impl QrCode {
+    /// Encode `data` in byte mode at level L, in the smallest of versions 1
+    /// to 4 it fits (up to 78 bytes), with mask pattern 0.
+    pub fn encode(data: &[u8]) -> Result<Self> {
+        let (version, &(data_len, ecc_len)) = VERSIONS
+            .iter()
+            .enumerate()
+            .find(|(_, (n, _))| data.len() + 2 <= *n)
+            .ok_or_else(|| {
+                VstorageError::Config(format!("{} bytes are too many for a QR code", data.len()))
+            })?;
+        let mut codewords = data_codewords(data, data_len);
+        codewords.extend(ecc(&codewords, ecc_len));
+
+        let size = 21 + version * 4;
+        let mut qr = Self {
+            size,
+            modules: vec![false; size * size],
+        };
+        let mut function = vec![false; size * size];
+        qr.draw_function_patterns(version + 1, &mut function);
+        qr.place(&codewords, &function);
+        Ok(qr)
+    }
+
+    /// Whether the module in column `x` of row `y` is dark.
+    pub fn get(&self, x: usize, y: usize) -> bool {
+        self.modules[y * self.size + x]
+    }
+
+    fn set(&mut self, function: &mut [bool], x: usize, y: usize, dark: bool) {
+        self.modules[y * self.size + x] = dark;
+        function[y * self.size + x] = true;
+    }
+
+    /// Timing, finder and alignment patterns and the format information.
+    fn draw_function_patterns(&mut self, version: usize, function: &mut [bool]) {
+        let size = self.size;
+        for i in 0..size {
+            self.set(function, 6, i, i % 2 == 0);
+            self.set(function, i, 6, i % 2 == 0);
+        }
+        for (cx, cy) in [(3, 3), (size - 4, 3), (3, size - 4)] {
+            for dy in -4i64..=4 {
+                for dx in -4i64..=4 {
+                    let (x, y) = (cx as i64 + dx, cy as i64 + dy);
+                    if (0..size as i64).contains(&x) && (0..size as i64).contains(&y) {
+                        let ring = dx.abs().max(dy.abs());
+                        self.set(function, x as usize, y as usize, ring != 2 && ring != 4);
+                    }
+                }
+            }
+        }
+        if version > 1 {
+            let c = size - 7;
+            for dy in -2i64..=2 {
+                for dx in -2i64..=2 {
+                    let (x, y) = ((c as i64 + dx) as usize, (c as i64 + dy) as usize);
+                    self.set(function, x, y, dx.abs().max(dy.abs()) != 1);
+                }
+            }
+        }
+
+        // Level L (01) and mask 0, BCH-coded, in both copies
+        let bits = format_bits(0b01 << 3);
+        let bit = |i: usize| bits >> i & 1 != 0;
+        for i in 0..6 {
+            self.set(function, 8, i, bit(i));
+        }
+        self.set(function, 8, 7, bit(6));
+        self.set(function, 8, 8, bit(7));
+        self.set(function, 7, 8, bit(8));
+        for i in 9..15 {
+            self.set(function, 14 - i, 8, bit(i));
+        }
+        for i in 0..8 {
+            self.set(function, size - 1 - i, 8, bit(i));
+        }
+        for i in 8..15 {
+            self.set(function, 8, size - 15 + i, bit(i));
+        }
+        self.set(function, 8, size - 8, true);
+    }
+
+    /// Lay the codewords in the zigzag of column pairs and apply mask 0.
+    fn place(&mut self, codewords: &[u8], function: &[bool]) {
+        let size = self.size;
+        let total_bits = codewords.len() * 8;
+        let mut i = 0;
+        let mut right = size - 1;
+        loop {
+            if right == 6 {
+                right = 5;
+            }
+            for vert in 0..size {
+                for x in [right, right - 1] {
+                    let upward = (right + 1) & 2 == 0;
+                    let y = if upward { size - 1 - vert } else { vert };
+                    if function[y * size + x] {
+                        continue;
+                    }
+                    let dark = i < total_bits && codewords[i / 8] >> (7 - i % 8) & 1 != 0;
+                    self.modules[y * size + x] = dark ^ ((x + y) % 2 == 0);
+                    i += 1;
+                }
+            }
+            if right < 2 {
+                break;
+            }
+            right -= 2;
+        }
+    }
+}
+
+/// Mode indicator, length, `data`, terminator and pad bytes, `len` in all.
+fn data_codewords(data: &[u8], len: usize) -> Vec<u8> {
+    let mut out = Vec::with_capacity(len);
+    // 0100 (byte mode), then the 8-bit count, shifting `data` by a nibble
+    out.push(0x40 | (data.len() >> 4) as u8);
+    let mut carry = (data.len() as u8) << 4;
+    for &byte in data {
+        out.push(carry | byte >> 4);
+        carry = byte << 4;
+    }
+    out.push(carry);
+    for pad in [0xEC, 0x11].into_iter().cycle() {
+        if out.len() >= len {
+            break;
+        }
+        out.push(pad);
+    }
+    out
+}
+
+/// Product of `a` and `b` in GF(256) modulo x^8 + x^4 + x^3 + x^2 + 1.
+fn gf_mul(mut a: u8, mut b: u8) -> u8 {
+    let mut product = 0u8;
+    while b != 0 {
+        if b & 1 != 0 {
+            product ^= a;
+        }
+        a = (a << 1) ^ if a & 0x80 != 0 { 0x1D } else { 0 };
+        b >>= 1;
+    }
+    product
+}
+
+/// The `len` ECC codewords of `data`.
+fn ecc(data: &[u8], len: usize) -> Vec<u8> {
+    // Generator (x - a^0)(x - a^1)...(x - a^(len-1)), leading 1 left out
+    let mut generator = vec![0u8; len];
+    generator[len - 1] = 1;
+    let mut root = 1u8;
+    for _ in 0..len {
+        for j in 0..len {
+            generator[j] = gf_mul(generator[j], root);
+            if j + 1 < len {
+                generator[j] ^= generator[j + 1];
+            }
+        }
+        root = gf_mul(root, 2);
+    }
+    let mut remainder = vec![0u8; len];
+    for &byte in data {
+        let factor = byte ^ remainder.remove(0);
+        remainder.push(0);
+        for (r, &g) in remainder.iter_mut().zip(&generator) {
+            *r ^= gf_mul(g, factor);
+        }
+    }
+    remainder
+}
+
+/// The 15 format bits for the 5 bits of `data` (level and mask).
+fn format_bits(data: u16) -> u16 {
+    let mut rem = data;
+    for _ in 0..10 {
+        rem = (rem << 1) ^ ((rem >> 9) * 0x537);
+    }
+    (data << 10 | rem) ^ 0x5412
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_codewords() {
+        // The HELLO WORLD example of the standard (version 1-M) codewords
+        let data = [
+            32, 91, 11, 120, 209, 114, 220, 77, 67, 64, 236, 17, 236, 17, 236, 17,
+        ];
+        assert_eq!(
+            ecc(&data, 10),
+            [196, 35, 39, 119, 235, 215, 231, 226, 93, 23]
+        );
+        assert_eq!(format_bits(0b01 << 3), 0b111011111000100);
+        assert_eq!(
+            data_codewords(b"ab", 6),
+            [0x40, 0x26, 0x16, 0x20, 0xEC, 0x11]
+        );
+    }
+
+    #[test]
+    fn test_symbol_layout() {
+        let qr = QrCode::encode(b"https://github.com/levish0/Vstorage").unwrap();
+        assert_eq!(qr.size, 29);
+        // Finder corners, their separators, timing and the dark module
+        assert!(qr.get(0, 0) && qr.get(28, 0) && qr.get(0, 28));
+        assert!(!qr.get(7, 0) && !qr.get(21, 7));
+        assert!(qr.get(8, 6) && !qr.get(9, 6));
+        assert!(qr.get(8, 21));
+        // Alignment pattern centred at (22, 22)
+        assert!(qr.get(22, 22) && !qr.get(21, 22) && qr.get(20, 22));
+        assert!(QrCode::encode(&[0; 79]).is_err());
+    }
+}
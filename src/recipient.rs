@@ -0,0 +1,253 @@
+use std::fmt;
+use std::str::FromStr;
+
+use sha2::{Digest, Sha256};
+use x25519_dalek::{x25519, X25519_BASEPOINT_BYTES};
+use zeroize::{Zeroize, Zeroizing};
+
+use crate::crypto::{FrameCipher, TAG_LEN};
+use crate::error::{Result, VstorageError};
+
+/// Start of the key list stored in a payload's key frame.
+pub const KEYS_MAGIC: &[u8; 4] = b"VKEY";
+
+const PUBLIC_PREFIX: &str = "vspub1";
+const SECRET_PREFIX: &str = "VSTORAGE-SECRET-KEY-";
+
+/// One wrapped file key: ephemeral public key | file key sealed under the
+/// shared secret.
+const STANZA_LEN: usize = 32 + 32 + TAG_LEN;
+
+/// An X25519 public key a payload can be encrypted to, written `vspub1`
+/// followed by 64 hex digits.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Recipient {
+    pub public: [u8; 32],
+}
+
+impl FromStr for Recipient {
+    type Err = VstorageError;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let public = s
+            .trim()
+            .strip_prefix(PUBLIC_PREFIX)
+            .and_then(parse_hex)
+            .ok_or_else(|| {
+                VstorageError::Config(format!(
+                    "invalid recipient '{s}' — expected {PUBLIC_PREFIX} and 64 hex digits"
+                ))
+            })?;
+        Ok(Self { public })
+    }
+}
+
+impl fmt::Display for Recipient {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{PUBLIC_PREFIX}{}", to_hex(&self.public))
+    }
+}
+
+/// The private key of a [`Recipient`]. Identity files hold it on a line
+/// `VSTORAGE-SECRET-KEY-` and 64 hex digits; `#` lines are comments.
+#[derive(Clone)]
+pub struct Identity {
+    secret: [u8; 32],
+}
+
+impl Identity {
+    pub fn generate() -> Self {
+        let mut secret = [0u8; 32];
+        rand::fill(&mut secret);
+        Self { secret }
+    }
+
+    pub fn recipient(&self) -> Recipient {
+        Recipient {
+            public: x25519(self.secret, X25519_BASEPOINT_BYTES),
+        }
+    }
+
+    /// Read an identity file's contents.
+    pub fn parse(text: &str) -> Result<Self> {
+        text.lines()
+            .map(str::trim)
+            .filter(|l| !l.is_empty() && !l.starts_with('#'))
+            .find_map(|l| l.strip_prefix(SECRET_PREFIX))
+            .and_then(parse_hex)
+            .map(|secret| Self { secret })
+            .ok_or_else(|| VstorageError::Config("no secret key found in the identity file".into()))
+    }
+
+    /// Contents of an identity file, with the public key as a comment.
+    pub fn to_file(&self) -> String {
+        format!(
+            "# public key: {}\n{SECRET_PREFIX}{}\n",
+            self.recipient(),
+            to_hex(&self.secret).to_uppercase()
+        )
+    }
+}
+
+impl Drop for Identity {
+    fn drop(&mut self) {
+        self.secret.zeroize();
+    }
+}
+
+impl fmt::Debug for Identity {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Identity({})", self.recipient())
+    }
+}
+
+/// A fresh file key wrapped for each of `recipients`: the frame cipher,
+/// its base nonce and the key list for the payload's key frame.
+pub fn seal(recipients: &[Recipient]) -> Result<(FrameCipher, [u8; 12], Vec<u8>)> {
+    let mut file_key = Zeroizing::new([0u8; 32]);
+    let mut nonce = [0u8; 12];
+    rand::fill(&mut *file_key);
+    rand::fill(&mut nonce);
+    let keys = wrap_key(&file_key, recipients)?;
+    Ok((FrameCipher::from_key(&file_key, nonce)?, nonce, keys))
+}
+
+/// Key list (magic | count u16 | stanzas) giving each recipient `file_key`:
+/// it is sealed under the X25519 secret shared by a one-off ephemeral key
+/// and the recipient's public key.
+pub fn wrap_key(file_key: &[u8; 32], recipients: &[Recipient]) -> Result<Vec<u8>> {
+    if recipients.is_empty() || recipients.len() > u16::MAX as usize {
+        return Err(VstorageError::Config(
+            "give between 1 and 65535 recipients".into(),
+        ));
+    }
+    let mut buf = Vec::with_capacity(6 + recipients.len() * STANZA_LEN);
+    buf.extend_from_slice(KEYS_MAGIC);
+    buf.extend_from_slice(&(recipients.len() as u16).to_be_bytes());
+    for recipient in recipients {
+        let ephemeral = Identity::generate();
+        let ephemeral_public = ephemeral.recipient().public;
+        let shared = Zeroizing::new(x25519(ephemeral.secret, recipient.public));
+        if *shared == [0; 32] {
+            return Err(VstorageError::Config(format!(
+                "{recipient} is not a usable public key"
+            )));
+        }
+        let wrapped = stanza_cipher(&shared, &ephemeral_public, &recipient.public)?
+            .encrypt_frame(0, file_key)?;
+        buf.extend_from_slice(&ephemeral_public);
+        buf.extend_from_slice(&wrapped);
+    }
+    Ok(buf)
+}
+
+/// The file key in `keys` wrapped for `identity`.
+pub fn unwrap_key(keys: &[u8], identity: &Identity) -> Result<Zeroizing<[u8; 32]>> {
+    if keys.len() < 6 || &keys[0..4] != KEYS_MAGIC {
+        return Err(VstorageError::Header("invalid key list magic".into()));
+    }
+    let count = u16::from_be_bytes([keys[4], keys[5]]) as usize;
+    let stanzas = &keys[6..];
+    if stanzas.len() < count * STANZA_LEN {
+        return Err(VstorageError::Header("key list truncated".into()));
+    }
+    let public = identity.recipient().public;
+    for stanza in stanzas.chunks_exact(STANZA_LEN).take(count) {
+        let ephemeral_public: [u8; 32] = stanza[..32].try_into().unwrap();
+        let shared = Zeroizing::new(x25519(identity.secret, ephemeral_public));
+        if *shared == [0; 32] {
+            continue;
+        }
+        let cipher = stanza_cipher(&shared, &ephemeral_public, &public)?;
+        if let Ok(key) = cipher.decrypt_frame(0, &stanza[32..]) {
+            let key = Zeroizing::new(key);
+            return Ok(Zeroizing::new(
+                key[..].try_into().expect("wrapped keys are 32 bytes"),
+            ));
+        }
+    }
+    Err(VstorageError::WrongIdentity)
+}
+
+/// Cipher sealing the file key of one stanza; its key is used only once, so
+/// the zero nonce is safe.
+fn stanza_cipher(
+    shared: &[u8; 32],
+    ephemeral_public: &[u8; 32],
+    recipient_public: &[u8; 32],
+) -> Result<FrameCipher> {
+    let key = Zeroizing::new(
+        Sha256::new()
+            .chain_update(b"vstorage-x25519")
+            .chain_update(ephemeral_public)
+            .chain_update(recipient_public)
+            .chain_update(shared)
+            .finalize()
+            .into(),
+    );
+    FrameCipher::from_key(&key, [0; 12])
+}
+
+pub(crate) fn to_hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|b| format!("{b:02x}")).collect()
+}
+
+pub(crate) fn parse_hex(s: &str) -> Option<[u8; 32]> {
+    if s.len() != 64 || !s.is_ascii() {
+        return None;
+    }
+    let mut out = [0u8; 32];
+    for (i, b) in out.iter_mut().enumerate() {
+        *b = u8::from_str_radix(&s[i * 2..i * 2 + 2], 16).ok()?;
+    }
+    Some(out)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_wrap_for_recipients() {
+        let (alice, bob, eve) = (
+            Identity::generate(),
+            Identity::generate(),
+            Identity::generate(),
+        );
+        let file_key = [7u8; 32];
+        let keys = wrap_key(&file_key, &[alice.recipient(), bob.recipient()]).unwrap();
+        assert_eq!(keys.len(), 6 + 2 * STANZA_LEN);
+        assert_eq!(*unwrap_key(&keys, &alice).unwrap(), file_key);
+        assert_eq!(*unwrap_key(&keys, &bob).unwrap(), file_key);
+        assert!(matches!(
+            unwrap_key(&keys, &eve),
+            Err(VstorageError::WrongIdentity)
+        ));
+    }
+
+    #[test]
+    fn test_key_text_roundtrip() {
+        let identity = Identity::generate();
+        let parsed = Identity::parse(&identity.to_file()).unwrap();
+        assert_eq!(parsed.recipient(), identity.recipient());
+
+        let text = identity.recipient().to_string();
+        assert!(text.starts_with("vspub1"));
+        assert_eq!(text.parse::<Recipient>().unwrap(), identity.recipient());
+        assert!("vspub1abc".parse::<Recipient>().is_err());
+        assert!(Identity::parse("# nothing here\n").is_err());
+    }
+
+    #[test]
+    fn test_rfc7748_key() {
+        // Identity files written before keep their public keys
+        let identity = Identity::parse(
+            "VSTORAGE-SECRET-KEY-77076D0A7318A57D3C16C17251B26645DF4C2F87EBC0992AB177FBA51DB92C2A",
+        )
+        .unwrap();
+        assert_eq!(
+            identity.recipient().to_string(),
+            "vspub18520f0098930a754748b7ddcb43ef75a0dbf3a0d26381af4eba4a98eaa9b4e6a"
+        );
+    }
+}
@@ -0,0 +1,148 @@
+use crate::json::Json;
+use crate::pieces::PieceCheck;
+
+/// Why a byte range of a partially recovered file cannot be trusted.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "lowercase"))]
+pub enum GapKind {
+    /// Not recovered; zero-filled in the output
+    Missing,
+    /// Recovered, but the frame's data hash did not match
+    Suspect,
+}
+
+impl GapKind {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            GapKind::Missing => "missing",
+            GapKind::Suspect => "suspect",
+        }
+    }
+}
+
+/// A byte range of the original file affected by damaged frames.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Gap {
+    pub offset: u64,
+    pub len: u64,
+    pub kind: GapKind,
+    pub reason: String,
+    /// First and last (0-based, inclusive) frame of the range
+    pub frames: (usize, usize),
+}
+
+/// Damaged frames of one decode, turned into file byte ranges.
+#[derive(Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct GapReport {
+    pub file_size: u64,
+    pub gaps: Vec<Gap>,
+    /// The file checked against its piece hashes, if they were stored and
+    /// readable
+    pub pieces: Option<PieceCheck>,
+}
+
+impl GapReport {
+    pub fn new(file_size: u64) -> Self {
+        Self {
+            file_size,
+            gaps: Vec::new(),
+            pieces: None,
+        }
+    }
+
+    /// Record frame `index` (carrying `per_frame` plaintext bytes) as
+    /// damaged, merging it with the previous range if they are adjacent and
+    /// of the same kind and reason.
+    pub fn add_frame(&mut self, index: usize, per_frame: u64, kind: GapKind, reason: &str) {
+        let offset = index as u64 * per_frame;
+        let len = per_frame.min(self.file_size.saturating_sub(offset));
+        if let Some(last) = self.gaps.last_mut() {
+            if last.kind == kind
+                && last.reason == reason
+                && last.frames.1 + 1 == index
+                && last.offset + last.len == offset
+            {
+                last.len += len;
+                last.frames.1 = index;
+                return;
+            }
+        }
+        self.gaps.push(Gap {
+            offset,
+            len,
+            kind,
+            reason: reason.to_string(),
+            frames: (index, index),
+        });
+    }
+
+    pub fn bytes(&self, kind: GapKind) -> u64 {
+        self.gaps
+            .iter()
+            .filter(|g| g.kind == kind)
+            .map(|g| g.len)
+            .sum()
+    }
+
+    /// Report as JSON; frame numbers are 1-based like everywhere else in
+    /// the CLI output.
+    pub fn to_json(&self) -> Json {
+        let gaps: Vec<Json> = self
+            .gaps
+            .iter()
+            .map(|g| {
+                Json::object([
+                    ("offset", g.offset.into()),
+                    ("length", g.len.into()),
+                    ("status", g.kind.as_str().into()),
+                    ("reason", g.reason.as_str().into()),
+                    ("first_frame", (g.frames.0 + 1).into()),
+                    ("last_frame", (g.frames.1 + 1).into()),
+                ])
+            })
+            .collect();
+        let mut json = Json::object([
+            ("file_size", self.file_size.into()),
+            ("missing_bytes", self.bytes(GapKind::Missing).into()),
+            ("suspect_bytes", self.bytes(GapKind::Suspect).into()),
+            ("gaps", gaps.into()),
+        ]);
+        if let Some(pieces) = &self.pieces {
+            json.push("pieces", pieces.to_json());
+        }
+        json
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_gap_merging() {
+        let mut report = GapReport::new(250);
+        report.add_frame(0, 100, GapKind::Missing, "uncorrectable");
+        report.add_frame(1, 100, GapKind::Missing, "uncorrectable");
+        report.add_frame(2, 100, GapKind::Suspect, "hash mismatch");
+        assert_eq!(report.gaps.len(), 2);
+        assert_eq!(report.gaps[0].len, 200);
+        assert_eq!(report.gaps[0].frames, (0, 1));
+        assert_eq!(report.gaps[1].len, 50);
+        assert_eq!(report.bytes(GapKind::Missing), 200);
+    }
+
+    #[test]
+    fn test_gap_json() {
+        let mut report = GapReport::new(10);
+        report.add_frame(0, 10, GapKind::Missing, "frame \"1\" absent");
+        assert_eq!(
+            report.to_json().to_string(),
+            "{\"file_size\":10,\"missing_bytes\":10,\"suspect_bytes\":0,\"gaps\":[\
+             {\"offset\":0,\"length\":10,\"status\":\"missing\",\
+             \"reason\":\"frame \\\"1\\\" absent\",\"first_frame\":1,\"last_frame\":1}]}"
+        );
+    }
+}
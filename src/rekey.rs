@@ -0,0 +1,214 @@
+use std::path::Path;
+use std::sync::Arc;
+
+use crate::cancel::CancelToken;
+use crate::collect::Collected;
+use crate::config::Workspace;
+use crate::crypto::FrameCipher;
+use crate::crypto::Token;
+use crate::decode::{self, DecodeOptions, Recovered};
+use crate::encode::{self, EncodeSummary, Input, SplitLimits};
+use crate::error::{Result, VstorageError};
+use crate::mmap::FileData;
+use crate::progress::ProgressSink;
+use crate::signature::SIGNATURE_NONCE;
+use crate::{audio, pack, video};
+
+/// Write `input` to `output` under `new_password` instead of
+/// `old_password`, keeping its frame settings, codec, audio side channel,
+/// signature and padding frames (with any file hidden in them).
+///
+/// The frames are keyed by the password itself, with no file key to rewrap,
+/// so the file is decoded into memory and encoded again; nothing of it is
+/// written out unencrypted. Videos holding several files (packed or appended
+/// to) or encrypted to recipients are refused, as is the password of a
+/// hidden file. Keys that need a hardware token take `hardware`'s response;
+/// frames are extracted and written in `workspace`.
+#[allow(clippy::too_many_arguments)]
+pub fn rekey(
+    input: &Path,
+    output: &Path,
+    old_password: Option<&str>,
+    new_password: Option<&str>,
+    crf: u8,
+    hardware: Option<Arc<dyn Token>>,
+    workspace: &Workspace,
+    progress: &dyn ProgressSink,
+    cancel: &CancelToken,
+) -> Result<EncodeSummary> {
+    video::check_ffmpeg()?;
+    let index = pack::list(input, workspace)?;
+    if index.entries.len() != 1 || index.entries[0].first_frame != 0 {
+        return Err(VstorageError::Config(
+            "rekey works on videos holding one file; extract the files of a packed or appended video first"
+                .into(),
+        ));
+    }
+    let stream = video::probe_video(input)?;
+    let temp_dir = video::temp_dir(workspace)?;
+
+    // 1. The file and the side channel, under the old password
+    let recovered = decode::recover(
+        &[input],
+        output,
+        old_password,
+        &DecodeOptions {
+            workspace: workspace.clone(),
+            hardware_token: hardware.clone(),
+            ..DecodeOptions::default()
+        },
+        temp_dir.path(),
+        progress,
+        cancel,
+    )
+    .map_err(|e| match e {
+        VstorageError::IdentityRequired => VstorageError::Config(
+            "the video is encrypted to recipients, whose keys rekey cannot wrap again; \
+             decode it with --identity and encode it anew"
+                .into(),
+        ),
+        e => e,
+    })?;
+    let Recovered {
+        collected,
+        cipher,
+        plaintext,
+        hidden,
+        ..
+    } = recovered;
+    if hidden {
+        return Err(VstorageError::Config(
+            "the password opens the file hidden in the padding, which rekey keeps as it is; \
+             give the password of the video's own file"
+                .into(),
+        ));
+    }
+    let (signature, padding) = carried_over(&collected, cipher.as_ref())?;
+    let wav = temp_dir.path().join("audio.wav");
+    let side = video::extract_audio(input, &wav, cancel).and_then(|()| {
+        audio::decode_side_channel(&audio::read_wav(&wav)?, old_password, hardware.as_deref())
+    });
+    let audio = match side {
+        Ok(side) => Some(side),
+        Err(e) => {
+            tracing::debug!("No audio side channel to carry over: {e}");
+            None
+        }
+    };
+
+    // 2. Everything again under the new one
+    let mut config = collected.config;
+    config.workspace = workspace.clone();
+    config.hardware_token = hardware;
+    config.fps = stream.fps;
+    config.crf = crf;
+    config.codec = stream.codec;
+    if stream.pix_fmt == "yuv420p" {
+        config.enable_yuv420p()?;
+    }
+    let input = Input {
+        data: FileData::Owned(plaintext),
+        path: input.to_path_buf(),
+        mtime: 0,
+        audio,
+        signature,
+    };
+    encode::encode_data(
+        input,
+        output,
+        new_password,
+        &[],
+        &config,
+        &padding,
+        None,
+        &SplitLimits::default(),
+        false,
+        progress,
+        cancel,
+    )
+}
+
+/// The signature frame (decrypted by `cipher`) and the padding of
+/// `collected`, for the frames encoded again to keep. A signature covers
+/// the stored file, not its key, so it holds under any password.
+fn carried_over(
+    collected: &Collected,
+    cipher: Option<&FrameCipher>,
+) -> Result<(Option<Vec<u8>>, Vec<u8>)> {
+    let signature = match (&collected.signature, cipher) {
+        (None, _) => None,
+        (Some(frame), Some(cipher)) => Some(cipher.decrypt_frame(SIGNATURE_NONCE, frame)?.to_vec()),
+        (Some(frame), None) => Some(frame.clone()),
+    };
+    let padding = match collected.padding.is_empty() {
+        true => Vec::new(),
+        false => collected.padding_bytes().ok_or_else(|| {
+            VstorageError::Header(
+                "a padding frame did not decode, so the padding cannot be kept".into(),
+            )
+        })?,
+    };
+    Ok((signature, padding))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::collect::{self, FrameCollector};
+    use crate::config::FrameConfig;
+    use crate::frame::Preprocess;
+    use crate::memory::{self, EncodeOptions, FramePlan};
+    use crate::signature::SigningKey;
+
+    #[test]
+    fn test_rekey_roundtrip() {
+        let config = FrameConfig::new(8, 2, 32, 30, 18).unwrap();
+        let data = crate::testutil::pattern(30_000, 7, 256);
+        let key = SigningKey::generate();
+        let padding = crate::hidden::padding(20_000, Some((b"inner file", "inner"))).unwrap();
+        let options = EncodeOptions {
+            password: Some("old".into()),
+            padding,
+            sign: Some(key.clone()),
+            ..EncodeOptions::new(config.clone())
+        };
+        let frames = memory::encode_bytes(&data, &options).unwrap();
+
+        let mut collector = FrameCollector::new(0, config.clone(), Preprocess::None, false);
+        for img in &frames {
+            collector.add(&"input", img).unwrap();
+        }
+        let collected = collector.finish().unwrap();
+        let old = collect::cipher_for(&collected.first_header, Some("old"), None).unwrap();
+        let (signature, padding) = carried_over(&collected, old.as_ref()).unwrap();
+
+        // The file re-framed under the new password, keeping the rest
+        let (cipher, nonce, salt) =
+            FrameCipher::generate("new", config.kdf, config.token, None).unwrap();
+        let plan = FramePlan::new(&config, &data, Some(cipher), nonce, salt, Vec::new())
+            .unwrap()
+            .with_signature_frame(signature.unwrap())
+            .with_padding(&padding);
+        let frames: Vec<_> = (0..plan.num_frames)
+            .map(|i| plan.render(i, 0, 1).unwrap())
+            .collect();
+        let decode = |password: &str| {
+            let options = memory::DecodeOptions {
+                password: Some(password.into()),
+                verify_key: Some(key.verify_key()),
+                ..Default::default()
+            };
+            memory::decode_frames(&frames, &options)
+        };
+        assert_eq!(decode("new").unwrap(), data);
+        assert!(matches!(decode("old"), Err(VstorageError::WrongPassword)));
+        let inner = memory::DecodeOptions {
+            password: Some("inner".into()),
+            ..Default::default()
+        };
+        assert_eq!(
+            memory::decode_frames(&frames, &inner).unwrap(),
+            b"inner file"
+        );
+    }
+}
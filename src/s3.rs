@@ -0,0 +1,152 @@
+use std::fmt;
+use std::io::Read;
+use std::path::{Path, PathBuf};
+use std::process::{Command, Stdio};
+use std::time::Duration;
+
+use crate::cancel::CancelToken;
+use crate::error::{Result, VstorageError};
+
+/// How long a presigned read URL stays valid; long enough for ffmpeg to
+/// extract every frame of a large video.
+const PRESIGN_SECS: u32 = 12 * 3600;
+
+/// An object in S3 or an S3-compatible store, written `s3://bucket/key`.
+///
+/// Transfers go through the `aws` CLI, so its credentials, profiles and
+/// endpoint settings (`AWS_ENDPOINT_URL` for other stores) apply.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct S3Url {
+    pub bucket: String,
+    pub key: String,
+}
+
+impl S3Url {
+    /// The object `path` names, if it is an `s3://` URL.
+    pub fn parse(path: &Path) -> Option<Self> {
+        let rest = path.to_str()?.strip_prefix("s3://")?;
+        let (bucket, key) = rest.split_once('/')?;
+        (!bucket.is_empty() && !key.is_empty()).then(|| Self {
+            bucket: bucket.into(),
+            key: key.into(),
+        })
+    }
+
+    /// Last component of the key.
+    pub fn file_name(&self) -> &str {
+        self.key.rsplit('/').next().unwrap_or(&self.key)
+    }
+}
+
+impl fmt::Display for S3Url {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "s3://{}/{}", self.bucket, self.key)
+    }
+}
+
+/// `path` itself, or for an `s3://` object a presigned HTTPS URL that
+/// ffmpeg reads with range requests, so the video is never downloaded whole.
+pub fn readable(path: &Path) -> Result<PathBuf> {
+    let Some(url) = S3Url::parse(path) else {
+        return Ok(path.to_path_buf());
+    };
+    let output = Command::new("aws")
+        .args(["s3", "presign", &url.to_string(), "--expires-in"])
+        .arg(PRESIGN_SECS.to_string())
+        .stdin(Stdio::null())
+        .output()
+        .map_err(not_found)?;
+    if !output.status.success() {
+        return Err(VstorageError::Storage(format!(
+            "cannot presign {url}: {}",
+            String::from_utf8_lossy(&output.stderr).trim()
+        )));
+    }
+    Ok(PathBuf::from(
+        String::from_utf8_lossy(&output.stdout).trim().to_string(),
+    ))
+}
+
+/// Stream object `url` from what `write` produces on the stdout it is
+/// given (a pipe into `aws s3 cp`, which uploads it in parts). The
+/// `expected_size` upper bound lets the CLI pick large enough parts; the
+/// upload is abandoned once `cancel` is cancelled.
+pub fn upload(
+    url: &S3Url,
+    expected_size: u64,
+    cancel: &CancelToken,
+    write: impl FnOnce(Stdio) -> Result<()>,
+) -> Result<()> {
+    let mut child = Command::new("aws")
+        .args(["s3", "cp", "-", &url.to_string(), "--only-show-errors"])
+        .arg("--expected-size")
+        .arg(expected_size.to_string())
+        .stdin(Stdio::piped())
+        .stdout(Stdio::null())
+        .stderr(Stdio::piped())
+        .spawn()
+        .map_err(not_found)?;
+    let stdin = child.stdin.take().expect("stdin is piped");
+    let mut stderr = child.stderr.take().expect("stderr is piped");
+    let reader = std::thread::spawn(move || {
+        let mut buf = String::new();
+        let _ = stderr.read_to_string(&mut buf);
+        buf
+    });
+
+    // An unfinished multipart upload never becomes an object
+    if let Err(e) = write(Stdio::from(stdin)) {
+        let _ = child.kill();
+        let _ = child.wait();
+        return Err(e);
+    }
+    let status = loop {
+        if let Some(status) = child.try_wait()? {
+            break status;
+        }
+        if cancel.is_cancelled() {
+            let _ = child.kill();
+            let _ = child.wait();
+            return Err(VstorageError::Cancelled);
+        }
+        std::thread::sleep(Duration::from_millis(50));
+    };
+    if !status.success() {
+        let stderr = reader.join().unwrap_or_default();
+        return Err(VstorageError::Storage(format!(
+            "upload to {url} failed ({status}): {}",
+            stderr.trim()
+        )));
+    }
+    Ok(())
+}
+
+fn not_found(e: std::io::Error) -> VstorageError {
+    match e.kind() {
+        std::io::ErrorKind::NotFound => VstorageError::Storage(
+            "aws CLI not found — install it to read or write s3:// paths".into(),
+        ),
+        _ => VstorageError::Storage(format!("failed to run aws: {e}")),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_s3_url() {
+        let url = S3Url::parse(Path::new("s3://backups/videos/out.mp4")).unwrap();
+        assert_eq!(url.bucket, "backups");
+        assert_eq!(url.key, "videos/out.mp4");
+        assert_eq!(url.file_name(), "out.mp4");
+        assert_eq!(url.to_string(), "s3://backups/videos/out.mp4");
+        assert!(S3Url::parse(Path::new("s3://backups")).is_none());
+        assert!(S3Url::parse(Path::new("s3:///key")).is_none());
+        assert!(S3Url::parse(Path::new("/tmp/out.mp4")).is_none());
+        assert_eq!(
+            readable(Path::new("/tmp/out.mp4")).unwrap(),
+            Path::new("/tmp/out.mp4")
+        );
+    }
+}
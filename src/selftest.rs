@@ -0,0 +1,139 @@
+use std::fmt;
+
+use crate::cancel::CancelToken;
+use crate::capacity::human_bytes;
+use crate::config::FrameConfig;
+use crate::decode::{self, DecodeOptions, EccStats};
+use crate::encode::{self, SplitLimits};
+use crate::error::{Result, VstorageError};
+use crate::frame::{self, LevelNoise};
+use crate::progress::NoProgress;
+use crate::video;
+
+/// Outcome of [`selftest`].
+#[derive(Debug, Clone)]
+pub struct SelfTestReport {
+    pub config: FrameConfig,
+    pub size: usize,
+    /// Frames the payload was encoded into (0 if encoding failed)
+    pub frames: usize,
+    /// ECC statistics of the decode, or why the round trip failed
+    pub result: std::result::Result<EccStats, String>,
+    /// Pixel noise in the first frame read back from the video
+    pub noise: Option<LevelNoise>,
+}
+
+impl SelfTestReport {
+    pub fn passed(&self) -> bool {
+        self.result.is_ok()
+    }
+}
+
+impl fmt::Display for SelfTestReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let c = &self.config;
+        writeln!(
+            f,
+            "Settings: block={}, levels={}, ecc={}, {} {} CRF {}",
+            c.block_size,
+            c.levels,
+            c.ecc_len,
+            c.codec,
+            c.pix_fmt(),
+            c.crf
+        )?;
+        match &self.result {
+            Ok(stats) => {
+                writeln!(
+                    f,
+                    "Self-test: PASS — {} round-tripped through {} frames",
+                    human_bytes(self.size as u64),
+                    self.frames
+                )?;
+                write!(f, "{stats}")?;
+            }
+            Err(reason) => write!(f, "Self-test: FAIL — {reason}")?,
+        }
+        if let Some(noise) = &self.noise {
+            write!(
+                f,
+                "\nPixel noise: mean {:.1}, worst {} of {:.0} allowed — level margin {:.0}%",
+                noise.mean,
+                noise.max,
+                noise.threshold,
+                noise.margin() * 100.0
+            )?;
+        }
+        Ok(())
+    }
+}
+
+/// Encode `size` random bytes with `config`, decode the video back and
+/// compare. A failed round trip is reported, not returned as an error.
+pub fn selftest(config: &FrameConfig, size: usize, cancel: &CancelToken) -> Result<SelfTestReport> {
+    video::check_ffmpeg()?;
+    let temp_dir = video::temp_dir(&config.workspace)?;
+    let dir = temp_dir.path();
+    let (input, video_path, restored, png) = (
+        dir.join("payload.bin"),
+        dir.join("selftest.mp4"),
+        dir.join("restored.bin"),
+        dir.join("frame.png"),
+    );
+    let mut payload = vec![0u8; size.max(1)];
+    rand::fill(&mut payload[..]);
+    std::fs::write(&input, &payload)?;
+
+    let mut report = SelfTestReport {
+        config: config.clone(),
+        size: payload.len(),
+        frames: 0,
+        result: Err(String::new()),
+        noise: None,
+    };
+    let fail = |e: VstorageError| match e {
+        VstorageError::Cancelled => Err(e),
+        e => Ok(e.to_string()),
+    };
+
+    let encoded = encode::encode(
+        &input,
+        &video_path,
+        None,
+        &[],
+        config,
+        None,
+        &[],
+        None,
+        &SplitLimits::default(),
+        false,
+        &NoProgress,
+        cancel,
+    );
+    match encoded {
+        Ok(summary) => report.frames = summary.frames,
+        Err(e) => {
+            report.result = Err(format!("encode failed: {}", fail(e)?));
+            return Ok(report);
+        }
+    }
+
+    video::extract_frame(&video_path, 0, &png, cancel)?;
+    report.noise = Some(frame::level_noise(&video::load_frame(&png)?, config));
+
+    report.result = match decode::decode(
+        &[video_path.as_path()],
+        &restored,
+        None,
+        &DecodeOptions::default(),
+        &NoProgress,
+        cancel,
+    ) {
+        Ok(_) if std::fs::read(&restored)? != payload => {
+            Err("decoded file differs from the payload".into())
+        }
+        Ok(summary) => Ok(summary.stats),
+        Err(e) => Err(format!("decode failed: {}", fail(e)?)),
+    };
+    Ok(report)
+}
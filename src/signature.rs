@@ -0,0 +1,198 @@
+use std::fmt;
+use std::str::FromStr;
+
+use ed25519_dalek::{Signature, Signer};
+use sha2::{Digest, Sha256};
+use zeroize::Zeroize;
+
+use crate::error::{Result, VstorageError};
+use crate::pieces::PIECE_NONCE_BASE;
+use crate::recipient::{parse_hex, to_hex};
+
+pub const SIGNATURE_MAGIC: &[u8; 4] = b"VSIG";
+
+/// The signature frame of an encrypted payload is encrypted under this frame
+/// number, just below the piece frames' range.
+pub const SIGNATURE_NONCE: u32 = PIECE_NONCE_BASE - 1;
+
+const VERIFY_PREFIX: &str = "vssig1";
+const SECRET_PREFIX: &str = "VSTORAGE-SIGNING-KEY-";
+
+/// Magic | signer's public key | Ed25519 signature.
+const SIGNATURE_LEN: usize = 4 + 32 + 64;
+
+/// An Ed25519 public key that checks a payload's signature, written `vssig1`
+/// followed by 64 hex digits.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct VerifyKey {
+    pub public: [u8; 32],
+}
+
+impl FromStr for VerifyKey {
+    type Err = VstorageError;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let public = s
+            .trim()
+            .strip_prefix(VERIFY_PREFIX)
+            .and_then(parse_hex)
+            .ok_or_else(|| {
+                VstorageError::Config(format!(
+                    "invalid verify key '{s}' — expected {VERIFY_PREFIX} and 64 hex digits"
+                ))
+            })?;
+        Ok(Self { public })
+    }
+}
+
+impl fmt::Display for VerifyKey {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{VERIFY_PREFIX}{}", to_hex(&self.public))
+    }
+}
+
+/// The private half of a [`VerifyKey`]. Key files hold it on a line
+/// `VSTORAGE-SIGNING-KEY-` and 64 hex digits; `#` lines are comments.
+#[derive(Clone)]
+pub struct SigningKey {
+    seed: [u8; 32],
+}
+
+impl SigningKey {
+    pub fn generate() -> Self {
+        let mut seed = [0u8; 32];
+        rand::fill(&mut seed);
+        Self { seed }
+    }
+
+    pub fn verify_key(&self) -> VerifyKey {
+        VerifyKey {
+            public: self.dalek().verifying_key().to_bytes(),
+        }
+    }
+
+    /// The key expanded for signing; it wipes itself when dropped.
+    fn dalek(&self) -> ed25519_dalek::SigningKey {
+        ed25519_dalek::SigningKey::from_bytes(&self.seed)
+    }
+
+    /// Read a signing key file's contents.
+    pub fn parse(text: &str) -> Result<Self> {
+        text.lines()
+            .map(str::trim)
+            .filter(|l| !l.is_empty() && !l.starts_with('#'))
+            .find_map(|l| l.strip_prefix(SECRET_PREFIX))
+            .and_then(parse_hex)
+            .map(|seed| Self { seed })
+            .ok_or_else(|| VstorageError::Config("no signing key found in the key file".into()))
+    }
+
+    /// Contents of a key file, with the verify key as a comment.
+    pub fn to_file(&self) -> String {
+        format!(
+            "# verify key: {}\n{SECRET_PREFIX}{}\n",
+            self.verify_key(),
+            to_hex(&self.seed).to_uppercase()
+        )
+    }
+}
+
+impl Drop for SigningKey {
+    fn drop(&mut self) {
+        self.seed.zeroize();
+    }
+}
+
+impl fmt::Debug for SigningKey {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "SigningKey({})", self.verify_key())
+    }
+}
+
+/// Contents of the signature frame for `data`: the signer's public key and
+/// its signature over the SHA-256 of `data`.
+pub fn sign(key: &SigningKey, data: &[u8]) -> Vec<u8> {
+    let mut buf = Vec::with_capacity(SIGNATURE_LEN);
+    buf.extend_from_slice(SIGNATURE_MAGIC);
+    buf.extend_from_slice(&key.verify_key().public);
+    buf.extend_from_slice(&key.dalek().sign(&message(data)).to_bytes());
+    buf
+}
+
+/// The key that made a signature frame.
+pub fn signer(frame: &[u8]) -> Result<VerifyKey> {
+    if frame.len() != SIGNATURE_LEN || &frame[..4] != SIGNATURE_MAGIC {
+        return Err(VstorageError::Signature(
+            "the signature frame is corrupt".into(),
+        ));
+    }
+    Ok(VerifyKey {
+        public: frame[4..36].try_into().unwrap(),
+    })
+}
+
+/// Check that `frame` is `key`'s signature of `data`.
+pub fn verify(frame: &[u8], data: &[u8], key: &VerifyKey) -> Result<()> {
+    let signer = signer(frame)?;
+    if signer != *key {
+        return Err(VstorageError::Signature(format!(
+            "signed by {signer}, not by {key}"
+        )));
+    }
+    let sig = Signature::from_bytes(frame[36..].try_into().unwrap());
+    let valid = ed25519_dalek::VerifyingKey::from_bytes(&key.public)
+        .and_then(|public| public.verify_strict(&message(data), &sig))
+        .is_ok();
+    if !valid {
+        return Err(VstorageError::Signature(
+            "the signature does not match the file".into(),
+        ));
+    }
+    Ok(())
+}
+
+fn message(data: &[u8]) -> Vec<u8> {
+    [&b"vstorage-signature"[..], &Sha256::digest(data)].concat()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sign_and_verify() {
+        let key = SigningKey::generate();
+        let parsed = SigningKey::parse(&key.to_file()).unwrap();
+        let verify_key = parsed.verify_key();
+        assert_eq!(verify_key, key.verify_key());
+        assert_eq!(
+            verify_key.to_string().parse::<VerifyKey>().unwrap(),
+            verify_key
+        );
+
+        let frame = sign(&key, b"the file");
+        verify(&frame, b"the file", &verify_key).unwrap();
+        assert!(matches!(
+            verify(&frame, b"another file", &verify_key),
+            Err(VstorageError::Signature(_))
+        ));
+        let other = SigningKey::generate().verify_key();
+        assert!(verify(&frame, b"the file", &other)
+            .unwrap_err()
+            .to_string()
+            .contains("signed by vssig1"));
+    }
+
+    #[test]
+    fn test_rfc8032_key() {
+        // Key files written before keep their verify keys
+        let key = SigningKey::parse(
+            "VSTORAGE-SIGNING-KEY-9D61B19DEFFD5A60BA844AF492EC2CC44449C5697B326919703BAC031CAE7F60",
+        )
+        .unwrap();
+        assert_eq!(
+            key.verify_key().to_string(),
+            "vssig1d75a980182b10ab7d54bfed3c964073a0ee172f3daa62325af021a68f707511a"
+        );
+    }
+}
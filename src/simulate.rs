@@ -0,0 +1,238 @@
+use std::fmt;
+
+use crate::cancel::CancelToken;
+use crate::config::{FrameConfig, VideoCodec};
+use crate::decode::{self, DecodeOptions, EccStats};
+use crate::encode::{self, SplitLimits};
+use crate::error::{Result, VstorageError};
+use crate::progress::NoProgress;
+use crate::video;
+
+/// Symbol errors seen when decoding one re-encoded variant.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SymbolErrors {
+    /// RS symbols read (255 per block)
+    pub symbols: usize,
+    pub stats: EccStats,
+}
+
+impl SymbolErrors {
+    pub fn rate(&self) -> f64 {
+        self.stats.corrected as f64 / self.symbols.max(1) as f64
+    }
+}
+
+/// One CRF / chroma setting of the sweep.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SimulationRow {
+    pub crf: u8,
+    pub yuv420p: bool,
+    /// Errors corrected, or why the variant did not decode
+    pub result: std::result::Result<SymbolErrors, String>,
+}
+
+/// Result of [`simulate`], one row per re-encoded variant.
+#[derive(Debug, Clone)]
+pub struct SimulationReport {
+    pub config: FrameConfig,
+    pub rows: Vec<SimulationRow>,
+}
+
+impl fmt::Display for SimulationReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let c = &self.config;
+        writeln!(
+            f,
+            "Settings: block={}, levels={}, ecc={}, {} (RS corrects {} symbols per block)",
+            c.block_size,
+            c.levels,
+            c.ecc_len,
+            c.codec,
+            c.ecc_len / 2
+        )?;
+        write!(f, "CRF  Chroma  Symbol errors  Worst block  Margin")?;
+        for row in &self.rows {
+            let chroma = if row.yuv420p { "4:2:0" } else { "4:4:4" };
+            write!(f, "\n{:<4} {chroma:<7} ", row.crf)?;
+            match &row.result {
+                Ok(errors) => {
+                    let stats = &errors.stats;
+                    let worst = stats.worst_block.map_or(0, |(_, w)| w);
+                    write!(
+                        f,
+                        "{:<14} {:<12} {}",
+                        format!("{:.4}%", errors.rate() * 100.0),
+                        format!("{worst}/{}", stats.per_block_capacity),
+                        stats.margin()
+                    )?;
+                }
+                Err(reason) => write!(f, "FAIL: {reason}")?,
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Encode `size` random bytes losslessly with `config`, re-encode the video
+/// at each CRF in `crfs` (also as 4:2:0 when `yuv420p` is set), decode each
+/// variant and record its symbol errors.
+pub fn simulate(
+    config: &FrameConfig,
+    crfs: &[u8],
+    yuv420p: bool,
+    size: usize,
+    cancel: &CancelToken,
+) -> Result<SimulationReport> {
+    video::check_ffmpeg()?;
+    let temp_dir = video::temp_dir(&config.workspace)?;
+    let dir = temp_dir.path();
+    let (input, master) = (dir.join("payload.bin"), dir.join("master.mp4"));
+    let mut payload = vec![0u8; size.max(1)];
+    rand::fill(&mut payload[..]);
+    std::fs::write(&input, &payload)?;
+
+    let mut lossless = config.clone();
+    lossless.codec = VideoCodec::H264;
+    lossless.crf = 0;
+    lossless.target_bitrate = None;
+    lossless.ffmpeg_args.clear();
+    encode::encode(
+        &input,
+        &master,
+        None,
+        &[],
+        &lossless,
+        None,
+        &[],
+        None,
+        &SplitLimits::default(),
+        false,
+        &NoProgress,
+        cancel,
+    )?;
+
+    let mut chroma = vec![config.yuv420p];
+    if yuv420p && !config.yuv420p {
+        chroma.push(true);
+    }
+    let mut rows = Vec::new();
+    for &crf in crfs {
+        for &subsample in &chroma {
+            cancel.check()?;
+            tracing::info!(
+                "Simulating CRF {crf}{}...",
+                if subsample { " yuv420p" } else { "" }
+            );
+            let mut variant = config.clone();
+            variant.crf = crf;
+            variant.target_bitrate = None;
+            let result = match (subsample && !variant.yuv420p)
+                .then(|| variant.enable_yuv420p())
+                .transpose()
+            {
+                Err(e) => Err(e.to_string()),
+                Ok(_) => decode_variant(&master, &variant, dir, &payload, cancel)?,
+            };
+            rows.push(SimulationRow {
+                crf,
+                yuv420p: subsample,
+                result,
+            });
+        }
+    }
+    Ok(SimulationReport {
+        config: config.clone(),
+        rows,
+    })
+}
+
+/// Re-encode `master` with `variant` and decode it; decode failures are
+/// returned as the row's reason rather than as errors.
+fn decode_variant(
+    master: &std::path::Path,
+    variant: &FrameConfig,
+    dir: &std::path::Path,
+    payload: &[u8],
+    cancel: &CancelToken,
+) -> Result<std::result::Result<SymbolErrors, String>> {
+    let video_path = dir.join("variant.mp4");
+    video::reencode(master, &video_path, variant, cancel)?;
+    decode_probe(&video_path, dir, payload, cancel)
+}
+
+/// Decode `video_path`, which should hold `payload`, and count its symbol
+/// errors; decode failures are returned as the reason rather than as errors.
+pub(crate) fn decode_probe(
+    video_path: &std::path::Path,
+    dir: &std::path::Path,
+    payload: &[u8],
+    cancel: &CancelToken,
+) -> Result<std::result::Result<SymbolErrors, String>> {
+    let restored = dir.join("restored.bin");
+    let decoded = decode::decode(
+        &[video_path],
+        &restored,
+        None,
+        &DecodeOptions::default(),
+        &NoProgress,
+        cancel,
+    );
+    Ok(match decoded {
+        Ok(_) if std::fs::read(&restored)? != payload => {
+            Err("decoded file differs from the payload".into())
+        }
+        Ok(summary) => Ok(SymbolErrors {
+            symbols: summary
+                .checks
+                .iter()
+                .flatten()
+                .map(|c| c.blocks * 255)
+                .sum(),
+            stats: summary.stats,
+        }),
+        Err(VstorageError::Cancelled) => return Err(VstorageError::Cancelled),
+        Err(VstorageError::Uncorrectable(blocks)) => {
+            Err(format!("{} RS blocks uncorrectable", blocks.len()))
+        }
+        Err(e) => Err(e.to_string()),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_report_table() {
+        let stats = EccStats {
+            per_block_capacity: 32,
+            frames: 2,
+            corrected: 51,
+            worst_block: Some((1, 7)),
+        };
+        let report = SimulationReport {
+            config: FrameConfig::new(8, 2, 64, 30, 18).unwrap(),
+            rows: vec![
+                SimulationRow {
+                    crf: 18,
+                    yuv420p: false,
+                    result: Ok(SymbolErrors {
+                        symbols: 51_000,
+                        stats,
+                    }),
+                },
+                SimulationRow {
+                    crf: 40,
+                    yuv420p: true,
+                    result: Err("3 RS blocks uncorrectable".into()),
+                },
+            ],
+        };
+        let text = report.to_string();
+        assert!(
+            text.contains("\n18   4:4:4   0.1000%        7/32         25"),
+            "{text}"
+        );
+        assert!(text.ends_with("\n40   4:2:0   FAIL: 3 RS blocks uncorrectable"));
+    }
+}
@@ -0,0 +1,193 @@
+use std::fmt;
+use std::path::Path;
+
+use crate::capacity::human_bytes;
+use crate::config::{FrameConfig, Workspace};
+use crate::error::Result;
+use crate::header::STREAMED_SIZE;
+use crate::info;
+
+/// Density and overhead of a video, from [`stats`].
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct VideoStats {
+    pub config: FrameConfig,
+    pub encrypted: bool,
+    /// Bytes stored (compressed, if the payload is); `None` for a streamed
+    /// video, whose size is only in its last data frame
+    pub payload_bytes: Option<u64>,
+    /// Data frames of the payload; `None` for a streamed video
+    pub data_frames: Option<u64>,
+    /// Frames in the video, data or not
+    pub video_frames: u64,
+    pub fps: u32,
+    /// Size of the video file; `None` if it cannot be read (an S3 object)
+    pub video_bytes: Option<u64>,
+}
+
+impl VideoStats {
+    /// Bytes a frame would hold if every block carried data.
+    fn frame_bytes(&self) -> f64 {
+        let blocks = self.config.logical_width() * self.config.logical_height();
+        (blocks * self.config.bits_per_pixel() as usize) as f64 / 8.0
+    }
+
+    /// Share of the frame taken by the header rows, CRC map, banner,
+    /// barcode and pilots.
+    pub fn header_overhead(&self) -> f64 {
+        1.0 - self.config.data_area_bytes() as f64 / self.frame_bytes()
+    }
+
+    /// Share of the data area taken by Reed-Solomon parity (and the
+    /// remainder too small for a block).
+    pub fn ecc_overhead(&self) -> f64 {
+        let area = self.config.data_area_bytes().max(1) as f64;
+        1.0 - self.config.max_raw_per_frame() as f64 / area
+    }
+
+    /// File bits per video pixel of a full data frame.
+    pub fn capacity_bits_per_pixel(&self) -> f64 {
+        let pixels = self.config.width as f64 * self.config.height as f64;
+        self.config.max_plain_per_frame(self.encrypted) as f64 * 8.0 / pixels
+    }
+
+    /// Payload bits per pixel over every frame of the video.
+    pub fn effective_bits_per_pixel(&self) -> Option<f64> {
+        let pixels = self.config.width as f64 * self.config.height as f64;
+        Some(self.payload_bytes? as f64 * 8.0 / (pixels * self.video_frames.max(1) as f64))
+    }
+
+    /// Video file bytes per payload byte.
+    pub fn container_ratio(&self) -> Option<f64> {
+        Some(self.video_bytes? as f64 / self.payload_bytes?.max(1) as f64)
+    }
+
+    /// Payload bytes per minute of video.
+    pub fn bytes_per_minute(&self) -> Option<f64> {
+        let minutes = self.video_frames.max(1) as f64 / self.fps.max(1) as f64 / 60.0;
+        Some(self.payload_bytes? as f64 / minutes)
+    }
+}
+
+impl fmt::Display for VideoStats {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let c = &self.config;
+        writeln!(
+            f,
+            "Frame:             {}x{}, {} bits per {bs}x{bs} block",
+            c.width,
+            c.height,
+            c.bits_per_pixel(),
+            bs = c.block_size
+        )?;
+        writeln!(
+            f,
+            "Capacity:          {} bytes per data frame, {:.3} bits per pixel",
+            c.max_plain_per_frame(self.encrypted),
+            self.capacity_bits_per_pixel()
+        )?;
+        writeln!(
+            f,
+            "Header overhead:   {:.1}% of the frame",
+            self.header_overhead() * 100.0
+        )?;
+        writeln!(
+            f,
+            "ECC overhead:      {:.1}% of the data area (RS(255,{}))",
+            self.ecc_overhead() * 100.0,
+            c.rs_data_len()
+        )?;
+        if let Some(data) = self.data_frames {
+            writeln!(
+                f,
+                "Other frames:      {} of {} (pieces, keys, index, intro)",
+                self.video_frames.saturating_sub(data),
+                self.video_frames
+            )?;
+        }
+        match (self.payload_bytes, self.effective_bits_per_pixel()) {
+            (Some(bytes), Some(bpp)) => writeln!(
+                f,
+                "Effective density: {bpp:.3} bits per pixel ({} over {} frames)",
+                human_bytes(bytes),
+                self.video_frames
+            )?,
+            _ => writeln!(f, "Effective density: unknown (streamed)")?,
+        }
+        if let (Some(video), Some(ratio)) = (self.video_bytes, self.container_ratio()) {
+            writeln!(
+                f,
+                "Container:         {} of video, {ratio:.2}x the payload",
+                human_bytes(video)
+            )?;
+        }
+        match self.bytes_per_minute() {
+            Some(rate) => write!(
+                f,
+                "Per minute:        {} of payload ({} fps)",
+                human_bytes(rate as u64),
+                self.fps
+            ),
+            None => write!(f, "Per minute:        unknown (streamed)"),
+        }
+    }
+}
+
+/// Work out the density and overheads of `input` from the header of its
+/// first frame (extracted in `workspace`) and the size of the file, without
+/// decoding the rest.
+pub fn stats(input: &Path, workspace: &Workspace) -> Result<VideoStats> {
+    let info = info::info(input, workspace)?;
+    let h = &info.header;
+    let streamed = h.streamed && h.file_size == STREAMED_SIZE;
+    let video_frames = info
+        .stream
+        .as_ref()
+        .map_or(h.total_frames as u64, |s| s.frames as u64);
+    Ok(VideoStats {
+        encrypted: info.encrypted(),
+        payload_bytes: (!streamed).then_some(h.file_size),
+        data_frames: (!streamed).then_some(h.total_frames as u64),
+        video_frames,
+        fps: info.stream.as_ref().map_or(info.config.fps, |s| s.fps),
+        video_bytes: std::fs::metadata(input).ok().map(|m| m.len()),
+        config: info.config,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_overheads() {
+        let config = FrameConfig::new(2, 4, 32, 30, 18).unwrap();
+        let per_frame = config.max_plain_per_frame(false) as u64;
+        let mut stats = VideoStats {
+            config,
+            encrypted: false,
+            payload_bytes: Some(per_frame * 59),
+            data_frames: Some(59),
+            video_frames: 60,
+            fps: 30,
+            video_bytes: Some(per_frame * 120),
+        };
+        let expected_ecc =
+            1.0 - stats.config.max_raw_per_frame() as f64 / stats.config.data_area_bytes() as f64;
+        assert!((stats.ecc_overhead() - expected_ecc).abs() < 1e-9);
+        assert!(stats.ecc_overhead() >= 32.0 / 255.0);
+        assert!(stats.header_overhead() > 0.0 && stats.header_overhead() < 0.2);
+        assert!(stats.effective_bits_per_pixel().unwrap() < stats.capacity_bits_per_pixel());
+        // Two seconds of video, so a minute holds thirty times the payload
+        let rate = stats.bytes_per_minute().unwrap();
+        assert!((rate - (per_frame * 59 * 30) as f64).abs() < 1.0);
+        assert!((stats.container_ratio().unwrap() - 120.0 / 59.0).abs() < 1e-9);
+        assert!(stats.to_string().contains("Other frames:      1 of 60"));
+
+        stats.payload_bytes = None;
+        stats.data_frames = None;
+        let text = stats.to_string();
+        assert!(text.contains("Effective density: unknown (streamed)"));
+        assert!(!text.contains("Container:"));
+    }
+}
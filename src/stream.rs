@@ -0,0 +1,152 @@
+use std::io::Read;
+use std::path::{Path, PathBuf};
+
+use image::RgbImage;
+
+use crate::cancel::CancelToken;
+use crate::config::FrameConfig;
+use crate::encode::{self, encode_segment, segment_path, work_dir_for, EncodeSummary};
+use crate::error::{Result, VstorageError};
+use crate::memory::{self, StreamPlan};
+use crate::progress::{ProgressSink, Stage, Tracker};
+use crate::recipient::{self, Recipient};
+use crate::{crypto, video};
+
+/// Encode everything `reader` yields (e.g. stdin fed by `pg_dump`) into
+/// `output_path` without knowing its length first or keeping it in memory:
+/// each frame is drawn as soon as its chunk is read, and the last data frame
+/// records the file's size and frame count (see [`StreamPlan`]).
+///
+/// Frame images go to the work dir and are handed to ffmpeg a segment at a
+/// time, as [`encode::encode`] does, but the encode cannot be resumed,
+/// split, compressed, signed or padded, since each needs the whole file
+/// first (see [`memory::check_streamable`]). Such a video can only be
+/// decoded in full.
+pub fn encode_stream(
+    reader: &mut dyn Read,
+    output_path: &Path,
+    password: Option<&str>,
+    recipients: &[Recipient],
+    config: &FrameConfig,
+    progress: &dyn ProgressSink,
+    cancel: &CancelToken,
+) -> Result<EncodeSummary> {
+    video::check_ffmpeg()?;
+    memory::check_streamable(config, &[], None)?;
+    let (cipher, nonce, salt, keys) = match (password, recipients) {
+        (Some(_), [_, ..]) => {
+            return Err(VstorageError::Config(
+                "give a password or recipients, not both".into(),
+            ));
+        }
+        (None, [_, ..]) => {
+            let (cipher, nonce, keys) = recipient::seal(recipients)?;
+            tracing::info!(
+                "Encrypting frames with AES-256-GCM to {} recipient(s)",
+                recipients.len()
+            );
+            (Some(cipher), nonce, [0u8; 16], keys)
+        }
+        (Some(pw), []) => {
+            let pb = Tracker::spinner(
+                progress,
+                Stage::DeriveKey,
+                format!("Deriving key ({})...", config.kdf),
+            );
+            let (cipher, nonce, salt) = crypto::FrameCipher::generate(
+                pw,
+                config.kdf,
+                config.token,
+                config.hardware_token(),
+            )?;
+            pb.finish_with_message("Encrypting frames with AES-256-GCM");
+            (Some(cipher), nonce, salt, Vec::new())
+        }
+        (None, []) => {
+            tracing::info!("No password — skipping encryption");
+            (None, [0u8; 12], [0u8; 16], Vec::new())
+        }
+    };
+    let encrypted = cipher.is_some();
+    let plan = StreamPlan::new(config, cipher, nonce, salt, keys)?;
+    tracing::info!(
+        "Encoding a stream of unknown length ({} bytes/frame, RS({},{}), ecc={})",
+        plan.per_frame,
+        config.rs_data_len() + config.ecc_len as usize,
+        config.rs_data_len(),
+        config.ecc_len
+    );
+
+    let work_dir = work_dir_for(output_path, &config.workspace);
+    if work_dir.exists() {
+        std::fs::remove_dir_all(&work_dir)?;
+    }
+    let frames_dir = work_dir.join("frames");
+    std::fs::create_dir_all(&frames_dir)?;
+
+    let mut pb = Tracker::open_frames(progress, Stage::EncodeFrames);
+    let segment_frames = encode::segment_frames(config)?;
+    let (mut position, mut segments_done) = (0, 0);
+    let (file_size, sha256) = {
+        let mut emit = |img: RgbImage, bytes: usize| -> Result<()> {
+            cancel.check()?;
+            // A full segment goes to ffmpeg when the next frame arrives,
+            // leaving the last one, full or not, for after the stream ends
+            if position > 0 && position % segment_frames == 0 {
+                encode_segment(
+                    &work_dir,
+                    segments_done,
+                    segment_frames,
+                    position,
+                    config,
+                    cancel,
+                )?;
+                segments_done += 1;
+            }
+            let path = video::frame_path(&frames_dir, position + 1, config.workspace.intermediate);
+            video::save_frame(&img, &path)?;
+            position += 1;
+            pb.inc(bytes as u64);
+            Ok(())
+        };
+        let result = plan.encode(reader, &mut emit);
+        if result.is_ok() {
+            encode_segment(
+                &work_dir,
+                segments_done,
+                segment_frames,
+                position,
+                config,
+                cancel,
+            )?;
+            segments_done += 1;
+        }
+        result?
+    };
+    pb.finish_with_message(format!("{position} frames encoded"));
+    tracing::info!("Read {file_size} bytes from the stream");
+
+    let pb = Tracker::spinner(
+        progress,
+        Stage::Mux,
+        format!("FFmpeg: producing {}...", output_path.display()),
+    );
+    let segments: Vec<PathBuf> = (0..segments_done)
+        .map(|s| segment_path(&work_dir, s))
+        .collect();
+    if let Err(e) = video::concat_segments(&segments, output_path, config, position, None, cancel) {
+        let _ = std::fs::remove_file(output_path);
+        return Err(e);
+    }
+    pb.finish_with_message(format!("Wrote {}", output_path.display()));
+    video::remove_work_dir(&work_dir, &config.workspace)?;
+
+    Ok(EncodeSummary {
+        config: config.clone(),
+        file_size,
+        sha256,
+        frames: position,
+        encrypted,
+        outputs: vec![output_path.to_path_buf()],
+    })
+}
@@ -0,0 +1,288 @@
+use std::collections::VecDeque;
+use std::future::Future;
+use std::panic::{catch_unwind, AssertUnwindSafe};
+use std::pin::Pin;
+use std::sync::{Arc, Mutex, MutexGuard};
+use std::task::{Context, Poll, Waker};
+
+use crate::cancel::CancelToken;
+use crate::error::{Result, VstorageError};
+
+struct Slot<T> {
+    result: Option<Result<T>>,
+    waker: Option<Waker>,
+}
+
+/// Store the `result` of a task and wake whoever awaits it.
+fn complete<T>(slot: &Mutex<Slot<T>>, result: Result<T>) {
+    let mut slot = slot.lock().unwrap_or_else(|e| e.into_inner());
+    slot.result = Some(result);
+    if let Some(waker) = slot.waker.take() {
+        waker.wake();
+    }
+}
+
+/// Encode, decode or other blocking work running on its own thread, awaitable
+/// from any async runtime without blocking it. Dropping the task cancels it.
+pub struct Task<T> {
+    slot: Arc<Mutex<Slot<T>>>,
+    cancel: CancelToken,
+    limiter: Limiter,
+}
+
+impl<T> Task<T> {
+    /// Stop the work at its next cancellation point, or drop it if it is
+    /// still waiting for a slot; the task then resolves to `Err(Cancelled)`.
+    pub fn cancel(&self) {
+        self.cancel.cancel();
+        self.limiter.drop_cancelled();
+    }
+}
+
+impl<T> Future for Task<T> {
+    type Output = Result<T>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Result<T>> {
+        let mut slot = self.slot.lock().unwrap_or_else(|e| e.into_inner());
+        match slot.result.take() {
+            Some(result) => Poll::Ready(result),
+            None => {
+                slot.waker = Some(cx.waker().clone());
+                Poll::Pending
+            }
+        }
+    }
+}
+
+impl<T> Drop for Task<T> {
+    fn drop(&mut self) {
+        self.cancel();
+    }
+}
+
+/// Run `work` on a new thread. It gets the task's cancel token, to pass on to
+/// [`crate::encode::encode`] or [`crate::decode::decode`].
+pub fn spawn<T, F>(work: F) -> Task<T>
+where
+    T: Send + 'static,
+    F: FnOnce(&CancelToken) -> Result<T> + Send + 'static,
+{
+    Limiter::new(usize::MAX).spawn(work)
+}
+
+/// Work waiting for a slot: started with a [`Permit`], or resolved as
+/// cancelled with `None`.
+type Job = Box<dyn FnOnce(Option<Permit>) + Send>;
+
+struct Queued {
+    cancel: CancelToken,
+    job: Job,
+}
+
+#[derive(Default)]
+struct Slots {
+    running: usize,
+    queue: VecDeque<Queued>,
+}
+
+/// Caps how many tasks run at once (each encode or decode runs ffmpeg and
+/// holds frames in memory); tasks over the limit wait in a queue, without a
+/// thread, until a slot frees.
+#[derive(Clone)]
+pub struct Limiter(Arc<(Mutex<Slots>, usize)>);
+
+impl Limiter {
+    pub fn new(max_running: usize) -> Self {
+        Self(Arc::new((Mutex::default(), max_running.max(1))))
+    }
+
+    /// Like [`spawn`], starting `work` once fewer than the limit are running.
+    pub fn spawn<T, F>(&self, work: F) -> Task<T>
+    where
+        T: Send + 'static,
+        F: FnOnce(&CancelToken) -> Result<T> + Send + 'static,
+    {
+        let slot = Arc::new(Mutex::new(Slot {
+            result: None,
+            waker: None,
+        }));
+        let cancel = CancelToken::new();
+        let (job_slot, token) = (slot.clone(), cancel.clone());
+        let job: Job = Box::new(move |permit| match permit {
+            Some(permit) => {
+                std::thread::spawn(move || {
+                    let result =
+                        catch_unwind(AssertUnwindSafe(|| work(&token))).unwrap_or_else(|_| {
+                            Err(std::io::Error::other("background task panicked").into())
+                        });
+                    drop(permit);
+                    complete(&job_slot, result);
+                });
+            }
+            None => complete(&job_slot, Err(VstorageError::Cancelled)),
+        });
+
+        let mut slots = self.slots();
+        if slots.running < self.0 .1 {
+            slots.running += 1;
+            drop(slots);
+            job(Some(Permit(self.clone())));
+        } else {
+            slots.queue.push_back(Queued {
+                cancel: cancel.clone(),
+                job,
+            });
+        }
+        Task {
+            slot,
+            cancel,
+            limiter: self.clone(),
+        }
+    }
+
+    /// Tasks currently running under this limiter.
+    pub fn running(&self) -> usize {
+        self.slots().running
+    }
+
+    /// Tasks waiting for a slot.
+    pub fn queued(&self) -> usize {
+        self.slots().queue.len()
+    }
+
+    fn slots(&self) -> MutexGuard<'_, Slots> {
+        self.0 .0.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    /// Resolve the queued tasks that were cancelled.
+    fn drop_cancelled(&self) {
+        let mut slots = self.slots();
+        let (cancelled, waiting) = std::mem::take(&mut slots.queue)
+            .into_iter()
+            .partition(|q: &Queued| q.cancel.is_cancelled());
+        slots.queue = waiting;
+        drop(slots);
+        for queued in cancelled {
+            (queued.job)(None);
+        }
+    }
+}
+
+/// A running task's slot, handed on to the next queued task when dropped.
+struct Permit(Limiter);
+
+impl Drop for Permit {
+    fn drop(&mut self) {
+        let mut slots = self.0.slots();
+        let mut cancelled = Vec::new();
+        let next = loop {
+            match slots.queue.pop_front() {
+                Some(q) if q.cancel.is_cancelled() => cancelled.push(q.job),
+                next => break next,
+            }
+        };
+        if next.is_none() {
+            slots.running -= 1;
+        }
+        drop(slots);
+        for job in cancelled {
+            job(None);
+        }
+        if let Some(next) = next {
+            (next.job)(Some(Permit(self.0.clone())));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::mpsc;
+    use std::task::Wake;
+    use std::time::Duration;
+
+    struct Unpark(std::thread::Thread);
+
+    impl Wake for Unpark {
+        fn wake(self: Arc<Self>) {
+            self.0.unpark();
+        }
+    }
+
+    fn block_on<T>(mut task: Task<T>) -> Result<T> {
+        let waker = Waker::from(Arc::new(Unpark(std::thread::current())));
+        let mut cx = Context::from_waker(&waker);
+        loop {
+            if let Poll::Ready(result) = Pin::new(&mut task).poll(&mut cx) {
+                return result;
+            }
+            std::thread::park();
+        }
+    }
+
+    #[test]
+    fn test_limiter_runs_one_at_a_time() {
+        let limiter = Limiter::new(1);
+        let (started, wait) = mpsc::channel();
+        let (release, gate) = mpsc::channel::<()>();
+        let first = limiter.spawn(move |_| {
+            started.send(()).unwrap();
+            gate.recv().unwrap();
+            Ok(1)
+        });
+        wait.recv().unwrap();
+        let second = limiter.spawn(|cancel: &CancelToken| cancel.check().map(|_| 2));
+        std::thread::sleep(Duration::from_millis(100));
+        assert_eq!(limiter.running(), 1);
+
+        release.send(()).unwrap();
+        assert_eq!(block_on(first).unwrap(), 1);
+        assert_eq!(block_on(second).unwrap(), 2);
+
+        // A task still waiting for a slot resolves to Cancelled
+        let busy = limiter.spawn(|c: &CancelToken| loop {
+            c.check()?;
+            std::thread::sleep(Duration::from_millis(10));
+        } as Result<()>);
+        while limiter.running() == 0 {
+            std::thread::sleep(Duration::from_millis(10));
+        }
+        let queued = limiter.spawn(|_| Ok(()));
+        queued.cancel();
+        assert!(matches!(block_on(queued), Err(VstorageError::Cancelled)));
+        drop(busy);
+    }
+
+    #[cfg(target_os = "linux")]
+    #[test]
+    fn test_queued_tasks_hold_no_thread() {
+        let threads = || std::fs::read_dir("/proc/self/task").unwrap().count();
+        let limiter = Limiter::new(1);
+        let (release, gate) = mpsc::channel::<()>();
+        let first = limiter.spawn(move |_| gate.recv().map_err(|_| VstorageError::Cancelled));
+        let before = threads();
+        let queued: Vec<_> = (0..1000).map(|i| limiter.spawn(move |_| Ok(i))).collect();
+        assert_eq!(limiter.queued(), 1000);
+        // Other tests may start a few threads meanwhile, not a thousand
+        assert!(threads() < before + 100);
+
+        release.send(()).unwrap();
+        block_on(first).unwrap();
+        for (i, task) in queued.into_iter().enumerate() {
+            assert_eq!(block_on(task).unwrap(), i);
+        }
+        assert_eq!((limiter.running(), limiter.queued()), (0, 0));
+    }
+
+    #[test]
+    fn test_cancel_leaves_other_tasks() {
+        // Each task runs an external tool, which only its own token stops
+        let run =
+            |c: &CancelToken| crate::video::run_tool("sleep", &["0.5"], VstorageError::Ffmpeg, c);
+        let (kept, dropped) = (spawn(run), spawn(run));
+        std::thread::sleep(Duration::from_millis(100));
+        dropped.cancel();
+        assert!(matches!(block_on(dropped), Err(VstorageError::Cancelled)));
+        assert!(block_on(kept).is_ok());
+    }
+}
@@ -0,0 +1,6 @@
+//! Fixtures shared by the unit tests.
+
+/// `len` bytes of a repeating pattern, byte `i` being `i * step % modulus`.
+pub(crate) fn pattern(len: usize, step: usize, modulus: usize) -> Vec<u8> {
+    (0..len).map(|i| (i * step % modulus) as u8).collect()
+}
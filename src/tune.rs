@@ -0,0 +1,240 @@
+use crate::cancel::CancelToken;
+use crate::config::{chroma_aware_levels, FrameConfig};
+use crate::encode::{self, SplitLimits};
+use crate::error::{Result, VstorageError};
+use crate::progress::NoProgress;
+use crate::simulate::{self, SymbolErrors};
+
+/// Frames of random data encoded for each probe.
+const PROBE_FRAMES: usize = 2;
+
+/// Block sizes, level counts and ECC lengths the tuner chooses from.
+const BLOCK_SIZES: [u8; 4] = [2, 4, 8, 16];
+const LEVELS: [u8; 4] = [2, 4, 8, 16];
+const ECC_LENS: [u8; 3] = [32, 64, 128];
+
+/// Steps below the requested CRF tried when a layout does not survive it.
+const CRF_STEPS: [u8; 3] = [0, 6, 12];
+
+/// One probe encode of the tuning run.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TuneProbe {
+    pub block_size: u8,
+    pub levels: u8,
+    pub ecc_len: u8,
+    pub crf: u8,
+    /// Errors corrected, or why the probe did not decode
+    pub result: std::result::Result<SymbolErrors, String>,
+}
+
+/// Result of [`auto_tune`].
+#[derive(Debug, Clone)]
+pub struct TuneReport {
+    /// `base` with the chosen block size, levels, ECC length and CRF
+    pub chosen: FrameConfig,
+    /// Probes in the order they ran; the last one was chosen
+    pub probes: Vec<TuneProbe>,
+}
+
+/// Pick the densest settings that survive the target codec: probe payloads
+/// are encoded with each candidate, densest first, and decoded again. A
+/// candidate is taken once its worst RS block used at most `1 - margin` of
+/// the block's correction capacity. The codec, chroma, fps and the rest stay
+/// as in `base`; its CRF is the first one tried, then a few lower ones.
+pub fn auto_tune(base: &FrameConfig, margin: f64, cancel: &CancelToken) -> Result<TuneReport> {
+    if !(0.0..1.0).contains(&margin) {
+        return Err(VstorageError::Config(format!(
+            "--auto-margin must be in 0..1, got {margin}"
+        )));
+    }
+    crate::video::check_ffmpeg()?;
+    let temp_dir = crate::video::temp_dir(&base.workspace)?;
+    let dir = temp_dir.path();
+    let mut probes = Vec::new();
+    for candidate in candidates(base) {
+        cancel.check()?;
+        let result = probe(&candidate, dir, cancel)?;
+        let passed = result.as_ref().is_ok_and(|e| survives(e, margin));
+        tracing::info!(
+            "Probe block={} levels={} ecc={} crf={}: {}",
+            candidate.block_size,
+            candidate.levels,
+            candidate.ecc_len,
+            candidate.crf,
+            match &result {
+                Ok(e) => format!(
+                    "{:.4}% symbol errors, margin {}{}",
+                    e.rate() * 100.0,
+                    e.stats.margin(),
+                    if passed { " — chosen" } else { "" }
+                ),
+                Err(reason) => format!("FAIL: {reason}"),
+            }
+        );
+        probes.push(TuneProbe {
+            block_size: candidate.block_size,
+            levels: candidate.levels,
+            ecc_len: candidate.ecc_len,
+            crf: candidate.crf,
+            result,
+        });
+        if passed {
+            return Ok(TuneReport {
+                chosen: candidate,
+                probes,
+            });
+        }
+    }
+    Err(VstorageError::Config(
+        "no candidate settings survived the codec — lower --auto-margin or --crf".into(),
+    ))
+}
+
+/// Candidate configs, densest first; among equally dense ones the fuller
+/// ECC comes first. Each layout is followed by its lower-CRF retries.
+fn candidates(base: &FrameConfig) -> Vec<FrameConfig> {
+    let mut layouts = Vec::new();
+    for block_size in BLOCK_SIZES {
+        for levels in LEVELS {
+            for ecc_len in ECC_LENS {
+                let Ok(mut config) =
+                    FrameConfig::new(block_size, levels, ecc_len, base.fps, base.crf)
+                else {
+                    continue;
+                };
+                // Skip the layouts whose header does not fit
+                if config
+                    .set_header_redundancy(base.header_rows, base.header_copies)
+                    .is_err()
+                {
+                    continue;
+                }
+                if base.yuv420p
+                    && config
+                        .enable_yuv420p()
+                        .and_then(|()| config.set_channel_levels(chroma_aware_levels(levels)))
+                        .is_err()
+                {
+                    continue;
+                }
+                config.codec = base.codec;
+                config.target_bitrate = base.target_bitrate;
+                config.ffmpeg_args = base.ffmpeg_args.clone();
+                config.kdf = base.kdf;
+                config.token = base.token;
+                config.compression = base.compression;
+                config.layout = base.layout;
+                config.crc_map = base.crc_map;
+                config.bottom_header = base.bottom_header;
+                config.banner = base.banner;
+                config.barcode = base.barcode;
+                config.pilots = base.pilots;
+                config.bin_centers = base.bin_centers;
+                config.intro = base.intro;
+                config.seed = base.seed.clone();
+                layouts.push(config);
+            }
+        }
+    }
+    layouts.sort_by_key(|c| {
+        (
+            std::cmp::Reverse(c.max_plain_per_frame(false)),
+            std::cmp::Reverse(c.ecc_len),
+        )
+    });
+
+    // A target bitrate replaces CRF, so there is nothing to retry
+    let crfs: Vec<u8> = match base.target_bitrate {
+        Some(_) => vec![base.crf],
+        None => {
+            let mut crfs: Vec<u8> = CRF_STEPS.map(|s| base.crf.saturating_sub(s)).to_vec();
+            crfs.dedup();
+            crfs
+        }
+    };
+    layouts
+        .into_iter()
+        .flat_map(|layout| {
+            crfs.iter().map(move |&crf| FrameConfig {
+                crf,
+                ..layout.clone()
+            })
+        })
+        .collect()
+}
+
+/// Whether the worst block of a probe left at least `margin` of its
+/// correction capacity unused.
+fn survives(errors: &SymbolErrors, margin: f64) -> bool {
+    let worst = errors.stats.worst_block.map_or(0, |(_, w)| w);
+    worst as f64 <= errors.stats.per_block_capacity as f64 * (1.0 - margin)
+}
+
+/// Encode [`PROBE_FRAMES`] frames of random data with `config` and decode
+/// them again.
+fn probe(
+    config: &FrameConfig,
+    dir: &std::path::Path,
+    cancel: &CancelToken,
+) -> Result<std::result::Result<SymbolErrors, String>> {
+    let (input, video) = (dir.join("probe.bin"), dir.join("probe.mp4"));
+    let mut payload = vec![0u8; config.max_plain_per_frame(false) * PROBE_FRAMES];
+    rand::fill(&mut payload[..]);
+    std::fs::write(&input, &payload)?;
+    encode::encode(
+        &input,
+        &video,
+        None,
+        &[],
+        config,
+        None,
+        &[],
+        None,
+        &SplitLimits::default(),
+        false,
+        &NoProgress,
+        cancel,
+    )?;
+    simulate::decode_probe(&video, dir, &payload, cancel)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::decode::EccStats;
+
+    #[test]
+    fn test_candidates_densest_first() {
+        let mut base = FrameConfig::new(4, 4, 64, 30, 23).unwrap();
+        base.enable_yuv420p().unwrap();
+        let all = candidates(&base);
+        assert!(all.iter().all(|c| c.yuv420p && c.levels <= 4));
+        let density: Vec<usize> = all.iter().map(|c| c.max_plain_per_frame(false)).collect();
+        assert!(density.windows(2).all(|w| w[0] >= w[1]));
+        let crfs: Vec<u8> = all[..3].iter().map(|c| c.crf).collect();
+        assert_eq!(crfs, [23, 17, 11]);
+        assert_eq!(
+            (all[0].block_size, all[0].levels, all[0].ecc_len),
+            (2, 4, 32)
+        );
+
+        base.target_bitrate = Some(8000);
+        assert_eq!(candidates(&base)[1].crf, 23);
+    }
+
+    #[test]
+    fn test_survives_margin() {
+        let errors = |worst| SymbolErrors {
+            symbols: 10_000,
+            stats: EccStats {
+                per_block_capacity: 32,
+                frames: 2,
+                corrected: 100,
+                worst_block: Some((0, worst)),
+            },
+        };
+        assert!(survives(&errors(16), 0.5));
+        assert!(!survives(&errors(17), 0.5));
+        assert!(survives(&errors(32), 0.0));
+    }
+}
@@ -0,0 +1,400 @@
+use std::fmt;
+use std::fs::File;
+use std::io::{BufReader, Read, Seek, SeekFrom};
+use std::path::{Path, PathBuf};
+
+use zeroize::Zeroizing;
+
+use crate::cancel::CancelToken;
+use crate::collect;
+use crate::compress::Compression;
+use crate::config::Workspace;
+use crate::crcmap::TileDamage;
+use crate::crypto::Token;
+use crate::decode::{self, EccStats, FrameCheck};
+use crate::error::{Result, VstorageError};
+use crate::json::Json;
+use crate::progress::NoProgress;
+use crate::video;
+
+/// Result of [`verify`]: how every frame of a payload decoded.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct VerifyReport {
+    pub payload: u16,
+    pub ecc_len: u8,
+    /// Per frame; `None` if the frame could not be decoded
+    pub checks: Vec<Option<FrameCheck>>,
+    /// Frames whose RS correction failed (and no other copy decoded)
+    pub uncorrectable: Vec<usize>,
+    /// Frames whose authentication tag did not verify
+    pub auth_failed: Vec<usize>,
+    /// Whether the decoded frames add up to the recorded file size
+    pub size_ok: bool,
+    /// Where the frames were damaged, for videos encoded with a CRC map
+    pub tile_damage: Option<TileDamage>,
+    /// The decoded data against the original file, with `--against`
+    pub against: Option<Comparison>,
+}
+
+/// How the decoded payload compares with the file it was encoded from.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Comparison {
+    pub path: PathBuf,
+    /// Size of the original file
+    pub size: u64,
+    /// Offset of the first byte that differs, or where the shorter of the two
+    /// ends; `None` if every decoded byte matches. Frames that did not decode
+    /// are not compared.
+    pub first_mismatch: Option<u64>,
+}
+
+impl Comparison {
+    pub fn to_json(&self) -> Json {
+        Json::object([
+            ("path", self.path.display().to_string().into()),
+            ("size", self.size.into()),
+            ("matches", self.first_mismatch.is_none().into()),
+            ("first_mismatch", self.first_mismatch.into()),
+        ])
+    }
+}
+
+impl VerifyReport {
+    pub fn passed(&self) -> bool {
+        self.uncorrectable.is_empty()
+            && self.auth_failed.is_empty()
+            && self.size_ok
+            && self
+                .against
+                .as_ref()
+                .is_none_or(|c| c.first_mismatch.is_none())
+            && self
+                .checks
+                .iter()
+                .all(|c| c.is_some_and(|c| c.hash_ok != Some(false)))
+    }
+}
+
+impl VerifyReport {
+    pub fn to_json(&self) -> Json {
+        let mut frames = decode::frames_json(&self.checks);
+        if let Json::Array(items) = &mut frames {
+            for (i, item) in items.iter_mut().enumerate() {
+                if self.uncorrectable.contains(&i) {
+                    item.push("uncorrectable", true);
+                }
+                if self.auth_failed.contains(&i) {
+                    item.push("auth_failed", true);
+                }
+            }
+        }
+        let mut json = Json::object([
+            ("command", "verify".into()),
+            ("payload", self.payload.into()),
+            ("passed", self.passed().into()),
+            ("ecc_len", self.ecc_len.into()),
+            ("size_ok", self.size_ok.into()),
+            ("uncorrectable", frame_numbers(&self.uncorrectable)),
+            ("auth_failed", frame_numbers(&self.auth_failed)),
+            ("ecc", EccStats::new(&self.checks, self.ecc_len).to_json()),
+            ("frames", frames),
+        ]);
+        if let Some(damage) = &self.tile_damage {
+            json.push("tile_damage", damage.to_json());
+        }
+        if let Some(against) = &self.against {
+            json.push("against", against.to_json());
+        }
+        json
+    }
+}
+
+/// 0-based frame indices as 1-based JSON frame numbers.
+fn frame_numbers(frames: &[usize]) -> Json {
+    frames.iter().map(|i| i + 1).collect::<Vec<_>>().into()
+}
+
+impl fmt::Display for VerifyReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let per_block = self.ecc_len as usize / 2;
+        let mut corrected = 0;
+        let mut worst = 0.0f64;
+        for (i, check) in self.checks.iter().enumerate() {
+            write!(f, "frame {:>6}: ", i + 1)?;
+            match check {
+                Some(c) => {
+                    let capacity = c.blocks * per_block;
+                    write!(f, "{:>6} corrected / {capacity} correctable", c.corrected)?;
+                    if c.hash_ok == Some(false) {
+                        write!(f, ", HASH MISMATCH")?;
+                    }
+                    corrected += c.corrected;
+                    if capacity > 0 {
+                        worst = worst.max(c.corrected as f64 / capacity as f64);
+                    }
+                }
+                None if self.uncorrectable.contains(&i) => write!(f, "UNCORRECTABLE")?,
+                None => write!(f, "MISSING")?,
+            }
+            if self.auth_failed.contains(&i) {
+                write!(f, ", AUTH FAILED")?;
+            }
+            writeln!(f)?;
+        }
+        writeln!(
+            f,
+            "payload {}: {} frames, {corrected} symbols corrected, worst frame at {:.1}% of capacity",
+            self.payload,
+            self.checks.len(),
+            worst * 100.0
+        )?;
+        writeln!(f, "{}", EccStats::new(&self.checks, self.ecc_len))?;
+        if let Some(damage) = self.tile_damage.as_ref().filter(|d| d.any()) {
+            writeln!(f, "{damage}")?;
+        }
+        if !self.size_ok {
+            writeln!(f, "decoded data is shorter than the recorded file size")?;
+        }
+        if let Some(c) = &self.against {
+            match c.first_mismatch {
+                None => writeln!(f, "matches {} ({} bytes)", c.path.display(), c.size)?,
+                Some(offset) => writeln!(f, "DIFFERS from {} at byte {offset}", c.path.display())?,
+            }
+        }
+        write!(f, "{}", if self.passed() { "PASS" } else { "FAIL" })
+    }
+}
+
+/// Run the full decode path over `input_paths` — headers, Reed-Solomon,
+/// data hashes and (if encrypted) authentication tags — without writing the
+/// decoded file, comparing the decoded data with the file at `against` if
+/// given, on up to `threads` threads (see [`crate::config::FrameConfig::threads`]).
+/// The frames are extracted in `workspace`; a key that needs a hardware
+/// token takes `hardware`'s response.
+pub fn verify(
+    input_paths: &[&Path],
+    password: Option<&str>,
+    hardware: Option<&dyn Token>,
+    payload: u16,
+    against: Option<&Path>,
+    threads: Option<usize>,
+    workspace: &Workspace,
+) -> Result<VerifyReport> {
+    video::check_ffmpeg()?;
+    let temp_dir = video::temp_dir(workspace)?;
+    let collected = decode::collect_frames(
+        input_paths,
+        payload,
+        temp_dir.path(),
+        true,
+        None,
+        workspace.intermediate,
+        threads,
+        &NoProgress,
+        &CancelToken::new(),
+    )?;
+
+    let cipher = decode::open_cipher(&collected.first_header, password, hardware, &NoProgress)?;
+    let mut original = match against {
+        Some(path) => Some((
+            BufReader::new(File::open(path)?),
+            std::fs::metadata(path)?.len(),
+        )),
+        None => None,
+    };
+    // A compressed payload is compared once decompressed, as a whole
+    let compressed = collected.first_header.compression != Compression::None;
+    let per_frame = collected.config.max_plain_per_frame(cipher.is_some()) as u64;
+    let mut packed = Zeroizing::new(Vec::new());
+    let mut first_mismatch = None;
+    let mut auth_failed = Vec::new();
+    let mut plain_len = 0u64;
+    let (streamed, total) = (collected.first_header.streamed, collected.chunks.len());
+    for (i, chunk) in collected.chunks.iter().enumerate() {
+        let Some(chunk) = chunk else { continue };
+        let last = collect::is_stream_end(streamed, i, total);
+        let decrypted;
+        let plain: &[u8] = match &cipher {
+            Some(c) => match c.decrypt_stream_frame(i as u32, last, chunk) {
+                Ok(plain) => {
+                    decrypted = plain;
+                    &decrypted
+                }
+                Err(_) => {
+                    auth_failed.push(i);
+                    continue;
+                }
+            },
+            None => chunk,
+        };
+        plain_len += plain.len() as u64;
+        match &mut original {
+            Some(_) if compressed => packed.extend_from_slice(plain),
+            Some((reader, _)) if first_mismatch.is_none() => {
+                first_mismatch = first_difference(reader, i as u64 * per_frame, plain)?;
+            }
+            _ => {}
+        }
+    }
+
+    if cipher.is_some() && !auth_failed.is_empty() && plain_len == 0 {
+        return Err(VstorageError::WrongPassword);
+    }
+
+    let mut uncorrectable: Vec<usize> = collected
+        .failures
+        .iter()
+        .map(|(i, _)| *i)
+        .filter(|&i| collected.chunks[i].is_none())
+        .collect();
+    uncorrectable.sort_unstable();
+    uncorrectable.dedup();
+
+    let size_ok = plain_len >= collected.first_header.file_size;
+    let against = match (against, original) {
+        (Some(path), Some((mut reader, size))) => {
+            let mut decoded_size = collected.first_header.file_size;
+            if compressed {
+                let complete = size_ok && uncorrectable.is_empty() && auth_failed.is_empty();
+                if !complete {
+                    return Err(VstorageError::Compression(
+                        "a damaged compressed payload cannot be compared with the original".into(),
+                    ));
+                }
+                let data = collected.first_header.compression.decompress(&packed)?;
+                decoded_size = data.len() as u64;
+                first_mismatch = first_difference(&mut reader, 0, &data)?;
+            }
+            if size != decoded_size {
+                let end = size.min(decoded_size);
+                first_mismatch = Some(first_mismatch.map_or(end, |m| m.min(end)));
+            }
+            Some(Comparison {
+                path: path.to_path_buf(),
+                size,
+                first_mismatch,
+            })
+        }
+        _ => None,
+    };
+
+    Ok(VerifyReport {
+        payload,
+        ecc_len: collected.config.ecc_len,
+        checks: collected.checks,
+        uncorrectable,
+        auth_failed,
+        size_ok,
+        tile_damage: collected.tile_damage,
+        against,
+    })
+}
+
+/// Offset of the first byte of `expected` that differs from `reader` read
+/// from `offset`, or where `reader` ends before `expected` does.
+fn first_difference(
+    reader: &mut (impl Read + Seek),
+    offset: u64,
+    expected: &[u8],
+) -> Result<Option<u64>> {
+    reader.seek(SeekFrom::Start(offset))?;
+    let mut buf = vec![0u8; expected.len().min(1 << 16)];
+    let mut pos = 0;
+    while pos < expected.len() {
+        let want = buf.len().min(expected.len() - pos);
+        let n = reader.read(&mut buf[..want])?;
+        if n == 0 {
+            return Ok(Some(offset + pos as u64));
+        }
+        if let Some(j) = buf[..n]
+            .iter()
+            .zip(&expected[pos..])
+            .position(|(a, b)| a != b)
+        {
+            return Ok(Some(offset + (pos + j) as u64));
+        }
+        pos += n;
+    }
+    Ok(None)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn report() -> VerifyReport {
+        let ok = FrameCheck {
+            corrected: 4,
+            blocks: 2,
+            worst_block: 3,
+            hash_ok: Some(true),
+        };
+        VerifyReport {
+            payload: 0,
+            ecc_len: 32,
+            checks: vec![Some(ok), Some(ok)],
+            uncorrectable: Vec::new(),
+            auth_failed: Vec::new(),
+            size_ok: true,
+            tile_damage: None,
+            against: None,
+        }
+    }
+
+    #[test]
+    fn test_report_pass_fail() {
+        let mut r = report();
+        assert!(r.passed());
+        assert!(r.to_string().contains("worst frame at 12.5% of capacity"));
+        assert!(r.to_string().contains("at 3/16 — margin 13 symbols"));
+        assert!(r.to_string().ends_with("PASS"));
+
+        r.checks[1] = None;
+        r.uncorrectable.push(1);
+        assert!(!r.passed());
+        let text = r.to_string();
+        assert!(text.contains("frame      2: UNCORRECTABLE"));
+        assert!(text.ends_with("FAIL"));
+
+        let mut r = report();
+        r.auth_failed.push(0);
+        assert!(!r.passed());
+        let json = r.to_json().to_string();
+        assert!(json.contains("\"passed\":false"));
+        assert!(json.contains("\"auth_failed\":[1]"));
+        assert!(!json.contains("tile_damage"));
+        assert!(json.contains("{\"frame\":1,\"corrected\":4,\"blocks\":2,\"worst_block\":3,\"hash_ok\":true,\"auth_failed\":true}"));
+
+        let mut r = report();
+        r.against = Some(Comparison {
+            path: PathBuf::from("original.bin"),
+            size: 100,
+            first_mismatch: Some(42),
+        });
+        assert!(!r.passed());
+        assert!(r
+            .to_string()
+            .contains("DIFFERS from original.bin at byte 42"));
+        assert!(r.to_json().to_string().contains(
+            "\"against\":{\"path\":\"original.bin\",\"size\":100,\"matches\":false,\"first_mismatch\":42}"
+        ));
+    }
+
+    #[test]
+    fn test_first_difference() {
+        let mut original = std::io::Cursor::new(b"hello world".to_vec());
+        assert_eq!(first_difference(&mut original, 6, b"world").unwrap(), None);
+        assert_eq!(
+            first_difference(&mut original, 6, b"worms").unwrap(),
+            Some(9)
+        );
+        // The original ends first
+        assert_eq!(
+            first_difference(&mut original, 6, b"world!").unwrap(),
+            Some(11)
+        );
+        assert_eq!(first_difference(&mut original, 20, b"x").unwrap(), Some(20));
+    }
+}
@@ -1,91 +1,610 @@
-use std::path::Path;
-use std::process::Command;
+use std::io::{Read, Write};
+use std::path::{Path, PathBuf};
+use std::process::{Command, Stdio};
+use std::time::Duration;
 
-use crate::config::FrameConfig;
+use crate::cancel::CancelToken;
+use crate::config::{FrameConfig, Intermediate, VideoCodec, Workspace, PROTOCOL_VERSION};
 use crate::error::{Result, VstorageError};
+use crate::json::Json;
+use crate::progress;
+use crate::s3::{self, S3Url};
+
+/// A new temporary directory for frames, in the workspace's directory if
+/// it has one; not removed on drop if it keeps frames.
+pub fn temp_dir(workspace: &Workspace) -> Result<tempfile::TempDir> {
+    let mut dir = match &workspace.dir {
+        Some(root) => {
+            std::fs::create_dir_all(root)?;
+            tempfile::tempdir_in(root)?
+        }
+        None => tempfile::tempdir()?,
+    };
+    if workspace.keep_frames {
+        dir.disable_cleanup(true);
+        tracing::info!("Keeping frames in {}", dir.path().display());
+    }
+    Ok(dir)
+}
+
+/// Remove a finished work directory, unless the workspace keeps frames.
+pub fn remove_work_dir(dir: &Path, workspace: &Workspace) -> Result<()> {
+    if workspace.keep_frames {
+        tracing::info!("Kept intermediates in {}", dir.display());
+        return Ok(());
+    }
+    std::fs::remove_dir_all(dir)?;
+    Ok(())
+}
+
+/// Path of numbered frame `number` (1-based, as ffmpeg counts) in `dir`.
+pub fn frame_path(dir: &Path, number: usize, format: Intermediate) -> PathBuf {
+    dir.join(format!("frame_{number:06}.{}", format.extension()))
+}
+
+/// The ffmpeg pattern matching [`frame_path`].
+fn frame_pattern(dir: &Path, format: Intermediate) -> PathBuf {
+    dir.join(format!("frame_%06d.{}", format.extension()))
+}
+
+/// Write an intermediate frame, in the format its extension names.
+pub fn save_frame(img: &image::RgbImage, path: &Path) -> Result<()> {
+    if path.extension().is_some_and(|ext| ext == "ppm") {
+        // Binary PPM, which the image crate only writes as PAM
+        let mut out = std::io::BufWriter::new(std::fs::File::create(path)?);
+        write!(out, "P6\n{} {}\n255\n", img.width(), img.height())?;
+        out.write_all(img.as_raw())?;
+        out.flush()?;
+        return Ok(());
+    }
+    Ok(img.save(path)?)
+}
+
+/// Read a frame image written by ffmpeg or [`save_frame`], as RGB.
+pub fn load_frame(path: &Path) -> Result<image::RgbImage> {
+    let reader = image::ImageReader::open(path)?;
+    Ok(reader.decode()?.to_rgb8())
+}
 
 /// Check that FFmpeg is available on PATH.
 pub fn check_ffmpeg() -> Result<()> {
     Command::new("ffmpeg")
         .arg("-version")
-        .stdout(std::process::Stdio::null())
-        .stderr(std::process::Stdio::null())
+        .stdout(Stdio::null())
+        .stderr(Stdio::null())
         .status()
-        .map_err(|_| {
-            VstorageError::Ffmpeg(
-                "FFmpeg not found. Please install FFmpeg and ensure it is in your PATH.".into(),
-            )
-        })?;
+        .map_err(|_| VstorageError::FfmpegNotFound)?;
     Ok(())
 }
 
-/// Convert a directory of numbered PNGs into an MP4 video.
-pub fn pngs_to_mp4(png_dir: &Path, output: &Path, config: &FrameConfig) -> Result<()> {
-    let pattern = png_dir.join("frame_%06d.png");
-    let fps_str = config.fps.to_string();
-    let crf_str = config.crf.to_string();
+/// Extensions of the frame images a directory can hold in place of a video.
+const FRAME_EXTENSIONS: [&str; 8] = ["png", "ppm", "pnm", "bmp", "jpg", "jpeg", "tif", "tiff"];
 
-    let status = Command::new("ffmpeg")
-        .args([
-            "-y",
-            "-framerate",
-            &fps_str,
-            "-i",
-            pattern.to_str().unwrap(),
+/// The extension (as written) of most frame images in `dir`, one of
+/// [`FRAME_EXTENSIONS`] in any case.
+fn frame_extension(dir: &Path) -> Result<String> {
+    let mut counts = std::collections::BTreeMap::new();
+    for entry in std::fs::read_dir(dir)? {
+        let path = entry?.path();
+        let ext = path.extension().and_then(|e| e.to_str()).unwrap_or("");
+        if FRAME_EXTENSIONS.iter().any(|e| e.eq_ignore_ascii_case(ext)) {
+            *counts.entry(ext.to_string()).or_insert(0usize) += 1;
+        }
+    }
+    counts
+        .into_iter()
+        .max_by_key(|&(_, n)| n)
+        .map(|(ext, _)| ext)
+        .ok_or_else(|| {
+            VstorageError::Config(format!(
+                "{} holds no frame images ({})",
+                dir.display(),
+                FRAME_EXTENSIONS.join(", ")
+            ))
+        })
+}
+
+/// ffmpeg arguments reading `input`: a video or URL as it is, or a
+/// directory of frame images (of its most common format) in name order.
+fn source_args(input: &Path, threads: Option<usize>) -> Result<Vec<String>> {
+    let input = s3::readable(input)?;
+    let mut args = thread_args(threads);
+    if !input.is_dir() {
+        args.extend(["-i".into(), input.to_string_lossy().into_owned()]);
+        return Ok(args);
+    }
+    let ext = frame_extension(&input)?;
+    let dir: String = input
+        .to_string_lossy()
+        .chars()
+        .flat_map(|c| match c {
+            '*' | '?' | '[' | ']' | '\\' => vec!['\\', c],
+            c => vec![c],
+        })
+        .collect();
+    args.extend(["-f", "image2", "-pattern_type", "glob", "-i"].map(String::from));
+    args.push(format!("{dir}/*.{ext}"));
+    Ok(args)
+}
+
+/// Run ffmpeg with `args`, capturing stderr so failures carry its last lines.
+/// The child is killed once `cancel` is cancelled.
+fn run_ffmpeg<S: AsRef<std::ffi::OsStr>>(args: &[S], cancel: &CancelToken) -> Result<()> {
+    run_tool("ffmpeg", args, VstorageError::Ffmpeg, cancel)
+}
+
+/// Run an external `program` like [`run_ffmpeg`]; failures are reported
+/// through `error`.
+pub(crate) fn run_tool<S: AsRef<std::ffi::OsStr>>(
+    program: &str,
+    args: &[S],
+    error: fn(String) -> VstorageError,
+    cancel: &CancelToken,
+) -> Result<()> {
+    run_tool_to(program, args, Stdio::null(), error, cancel)
+}
+
+/// [`run_tool`] with the child's stdout sent to `stdout`.
+fn run_tool_to<S: AsRef<std::ffi::OsStr>>(
+    program: &str,
+    args: &[S],
+    stdout: Stdio,
+    error: fn(String) -> VstorageError,
+    cancel: &CancelToken,
+) -> Result<()> {
+    let mut child = Command::new(program)
+        .args(args)
+        .stdin(Stdio::null())
+        .stdout(stdout)
+        .stderr(Stdio::piped())
+        .spawn()
+        .map_err(|e| error(format!("failed to run {program}: {e}")))?;
+    let mut stderr = child.stderr.take().expect("stderr is piped");
+    let report_frames = program == "ffmpeg";
+    let reader = std::thread::spawn(move || {
+        let (mut buf, mut chunk, mut line_start) = (Vec::new(), [0u8; 4096], 0);
+        while let Ok(n @ 1..) = stderr.read(&mut chunk) {
+            buf.extend_from_slice(&chunk[..n]);
+            // ffmpeg rewrites its stats line after a carriage return
+            while let Some(end) = buf[line_start..]
+                .iter()
+                .position(|&b| b == b'\r' || b == b'\n')
+            {
+                let line = &buf[line_start..line_start + end];
+                if let Some(frame) = ffmpeg_frame(line).filter(|_| report_frames) {
+                    progress::event(Json::object([
+                        ("event", "ffmpeg".into()),
+                        ("frame", frame.into()),
+                    ]));
+                }
+                line_start += end + 1;
+            }
+        }
+        buf
+    });
+
+    let status = loop {
+        if let Some(status) = child.try_wait()? {
+            break status;
+        }
+        if cancel.is_cancelled() {
+            let _ = child.kill();
+            let _ = child.wait();
+            let _ = reader.join();
+            return Err(VstorageError::Cancelled);
+        }
+        std::thread::sleep(Duration::from_millis(50));
+    };
+
+    if !status.success() {
+        // Ctrl-C reaches the child too, which may exit before we notice
+        if cancel.is_cancelled() {
+            return Err(VstorageError::Cancelled);
+        }
+        let stderr = reader.join().unwrap_or_default();
+        let stderr = String::from_utf8_lossy(&stderr);
+        let tail: Vec<&str> = stderr.lines().rev().take(3).collect();
+        let tail: Vec<&str> = tail.into_iter().rev().collect();
+        return Err(error(format!(
+            "{program} exited with status {status}: {}",
+            tail.join(" | ")
+        )));
+    }
+
+    Ok(())
+}
+
+/// Frames done according to a line of ffmpeg's stats (`frame=  120 fps=...`).
+fn ffmpeg_frame(line: &[u8]) -> Option<u64> {
+    let line = std::str::from_utf8(line).ok()?;
+    let rest = line.strip_prefix("frame=")?.trim_start();
+    let digits = rest
+        .find(|c: char| !c.is_ascii_digit())
+        .unwrap_or(rest.len());
+    rest[..digits].parse().ok()
+}
+
+/// Human-readable container tags (title, comment) describing a vstorage video.
+pub fn container_tags(config: &FrameConfig, total_frames: usize) -> (String, String) {
+    let title = format!(
+        "vstorage v{PROTOCOL_VERSION}, {total_frames} frames, block={} levels={} ecc={}",
+        config.block_size, config.levels, config.ecc_len
+    );
+    let comment = format!(
+        "Data encoded by vstorage {}. Decode with https://github.com/levish0/Vstorage",
+        env!("CARGO_PKG_VERSION")
+    );
+    (title, comment)
+}
+
+/// `-threads` for a [`FrameConfig::threads`] cap, if there is one.
+fn thread_args(threads: Option<usize>) -> Vec<String> {
+    threads.map_or(Vec::new(), |n| vec!["-threads".into(), n.to_string()])
+}
+
+/// Video codec and rate-control arguments. `pass` selects a two-pass stage
+/// (1 or 2) together with the shared pass log prefix.
+fn codec_args(config: &FrameConfig, pass: Option<(u8, &Path)>) -> Vec<String> {
+    let mut args: Vec<String> = match config.codec {
+        VideoCodec::H264 => [
             "-c:v",
             "libx264",
-            "-pix_fmt",
-            "yuv444p",
-            "-color_range",
-            "pc",
-            "-crf",
-            &crf_str,
             "-tune",
             "stillimage",
             "-preset",
             "medium",
-            output.to_str().unwrap(),
-        ])
-        .stdout(std::process::Stdio::null())
-        .stderr(std::process::Stdio::piped())
-        .status()
-        .map_err(|e| VstorageError::Ffmpeg(format!("failed to run ffmpeg: {e}")))?;
+        ]
+        .map(String::from)
+        .to_vec(),
+        VideoCodec::Vp9 => ["-c:v", "libvpx-vp9", "-row-mt", "1", "-deadline", "good"]
+            .map(String::from)
+            .to_vec(),
+    };
+    args.extend(["-pix_fmt", config.pix_fmt(), "-color_range", "pc"].map(String::from));
+    args.extend(thread_args(config.threads));
 
-    if !status.success() {
-        return Err(VstorageError::Ffmpeg(format!(
-            "ffmpeg exited with status {status}"
-        )));
+    match config.target_bitrate {
+        Some(kbps) => args.extend(["-b:v".into(), format!("{kbps}k")]),
+        None => {
+            args.extend(["-crf".into(), config.crf.to_string()]);
+            if config.codec == VideoCodec::Vp9 {
+                // Constant-quality mode for libvpx
+                args.extend(["-b:v".into(), "0".into()]);
+            }
+        }
     }
 
-    Ok(())
+    args.extend(config.ffmpeg_args.iter().cloned());
+
+    if let Some((n, log)) = pass {
+        args.extend([
+            "-pass".into(),
+            n.to_string(),
+            "-passlogfile".into(),
+            log.to_str().unwrap().into(),
+        ]);
+    }
+    args
 }
 
-/// Extract frames from an MP4 video into numbered PNGs.
-pub fn mp4_to_pngs(input: &Path, output_dir: &Path) -> Result<()> {
-    let pattern = output_dir.join("frame_%06d.png");
+/// Encode `count` numbered frames starting at [`frame_path`] `start_number`
+/// into a video-only segment.
+///
+/// With `config.target_bitrate` set, the segment is encoded in two passes so
+/// the bitrate budget is distributed across frames instead of relying on CRF.
+pub fn frames_to_segment(
+    frames_dir: &Path,
+    start_number: usize,
+    count: usize,
+    output: &Path,
+    config: &FrameConfig,
+    cancel: &CancelToken,
+) -> Result<()> {
+    let pattern = frame_pattern(frames_dir, config.workspace.intermediate);
+    let input_args: Vec<String> = vec![
+        "-y".into(),
+        "-framerate".into(),
+        config.fps.to_string(),
+        "-start_number".into(),
+        start_number.to_string(),
+        "-i".into(),
+        pattern.to_str().unwrap().into(),
+        "-frames:v".into(),
+        count.to_string(),
+        "-an".into(),
+    ];
 
-    let status = Command::new("ffmpeg")
-        .args([
+    let passlog = output.with_extension("2pass");
+    let final_pass = if config.target_bitrate.is_some() {
+        let mut args = input_args.clone();
+        args.extend(codec_args(config, Some((1, &passlog))));
+        args.extend(["-f", "null", "-"].map(String::from));
+        run_ffmpeg(&args, cancel)?;
+        Some((2, passlog.as_path()))
+    } else {
+        None
+    };
+
+    let mut args = input_args;
+    args.extend(codec_args(config, final_pass));
+    args.push(output.to_str().unwrap().into());
+    run_ffmpeg(&args, cancel)
+}
+
+/// Join video segments (stream copy) into the final MP4, muxing an optional
+/// WAV file as the audio track and writing the container tags. An `s3://`
+/// output is written as fragmented MP4 straight into the upload.
+pub fn concat_segments(
+    segments: &[std::path::PathBuf],
+    output: &Path,
+    config: &FrameConfig,
+    total_frames: usize,
+    audio: Option<&Path>,
+    cancel: &CancelToken,
+) -> Result<()> {
+    let list_path = segments[0].with_file_name("segments.txt");
+    let list: String = segments
+        .iter()
+        .map(|p| format!("file '{}'\n", p.to_str().unwrap().replace('\'', "'\\''")))
+        .collect();
+    std::fs::write(&list_path, list)?;
+
+    let (title, comment) = container_tags(config, total_frames);
+    let mut args: Vec<String> = ["-y", "-f", "concat", "-safe", "0", "-i"]
+        .map(String::from)
+        .to_vec();
+    args.push(list_path.to_str().unwrap().into());
+    if let Some(wav) = audio {
+        args.extend(["-i".into(), wav.to_str().unwrap().into()]);
+        args.extend(["-c:a", "aac", "-b:a", "256k"].map(String::from));
+    }
+    args.extend(["-c:v", "copy"].map(String::from));
+    args.extend([
+        "-metadata".into(),
+        format!("title={title}"),
+        "-metadata".into(),
+        format!("comment={comment}"),
+    ]);
+    let result = match S3Url::parse(output) {
+        Some(url) => {
+            let expected_size = segments
+                .iter()
+                .map(PathBuf::as_path)
+                .chain(audio)
+                .map(|p| std::fs::metadata(p).map(|m| m.len()))
+                .sum::<std::io::Result<u64>>()?;
+            args.extend(
+                [
+                    "-f",
+                    "mp4",
+                    "-movflags",
+                    "frag_keyframe+empty_moov",
+                    "pipe:1",
+                ]
+                .map(String::from),
+            );
+            s3::upload(&url, expected_size, cancel, |stdout| {
+                run_tool_to("ffmpeg", &args, stdout, VstorageError::Ffmpeg, cancel)
+            })
+        }
+        None => {
+            args.push(output.to_str().unwrap().into());
+            run_ffmpeg(&args, cancel)
+        }
+    };
+    let _ = std::fs::remove_file(&list_path);
+    result
+}
+
+/// Extract frames from an MP4 video into numbered frames of `format` (see
+/// [`frame_path`]), running ffmpeg with `-threads` for a `threads` cap.
+pub fn mp4_to_frames(
+    input: &Path,
+    output_dir: &Path,
+    format: Intermediate,
+    threads: Option<usize>,
+    cancel: &CancelToken,
+) -> Result<()> {
+    let lossy = |ext: String| ["jpg", "jpeg"].iter().any(|e| e.eq_ignore_ascii_case(&ext));
+    if input.is_dir() && lossy(frame_extension(input)?) {
+        tracing::warn!(
+            "{} holds JPEG frames; their lossy compression may have damaged small blocks",
+            input.display()
+        );
+    }
+    let mut args = source_args(input, threads)?;
+    args.extend(["-pix_fmt", "rgb24", "-color_range", "pc"].map(String::from));
+    args.push(frame_pattern(output_dir, format).to_str().unwrap().into());
+    run_ffmpeg(&args, cancel)
+}
+
+/// Extract a single frame (0-based `index`) from a video as an RGB PNG.
+pub fn extract_frame(
+    input: &Path,
+    index: usize,
+    png_out: &Path,
+    cancel: &CancelToken,
+) -> Result<()> {
+    let select = format!("select=eq(n\\,{index})");
+    let mut args = vec!["-y".to_string()];
+    args.extend(source_args(input, None)?);
+    args.extend(["-vf", &select, "-vframes", "1", "-pix_fmt", "rgb24"].map(String::from));
+    args.extend(["-color_range", "pc", png_out.to_str().unwrap()].map(String::from));
+    run_ffmpeg(&args, cancel)
+}
+
+/// Extract `count` consecutive frames starting at 0-based `first` into
+/// numbered frames of `format` (numbering restarts at 1), capping ffmpeg
+/// at `threads`.
+pub fn extract_frame_range(
+    input: &Path,
+    first: usize,
+    count: usize,
+    output_dir: &Path,
+    format: Intermediate,
+    threads: Option<usize>,
+    cancel: &CancelToken,
+) -> Result<()> {
+    let select = format!("select=between(n\\,{first}\\,{})", first + count - 1);
+    let mut args = source_args(input, threads)?;
+    args.extend(["-vf", &select, "-vsync", "0", "-pix_fmt", "rgb24"].map(String::from));
+    args.extend(["-color_range", "pc"].map(String::from));
+    args.push(frame_pattern(output_dir, format).to_str().unwrap().into());
+    run_ffmpeg(&args, cancel)
+}
+
+/// Extract the audio track of a video as mono 16-bit PCM WAV.
+pub fn extract_audio(input: &Path, wav_out: &Path, cancel: &CancelToken) -> Result<()> {
+    let input = s3::readable(input)?;
+    let rate_str = crate::audio::SAMPLE_RATE.to_string();
+
+    run_ffmpeg(
+        &[
+            "-y",
             "-i",
             input.to_str().unwrap(),
-            "-pix_fmt",
-            "rgb24",
-            "-color_range",
-            "pc",
-            pattern.to_str().unwrap(),
-        ])
-        .stdout(std::process::Stdio::null())
-        .stderr(std::process::Stdio::piped())
-        .status()
-        .map_err(|e| VstorageError::Ffmpeg(format!("failed to run ffmpeg: {e}")))?;
+            "-vn",
+            "-ac",
+            "1",
+            "-ar",
+            &rate_str,
+            "-c:a",
+            "pcm_s16le",
+            wav_out.to_str().unwrap(),
+        ],
+        cancel,
+    )
+    .map_err(|e| VstorageError::Ffmpeg(format!("could not extract an audio track ({e})")))
+}
 
-    if !status.success() {
+/// Properties of an existing video's first video stream.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct StreamInfo {
+    pub codec: VideoCodec,
+    pub pix_fmt: String,
+    pub fps: u32,
+    pub frames: usize,
+}
+
+/// Probe codec, pixel format, frame rate and frame count with ffprobe.
+pub fn probe_video(input: &Path) -> Result<StreamInfo> {
+    let readable = s3::readable(input)?;
+    let output = Command::new("ffprobe")
+        .args([
+            "-v",
+            "error",
+            "-select_streams",
+            "v:0",
+            "-count_packets",
+            "-show_entries",
+            "stream=codec_name,pix_fmt,avg_frame_rate,nb_read_packets",
+            "-of",
+            "default=noprint_wrappers=1",
+        ])
+        .arg(&readable)
+        .stdin(std::process::Stdio::null())
+        .output()
+        .map_err(|e| VstorageError::Ffmpeg(format!("failed to run ffprobe: {e}")))?;
+    if !output.status.success() {
         return Err(VstorageError::Ffmpeg(format!(
-            "ffmpeg exited with status {status}"
+            "ffprobe could not read {}",
+            input.display()
         )));
     }
+    parse_probe(&String::from_utf8_lossy(&output.stdout))
+}
 
-    Ok(())
+/// Parse ffprobe's `key=value` output for [`probe_video`].
+fn parse_probe(text: &str) -> Result<StreamInfo> {
+    let get = |key: &str| {
+        text.lines()
+            .find_map(|l| l.strip_prefix(key).and_then(|r| r.strip_prefix('=')))
+            .map(str::trim)
+            .ok_or_else(|| VstorageError::Ffmpeg(format!("ffprobe did not report {key}")))
+    };
+    let codec = match get("codec_name")? {
+        "h264" => VideoCodec::H264,
+        "vp9" => VideoCodec::Vp9,
+        other => {
+            return Err(VstorageError::Ffmpeg(format!(
+                "unsupported video codec '{other}'"
+            )))
+        }
+    };
+    let rate = get("avg_frame_rate")?;
+    let (num, den) = rate.split_once('/').unwrap_or((rate, "1"));
+    let fps = match (num.parse::<f64>(), den.parse::<f64>()) {
+        (Ok(n), Ok(d)) if d > 0.0 && n > 0.0 => (n / d).round() as u32,
+        _ => return Err(VstorageError::Ffmpeg(format!("bad frame rate '{rate}'"))),
+    };
+    let frames = get("nb_read_packets")?
+        .parse()
+        .map_err(|_| VstorageError::Ffmpeg("bad frame count from ffprobe".into()))?;
+    Ok(StreamInfo {
+        codec,
+        pix_fmt: get("pix_fmt")?.to_string(),
+        fps,
+        frames,
+    })
+}
+
+/// Re-mux `existing` followed by new video `segments` into `output` without
+/// re-encoding, keeping the existing audio track (if any).
+pub fn append_segments(
+    existing: &Path,
+    segments: &[std::path::PathBuf],
+    output: &Path,
+    config: &FrameConfig,
+    total_frames: usize,
+    cancel: &CancelToken,
+) -> Result<()> {
+    let list_path = output.with_extension("segments.txt");
+    let list: String = std::iter::once(existing)
+        .chain(segments.iter().map(|p| p.as_path()))
+        .map(|p| format!("file '{}'\n", p.to_str().unwrap().replace('\'', "'\\''")))
+        .collect();
+    std::fs::write(&list_path, list)?;
+
+    let (title, comment) = container_tags(config, total_frames);
+    let mut args: Vec<String> = ["-y", "-f", "concat", "-safe", "0", "-i"]
+        .map(String::from)
+        .to_vec();
+    args.push(list_path.to_str().unwrap().into());
+    args.extend(["-i".into(), existing.to_str().unwrap().into()]);
+    args.extend(["-map", "0:v", "-map", "1:a?", "-c", "copy"].map(String::from));
+    args.extend([
+        "-metadata".into(),
+        format!("title={title}"),
+        "-metadata".into(),
+        format!("comment={comment}"),
+        output.to_str().unwrap().into(),
+    ]);
+    let result = run_ffmpeg(&args, cancel);
+    let _ = std::fs::remove_file(&list_path);
+    result
+}
+
+/// Re-encode the video stream of `input` with `config`'s codec, CRF and pixel
+/// format, as a platform transcoding an upload would. Metadata is kept.
+pub fn reencode(
+    input: &Path,
+    output: &Path,
+    config: &FrameConfig,
+    cancel: &CancelToken,
+) -> Result<()> {
+    let mut args: Vec<String> = vec![
+        "-y".into(),
+        "-i".into(),
+        input.to_str().unwrap().into(),
+        "-map".into(),
+        "0:v".into(),
+        "-map_metadata".into(),
+        "0".into(),
+    ];
+    args.extend(codec_args(config, None));
+    args.push(output.to_str().unwrap().into());
+    run_ffmpeg(&args, cancel)
 }
 
 // Naekkori's a cute catgirl character that appears in the video encoding process. This function is a placeholder for any future functionality related to Naekkori,
@@ -96,4 +615,110 @@ pub fn to_nekomimi()->Vec<u8>{
 255,216,255,224,0,16,74,70,73,70,0,1,1,0,0,1,0,1,0,0,255,226,1,216,73,67,67,95,80,82,79,70,73,76,69,0,1,1,0,0,1,200,0,0,0,0,4,48,0,0,109,110,116,114,82,71,66,32,88,89,90,32,7,224,0,1,0,1,0,0,0,0,0,0,97,99,115,112,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,1,0,0,246,214,0,1,0,0,0,0,211,45,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,9,100,101,115,99,0,0,0,240,0,0,0,36,114,88,89,90,0,0,1,20,0,0,0,20,103,88,89,90,0,0,1,40,0,0,0,20,98,88,89,90,0,0,1,60,0,0,0,20,119,116,112,116,0,0,1,80,0,0,0,20,114,84,82,67,0,0,1,100,0,0,0,40,103,84,82,67,0,0,1,100,0,0,0,40,98,84,82,67,0,0,1,100,0,0,0,40,99,112,114,116,0,0,1,140,0,0,0,60,109,108,117,99,0,0,0,0,0,0,0,1,0,0,0,12,101,110,85,83,0,0,0,8,0,0,0,28,0,115,0,82,0,71,0,66,88,89,90,32,0,0,0,0,0,0,111,162,0,0,56,245,0,0,3,144,88,89,90,32,0,0,0,0,0,0,98,153,0,0,183,133,0,0,24,218,88,89,90,32,0,0,0,0,0,0,36,160,0,0,15,132,0,0,182,207,88,89,90,32,0,0,0,0,0,0,246,214,0,1,0,0,0,0,211,45,112,97,114,97,0,0,0,0,0,4,0,0,0,2,102,102,0,0,242,167,0,0,13,89,0,0,19,208,0,0,10,91,0,0,0,0,0,0,0,0,109,108,117,99,0,0,0,0,0,0,0,1,0,0,0,12,101,110,85,83,0,0,0,32,0,0,0,28,0,71,0,111,0,111,0,103,0,108,0,101,0,32,0,73,0,110,0,99,0,46,0,32,0,50,0,48,0,49,0,54,255,219,0,67,0,8,6,6,7,6,5,8,7,7,7,9,9,8,10,12,20,13,12,11,11,12,25,18,19,15,20,29,26,31,30,29,26,28,28,32,36,46,39,32,34,44,35,28,28,40,55,41,44,48,49,52,52,52,31,39,57,61,56,50,60,46,51,52,50,255,219,0,67,1,9,9,9,12,11,12,24,13,13,24,50,33,28,33,50,50,50,50,50,50,50,50,50,50,50,50,50,50,50,50,50,50,50,50,50,50,50,50,50,50,50,50,50,50,50,50,50,50,50,50,50,50,50,50,50,50,50,50,50,50,50,50,50,50,255,192,0,17,8,6,250,4,121,3,1,34,0,2,17,1,3,17,1,255,196,0,28,0,1,1,0,2,3,1,1,0,0,0,0,0,0,0,0,0,0,1,2,4,3,5,6,7,8,255,196,0,83,16,0,2,1,3,3,2,4,4,3,6,3,4,7,5,2,15,0,1,2,3,4,17,33,49,65,5,18,6,19,81,97,20,34,50,113,7,129,145,21,35,66,82,161,177,36,51,193,22,98,114,209,8,52,67,83,130,146,225,37,162,178,240,241,23,38,53,54,115,194,68,84,99,163,179,39,69,85,100,116,117,255,196,0,24,1,1,1,1,1,1,0,0,0,0,0,0,0,0,0,0,0,0,1,2,3,4,255,196,0,39,17,1,1,1,0,2,3,0,2,2,2,2,3,1,0,0,0,0,1,17,2,49,3,33,65,18,50,34,81,4,97,19,66,35,51,113,67,255,218,0,12,3,1,0,2,17,3,17,0,63,0,251,45,162,95,1,108,211,120,242,163,253,142,92,101,225,18,206,9,116,219,101,233,78,63,216,230,140,82,216,227,107,110,38,154,90,50,107,141,78,110,205,73,217,234,52,199,22,94,55,43,148,189,78,78,221,52,49,237,192,209,130,108,146,148,154,212,228,237,193,139,142,163,85,199,41,73,44,229,158,59,241,22,249,219,248,107,202,77,247,87,168,163,133,202,61,156,163,169,224,191,18,176,172,109,160,255,0,138,79,24,245,51,202,250,111,132,222,82,63,58,120,163,31,181,86,63,148,207,194,212,221,111,16,89,81,254,122,208,91,123,156,62,37,127,251,83,183,209,29,207,225,237,186,184,241,111,76,131,219,207,71,93,254,46,124,166,114,175,209,126,59,156,233,248,62,253,211,127,53,58,81,75,237,148,143,204,158,35,82,237,167,220,185,63,80,248,210,159,127,133,186,172,125,40,231,244,212,252,195,226,60,73,65,199,56,111,147,159,11,252,221,56,79,225,93,63,78,106,55,180,165,219,221,137,45,15,216,189,26,164,163,225,254,157,199,238,35,148,190,199,227,155,44,252,85,53,29,53,201,251,19,195,114,117,124,49,211,164,247,116,99,253,142,220,239,199,39,102,170,207,31,83,10,173,69,252,76,170,41,173,75,216,182,71,61,92,71,82,82,88,108,138,83,75,71,161,147,138,47,110,130,85,99,230,77,47,169,152,121,146,105,234,206,78,220,142,198,145,127,36,199,26,157,69,28,119,63,212,121,146,199,212,204,251,76,123,112,198,174,49,117,102,150,146,103,143,252,66,184,169,75,160,247,41,225,74,162,76,246,82,138,201,227,255,0,16,169,197,120,102,82,146,238,74,164,87,245,49,206,250,111,197,251,199,194,124,75,77,207,167,84,106,73,165,169,225,233,97,212,143,220,250,15,136,105,37,210,174,179,191,110,159,169,243,234,73,55,21,238,116,241,219,120,183,254,68,202,253,67,248,63,221,31,195,106,15,58,249,245,63,45,79,95,123,230,75,167,220,118,75,231,242,229,219,247,193,227,255,0,8,36,167,248,121,15,85,113,36,255,0,161,237,43,199,186,214,170,79,87,6,145,158,85,231,143,205,238,82,242,59,178,219,89,79,220,249,229,194,95,21,85,127,188,207,165,221,91,171,122,117,40,107,242,54,177,249,159,54,184,93,183,181,87,164,217,60,86,187,249,166,72,250,247,224,44,59,60,75,118,227,183,194,189,87,174,81,247,165,57,107,135,143,99,225,159,128,145,75,175,95,233,171,183,253,53,71,221,163,21,131,124,156,14,233,109,150,101,230,77,105,157,134,10,150,76,234,175,124,158,126,102,76,203,31,83,47,104,192,211,24,230,94,172,74,82,245,102,88,208,99,65,249,24,225,238,109,104,222,73,41,75,27,179,147,176,146,142,154,162,126,70,53,234,185,42,19,121,122,69,159,159,186,151,116,239,171,205,203,95,50,89,251,228,253,11,56,230,156,215,179,63,62,245,52,213,245,228,86,152,175,47,238,115,242,95,113,233,240,125,124,231,196,177,237,234,205,55,151,218,155,55,188,11,23,47,26,244,104,240,239,41,127,241,35,87,197,75,29,93,63,88,38,111,120,1,119,120,231,162,44,124,206,242,159,110,61,123,145,222,126,175,55,147,246,175,214,211,111,226,94,125,79,207,62,41,163,31,246,159,171,99,57,248,134,245,63,68,213,105,93,77,165,166,79,132,120,218,206,54,254,44,234,26,38,230,212,181,247,56,115,229,142,191,227,205,229,143,146,117,152,184,245,41,166,189,207,93,248,59,44,126,37,116,151,37,140,74,88,255,0,202,207,41,215,35,219,213,27,203,214,57,61,55,225,92,212,127,16,250,52,155,199,239,146,127,99,211,63,93,115,229,251,87,234,202,142,94,99,89,216,69,181,44,153,84,135,239,165,135,201,99,28,173,142,122,140,82,215,70,73,69,247,103,39,42,138,14,35,71,15,108,176,59,95,174,14,84,151,36,192,213,96,214,22,56,36,116,71,38,6,48,52,113,202,57,49,167,28,182,154,211,7,43,212,137,225,234,52,124,19,197,148,37,254,210,245,12,175,155,204,254,152,62,119,226,154,126,93,42,50,107,118,210,62,169,227,58,78,62,42,234,10,90,102,73,175,208,249,111,139,150,33,69,235,149,45,184,57,120,173,252,222,191,45,255,0,198,243,148,177,230,211,215,248,145,251,102,130,83,181,179,123,70,84,33,255,0,194,143,197,52,34,157,90,82,124,77,127,115,246,197,20,149,173,166,63,238,97,143,209,29,249,188,47,129,120,190,45,248,199,170,201,60,71,205,194,215,216,240,126,43,147,116,45,214,115,243,61,79,163,248,226,159,103,140,58,154,75,71,83,56,252,145,243,143,21,233,66,213,115,150,217,195,199,127,151,183,171,151,232,232,45,99,254,34,147,79,95,50,63,220,253,175,74,13,89,218,199,210,140,127,177,248,162,217,102,181,56,236,156,210,254,167,237,200,127,213,237,223,255,0,178,143,246,59,215,153,98,146,88,50,75,115,40,235,186,46,48,99,85,143,110,72,226,247,212,228,254,228,105,133,113,56,235,177,143,107,206,14,102,99,129,163,7,22,96,215,7,59,246,70,13,106,93,28,56,253,220,253,90,193,240,11,152,55,113,112,159,110,60,217,52,177,198,79,208,77,124,178,251,51,224,189,75,189,92,212,115,135,110,103,38,210,126,231,62,87,220,122,127,198,158,235,197,120,174,24,233,208,120,93,189,216,60,111,111,200,253,143,103,226,252,254,204,134,154,121,155,158,59,183,247,76,235,194,239,29,114,255,0,35,247,126,193,240,140,59,60,7,225,245,235,99,73,255,0,238,163,207,126,39,83,156,186,109,157,85,180,106,97,158,163,195,49,237,240,95,64,139,222,61,62,130,127,249,17,212,254,33,208,243,60,54,170,127,221,207,184,231,207,166,124,95,180,124,74,254,56,181,184,114,250,92,31,228,124,229,124,210,120,90,100,250,61,219,82,178,171,153,106,233,183,175,39,206,237,96,234,86,80,247,53,225,187,26,255,0,35,184,253,13,255,0,71,200,39,225,78,175,61,179,117,21,250,68,250,220,99,166,199,201,191,232,254,212,124,53,214,41,61,49,120,191,248,81,245,197,174,168,215,42,227,22,49,69,113,198,166,81,88,89,26,62,76,171,22,178,182,10,57,70,79,109,9,134,214,140,9,218,140,92,76,218,0,113,52,70,142,70,140,121,212,106,190,107,248,151,31,46,246,217,181,245,197,175,177,243,11,216,41,80,168,155,211,15,84,125,115,241,46,140,170,211,183,148,82,203,79,87,193,242,139,133,219,70,225,73,167,251,169,47,179,193,230,190,185,215,183,199,239,199,143,152,78,63,226,39,143,230,62,243,255,0,71,136,56,244,159,17,79,249,171,209,143,232,165,255,0,51,225,20,98,234,93,53,186,109,159,160,63,232,251,24,175,15,245,213,207,198,69,63,202,7,183,151,79,13,237,244,158,189,28,248,118,253,71,234,242,153,240,91,138,126,84,220,37,29,119,193,250,3,171,67,204,232,247,112,90,55,7,169,240,142,165,75,178,187,92,167,134,121,124,183,220,122,60,31,95,60,241,76,59,58,175,102,127,133,51,123,240,222,143,157,248,147,225,200,227,61,183,212,231,175,179,207,250,29,127,138,115,46,175,150,177,136,37,131,188,252,45,77,126,37,248,121,37,189,119,255,0,194,207,95,31,209,195,201,251,87,235,39,245,183,206,66,213,234,100,227,243,50,165,167,185,202,178,138,40,184,47,5,219,80,168,144,193,150,228,3,22,137,131,50,1,131,70,175,81,199,236,155,148,214,157,143,63,161,182,210,53,122,148,92,250,85,212,35,187,166,240,69,157,190,5,212,100,229,82,82,75,76,158,15,197,178,78,173,60,60,190,211,223,245,47,150,165,76,44,124,239,76,31,61,241,86,21,228,34,184,142,167,63,7,236,246,121,255,0,245,186,255,0,14,82,117,124,83,210,33,140,247,94,209,88,255,0,198,143,214,222,54,238,126,27,189,236,90,167,157,189,207,202,254,15,164,223,140,186,3,81,210,87,212,191,248,145,250,207,196,212,93,110,131,121,8,111,218,240,118,242,60,92,59,143,207,215,105,206,74,41,97,122,30,15,196,159,55,83,147,229,104,207,161,117,106,125,149,231,21,36,179,202,62,123,226,40,165,214,42,164,242,184,57,120,111,242,122,188,221,61,15,225,12,63,254,104,244,79,248,230,255,0,247,36,126,176,198,175,239,161,249,83,240,130,13,254,40,244,127,101,81,175,202,12,253,86,183,59,121,59,121,89,196,184,34,50,224,192,155,140,23,36,231,66,131,68,46,51,185,82,212,12,25,139,88,57,30,230,50,3,168,241,28,92,250,37,93,113,131,226,23,249,243,167,223,179,223,7,221,250,221,63,55,163,215,130,120,204,119,244,62,27,214,41,118,94,87,229,55,161,199,159,174,79,87,248,253,87,207,60,84,255,0,121,23,159,151,24,88,58,11,8,249,151,246,177,199,213,94,11,250,163,190,241,82,93,240,138,221,234,205,31,15,217,187,142,187,210,105,201,124,181,111,41,195,255,0,121,30,174,63,175,183,15,55,238,253,103,227,8,185,116,58,242,73,252,173,188,35,225,23,212,188,169,201,39,150,219,63,66,245,184,121,221,42,233,118,231,11,68,124,11,174,209,149,189,74,178,198,35,39,133,236,121,121,207,229,174,222,11,63,26,249,117,239,253,114,170,231,185,159,88,255,0,163,211,199,138,58,196,115,189,156,95,254,241,242,123,228,149,237,70,180,215,39,213,63,232,255,0,167,141,122,146,122,103,167,55,255,0,239,33,255,0,51,211,127,87,146,246,253,23,29,140,247,70,43,68,140,145,201,165,89,104,103,213,21,237,146,110,138,163,107,128,158,9,176,215,96,43,193,115,236,201,22,100,6,167,79,151,127,74,180,150,115,154,81,254,200,216,224,210,232,175,61,10,193,233,151,66,57,253,13,212,90,47,4,192,35,120,32,2,110,176,198,192,31,169,139,198,76,204,113,171,32,227,103,205,255,0,20,103,138,157,54,155,211,46,114,207,219,7,210,100,176,179,185,243,95,196,232,42,149,250,123,107,229,140,106,55,253,12,121,57,103,23,79,15,239,31,158,124,65,154,157,106,172,155,202,201,235,63,10,168,42,158,55,233,203,79,150,110,88,245,194,60,127,89,79,246,181,84,247,79,7,186,252,33,167,223,227,187,36,150,176,132,166,223,229,143,245,59,239,240,142,124,189,242,126,132,235,148,126,39,163,245,10,73,103,205,165,56,254,168,252,175,226,120,121,116,86,50,218,151,107,103,235,73,165,39,53,140,166,153,249,115,198,118,110,207,227,45,158,90,165,115,37,22,247,122,156,103,174,114,186,248,253,240,177,228,172,154,141,196,91,231,67,246,7,132,229,230,120,59,166,79,149,70,41,254,135,227,235,72,186,149,99,20,240,242,143,214,127,135,119,14,227,192,125,58,78,74,82,138,112,111,236,206,252,220,94,158,41,25,22,49,200,228,230,169,128,150,187,151,56,39,118,0,96,123,149,239,146,61,128,152,35,91,25,45,158,73,192,24,99,212,242,254,57,165,231,120,94,234,11,116,227,47,234,122,150,247,58,174,191,111,27,142,131,121,20,181,84,219,199,219,82,94,154,225,235,148,126,122,235,248,125,38,242,50,250,251,55,62,115,111,171,90,159,74,235,80,115,233,151,46,75,18,116,222,79,157,217,83,85,52,198,200,233,224,191,198,186,255,0,149,251,71,233,79,193,138,157,254,1,156,118,106,225,183,250,30,251,179,186,47,92,101,31,51,252,14,175,230,120,83,169,67,57,242,238,18,199,166,81,244,248,173,190,198,121,60,209,249,247,173,209,116,58,175,82,161,219,251,200,86,150,51,233,185,242,171,152,183,127,95,185,188,247,179,237,126,52,183,118,222,40,190,147,237,139,168,148,163,158,116,62,51,94,46,125,90,186,211,46,161,60,51,222,61,31,228,126,156,107,235,127,128,127,254,51,93,229,239,110,214,63,71,254,135,222,210,89,211,67,243,255,0,224,139,118,158,62,175,109,36,211,118,242,95,211,39,232,52,158,184,216,223,39,154,42,77,160,147,193,154,38,12,52,137,104,76,54,103,141,9,169,85,128,107,8,200,50,12,90,49,102,76,197,189,53,64,97,37,163,247,71,192,122,196,35,30,167,123,31,226,117,229,143,212,251,254,242,212,248,119,138,173,190,31,196,23,208,195,89,168,228,179,232,206,92,251,143,71,130,251,177,242,127,20,231,246,194,206,253,136,220,240,36,167,15,27,116,121,211,95,52,110,96,215,234,106,120,159,47,172,71,45,60,197,35,178,240,52,124,191,28,244,54,211,249,174,160,191,169,233,255,0,171,207,228,159,202,191,89,214,249,107,254,122,159,31,252,76,180,118,222,34,85,222,92,43,211,88,126,141,31,97,174,191,196,75,57,220,240,159,138,86,62,111,70,161,116,162,159,151,60,55,247,56,121,58,111,195,115,147,243,127,136,86,58,154,114,230,58,29,183,225,229,95,134,241,215,71,171,45,34,171,199,251,154,30,41,135,248,187,121,52,147,112,195,253,77,254,131,103,82,207,169,116,30,160,214,41,213,189,84,227,47,120,184,183,253,209,223,141,254,12,121,63,122,253,121,85,98,175,247,50,89,206,196,155,238,148,126,200,191,99,8,1,253,134,114,128,166,47,109,202,217,51,141,200,166,9,177,117,220,110,81,143,36,211,187,83,39,140,145,174,64,249,15,226,37,179,167,226,87,89,44,41,82,90,250,159,32,241,115,125,180,244,209,179,239,95,137,214,149,59,109,47,33,12,197,119,70,120,92,104,124,59,198,144,238,180,183,171,21,163,147,90,24,241,204,242,61,87,223,135,94,114,22,242,135,75,248,142,221,35,85,71,62,231,236,219,89,127,129,178,207,253,204,63,177,249,94,211,166,194,191,225,39,84,234,31,246,150,183,180,112,253,164,241,254,167,234,62,151,87,206,232,125,54,175,243,218,211,155,127,120,163,191,145,227,250,249,39,226,45,188,168,248,186,173,103,244,214,166,154,246,107,67,229,126,45,134,41,80,158,249,147,77,159,111,252,82,180,140,107,90,220,165,164,190,86,207,137,120,191,14,222,223,181,237,39,148,121,184,111,230,245,114,255,0,212,243,182,233,121,212,242,244,239,95,220,253,183,109,137,88,90,73,60,230,132,30,127,240,163,241,141,173,148,234,116,122,151,144,89,141,41,198,50,126,153,103,236,158,155,36,250,63,78,255,0,253,106,107,255,0,117,30,142,126,158,88,219,94,230,72,137,35,44,106,97,83,11,35,6,68,3,23,236,98,204,217,142,50,21,142,12,89,159,6,45,106,6,24,203,195,216,248,159,137,97,229,117,155,136,78,9,126,245,168,175,109,207,183,60,69,172,159,36,241,245,156,169,117,233,215,206,32,215,119,230,113,242,239,199,163,252,123,156,177,242,143,24,183,251,58,154,105,227,205,209,250,158,78,20,220,173,103,56,197,246,199,118,122,223,25,127,248,62,147,206,142,89,70,125,15,164,70,231,240,171,196,29,81,227,54,213,169,67,94,50,215,252,206,254,41,252,28,191,200,187,205,250,91,195,105,255,0,177,221,19,56,238,86,52,83,255,0,200,140,60,75,103,241,158,29,186,165,140,190,198,209,205,225,207,155,194,189,29,181,143,240,116,180,255,0,194,142,194,173,40,213,165,58,114,218,81,104,205,99,141,203,175,205,29,74,131,116,42,166,181,80,120,60,95,135,169,43,143,17,217,208,113,238,85,171,168,56,253,222,15,160,120,130,143,100,239,41,73,118,186,114,148,63,67,195,248,55,49,241,199,73,211,63,226,163,167,230,79,241,239,170,239,254,68,204,175,185,254,5,83,116,250,111,136,233,201,97,211,191,237,199,167,202,125,106,11,49,201,242,111,193,186,201,95,120,217,55,133,251,73,73,47,77,103,255,0,161,245,138,45,78,158,81,190,93,188,209,154,215,65,162,46,196,127,208,202,152,39,177,94,116,192,101,83,130,53,238,80,210,3,6,145,139,75,38,68,96,121,31,196,27,74,149,250,44,42,211,142,101,74,90,159,21,191,142,40,215,206,159,43,216,253,7,226,74,14,227,195,151,144,95,87,110,231,192,122,141,188,163,109,113,219,53,217,24,73,229,51,143,46,63,203,94,175,15,47,227,143,159,120,122,206,87,254,37,180,178,166,179,42,245,148,23,230,125,235,240,54,219,225,122,111,136,169,183,137,70,251,178,75,237,5,255,0,54,124,103,240,231,18,252,74,232,121,120,95,22,155,201,246,239,193,136,169,90,120,166,81,207,255,0,133,100,147,245,73,97,30,158,125,60,119,183,210,42,67,204,163,86,47,248,162,209,240,62,175,70,116,111,43,169,60,73,212,120,251,100,251,242,194,150,175,67,227,94,51,162,169,117,218,212,35,134,243,223,159,76,158,126,126,241,232,255,0,31,187,31,26,241,37,71,62,175,45,120,61,127,225,133,163,143,143,124,47,89,175,243,103,82,73,253,161,47,249,30,59,196,185,253,177,86,50,214,81,208,250,215,135,169,80,176,241,7,225,122,165,162,169,103,82,164,253,114,212,219,254,173,158,137,250,184,115,253,171,239,175,234,220,184,56,45,170,42,242,151,111,27,155,50,88,208,194,49,0,161,66,0,0,141,100,160,12,94,199,21,101,223,109,90,27,230,15,67,153,238,97,140,203,11,76,232,21,240,95,16,83,116,175,39,28,36,242,244,193,242,239,20,73,75,168,67,60,67,15,220,251,71,141,108,59,58,165,94,212,211,203,203,62,39,226,137,41,117,106,137,109,20,114,240,113,179,149,122,188,220,183,199,30,143,192,118,56,241,127,132,170,84,139,237,175,117,223,21,236,179,175,234,143,212,183,180,188,251,59,136,61,115,23,161,240,111,10,218,211,135,138,127,13,104,198,58,252,28,234,203,221,191,53,163,244,14,19,114,202,211,24,59,243,237,227,226,252,233,226,42,51,163,127,56,182,187,118,252,207,154,117,215,21,212,234,99,250,159,94,241,164,233,174,173,117,26,120,81,132,218,246,62,59,214,245,234,21,27,228,243,248,127,103,171,203,250,189,247,225,21,149,75,127,196,158,131,57,193,197,85,161,90,164,91,229,121,114,215,250,51,244,222,114,244,62,31,225,72,198,63,137,94,10,165,78,10,42,29,2,50,146,247,116,234,38,255,0,169,246,154,117,83,187,84,20,190,127,229,246,59,249,59,121,163,109,34,150,75,128,244,50,39,3,130,180,78,10,32,228,15,112,6,47,99,34,48,56,46,97,230,90,86,135,172,89,241,15,16,209,84,175,171,60,53,223,39,171,255,0,145,247,61,162,211,215,39,201,252,99,96,169,222,73,54,245,147,121,246,57,242,227,182,87,127,5,203,143,135,248,163,254,182,150,117,126,251,29,247,132,122,60,149,199,131,111,94,123,110,250,167,106,255,0,195,52,142,143,197,202,49,234,47,182,89,193,244,111,11,194,10,151,225,101,135,116,124,201,92,86,185,107,156,121,205,175,236,122,39,190,56,229,229,253,235,239,117,169,249,182,245,169,189,164,154,62,19,227,27,57,82,189,148,100,146,75,215,212,251,242,131,134,101,166,51,147,226,127,136,113,132,239,170,201,67,26,54,217,230,229,235,29,124,31,99,225,157,77,118,221,85,202,215,39,216,255,0,6,250,127,236,223,28,87,114,202,243,122,82,146,202,245,113,103,198,250,166,93,212,245,219,124,159,161,60,37,8,211,252,70,180,183,167,140,255,0,179,244,101,38,190,209,255,0,153,223,149,254,14,60,187,125,106,47,49,71,34,216,235,149,103,70,238,149,9,127,27,194,59,57,124,175,12,231,46,162,51,30,12,147,202,35,208,170,196,114,62,229,91,129,146,220,164,201,64,235,122,27,95,236,253,138,75,69,70,41,126,134,250,195,58,207,14,203,191,195,118,19,245,165,31,236,118,94,184,53,75,218,243,236,97,45,89,150,116,49,193,144,224,39,171,208,50,99,220,11,146,173,22,164,251,134,6,18,120,77,159,55,252,68,154,157,156,113,254,116,103,167,252,60,159,72,151,210,207,1,227,235,55,59,90,115,130,215,47,187,236,114,242,254,174,158,47,217,249,183,170,102,175,91,174,222,237,236,125,11,240,122,57,241,245,164,18,95,68,212,191,242,183,253,207,11,115,73,75,196,245,224,255,0,159,26,159,76,252,61,178,159,73,252,82,183,180,168,187,106,66,45,191,124,193,224,244,127,214,49,207,215,39,222,103,73,169,54,177,179,63,56,126,35,211,114,185,234,51,148,123,100,170,101,31,164,52,114,105,189,25,240,111,197,123,15,35,171,221,174,237,43,65,77,46,15,61,151,242,141,120,239,111,146,116,248,55,150,183,237,206,79,209,255,0,133,23,147,159,128,92,115,254,85,195,77,251,97,31,21,252,58,232,244,186,231,95,163,99,90,45,198,170,148,50,184,120,120,62,183,248,46,219,232,125,107,165,220,97,74,222,186,132,163,157,83,93,201,255,0,99,209,228,220,115,125,106,223,231,182,133,69,170,146,206,65,173,209,229,42,118,83,182,156,187,188,169,98,47,216,218,121,49,61,193,142,116,92,13,132,137,201,85,114,242,64,255,0,160,32,50,13,216,3,9,28,119,20,227,90,141,74,50,89,85,32,226,245,225,156,205,28,114,121,121,3,224,126,35,181,84,42,94,219,77,44,198,50,138,62,119,225,27,55,125,214,105,89,118,230,85,59,227,21,239,218,223,250,31,97,252,72,233,255,0,13,214,103,115,20,149,42,244,251,158,87,241,35,229,62,9,187,141,143,226,15,75,148,254,133,125,77,63,179,150,31,244,99,193,235,99,183,154,206,82,88,250,231,224,76,162,236,122,213,170,120,169,25,198,78,47,140,54,143,173,121,51,107,78,212,207,147,126,26,202,29,59,241,75,197,253,58,58,71,207,170,227,143,69,55,143,232,207,172,170,184,150,143,44,114,237,231,143,145,254,34,70,21,186,194,204,82,149,40,246,201,224,248,125,244,35,15,16,220,40,109,223,148,125,255,0,241,46,217,211,186,182,188,135,111,101,88,184,201,115,147,224,30,32,194,235,179,156,118,105,61,62,199,63,7,43,249,217,94,143,39,191,28,175,175,248,118,209,116,143,198,27,8,209,250,47,108,105,84,79,213,74,26,191,213,31,107,233,247,10,233,215,138,214,84,164,227,35,225,157,55,171,199,253,191,240,61,228,177,24,213,233,148,169,60,251,55,19,237,149,148,186,103,82,85,168,255,0,151,113,89,83,156,61,222,204,235,202,229,121,227,177,193,26,208,206,162,249,153,131,206,66,166,161,153,63,98,125,200,169,193,55,50,111,58,17,129,139,216,140,201,253,137,187,3,13,164,159,163,62,81,248,141,101,229,248,134,149,109,149,106,124,122,163,235,15,25,212,241,127,136,86,31,19,211,40,93,194,154,114,161,45,223,9,152,231,214,187,120,57,103,56,252,225,226,200,42,61,110,154,75,63,42,61,93,167,74,167,211,186,143,128,111,169,212,77,223,87,147,147,92,118,214,75,251,51,202,248,209,185,245,58,114,198,62,76,29,197,183,93,167,90,203,192,244,22,181,122,109,213,110,245,237,42,144,146,255,0,83,191,11,188,53,203,201,251,215,234,202,244,115,82,79,187,83,201,254,32,81,238,240,220,168,203,233,158,189,223,203,141,79,83,113,62,202,188,236,117,157,114,211,246,167,65,187,182,139,196,229,7,218,253,207,63,63,114,195,135,113,249,83,198,20,123,29,188,247,77,180,153,235,234,90,81,151,224,127,72,234,52,230,190,38,151,91,79,43,248,115,6,177,237,244,166,121,95,22,39,43,88,66,95,85,57,181,255,0,51,30,157,215,101,15,1,117,30,131,60,180,239,104,94,81,126,142,57,140,191,163,95,161,219,197,191,132,213,243,76,231,113,250,173,117,56,197,216,247,166,149,122,81,113,127,145,220,206,29,184,76,240,246,53,151,84,240,39,135,239,233,235,90,157,173,54,254,233,36,255,0,177,238,99,87,207,183,165,85,109,56,38,115,227,203,109,149,155,61,56,214,163,146,236,202,109,24,191,238,79,190,197,121,33,20,124,7,176,43,2,45,72,209,121,35,216,15,59,227,91,9,223,120,102,233,83,111,190,17,238,93,187,233,169,249,219,198,177,81,232,244,90,151,115,239,220,253,77,82,49,169,9,65,237,36,211,63,53,126,38,88,86,177,156,237,37,79,178,48,147,112,127,204,179,185,37,206,113,211,143,47,225,99,167,232,125,78,138,252,39,241,79,75,156,159,157,58,246,213,96,189,82,154,201,250,107,194,143,207,240,79,135,234,73,234,250,125,20,255,0,40,36,126,58,181,170,227,109,113,77,75,10,113,89,94,184,63,89,254,29,221,70,191,225,167,135,230,158,113,111,216,254,241,109,29,124,157,56,186,47,197,10,210,133,27,122,14,41,193,203,185,75,147,225,190,47,88,178,165,36,181,239,213,159,124,252,80,141,47,216,84,171,75,30,100,38,146,247,62,3,226,154,177,171,98,241,196,147,60,254,57,255,0,145,233,255,0,241,214,223,66,242,255,0,251,43,241,44,223,249,176,185,181,138,251,57,55,254,135,233,94,159,90,179,240,111,74,186,167,243,55,105,78,79,31,240,163,242,63,76,234,21,168,244,142,167,96,165,251,155,159,46,114,143,172,160,222,63,248,153,250,171,193,189,90,131,252,57,232,149,43,79,182,63,7,24,54,253,82,199,250,29,124,252,167,25,181,195,143,191,111,81,99,94,55,93,62,149,196,26,106,107,92,122,156,200,234,252,51,79,203,233,213,161,20,251,60,215,40,126,122,157,182,9,199,220,148,189,166,228,43,246,35,216,168,141,104,66,189,200,6,36,50,35,89,10,227,154,202,60,47,226,71,79,85,109,104,222,38,211,142,141,46,79,120,244,58,159,17,216,71,169,116,122,212,177,153,37,147,28,166,198,248,92,229,175,204,222,51,211,164,80,79,120,213,213,19,161,245,111,135,252,32,241,71,79,114,95,189,190,180,81,143,58,247,183,255,0,240,209,205,227,170,50,163,102,161,81,97,170,172,241,148,107,118,244,251,138,27,121,142,50,251,181,156,127,118,117,240,254,169,229,253,159,179,188,57,40,63,10,116,87,140,119,88,209,120,244,249,17,189,90,154,169,70,162,134,147,81,125,172,233,60,45,85,63,4,244,10,141,234,250,117,7,255,0,238,209,217,206,242,149,52,156,171,66,57,254,103,131,157,177,153,31,159,252,70,166,238,46,213,118,157,78,249,185,123,179,231,94,23,156,169,248,199,166,202,18,237,106,230,45,55,247,62,133,227,107,200,220,120,146,246,84,113,228,101,165,142,94,53,62,99,97,37,75,175,81,105,246,165,93,126,90,143,241,254,187,255,0,145,118,71,220,191,10,170,202,157,111,27,78,15,230,87,17,154,94,186,204,250,231,133,235,202,239,195,244,235,79,89,185,205,63,178,147,199,244,193,240,191,194,110,167,78,219,168,120,154,19,107,247,148,227,60,55,190,36,207,176,120,2,234,23,125,14,189,106,50,205,25,213,114,130,244,227,31,170,28,175,254,71,158,116,244,250,228,6,13,2,68,46,227,144,25,35,1,1,12,113,194,51,49,34,184,170,210,85,105,206,155,218,73,163,225,126,51,177,253,153,59,232,181,219,25,66,73,63,118,125,217,159,46,252,91,233,114,125,38,181,229,56,101,184,103,41,108,209,44,219,29,60,124,179,95,0,240,189,227,176,241,119,79,187,75,252,154,221,199,222,127,1,110,60,206,141,226,25,61,100,250,135,123,252,209,249,210,156,251,46,123,160,245,79,41,251,159,160,63,0,42,184,248,119,172,183,22,251,175,62,175,117,21,255,0,51,167,146,228,113,125,121,184,77,78,13,44,180,124,63,198,31,225,186,189,204,103,46,233,70,120,111,58,254,103,213,250,167,91,180,233,116,254,38,238,175,149,79,56,203,62,19,226,158,179,75,171,117,219,235,139,118,252,154,179,78,45,243,133,131,205,202,203,219,209,225,150,123,124,243,175,203,191,169,212,159,169,244,207,14,191,136,241,143,128,104,74,166,148,250,122,89,207,211,172,223,250,159,47,235,95,245,249,158,143,195,253,101,244,190,189,210,58,133,199,116,227,105,73,36,147,215,24,120,95,212,244,255,0,249,184,242,253,223,168,60,51,85,194,242,250,214,114,114,121,239,139,126,135,125,45,89,243,207,194,142,179,63,16,210,187,190,155,93,208,147,132,180,253,15,161,203,57,57,120,255,0,83,151,104,208,67,28,131,104,158,224,104,0,96,152,46,64,24,179,28,107,156,153,179,30,72,60,7,143,172,42,58,142,226,156,114,156,91,108,252,207,215,228,223,80,158,185,203,224,253,117,226,123,31,143,232,181,233,71,61,241,77,172,114,126,74,241,68,21,30,181,90,148,83,88,147,88,127,115,94,57,252,171,124,185,127,28,125,87,193,23,148,175,127,19,252,29,70,146,196,44,122,63,100,179,252,221,147,111,255,0,136,251,227,170,187,218,246,63,51,254,16,198,117,191,18,109,101,22,228,233,89,84,147,199,11,9,127,169,245,255,0,25,120,195,246,12,60,186,61,178,185,154,202,82,90,34,121,121,101,103,135,29,175,157,248,230,165,56,120,130,238,218,159,205,229,207,230,126,169,234,124,127,171,79,186,254,170,75,9,109,238,123,158,163,123,90,250,250,165,220,244,173,85,230,77,114,207,11,213,226,215,82,170,189,206,94,15,218,187,121,255,0,89,31,89,252,48,234,14,251,241,67,164,212,155,207,195,244,127,41,103,140,67,31,234,125,134,135,82,165,95,198,84,105,209,154,109,197,169,165,236,143,204,62,24,235,55,93,19,169,195,168,218,84,81,184,141,39,73,54,179,163,62,185,248,53,119,117,214,188,89,212,111,46,166,231,42,84,147,111,26,101,191,253,13,249,102,242,142,124,100,252,109,125,186,81,105,178,25,205,234,96,105,132,224,133,15,64,38,227,3,35,32,77,153,25,67,64,97,45,176,120,239,25,116,232,213,106,191,110,22,62,175,115,216,203,103,147,175,234,182,170,239,167,212,164,241,221,143,151,35,227,92,57,101,215,228,47,21,169,46,177,89,103,47,44,246,127,135,151,127,29,248,133,225,26,17,78,74,198,140,227,246,194,148,159,245,103,146,241,173,41,91,248,166,234,140,176,165,25,181,36,184,121,61,127,224,101,15,137,252,70,114,107,187,200,176,171,81,103,141,99,31,255,0,56,233,255,0,86,57,93,175,209,85,250,141,59,120,202,50,154,83,124,51,226,255,0,136,29,78,141,207,82,169,78,221,103,68,155,143,169,235,188,119,123,115,70,147,165,71,49,139,210,114,91,159,44,173,74,85,46,98,181,213,242,120,185,114,252,189,61,94,30,23,183,207,58,158,151,53,87,57,62,225,225,30,163,10,127,136,246,149,93,68,151,251,55,111,174,121,84,233,182,143,138,117,184,184,245,58,209,255,0,121,157,215,75,234,87,112,234,84,107,82,125,181,99,110,168,167,191,203,140,30,158,94,248,56,95,124,177,250,106,223,171,218,222,223,84,163,231,70,87,22,208,87,24,79,63,38,112,207,82,166,170,69,77,108,214,81,249,175,192,21,239,105,120,178,52,233,247,84,157,221,41,208,159,186,122,231,250,31,164,105,83,116,168,82,166,222,176,138,139,253,14,62,47,173,115,227,35,62,52,24,26,131,171,154,52,16,1,89,2,101,224,97,250,129,210,248,78,106,94,16,233,110,47,71,66,63,216,238,22,231,67,224,182,255,0,216,142,146,155,203,84,34,178,119,169,179,84,19,203,13,176,70,64,100,40,228,2,5,111,76,24,236,6,51,60,95,141,238,41,82,233,109,212,127,197,163,108,246,115,206,25,224,124,127,107,26,253,6,189,73,69,191,47,85,131,151,151,245,173,248,255,0,103,231,75,250,145,135,137,171,205,188,102,162,104,250,237,135,116,127,27,104,84,143,205,9,219,210,148,101,234,157,51,227,125,77,37,212,37,55,191,185,244,143,1,222,206,231,241,31,166,58,141,205,42,80,167,25,73,231,9,68,237,63,72,199,63,219,219,239,181,107,58,115,148,146,105,35,227,223,138,151,148,174,175,97,75,181,170,177,167,202,221,31,105,186,183,141,71,82,26,166,246,103,231,255,0,25,88,220,71,169,221,66,172,157,73,70,122,54,245,193,231,231,114,205,107,198,242,95,135,93,106,61,15,198,221,61,212,105,82,87,81,82,111,132,222,63,212,250,15,135,250,173,47,14,254,46,120,162,194,85,20,104,215,184,169,56,250,54,218,154,95,164,153,241,139,216,212,183,234,143,31,43,82,79,40,244,93,74,173,75,207,22,219,221,41,203,204,186,84,164,228,158,173,225,71,253,15,85,247,25,250,253,93,210,103,27,139,79,137,167,135,10,169,73,51,111,156,29,111,132,237,167,103,225,91,58,53,115,220,147,223,211,58,127,67,179,151,177,143,137,245,143,38,57,213,153,60,228,156,145,81,236,56,216,185,208,99,76,1,22,67,70,79,131,23,184,16,194,113,202,206,12,240,89,109,130,81,226,63,16,186,114,189,240,237,89,246,119,78,140,92,147,75,99,243,12,230,236,250,212,107,69,181,40,212,83,88,221,52,242,126,188,235,206,43,167,87,140,190,153,83,107,238,207,201,62,37,181,118,125,98,81,107,13,188,151,199,115,150,45,253,95,79,252,56,235,113,185,252,95,185,184,159,255,0,212,21,73,55,198,90,79,253,15,183,214,81,167,23,39,39,21,157,207,204,31,135,215,209,183,241,207,69,175,55,219,221,94,20,219,246,111,31,234,126,167,188,179,166,220,226,226,241,47,66,243,98,87,203,191,18,239,161,10,54,180,105,85,141,76,183,149,202,103,194,188,66,187,122,140,36,214,189,186,159,74,241,109,172,233,245,155,152,212,169,41,226,111,179,47,100,124,227,196,20,187,37,25,103,58,227,44,243,127,143,119,157,175,95,146,89,227,198,237,14,175,36,250,13,195,156,155,177,88,109,110,146,155,146,254,231,233,79,14,248,142,135,137,107,212,139,169,23,56,86,165,86,17,91,227,180,252,157,70,89,160,150,216,103,232,127,193,62,149,74,226,221,245,105,77,247,211,130,130,137,237,231,199,94,73,113,245,185,234,219,48,195,212,206,91,178,28,234,198,30,196,193,155,34,216,138,199,159,114,236,245,32,200,2,23,36,123,129,139,52,186,141,164,111,108,43,91,75,24,156,26,195,55,94,198,45,172,140,223,75,43,242,71,143,109,170,90,117,121,210,168,147,116,230,225,149,182,135,157,181,173,56,212,167,36,241,40,77,73,63,177,237,191,21,40,170,94,33,234,105,127,13,222,127,243,71,187,253,79,11,111,172,150,118,206,230,252,83,241,241,227,60,238,221,126,211,187,185,243,232,82,171,8,60,78,17,154,251,52,116,29,111,196,150,157,42,194,163,173,85,83,159,150,228,147,229,250,29,247,68,175,241,158,25,232,215,13,38,235,89,81,155,202,245,130,62,127,248,173,101,242,80,185,86,202,80,73,169,53,177,230,242,75,187,27,241,251,175,135,248,146,183,197,198,173,116,177,25,73,203,12,243,246,149,84,99,40,189,164,143,83,212,168,185,89,87,249,116,81,60,109,39,137,163,209,225,253,113,124,246,206,90,251,23,130,60,121,240,175,161,116,155,250,146,141,172,103,42,82,156,101,132,178,254,92,175,77,79,208,214,141,46,159,73,45,86,52,107,208,252,103,99,243,58,125,186,181,53,175,166,167,236,30,130,229,47,12,244,202,147,207,116,237,225,44,62,50,145,137,199,57,91,24,183,99,123,57,100,212,45,254,198,79,99,72,197,229,163,29,118,51,126,166,60,145,66,189,0,126,160,52,35,69,206,228,3,138,167,202,178,124,79,241,194,205,252,29,157,242,95,42,148,161,37,203,109,127,232,125,174,191,208,207,147,254,52,40,203,195,116,148,162,242,231,136,191,200,110,86,163,243,189,29,92,158,218,31,169,63,9,231,43,143,194,222,153,40,60,121,114,169,31,205,73,159,150,105,231,185,175,200,253,61,248,25,93,92,254,25,42,60,208,187,169,7,249,225,255,0,169,211,159,183,61,121,79,196,31,19,87,190,234,11,166,60,186,116,31,115,207,50,62,103,214,227,41,116,218,141,239,220,153,244,47,27,116,186,182,157,126,242,165,72,60,73,230,45,114,120,110,177,67,187,166,213,147,82,209,104,121,188,127,182,189,62,191,227,200,242,52,37,171,94,168,247,61,59,197,253,90,135,66,177,233,78,186,248,42,114,237,113,75,84,155,216,240,116,158,37,238,122,142,129,217,90,250,198,148,181,243,46,33,22,159,221,30,143,47,25,203,143,184,225,227,185,95,177,58,125,189,59,94,155,111,74,146,249,21,56,239,246,57,25,200,160,169,209,132,22,138,49,73,28,108,153,140,177,193,30,75,134,70,194,163,102,47,83,38,98,214,132,83,130,20,143,80,49,102,13,39,148,214,114,176,114,51,142,75,77,2,191,62,254,50,244,185,244,218,109,249,107,201,157,84,227,44,172,189,61,15,144,82,250,101,246,63,66,126,63,210,131,240,157,141,110,223,157,93,118,231,219,181,191,244,63,60,211,202,82,199,161,175,12,201,98,115,187,95,177,188,25,79,226,60,3,225,233,39,140,88,82,143,233,28,127,161,229,255,0,16,111,170,91,209,242,41,211,75,11,86,158,25,233,255,0,15,235,42,159,134,254,30,148,86,159,9,21,250,104,117,190,63,232,209,189,178,87,48,130,238,140,94,101,232,121,188,220,109,233,175,29,146,251,124,34,225,212,173,82,93,210,122,232,178,120,153,75,202,234,189,207,85,26,171,63,169,244,59,175,146,93,221,184,111,134,124,238,245,118,245,10,171,253,243,175,248,253,186,255,0,147,238,71,111,111,86,162,175,112,168,206,81,141,88,226,73,60,100,253,27,248,30,234,75,192,13,212,77,69,87,148,98,253,81,249,162,201,201,212,120,120,202,63,77,254,9,87,85,191,13,105,83,81,195,163,115,86,13,250,190,236,255,0,102,117,229,61,184,127,213,244,17,192,6,16,193,3,34,208,10,76,21,232,52,69,16,143,98,189,140,94,168,131,22,143,41,227,218,74,191,131,250,140,63,137,209,150,63,67,213,200,242,254,57,140,229,224,206,172,169,231,204,141,180,220,113,246,35,81,248,250,47,182,163,199,12,253,27,255,0,71,168,198,126,14,234,255,0,205,251,65,229,123,121,113,63,57,246,254,247,30,187,159,162,63,232,240,187,60,55,214,164,255,0,138,242,63,210,7,78,115,211,156,101,248,137,70,229,245,39,31,157,219,99,72,183,162,103,205,107,80,237,146,138,216,251,231,141,186,108,110,172,103,116,155,196,35,243,44,109,238,124,70,254,156,99,93,184,182,243,177,226,229,44,175,127,138,203,197,224,122,242,79,171,84,199,211,133,130,219,84,115,149,53,39,157,48,140,122,218,255,0,218,18,56,237,37,219,94,156,179,161,237,227,250,60,92,191,119,233,223,192,155,72,209,252,60,119,41,46,251,139,170,173,191,100,251,127,208,250,60,183,62,127,248,28,218,252,51,163,22,246,185,173,143,179,150,127,212,247,237,229,153,157,23,179,114,108,56,28,1,25,10,204,118,68,25,33,177,22,197,96,66,50,189,136,214,0,211,184,73,169,101,233,131,241,215,139,167,230,248,195,171,73,125,42,234,162,138,246,238,103,236,91,197,254,26,164,191,150,45,179,241,159,93,171,26,254,32,190,173,31,166,117,230,215,234,111,129,95,76,252,2,167,26,158,61,190,147,255,0,179,233,114,215,221,212,166,123,79,29,244,199,251,66,180,170,102,106,166,28,52,217,30,91,254,143,52,51,226,14,185,117,255,0,119,105,10,127,249,166,159,255,0,154,125,115,197,221,59,227,58,107,175,5,251,202,126,158,135,47,47,187,113,175,29,202,248,21,106,49,165,93,99,70,159,39,129,234,211,239,234,149,229,254,241,244,158,165,136,87,105,181,187,62,103,212,95,117,253,87,254,243,39,131,187,174,222,126,163,115,165,98,165,117,7,182,50,125,235,240,14,221,70,167,136,43,227,73,121,49,139,245,199,126,127,208,248,39,74,249,107,73,255,0,187,177,250,19,240,30,107,246,111,82,166,247,140,211,127,159,255,0,66,242,191,205,206,127,235,125,110,102,37,147,212,193,188,44,154,115,92,145,176,158,71,32,78,67,5,2,112,70,94,3,3,23,142,78,25,193,79,70,184,201,206,113,78,74,18,203,244,100,170,252,121,227,250,222,127,141,122,140,210,196,93,121,180,191,51,222,255,0,209,242,147,126,54,234,117,210,210,29,50,80,127,248,170,83,255,0,145,224,252,101,111,82,159,136,110,124,229,138,178,173,54,243,233,147,233,255,0,244,122,165,24,85,241,13,195,198,82,163,77,63,205,179,118,255,0,22,62,190,157,226,222,157,231,219,202,172,34,181,209,159,28,191,162,173,175,51,45,179,161,250,2,188,97,117,109,58,50,107,13,60,31,10,241,133,90,118,157,66,189,23,142,248,55,161,230,188,36,186,245,248,57,220,199,200,250,188,187,186,149,71,157,219,59,62,137,60,94,69,183,150,162,117,29,66,93,247,147,151,171,59,62,149,242,92,70,88,215,24,61,28,191,71,30,63,190,190,195,248,59,103,27,143,26,93,93,99,42,214,217,225,250,57,60,127,204,251,156,147,201,241,255,0,192,202,78,85,250,197,203,95,83,133,63,191,110,115,253,209,245,249,60,201,179,159,139,245,60,151,121,49,201,27,40,54,194,13,1,120,193,21,6,160,184,251,1,231,124,17,37,47,4,116,183,23,152,249,43,12,239,150,199,156,240,6,127,216,46,149,149,135,228,172,163,210,105,131,84,68,183,18,216,188,228,141,225,25,16,187,145,23,144,0,99,144,81,139,60,119,141,224,169,248,94,253,188,172,173,15,98,242,116,30,45,178,119,190,25,190,165,15,173,195,43,238,140,115,233,103,111,201,157,114,46,23,205,52,242,214,50,207,81,248,123,123,42,94,48,233,85,37,39,242,206,48,60,247,136,126,122,209,146,223,45,99,211,7,55,134,174,221,159,89,176,185,238,237,84,235,69,182,254,231,110,51,248,51,202,255,0,39,236,74,211,78,166,156,234,124,187,241,31,164,58,53,215,84,163,31,150,107,182,162,254,204,247,31,183,40,84,132,102,166,158,96,158,87,216,232,188,65,214,250,53,239,69,185,161,90,238,17,151,107,210,73,173,79,47,146,241,177,211,134,203,31,154,188,81,5,14,165,9,71,121,71,95,185,44,42,212,243,237,170,106,229,6,146,126,134,215,137,252,170,202,148,233,231,190,47,15,236,117,253,49,57,77,53,39,242,188,158,158,63,164,57,204,229,95,180,122,77,88,215,240,255,0,79,171,6,156,101,66,13,53,246,57,142,175,194,180,229,109,225,94,155,107,61,225,66,56,126,169,234,118,111,125,201,92,152,189,126,193,97,14,112,49,169,20,224,39,200,1,70,245,38,19,102,79,114,61,0,106,99,34,229,228,61,136,58,174,179,69,214,181,148,22,53,208,252,183,248,133,109,240,253,114,180,94,127,117,90,80,215,149,157,15,213,245,214,28,91,216,252,209,248,177,108,233,248,207,169,83,237,194,159,109,88,175,102,137,61,114,148,248,241,157,46,233,219,94,90,215,140,176,233,213,140,215,228,243,254,135,237,8,92,198,230,133,189,196,30,97,86,156,102,159,170,104,252,69,74,93,177,107,216,253,79,224,127,17,219,220,126,31,116,106,210,148,159,149,65,82,147,126,177,211,253,13,243,153,237,136,243,223,137,93,21,82,234,20,111,160,177,78,162,106,163,247,62,61,226,138,93,182,9,246,227,19,90,159,127,241,103,89,232,151,253,14,234,132,239,232,170,189,189,209,139,122,229,108,124,3,172,220,194,230,206,173,53,44,174,14,62,41,39,47,79,77,231,121,120,242,188,189,9,102,88,206,135,232,207,250,63,221,249,157,47,168,90,183,244,225,163,243,149,7,219,61,143,189,255,0,209,233,193,207,170,118,229,78,46,43,30,207,39,170,244,243,199,220,36,177,44,51,19,146,175,212,206,61,145,205,98,7,190,70,235,220,158,196,21,172,152,201,99,1,182,2,163,108,140,201,173,73,246,2,55,161,197,82,74,41,229,100,228,53,238,30,16,31,156,127,23,173,252,159,17,117,12,173,107,58,117,82,244,194,237,62,101,71,92,181,193,246,15,198,138,56,234,86,183,31,197,94,50,131,247,81,193,241,250,82,81,114,88,221,96,223,9,233,158,111,216,62,1,190,87,255,0,135,93,10,225,113,108,169,255,0,229,125,191,232,111,120,131,166,199,171,244,90,246,248,93,238,45,199,238,120,31,194,94,189,15,254,206,104,81,249,165,59,90,179,131,143,179,215,67,211,203,198,182,20,230,233,86,141,74,82,75,47,189,104,113,231,100,245,90,227,191,31,9,234,246,181,40,92,92,91,213,209,197,180,244,62,117,244,213,107,209,159,79,241,191,93,177,190,235,213,171,88,60,198,95,86,154,54,124,202,191,253,102,120,211,50,201,191,12,178,123,95,45,215,121,225,133,10,253,110,214,218,164,177,26,181,161,22,253,19,103,236,232,80,141,173,189,27,106,127,229,209,132,97,31,178,88,63,15,116,234,147,141,253,31,45,226,125,203,181,175,92,159,183,44,170,74,183,73,179,169,55,243,202,148,123,190,248,89,254,166,172,246,196,233,155,223,114,182,48,92,38,101,88,189,81,12,180,201,2,167,5,225,14,8,245,1,140,234,6,168,153,32,226,173,244,179,229,159,141,52,220,188,47,9,173,59,106,172,125,217,245,74,139,49,212,249,191,226,229,7,83,192,215,83,237,207,145,90,156,219,244,89,199,250,138,177,249,163,106,255,0,55,230,126,134,255,0,163,237,234,159,132,250,197,162,207,117,27,213,83,242,148,82,255,0,243,89,249,238,186,74,187,105,31,84,252,14,234,183,54,93,87,172,90,210,105,211,171,111,26,178,139,229,197,225,63,253,227,167,47,83,92,227,234,63,136,125,30,55,125,51,227,97,31,222,67,234,211,131,225,253,85,57,216,220,69,73,75,49,224,250,143,139,63,18,105,217,90,87,233,238,207,190,181,72,56,227,58,44,242,124,110,183,82,156,232,186,110,41,46,214,121,229,254,91,30,142,55,56,229,121,104,47,157,174,83,61,7,134,166,227,214,172,27,198,33,121,74,95,164,209,208,69,184,84,111,220,236,122,43,117,122,197,181,45,148,171,71,251,158,158,93,56,113,237,251,146,166,136,224,122,150,157,101,113,109,10,177,217,162,25,171,17,144,164,34,166,193,149,234,183,35,89,34,49,246,24,43,143,185,61,194,166,52,48,154,120,57,52,49,158,168,15,145,254,62,188,120,50,202,159,241,74,241,75,242,81,146,255,0,83,243,173,53,136,205,115,131,244,175,227,141,149,75,143,3,171,140,101,81,173,28,191,76,159,155,35,133,22,253,81,211,199,210,114,237,250,215,240,226,172,106,126,26,244,30,201,105,27,100,159,223,147,210,85,165,11,171,122,148,42,37,40,84,139,139,201,242,223,195,218,247,175,240,207,164,124,45,76,63,222,47,210,114,71,63,136,60,119,87,160,211,242,43,67,53,220,119,239,198,31,216,243,242,185,203,22,77,120,159,23,219,254,206,235,245,237,127,146,89,143,216,249,95,81,215,169,86,151,251,249,61,55,84,234,247,93,78,238,119,55,53,92,165,55,157,94,169,30,98,239,91,153,51,126,30,57,219,126,91,234,54,108,106,71,226,82,221,224,253,47,248,23,47,254,225,220,83,202,238,141,237,71,143,68,210,63,46,208,110,53,83,90,31,165,255,0,1,92,229,225,75,169,55,152,202,171,253,78,156,187,115,151,211,234,69,100,231,98,179,42,132,41,24,64,48,52,32,50,23,144,244,64,113,182,121,191,27,212,116,60,13,215,235,199,234,141,149,76,123,60,96,244,172,243,158,56,167,42,158,4,235,176,142,238,206,107,250,8,191,31,142,215,203,56,233,201,247,175,192,78,161,70,223,195,253,102,149,73,168,181,117,25,107,232,226,124,34,80,125,216,123,199,67,233,95,133,247,246,221,55,161,245,170,213,103,23,56,213,142,32,223,212,176,205,249,46,113,214,56,246,251,221,247,88,233,114,177,173,78,181,205,53,9,69,197,182,244,212,252,249,215,58,149,179,234,21,233,208,172,167,8,205,197,78,43,73,97,238,141,78,179,214,238,58,141,197,86,170,74,149,41,191,242,227,45,14,130,120,130,194,201,230,205,237,232,227,202,241,158,157,111,85,146,169,125,81,197,229,103,70,113,80,169,217,37,157,133,226,255,0,16,215,177,199,29,116,61,83,167,159,125,191,80,254,6,93,198,94,9,248,95,226,141,73,79,30,205,255,0,232,125,45,159,42,252,13,239,169,208,104,202,48,74,16,167,40,202,94,249,208,250,179,220,231,47,166,249,118,199,4,50,39,33,16,6,0,1,192,2,46,67,212,160,14,167,196,21,254,23,160,117,58,203,120,90,212,146,251,168,182,126,51,185,139,119,19,147,221,201,179,246,63,137,233,198,227,195,221,74,131,147,139,169,107,81,119,46,62,86,126,64,189,166,221,85,82,17,249,90,201,190,62,146,190,175,248,11,121,240,117,122,252,228,180,148,105,44,254,108,247,29,119,241,47,165,218,218,221,91,67,186,117,162,156,92,23,252,207,138,120,67,174,87,232,157,43,169,83,183,143,207,117,56,174,254,86,19,255,0,153,163,90,83,149,89,206,89,114,155,204,155,220,225,206,91,203,211,167,14,63,219,110,247,169,58,145,109,55,151,174,89,227,235,252,213,230,223,44,238,171,101,122,157,84,233,74,85,27,199,39,79,31,165,242,93,114,217,212,236,173,149,246,62,255,0,248,19,83,18,234,145,111,89,225,165,246,62,1,109,74,82,155,73,61,15,190,126,10,63,253,169,127,8,52,227,10,75,187,28,103,111,236,204,115,253,165,94,63,165,125,158,91,152,224,178,122,144,219,148,76,13,17,72,81,57,30,224,52,65,120,32,39,57,0,205,106,241,117,36,162,158,50,240,108,154,215,21,60,154,82,168,150,171,233,251,146,172,126,81,252,72,173,78,191,143,122,154,164,190,74,119,19,135,232,241,254,135,182,252,26,148,173,252,55,213,174,33,62,201,78,237,71,57,244,138,127,234,120,79,21,89,87,159,136,175,42,205,55,41,215,168,223,223,45,158,139,193,93,66,167,72,240,213,74,46,213,212,83,184,149,69,44,233,156,37,143,232,60,156,179,130,78,59,201,234,58,255,0,226,21,245,132,37,66,197,183,85,182,187,229,170,137,243,59,203,251,155,170,149,43,92,213,117,107,212,110,82,148,158,173,155,183,94,125,204,170,74,81,106,164,164,229,219,141,181,216,209,169,99,90,81,121,88,103,30,15,68,201,61,60,221,196,127,126,243,174,89,207,70,179,167,94,50,79,85,177,207,90,194,170,147,196,91,195,56,109,237,231,59,135,30,201,105,236,122,118,99,142,93,125,243,240,46,230,43,165,78,9,254,246,173,213,87,81,123,118,199,31,217,159,99,120,71,193,255,0,4,40,213,143,89,169,77,198,74,9,74,126,222,135,222,101,30,215,131,151,142,102,156,230,86,0,175,98,27,172,167,32,184,27,50,42,2,147,80,60,159,225,197,78,255,0,0,244,249,101,203,229,105,231,219,67,212,173,81,228,63,12,99,219,248,125,97,23,191,116,255,0,248,153,235,214,229,168,166,38,76,132,84,216,171,114,23,26,0,99,58,21,122,152,236,80,150,59,77,57,197,86,132,233,183,148,218,77,122,163,106,111,70,141,91,106,126,93,204,156,182,108,153,163,242,71,138,45,165,75,170,95,211,236,120,163,117,82,31,146,147,58,206,154,218,169,137,197,246,229,52,176,126,144,235,159,132,214,29,91,168,93,93,171,184,211,117,234,57,246,246,242,245,103,81,31,193,74,116,110,41,77,117,8,78,151,114,239,93,186,227,216,235,212,198,51,219,213,209,118,241,232,214,21,211,138,131,161,22,211,231,67,228,254,49,191,181,173,127,90,20,164,176,165,141,30,231,221,151,69,165,79,165,83,177,139,131,132,33,218,156,184,60,101,239,225,69,189,221,74,149,123,232,252,250,164,242,121,175,138,238,186,241,229,35,243,253,245,63,50,18,88,111,209,35,75,167,90,220,187,140,70,148,241,156,236,125,223,255,0,178,41,83,148,84,213,46,215,44,57,65,189,23,230,119,221,51,240,163,162,88,84,239,243,235,212,150,114,147,107,7,94,27,38,83,157,151,167,173,240,181,105,221,120,95,166,214,169,9,66,106,218,16,146,107,26,165,131,179,122,28,150,244,233,208,180,167,66,43,17,167,21,21,249,24,188,50,216,230,199,109,71,168,220,99,66,52,104,30,219,14,114,27,2,2,108,55,192,12,140,101,7,168,225,162,224,212,185,148,115,21,157,114,124,59,241,190,202,49,235,253,58,242,49,113,149,106,18,167,63,247,187,94,159,220,251,103,80,183,149,89,83,148,51,221,23,156,157,23,140,60,14,188,95,99,102,165,90,157,42,214,237,181,41,173,211,90,162,101,212,229,211,242,98,166,252,201,44,103,83,238,31,132,119,180,238,252,41,127,211,106,212,140,42,91,85,239,138,151,242,201,127,205,51,176,181,252,10,156,42,185,86,189,160,226,223,25,71,107,209,127,11,111,124,63,212,110,170,194,238,218,181,11,138,94,91,195,121,90,231,108,26,242,111,46,56,204,244,249,87,139,111,103,94,246,165,24,168,69,66,77,119,70,56,238,60,157,194,114,139,73,123,31,110,187,252,36,188,184,186,173,82,141,122,93,147,127,47,115,201,167,95,240,122,253,74,148,98,232,201,53,243,75,60,156,120,112,188,93,47,61,124,35,201,151,154,163,206,79,191,255,0,209,242,218,112,179,234,109,199,13,84,142,94,61,142,186,207,240,70,251,227,59,238,42,209,84,93,77,251,181,199,169,245,143,5,120,74,135,132,45,43,219,219,215,149,88,86,151,115,238,90,166,118,172,122,122,41,229,201,179,3,41,189,76,89,42,163,96,184,194,49,247,32,61,198,8,198,161,76,101,19,58,108,93,145,140,159,106,111,128,25,253,14,26,233,56,60,163,146,50,82,73,173,140,107,39,42,111,183,114,15,145,126,51,244,206,255,0,9,91,95,65,102,116,110,148,125,241,36,207,207,211,139,85,27,81,103,236,110,163,225,235,95,16,244,154,189,62,253,181,9,190,229,142,37,193,243,11,239,192,107,199,10,147,181,234,54,213,31,115,237,82,77,54,191,230,107,135,166,121,52,63,5,170,169,116,46,169,74,73,246,211,169,26,143,30,235,255,0,67,95,199,30,35,180,171,10,150,214,50,159,126,113,44,211,106,75,245,61,255,0,225,215,225,255,0,81,240,165,46,161,78,234,116,101,11,152,199,183,15,84,214,77,110,163,248,105,115,213,58,157,197,106,142,156,35,55,136,179,28,184,219,117,120,215,192,230,158,82,150,231,85,117,66,74,225,188,110,125,222,227,240,102,246,23,107,202,184,163,42,109,234,223,6,23,159,129,151,117,239,97,229,95,91,170,78,57,114,150,86,31,166,14,147,75,237,241,78,139,111,41,245,203,52,211,121,171,29,63,51,246,173,157,57,83,233,150,240,146,196,148,86,81,243,175,13,254,15,244,190,143,121,111,117,119,114,238,43,82,146,159,98,134,35,149,253,90,62,155,86,73,203,76,96,181,62,99,139,125,6,195,236,71,185,133,30,50,76,100,185,206,8,130,170,217,106,78,67,64,7,35,30,225,111,176,126,192,97,56,247,69,158,47,199,54,85,122,191,129,58,229,173,188,123,234,78,17,81,75,119,137,197,255,0,161,237,164,190,87,246,58,251,59,21,10,245,188,217,119,70,111,61,188,12,31,142,175,44,171,80,184,116,229,31,167,229,127,115,189,240,55,84,159,66,235,245,235,74,13,198,181,165,74,75,238,240,215,245,71,223,250,207,225,31,64,234,247,158,125,41,212,180,238,214,74,155,206,95,174,166,164,63,7,58,69,173,245,43,154,87,245,159,98,195,140,162,154,127,208,213,220,98,118,248,53,205,43,170,245,234,85,175,55,57,55,156,183,151,131,93,218,78,75,68,243,246,63,68,215,252,43,232,149,176,188,250,177,107,149,201,207,105,248,97,208,225,111,82,21,165,57,55,180,151,6,127,7,95,205,249,90,118,179,243,166,182,105,234,118,126,23,179,169,83,196,118,112,116,251,151,155,22,215,182,79,209,19,252,23,232,53,107,78,172,175,174,49,46,20,86,135,109,225,255,0,195,63,15,244,11,133,113,10,110,234,178,121,140,235,37,152,253,145,215,230,49,235,117,235,186,109,63,47,164,80,139,88,249,115,131,152,229,156,151,106,81,219,7,23,39,58,33,12,153,142,0,16,187,0,49,99,24,69,12,131,0,204,176,201,150,144,87,131,252,83,255,0,23,248,121,213,109,151,212,167,78,95,146,103,230,31,129,158,82,105,231,109,17,251,50,191,77,183,185,149,72,220,83,141,90,85,86,39,78,75,41,157,5,127,195,47,10,86,188,87,10,205,211,127,197,8,203,229,102,248,223,73,113,243,15,12,198,242,219,240,134,147,163,221,25,194,250,116,211,77,167,24,189,114,191,51,199,117,11,59,219,186,202,119,117,170,214,171,183,116,219,150,17,250,90,159,134,186,61,14,151,251,58,141,15,46,209,75,187,203,143,169,215,191,1,248,121,172,43,121,175,252,108,197,227,237,101,199,230,138,221,58,164,119,90,123,157,61,213,150,107,225,229,103,216,253,75,117,248,109,225,218,241,93,180,230,165,234,229,176,176,252,55,240,205,146,110,118,81,185,155,121,205,76,188,26,227,232,183,95,151,45,186,93,90,181,149,58,81,114,147,227,159,208,253,87,248,91,225,229,225,223,1,218,194,73,170,215,31,189,154,123,172,240,118,214,61,11,164,88,201,206,219,166,219,83,147,254,40,211,89,253,78,233,73,70,140,97,5,132,150,200,182,178,226,79,50,105,25,50,45,25,113,169,149,30,131,128,198,116,32,133,100,47,160,16,17,231,43,5,40,137,102,90,157,79,83,95,17,109,212,45,39,28,210,149,9,101,122,233,177,219,28,126,92,124,222,233,69,52,244,126,228,87,226,234,246,21,62,38,127,36,146,238,127,194,122,63,14,116,11,138,221,62,242,94,77,72,69,184,180,253,79,210,85,60,27,225,202,149,167,82,93,54,155,115,151,115,88,228,237,41,244,254,159,74,148,105,82,177,161,24,69,97,37,4,106,237,152,158,165,126,99,126,27,168,243,40,198,115,81,244,70,52,188,53,94,226,188,105,209,166,234,77,235,219,21,150,143,212,17,179,180,140,90,86,116,48,222,191,34,49,167,211,236,104,214,117,104,89,80,167,55,188,163,4,178,103,241,107,242,126,82,191,240,141,255,0,198,75,255,0,103,220,109,171,242,222,167,5,31,11,117,9,92,66,42,194,186,238,120,93,208,104,253,123,217,78,73,102,148,63,64,168,81,114,77,208,134,86,207,180,211,62,183,94,103,240,183,195,213,60,55,224,107,107,123,152,118,220,84,114,156,255,0,55,167,244,61,107,220,203,189,246,97,36,98,201,240,71,169,11,193,30,192,76,0,131,251,16,56,0,0,206,17,24,221,145,236,7,83,214,168,59,154,51,183,143,253,172,28,31,230,124,87,172,254,14,245,139,75,127,50,143,109,104,101,180,169,234,241,238,143,189,165,221,171,91,61,14,69,57,240,217,101,43,243,199,66,252,63,234,211,182,157,41,116,218,221,234,93,207,49,237,254,231,109,31,194,238,165,81,247,187,23,24,183,204,181,75,212,251,148,167,55,201,19,150,115,150,61,18,215,230,234,223,135,253,86,181,244,233,81,177,156,177,46,204,189,14,194,219,240,95,174,215,214,167,147,74,47,223,84,126,129,217,188,37,151,206,12,190,111,82,204,77,124,94,195,240,70,238,141,57,58,215,20,123,222,49,142,15,121,248,127,224,138,158,18,119,213,43,78,19,157,195,88,148,119,194,245,61,114,207,38,125,207,24,201,61,46,220,196,111,82,112,24,223,144,68,47,3,216,156,5,7,220,2,32,76,15,98,129,139,208,212,191,163,58,208,132,96,249,212,220,35,89,10,240,151,159,133,189,59,169,94,85,189,169,113,53,82,109,203,177,125,57,52,44,255,0,14,110,173,172,105,218,58,116,148,96,228,211,238,79,57,62,148,180,216,165,178,89,148,252,172,124,194,151,225,197,74,221,65,194,116,161,10,106,58,205,242,118,86,159,133,214,20,107,249,215,53,101,52,181,81,71,191,77,227,113,198,9,56,241,157,45,229,94,46,227,240,239,195,245,105,206,149,43,60,84,169,22,179,238,116,118,127,131,54,212,46,84,234,93,197,211,230,41,109,249,159,80,75,183,97,169,114,51,181,208,248,111,193,150,30,27,188,169,115,106,240,231,79,179,11,56,223,57,213,158,130,111,186,100,224,3,66,21,106,70,65,24,252,128,34,141,144,160,35,199,126,24,182,255,0,15,236,228,222,92,167,55,249,119,51,214,199,115,199,126,22,118,255,0,176,22,189,187,41,190,79,98,139,125,168,198,116,15,129,193,4,94,232,201,106,140,75,178,208,3,206,195,117,169,81,30,89,70,45,101,28,50,142,30,81,205,170,68,198,75,7,22,225,60,28,189,154,151,183,26,151,83,28,109,182,194,57,48,155,208,118,45,208,212,113,225,243,147,40,239,177,150,10,150,30,9,70,81,111,96,30,155,12,225,110,4,206,3,15,96,150,72,169,159,80,55,101,104,42,37,200,251,128,4,192,211,37,220,96,208,227,154,100,89,57,48,131,142,0,199,56,194,46,116,51,237,70,56,200,71,22,62,109,17,26,107,150,115,56,164,78,223,232,12,113,37,157,205,138,114,107,67,5,28,112,103,31,82,24,203,57,212,45,217,22,193,144,27,32,221,149,173,66,177,28,96,200,199,156,128,48,154,110,13,25,50,61,128,226,183,120,160,147,250,147,193,203,131,90,147,146,125,185,218,79,44,218,32,227,81,195,202,57,19,150,229,194,42,91,154,137,83,47,212,197,231,62,231,39,105,139,90,151,81,198,211,114,203,220,186,189,204,251,81,113,166,131,70,43,208,203,45,140,109,130,173,140,169,239,200,221,33,130,110,0,45,194,208,107,144,166,236,49,159,82,160,38,204,60,224,108,0,197,172,147,181,228,228,192,75,64,140,123,94,12,90,202,72,229,192,198,133,218,56,93,51,37,13,12,241,161,146,67,70,10,58,25,37,141,12,176,48,52,100,65,192,96,65,200,26,228,42,48,24,228,34,50,55,131,38,98,209,5,228,197,149,238,0,197,199,40,40,232,180,51,5,131,138,75,18,208,198,148,187,243,167,210,240,204,170,103,185,18,159,203,57,69,47,118,41,140,187,114,246,10,24,220,207,26,21,47,93,136,36,98,178,114,44,96,199,66,191,98,130,105,177,252,76,32,223,160,0,49,160,220,3,33,88,2,114,80,27,3,18,53,147,39,184,192,28,110,57,42,137,158,2,64,76,112,72,199,83,46,70,0,96,169,33,232,93,184,2,144,4,0,133,102,32,82,21,174,75,186,3,20,87,176,246,35,221,16,18,49,146,51,224,198,91,10,56,224,140,241,131,26,107,115,147,26,8,168,92,21,45,53,42,244,42,49,193,75,166,73,184,4,82,99,82,132,6,8,95,184,80,0,21,0,40,67,98,52,152,8,6,200,156,100,161,128,11,80,138,3,146,240,66,130,161,71,4,8,160,2,1,0,10,139,220,108,52,220,50,40,48,128,42,60,39,225,44,156,188,1,110,191,253,164,153,237,209,226,63,9,101,25,120,22,155,143,211,222,123,126,62,194,170,240,52,76,45,114,57,34,26,33,185,37,185,86,197,5,166,134,56,50,123,19,112,168,77,153,88,3,38,68,178,193,51,134,1,253,203,192,223,238,76,129,75,246,38,141,12,234,17,114,98,54,42,96,48,246,39,37,122,240,50,21,48,51,161,88,224,9,193,12,158,250,2,193,52,200,245,97,110,48,81,84,121,46,53,11,35,33,6,77,222,133,35,219,96,98,181,161,142,12,179,161,139,221,0,224,177,89,38,53,50,90,108,69,86,99,193,115,234,77,217,17,120,32,219,5,97,81,12,176,30,113,128,38,236,143,115,61,145,131,8,214,127,43,151,167,113,179,21,162,212,211,146,146,132,181,213,203,38,236,86,18,245,192,90,99,12,201,12,103,112,88,134,229,24,244,0,9,130,128,137,140,14,65,72,169,200,194,193,112,78,64,15,185,70,0,152,208,34,178,37,166,64,4,93,139,128,32,90,44,20,114,80,0,128,12,136,80,46,116,4,40,80,154,228,164,2,144,163,116,4,198,0,123,128,35,38,10,2,38,224,164,228,128,82,240,54,40,225,171,132,227,238,199,253,171,92,138,219,199,238,99,41,98,234,43,59,173,8,174,98,235,177,11,166,66,5,34,41,67,208,23,66,45,194,155,141,135,33,1,72,193,120,2,114,66,177,192,64,0,180,0,18,0,40,198,75,130,96,9,201,150,116,8,143,128,46,131,56,39,32,6,224,164,8,188,16,101,240,57,10,96,21,147,96,129,140,182,102,123,163,9,100,148,97,79,118,114,110,113,211,79,95,83,151,100,32,114,84,70,17,67,1,34,145,5,25,119,68,202,42,8,2,114,80,166,64,0,8,81,141,64,34,96,186,147,80,42,220,50,12,97,1,70,6,116,25,8,20,133,0,0,34,4,121,41,2,139,96,9,201,3,96,180,15,86,92,0,220,159,152,46,10,62,117,248,57,36,252,14,160,158,123,107,60,175,76,159,64,198,120,208,249,183,224,195,82,240,157,195,79,254,213,119,47,71,131,233,43,84,94,74,169,13,125,66,67,26,228,202,39,44,185,194,220,104,181,24,40,152,200,107,27,20,110,182,3,29,247,31,144,107,64,189,130,174,132,123,151,157,201,156,128,200,215,146,54,0,203,65,201,23,184,108,10,244,99,157,14,26,119,17,171,81,199,58,167,134,142,86,241,238,88,139,168,221,145,61,72,158,30,224,100,181,50,38,71,57,8,143,87,161,56,50,231,113,140,100,170,199,146,191,67,134,53,98,234,184,103,230,220,229,252,136,51,246,49,216,188,105,184,90,234,128,6,180,34,231,37,32,123,19,118,81,200,209,143,38,92,13,114,27,10,103,66,114,84,180,38,217,8,169,0,131,0,30,133,49,107,43,70,5,126,164,194,237,220,171,98,113,236,6,171,95,94,117,72,217,139,249,22,61,14,15,251,57,182,141,136,97,194,63,96,181,146,216,114,66,132,0,1,0,129,113,128,168,10,136,208,15,176,0,6,6,200,185,208,45,64,99,66,123,25,100,152,101,19,147,44,250,16,160,8,82,0,120,26,23,25,24,2,96,164,193,151,230,4,28,23,96,130,128,63,81,192,1,157,8,80,35,220,61,75,201,0,133,43,49,8,175,114,96,4,5,26,140,232,131,3,138,174,235,238,97,219,155,181,39,191,110,18,45,195,199,99,94,186,152,118,191,142,132,219,209,69,232,69,108,227,5,72,62,2,42,24,42,220,143,250,12,133,25,72,80,1,5,176,2,4,55,40,17,175,66,235,128,131,8,196,164,228,1,112,203,177,54,69,200,13,70,70,9,128,171,157,8,80,0,108,0,13,134,227,113,128,38,11,128,0,2,145,238,0,194,123,51,62,12,100,147,139,200,28,84,125,222,78,99,134,146,195,103,49,32,123,23,146,21,21,7,185,10,201,128,161,72,180,40,64,17,45,75,128,160,27,132,16,76,48,2,156,0,30,192,6,161,20,34,20,0,39,37,0,0,1,132,8,138,66,40,7,35,26,128,3,146,129,57,25,28,141,0,249,127,224,170,107,195,55,113,202,111,205,203,195,246,62,148,158,52,62,91,248,31,56,203,195,247,113,73,175,157,54,253,79,168,164,94,75,123,100,183,50,224,137,110,77,76,163,34,103,80,190,225,234,244,40,99,81,144,182,53,238,166,233,210,115,79,56,221,5,115,101,112,22,134,141,189,253,42,173,67,189,119,99,41,100,219,82,200,25,135,162,200,206,132,107,64,53,106,86,156,46,98,178,187,26,216,217,139,202,208,235,175,151,109,197,41,55,201,216,199,24,207,170,32,201,125,200,223,160,206,54,35,208,168,234,228,252,190,174,165,157,26,213,29,162,103,83,119,219,79,169,69,188,230,81,208,237,32,243,20,253,132,90,205,28,117,170,42,80,238,193,158,112,104,95,206,83,140,99,9,225,183,130,212,111,210,154,156,59,150,190,166,121,56,45,32,225,65,119,110,205,132,32,39,234,22,229,38,10,58,110,163,41,81,189,183,169,13,251,177,247,71,111,6,165,21,35,169,235,203,178,157,42,209,89,113,146,59,43,121,119,81,140,151,161,34,222,156,219,17,183,145,146,234,84,66,164,70,248,11,36,197,82,101,166,62,197,220,98,38,66,11,113,130,43,35,28,50,173,80,72,32,138,182,24,0,76,2,145,1,113,161,10,77,128,224,150,242,75,100,115,65,167,4,209,194,215,213,158,78,88,99,177,96,43,47,113,145,163,88,11,96,140,183,91,145,13,64,64,2,162,168,76,20,0,67,3,3,32,56,8,0,31,152,245,24,0,1,72,20,5,198,72,244,2,236,78,64,2,142,65,64,2,96,160,6,116,0,10,76,106,54,64,3,8,96,1,24,192,1,19,5,198,128,32,4,108,173,19,128,56,107,255,0,153,73,113,146,188,43,136,174,90,19,121,171,77,96,37,154,241,246,68,87,51,216,160,21,19,181,75,229,124,157,93,183,81,161,43,218,150,177,173,223,56,201,197,167,186,59,85,140,167,238,124,194,82,184,179,241,237,226,165,44,184,79,204,107,127,149,147,149,197,227,53,244,238,66,123,163,130,218,226,23,84,33,86,155,202,146,57,190,197,70,188,238,225,11,191,135,203,238,198,77,148,206,170,174,63,110,82,90,102,90,51,181,124,227,126,9,165,244,103,212,185,71,81,119,213,225,101,90,157,43,186,85,34,229,252,80,143,114,57,191,106,90,198,49,110,163,140,101,179,104,104,236,51,160,201,175,70,230,141,84,156,42,39,157,142,124,148,87,128,106,223,93,211,177,165,26,149,118,111,26,27,20,166,170,83,83,142,205,100,104,205,236,76,96,20,9,238,80,30,216,2,54,51,169,197,82,188,41,233,38,97,241,84,177,149,82,63,169,52,108,240,14,37,86,45,38,154,105,243,147,145,60,253,138,9,148,0,160,96,0,28,128,17,9,45,34,100,209,133,77,130,176,164,180,127,115,149,232,97,77,40,195,28,25,48,148,228,203,38,40,203,0,55,32,12,6,229,91,24,166,102,4,217,0,64,170,48,56,24,1,192,0,6,50,30,195,146,132,56,33,64,4,8,80,0,0,0,2,32,128,5,2,20,139,98,42,20,20,8,192,40,31,31,252,10,105,244,123,252,103,42,105,52,207,172,173,207,146,254,5,73,75,166,117,5,207,200,223,232,125,109,45,77,115,237,106,243,160,203,0,194,42,49,209,54,84,76,106,81,78,42,244,227,82,148,163,45,154,193,202,244,70,19,122,5,121,251,26,49,117,170,67,183,182,165,23,132,253,81,218,91,86,148,147,83,95,50,53,45,163,217,214,42,167,181,72,166,191,35,150,241,59,106,138,188,62,152,191,153,122,162,69,174,197,23,39,28,36,167,21,40,188,166,103,147,76,186,254,164,224,187,59,218,223,79,185,189,73,185,82,139,126,135,95,214,22,109,148,176,159,108,147,55,109,158,109,161,157,241,169,21,205,206,129,161,146,200,136,234,186,146,143,155,9,189,37,141,26,59,10,45,186,16,202,224,235,250,187,105,83,237,229,155,180,167,154,16,123,104,105,86,180,252,184,57,51,78,140,101,113,53,45,240,249,45,220,157,103,26,112,127,49,189,70,146,165,77,36,181,228,157,142,69,162,195,50,70,41,100,201,21,20,136,106,216,224,14,187,173,168,202,194,74,75,68,211,77,112,204,186,84,220,172,99,221,140,173,240,58,188,251,58,93,196,214,27,140,114,140,122,78,37,103,220,189,118,247,7,198,254,187,21,127,64,54,208,34,178,45,203,169,51,135,236,20,26,224,45,94,197,198,61,198,170,36,101,146,112,17,16,28,100,164,199,220,11,186,38,10,78,64,187,2,232,78,64,44,145,236,80,246,3,94,79,70,217,205,77,37,4,189,17,197,63,166,75,131,146,31,229,172,122,2,178,228,160,4,10,8,81,70,1,83,2,12,234,94,9,192,7,156,13,131,0,92,14,1,2,174,52,38,197,201,0,185,208,136,163,0,0,9,106,20,91,33,192,27,128,220,112,80,3,37,49,228,187,4,2,0,40,194,0,8,81,200,8,19,146,134,21,63,185,53,228,172,4,0,65,188,129,197,54,221,104,45,4,83,248,188,241,218,87,133,90,62,164,167,44,214,146,246,34,185,131,206,50,3,42,34,77,180,120,139,10,113,171,248,155,213,147,93,222,109,188,127,240,224,247,16,223,115,199,120,127,203,171,227,174,175,86,49,125,209,166,162,229,198,228,171,29,189,140,191,102,220,74,206,163,199,116,179,19,184,195,224,235,122,213,7,82,16,184,165,254,101,61,89,179,99,119,27,203,85,85,63,84,254,226,21,214,217,53,95,172,213,169,38,156,160,241,28,127,83,187,251,157,7,64,196,186,149,236,176,254,87,132,255,0,51,208,49,58,78,78,143,172,166,250,133,7,220,146,198,204,237,167,66,141,88,65,186,107,24,244,58,126,182,147,190,163,187,150,22,14,242,47,48,143,216,176,248,226,86,180,34,226,213,52,156,118,193,204,52,192,3,205,248,178,117,41,85,183,146,159,238,219,89,137,220,116,154,146,169,211,41,78,111,230,107,95,99,168,241,156,37,251,62,149,84,245,139,55,58,117,212,109,60,63,74,85,164,148,220,115,134,103,170,173,203,251,250,86,22,238,181,70,222,184,81,91,182,108,210,159,125,56,207,110,229,156,30,75,166,58,253,111,169,194,226,164,177,66,18,114,132,120,120,208,246,10,42,11,30,133,158,253,151,208,106,245,14,161,67,166,219,74,189,121,109,180,121,102,204,228,161,78,115,111,72,172,179,200,199,187,172,245,73,92,86,142,40,47,162,47,148,45,35,134,226,191,81,234,151,62,117,58,78,84,30,169,39,141,25,202,252,63,123,115,109,24,121,178,166,247,150,117,61,61,181,8,81,139,84,214,34,248,57,245,75,70,51,77,121,143,130,186,233,49,166,169,92,74,113,134,27,167,37,163,71,162,182,170,171,83,140,227,179,91,122,26,221,85,191,132,140,212,123,166,165,141,125,14,62,135,41,78,207,190,79,57,111,82,78,199,104,199,216,103,92,20,210,8,61,128,192,83,5,0,34,125,206,58,154,197,156,140,227,158,218,144,74,121,104,205,152,210,250,76,152,17,110,102,112,58,176,82,199,118,168,229,139,238,136,131,38,204,75,35,130,189,120,91,210,157,90,141,40,197,101,178,142,101,185,151,39,5,189,104,214,138,148,101,148,245,71,57,1,142,12,83,212,165,20,164,13,225,0,99,35,57,38,236,42,128,57,2,129,176,8,0,0,6,30,128,0,212,2,0,0,34,108,0,97,76,132,10,4,96,114,50,192,248,231,224,61,69,46,157,120,155,93,233,44,164,177,161,245,214,244,193,241,191,192,121,166,175,227,159,170,148,90,253,89,246,79,99,92,251,21,104,95,80,182,27,24,84,72,169,96,37,232,61,74,138,97,44,189,140,185,13,1,208,190,231,215,227,38,244,140,115,131,183,156,85,88,184,201,38,153,211,184,77,245,213,39,36,163,140,99,147,187,194,252,132,90,209,165,85,219,213,149,25,231,15,102,110,197,230,38,159,80,163,82,84,227,82,142,59,225,44,235,232,115,91,213,85,109,212,128,212,234,233,74,213,167,174,90,194,57,186,118,180,61,177,201,197,213,31,248,116,241,150,181,57,58,100,251,236,227,38,177,158,5,27,201,225,146,66,59,153,75,98,14,167,170,201,47,37,106,219,123,28,23,245,171,82,181,164,233,197,183,220,147,73,112,114,117,154,144,167,8,57,97,62,12,172,168,213,175,219,41,172,36,138,57,186,124,96,228,231,157,121,79,115,178,120,194,58,203,170,51,180,170,238,105,69,202,45,230,72,221,183,184,133,197,37,56,227,81,47,196,174,111,80,130,69,219,99,64,131,31,153,88,26,29,78,41,216,85,77,188,99,131,14,149,46,234,15,131,155,168,164,236,43,104,222,34,217,213,116,158,165,111,74,202,85,43,205,66,47,108,146,47,199,125,221,128,228,176,116,147,235,180,101,7,58,112,169,36,158,22,35,185,148,58,141,245,85,154,84,96,162,255,0,157,106,77,76,119,61,232,54,159,40,233,148,186,157,88,183,242,167,246,51,161,111,212,37,220,234,214,231,56,72,106,227,182,139,203,193,145,173,66,21,35,245,188,155,43,64,11,82,164,7,33,12,101,238,66,227,0,8,138,0,0,192,1,141,73,157,25,67,216,35,133,189,209,201,21,242,164,112,201,189,126,231,52,126,148,152,105,145,73,141,74,194,27,5,184,224,133,69,28,0,1,20,155,21,160,33,114,99,201,144,0,56,27,5,195,26,144,161,236,20,224,0,3,32,133,64,66,224,23,216,8,3,216,32,43,0,0,3,144,0,0,0,0,17,57,1,134,5,220,49,192,224,8,136,101,177,139,220,14,57,107,87,28,164,99,111,173,106,153,227,99,61,234,28,118,185,114,172,222,221,248,68,27,36,111,36,228,50,134,209,148,189,19,103,144,240,130,207,88,234,149,90,249,228,242,255,0,83,214,212,89,183,172,189,96,207,51,225,26,19,163,43,199,54,155,114,39,213,143,81,40,70,164,28,36,180,146,195,58,74,83,143,79,149,213,23,23,30,212,218,193,221,173,14,155,174,82,148,26,173,21,220,166,177,63,108,22,163,15,12,210,148,45,235,213,158,245,37,149,175,7,124,142,183,162,71,22,10,90,98,91,29,136,43,206,120,146,188,168,220,210,242,223,207,148,191,83,180,141,91,200,211,166,231,65,125,58,225,157,95,91,146,151,87,161,28,101,168,232,189,117,61,18,227,236,102,118,183,212,105,46,161,78,63,45,85,40,75,236,109,194,113,156,114,158,133,113,140,158,101,20,255,0,34,164,163,178,193,164,215,73,226,152,42,182,20,105,225,185,74,120,251,114,117,84,169,202,250,172,104,185,119,97,97,199,132,118,158,39,171,228,217,198,75,13,247,108,101,225,203,40,83,180,119,82,143,239,106,122,152,179,110,31,28,125,54,218,29,34,249,219,167,242,79,88,231,131,190,206,78,191,172,80,148,232,124,69,56,254,246,158,186,114,141,139,26,241,185,182,167,86,45,98,72,212,159,6,191,95,157,88,116,43,175,37,102,164,163,219,31,204,235,58,92,123,21,26,42,58,246,164,219,61,13,122,74,189,189,74,121,89,107,70,253,79,49,110,234,90,206,156,170,38,234,41,225,175,108,146,172,122,152,199,8,19,189,97,52,247,70,173,213,212,40,172,57,106,246,89,52,58,206,189,121,42,20,213,56,166,220,165,141,56,59,46,149,74,52,186,93,8,198,45,38,179,169,208,212,115,190,173,136,60,54,240,229,190,17,233,237,227,217,109,78,27,226,56,201,153,238,149,200,138,66,163,72,20,133,0,0,64,25,197,81,225,61,50,114,240,112,212,216,81,149,53,242,156,87,117,163,111,66,83,148,146,244,57,161,178,103,93,213,96,171,84,165,73,253,46,73,227,212,151,162,56,186,109,180,174,35,58,245,155,196,219,104,237,227,21,8,40,199,100,99,78,49,133,56,198,49,73,37,140,35,62,68,152,49,145,211,86,156,186,151,80,171,98,147,84,148,126,102,142,210,238,183,145,109,58,153,213,69,227,238,117,221,5,44,84,171,81,254,250,111,50,206,226,172,114,244,214,161,223,77,97,70,15,181,36,118,123,166,117,54,82,78,254,181,53,164,98,255,0,169,219,39,135,145,10,235,229,212,35,9,184,206,156,214,30,51,141,14,79,139,135,159,229,183,140,237,147,130,218,171,119,117,109,165,135,20,251,151,186,108,236,59,97,38,159,106,111,215,4,131,53,177,215,221,215,188,165,91,182,157,56,74,18,217,103,83,125,36,142,163,170,205,207,170,218,209,131,105,246,182,203,72,218,141,205,215,151,173,179,238,244,201,207,66,83,148,123,167,30,215,233,232,114,199,229,138,94,136,185,212,98,106,132,10,81,1,120,0,0,1,0,0,80,156,20,4,56,0,17,80,20,128,92,100,154,128,183,1,201,72,198,64,248,111,224,19,195,189,166,214,174,57,95,99,237,168,248,127,224,70,85,253,109,87,107,164,209,246,246,205,115,245,70,72,55,157,49,168,224,100,194,166,116,40,91,6,202,135,220,146,219,216,17,236,21,208,167,255,0,222,8,197,106,156,117,59,195,161,181,158,124,69,82,58,185,110,147,244,61,3,198,94,132,133,99,37,152,99,7,79,211,171,59,126,161,113,105,86,79,89,119,67,62,140,238,177,141,142,163,171,226,222,189,11,136,195,45,190,214,209,72,228,234,245,35,74,214,114,221,174,14,107,12,59,72,53,179,246,53,122,174,182,106,120,82,79,15,25,193,185,211,215,248,56,250,96,163,101,24,214,147,132,91,199,220,205,110,101,40,198,73,169,44,172,97,145,53,228,106,222,211,191,185,85,38,147,140,39,219,24,251,158,166,132,18,183,134,52,202,60,231,81,179,163,109,117,70,157,36,227,221,60,175,125,79,79,5,219,8,175,97,39,246,180,194,121,79,84,206,182,189,47,128,171,241,20,162,252,175,227,75,143,115,179,192,105,52,212,150,83,221,50,224,227,161,94,21,160,165,9,38,142,86,178,117,53,237,167,211,235,121,246,240,110,155,127,52,23,6,253,181,120,215,164,170,69,175,177,34,57,208,99,43,4,227,67,66,78,17,169,78,80,154,249,100,176,209,208,71,195,177,167,117,38,167,154,50,245,254,19,208,165,234,26,208,138,208,163,210,232,80,167,24,253,109,114,205,181,5,12,118,165,131,52,178,57,208,84,93,56,42,68,199,232,25,4,73,109,130,141,134,232,11,200,33,120,0,198,195,129,141,0,12,100,156,153,32,39,1,142,0,64,99,40,99,65,192,26,242,213,254,103,50,90,108,112,212,138,88,215,147,97,108,131,66,224,184,33,81,81,61,74,131,208,96,2,28,128,16,41,10,21,57,30,197,8,0,0,0,28,142,0,14,73,201,64,0,192,13,199,0,112,20,224,164,88,220,0,228,164,201,83,1,128,0,0,16,0,192,27,160,129,7,177,64,61,135,3,112,4,49,104,203,6,47,64,56,155,106,108,206,143,249,109,174,94,166,165,197,78,202,209,196,177,234,141,186,47,52,147,224,139,89,242,7,37,43,41,140,166,154,209,232,205,91,91,10,86,115,171,42,123,77,231,6,217,36,212,96,219,97,85,60,156,87,52,85,122,19,166,214,235,70,90,50,82,203,57,0,212,233,244,103,111,104,169,207,117,46,56,70,225,31,36,198,128,116,29,67,53,58,151,156,146,120,196,86,187,29,228,100,187,18,202,209,122,154,151,61,38,141,203,238,115,148,37,156,230,36,143,77,237,82,94,124,222,116,212,202,238,183,84,178,204,183,52,233,217,213,162,210,141,119,36,189,77,212,154,88,108,177,49,210,117,203,89,92,168,195,87,202,244,59,91,90,74,133,157,42,107,68,145,107,211,239,199,177,201,21,242,164,51,216,56,247,66,81,122,229,52,117,157,58,131,176,154,181,121,112,81,202,151,171,59,68,113,213,162,166,212,182,146,228,162,185,254,241,71,15,85,156,157,111,85,179,238,165,42,148,214,51,22,164,118,139,143,82,232,211,82,89,79,116,8,243,211,234,110,222,140,45,226,156,170,198,150,91,126,184,56,232,91,206,181,53,90,171,115,168,214,239,134,119,202,210,135,155,230,58,105,203,220,228,84,160,158,144,75,147,56,186,235,58,127,78,242,179,41,65,71,47,58,114,118,201,97,96,184,24,44,152,33,82,192,216,21,20,133,0,66,128,0,225,169,185,204,113,79,14,77,10,45,55,152,174,13,91,152,75,227,41,84,122,197,27,52,222,81,149,74,106,172,123,118,124,16,17,151,6,180,29,88,181,9,67,109,50,108,165,234,81,213,245,169,183,78,149,10,111,231,147,238,55,168,91,198,52,160,187,82,147,90,179,26,182,112,171,115,26,242,213,197,104,141,144,58,186,22,234,151,82,155,139,127,239,35,178,113,109,53,156,123,156,81,183,237,184,149,94,239,171,131,159,56,140,155,217,38,33,93,21,141,26,213,58,157,121,249,137,67,101,166,231,103,74,133,104,79,186,117,84,151,162,70,143,69,79,186,181,71,180,228,218,251,29,198,12,200,186,137,107,131,207,220,66,226,243,197,50,248,121,194,49,163,4,155,146,206,188,158,133,104,242,116,61,42,172,29,221,205,87,47,222,78,114,111,245,53,223,162,55,234,219,94,74,47,23,16,206,52,194,54,45,169,85,165,77,42,181,20,229,203,193,154,146,202,73,234,204,201,32,160,2,160,0,1,128,0,16,160,4,0,0,0,4,84,28,6,198,224,68,100,76,0,47,4,5,3,224,31,128,243,81,234,151,20,243,151,217,159,177,247,126,79,128,126,4,77,254,222,169,140,97,209,212,251,251,220,215,62,198,75,33,251,23,33,189,61,12,42,34,231,66,45,195,122,123,20,86,99,190,134,72,97,32,60,220,42,70,143,139,93,60,125,81,211,39,162,121,77,154,117,122,124,39,212,33,118,146,239,90,54,111,114,39,162,139,56,203,53,58,133,188,174,108,167,8,165,220,246,54,217,56,192,71,156,234,42,127,177,33,9,252,179,209,54,214,199,117,99,159,129,163,157,212,75,119,107,78,234,132,233,77,105,47,67,59,106,42,133,8,83,77,190,223,82,142,84,181,43,206,70,165,24,142,143,171,197,59,219,70,214,90,206,25,220,195,230,165,22,159,6,181,253,146,187,130,195,196,227,170,102,197,37,219,74,49,244,68,246,177,146,247,224,100,164,211,60,148,95,109,211,53,169,218,198,133,105,58,122,70,79,56,54,61,64,81,21,99,59,128,151,177,66,91,5,238,198,117,192,216,2,99,60,225,141,129,42,42,12,154,228,162,0,4,251,20,94,72,139,145,185,144,0,0,220,12,234,2,40,123,144,165,2,62,70,72,217,21,175,81,97,47,185,177,232,112,201,101,165,238,115,250,2,168,0,164,66,161,129,202,8,48,131,46,50,3,144,3,10,112,0,10,0,0,100,0,16,39,37,228,0,193,10,178,93,16,17,104,82,20,1,10,70,130,152,28,151,96,0,60,0,247,1,184,224,108,2,0,164,0,246,27,128,194,131,96,189,195,212,34,61,73,201,151,6,44,43,173,188,165,26,154,202,57,55,173,210,86,240,75,100,180,53,174,34,242,179,182,77,155,116,213,188,83,220,147,178,185,10,98,74,149,35,74,46,83,146,138,245,101,70,121,53,46,234,246,56,195,58,200,177,191,160,254,153,166,222,216,53,110,101,42,157,178,140,28,154,216,17,191,67,72,123,242,114,100,215,183,140,163,73,119,125,79,115,147,50,198,196,28,133,221,24,54,214,10,154,40,203,0,199,57,42,220,6,10,79,185,114,5,193,6,67,97,77,138,76,128,5,193,57,47,0,54,1,125,138,3,146,50,242,2,39,220,160,0,0,0,0,188,1,14,26,141,41,125,206,99,134,113,204,179,232,74,50,165,164,76,217,140,86,134,120,208,162,34,145,20,0,0,3,122,17,165,40,184,189,154,195,40,3,94,214,214,54,180,252,184,235,174,230,192,0,113,214,165,230,211,113,82,113,247,71,83,107,225,250,20,106,74,117,39,41,182,242,181,59,174,8,144,28,17,180,167,26,145,159,116,243,29,150,116,57,193,64,0,0,0,0,0,64,40,0,7,0,0,128,4,10,20,16,130,128,0,131,5,38,160,126,115,252,10,170,227,226,71,143,167,202,121,63,67,99,86,126,112,252,15,148,97,226,186,73,205,247,78,13,118,254,71,233,9,253,70,249,146,170,49,251,141,74,145,133,10,181,102,47,216,171,32,85,169,72,76,54,17,90,24,68,252,139,156,112,5,68,9,141,192,141,100,44,148,153,219,64,50,33,72,223,6,140,83,23,161,70,224,55,67,96,79,96,170,150,163,37,35,8,104,49,232,200,145,88,6,181,79,208,50,106,204,128,197,123,153,34,48,182,37,13,202,66,238,72,35,208,21,162,107,147,64,193,86,7,59,19,0,172,156,128,129,86,196,41,0,1,193,65,227,6,44,161,188,34,43,138,107,13,28,185,246,56,101,149,36,179,169,204,21,74,137,200,216,168,2,176,12,66,140,126,165,72,8,128,8,7,35,220,184,35,220,41,184,224,34,189,0,128,4,16,41,6,185,0,10,201,192,20,103,81,192,0,8,63,48,40,39,5,65,71,176,33,64,112,61,193,66,4,40,2,20,140,32,163,24,5,8,140,133,35,3,82,246,162,167,77,75,223,83,150,217,230,132,95,15,84,112,95,107,65,46,91,54,45,83,248,120,229,18,118,87,41,199,90,133,58,235,182,172,84,163,232,206,86,70,202,56,85,189,8,82,210,156,82,92,224,226,167,135,60,173,141,62,191,212,127,102,218,82,154,135,114,148,241,36,153,193,209,239,167,117,90,164,28,82,135,240,180,247,38,174,59,180,82,34,132,48,98,215,177,152,104,8,139,148,69,161,193,113,119,74,217,39,86,113,138,111,9,182,52,108,147,38,140,250,157,188,32,228,234,44,108,105,207,173,199,191,178,16,147,203,223,2,213,119,57,25,201,212,126,215,139,186,242,92,101,221,140,232,182,56,151,91,236,156,187,169,181,20,240,185,100,211,29,244,74,117,86,189,102,222,188,94,126,73,247,118,226,94,167,103,9,119,71,37,130,132,80,80,40,1,0,7,32,0,0,0,0,82,0,0,226,123,182,114,189,142,38,178,201,70,113,217,51,35,24,236,140,138,0,110,107,209,186,167,113,57,198,156,187,187,30,24,27,0,0,0,0,128,25,38,66,168,38,70,64,20,153,40,0,0,0,24,0,0,0,2,216,4,0,224,0,0,0,0,136,138,160,0,0,0,63,47,254,12,77,71,198,86,121,209,183,40,175,252,173,159,166,166,254,118,126,95,252,28,147,94,51,176,123,181,41,63,203,24,63,79,63,173,155,228,145,146,43,122,24,173,86,230,88,70,21,137,83,193,52,102,91,5,57,26,19,116,84,16,107,209,145,130,231,76,133,52,68,228,203,131,29,2,43,208,19,26,103,113,156,178,140,178,77,57,67,112,0,133,200,76,170,115,160,198,160,1,73,246,25,40,64,133,35,120,2,70,73,188,114,101,131,20,181,206,53,50,2,21,17,178,128,0,125,192,114,8,158,165,220,0,39,5,38,131,8,13,136,50,123,17,176,200,84,81,140,16,160,92,24,189,17,145,139,10,224,155,205,72,155,24,56,37,143,54,58,27,1,81,108,55,5,0,3,192,228,2,46,73,184,198,10,139,130,99,82,234,8,39,35,146,241,130,21,64,31,168,254,225,12,0,8,136,183,41,72,21,119,5,68,101,65,236,78,6,227,146,40,80,16,83,144,54,96,33,178,3,112,20,1,148,34,20,0,25,33,64,16,160,0,35,40,122,1,163,125,165,53,132,219,123,27,84,150,40,193,99,131,79,168,205,71,202,90,252,210,73,27,248,194,88,36,40,206,10,211,113,90,45,78,118,116,189,86,246,116,170,40,66,57,79,113,76,105,248,146,84,234,88,82,243,36,179,221,183,39,89,225,107,218,31,22,233,170,170,93,211,146,142,53,52,188,75,43,200,85,178,151,116,84,106,183,36,165,191,203,143,249,156,254,4,182,181,181,175,115,83,15,186,79,185,202,91,103,216,70,163,222,37,169,86,50,104,62,163,69,182,151,115,105,250,28,79,169,75,14,94,84,187,87,45,13,137,142,211,64,205,26,93,66,148,166,161,47,149,190,25,184,228,182,67,83,21,175,151,28,157,101,207,71,141,226,138,173,39,40,198,93,216,247,59,36,242,140,184,46,14,154,125,14,18,167,78,13,41,40,60,172,156,171,163,67,182,42,111,103,147,180,94,134,68,145,117,214,195,165,194,18,115,83,125,239,156,22,125,62,214,157,41,185,174,50,217,216,28,87,84,124,251,106,180,155,194,156,92,91,8,242,61,46,194,61,71,172,78,181,46,245,111,29,117,245,61,141,56,42,116,212,34,219,75,212,224,176,178,167,99,103,10,20,191,133,98,79,150,205,149,177,84,198,81,64,96,80,0,67,58,224,0,0,23,130,0,11,64,0,0,84,178,4,103,27,194,57,25,197,45,112,74,57,22,195,58,133,177,215,213,165,122,238,27,85,18,167,232,144,29,131,154,140,92,159,8,233,122,12,28,107,93,206,89,204,166,103,118,175,168,216,85,146,156,94,19,223,131,83,165,91,245,26,118,126,100,38,159,152,242,251,151,1,103,79,69,148,98,230,151,38,140,127,104,194,127,68,36,146,202,212,235,46,231,95,165,221,211,185,113,169,82,157,204,210,148,36,244,131,246,26,99,210,101,48,97,73,230,148,91,91,163,50,178,214,175,42,234,120,133,60,199,27,228,214,243,175,154,196,105,44,224,236,130,120,216,152,173,21,11,198,162,179,28,189,242,100,165,117,78,50,239,166,165,141,187,89,184,54,42,52,126,54,112,89,157,188,210,198,186,27,84,171,70,180,20,160,243,23,179,51,169,53,78,141,73,62,34,247,52,122,58,255,0,0,170,63,227,109,165,232,130,183,192,0,0,0,0,224,4,0,0,0,28,128,0,0,33,65,20,0,0,224,16,163,19,95,149,255,0,8,180,241,141,135,110,19,114,107,95,116,126,161,151,249,143,238,126,87,252,37,148,151,142,58,127,56,154,120,63,84,77,126,246,79,213,155,228,69,90,108,76,149,44,24,185,197,45,116,48,211,45,158,131,115,28,231,15,37,217,0,231,66,161,156,160,3,145,187,46,48,200,180,2,188,182,189,6,16,206,112,77,192,171,127,97,185,25,90,44,68,211,33,166,85,160,42,152,253,6,155,19,57,25,206,161,20,155,232,92,232,61,192,96,106,128,212,7,184,198,133,25,2,33,177,73,191,0,16,88,220,23,236,4,206,165,8,1,10,22,128,6,128,143,109,2,201,12,80,26,4,23,36,0,162,166,50,64,130,41,140,181,221,25,123,145,234,128,226,147,253,228,113,249,28,218,227,83,130,95,89,206,181,13,25,8,96,188,4,10,64,182,42,168,224,112,2,3,3,0,130,6,1,84,67,0,32,128,25,28,129,65,22,229,0,66,128,3,0,0,39,37,97,34,40,202,78,64,67,96,0,0,0,20,16,160,0,0,16,0,0,123,21,18,79,181,63,64,52,107,62,250,241,246,124,155,219,114,117,169,77,220,41,69,101,57,107,150,118,65,80,224,169,107,70,165,85,57,197,57,35,99,6,157,221,196,105,83,146,207,204,214,129,30,7,199,55,83,173,214,237,41,91,210,83,86,209,150,188,101,227,67,181,240,95,76,157,126,157,43,170,239,9,182,148,23,15,147,91,168,66,106,122,40,57,206,92,173,207,79,225,218,94,87,73,237,237,237,78,77,164,42,183,35,105,74,49,75,26,156,145,167,24,237,20,114,110,77,50,68,113,86,183,163,112,177,82,154,215,149,163,58,139,91,153,219,117,231,211,171,77,201,58,125,240,111,211,39,120,241,134,243,162,212,243,54,114,248,223,16,187,152,199,45,232,165,233,20,217,8,244,137,172,251,25,41,166,241,146,69,102,88,60,237,149,253,205,95,18,214,134,63,195,169,56,47,200,186,184,244,188,5,168,228,38,84,94,64,77,17,48,24,69,211,4,1,85,236,55,9,104,80,128,27,128,0,0,41,25,114,64,0,0,0,185,208,128,27,56,158,120,57,30,198,25,213,18,140,214,195,114,130,142,191,172,203,183,167,53,156,119,201,68,217,179,78,54,116,227,140,98,38,167,84,74,181,90,54,237,236,212,142,197,69,70,42,43,100,176,67,226,229,179,170,235,159,61,107,42,45,233,41,106,191,52,118,177,212,234,111,218,159,90,182,131,89,236,69,189,17,218,164,146,73,45,17,64,0,0,0,8,86,212,83,114,209,45,216,26,61,90,111,224,188,152,188,78,180,148,23,219,58,155,116,105,70,133,40,82,143,211,21,132,117,180,229,46,163,212,221,100,191,195,209,210,45,242,206,208,133,50,50,142,190,247,170,82,179,151,108,161,82,82,244,138,52,46,186,189,117,109,86,165,181,28,202,48,114,249,184,26,59,223,54,43,71,161,150,79,25,225,234,253,107,170,82,149,107,183,221,157,158,48,143,65,26,29,78,57,238,157,62,220,97,44,236,196,187,237,113,217,167,150,83,10,106,74,154,238,214,88,212,204,172,128,23,76,1,0,0,0,0,0,1,64,1,4,5,1,31,147,127,11,170,121,94,46,176,150,53,243,22,31,250,31,171,106,105,54,126,76,252,52,169,219,226,206,158,180,105,86,139,63,89,84,77,75,30,199,78,100,55,70,173,74,48,169,55,26,189,205,173,86,30,13,168,6,147,109,242,115,105,193,26,56,75,178,109,37,195,47,109,68,214,26,127,115,146,58,23,251,1,131,243,50,242,147,251,19,186,107,248,127,169,203,157,8,6,42,111,149,168,238,227,6,90,182,101,132,128,195,187,26,147,205,88,57,48,189,8,215,178,3,141,85,135,243,44,151,204,143,243,47,212,201,194,15,94,197,147,9,80,167,54,179,4,240,81,146,171,29,117,69,83,77,104,214,78,63,34,156,118,142,10,168,71,183,209,129,146,146,50,77,28,110,130,211,18,101,242,158,86,36,202,51,209,6,253,204,28,37,141,36,99,219,85,236,209,7,50,41,192,149,88,238,147,50,114,158,62,140,20,114,233,128,113,119,61,50,153,151,152,128,201,151,100,99,222,178,59,214,119,8,186,21,24,247,167,177,115,128,40,220,100,139,242,1,144,181,212,104,194,1,130,228,153,46,64,110,24,35,251,144,95,66,145,21,224,8,6,74,183,0,73,23,146,48,142,25,255,0,155,19,156,224,158,60,212,185,57,209,35,65,120,33,74,135,5,216,156,5,140,149,84,164,1,2,114,51,169,30,129,84,32,56,0,192,220,123,48,24,1,0,5,228,0,41,6,74,16,33,73,128,167,35,56,0,128,6,10,4,228,164,40,64,0,4,41,57,40,16,160,96,2,0,0,71,21,196,187,105,253,222,14,83,94,229,57,78,148,83,211,185,100,12,49,251,218,112,252,205,179,93,175,241,169,47,225,91,27,0,99,57,170,116,229,55,178,89,58,21,15,140,235,85,62,105,74,52,245,146,91,37,131,191,194,105,166,178,153,197,10,20,237,213,105,211,138,238,169,171,247,4,121,30,173,229,252,111,109,37,23,217,206,238,44,244,61,14,126,103,73,166,249,89,79,238,120,202,74,165,110,171,123,137,167,24,212,195,199,26,30,223,163,208,248,126,149,70,28,189,91,39,210,182,165,37,78,46,82,209,35,172,169,127,86,187,95,11,14,252,188,119,112,141,94,167,212,37,83,168,171,26,93,207,58,55,29,141,207,50,223,165,90,37,12,78,167,41,61,114,77,29,15,87,235,221,78,17,118,180,109,163,55,55,216,229,23,177,187,208,212,45,106,165,93,168,85,113,74,17,103,55,78,233,211,184,172,234,87,167,159,157,207,47,141,115,131,186,184,181,163,114,151,116,18,113,217,175,82,197,103,198,86,236,243,61,49,77,120,134,181,41,47,150,18,147,121,221,183,169,232,109,124,232,57,66,178,77,167,164,215,39,89,58,127,15,214,235,87,169,164,103,170,100,88,238,112,211,220,169,24,83,146,149,56,203,58,51,39,52,180,109,47,204,211,44,177,129,132,141,90,151,180,32,241,230,38,253,22,172,83,186,149,92,246,210,159,111,171,208,138,218,24,68,138,123,178,224,168,110,3,42,216,9,177,64,0,6,117,0,10,77,203,192,16,0,0,2,236,192,141,104,113,255,0,218,35,145,156,105,124,239,64,57,12,106,205,82,163,58,178,120,81,89,50,53,58,141,189,107,139,87,78,139,89,111,84,249,3,83,166,39,125,117,43,217,188,193,253,62,199,99,114,229,240,181,157,60,247,168,182,177,185,109,232,43,123,120,210,90,52,181,57,83,195,201,36,29,119,74,187,141,213,148,107,42,137,164,159,118,187,53,190,77,14,155,114,186,143,86,173,117,9,102,146,125,168,229,234,157,38,49,161,117,113,103,57,209,149,84,252,200,195,105,55,201,207,208,250,108,108,58,124,35,163,147,215,35,23,93,152,0,168,0,73,206,52,224,231,54,148,86,237,129,95,232,116,215,55,117,58,165,220,172,236,243,228,197,226,173,85,183,186,70,110,173,126,173,85,211,162,229,74,217,104,229,204,190,199,101,66,222,149,181,24,210,163,5,24,175,78,73,218,244,180,104,194,133,37,78,154,194,70,120,1,21,24,184,66,95,84,34,254,232,210,234,170,157,30,143,114,227,20,155,142,17,185,42,144,140,176,228,142,171,169,222,80,175,65,209,167,90,61,249,219,125,73,70,94,29,196,122,45,24,173,112,222,78,212,232,58,77,196,44,237,60,154,141,247,247,55,162,59,91,123,184,220,107,21,36,189,214,4,232,109,162,173,200,182,41,80,33,71,246,40,128,13,136,0,0,0,0,1,128,69,56,0,126,165,31,143,255,0,15,38,168,248,146,202,175,115,114,243,162,177,249,159,174,101,44,201,122,224,252,127,224,92,47,18,216,73,188,37,90,57,253,79,215,219,118,172,235,133,150,116,230,78,153,39,141,198,227,70,19,245,57,40,214,11,146,54,152,0,180,15,66,152,129,150,116,216,44,141,130,212,10,180,97,177,247,24,8,152,47,56,38,127,64,88,13,106,95,96,181,5,4,189,75,201,139,46,251,1,90,39,37,39,216,11,128,183,28,104,68,176,1,234,134,252,20,1,18,94,136,158,92,115,150,100,79,80,49,116,253,30,8,225,174,230,111,216,97,129,131,139,207,212,194,238,201,201,143,98,99,80,48,249,215,1,205,231,99,49,236,21,130,147,206,168,189,250,189,12,194,8,197,75,37,46,22,248,38,23,160,23,58,23,115,30,197,234,255,0,80,169,180,243,220,200,41,115,131,14,217,103,73,6,164,182,220,131,50,24,41,79,152,147,190,91,184,203,0,199,28,214,46,99,36,108,231,67,78,85,83,174,150,30,81,179,26,139,24,42,179,90,148,193,77,112,202,230,130,50,9,24,42,137,172,228,203,185,122,133,100,87,177,142,70,66,46,7,185,59,181,25,247,10,61,202,214,73,145,156,129,73,130,173,136,202,24,3,32,1,81,56,42,0,24,27,144,10,65,176,69,39,1,140,0,27,32,138,21,17,64,8,99,80,0,2,20,0,123,0,82,136,10,64,7,13,69,154,212,211,219,39,49,132,214,90,126,154,144,112,211,121,189,168,158,116,70,195,102,149,175,205,123,94,89,102,240,128,52,231,84,64,7,159,167,211,45,168,117,90,240,165,15,150,179,78,76,239,169,194,52,169,198,156,126,152,172,36,112,202,210,62,122,170,164,211,206,77,156,18,65,211,220,116,37,59,255,0,140,163,81,66,88,250,78,106,61,38,140,90,157,72,167,60,229,190,78,196,12,18,49,80,88,138,194,41,80,40,140,226,175,111,78,234,155,133,88,166,142,82,173,0,235,223,76,169,223,138,119,115,167,79,24,194,90,163,37,211,215,111,100,234,206,105,127,19,122,155,165,38,43,94,149,149,189,31,162,148,83,229,227,115,97,0,84,0,0,0,64,0,41,0,0,0,160,16,0,228,0,41,10,246,68,40,24,103,231,71,33,130,220,131,32,1,64,0,136,46,241,105,173,25,52,90,45,17,73,128,8,2,238,17,133,71,40,197,184,174,230,184,52,190,22,189,203,255,0,19,36,163,159,165,114,111,129,139,24,211,167,10,80,80,132,112,145,144,0,4,150,99,140,227,60,148,128,117,179,233,30,117,121,212,171,115,81,172,252,177,90,44,28,144,232,246,48,159,122,166,251,151,251,207,115,116,163,13,112,194,206,218,25,237,164,181,245,57,84,82,73,36,146,69,41,81,1,94,156,0,33,70,9,200,0,138,201,193,0,0,20,0,4,0,4,81,20,128,15,198,158,12,155,93,126,214,26,37,42,177,89,225,106,143,216,77,229,69,255,0,186,191,177,248,215,194,207,29,102,211,47,254,222,26,254,103,236,149,254,85,63,248,81,215,154,70,69,105,122,132,78,78,77,1,108,70,139,156,104,1,238,86,76,228,160,51,160,192,209,12,106,81,146,43,209,4,136,200,136,92,96,14,117,44,21,2,100,101,228,160,247,69,208,140,137,129,151,185,55,47,169,53,200,20,143,24,40,0,0,0,57,0,9,146,128,5,68,215,32,185,2,16,4,180,0,183,50,38,10,128,16,164,2,133,168,90,133,160,15,96,86,245,32,0,202,144,32,214,146,74,163,103,60,87,202,180,56,102,255,0,120,211,54,54,75,236,34,177,237,94,129,211,143,242,163,34,238,6,30,84,30,184,198,76,126,30,158,115,135,250,156,188,12,1,199,228,172,172,55,161,29,29,114,166,211,57,64,193,193,228,213,226,175,219,40,199,201,174,179,251,216,63,200,218,38,6,13,116,171,172,44,69,165,206,76,191,121,157,142,99,23,145,131,12,207,58,199,66,185,184,167,152,189,17,201,192,224,13,88,221,41,107,219,53,143,84,114,43,136,55,161,202,26,79,116,7,20,107,194,90,198,89,94,165,141,104,182,214,76,221,56,53,219,218,176,113,186,20,243,156,15,99,46,245,22,100,167,29,242,142,55,66,14,45,107,175,185,138,180,135,111,110,101,135,238,61,142,126,245,234,50,189,78,47,134,88,199,124,151,167,177,138,183,169,28,226,171,107,140,160,54,6,78,5,78,180,116,243,19,252,137,138,201,173,51,234,6,200,53,251,234,45,59,91,70,17,185,110,125,178,132,151,228,6,209,78,15,136,138,122,229,21,92,83,150,189,216,75,212,35,148,166,10,164,120,102,93,203,59,133,80,69,171,25,212,34,128,0,0,0,17,172,166,138,73,60,65,253,128,212,179,214,165,86,179,185,184,106,88,98,74,180,215,50,193,182,32,16,100,160,3,0,1,57,40,0,70,80,0,0,0,0,192,168,133,91,14,0,128,0,0,21,100,8,0,0,1,74,32,41,8,41,0,0,0,0,79,226,41,138,95,51,96,100,1,120,40,16,23,25,32,2,20,162,21,16,187,4,49,130,21,178,0,8,186,164,64,46,158,164,101,75,36,228,0,3,128,42,193,10,136,0,23,130,96,0,5,10,133,97,142,2,32,193,80,127,114,8,61,0,2,140,106,55,25,10,131,0,164,31,138,124,49,53,79,169,210,155,221,77,99,67,246,85,63,154,222,140,189,96,159,244,63,27,120,93,119,117,107,101,207,155,21,253,79,217,20,115,240,150,255,0,254,78,63,216,235,201,35,147,98,173,181,24,209,24,179,147,76,177,150,70,76,233,232,85,232,5,69,35,209,149,172,129,12,150,48,69,166,229,228,6,193,142,26,24,42,9,14,119,25,194,34,42,175,247,8,114,49,232,16,12,96,0,201,72,183,47,32,1,57,40,0,128,0,0,0,16,0,30,227,113,144,3,0,96,160,66,236,7,32,50,0,123,5,16,200,1,12,148,152,201,64,112,0,10,212,171,221,241,73,255,0,14,13,164,244,89,56,103,254,110,188,156,203,98,65,151,216,0,5,4,122,130,160,16,1,84,2,4,1,72,20,33,119,33,3,144,152,123,0,13,151,4,41,64,13,192,12,132,6,204,130,130,4,16,228,20,139,32,48,179,140,34,58,116,228,154,112,77,51,32,7,18,183,167,24,225,44,23,202,92,54,142,64,7,19,162,243,149,54,73,83,171,143,150,167,244,57,128,193,197,219,91,27,166,51,85,61,147,57,128,193,193,42,181,34,211,242,219,92,251,25,198,106,90,164,206,68,179,145,133,145,131,139,206,135,119,110,117,49,169,81,121,114,215,76,28,206,49,123,164,206,42,180,99,40,52,146,89,3,95,167,181,10,51,89,213,203,38,230,81,169,109,74,155,157,100,147,209,227,43,147,157,210,202,198,89,21,200,216,48,84,218,90,77,254,99,182,105,106,209,81,158,74,113,226,121,225,132,231,204,127,168,28,131,147,138,85,36,191,129,135,83,181,106,152,209,202,14,53,90,31,99,37,56,188,234,128,200,17,73,62,71,114,2,129,144,5,227,0,103,3,32,64,87,161,0,20,128,160,10,0,133,0,7,4,0,1,72,80,39,5,220,19,36,3,8,239,177,153,140,113,220,192,205,6,54,33,64,168,13,128,108,199,35,118,198,0,61,198,227,238,2,3,33,110,71,184,20,100,112,30,192,50,66,240,76,144,56,5,11,77,74,35,8,187,142,8,163,0,34,160,48,31,1,176,27,145,50,236,64,41,11,193,0,1,200,32,172,128,5,6,0,32,252,79,225,215,40,245,10,125,145,238,154,146,237,215,147,246,117,31,250,157,191,255,0,147,143,246,63,24,248,110,110,143,84,165,53,196,147,107,243,63,102,89,229,244,235,92,188,230,148,95,244,58,243,235,82,57,67,212,4,114,105,48,179,169,119,12,152,208,12,158,165,227,115,20,195,246,2,191,66,144,104,1,61,67,111,208,111,168,201,65,23,24,200,88,123,15,96,131,208,108,24,68,23,98,114,26,121,40,13,201,201,65,64,114,0,0,1,80,3,0,0,0,40,0,0,82,32,0,164,42,121,0,7,0,2,47,1,13,130,156,141,64,224,2,35,220,161,129,175,95,74,208,206,248,54,22,198,173,109,102,155,198,136,217,95,76,126,196,25,0,139,193,68,40,35,65,20,131,216,5,10,0,68,3,130,145,80,20,128,3,35,101,224,2,0,0,3,30,229,2,32,82,114,16,5,228,0,8,0,24,0,0,1,110,92,1,10,180,33,64,48,66,129,16,5,0,73,108,253,11,193,139,198,31,160,26,246,105,118,212,146,105,167,46,13,147,94,209,118,209,105,44,124,204,216,0,1,0,160,0,3,0,0,105,62,17,28,34,247,72,201,0,48,84,161,156,224,158,74,79,41,181,147,48,48,113,186,114,111,234,42,132,215,241,47,208,228,3,6,31,58,244,97,54,184,51,28,1,135,127,170,100,243,99,156,103,83,147,130,97,62,0,199,204,143,169,84,189,24,236,139,122,164,59,19,30,197,238,76,24,121,81,88,195,122,25,56,190,36,5,4,73,227,86,62,111,64,50,27,24,166,253,6,125,64,203,64,99,220,71,52,183,26,50,46,12,20,215,169,150,64,54,97,79,115,44,146,59,176,50,1,50,129,0,193,116,40,14,116,13,149,96,8,192,211,57,31,160,65,104,50,82,61,192,36,24,251,15,184,1,238,54,65,0,200,91,6,134,164,83,128,135,1,238,16,3,35,130,136,11,194,27,48,24,207,35,24,27,12,234,4,123,130,178,1,86,164,46,169,16,129,184,46,53,1,80,0,65,248,139,162,39,43,202,105,99,57,71,236,222,159,60,244,123,9,127,53,188,51,250,35,241,135,70,147,87,212,187,94,189,216,63,101,244,137,103,160,244,239,122,17,127,208,237,207,164,141,197,185,83,192,227,33,28,90,76,228,169,147,26,151,128,39,38,68,42,0,76,224,161,176,9,61,198,161,191,148,34,139,148,22,161,123,4,68,6,195,112,246,2,130,21,110,0,14,64,0,50,10,129,112,78,75,159,66,170,20,13,194,24,32,201,114,20,0,1,57,41,11,200,0,7,216,1,81,10,180,2,20,32,247,10,6,130,65,188,1,73,158,64,8,213,169,30,234,218,236,109,175,165,26,117,162,188,228,205,190,17,21,64,192,40,0,80,137,128,50,0,6,6,64,1,184,32,100,20,1,26,212,0,20,5,34,8,20,19,144,41,25,64,12,1,144,0,0,0,21,16,0,47,4,0,11,193,0,160,16,160,82,20,128,204,37,158,215,143,67,35,25,188,65,253,128,215,176,238,248,119,220,219,110,77,234,109,26,246,120,116,22,54,203,57,201,5,0,20,56,0,0,224,171,28,144,1,94,228,41,0,96,165,224,196,11,192,198,184,28,134,138,5,198,129,106,51,166,0,156,14,6,232,0,33,112,8,28,128,10,11,112,0,2,20,1,3,90,234,139,157,70,239,82,12,123,83,224,189,171,5,26,151,6,14,38,17,139,79,115,148,193,110,200,47,204,159,177,114,204,128,193,143,119,56,10,73,173,204,138,210,244,40,195,62,230,73,147,181,49,219,194,96,92,140,234,99,219,238,26,100,25,103,82,19,81,151,232,17,150,195,147,28,191,66,57,251,48,172,202,140,20,140,187,180,8,52,83,30,75,156,129,119,35,192,111,66,20,82,2,144,6,200,45,198,229,12,123,13,138,0,137,100,23,98,32,28,147,144,82,11,130,2,114,20,0,186,251,129,248,119,163,201,66,246,155,229,60,159,178,122,36,219,240,231,78,111,154,17,254,199,227,46,153,165,210,123,180,214,135,236,143,15,60,248,103,166,183,179,163,28,126,135,94,125,36,118,169,232,85,234,98,151,185,145,197,163,112,214,195,35,238,0,50,100,187,164,5,90,161,140,173,136,145,144,17,5,160,228,20,19,102,88,194,34,101,34,15,216,104,200,54,2,176,77,202,4,202,206,75,144,80,32,46,219,145,110,84,7,37,39,35,69,206,163,144,26,69,19,146,242,57,1,64,183,0,3,65,12,140,0,42,32,2,178,110,129,115,192,81,97,6,16,0,6,10,4,28,14,74,246,3,78,179,197,120,197,243,177,184,182,53,46,34,157,104,105,134,181,70,215,185,5,28,12,134,80,64,32,16,5,39,184,20,156,128,5,0,0,224,2,18,128,200,0,10,136,16,85,0,4,66,128,3,32,0,40,32,2,144,21,0,100,43,5,0,7,4,12,234,8,0,20,133,216,9,201,133,103,138,114,111,208,228,199,39,29,84,157,57,103,108,1,199,106,177,109,28,156,231,21,172,92,109,105,167,186,71,40,80,0,16,0,0,64,163,0,49,145,206,160,0,1,149,123,129,16,101,32,2,240,64,183,0,244,1,234,10,11,112,138,176,131,220,8,92,104,66,234,65,11,174,72,138,222,81,85,51,200,27,142,66,24,5,216,128,0,35,0,97,31,173,229,153,156,116,240,234,73,164,65,204,55,35,246,9,251,148,86,180,39,5,246,39,32,0,26,128,46,8,135,32,9,177,64,68,31,145,82,200,193,4,72,173,100,103,208,0,237,38,61,202,201,200,80,154,153,48,17,142,125,139,146,140,100,42,119,122,151,33,197,122,24,246,63,230,104,35,36,22,48,76,53,179,38,190,129,89,45,201,206,161,50,100,34,128,0,184,208,133,4,84,46,95,171,32,40,252,57,210,191,235,73,246,231,254,71,236,47,11,252,222,18,233,109,255,0,220,71,79,200,252,119,211,211,117,100,147,195,198,231,236,47,8,188,248,63,165,54,177,155,120,255,0,99,175,62,153,142,233,108,82,36,92,156,91,92,114,27,99,129,246,1,150,248,30,128,168,6,52,201,120,35,9,96,34,189,9,191,216,187,140,104,93,4,176,0,24,0,12,144,19,201,72,134,50,5,64,45,138,16,220,133,64,160,128,0,6,128,32,11,70,24,206,160,40,56,33,88,16,188,147,146,148,10,8,21,65,57,50,0,24,224,155,160,130,97,178,152,227,1,89,6,30,193,32,141,107,136,247,201,122,155,62,198,181,93,107,69,27,11,36,85,41,1,80,200,3,128,160,5,0,0,8,0,0,0,8,4,40,2,48,138,0,0,56,0,0,0,82,0,5,224,128,11,194,0,96,160,8,0,185,4,42,32,128,48,5,211,4,0,1,197,114,241,111,55,236,114,55,132,112,221,79,182,214,79,124,129,201,71,252,152,122,96,204,194,146,197,56,253,140,248,200,0,0,0,84,0,5,185,10,3,144,11,247,40,133,224,131,112,43,32,206,128,6,224,23,26,1,0,192,192,23,57,35,88,47,216,96,8,3,220,5,3,99,146,241,168,68,192,46,114,192,84,5,68,1,176,120,31,112,17,12,105,232,217,145,132,30,175,216,131,147,86,93,145,49,176,101,69,31,145,55,27,1,127,176,100,99,80,162,212,164,40,68,122,176,247,47,36,123,128,90,15,118,25,88,17,45,65,120,193,16,13,201,130,132,181,0,30,225,6,245,0,95,238,77,217,146,244,2,123,19,58,225,151,146,0,202,24,24,13,106,1,44,134,181,27,14,0,96,152,50,198,134,36,85,1,2,0,208,140,1,248,107,166,252,215,42,62,175,244,63,97,120,65,169,120,47,163,184,236,237,163,253,143,199,221,51,63,19,166,154,110,126,186,240,53,69,87,192,61,30,73,104,168,40,254,141,175,244,58,243,102,61,9,83,49,90,189,204,150,51,185,201,181,26,96,50,32,46,50,22,217,192,88,70,65,17,50,162,61,50,19,8,203,36,89,5,224,42,50,50,224,184,40,139,37,0,0,68,198,154,148,128,0,215,32,92,224,110,66,149,16,45,202,64,40,97,144,160,48,92,0,176,91,1,192,223,114,8,81,247,25,0,1,74,34,212,163,97,128,46,72,7,1,77,134,236,156,153,112,65,10,201,130,238,138,53,170,105,112,158,116,102,207,177,175,83,252,248,172,110,115,162,10,0,40,164,27,128,129,72,0,160,16,11,157,48,2,0,0,4,14,64,0,0,0,0,0,0,0,0,0,0,5,23,236,66,165,160,224,0,0,0,224,16,128,0,0,0,3,25,229,83,150,61,13,123,153,246,219,41,110,219,194,54,38,254,71,161,167,118,155,163,70,16,89,204,214,73,70,236,52,132,126,197,28,2,128,0,11,193,10,64,40,198,160,0,208,175,130,114,48,85,85,168,225,224,110,51,232,3,100,76,45,203,236,49,168,67,116,69,162,46,203,82,0,201,72,50,0,171,146,112,85,184,19,118,80,241,193,16,25,17,148,139,112,169,193,112,61,134,117,2,2,236,52,2,2,227,65,192,68,208,194,43,18,102,108,226,131,204,229,146,14,92,134,178,198,125,10,138,134,112,57,43,198,9,128,30,228,111,66,161,200,15,114,50,128,8,19,56,101,96,92,17,188,12,228,114,4,251,12,50,242,94,0,199,98,228,17,234,1,23,25,100,0,10,136,130,244,2,236,68,0,5,185,119,228,32,222,160,25,6,70,193,98,183,177,57,4,32,169,2,23,128,33,116,39,37,195,244,32,252,49,211,219,243,176,181,63,92,126,30,197,199,240,247,164,115,242,75,255,0,141,182,126,70,233,249,248,184,203,136,238,126,187,252,63,121,240,7,74,91,98,13,254,173,191,245,59,115,233,35,209,45,76,137,249,151,7,26,211,37,132,70,3,206,64,96,169,224,131,112,46,224,139,68,95,236,5,65,250,12,36,16,67,238,202,78,74,4,228,188,140,13,128,106,192,201,17,81,112,0,192,85,25,64,4,50,1,0,160,32,202,0,14,66,128,0,3,130,20,10,8,80,168,50,87,161,50,5,192,217,21,17,128,224,113,128,50,0,163,4,64,107,215,120,173,7,157,153,178,244,53,235,201,41,71,77,115,132,115,234,180,32,160,20,168,0,0,17,2,128,34,41,24,20,0,0,0,64,0,0,0,0,0,0,0,188,20,64,1,0,160,38,0,48,57,0,64,94,0,128,0,0,20,8,24,0,71,177,167,90,78,62,94,141,230,120,251,27,187,38,205,45,29,196,83,221,146,172,110,251,20,133,42,32,40,0,182,99,112,63,48,24,0,34,134,118,208,171,250,19,130,227,132,3,212,45,17,116,35,211,64,162,198,71,216,134,90,126,96,99,156,151,56,46,9,200,16,96,185,200,216,3,223,66,45,0,92,132,92,227,65,201,57,220,191,220,41,175,40,32,30,0,61,89,10,145,63,32,42,90,100,154,96,188,17,110,5,246,33,121,25,211,0,76,250,156,52,117,156,159,8,230,225,156,84,180,238,68,71,41,82,208,32,222,197,21,147,128,222,166,59,188,132,101,253,139,178,39,5,64,64,181,35,220,168,11,161,0,245,1,246,27,132,180,38,160,82,191,82,108,253,202,4,206,163,58,129,148,4,122,236,54,90,151,130,110,0,184,90,19,208,185,1,140,145,238,82,48,41,52,201,81,30,160,7,246,11,82,176,169,146,20,155,178,0,4,96,80,9,146,35,240,213,131,94,122,79,39,235,207,1,227,253,133,233,120,217,210,201,249,11,167,175,241,113,206,199,235,175,195,249,57,248,3,165,54,176,252,183,253,206,220,250,35,209,232,101,131,20,140,142,45,40,213,61,194,220,184,245,0,194,120,90,7,232,77,144,23,238,51,169,119,90,5,144,3,86,56,212,126,97,12,23,128,158,160,0,38,155,0,43,219,77,200,182,199,33,133,166,128,80,70,85,177,96,164,3,96,41,48,80,152,12,1,144,217,64,108,10,192,128,114,0,0,48,0,171,114,123,128,43,100,40,88,220,11,178,35,97,106,242,70,65,71,1,20,170,4,50,24,70,189,111,243,99,44,55,134,115,238,178,112,85,127,188,71,52,62,148,69,100,6,10,80,4,216,161,17,20,133,0,70,80,0,0,0,0,64,0,0,28,128,0,0,0,175,100,6,64,4,66,129,119,68,4,2,228,16,0,0,0,0,0,41,0,0,0,3,174,166,251,250,150,48,251,96,180,103,98,105,211,79,226,228,214,201,232,74,173,192,56,41,81,11,193,57,40,14,0,64,160,22,248,5,227,220,8,100,159,36,198,165,192,81,253,201,187,34,46,48,4,193,151,4,228,178,97,19,60,133,130,165,161,54,224,42,32,244,47,1,44,132,66,227,65,176,227,1,81,125,89,101,227,220,97,164,64,41,112,76,250,6,178,0,188,147,56,216,44,182,5,201,142,117,50,253,71,32,51,161,17,73,192,65,225,35,134,150,242,251,232,115,75,85,131,134,142,28,159,161,40,230,195,12,53,130,50,138,245,100,198,229,198,133,216,9,141,74,6,128,77,192,123,0,135,0,112,55,0,18,28,141,112,4,193,80,73,140,173,0,159,118,49,160,72,190,136,11,193,24,216,1,48,92,14,7,220,6,6,2,1,68,195,206,65,0,6,1,4,5,32,0,8,17,70,132,46,64,252,47,100,179,93,97,227,7,235,223,0,172,120,11,166,99,76,211,216,252,133,96,179,112,150,15,215,190,1,207,251,9,210,155,230,153,215,159,68,122,36,82,25,45,78,45,8,185,27,104,71,190,64,185,25,208,128,11,148,30,164,89,220,188,1,81,121,34,209,23,92,4,68,57,69,0,0,65,232,16,48,155,196,114,183,50,201,140,180,79,236,7,29,26,241,171,22,211,89,139,195,215,147,41,87,140,22,167,71,46,153,81,213,169,26,85,164,188,201,247,104,246,54,33,209,174,41,174,239,137,117,57,195,26,214,59,23,123,65,105,223,145,43,186,81,75,19,88,52,101,103,93,203,42,158,120,49,149,188,233,172,121,110,73,112,144,209,187,59,232,165,163,94,218,156,50,234,244,105,77,42,170,75,59,52,180,56,99,77,227,186,80,252,154,17,165,70,109,119,71,47,57,72,106,182,233,117,75,90,155,84,73,123,156,144,191,180,168,241,10,244,220,189,19,53,101,99,70,164,112,225,163,246,56,255,0,99,209,236,110,140,59,42,97,226,73,106,61,163,181,141,88,75,24,104,203,57,60,247,236,89,166,219,173,81,225,112,241,169,175,87,165,223,82,169,9,91,220,85,194,221,202,109,141,166,61,78,112,241,200,89,111,24,60,178,93,85,53,230,213,158,83,209,228,206,119,29,67,205,81,87,82,139,207,166,131,76,122,110,112,18,207,39,71,241,215,57,81,115,204,150,153,193,151,237,11,136,84,225,180,179,36,81,221,103,128,142,166,61,83,186,122,111,179,50,93,70,109,255,0,11,79,98,105,142,208,63,67,78,55,110,81,203,237,143,221,156,138,173,86,178,210,146,245,76,163,99,59,131,134,53,100,247,90,28,169,172,100,35,37,176,35,105,106,216,140,147,217,160,172,128,200,192,70,181,72,230,174,127,67,158,58,36,113,207,252,200,163,149,33,21,74,1,80,4,40,2,114,2,2,128,0,0,0,0,8,0,0,0,0,0,0,5,245,32,40,23,129,193,8,24,5,32,2,144,0,0,160,64,10,0,133,96,1,1,112,4,123,26,118,205,206,234,105,44,66,60,190,89,185,147,70,210,82,248,251,136,181,242,238,128,223,11,86,1,64,104,8,65,74,136,92,149,71,236,56,38,48,93,112,4,201,112,243,185,31,216,168,11,199,4,90,178,189,137,176,1,140,228,169,96,104,4,76,133,69,8,196,21,165,144,246,192,80,63,234,55,209,149,32,24,68,122,254,69,126,129,165,162,1,157,2,13,46,66,0,69,167,216,99,82,238,128,110,49,175,184,200,108,6,236,13,136,18,166,116,103,13,182,145,146,94,167,52,180,140,159,24,56,109,190,140,181,171,32,231,224,34,240,76,20,49,238,86,8,3,37,39,250,132,18,131,129,146,129,1,72,192,39,168,99,125,137,160,21,123,144,112,93,48,4,69,39,216,160,50,153,57,201,121,32,20,111,247,25,32,86,94,196,199,33,108,92,129,50,76,105,146,253,217,53,0,86,180,68,42,123,144,36,140,74,245,100,0,0,32,25,96,199,147,147,30,197,31,133,122,119,253,110,15,25,89,89,63,92,254,31,201,63,0,116,174,220,225,193,239,255,0,19,63,34,88,229,220,36,158,25,250,235,240,242,106,127,135,253,41,165,167,150,215,232,218,58,243,72,244,203,98,173,26,212,139,5,217,156,90,6,235,80,222,129,160,46,24,251,110,68,92,104,5,217,12,5,132,144,200,67,35,216,97,142,64,187,0,76,234,5,3,3,26,132,12,101,170,50,220,198,127,78,129,99,82,29,191,16,228,154,109,127,67,116,235,105,201,198,230,162,73,35,177,89,194,101,138,184,68,75,5,192,34,35,138,111,45,33,229,195,249,86,126,198,64,168,199,181,103,99,53,161,57,40,84,100,113,70,68,3,23,8,189,26,207,220,227,118,212,37,163,134,159,115,155,118,6,14,7,103,67,110,215,143,185,132,186,125,188,184,107,215,15,115,111,4,72,14,182,93,14,195,204,243,99,78,81,159,180,152,93,34,58,246,85,107,47,103,169,217,48,180,6,186,169,116,88,55,159,50,79,28,54,113,71,164,92,70,226,117,99,117,37,151,148,184,59,166,18,212,46,180,35,70,188,26,111,62,250,238,102,227,95,77,27,70,235,213,128,52,102,167,37,137,38,176,74,84,19,155,148,39,53,44,115,162,55,222,195,11,124,1,170,149,204,55,113,148,127,169,201,58,205,83,114,124,46,14,118,142,58,176,238,167,37,206,6,14,24,205,78,84,158,253,219,51,104,235,108,229,254,35,201,150,142,58,228,236,185,16,10,1,80,0,0,46,196,0,0,0,0,0,0,4,0,7,32,0,0,10,66,240,81,11,193,11,192,1,128,80,49,216,164,96,130,144,164,96,86,64,92,104,4,41,17,90,192,16,187,128,0,14,0,17,106,104,216,197,124,69,121,231,57,120,108,223,254,23,235,131,78,193,53,222,158,18,203,216,138,220,33,65,164,84,99,201,151,241,104,76,16,19,192,46,1,85,55,50,217,96,45,201,151,157,66,28,110,138,182,38,50,93,112,20,27,5,177,120,2,100,112,22,119,28,128,206,80,75,4,90,50,128,126,164,195,199,177,119,24,1,176,207,33,161,140,128,220,108,50,16,12,236,54,69,27,129,57,26,140,97,141,115,236,4,198,12,155,38,125,6,224,22,218,147,100,87,162,1,43,25,235,78,95,99,142,142,54,244,57,95,208,254,199,21,190,28,91,94,187,144,142,101,232,6,53,25,201,67,220,63,66,145,129,56,46,195,116,50,10,45,201,201,70,2,24,65,232,30,196,228,2,100,69,46,0,143,96,182,13,100,128,92,112,134,193,106,27,244,2,130,45,16,95,208,6,252,19,115,38,136,5,224,143,114,162,46,66,152,211,33,232,86,244,49,224,40,1,86,228,68,35,50,123,152,128,47,35,32,8,114,99,220,227,50,195,3,240,183,79,89,172,176,126,184,252,59,135,103,225,231,73,142,127,130,95,159,206,207,201,29,54,88,186,142,22,79,215,126,0,74,63,135,253,33,45,126,70,255,0,247,153,211,154,71,163,75,66,250,133,134,17,201,163,92,2,147,98,1,150,232,133,40,63,113,128,209,120,8,96,96,0,15,236,0,0,138,64,152,2,52,100,64,52,42,98,23,9,99,57,55,214,169,26,85,210,85,56,201,187,31,165,125,132,0,82,54,84,80,198,0,80,164,11,45,128,46,2,0,7,35,33,110,85,8,204,154,32,64,36,57,0,57,28,0,64,41,11,144,160,201,57,24,203,40,186,17,172,166,0,70,141,40,165,112,158,153,108,223,52,38,187,46,225,133,166,117,55,200,170,0,42,28,0,57,0,0,0,0,32,1,144,0,0,0,0,0,0,0,2,148,66,144,16,80,66,233,143,112,33,64,192,16,20,1,1,127,168,200,12,6,63,184,198,128,52,216,0,80,33,64,17,234,158,30,52,52,58,116,151,153,86,9,229,38,111,191,166,95,99,75,167,227,190,179,207,205,159,155,77,137,123,86,233,146,211,114,34,250,4,31,219,3,25,99,86,22,140,170,110,192,216,110,3,26,232,18,47,184,252,192,133,100,91,151,216,10,131,107,32,152,198,160,22,227,9,151,251,144,2,72,114,51,168,2,147,114,132,3,3,60,96,17,250,132,23,161,73,200,97,71,176,91,15,185,64,156,143,184,216,188,129,48,77,153,145,22,128,55,65,142,74,194,49,122,198,95,99,94,201,191,37,169,44,98,77,27,15,42,47,215,7,21,22,176,240,65,204,216,198,132,72,175,37,83,219,82,144,112,17,112,68,138,52,64,64,86,76,132,22,30,193,141,128,19,35,93,203,192,227,64,35,27,14,61,195,208,6,195,113,130,242,3,128,57,35,2,129,130,61,128,190,185,34,28,100,184,10,143,114,50,242,2,166,195,220,103,128,68,6,0,0,55,28,128,38,53,57,78,52,115,246,161,17,248,62,195,46,227,71,135,234,126,189,252,62,211,192,61,41,122,83,107,63,155,63,33,216,172,213,199,44,253,121,248,127,175,128,186,91,95,247,103,78,93,28,94,140,201,104,98,140,182,91,156,85,27,224,112,22,75,187,10,154,151,236,53,244,41,69,207,24,4,198,10,16,224,156,21,234,176,0,5,187,0,0,24,24,0,10,66,141,75,148,188,213,159,200,218,131,209,26,151,105,247,69,183,132,109,199,252,184,183,232,65,144,32,42,40,97,61,0,80,23,33,128,25,25,0,27,212,2,224,170,132,46,6,2,0,108,2,166,12,136,16,23,66,0,0,164,42,2,96,110,87,176,65,26,149,214,106,39,157,81,179,73,247,83,139,123,224,215,184,202,154,195,220,230,161,244,17,92,156,148,128,34,128,10,0,2,0,27,0,0,0,0,0,0,0,0,0,5,200,65,148,66,240,0,13,8,95,65,238,64,12,14,10,0,16,10,66,242,48,1,108,51,193,112,199,220,8,7,35,144,0,122,232,2,158,191,99,175,233,77,53,113,37,150,220,241,147,176,245,251,26,29,46,61,180,170,44,97,119,182,145,40,222,70,77,238,76,104,10,138,130,91,141,208,246,224,41,193,121,27,134,0,168,143,208,160,65,176,91,149,160,35,219,212,63,64,63,32,46,76,114,87,182,16,90,164,4,221,176,140,189,64,64,1,184,13,137,183,37,24,2,2,173,192,80,0,16,13,232,8,181,94,192,84,0,224,0,0,9,44,168,60,107,161,175,107,172,91,206,117,57,229,244,201,251,26,246,89,242,94,86,30,89,6,209,11,158,6,165,0,9,192,20,153,215,98,128,161,48,198,218,34,228,34,63,113,249,141,240,55,6,41,3,208,110,12,54,33,89,22,224,82,4,178,138,210,6,26,242,68,25,82,6,47,26,19,141,67,32,49,94,192,131,128,25,39,5,198,193,176,39,37,216,139,113,201,0,110,8,6,89,34,27,128,28,156,184,56,150,135,54,31,168,137,95,132,250,126,151,17,223,87,131,245,199,225,234,113,240,15,76,140,158,113,23,175,230,126,70,233,250,220,37,141,79,215,95,135,250,120,19,166,127,192,255,0,185,215,151,68,122,66,144,168,224,210,175,64,247,11,238,84,144,19,118,22,91,246,47,27,5,185,65,238,85,177,30,195,11,64,138,0,0,26,28,147,58,224,10,27,195,47,4,228,10,64,0,212,187,109,225,123,155,52,255,0,203,138,223,67,130,229,98,61,216,57,173,223,117,8,177,15,142,64,57,5,13,50,48,66,172,128,198,10,214,132,41,65,6,18,13,16,10,77,11,130,170,20,97,14,0,133,123,140,6,4,217,1,206,165,96,64,0,20,96,12,128,4,228,184,8,213,185,194,199,175,169,157,187,121,148,95,5,184,95,38,168,226,180,150,106,185,112,226,69,110,0,77,202,138,76,234,81,201,3,144,0,14,73,201,64,0,6,64,0,70,5,0,0,28,0,0,191,114,0,40,28,14,64,110,85,161,2,216,161,192,3,0,16,192,0,48,86,180,208,7,249,5,49,161,10,216,72,3,198,228,27,0,28,0,56,8,169,225,234,104,244,236,167,89,191,167,189,224,221,198,152,52,236,51,154,171,43,181,77,237,185,21,186,66,231,66,232,81,19,208,23,125,200,216,23,102,50,195,0,84,70,245,42,216,4,61,136,83,29,66,170,122,14,66,208,114,68,55,97,104,134,61,74,0,0,0,0,128,32,192,40,96,1,200,0,0,4,79,177,71,33,81,151,32,4,0,228,32,49,158,123,37,143,67,130,213,252,143,59,228,230,169,159,46,94,184,56,173,86,33,174,228,28,254,229,3,5,2,125,202,70,128,160,61,128,0,76,128,162,121,251,5,235,184,90,32,181,64,83,21,185,70,186,0,146,24,194,40,8,155,110,82,34,133,98,223,177,86,228,252,139,176,19,97,238,81,200,17,114,195,43,90,19,118,3,156,144,187,125,201,200,15,98,161,146,103,82,32,244,3,35,96,4,41,0,28,216,56,78,124,253,132,74,252,37,211,95,248,184,69,44,182,207,215,158,0,109,248,15,165,54,176,221,55,167,230,126,67,233,233,187,168,188,225,122,159,175,188,8,218,240,47,73,206,254,86,63,171,58,243,253,72,244,28,232,100,181,220,197,104,100,189,142,13,46,250,100,139,3,31,144,192,69,24,198,165,5,17,50,234,76,228,160,56,96,12,105,150,0,20,20,18,208,2,1,72,51,144,6,189,211,196,83,122,199,208,206,221,167,66,46,43,67,138,242,105,71,181,228,228,181,210,222,43,24,32,231,28,13,131,40,23,114,15,184,2,143,184,1,146,144,20,94,71,36,212,175,98,1,24,26,5,22,129,6,53,224,1,81,18,208,4,10,64,209,85,86,64,75,3,146,10,128,228,21,28,85,150,98,205,107,71,171,79,141,190,198,213,69,149,171,208,214,161,28,85,111,57,203,39,213,110,61,128,224,0,108,164,40,68,28,20,0,25,33,94,192,19,208,36,0,0,48,0,0,0,23,146,0,40,104,0,0,5,150,192,61,192,111,82,240,81,0,228,112,1,110,31,160,91,149,133,56,38,74,214,3,120,224,8,138,217,17,66,3,65,194,12,42,21,106,177,200,252,194,223,64,24,195,208,211,181,249,110,43,71,140,229,155,187,154,22,73,174,161,119,174,99,149,130,81,188,150,133,217,6,30,165,68,122,162,227,36,70,72,128,79,96,216,1,160,227,66,130,135,184,97,145,60,176,1,111,146,142,8,0,14,0,32,0,0,0,0,0,0,1,64,0,0,123,129,128,3,32,96,0,24,192,200,28,117,245,161,81,45,251,89,197,99,37,43,120,253,176,115,85,255,0,46,127,99,130,203,90,43,68,190,196,250,54,128,5,0,57,97,104,3,220,12,232,0,52,0,2,49,192,206,161,108,20,12,113,238,30,115,128,28,148,153,12,0,223,81,167,1,0,111,4,217,149,17,238,1,110,94,7,35,216,3,126,228,111,93,3,208,128,94,8,202,247,33,16,28,129,157,74,28,134,1,4,0,0,91,156,248,56,86,232,216,212,177,31,132,58,118,183,24,198,79,215,158,2,105,248,7,164,180,243,154,114,215,255,0,19,63,34,116,207,250,220,113,147,245,199,225,251,143,255,0,103,189,35,177,167,30,201,107,255,0,137,231,250,228,233,207,164,143,75,157,10,178,181,49,79,83,60,156,27,93,90,8,113,163,24,8,188,13,130,38,197,14,10,0,20,48,10,33,86,161,144,10,48,0,0,79,113,192,26,87,207,69,198,78,107,39,221,108,159,190,12,111,33,152,39,141,19,51,181,93,180,48,184,96,115,173,195,99,24,0,93,6,228,228,160,94,65,6,128,11,130,114,30,64,160,152,40,84,200,70,62,101,60,225,212,134,125,50,101,149,195,79,243,40,190,216,35,42,213,101,16,10,78,64,32,4,11,178,0,152,38,74,3,32,114,2,48,171,244,51,82,146,74,105,71,77,77,202,171,229,102,146,125,149,32,211,207,205,169,42,183,193,94,128,168,0,0,0,0,0,0,0,0,0,0,0,0,0,0,184,211,32,32,0,168,136,0,0,175,92,21,83,128,178,26,40,22,38,37,67,157,2,11,93,198,235,35,56,208,115,160,84,46,25,12,255,0,48,48,224,203,77,200,244,122,13,95,216,10,246,34,201,120,25,208,136,26,118,95,231,92,225,127,22,77,179,86,213,167,113,93,165,163,123,251,129,182,138,48,10,39,32,21,104,65,48,48,81,128,0,0,24,228,13,128,1,128,19,0,57,0,0,220,12,128,67,144,183,0,10,64,3,128,1,64,20,128,0,212,0,28,128,3,144,0,24,85,199,150,243,177,193,101,40,202,222,45,122,179,154,190,148,102,214,248,208,225,177,135,151,110,161,232,245,39,213,108,151,96,10,128,212,155,148,3,0,45,0,114,0,2,99,27,13,145,89,52,97,87,216,152,193,71,32,98,247,27,7,171,208,48,11,5,221,17,21,100,0,96,143,93,64,171,82,106,31,166,165,202,216,9,128,51,184,217,100,3,33,126,227,216,136,131,26,100,99,208,125,202,1,177,201,8,0,20,128,183,70,193,174,190,164,108,26,140,215,225,30,155,22,235,166,180,103,235,111,195,234,125,159,135,253,29,39,162,167,39,250,201,179,242,79,78,105,87,94,199,235,175,1,41,71,192,61,38,51,88,106,15,244,203,104,233,207,245,88,244,9,180,245,70,106,113,122,38,179,232,85,177,132,168,198,79,56,213,235,148,112,105,201,147,47,200,227,112,120,209,224,199,182,170,122,75,64,57,155,192,56,18,170,179,220,211,57,51,47,66,163,45,114,204,153,197,41,77,125,49,200,243,31,49,96,114,131,137,85,89,198,25,154,156,90,220,12,201,187,212,153,65,52,249,40,187,147,92,20,0,0,112,17,175,117,159,43,4,178,109,194,166,120,122,11,183,219,21,253,9,100,240,170,103,146,43,104,164,99,26,20,57,41,12,128,17,128,1,9,53,21,150,73,203,182,57,124,29,67,187,171,212,111,170,89,81,93,176,138,204,170,122,5,115,214,234,83,149,103,70,210,159,155,37,190,56,52,58,135,67,190,234,221,58,165,26,157,66,173,157,73,109,42,47,13,29,221,11,122,54,180,251,40,197,123,203,150,114,131,115,167,132,234,95,134,209,184,86,213,44,122,165,213,11,136,52,170,202,117,165,46,229,206,61,205,136,120,59,173,89,164,237,60,73,86,171,142,208,175,29,31,230,123,67,23,174,131,23,242,175,19,94,191,139,58,53,74,106,181,75,106,177,168,240,170,73,73,193,125,240,178,191,67,184,233,222,36,171,82,74,223,170,217,171,75,134,179,25,211,159,153,74,167,188,101,143,232,206,251,71,23,25,36,211,221,51,206,245,142,137,254,10,243,225,251,157,189,72,54,233,45,225,47,230,135,161,155,44,233,55,123,122,40,181,40,169,112,246,107,145,147,228,254,13,241,213,247,77,186,143,70,235,107,206,162,159,109,27,149,187,92,100,250,198,141,41,39,152,201,101,51,83,162,203,2,240,200,2,42,42,68,91,148,40,222,7,184,24,192,68,150,48,205,68,151,153,249,155,114,216,210,250,94,86,191,49,42,183,243,146,100,45,138,84,0,0,0,192,0,16,0,0,0,0,0,0,0,10,136,0,163,144,10,27,148,139,250,140,190,64,23,33,133,160,83,129,178,42,122,224,108,128,152,209,21,44,19,236,94,53,1,166,125,89,16,224,191,144,19,157,70,127,250,151,157,195,193,16,208,96,45,138,3,26,96,156,13,88,224,12,101,162,207,8,224,178,138,141,57,97,231,50,108,216,147,197,57,62,113,161,195,107,30,202,62,249,203,3,96,0,80,5,34,32,106,57,5,2,23,4,228,160,64,16,123,128,0,0,3,144,0,14,7,0,7,4,193,64,96,1,200,20,131,144,80,3,112,3,96,0,0,0,0,0,28,87,31,228,77,231,11,7,21,148,148,168,185,167,148,222,231,37,199,249,19,92,53,177,45,22,45,210,194,94,222,134,126,171,152,164,231,5,52,137,179,40,0,2,0,0,28,128,38,236,37,168,97,108,5,100,15,129,134,249,1,247,28,108,70,244,25,206,2,143,66,172,224,111,160,215,0,76,23,96,71,184,13,245,212,191,114,103,208,187,0,231,220,143,80,54,1,250,15,177,51,175,177,72,136,7,35,32,64,0,0,216,4,21,110,108,26,209,220,217,44,74,252,35,211,35,221,117,174,169,44,159,175,188,13,159,246,27,165,103,154,41,159,148,186,119,77,157,59,134,211,206,81,250,183,192,144,237,240,47,73,92,121,75,115,167,43,177,171,198,241,237,232,82,216,188,152,153,108,114,69,246,0,0,90,13,2,15,112,3,215,212,0,27,145,193,114,178,92,254,133,221,1,143,102,76,124,190,19,104,228,216,1,135,108,151,36,94,98,244,103,32,3,143,189,174,53,10,174,94,26,104,228,193,28,83,79,64,53,238,156,101,79,86,113,88,84,82,114,196,147,203,219,59,27,53,105,198,80,121,92,26,182,157,170,171,93,189,161,93,129,9,156,148,168,188,0,177,145,184,0,56,32,26,93,78,227,200,183,120,122,189,18,29,42,130,165,106,234,53,243,212,121,147,193,214,117,203,139,105,85,167,21,85,170,145,146,143,203,234,206,246,19,165,219,21,9,199,9,99,114,69,248,207,8,203,24,39,233,168,221,149,2,61,203,237,146,108,20,99,56,107,208,137,167,202,38,117,76,154,143,143,126,36,244,59,110,151,114,250,149,11,143,41,206,93,244,169,37,252,92,159,67,240,95,89,143,92,240,197,181,194,159,117,88,46,218,139,209,157,87,226,55,135,167,214,186,75,149,189,53,82,180,23,203,30,87,216,242,159,132,29,65,89,245,11,206,155,94,82,167,56,252,174,148,244,121,39,31,78,157,241,125,131,128,86,176,240,17,167,53,90,1,184,10,164,13,128,15,109,14,190,114,197,105,69,233,219,169,216,108,141,26,153,149,105,227,158,73,70,242,217,20,145,250,87,216,172,168,32,68,80,0,17,232,178,5,4,139,204,81,64,0,128,0,0,0,82,1,64,5,16,186,144,185,32,0,100,145,68,198,129,240,86,201,157,192,45,202,69,182,66,206,192,95,98,107,140,5,130,224,43,30,74,244,13,96,36,3,146,141,193,16,42,32,0,139,178,3,0,113,212,255,0,45,255,0,99,27,103,23,77,246,189,158,25,201,61,32,217,197,107,21,26,114,247,121,39,209,206,16,24,40,160,0,32,67,144,5,32,0,56,0,160,65,201,73,128,3,82,147,0,7,0,48,0,21,1,1,72,80,0,160,64,82,0,0,112,0,0,5,49,245,40,224,14,27,149,221,66,105,110,214,12,109,34,227,69,71,211,67,59,143,242,100,249,90,163,27,87,154,41,231,57,228,159,85,206,0,224,168,0,0,6,128,0,56,0,7,161,16,91,132,244,10,185,35,28,4,4,227,64,180,212,188,188,6,150,192,76,234,84,242,198,61,198,112,182,0,216,75,150,23,184,97,19,146,228,143,8,5,30,48,62,228,216,160,55,99,35,27,12,105,169,17,0,206,164,0,0,32,0,0,177,220,216,193,173,29,209,178,106,51,95,143,250,125,39,11,184,201,46,53,193,250,83,193,191,47,131,250,124,86,202,158,135,231,126,159,24,212,171,4,229,219,38,241,31,115,244,71,131,164,229,224,254,156,223,213,229,225,146,95,79,79,153,222,166,101,193,138,213,23,82,56,47,3,32,152,2,128,16,14,125,134,64,192,23,116,51,250,1,141,10,0,0,28,16,164,2,242,84,66,129,140,150,98,240,105,210,194,173,156,107,234,110,207,103,232,104,193,53,93,250,100,149,99,120,13,48,94,10,136,139,129,177,115,144,35,49,169,159,42,105,111,218,204,189,204,101,30,232,181,157,214,5,30,35,194,53,171,117,46,181,127,78,234,132,29,43,121,233,41,54,220,223,169,235,170,116,219,74,185,239,132,163,159,229,120,60,189,106,181,188,63,241,81,167,71,247,243,204,161,53,28,167,247,55,250,95,138,168,220,90,83,119,180,234,83,171,179,106,13,167,239,147,60,122,246,213,151,227,180,253,147,8,38,168,220,215,167,255,0,143,38,31,179,186,140,36,188,171,248,181,254,244,117,44,58,239,78,155,194,185,138,255,0,139,67,109,93,219,202,42,74,189,55,23,202,101,196,218,213,141,133,226,250,239,179,255,0,10,54,41,217,184,60,202,226,117,51,234,101,43,171,104,164,221,104,172,251,156,114,234,54,177,150,29,77,64,229,118,176,147,95,52,151,217,143,135,138,254,41,63,204,194,29,66,210,120,197,196,22,116,213,224,228,157,213,8,127,219,65,253,158,71,160,84,33,148,222,94,61,89,209,221,244,43,42,23,213,58,157,11,72,70,233,173,106,114,206,214,175,84,178,163,219,223,93,46,237,146,77,152,92,117,43,101,77,83,238,203,169,162,202,220,132,214,213,181,85,113,105,78,170,229,106,114,173,141,14,151,70,173,42,19,83,111,181,203,49,76,236,61,141,32,0,10,143,37,220,110,0,51,70,165,90,127,16,232,169,98,126,140,222,52,46,105,193,214,148,156,126,108,110,6,236,90,236,90,165,133,234,100,107,210,182,139,164,190,103,134,114,198,146,81,194,147,8,228,35,56,220,36,158,123,153,36,170,71,85,175,176,28,195,116,112,198,117,36,179,217,140,112,204,188,223,247,30,125,128,205,36,182,41,138,154,199,184,83,79,159,212,12,138,68,211,228,188,1,55,46,112,76,148,8,80,0,6,6,0,156,2,128,5,90,178,21,45,10,24,9,36,130,124,23,236,1,108,134,248,100,47,33,76,106,3,220,0,231,36,111,37,228,172,12,119,90,149,2,17,21,20,155,20,0,5,3,138,190,148,36,204,104,188,101,113,185,157,124,249,50,215,26,28,54,141,186,81,111,45,189,217,62,141,156,20,129,154,20,17,236,8,0,0,5,0,162,20,0,0,16,10,8,80,32,24,0,10,64,5,33,64,0,0,13,128,0,0,192,2,2,128,32,99,128,7,5,212,251,40,183,203,208,202,221,98,140,87,162,48,187,93,244,113,238,103,67,252,181,169,62,159,28,160,2,137,201,70,0,2,147,146,129,0,24,200,4,0,2,110,202,16,97,81,147,102,93,200,215,171,2,189,135,161,11,184,14,64,39,179,216,7,176,27,177,171,1,185,25,94,132,34,46,117,39,35,128,128,45,153,10,64,0,16,128,6,65,5,143,212,109,96,214,143,212,141,147,124,82,191,35,89,84,80,185,140,179,170,254,135,232,223,7,69,83,240,127,78,143,164,63,212,252,209,211,228,170,86,196,244,95,115,244,167,131,164,229,225,14,157,41,127,20,27,254,172,207,23,163,204,239,215,177,145,140,116,50,14,32,200,64,33,234,16,24,2,144,36,82,129,120,32,224,0,0,0,25,0,93,64,192,192,68,150,168,208,255,0,244,158,211,124,235,170,190,203,140,175,177,42,199,97,232,101,157,12,97,244,35,36,80,201,76,121,47,32,94,9,128,27,208,14,191,168,194,13,69,202,42,92,36,204,236,105,81,149,138,143,147,79,181,61,146,45,244,20,169,44,240,99,210,228,190,26,73,60,252,196,157,175,199,44,186,117,140,222,103,103,73,251,246,154,213,58,23,77,169,46,239,38,81,127,238,201,164,118,60,148,168,234,191,96,90,44,118,74,164,127,60,153,199,162,209,138,112,243,91,88,229,35,178,33,48,246,235,191,98,89,201,40,201,55,31,229,50,135,67,233,212,215,203,71,58,228,236,16,208,170,225,165,105,109,73,124,148,32,159,186,201,133,213,56,170,46,81,140,123,150,218,27,44,212,234,21,124,171,102,255,0,48,57,173,229,221,111,22,115,35,130,206,94,101,173,58,137,105,36,115,129,72,66,160,28,128,0,166,157,202,196,155,246,55,13,123,135,151,21,143,184,71,37,23,154,16,199,161,153,199,109,143,33,97,228,229,1,140,0,0,0,0,142,41,145,194,47,116,100,84,6,29,137,109,161,59,95,243,51,144,128,99,134,62,99,50,1,139,155,75,102,94,244,150,185,70,77,122,145,164,222,192,78,228,94,229,234,71,20,248,49,242,163,158,64,228,206,160,195,177,240,245,36,149,69,140,61,64,228,42,208,225,114,169,182,19,107,115,63,51,108,232,198,140,241,140,177,133,141,89,21,68,249,25,90,129,147,224,152,228,103,144,153,85,70,117,27,133,144,41,30,229,35,34,35,40,67,25,96,10,78,74,0,108,2,123,228,14,43,159,242,31,245,37,29,30,61,140,171,255,0,150,211,228,198,142,173,250,236,79,163,156,140,163,26,20,64,129,64,128,108,84,4,41,10,81,1,80,32,16,160,162,61,135,161,72,5,33,72,5,228,0,0,0,0,0,0,0,0,0,0,133,38,224,0,64,13,123,191,161,107,179,203,247,71,37,5,138,49,247,48,184,89,137,203,77,40,210,138,246,39,209,153,0,40,164,1,0,0,160,64,82,0,0,114,1,60,134,200,201,158,64,191,113,143,99,165,241,15,136,95,68,185,233,52,35,110,170,252,125,212,109,220,155,199,98,124,157,203,88,147,38,251,192,65,60,33,141,7,5,81,250,144,175,97,185,16,206,70,204,4,189,74,162,39,37,107,59,13,130,32,216,6,64,35,47,4,0,78,74,66,7,32,100,1,148,62,172,155,38,180,62,164,108,224,215,22,107,241,189,170,171,222,188,180,155,63,78,248,70,10,62,15,233,171,102,169,44,164,126,100,178,150,106,40,201,188,103,58,31,167,60,36,219,240,143,77,147,88,114,167,156,122,18,116,244,249,175,183,117,18,145,104,100,71,0,114,6,117,192,0,70,203,178,2,160,6,10,3,26,0,3,32,20,9,200,217,128,3,82,132,2,35,90,51,171,174,177,93,247,60,101,225,29,171,52,47,32,156,160,255,0,222,68,173,70,229,47,242,162,100,99,73,230,8,207,98,160,2,0,87,234,99,146,144,14,43,136,169,80,146,103,95,210,101,219,221,23,28,124,207,68,118,53,181,166,214,231,93,70,78,141,69,162,223,82,43,182,198,165,100,90,172,162,149,2,20,32,168,246,5,0,67,71,169,201,70,219,47,236,180,55,153,214,245,7,150,148,182,206,136,82,55,44,211,86,208,203,203,198,231,59,56,109,180,183,134,119,193,205,200,0,27,212,0,228,114,16,2,154,247,45,69,119,61,146,57,205,123,180,156,24,25,219,105,75,219,58,28,199,5,175,249,24,220,231,8,0,0,0,0,0,0,2,172,96,128,81,200,0,27,8,0,0,2,135,32,12,0,75,37,237,94,132,216,201,104,65,143,106,122,36,59,23,169,86,229,228,12,59,36,150,249,39,204,142,76,13,184,3,141,77,165,170,30,106,103,38,22,54,39,98,123,224,8,167,22,247,70,89,244,102,62,92,22,200,121,120,90,48,50,76,100,195,18,75,44,197,247,231,69,167,168,209,203,145,201,198,234,37,44,56,189,118,51,239,88,244,3,32,69,36,214,229,200,28,87,43,247,113,255,0,136,148,119,102,85,241,218,147,221,236,74,88,89,92,147,232,230,4,41,64,0,4,46,196,40,0,1,64,0,0,0,0,136,164,2,128,0,0,0,0,0,0,0,0,0,17,236,10,0,133,0,66,144,16,112,214,202,198,14,88,47,149,28,117,55,71,36,118,2,142,64,40,153,41,18,223,82,240,0,164,0,1,64,19,145,140,128,4,217,24,203,42,45,164,222,22,137,114,101,45,134,187,1,224,58,133,94,163,226,159,27,116,139,56,116,107,203,75,46,149,112,238,110,110,107,199,16,147,73,246,168,190,117,61,244,158,101,163,208,178,148,158,141,232,76,106,76,14,8,2,213,149,69,238,81,144,68,66,173,6,6,125,66,153,245,33,120,32,0,0,68,5,32,2,20,16,76,128,80,17,250,145,181,147,90,63,82,54,13,113,169,95,142,250,125,46,235,149,157,241,161,250,123,194,184,126,18,233,156,165,73,31,152,44,28,163,112,154,109,203,131,244,255,0,133,98,161,225,46,153,21,183,146,153,39,78,254,110,221,198,10,68,204,184,35,138,13,181,28,0,5,201,61,130,3,33,193,1,69,251,144,187,145,160,5,91,0,4,40,228,48,34,208,184,8,61,66,35,216,210,185,143,206,158,127,83,117,154,151,113,238,142,171,255,0,66,85,141,138,43,247,107,7,39,177,197,106,177,111,21,150,254,231,41,64,96,99,12,160,78,221,67,220,164,96,174,58,159,75,58,154,159,45,108,46,30,167,109,53,163,58,171,212,233,181,46,91,193,22,59,122,114,205,40,191,99,45,206,27,73,119,208,143,177,207,130,136,82,52,80,12,96,143,114,129,55,77,29,95,81,199,122,79,56,59,93,142,170,253,102,188,86,117,220,17,216,80,142,40,197,28,187,24,82,88,167,31,83,48,3,113,186,0,82,110,10,16,53,46,180,106,90,229,108,145,182,107,221,165,228,182,248,10,182,185,84,155,107,13,240,115,112,112,218,255,0,148,181,207,185,206,0,2,132,64,82,0,0,175,96,33,72,94,64,24,183,53,56,225,46,222,89,150,64,1,157,0,211,5,0,10,240,4,46,120,15,82,5,84,203,140,17,34,173,0,45,194,211,64,198,128,80,3,8,61,64,192,2,99,82,128,64,192,228,112,16,14,67,73,130,129,135,98,36,169,229,60,54,159,177,153,64,213,174,167,24,45,114,74,53,27,78,77,60,231,102,114,92,253,41,250,61,137,111,134,156,136,174,85,45,53,69,82,41,139,72,168,203,32,195,182,79,102,49,36,176,152,25,242,12,115,47,79,208,119,227,117,168,25,131,21,36,202,154,2,134,50,0,0,10,0,16,10,0,0,0,1,192,0,0,0,0,0,0,0,1,0,2,145,128,7,13,71,243,36,249,57,182,71,5,76,249,137,238,151,7,57,32,17,236,94,65,65,108,1,64,0,0,128,0,0,0,35,246,11,93,195,107,32,3,215,92,147,112,241,144,150,64,15,200,112,56,216,42,240,55,252,129,51,236,1,228,54,57,35,88,96,1,116,219,250,144,136,110,62,224,128,0,0,8,202,76,106,64,41,10,5,135,212,141,147,90,9,247,35,104,215,24,205,126,57,233,177,148,235,201,45,220,116,126,135,234,31,12,199,30,21,233,171,57,253,196,117,252,143,204,118,48,110,111,177,107,236,126,159,240,242,237,240,223,78,143,49,161,20,254,248,36,122,124,206,201,44,25,17,108,8,224,186,16,164,1,135,198,6,74,10,27,142,2,0,82,20,32,160,216,19,1,23,25,35,9,148,2,244,26,132,0,156,154,215,122,83,109,110,108,238,112,215,142,97,156,126,68,33,102,220,173,224,219,212,231,56,109,90,242,51,140,106,115,21,65,168,1,21,17,149,108,70,6,45,60,109,147,173,186,131,250,178,251,83,216,236,164,106,87,139,146,145,4,233,85,59,233,84,249,148,146,122,27,231,83,211,51,78,234,181,52,177,28,119,47,118,118,197,139,64,9,128,24,193,64,8,61,142,178,246,73,93,82,211,87,242,157,158,82,58,168,126,254,249,101,101,70,89,251,17,99,180,75,9,34,176,247,28,21,4,82,13,130,133,0,32,112,93,44,209,105,188,101,96,231,53,239,20,124,143,155,108,167,160,84,178,78,54,234,50,222,43,12,217,53,172,231,230,66,82,90,199,131,100,21,72,82,4,16,0,1,72,0,161,110,56,5,1,141,0,64,1,1,5,96,12,20,93,48,7,27,4,158,129,87,236,24,111,4,91,129,121,47,4,212,168,9,206,165,15,80,0,1,130,33,168,28,0,40,224,2,134,76,123,179,44,28,85,238,33,69,39,55,163,120,88,58,119,214,40,86,235,80,182,167,230,42,145,125,179,93,175,79,185,155,87,29,241,76,83,203,46,74,142,27,156,40,193,227,57,150,196,183,111,51,88,194,206,134,85,146,239,167,39,252,44,198,223,63,51,124,190,8,57,192,5,2,181,160,64,0,211,26,128,81,59,86,73,216,179,161,145,112,65,135,110,54,99,18,245,201,151,0,12,115,37,192,239,246,50,35,64,98,167,22,240,103,146,97,122,17,199,92,229,129,144,49,195,34,239,79,140,1,152,49,114,126,143,3,204,92,166,6,64,138,73,232,84,242,80,5,32,64,0,20,0,128,82,20,128,0,39,40,14,41,125,127,153,204,112,84,207,152,146,229,166,115,189,137,1,123,128,10,27,132,0,2,144,160,0,32,14,12,94,250,21,255,0,66,103,44,3,107,238,61,144,120,88,32,86,67,94,71,6,57,108,11,186,15,98,255,0,14,198,59,129,86,154,142,70,71,220,130,55,144,55,43,193,81,56,25,25,212,53,130,7,12,133,0,30,196,25,200,32,17,148,128,56,47,25,4,108,12,225,186,54,13,120,253,72,217,201,190,44,215,228,94,159,149,86,77,75,11,27,35,244,199,135,229,223,225,206,159,62,37,66,47,250,31,153,122,109,72,39,150,159,163,63,77,244,56,40,116,30,158,160,243,21,70,56,251,96,153,233,232,242,246,236,82,194,69,39,216,166,92,76,129,129,160,21,108,7,3,242,40,32,93,200,21,64,32,20,61,198,0,67,25,38,53,46,64,23,98,100,0,35,56,43,235,13,53,126,134,195,56,39,201,41,22,215,42,142,55,212,230,56,168,63,221,233,234,114,149,66,173,76,121,40,69,28,19,118,80,48,102,14,41,201,156,141,24,97,247,101,1,163,109,23,11,245,46,237,26,107,12,236,206,166,226,126,79,80,162,176,241,41,126,71,108,254,166,137,21,11,192,5,68,101,228,34,133,97,55,136,54,248,71,95,211,228,234,85,155,198,139,248,189,77,203,137,118,80,155,111,11,27,154,253,46,14,20,26,107,156,160,55,74,2,8,0,80,0,0,7,21,199,249,45,189,145,202,113,92,127,148,243,182,53,3,134,193,185,70,121,225,155,102,189,132,82,167,60,45,56,54,66,161,120,3,128,136,0,0,0,2,162,0,5,4,0,95,238,64,0,185,46,223,114,12,128,224,188,16,20,85,168,200,11,94,53,10,185,41,18,105,148,7,32,0,136,80,24,20,128,1,81,163,113,212,237,105,92,84,182,85,83,184,167,14,249,83,75,45,68,199,170,245,56,244,234,116,162,162,229,90,172,187,98,151,30,231,155,188,234,244,250,108,186,133,202,146,119,148,168,197,205,201,127,152,189,17,155,113,100,58,151,94,163,101,97,75,171,94,206,81,82,150,41,211,143,252,142,155,194,23,179,234,138,227,168,84,167,90,141,221,91,231,59,138,143,233,148,23,208,151,182,18,68,232,30,20,173,226,186,148,58,207,93,150,45,225,220,232,219,167,152,234,243,147,216,117,219,75,75,46,137,86,48,165,229,219,70,147,78,52,150,27,198,26,219,220,207,187,53,171,126,71,111,74,163,156,231,148,146,88,198,14,99,205,248,119,168,74,234,157,188,229,162,169,28,99,211,208,244,143,118,107,139,21,140,215,202,217,195,106,190,89,127,196,115,203,232,103,21,187,202,111,111,98,142,112,1,64,0,0,0,0,164,224,161,16,20,48,32,0,40,0,0,0,1,130,52,153,114,0,193,210,139,245,68,242,86,62,169,126,166,53,46,237,168,166,234,87,167,28,110,187,141,58,189,118,206,26,67,204,169,47,72,196,158,143,109,229,78,120,210,99,231,219,127,115,167,233,125,98,181,199,87,175,109,113,24,194,18,138,149,5,203,245,212,239,4,43,137,202,81,123,100,70,183,118,157,173,51,148,141,123,1,130,170,189,25,123,226,246,101,194,124,24,186,81,121,208,12,187,150,186,162,167,238,113,124,60,83,202,109,126,97,210,146,206,39,255,0,160,246,57,73,147,137,211,171,196,245,39,109,108,172,180,198,137,38,221,117,233,157,13,131,69,167,231,166,155,204,120,57,221,105,199,122,109,253,137,163,157,3,95,226,225,166,98,214,120,107,6,106,180,113,150,240,139,163,148,24,42,180,223,241,163,46,248,227,234,64,100,9,220,189,74,80,15,64,99,45,128,50,108,76,229,13,200,46,51,168,194,72,4,181,42,159,153,120,35,220,106,192,172,139,1,47,232,92,99,238,4,97,177,200,207,36,65,108,22,48,22,218,147,25,40,185,246,15,36,228,188,144,64,17,88,24,130,224,128,64,94,8,65,72,1,6,80,250,145,205,150,113,67,234,57,141,68,175,197,253,47,170,69,213,90,101,228,253,105,208,227,219,225,238,155,157,95,195,193,183,249,31,141,58,78,93,76,45,242,143,217,125,19,255,0,197,238,155,237,111,5,253,14,156,230,70,175,45,111,162,176,182,43,212,228,136,181,5,216,128,94,1,56,41,64,169,19,130,166,4,40,32,85,24,24,208,4,16,0,9,176,46,227,68,8,28,53,86,32,218,223,131,155,216,227,170,179,22,130,184,44,37,85,211,146,170,150,83,221,114,109,154,150,107,181,205,122,179,104,68,93,193,17,113,168,20,143,112,2,131,0,168,14,171,169,39,41,83,195,195,82,79,39,103,158,236,53,202,53,111,105,119,82,108,182,151,116,171,208,142,38,187,150,152,32,219,3,97,220,84,6,71,216,1,163,213,103,24,90,164,243,153,75,8,230,179,196,173,227,37,179,53,186,164,96,227,77,75,124,229,44,155,150,209,237,183,130,11,241,204,1,2,46,6,192,0,0,0,48,173,254,91,211,43,208,204,198,107,48,124,232,7,5,151,119,151,60,172,102,90,35,58,212,106,84,121,165,85,211,146,253,25,133,147,205,57,235,159,152,202,238,133,59,155,121,211,170,228,160,214,174,47,13,17,90,63,181,234,209,234,14,210,226,217,186,113,142,93,104,201,97,63,116,118,20,110,168,87,143,117,41,169,47,99,227,222,36,232,21,227,213,238,46,105,214,184,141,57,75,229,185,82,121,151,220,212,157,223,136,250,61,253,181,43,91,233,86,165,82,29,233,73,173,12,254,89,125,175,226,251,119,152,164,244,101,79,83,231,221,59,198,221,66,222,165,42,29,79,167,185,78,109,37,40,35,211,91,248,175,161,213,106,18,190,167,70,170,125,174,157,71,134,153,189,75,44,119,137,148,198,46,19,130,157,57,41,193,172,169,69,229,51,32,128,25,192,2,144,100,0,0,0,228,164,108,1,65,10,128,184,212,177,39,9,151,56,255,0,208,170,160,4,16,224,153,212,200,152,212,130,147,3,35,32,14,187,172,117,122,29,42,206,117,106,206,42,73,60,38,113,245,94,189,109,210,225,23,57,119,212,148,148,20,35,171,77,232,121,203,88,74,173,73,245,46,189,69,85,114,148,234,81,162,158,82,130,219,40,151,151,197,145,44,251,235,218,190,177,118,221,56,40,57,195,185,234,255,0,34,116,207,15,190,183,212,107,94,92,102,54,50,138,140,183,78,183,183,216,157,50,207,168,120,174,167,198,220,198,86,93,61,63,221,211,90,57,47,177,237,20,163,10,106,141,37,219,8,44,38,98,77,237,109,199,34,140,41,83,141,42,81,80,167,5,219,24,173,18,70,183,82,180,87,189,50,189,188,155,74,113,215,7,53,56,168,197,44,182,253,89,205,21,157,249,58,118,203,195,116,170,210,182,234,80,182,138,197,58,51,236,150,94,184,225,158,235,127,177,226,111,236,99,67,196,117,235,198,147,117,106,246,226,105,236,145,235,237,42,121,214,116,170,122,163,60,127,165,191,219,153,188,44,156,84,90,114,150,14,89,125,47,236,112,219,181,36,218,88,102,145,206,0,40,0,0,0,0,20,0,128,100,96,0,228,24,78,189,42,95,92,177,128,51,123,131,174,175,214,104,81,156,98,163,41,55,207,7,2,190,185,184,150,97,22,163,39,244,165,193,159,202,46,59,90,149,105,82,255,0,50,164,99,247,102,141,78,179,111,25,184,193,57,226,88,207,12,226,125,62,173,116,251,190,89,55,187,215,6,204,58,85,180,97,21,44,203,26,224,123,171,233,214,221,245,123,228,154,183,161,23,38,244,198,186,28,115,163,214,110,106,167,26,205,82,113,89,91,106,122,8,81,165,79,29,148,162,177,202,70,108,101,53,210,175,15,82,169,143,57,164,211,207,116,119,201,191,75,166,218,210,203,236,238,147,222,79,115,150,230,226,22,212,148,231,182,112,115,39,148,154,217,172,137,137,181,211,117,122,31,13,86,202,242,140,22,104,212,237,194,92,61,206,226,50,239,140,100,182,146,201,167,214,20,223,71,185,116,254,184,195,49,246,99,163,212,117,122,61,172,229,37,41,118,97,181,206,11,244,248,221,32,41,68,5,35,32,19,102,85,238,57,0,66,243,236,0,214,138,205,214,86,153,220,217,71,4,18,248,134,214,231,57,32,52,158,232,227,149,56,77,97,194,56,244,104,228,100,217,20,112,187,90,13,97,211,88,246,208,191,13,75,132,210,246,103,46,53,46,6,14,53,69,40,246,166,240,71,78,107,233,159,228,114,151,129,131,129,198,178,217,167,236,99,41,92,66,18,125,137,188,104,147,54,76,101,236,48,105,170,245,162,162,167,70,77,191,66,171,190,217,98,80,146,207,177,179,151,146,189,86,164,197,113,124,68,30,137,153,121,145,107,73,25,246,199,26,163,7,74,18,209,197,126,69,246,140,148,211,90,96,189,203,7,19,161,77,231,9,172,250,48,168,69,104,164,241,238,61,142,94,236,131,141,211,105,97,72,227,113,184,79,229,105,199,209,141,27,25,201,57,56,101,58,177,90,193,191,177,124,228,150,101,22,134,142,92,148,226,141,104,73,111,250,153,247,193,236,242,52,101,193,6,74,3,236,64,56,2,1,192,32,0,8,4,99,144,6,112,209,156,135,28,53,150,14,124,35,82,37,126,26,232,171,252,76,126,232,253,153,209,226,163,208,172,34,181,197,8,47,232,126,50,232,217,248,132,215,15,99,246,103,70,207,236,46,157,159,251,136,103,244,58,249,58,35,119,216,2,156,85,17,116,228,16,3,246,40,91,2,128,46,193,110,20,15,70,8,5,220,14,8,182,8,163,118,19,246,0,7,35,26,142,64,101,35,142,174,176,103,33,140,215,202,192,213,181,109,54,187,147,76,220,193,163,111,14,201,247,101,239,156,27,194,41,201,72,2,13,14,2,220,48,160,91,0,128,52,158,141,101,26,242,176,181,146,105,82,81,203,206,99,163,54,114,0,211,86,17,134,180,171,85,139,244,114,202,19,165,122,150,41,85,134,125,103,28,155,131,113,131,78,46,250,156,82,168,169,84,126,176,208,84,167,121,85,44,85,84,190,203,38,230,1,7,95,62,149,26,245,35,82,189,121,212,148,86,61,17,191,78,10,16,81,91,45,10,10,41,10,68,17,64,0,0,0,83,9,191,149,251,153,152,79,233,111,208,13,123,24,168,42,177,95,75,150,78,122,152,237,105,236,112,88,184,226,164,98,154,195,213,190,78,106,217,242,165,133,150,65,210,94,81,167,251,66,132,48,156,27,214,47,84,217,47,188,49,211,47,171,121,238,221,81,174,182,156,12,235,84,205,213,23,37,148,166,158,139,147,187,146,228,127,245,95,47,235,158,29,234,157,34,179,185,182,149,197,237,9,79,186,81,81,110,75,217,35,66,133,175,74,234,149,163,10,246,213,105,92,201,180,212,233,226,111,217,159,94,77,173,153,212,117,158,143,99,213,162,157,100,233,214,95,77,88,105,36,75,26,156,158,59,167,90,245,238,143,93,211,233,55,13,209,131,202,182,184,109,166,190,252,30,171,167,248,174,133,74,170,219,171,83,125,54,239,136,215,249,99,63,248,94,204,232,33,14,177,225,202,248,187,143,237,30,149,178,171,13,106,83,247,126,167,164,178,167,101,127,111,53,82,112,189,183,168,190,137,181,62,213,246,224,147,215,166,111,183,120,181,213,52,226,214,83,91,49,156,158,66,191,71,234,189,9,74,231,195,151,174,165,29,221,133,203,238,135,254,22,245,70,215,76,241,149,26,245,163,107,213,172,107,244,203,151,179,170,179,78,79,218,75,67,82,255,0,102,61,40,17,196,227,221,9,70,81,221,52,242,78,74,138,70,244,25,0,53,40,0,10,182,33,64,165,194,244,38,200,175,66,170,130,38,57,34,41,76,77,30,165,213,45,186,125,9,206,189,88,211,130,139,249,164,240,179,141,22,125,70,224,203,168,117,75,126,159,66,181,73,230,114,164,187,156,97,171,193,226,122,151,142,235,117,20,237,252,57,111,82,181,92,126,242,171,142,148,215,167,187,60,199,80,175,214,60,89,127,70,141,27,75,219,46,153,6,161,86,243,186,74,53,161,149,206,48,243,147,210,116,250,20,186,91,185,233,189,22,14,157,149,26,152,157,106,154,202,115,229,47,101,234,99,117,185,36,94,157,211,104,244,8,95,117,126,166,234,86,187,171,219,85,66,164,187,156,116,206,9,225,218,149,188,97,127,82,230,113,157,11,26,24,83,121,221,239,218,141,132,170,245,158,165,62,147,70,178,171,90,41,84,175,87,185,98,140,118,195,247,126,135,163,141,189,62,159,211,191,103,89,69,39,171,147,90,57,55,187,38,26,217,149,234,169,86,54,118,112,249,34,181,105,104,151,177,201,219,56,73,66,45,74,89,195,246,45,173,24,91,210,143,110,92,218,213,189,206,120,225,103,11,87,201,166,21,105,159,85,161,156,94,175,209,28,111,99,58,105,227,44,163,206,120,170,110,149,205,188,233,229,212,107,72,165,191,220,236,188,63,87,204,233,110,46,125,210,133,70,158,56,246,56,250,245,5,81,209,170,227,221,217,9,175,207,131,79,193,114,111,166,87,140,163,137,41,167,39,234,240,78,185,47,199,163,147,249,27,246,56,109,26,149,55,46,27,208,229,169,151,78,88,223,7,29,180,148,160,253,180,52,142,126,64,192,40,0,92,4,64,0,20,226,169,113,74,149,72,83,156,251,101,55,136,231,147,148,235,186,221,31,55,167,101,105,56,77,74,45,111,146,91,144,110,85,184,165,70,14,83,150,136,213,173,212,97,78,10,75,26,180,147,251,156,54,118,21,106,209,167,82,226,191,118,86,90,72,222,141,181,24,71,10,154,199,185,153,109,95,77,57,220,92,212,249,97,74,77,167,198,198,84,172,235,206,158,46,38,183,206,134,250,74,43,11,64,219,195,193,112,214,162,233,182,250,119,46,237,114,109,198,49,130,196,98,146,246,56,109,106,202,172,36,230,146,148,94,26,76,183,55,52,173,40,186,181,94,139,101,234,89,131,152,26,11,169,186,148,227,42,116,36,219,213,39,232,115,91,93,249,242,112,157,55,78,107,135,200,211,28,245,28,149,57,56,44,201,44,164,222,226,149,72,213,167,25,69,175,124,60,225,150,109,40,62,239,165,172,29,119,68,135,149,70,226,154,135,100,21,86,225,28,229,164,55,216,230,234,177,114,233,213,49,30,230,176,240,115,219,180,237,233,227,249,81,197,212,148,159,78,173,217,245,99,79,212,202,201,53,105,8,57,119,56,232,217,62,159,25,221,83,85,172,235,82,123,74,13,51,175,240,237,90,117,58,53,56,82,139,138,165,41,67,13,122,51,181,88,111,30,167,79,209,95,151,115,127,109,166,41,213,207,234,95,167,199,112,8,10,28,149,144,0,1,250,6,64,35,120,69,12,13,122,111,252,68,158,119,208,216,56,41,246,249,205,46,53,57,201,1,152,240,87,182,132,40,203,236,54,34,220,110,5,0,20,82,48,24,24,181,128,87,140,133,185,4,251,140,129,192,15,112,214,7,184,96,55,231,81,187,193,10,241,143,112,39,32,34,238,6,46,49,150,233,24,186,48,124,28,152,0,113,186,126,141,161,219,53,252,89,251,156,131,27,234,65,198,251,244,216,185,120,216,201,144,12,93,69,29,200,170,69,236,206,68,98,226,189,16,160,154,124,131,23,78,45,108,78,196,182,109,17,89,123,231,32,197,169,113,33,137,250,47,212,131,150,31,81,179,147,86,158,123,181,70,206,166,248,179,201,248,107,163,71,190,182,61,211,208,253,155,210,86,58,45,143,255,0,145,143,246,63,25,116,38,254,34,81,138,215,25,203,63,103,116,214,159,71,177,107,111,34,15,250,29,121,244,70,210,41,20,181,43,193,197,64,137,156,224,115,128,5,76,133,227,98,138,8,80,166,117,35,46,228,2,240,8,93,130,0,103,80,0,126,101,39,1,20,194,91,52,100,71,176,86,181,24,183,57,166,244,224,218,142,112,178,112,209,88,108,230,220,145,70,24,216,96,161,200,12,0,91,4,17,64,7,176,206,164,2,128,16,2,106,80,0,0,0,100,0,40,0,33,201,114,64,5,49,158,123,94,12,137,45,128,213,178,107,190,172,82,219,115,102,95,75,251,26,150,56,87,87,18,195,93,216,220,220,228,145,94,67,169,117,40,209,189,81,140,181,114,74,49,198,30,114,122,214,177,133,236,121,79,21,198,20,175,104,85,237,90,107,156,114,122,170,77,202,133,41,55,150,224,159,244,51,199,186,183,165,75,70,117,53,230,231,112,229,9,63,221,188,56,240,206,210,166,85,41,184,231,56,120,193,212,81,155,149,56,198,81,125,223,197,247,45,102,55,105,207,177,37,37,152,203,117,141,206,135,168,120,97,202,188,250,143,135,238,21,141,239,110,180,210,253,213,71,238,189,78,238,165,72,211,73,180,220,118,211,115,58,61,212,231,39,140,41,5,121,142,145,226,201,209,188,167,211,188,73,67,225,47,118,141,86,177,10,143,217,158,151,170,88,218,220,90,184,213,161,26,180,231,252,50,214,44,195,169,116,187,46,177,103,43,59,251,120,86,165,47,93,226,253,83,225,158,98,189,207,87,240,107,242,234,170,253,75,162,70,24,85,26,205,74,30,207,213,123,141,254,206,216,93,120,86,118,158,101,231,134,186,141,91,75,186,111,63,13,90,171,149,25,123,97,236,110,244,127,30,81,171,123,251,47,175,219,79,166,117,40,164,151,153,254,93,95,120,200,243,84,186,213,15,218,119,215,29,54,242,87,20,46,226,166,233,206,95,229,188,107,167,7,145,234,214,54,125,90,46,55,157,75,168,206,226,159,205,5,81,102,16,89,225,250,19,113,185,55,183,232,45,215,114,195,139,213,52,68,214,79,138,217,248,147,173,248,46,214,222,165,58,242,234,125,45,73,70,116,170,125,80,79,152,179,233,221,23,197,125,23,175,116,229,123,105,127,74,17,75,53,41,214,154,140,233,191,116,106,123,98,204,119,197,73,189,145,231,42,248,182,222,119,42,215,163,198,143,85,175,252,74,133,101,37,5,239,131,154,118,126,32,234,11,186,175,82,133,140,37,255,0,103,70,158,101,31,207,35,76,119,206,45,112,199,107,244,58,41,244,154,150,148,20,106,248,131,168,39,47,226,115,207,233,185,201,91,165,223,208,183,115,163,213,238,235,84,140,114,148,218,203,254,133,29,214,53,99,181,242,120,203,62,177,226,62,178,229,107,111,111,240,138,26,74,173,124,41,188,115,133,147,179,163,225,153,77,119,117,46,167,115,115,60,231,10,93,171,236,77,166,127,110,242,165,122,20,127,204,184,165,12,111,221,52,139,78,181,26,191,229,214,167,61,51,242,201,61,15,55,119,225,14,129,8,202,172,186,116,170,212,147,89,147,155,111,63,169,163,115,210,122,119,64,171,59,251,139,218,148,45,84,18,196,166,244,199,11,146,109,49,233,122,191,85,161,210,58,93,123,234,147,143,109,37,171,111,24,62,123,211,44,122,143,142,37,43,158,161,82,165,183,65,167,46,244,191,142,226,75,93,61,23,185,183,79,195,180,252,89,127,30,185,212,221,194,232,148,18,149,189,156,155,205,118,191,138,75,211,216,237,62,42,181,237,189,90,170,156,122,111,78,164,157,56,198,163,237,206,155,164,137,118,251,171,61,54,106,206,222,181,179,180,168,157,181,164,123,85,56,44,40,201,39,148,151,232,97,115,82,22,246,245,239,170,174,201,85,146,141,40,99,93,177,159,205,154,221,50,132,46,235,209,173,83,230,181,183,163,230,44,243,37,177,219,80,182,125,67,171,124,69,76,74,141,53,221,24,113,158,0,216,233,246,81,233,86,29,180,212,126,34,225,247,213,154,88,109,179,106,133,14,212,164,222,123,86,254,167,50,166,165,83,59,156,149,97,154,88,90,21,28,105,101,229,111,193,201,21,219,19,24,166,181,123,179,60,229,96,68,99,82,88,194,94,135,44,51,216,155,56,100,211,150,50,151,161,206,182,88,53,6,135,86,135,125,8,62,238,213,22,219,199,58,29,79,131,42,78,167,79,170,231,36,230,218,147,75,141,244,59,142,167,133,109,25,52,222,37,156,46,78,147,194,26,84,174,212,59,84,242,222,125,114,98,254,218,179,245,175,79,63,162,95,99,138,209,230,130,126,167,37,68,252,185,99,124,28,118,143,54,176,120,198,119,54,142,112,1,80,40,0,66,130,100,10,113,214,167,230,208,156,61,86,159,115,50,74,162,134,173,144,105,116,138,138,173,156,147,121,146,155,82,246,212,214,159,81,187,163,127,90,218,141,155,173,24,205,62,247,44,40,199,31,98,80,156,233,117,183,21,30,202,53,178,150,22,53,90,228,217,243,163,111,214,188,151,159,223,195,41,250,52,70,150,29,94,221,203,182,162,149,55,203,146,208,221,132,163,82,154,157,57,41,70,91,53,177,193,94,165,156,115,11,143,45,231,116,214,77,94,153,56,66,85,104,209,89,164,165,152,181,178,27,253,163,14,153,82,95,181,58,133,6,243,218,226,215,245,59,26,246,244,110,32,161,90,156,103,20,242,148,151,38,141,60,211,235,181,98,162,187,106,69,54,206,200,66,180,174,122,133,11,74,138,141,56,70,117,180,249,35,166,61,13,5,94,239,246,212,167,229,39,154,127,44,115,177,220,249,84,252,206,247,78,61,237,231,56,212,42,80,248,143,63,248,251,123,75,236,106,194,222,189,90,138,165,196,155,229,69,61,19,48,233,244,165,111,94,188,94,112,222,114,253,89,216,33,133,156,242,76,53,133,116,229,111,81,45,220,116,201,169,210,148,163,101,219,63,173,77,231,245,55,140,41,211,140,35,136,199,26,228,191,70,89,212,233,95,117,151,138,210,133,44,81,190,167,243,79,63,199,20,240,191,161,220,84,156,40,199,186,173,72,194,62,173,224,232,58,151,84,177,189,140,99,111,26,213,174,40,207,186,148,233,197,225,75,221,250,19,149,200,177,232,61,138,113,91,78,164,232,70,85,146,85,30,248,57,121,42,24,28,141,128,20,17,2,138,64,49,161,6,189,47,243,166,253,94,17,176,205,122,127,231,188,189,89,207,198,4,24,238,80,138,128,160,155,20,0,5,2,17,151,238,48,176,6,37,224,173,16,8,30,163,144,128,15,96,222,163,112,0,127,96,0,1,184,12,233,177,10,64,9,100,21,104,51,168,16,112,52,32,21,17,128,200,0,16,128,0,10,206,159,212,142,115,130,159,212,115,100,212,172,215,225,190,135,165,195,215,235,93,184,63,103,244,216,168,116,139,40,47,225,161,5,253,17,248,203,162,40,186,203,57,203,105,47,204,253,161,101,159,217,182,153,255,0,185,139,254,136,235,228,35,153,34,224,133,201,197,77,136,61,192,23,118,30,163,128,138,24,40,12,40,144,220,12,0,12,133,94,224,7,32,165,2,103,65,186,15,98,32,131,250,125,192,97,28,20,220,124,217,37,193,204,180,56,147,110,179,219,7,54,73,20,41,55,15,68,80,97,129,200,83,128,129,64,136,50,243,160,1,144,69,232,80,28,6,9,200,21,0,30,161,5,168,41,2,133,0,32,0,2,240,70,178,154,201,73,195,3,86,210,89,184,171,31,69,185,182,244,102,141,163,148,111,235,69,250,103,6,235,17,94,99,198,80,143,192,198,167,122,140,251,180,111,251,29,239,76,175,27,158,145,103,94,59,78,148,95,244,58,143,21,198,156,236,20,42,65,203,185,230,56,245,57,252,45,82,79,195,244,41,73,98,84,155,131,95,99,18,255,0,42,191,245,118,245,94,41,77,173,210,208,234,45,106,206,82,154,127,197,38,240,118,181,245,183,151,178,58,155,25,42,182,242,168,220,98,251,154,89,45,237,27,116,233,167,82,18,150,217,57,37,37,231,56,110,253,78,56,247,97,45,212,119,49,79,50,202,121,96,115,193,169,37,36,254,231,31,125,88,85,170,234,184,206,140,180,237,105,99,30,230,120,237,132,154,88,120,56,212,165,56,164,214,115,186,3,194,117,255,0,195,168,187,255,0,218,254,28,168,169,84,142,101,86,203,248,42,175,247,125,31,182,199,159,86,53,60,65,90,162,177,157,43,123,152,83,242,238,44,166,177,45,55,126,199,216,227,24,209,146,148,52,208,240,254,50,184,141,189,194,191,232,54,244,167,213,163,242,213,106,25,148,160,254,222,248,37,147,234,205,120,62,167,212,63,217,168,202,221,90,81,173,7,15,46,165,189,105,201,201,75,216,232,250,23,225,199,93,241,45,234,169,228,187,74,51,125,210,157,71,142,212,253,183,62,159,210,188,41,59,187,170,125,91,169,214,117,47,106,77,85,156,92,23,106,104,246,23,221,78,22,54,147,169,23,8,201,203,13,236,56,250,139,123,117,221,3,193,158,27,240,143,151,43,58,79,227,41,193,169,87,157,70,220,179,191,56,59,171,158,171,10,22,21,107,209,165,42,213,35,28,198,9,253,79,238,121,95,137,171,90,53,47,46,170,69,82,105,198,20,222,239,39,101,8,78,219,162,98,190,20,231,31,150,28,165,130,239,244,153,253,181,172,174,46,186,213,212,174,238,211,167,228,188,121,49,250,125,119,228,244,86,125,74,157,119,223,57,56,166,182,124,29,58,77,244,248,83,182,147,131,156,83,138,151,30,166,118,244,254,21,79,203,166,156,230,190,105,55,187,18,213,190,219,215,16,81,190,156,160,211,111,231,131,95,109,141,171,91,175,58,154,195,249,154,217,154,85,40,212,165,82,218,188,101,149,221,170,254,200,202,218,120,234,117,84,18,214,148,102,151,191,36,250,141,218,181,37,240,78,114,202,146,223,7,73,213,186,5,175,84,175,70,189,239,125,88,83,146,156,105,55,242,183,143,67,183,181,155,169,43,136,205,102,47,84,176,113,169,74,75,18,89,113,224,168,219,177,147,149,187,167,58,106,17,73,40,199,140,18,250,222,214,230,20,237,235,198,61,178,250,86,52,201,133,189,79,223,167,39,186,193,201,125,73,206,16,148,87,205,7,148,203,190,143,174,150,255,0,202,181,199,74,167,90,62,125,198,144,73,99,11,147,122,210,148,173,233,206,148,187,169,212,199,111,118,52,126,231,29,107,90,110,237,95,56,247,84,142,191,99,178,182,184,248,154,10,79,31,98,21,208,220,245,14,163,99,39,74,230,50,116,212,151,101,122,113,202,199,185,217,83,191,148,212,60,201,44,53,163,245,59,47,46,61,174,45,41,70,91,166,116,215,221,45,80,132,148,59,188,135,175,203,188,31,183,176,164,178,187,74,51,85,87,116,94,81,149,89,168,83,114,216,234,232,249,148,33,21,78,111,41,106,222,185,59,26,85,41,220,208,195,107,185,106,209,96,198,56,169,89,73,47,163,57,203,245,193,181,13,96,141,104,199,78,200,242,245,126,198,202,120,196,80,136,211,234,47,228,130,89,202,203,53,60,63,70,156,41,84,156,31,116,155,249,159,185,177,212,234,74,49,151,102,59,227,6,245,56,60,55,24,252,13,73,172,102,82,215,4,250,191,29,157,119,138,19,150,51,133,156,122,139,117,21,66,29,187,99,37,173,254,84,151,170,20,22,40,172,108,105,28,128,2,160,2,36,164,163,187,72,131,34,55,133,150,69,40,202,57,82,77,26,53,239,92,171,198,133,40,55,157,228,75,87,28,245,46,225,23,218,179,157,179,131,142,141,10,147,171,230,84,147,237,244,124,156,180,104,118,46,233,188,183,174,61,14,126,10,58,222,179,230,81,133,189,197,8,230,113,154,139,126,137,156,157,66,159,153,43,107,132,155,236,150,90,75,86,153,207,121,71,226,44,234,83,203,79,29,201,253,142,14,145,113,241,61,58,18,121,204,91,139,207,177,47,120,124,114,46,159,65,215,117,231,30,234,146,195,203,57,233,209,133,37,46,200,164,228,242,244,51,5,193,171,90,29,183,148,106,198,45,231,49,147,244,54,180,123,12,5,30,18,0,14,58,247,20,45,96,231,113,90,20,226,185,148,176,116,151,126,42,183,166,187,108,168,202,226,127,205,140,71,245,22,225,154,244,9,28,23,55,150,182,116,220,238,43,194,156,82,229,157,12,42,117,158,175,78,46,50,248,122,114,223,181,96,219,163,225,203,85,24,187,150,235,84,92,203,82,109,248,103,246,159,237,21,26,213,124,187,74,21,106,63,231,113,194,56,235,75,175,222,84,113,163,40,91,83,75,119,28,182,119,52,109,168,219,199,182,157,56,197,47,99,149,189,6,95,171,191,211,162,165,225,197,82,164,106,223,220,212,184,168,150,237,233,249,35,182,163,107,111,111,30,218,84,163,20,189,17,204,4,145,52,40,33,64,20,133,0,48,82,8,31,160,13,225,100,13,106,41,42,174,75,124,179,96,215,164,146,173,83,27,75,83,96,138,108,1,112,84,56,24,0,0,200,46,0,139,56,28,0,4,28,149,238,69,184,12,104,66,147,144,0,0,0,61,202,4,108,114,57,42,96,65,142,74,0,132,41,0,114,0,228,1,10,78,8,4,40,32,129,19,58,149,5,103,15,168,231,56,41,239,147,151,185,26,140,215,225,222,139,159,62,24,199,214,183,251,159,179,172,228,229,211,44,255,0,252,140,63,178,63,27,120,122,155,173,121,10,81,89,148,164,177,247,63,100,217,44,116,219,53,233,66,25,251,225,29,60,132,108,162,145,123,140,229,232,114,80,6,198,0,164,64,115,128,50,65,128,85,76,250,148,105,186,39,184,69,228,114,82,5,82,61,199,220,20,48,30,227,216,164,68,35,43,38,160,113,69,56,214,147,221,51,155,70,113,237,85,25,136,43,209,13,200,203,176,13,70,193,19,32,80,0,21,2,34,133,8,92,147,58,129,66,212,106,22,224,48,7,35,32,80,66,132,0,0,0,0,82,112,3,216,13,74,45,124,108,191,155,183,83,108,212,165,173,219,219,185,239,246,55,24,139,93,111,89,162,171,89,181,36,177,20,222,125,14,167,194,151,43,231,163,41,247,74,172,21,92,29,253,229,47,54,218,113,123,97,228,241,221,38,187,181,241,4,103,82,17,165,8,199,177,97,232,227,156,104,98,250,171,58,123,106,152,236,121,89,94,135,85,10,120,77,70,24,110,90,68,237,43,172,39,174,57,58,248,206,74,242,154,81,110,59,182,106,163,102,148,254,106,239,181,165,7,219,170,53,211,106,122,45,55,201,177,81,202,80,171,39,180,180,53,92,187,41,73,167,174,52,201,40,231,82,239,198,117,203,49,170,225,111,218,234,77,69,55,203,52,47,58,173,27,27,41,214,148,155,154,90,71,27,191,67,201,246,245,175,16,92,85,185,175,9,198,218,132,123,161,74,155,254,228,214,164,215,101,121,226,75,202,253,82,22,150,214,238,52,170,202,80,141,95,76,114,108,208,141,159,72,171,10,86,169,87,190,174,251,167,82,79,83,206,116,215,119,97,78,181,107,219,137,92,212,239,106,149,188,98,161,229,199,223,212,230,240,237,73,86,188,185,235,125,70,31,15,105,107,150,162,181,114,107,130,201,171,94,155,173,245,111,216,253,55,206,156,28,174,38,251,104,210,166,179,175,243,63,68,121,217,78,165,242,117,107,83,169,81,119,119,118,211,249,156,165,232,114,209,188,187,235,183,85,58,141,106,84,233,88,202,125,148,98,214,106,84,246,72,246,125,43,167,194,198,217,55,70,48,169,63,153,175,66,217,181,158,154,150,62,29,165,230,81,185,187,156,165,56,164,227,69,237,19,175,234,85,105,94,117,57,37,39,251,172,193,44,254,167,167,173,57,70,149,73,69,230,93,175,25,245,60,164,126,30,149,124,205,69,93,73,229,197,73,188,153,176,142,126,200,214,140,126,124,74,154,198,13,138,17,112,139,169,45,87,25,49,140,35,74,156,164,223,215,39,219,39,253,141,219,138,73,194,132,59,116,120,202,219,80,54,107,66,82,233,203,181,102,113,93,250,157,61,221,122,125,42,118,215,149,106,190,249,199,203,81,130,238,223,25,122,30,139,182,50,167,219,133,218,214,26,58,222,175,66,133,59,91,106,181,41,78,110,149,69,219,26,107,87,145,97,25,217,213,143,114,156,155,79,57,195,71,37,122,106,19,156,146,120,122,154,221,54,229,93,117,43,152,78,147,131,81,198,36,176,241,193,191,81,118,67,18,95,238,167,236,95,136,225,181,148,229,66,166,169,181,172,116,55,169,73,85,165,22,245,202,52,109,147,167,85,65,103,15,70,110,209,143,151,30,204,99,27,33,6,173,56,53,86,165,181,79,154,13,103,184,198,215,186,141,121,69,199,17,90,36,109,87,181,133,106,148,230,254,168,60,162,78,143,117,69,55,186,22,9,117,121,27,74,62,99,139,150,169,28,148,171,42,178,116,231,30,217,181,158,215,202,37,90,16,175,66,165,26,139,49,146,199,216,234,85,210,183,148,45,46,36,252,250,95,228,213,254,101,232,202,142,75,219,57,91,230,116,59,156,30,62,92,231,30,166,51,110,140,163,94,11,92,99,17,127,232,118,148,107,70,238,131,107,71,244,206,62,140,235,252,149,101,44,203,186,116,164,240,223,49,126,164,86,197,157,236,110,27,131,135,108,248,94,166,226,92,157,13,197,173,104,245,11,90,176,155,88,158,101,131,183,177,189,133,237,14,255,0,166,125,206,45,125,153,97,92,29,69,47,42,164,166,187,163,229,184,227,212,226,240,237,53,79,166,233,20,156,165,150,151,7,63,85,196,109,219,123,14,141,7,14,157,22,214,28,228,222,9,59,62,54,235,235,73,162,208,88,163,21,232,42,253,31,153,105,253,8,179,180,100,95,98,28,23,119,10,222,147,121,249,158,200,186,37,123,158,223,150,26,188,227,39,28,40,86,171,38,235,225,46,48,91,43,117,24,170,147,250,165,174,61,25,185,169,36,209,211,117,42,49,183,236,141,7,40,205,172,232,115,244,153,202,173,38,235,172,92,67,73,44,106,137,111,83,227,47,36,214,49,9,52,255,0,33,95,182,211,172,70,170,202,87,41,70,94,141,162,73,239,87,253,59,32,57,6,208,194,105,167,179,88,103,85,100,254,10,250,226,210,114,81,131,196,169,231,156,157,169,165,212,250,85,167,86,162,169,221,83,207,107,204,100,158,26,37,35,107,229,134,37,58,145,75,221,154,181,186,181,133,5,46,235,132,220,119,81,212,208,143,134,45,161,44,186,181,101,166,153,147,208,217,165,208,108,161,218,229,23,54,158,117,228,158,215,211,86,231,196,115,84,187,186,125,148,174,165,140,225,203,183,253,25,164,171,248,143,171,65,252,203,167,172,253,16,142,94,62,236,244,148,108,237,173,227,219,74,140,32,179,157,17,207,146,126,55,237,54,124,143,53,67,195,30,126,39,212,36,170,79,150,219,127,220,238,173,58,117,173,149,40,194,149,40,252,171,29,205,106,205,160,106,73,18,219,76,128,2,12,152,201,64,197,76,21,2,1,72,82,20,80,66,144,0,5,3,23,44,20,227,122,212,251,16,97,24,165,87,59,156,220,28,20,229,254,35,183,212,231,36,20,153,212,163,5,0,10,4,40,35,1,185,72,24,16,4,0,127,16,228,114,50,4,200,69,32,21,144,37,168,0,82,0,3,236,0,16,23,30,161,129,6,229,123,16,6,72,192,122,144,8,134,3,216,130,53,169,118,4,10,229,167,185,201,148,113,211,220,230,199,177,169,53,154,252,63,225,236,171,168,77,103,49,150,152,245,63,100,216,105,210,236,147,223,200,134,127,68,126,56,240,220,101,43,218,113,90,102,113,95,212,253,151,108,156,44,237,163,233,74,43,250,29,60,135,23,47,37,251,0,114,85,38,192,47,184,21,114,0,79,66,129,72,92,128,198,129,32,2,128,12,96,10,76,14,64,0,82,1,11,238,9,247,8,225,149,106,110,95,86,169,156,138,164,90,223,24,245,56,229,107,70,77,183,19,142,167,79,161,85,167,46,253,56,82,208,158,199,44,238,33,28,103,92,237,130,170,233,175,165,224,224,141,141,56,75,186,57,251,55,161,201,229,181,176,86,126,114,223,26,21,77,51,137,169,37,135,18,119,175,229,107,242,3,99,185,122,142,229,234,106,249,208,82,196,158,62,229,82,165,81,172,84,194,245,26,152,218,224,100,215,78,82,151,201,90,24,244,47,239,94,145,148,92,151,13,133,108,20,225,138,175,156,225,125,178,29,74,144,250,161,171,244,40,230,206,1,175,241,56,90,210,155,193,197,251,86,213,125,126,108,23,171,166,223,246,26,55,65,193,78,246,214,172,154,133,120,63,102,240,115,166,164,178,154,107,216,10,1,50,17,65,19,79,146,128,0,1,72,192,96,106,83,88,186,111,157,141,198,141,104,225,92,108,109,99,34,21,132,227,223,7,12,227,40,249,215,138,31,193,93,219,249,109,190,214,245,247,79,39,209,217,228,124,93,98,252,132,213,7,53,39,172,146,213,25,231,23,141,122,75,91,133,125,211,232,220,45,28,224,155,251,224,194,81,253,244,86,112,185,104,210,240,221,89,74,199,201,154,146,237,107,25,244,193,217,206,17,141,76,190,11,220,43,170,159,91,180,167,90,165,172,213,71,37,151,244,232,121,155,190,173,230,245,25,183,82,116,173,227,31,225,229,250,28,61,74,246,175,196,221,170,58,215,169,38,169,225,103,236,115,71,165,193,116,218,22,55,112,243,111,238,53,156,163,252,12,151,253,181,152,215,167,59,142,169,121,74,157,85,8,83,132,178,154,215,43,220,244,93,66,234,149,141,155,183,161,39,12,172,185,174,95,250,152,174,153,14,157,211,169,198,222,221,254,242,74,13,173,100,119,86,253,34,218,20,233,202,188,21,74,177,89,78,92,18,74,90,240,151,253,30,246,173,9,245,122,142,20,234,77,168,209,167,83,76,199,215,6,236,58,21,107,63,14,198,223,168,66,85,101,119,85,254,238,148,218,198,86,114,122,238,161,210,233,223,92,81,173,41,46,234,122,36,214,86,62,196,189,139,165,113,66,109,206,163,138,249,98,150,137,154,233,37,117,158,24,232,95,179,161,221,89,205,249,107,20,227,55,156,27,61,110,246,188,107,80,167,66,77,119,60,74,56,213,157,195,147,141,14,250,152,88,142,95,177,231,97,90,87,213,106,84,117,34,148,31,202,223,161,47,162,123,187,93,188,42,202,165,14,206,214,166,145,163,11,43,133,115,57,206,170,151,122,217,67,24,54,186,108,251,167,219,157,92,91,59,12,224,118,154,234,174,40,118,82,167,78,43,14,58,197,179,154,238,47,204,183,132,86,92,183,127,99,118,165,40,85,135,108,145,85,56,168,197,99,61,171,8,97,172,240,150,219,26,247,209,238,181,126,169,229,27,11,115,26,171,186,151,110,52,102,172,71,81,66,110,29,82,156,85,55,170,89,147,231,39,103,126,212,45,42,213,111,232,93,198,149,79,49,93,82,237,250,84,151,232,118,147,132,107,210,169,78,81,204,101,22,154,124,147,22,186,238,155,81,92,211,111,187,231,167,44,52,247,59,36,176,242,247,60,199,68,140,237,228,234,85,207,155,81,184,75,239,29,50,122,88,77,78,58,60,147,137,89,103,85,142,68,180,104,224,169,25,84,165,40,194,110,50,123,53,193,214,116,187,186,245,43,84,181,184,171,41,86,167,190,86,235,37,212,199,114,181,108,213,186,178,167,121,78,165,41,197,41,74,63,44,185,70,206,126,100,204,101,165,88,203,32,121,110,155,214,106,244,219,248,244,235,218,51,243,20,154,243,86,170,107,135,247,61,77,68,165,87,11,13,61,26,245,58,95,17,89,119,82,248,218,16,205,106,50,140,218,245,138,221,28,150,157,70,131,242,165,7,83,21,62,148,214,139,219,36,147,26,190,253,183,148,37,70,225,247,201,121,109,61,247,52,171,65,89,87,167,89,85,113,162,222,92,146,209,29,149,228,227,27,10,245,158,189,148,165,47,209,29,7,78,234,112,189,233,20,41,215,237,74,164,48,212,150,80,182,79,73,29,141,229,106,87,212,40,70,157,88,203,190,92,51,180,167,5,78,148,33,29,162,176,121,30,154,233,219,245,170,112,164,226,233,198,46,51,151,9,250,47,200,245,233,166,178,158,87,4,227,116,179,24,85,254,31,185,148,62,147,26,203,41,62,76,227,170,76,215,212,100,104,214,140,46,174,33,9,67,61,141,188,231,99,123,94,22,166,143,77,169,10,244,170,214,134,206,163,95,163,212,82,55,176,150,18,217,6,210,140,159,9,2,78,46,84,229,21,187,88,42,58,174,137,28,213,189,169,235,60,36,142,110,173,8,202,140,27,146,82,134,101,20,107,116,90,214,214,255,0,21,66,85,33,9,194,167,204,187,181,102,111,55,253,70,19,139,204,18,199,170,193,153,211,89,237,218,193,230,156,91,122,184,162,132,146,88,91,3,76,128,0,160,1,4,10,136,0,1,201,112,4,5,35,40,0,2,128,0,12,152,5,32,133,0,160,3,32,20,227,107,231,71,33,195,83,252,216,203,58,45,18,245,37,28,116,83,243,230,241,162,209,27,38,174,91,188,81,206,48,245,94,198,211,16,10,66,129,10,136,82,129,10,64,25,12,2,9,144,48,0,143,82,132,84,4,38,229,221,143,100,3,98,123,149,250,0,34,216,19,146,128,200,91,128,1,17,149,16,0,27,133,128,4,12,18,129,10,66,1,22,197,9,17,92,148,183,54,13,122,95,81,176,117,224,197,126,36,240,170,83,234,86,233,166,241,86,63,220,253,145,70,79,200,162,191,253,154,254,199,227,111,11,39,251,74,217,167,175,157,21,253,79,217,52,244,165,75,60,65,26,230,188,92,133,34,46,199,37,25,16,224,32,27,2,238,77,138,40,192,121,101,91,128,3,65,140,133,50,6,192,6,173,130,164,70,192,1,192,0,78,70,74,17,50,11,128,4,198,66,69,228,104,0,152,46,70,66,164,162,165,164,146,127,145,131,161,73,227,247,113,253,14,65,156,4,107,212,233,246,245,23,209,143,179,49,151,79,163,40,168,247,78,41,122,51,108,12,87,93,62,146,155,196,111,110,97,30,20,101,130,199,167,220,82,202,135,80,169,40,191,225,168,147,59,2,61,194,52,21,46,165,25,73,249,180,230,191,134,45,96,203,187,168,121,127,189,167,65,255,0,187,20,111,32,7,85,58,21,106,190,233,218,71,63,124,24,84,178,75,31,37,90,109,113,25,189,14,228,143,212,152,174,157,78,113,120,140,235,47,248,145,200,238,171,195,233,168,155,219,230,92,157,166,50,78,200,61,92,19,127,97,137,174,161,213,173,86,167,116,220,84,151,163,193,101,117,90,158,138,77,35,180,149,10,50,222,148,127,67,23,105,69,232,225,160,197,215,92,186,157,88,75,230,148,113,254,242,57,151,86,130,148,84,162,155,127,202,205,153,216,219,212,142,39,12,152,62,155,106,255,0,236,241,246,25,79,76,163,127,70,77,39,148,206,88,86,165,85,53,25,167,249,154,181,58,85,188,150,33,41,195,221,51,70,203,166,181,86,111,205,110,48,110,41,174,125,198,221,61,59,135,31,158,44,230,107,6,173,42,19,134,26,168,218,244,124,27,26,149,25,104,145,215,245,90,50,175,108,161,25,118,252,203,185,189,176,111,152,87,164,171,81,149,60,125,66,143,61,208,235,41,245,155,138,48,169,221,78,148,116,198,205,155,126,32,234,116,172,172,107,69,201,170,146,131,140,113,252,205,104,117,214,19,149,143,88,171,9,81,132,20,27,89,92,175,115,67,174,85,133,237,86,235,40,170,42,89,95,62,173,152,151,35,93,214,143,131,58,101,94,163,119,94,234,230,111,182,150,33,7,239,141,89,238,45,122,85,189,180,91,147,149,90,142,89,115,150,230,135,135,93,40,81,167,111,100,160,232,198,154,148,166,185,147,108,238,220,176,241,161,172,57,95,108,180,217,108,184,13,225,54,206,56,73,183,54,243,161,133,70,229,86,17,89,213,234,93,101,206,71,128,223,204,210,217,5,171,136,29,103,95,188,86,214,62,76,83,149,74,201,165,21,190,14,154,209,121,180,27,146,236,132,35,132,156,48,242,99,227,26,181,103,78,230,20,102,168,86,140,34,163,86,79,233,142,115,38,191,35,102,54,255,0,3,208,108,163,222,170,85,116,226,229,39,175,126,81,207,151,186,222,122,110,116,89,57,57,212,148,112,251,48,151,177,219,73,252,144,206,53,58,206,138,212,225,113,136,97,71,11,62,231,101,186,137,169,211,53,201,147,40,238,140,89,210,221,117,27,200,245,40,208,165,36,169,247,107,218,179,161,119,8,239,94,143,66,75,88,191,65,45,24,221,21,26,169,57,220,36,163,132,150,114,109,167,140,24,69,118,200,58,157,178,219,62,162,14,141,73,83,187,169,73,70,95,52,223,205,198,25,216,91,74,116,46,29,60,102,151,99,147,111,124,157,77,75,189,105,213,169,149,251,247,7,219,232,142,230,230,49,175,104,171,209,150,62,92,197,163,13,86,202,107,189,53,177,231,239,107,124,23,137,113,58,109,70,226,41,66,73,232,206,198,85,252,155,106,114,206,101,220,150,9,214,236,149,213,189,58,249,249,233,60,199,30,165,248,147,211,125,54,227,246,51,169,30,232,167,233,169,215,116,59,181,125,211,229,52,165,242,205,197,231,150,142,203,234,166,209,103,72,194,120,148,117,217,172,52,121,218,214,239,167,92,78,131,204,173,107,62,234,109,111,78,103,160,207,116,22,143,67,142,234,210,55,118,242,142,157,233,102,63,112,178,176,140,254,50,197,211,79,231,81,237,145,208,220,249,212,35,83,25,148,161,132,165,133,193,187,209,110,148,165,89,227,21,41,203,178,180,125,25,58,182,105,216,222,194,15,247,146,74,73,251,51,22,111,181,158,174,36,40,59,139,72,213,166,251,219,151,115,88,89,76,223,233,151,174,180,157,41,172,99,68,243,207,161,167,209,229,10,117,99,109,44,40,85,167,242,103,150,183,53,237,157,107,62,167,82,149,196,251,96,158,32,253,75,184,119,233,232,106,188,84,138,75,44,229,75,6,191,152,170,246,73,122,155,5,101,83,195,49,167,78,20,163,219,8,226,57,111,31,114,162,154,2,144,4,107,212,176,181,169,81,212,149,24,247,189,222,14,90,84,97,66,29,180,226,146,51,3,20,224,0,80,0,4,2,28,2,3,0,5,0,0,1,72,84,0,1,66,114,10,4,41,1,5,0,133,20,133,33,1,156,117,49,221,28,172,234,114,156,53,86,90,203,192,163,130,58,117,9,41,97,189,211,228,220,53,41,181,241,146,195,238,211,115,108,0,28,140,1,72,0,20,16,160,65,178,41,0,16,185,0,76,105,232,62,197,224,128,3,27,147,144,28,128,244,96,7,35,33,144,1,120,12,152,2,25,2,123,128,25,212,16,10,64,66,80,12,2,41,168,228,112,66,14,90,90,182,108,26,244,183,54,14,188,58,98,191,18,248,89,119,117,10,17,79,18,117,98,147,244,212,253,146,154,106,24,254,84,126,57,240,164,20,186,149,178,215,187,205,139,95,147,63,100,77,98,81,94,137,26,242,16,220,184,208,33,193,201,164,40,0,19,4,42,216,161,168,15,82,173,192,0,194,10,110,50,11,128,38,195,145,184,216,1,11,192,8,133,68,42,0,66,144,2,216,23,1,0,28,128,21,119,34,192,251,148,6,64,228,4,77,138,9,176,86,64,153,8,34,128,24,0,69,236,80,0,0,3,0,172,12,90,206,134,133,171,148,107,86,131,77,37,45,61,206,197,26,84,148,85,221,84,231,171,215,1,91,177,209,96,175,97,21,161,89,81,136,223,28,12,149,233,28,144,121,79,17,69,82,243,174,123,165,136,225,203,181,227,62,199,143,235,247,53,238,254,30,141,42,48,162,234,172,211,242,214,175,215,39,180,235,147,163,8,202,87,17,205,39,171,138,228,240,182,221,58,181,26,142,116,148,93,87,38,233,202,79,56,203,57,242,111,143,79,163,120,106,197,88,120,122,138,196,148,230,187,165,157,242,118,48,88,236,238,122,189,114,113,74,164,173,186,45,60,125,74,146,215,220,89,169,59,106,114,169,55,41,202,57,126,198,217,114,209,150,105,205,165,252,77,25,69,126,249,54,139,13,41,109,201,99,172,128,203,58,55,232,112,218,212,169,82,233,169,118,246,40,232,189,206,93,169,200,211,180,106,21,234,55,152,168,166,219,101,30,115,196,113,149,247,87,140,52,237,147,242,59,125,114,206,223,171,208,141,58,182,176,150,144,132,84,112,185,224,234,250,93,188,58,183,138,29,227,95,187,162,251,224,253,120,201,222,117,218,30,117,8,87,115,112,84,101,151,142,76,103,171,90,222,163,91,160,119,83,163,94,21,52,148,170,54,151,183,7,108,155,93,184,252,207,59,225,187,184,215,185,184,82,147,142,26,130,76,244,88,105,168,251,137,211,53,200,147,114,208,243,177,175,223,213,167,85,199,17,166,251,26,79,248,178,122,72,233,54,253,17,226,106,198,173,94,167,82,49,171,26,110,87,49,158,188,235,170,45,132,123,118,176,146,123,173,194,122,9,60,203,238,98,222,13,34,242,97,81,246,209,168,214,51,141,12,147,249,176,113,215,203,163,36,182,123,176,60,84,111,148,111,45,236,37,243,202,117,37,221,37,252,51,103,180,182,140,85,170,164,146,249,62,86,143,3,82,246,53,122,205,106,85,29,58,16,141,100,233,233,243,77,250,158,238,201,98,131,254,108,252,223,115,60,90,229,49,215,92,73,124,93,59,88,166,187,164,119,18,93,246,18,139,89,125,184,252,206,190,254,10,157,120,86,237,249,179,164,189,25,177,211,107,169,198,84,37,62,250,153,114,127,153,98,94,157,119,66,170,233,94,214,182,199,106,150,100,163,254,167,122,147,140,189,143,55,78,226,149,46,189,56,69,247,85,167,242,84,199,11,255,0,156,29,196,58,181,55,213,231,211,42,208,171,74,175,103,125,26,146,142,97,90,60,226,94,171,209,234,78,57,61,21,183,56,242,183,244,16,250,240,247,56,227,93,203,140,101,150,79,19,79,76,26,71,85,78,48,182,241,29,213,23,28,43,200,169,71,238,150,14,123,251,110,235,116,255,0,151,229,151,186,53,188,73,223,109,78,151,81,162,179,82,134,169,122,157,141,90,209,188,179,139,134,138,112,238,202,224,207,250,95,246,243,183,13,208,86,247,46,50,237,167,53,218,241,178,201,219,245,74,80,117,41,222,41,39,30,223,254,89,214,206,42,116,167,109,26,211,154,113,107,18,102,207,78,115,186,240,155,141,88,167,82,148,37,77,60,238,150,153,50,181,191,97,83,186,156,112,243,157,77,252,158,103,160,214,171,77,80,167,230,121,144,198,37,55,187,61,46,62,108,34,241,187,18,246,205,109,130,152,229,228,200,220,64,0,0,0,16,0,21,64,1,0,160,129,0,0,0,57,0,0,1,66,20,133,2,130,0,28,12,20,130,20,2,136,92,0,0,226,169,245,36,114,156,53,19,243,99,174,156,144,107,210,151,248,233,199,28,228,221,52,169,37,241,211,169,174,90,237,55,132,40,129,6,192,0,24,2,128,66,128,0,128,55,65,128,4,206,131,145,192,2,21,236,24,17,238,56,27,0,33,66,216,175,84,4,27,141,145,54,0,6,195,112,4,47,176,2,19,12,160,148,64,2,34,131,144,245,8,14,90,91,156,231,5,45,206,115,167,14,152,175,197,158,15,207,237,75,62,95,156,143,216,181,62,191,200,252,127,224,120,170,157,115,167,211,226,87,16,79,237,147,245,252,223,207,167,162,47,145,98,175,81,185,10,115,81,228,134,91,19,58,129,11,130,20,161,146,144,190,128,30,72,82,1,120,0,129,87,62,132,0,32,0,192,20,0,1,19,112,80,25,33,64,1,128,2,153,11,35,216,187,32,8,12,128,12,17,106,84,1,2,162,114,17,64,0,0,64,0,0,10,66,135,161,65,232,141,10,10,46,242,180,223,213,132,145,187,55,136,73,250,35,66,223,89,185,46,117,201,42,199,99,23,178,201,94,119,49,142,121,102,88,208,34,106,132,181,13,15,226,40,243,126,39,112,167,26,81,92,231,131,204,244,170,21,101,127,66,50,93,209,75,186,89,90,231,39,123,226,170,254,101,221,58,73,103,179,50,238,79,102,107,116,59,106,170,55,119,243,89,115,156,99,21,156,232,151,252,206,87,246,110,122,142,243,172,206,78,202,52,105,180,165,133,141,77,187,57,246,91,82,140,162,251,177,169,212,221,73,92,94,193,39,137,199,13,175,99,181,182,146,149,203,75,233,75,111,67,125,214,113,205,26,189,213,156,61,22,78,104,253,77,154,112,74,87,242,249,154,211,250,27,177,223,77,138,36,219,84,165,141,206,178,172,154,180,175,53,36,190,87,150,217,189,90,171,138,148,19,75,60,179,207,117,249,170,61,14,88,213,206,93,159,124,146,140,252,21,22,233,94,84,206,84,103,229,199,211,27,255,0,169,223,245,4,159,78,173,149,156,172,96,209,240,189,170,178,240,253,8,37,243,75,50,147,245,103,99,118,187,173,38,180,198,4,232,189,188,71,77,186,133,5,119,63,38,113,150,116,109,114,180,201,236,109,166,231,66,140,230,241,38,181,92,228,249,213,197,181,197,173,221,197,173,53,42,142,172,155,236,238,194,81,207,171,61,237,140,235,124,4,42,213,148,101,85,36,187,54,73,253,204,72,215,38,253,70,212,38,243,140,35,197,87,173,70,149,253,25,206,59,84,253,228,153,236,111,53,177,168,212,176,244,213,112,120,91,215,74,242,81,133,42,178,249,103,221,41,53,140,234,106,179,30,242,132,213,74,16,113,150,94,56,57,59,114,206,146,23,82,133,170,237,147,74,105,97,237,140,28,178,234,21,110,44,225,229,167,26,177,170,147,79,248,144,211,29,131,171,10,115,126,101,69,159,229,90,154,245,238,191,123,58,48,238,159,116,84,178,182,70,149,181,90,117,238,124,218,175,203,156,98,210,142,126,175,115,78,189,229,196,186,133,53,70,138,141,188,244,148,212,181,26,177,210,94,78,226,239,196,74,132,45,233,69,193,247,103,183,94,221,55,61,213,167,108,105,71,230,143,187,245,103,138,140,101,105,86,230,250,133,44,246,205,166,220,178,231,234,109,89,223,117,24,211,171,82,230,165,59,120,206,163,149,21,10,19,172,251,56,207,163,18,69,175,91,123,143,133,148,180,125,186,157,85,42,179,165,90,18,140,187,100,249,199,244,37,133,123,234,242,80,169,82,149,91,118,154,151,117,39,9,127,115,43,153,70,140,251,162,190,152,182,147,231,2,214,93,53,244,46,44,250,234,185,110,42,157,117,251,199,252,204,244,206,188,124,170,85,96,211,139,88,71,149,235,247,61,212,105,92,70,81,131,116,150,59,214,99,147,189,232,149,39,113,225,170,83,171,77,70,166,52,93,186,125,210,51,22,183,226,220,210,221,28,117,235,254,230,120,238,204,94,50,99,39,55,74,78,50,107,109,150,198,53,36,221,53,29,59,154,213,181,253,203,163,98,242,140,122,135,69,171,74,109,235,12,231,221,29,87,134,46,37,90,206,20,43,38,167,58,110,58,175,67,185,183,148,37,66,84,211,140,163,219,140,103,115,207,244,164,173,250,205,104,202,115,197,21,7,10,126,142,79,15,242,23,185,79,140,171,218,74,222,105,198,113,138,162,240,178,115,244,185,78,133,205,75,105,227,202,157,39,175,29,222,198,223,80,162,188,235,149,39,242,78,158,222,230,130,107,48,113,127,187,138,89,88,29,13,122,17,165,111,86,118,244,119,166,242,215,39,166,181,170,171,91,66,162,206,95,169,230,107,212,141,58,213,49,114,187,162,251,150,155,47,115,211,219,69,66,132,48,211,238,89,109,14,51,18,182,16,11,100,83,104,0,22,128,0,0,0,5,0,7,185,145,72,16,42,0,0,160,0,0,0,0,0,160,0,0,0,0,8,8,40,0,161,201,195,83,90,209,57,142,10,186,85,139,76,148,112,209,151,248,154,139,87,243,224,221,53,40,175,241,114,252,217,182,37,40,8,10,1,2,144,0,33,69,33,72,65,57,15,84,92,14,0,152,31,216,164,216,0,99,36,96,94,8,16,0,94,8,0,1,140,128,4,198,165,32,2,21,19,144,4,40,37,16,20,152,32,100,33,176,3,154,159,7,49,193,75,115,156,233,195,166,107,241,175,128,49,254,209,244,216,231,18,149,120,99,245,63,93,207,87,147,242,47,128,35,255,0,222,78,150,222,152,184,130,79,243,63,93,202,61,181,30,219,26,242,44,68,82,34,224,228,160,192,42,64,78,7,161,73,130,138,0,64,48,30,6,228,10,171,98,103,37,91,16,34,253,193,2,0,85,182,132,40,0,24,200,80,108,241,129,192,0,199,3,112,130,8,13,128,83,58,148,159,112,5,33,70,0,136,200,156,19,44,12,136,61,138,16,0,0,0,0,46,196,46,128,6,236,12,224,162,73,102,44,235,173,46,29,205,197,109,23,100,94,34,215,38,245,89,118,210,147,246,52,236,23,101,57,105,134,223,4,171,29,130,216,185,212,44,118,128,15,82,44,161,130,185,40,198,77,173,144,71,138,241,10,242,174,103,39,6,220,158,51,141,217,216,244,234,62,79,77,161,77,188,101,247,60,114,116,254,34,243,106,252,59,238,125,178,171,142,212,240,217,220,195,52,237,233,47,225,138,211,59,163,13,252,113,198,239,21,167,5,79,52,212,190,166,181,59,43,60,70,239,24,222,58,188,29,77,181,94,217,75,183,21,35,45,53,227,83,180,163,86,84,188,218,242,214,42,57,147,101,136,194,178,146,169,81,74,75,178,90,38,111,194,162,141,24,165,140,37,161,210,209,189,87,73,190,220,167,252,47,131,117,226,42,29,146,122,125,67,70,117,43,70,116,252,204,239,149,175,169,213,245,218,62,117,141,40,74,82,122,125,43,151,234,118,82,113,146,120,142,86,248,58,238,167,86,51,233,206,181,53,133,13,31,223,98,142,219,195,241,236,232,54,169,205,201,225,234,254,230,253,68,165,74,113,123,52,105,116,47,255,0,2,90,231,31,73,216,61,158,55,192,75,219,192,215,181,157,78,181,82,237,213,140,233,81,139,73,123,241,147,188,232,181,165,82,202,62,99,205,85,172,242,142,190,235,167,211,163,26,181,174,92,231,154,205,36,182,212,216,233,42,83,167,81,56,168,249,114,196,117,213,175,115,51,182,171,184,186,184,74,194,166,94,19,196,127,86,121,106,246,142,218,62,87,151,74,18,169,25,56,230,77,57,37,187,88,59,154,245,42,202,149,101,152,186,169,63,38,50,219,187,213,157,5,91,169,92,245,187,117,85,247,59,58,111,226,37,140,44,74,91,37,236,45,72,222,149,106,170,222,141,69,78,114,206,52,75,61,190,237,9,85,189,169,74,173,37,136,215,207,116,39,5,133,246,215,131,158,217,93,220,117,42,246,176,163,219,105,9,69,211,172,147,213,115,151,201,217,209,177,174,165,86,14,49,80,206,98,222,237,18,77,93,117,180,85,85,135,86,10,61,177,237,207,191,169,140,227,85,87,167,42,77,249,125,173,79,49,215,187,58,52,119,148,44,243,173,104,166,253,56,54,252,152,53,178,88,216,215,226,154,243,180,44,37,113,108,237,33,152,197,73,183,41,45,219,220,244,54,212,126,26,214,20,86,62,85,140,227,115,56,197,37,162,71,38,133,147,18,221,99,40,185,83,146,73,41,97,227,11,147,198,245,11,106,183,189,93,214,167,117,56,70,148,21,58,150,242,167,162,123,182,159,190,135,180,201,197,90,155,156,178,163,28,61,244,212,114,154,145,243,142,181,85,219,244,187,105,202,46,10,156,243,41,172,52,143,83,225,123,185,117,14,141,57,52,219,107,235,206,146,247,55,250,183,76,141,110,141,115,26,84,160,234,227,185,38,180,109,26,158,21,168,165,109,86,154,130,131,142,142,49,88,73,147,62,53,187,27,84,242,161,42,85,101,132,214,31,220,224,146,253,204,181,88,138,211,93,205,234,244,147,111,58,63,83,74,226,156,225,73,121,122,207,63,79,170,24,142,94,153,91,52,221,55,20,228,166,251,90,229,29,93,197,197,30,159,227,140,87,143,201,115,8,249,77,61,164,158,54,54,58,77,72,202,230,189,40,38,165,77,226,89,245,58,207,19,170,116,58,255,0,72,184,156,28,148,146,204,146,213,62,237,9,122,39,111,99,115,78,57,148,229,135,219,22,240,206,146,187,205,56,226,150,146,213,180,119,183,111,20,231,37,196,78,158,81,147,163,81,79,229,194,195,199,185,111,100,190,157,69,197,10,83,169,57,189,20,162,148,154,244,61,55,77,106,118,80,146,121,73,99,115,206,93,174,219,74,141,71,45,69,69,36,118,190,26,93,150,53,41,101,190,217,114,39,101,233,221,228,164,224,35,72,160,0,0,0,1,128,0,0,64,0,26,1,144,48,64,198,64,5,0,0,0,61,128,0,0,17,20,0,1,0,0,0,4,251,154,243,110,85,210,54,36,241,22,253,17,193,29,110,99,255,0,14,73,71,21,180,187,238,234,62,53,55,48,106,90,127,153,85,250,73,240,110,18,66,132,41,10,41,17,65,64,156,148,32,129,30,224,52,64,100,43,32,85,224,143,65,184,246,1,190,73,185,125,72,5,200,38,0,0,183,96,160,54,70,37,123,140,1,1,73,156,176,33,89,0,0,1,40,140,100,12,100,129,128,128,10,229,165,185,206,112,210,57,142,188,58,98,191,29,254,29,56,203,196,189,50,18,203,110,188,113,249,51,245,180,158,89,249,55,240,215,181,120,175,165,39,28,226,226,57,127,153,250,202,111,230,122,23,200,65,5,144,154,245,41,201,163,0,126,97,48,24,5,207,168,201,84,100,65,134,3,82,99,83,46,0,68,216,133,1,81,141,144,101,224,33,192,4,228,42,144,175,98,100,10,8,84,4,216,160,96,3,216,45,138,76,129,119,32,8,3,42,1,128,0,0,193,72,84,16,0,0,0,0,5,0,64,0,28,87,79,22,242,56,109,158,18,137,201,116,179,5,28,232,247,193,199,111,135,44,45,123,71,213,248,220,90,21,253,134,152,15,98,162,240,97,37,149,142,12,183,24,32,240,222,32,82,169,115,111,10,57,207,153,149,133,190,14,222,229,60,168,166,146,236,89,126,230,151,86,156,126,49,118,104,233,201,119,190,23,38,205,92,214,157,63,41,41,66,107,50,212,195,90,180,232,74,141,42,85,42,40,197,203,63,74,37,212,252,218,21,45,251,251,84,241,175,219,95,244,55,111,190,74,52,99,55,219,8,44,201,191,67,175,132,40,92,220,46,234,223,187,115,82,142,56,45,26,246,119,182,147,234,14,214,53,32,171,40,230,81,79,12,237,163,14,214,187,94,34,222,167,140,235,247,116,122,103,90,85,168,186,48,170,234,246,102,113,214,75,77,153,235,33,115,42,189,144,165,70,115,172,227,151,152,181,18,73,134,122,109,87,237,109,202,9,166,214,23,177,173,113,70,156,186,101,106,11,56,75,186,77,189,205,170,48,187,167,218,165,65,201,73,226,75,58,69,122,156,82,167,27,91,25,206,230,181,58,116,146,106,114,169,44,96,17,216,244,186,74,135,77,183,165,156,168,193,106,109,236,245,52,172,238,173,29,189,40,82,185,167,54,214,152,146,212,220,213,110,110,35,175,234,118,174,116,252,200,203,229,79,51,142,51,148,121,250,180,213,141,89,75,178,74,53,86,51,20,242,123,13,90,195,216,97,127,42,211,216,150,26,241,253,149,46,122,156,109,97,41,203,20,222,112,158,159,152,233,190,28,161,70,19,157,24,74,53,166,156,37,41,201,203,58,238,122,200,65,67,189,197,36,228,242,223,169,146,167,24,188,164,137,248,175,228,225,178,183,157,173,178,165,57,41,60,238,141,142,213,140,189,203,141,14,27,219,219,110,155,99,86,246,238,162,167,70,154,203,108,211,44,235,212,133,10,78,164,218,94,153,103,95,107,215,122,125,229,202,181,183,184,133,107,132,241,40,211,121,237,251,158,69,116,254,187,227,155,143,136,187,173,62,155,209,51,154,74,155,253,237,88,253,184,251,158,207,166,116,126,155,208,173,92,44,109,99,74,41,124,211,222,82,247,108,206,219,210,220,141,229,31,82,254,71,81,83,196,157,58,223,189,220,215,167,73,69,225,167,53,159,208,224,165,227,46,137,90,214,165,213,58,243,149,40,52,158,35,174,175,11,66,236,50,187,230,208,224,233,174,60,91,208,45,162,229,95,169,81,167,234,165,45,127,67,122,223,171,116,251,165,15,34,234,156,251,214,98,147,213,141,136,220,107,186,50,79,148,211,58,126,135,70,52,99,89,211,95,44,165,221,239,169,221,118,179,169,177,131,163,117,90,154,207,111,161,105,27,117,86,101,236,112,78,154,148,211,142,248,208,219,107,38,18,142,218,106,100,142,146,218,221,209,190,184,113,151,249,147,238,199,185,169,226,165,57,88,89,74,156,98,230,170,197,167,47,103,177,217,66,117,149,229,120,202,158,33,28,118,203,59,154,94,33,165,230,116,88,172,180,225,85,73,73,253,242,75,210,253,122,74,217,169,111,46,222,105,233,250,26,18,131,157,42,178,88,77,199,45,51,118,218,110,165,149,10,141,235,42,105,233,246,36,97,221,223,23,29,26,198,188,154,71,156,148,100,169,185,172,52,215,6,199,133,174,190,43,207,126,91,138,90,119,103,61,198,23,20,221,30,200,195,17,143,115,139,238,217,179,79,195,53,188,142,175,90,214,17,148,96,165,46,236,173,50,103,166,190,61,143,30,229,94,134,49,51,216,211,32,0,0,1,128,228,1,178,2,20,0,0,14,77,0,0,0,0,0,0,0,0,0,41,2,0,0,160,0,7,4,64,16,71,179,56,97,143,63,62,216,57,158,199,5,54,157,87,182,154,144,99,100,176,166,219,221,183,249,27,102,165,138,78,21,36,150,31,118,13,173,203,0,112,10,4,227,5,32,1,144,54,40,2,48,57,8,0,71,176,80,186,19,208,48,0,134,91,32,33,55,5,228,9,185,57,42,120,90,144,10,57,0,3,100,12,0,96,133,122,129,56,0,18,136,25,76,89,21,57,50,91,132,8,57,105,110,115,156,20,183,57,206,188,58,98,191,30,126,29,86,237,241,95,74,113,142,191,19,15,238,126,175,147,147,168,244,194,206,154,159,149,191,11,224,167,227,14,143,28,107,241,9,179,245,111,253,163,47,145,99,134,116,106,74,89,82,105,99,134,97,230,213,134,51,191,216,219,221,36,131,73,189,78,88,186,225,167,113,221,245,44,51,149,53,190,116,14,49,244,48,157,53,37,140,1,203,148,56,56,99,75,178,42,49,147,193,28,42,99,14,95,161,85,207,200,53,227,231,46,114,87,113,40,47,158,155,207,177,116,115,228,26,234,238,45,164,225,37,239,131,63,62,15,84,240,189,198,142,92,3,143,207,167,252,232,203,190,45,233,36,254,192,80,128,216,34,175,112,78,10,130,153,33,112,48,16,193,49,168,12,10,55,68,69,10,100,16,184,0,145,73,200,75,93,192,160,123,128,8,1,184,20,16,161,19,37,0,0,24,0,10,78,11,128,4,40,3,86,235,61,169,39,133,156,182,253,5,170,249,92,177,140,177,91,21,43,42,114,95,43,90,175,83,146,158,209,88,194,75,9,16,248,231,90,141,244,42,208,26,12,122,34,173,8,181,25,3,192,120,218,198,241,206,85,44,238,156,37,41,41,121,73,97,77,175,86,105,244,126,191,213,47,58,18,148,250,124,35,113,70,179,162,228,165,194,89,206,15,115,214,44,233,220,217,185,55,137,65,60,72,232,188,47,97,111,105,105,82,10,115,159,125,78,228,228,255,0,35,149,158,218,248,211,182,171,79,169,222,59,75,171,215,90,191,253,204,98,227,31,205,157,188,60,49,96,187,105,214,134,86,115,24,197,191,151,243,56,186,253,40,89,213,167,212,113,219,77,105,57,37,183,185,220,116,203,186,119,182,240,148,106,70,111,31,82,228,179,250,26,209,240,191,73,141,104,86,149,41,84,148,36,165,5,57,101,69,250,157,198,89,139,156,59,187,92,226,165,232,217,215,94,248,131,166,88,86,149,26,215,11,205,95,195,21,150,106,228,71,99,82,148,106,210,149,41,231,182,75,15,30,135,155,185,252,63,232,55,148,163,74,234,157,106,180,34,251,149,47,49,168,231,236,142,8,120,174,230,226,234,81,167,26,10,138,127,47,107,114,148,151,186,198,135,7,251,77,214,93,71,229,89,247,71,56,215,49,254,232,132,151,227,47,254,203,60,47,78,126,109,133,59,174,159,93,125,53,45,238,36,176,254,207,40,237,250,45,159,88,233,117,101,105,121,123,78,254,207,254,202,172,150,42,199,217,240,205,27,95,21,215,78,86,247,84,169,43,142,228,177,23,161,223,91,94,66,230,29,208,154,202,250,163,202,44,197,187,245,185,200,48,140,148,245,91,25,149,148,111,66,225,52,67,39,162,1,193,231,186,199,70,188,235,94,33,177,87,53,99,14,141,106,188,217,80,90,186,245,117,199,119,178,61,12,94,134,51,125,144,148,154,238,194,202,75,144,56,238,110,173,236,237,93,107,138,145,165,74,11,236,146,60,125,231,139,58,149,213,218,165,211,108,211,181,169,9,40,206,79,18,255,0,139,108,37,247,37,228,43,245,238,169,240,87,63,44,40,127,153,4,244,82,221,47,208,236,186,205,171,233,158,26,169,240,84,20,171,203,182,11,77,113,157,113,249,25,183,90,146,71,136,234,190,17,177,176,233,244,122,159,136,28,111,110,174,170,246,194,48,202,195,127,103,169,214,222,218,116,183,226,43,110,143,30,129,86,118,114,165,30,219,136,169,46,217,54,243,153,125,145,222,211,184,184,241,93,237,149,74,214,158,93,167,74,78,80,238,122,206,120,193,211,88,93,87,234,157,119,168,244,174,165,60,209,186,130,163,164,180,139,79,58,122,25,217,171,53,181,71,193,254,24,234,84,169,198,219,172,42,147,168,211,83,146,238,80,123,37,39,253,14,27,207,4,248,215,163,70,175,236,184,83,184,130,250,93,26,202,51,107,236,205,249,219,203,167,83,253,152,252,218,202,147,249,115,20,177,31,119,201,244,222,157,82,83,233,118,178,231,203,75,57,246,53,50,250,75,235,167,202,186,31,94,241,101,30,173,93,214,161,117,105,99,111,8,121,148,47,106,194,165,73,75,158,215,149,132,125,3,163,117,168,117,122,170,178,166,233,249,145,202,93,201,254,89,77,163,179,188,233,150,189,71,42,226,140,36,218,195,151,106,206,15,63,103,210,104,244,207,18,83,180,182,156,99,78,156,92,227,77,44,98,47,141,12,217,202,83,183,168,68,168,179,29,12,214,217,49,169,164,27,70,217,117,18,239,149,251,74,31,34,135,205,235,185,173,226,105,66,159,135,42,202,85,97,74,62,100,91,157,76,225,126,135,37,89,199,246,143,114,109,188,98,75,60,28,94,36,196,186,55,148,227,152,78,111,87,198,53,70,111,77,59,219,9,66,93,54,218,84,165,25,67,203,74,46,59,53,131,159,6,143,69,148,95,70,182,140,27,113,132,123,117,55,209,184,203,207,222,58,114,156,41,247,106,219,105,99,248,178,116,22,247,202,223,197,118,182,206,114,83,173,56,185,41,61,49,169,232,250,173,22,171,121,148,220,148,224,158,26,105,45,126,231,143,191,185,169,211,186,229,141,206,35,86,173,73,211,166,223,174,100,115,186,212,233,244,199,164,158,76,137,44,119,132,116,198,71,190,133,1,178,0,0,0,0,0,228,16,10,1,77,34,49,200,228,114,20,0,161,16,23,130,0,0,0,5,32,2,144,0,0,0,35,43,216,132,84,123,109,193,175,12,42,146,199,60,155,18,203,139,70,181,63,227,92,36,200,50,179,93,180,228,185,111,58,155,7,13,183,249,111,44,230,201,96,14,64,2,144,4,0,114,7,32,24,111,0,141,1,80,38,66,245,1,200,220,103,47,0,7,36,47,1,1,6,0,228,9,200,46,228,192,14,7,5,38,128,1,114,78,0,50,20,128,0,12,148,8,1,0,16,161,92,180,183,57,206,10,91,156,199,78,29,49,95,146,63,11,52,241,135,73,120,215,226,34,190,249,103,234,153,44,84,146,63,43,126,22,101,248,203,164,252,175,17,172,165,147,245,79,241,203,93,114,95,34,197,41,1,204,82,15,97,198,129,85,32,208,34,40,184,208,96,5,175,1,88,184,167,193,131,161,9,61,81,202,198,0,225,149,173,23,188,114,112,187,52,190,150,226,253,81,182,78,72,53,163,78,225,44,121,157,223,115,145,74,180,49,167,119,177,204,131,24,56,124,233,55,254,91,193,146,175,28,246,201,52,189,89,201,177,28,98,247,89,3,24,215,132,180,76,207,185,61,153,139,132,18,209,19,202,138,219,66,140,242,53,48,116,154,122,54,137,219,63,82,14,76,151,220,225,205,72,240,153,84,234,53,172,63,169,71,40,89,198,14,31,58,73,235,6,37,113,24,181,152,180,52,114,173,202,113,43,154,82,122,75,83,53,56,189,114,128,204,136,157,241,123,73,23,43,135,144,40,34,101,0,82,20,32,6,224,0,0,0,42,0,8,10,222,128,106,87,255,0,62,56,228,231,129,195,81,119,93,67,93,145,207,30,9,7,42,254,229,102,37,220,210,153,92,7,191,184,73,6,194,52,186,181,111,135,233,117,170,252,184,75,157,142,179,162,210,163,11,26,115,165,135,220,220,164,210,194,203,244,57,252,75,90,17,233,145,163,81,119,42,211,237,194,100,233,240,80,176,182,132,52,93,186,156,239,109,124,109,85,132,43,197,208,171,24,202,148,226,212,162,246,60,149,173,117,210,110,101,77,213,242,172,233,55,243,39,140,250,35,214,202,93,181,123,121,113,103,149,241,13,165,53,109,251,206,207,46,57,115,83,221,175,111,113,127,176,125,113,221,221,198,143,149,57,75,57,83,132,181,193,187,210,188,43,23,93,221,94,214,157,87,230,41,198,53,30,90,75,212,234,122,26,86,177,160,250,109,25,214,156,215,124,163,40,167,44,127,196,207,123,74,106,173,40,201,71,177,255,0,20,125,24,147,125,210,250,121,27,202,150,158,28,235,242,184,149,40,170,21,32,156,165,143,163,93,206,135,173,248,249,245,46,176,172,250,45,26,119,86,234,11,231,90,235,203,61,151,136,172,165,115,107,93,83,242,251,231,73,197,119,172,173,143,53,224,47,11,244,190,153,90,165,205,42,117,37,93,39,25,185,175,151,47,208,215,250,61,102,188,196,104,117,72,245,74,119,49,163,82,175,108,187,228,191,149,122,158,175,195,29,94,223,169,92,87,171,66,178,238,243,49,37,157,223,177,219,90,116,203,170,61,126,189,197,104,197,88,170,114,81,237,219,94,15,61,107,225,186,222,28,241,3,187,132,41,252,13,119,251,184,211,88,236,127,98,69,221,125,30,17,236,130,94,198,107,115,24,190,234,84,223,46,43,38,73,154,96,228,16,160,85,162,193,101,30,229,133,185,16,121,113,107,56,207,32,116,125,22,140,23,84,234,46,149,38,233,66,120,157,121,111,82,163,213,227,217,29,165,253,180,175,58,125,106,17,106,21,92,91,165,63,229,159,15,245,51,180,180,167,101,105,11,122,89,237,139,109,183,187,111,147,153,104,73,61,97,123,124,187,161,121,180,239,188,187,69,57,194,156,220,238,99,53,244,202,47,13,47,105,111,142,15,93,111,225,142,141,75,171,254,212,161,74,52,174,36,187,167,78,47,41,183,206,11,212,122,28,173,174,29,255,0,77,202,155,207,153,73,109,44,143,218,181,41,82,165,86,61,42,175,157,219,24,212,109,165,131,18,103,109,110,244,235,188,103,97,26,118,146,175,74,156,157,106,245,96,177,28,182,151,216,245,182,20,213,62,155,111,5,30,212,169,165,143,77,14,158,133,157,253,207,85,169,123,113,93,184,56,165,78,151,111,203,15,83,190,138,199,37,227,222,196,172,150,135,137,188,187,116,63,19,213,25,188,42,150,244,221,60,126,105,255,0,99,219,31,61,241,38,159,137,86,178,90,74,54,180,228,223,162,239,146,53,203,165,227,219,223,189,30,166,21,114,169,60,110,102,255,0,204,150,127,33,132,222,187,21,151,67,90,156,101,213,149,62,252,73,47,165,45,243,238,112,117,198,170,58,80,173,244,81,125,221,171,151,140,101,254,70,197,237,178,135,92,119,85,20,149,57,40,164,211,198,219,154,253,81,194,229,78,164,94,97,40,233,47,84,184,51,241,168,236,250,27,127,9,115,73,45,41,87,113,139,245,78,49,151,255,0,157,253,14,205,54,117,221,17,167,103,89,46,42,235,255,0,149,29,139,43,63,93,47,87,141,42,181,234,210,170,147,140,169,172,41,44,172,158,51,168,165,75,170,244,155,132,147,133,189,94,207,102,217,239,250,165,41,78,202,115,130,139,146,223,39,207,124,81,81,218,244,155,124,91,170,138,119,81,140,154,127,71,57,51,201,174,47,167,199,230,140,100,249,73,153,163,130,210,172,107,216,219,213,142,211,167,22,177,246,57,205,178,17,148,130,138,0,32,0,10,0,2,0,0,210,0,0,160,0,33,193,120,32,0,82,0,41,6,224,3,0,5,10,64,16,33,64,24,79,72,73,251,26,244,101,230,83,169,36,244,107,115,98,163,197,57,63,99,90,130,255,0,15,81,227,28,104,101,92,182,205,58,90,108,158,14,99,134,221,98,146,94,135,48,130,130,20,210,39,184,28,0,167,0,164,198,132,0,198,228,221,128,216,12,234,57,0,244,27,176,245,26,0,11,93,136,92,0,111,66,106,86,134,192,76,224,114,78,66,2,227,65,193,57,40,17,7,245,100,125,128,6,70,82,48,0,17,146,134,224,17,145,66,162,112,49,144,57,169,110,115,156,20,183,57,242,116,225,211,21,249,51,240,167,31,237,95,77,109,235,231,36,143,212,179,255,0,53,165,193,249,99,240,162,41,248,195,165,101,255,0,218,236,126,165,111,53,101,143,82,249,22,50,40,99,99,154,177,47,3,32,6,168,172,153,8,162,172,224,114,56,8,40,1,0,61,135,0,48,128,108,114,0,34,240,68,0,1,193,112,0,155,48,247,27,176,24,41,48,80,24,68,194,111,84,153,72,21,59,35,252,168,121,113,107,13,104,100,134,64,193,209,134,52,209,24,249,61,175,186,18,121,247,57,73,184,24,37,81,114,130,117,83,121,138,107,212,229,200,3,141,85,151,48,104,121,203,187,84,215,228,114,6,178,4,243,35,234,35,56,207,105,32,226,176,99,229,71,209,17,28,156,140,156,125,141,61,37,167,161,126,110,10,57,55,33,135,116,179,244,151,185,99,93,198,140,129,59,146,223,66,229,61,83,202,3,95,25,186,111,132,142,120,173,78,8,44,220,205,231,9,173,142,106,122,234,10,228,41,63,34,148,208,125,195,208,197,176,60,159,140,106,78,95,8,225,78,115,236,158,27,138,120,89,105,29,252,45,227,110,161,78,11,30,92,82,58,59,138,113,187,241,21,26,115,130,114,239,77,63,101,175,250,30,154,231,233,148,189,61,12,79,181,171,242,53,165,20,229,158,78,183,169,244,186,61,78,116,225,113,25,74,146,150,90,131,220,222,162,234,84,140,170,54,183,237,81,198,199,46,177,90,61,82,213,149,30,74,226,206,125,42,53,21,57,121,86,242,127,36,84,254,100,254,231,161,232,202,229,211,140,234,73,184,165,140,250,154,221,97,194,191,74,173,52,161,41,82,121,195,91,152,120,103,168,66,173,191,149,58,159,60,222,82,225,123,24,234,227,95,30,137,198,50,140,163,36,154,107,12,232,149,173,223,77,148,157,38,167,77,185,74,49,75,251,157,235,202,208,45,54,58,86,117,212,81,234,178,151,106,185,162,224,150,241,75,67,175,188,87,125,91,175,219,170,110,80,178,140,52,73,115,238,122,119,8,201,230,113,82,251,161,8,70,47,72,164,76,37,20,84,98,160,182,138,193,146,88,15,77,113,147,169,188,235,48,161,94,54,234,51,243,37,244,164,179,220,253,11,106,59,87,185,112,26,120,89,24,208,2,216,203,4,72,188,0,33,120,28,129,57,46,23,118,112,181,246,15,0,11,150,192,12,161,157,79,156,120,158,175,111,226,45,26,112,249,170,84,183,167,77,175,103,38,125,29,31,45,241,29,79,35,241,94,132,188,185,57,201,208,81,126,218,228,207,62,154,225,219,234,18,95,189,100,90,61,69,79,243,52,15,212,172,180,250,149,42,114,161,26,211,89,84,165,222,254,216,60,220,238,237,238,186,77,56,89,84,133,88,186,152,110,47,56,215,99,212,245,9,198,159,75,186,169,44,53,26,51,122,237,179,62,101,224,117,73,120,117,84,84,252,167,10,221,211,89,250,156,189,12,222,241,174,61,62,145,210,105,121,54,46,47,234,114,203,102,238,14,59,116,149,181,63,70,147,57,55,53,140,184,174,105,42,214,213,41,57,99,185,104,207,155,248,190,173,213,191,71,146,86,254,98,85,84,116,229,190,63,68,207,163,222,118,124,37,71,54,210,75,86,184,60,95,84,156,170,244,90,182,213,174,169,168,212,156,106,209,168,161,153,67,30,198,57,69,226,244,190,26,171,223,209,227,69,203,185,208,126,91,145,219,158,95,193,85,226,237,42,91,247,39,218,147,79,26,203,213,158,157,27,157,37,237,72,82,20,10,8,65,64,0,0,13,225,100,7,57,0,20,0,4,0,0,69,33,121,33,64,2,128,32,99,0,0,0,0,28,0,0,1,199,93,102,132,227,234,176,107,90,235,105,218,184,77,63,190,77,139,135,138,47,92,106,112,219,46,219,121,99,238,102,246,191,28,180,31,238,214,135,41,199,67,252,152,156,153,44,20,114,1,80,0,0,35,3,114,40,128,100,0,57,3,144,1,234,246,11,97,158,0,14,70,2,208,4,181,35,43,195,38,80,19,134,48,94,8,81,73,193,65,4,1,128,0,16,0,198,64,37,16,140,163,58,145,65,201,2,3,154,150,249,57,176,112,210,255,0,83,156,233,197,138,252,151,248,75,24,203,198,61,47,50,125,222,107,105,126,76,253,72,151,206,215,185,249,119,240,145,39,227,78,150,227,204,219,254,140,253,68,158,103,45,11,205,99,61,48,77,0,57,170,23,129,184,91,0,8,133,72,162,231,35,100,54,27,173,2,155,242,8,180,12,34,145,21,236,68,0,32,0,104,26,212,105,145,200,15,200,50,144,6,116,3,25,0,53,30,197,38,160,80,22,163,144,167,184,247,27,182,51,128,25,41,50,50,5,198,165,33,64,14,72,138,16,0,0,0,0,3,112,4,237,88,49,116,150,52,109,28,139,112,216,26,20,99,41,215,168,220,158,22,198,212,92,210,217,110,113,80,107,51,121,223,68,115,81,79,183,15,242,36,86,74,126,169,153,247,166,137,239,161,82,42,9,229,61,76,102,241,74,109,106,210,109,34,246,39,236,106,117,10,178,180,177,171,94,26,246,166,192,232,250,45,104,223,120,134,226,226,162,116,229,78,29,177,132,150,30,125,79,65,121,81,83,182,156,158,216,60,199,134,100,175,58,205,91,132,190,85,12,185,53,187,59,222,185,86,43,167,202,154,159,108,228,201,58,106,246,228,177,237,151,77,165,37,135,157,217,156,226,164,251,119,142,14,43,74,212,221,40,91,80,249,149,42,105,185,35,149,198,110,90,44,115,144,142,158,112,115,183,173,71,41,122,169,112,116,150,87,63,5,213,190,25,164,212,164,148,38,182,82,59,203,212,149,195,237,105,41,44,202,79,212,232,171,249,85,45,213,25,175,38,234,51,238,139,122,41,251,166,99,22,87,189,140,156,226,165,161,77,14,139,82,117,122,101,39,39,220,158,113,39,207,6,250,58,50,25,17,47,66,240,1,201,70,50,155,105,36,178,207,159,116,254,188,186,143,142,163,106,220,34,169,201,201,106,243,47,178,244,61,229,197,47,137,182,171,65,201,199,190,46,61,203,131,231,93,210,240,143,88,151,84,235,22,180,234,174,239,34,218,118,209,89,138,127,197,46,76,216,177,244,169,110,17,173,101,121,75,168,89,83,187,163,56,206,157,69,148,211,54,22,230,145,150,66,33,64,166,60,148,96,0,0,1,72,82,129,243,79,23,83,239,252,83,232,213,92,187,105,83,133,24,212,199,46,83,146,137,244,191,93,176,120,78,166,168,221,126,40,217,194,19,141,88,170,84,221,88,173,123,37,7,39,31,254,35,60,186,94,61,189,189,85,243,151,6,50,203,155,121,228,149,107,70,141,39,82,121,237,94,129,29,15,142,174,42,208,240,165,122,116,107,42,51,184,148,105,121,141,103,182,45,234,255,0,76,158,127,162,78,205,210,183,180,161,41,181,79,31,51,167,142,233,114,246,193,191,248,133,39,94,210,198,217,119,40,74,110,110,73,232,222,52,95,212,203,165,218,220,90,210,163,74,167,148,223,106,203,135,9,241,238,102,251,228,220,233,235,104,188,80,130,202,110,43,180,228,212,208,138,249,233,188,182,147,245,208,222,122,114,191,51,108,48,168,148,232,85,78,57,204,94,135,142,156,82,180,74,164,41,213,161,36,226,251,228,254,85,232,145,237,112,179,143,85,131,197,245,106,47,49,248,122,83,156,40,79,203,159,182,94,230,121,175,19,194,215,45,83,163,90,63,228,198,78,139,206,249,114,120,254,143,7,182,122,51,230,157,34,241,174,171,86,222,11,181,66,180,92,187,162,254,149,202,252,207,164,198,74,113,140,214,210,89,51,194,156,187,95,92,1,234,83,166,162,2,144,11,192,4,2,152,180,223,58,20,160,54,0,20,0,4,64,104,1,64,160,0,68,41,61,0,187,134,55,32,2,240,0,16,14,64,80,0,7,13,202,82,162,209,199,111,173,41,62,56,57,46,155,242,90,92,163,27,124,74,151,221,24,250,124,114,80,214,146,123,153,146,11,182,42,43,131,35,80,8,80,84,66,128,21,2,26,142,72,4,227,220,188,15,64,4,28,128,27,129,144,222,128,6,24,203,72,45,128,140,105,192,213,232,61,202,167,234,67,39,253,73,130,1,0,104,32,0,0,64,0,17,20,16,66,61,202,66,40,194,200,41,7,45,45,209,205,169,195,75,117,247,57,206,188,122,98,191,39,254,18,69,127,182,189,37,103,31,188,105,253,187,89,250,135,24,156,190,231,229,255,0,194,40,103,198,189,46,75,85,222,215,244,103,234,6,150,89,174,107,20,128,28,148,200,65,162,236,0,168,128,161,247,47,216,152,4,6,80,30,197,16,100,12,128,0,48,0,6,0,12,140,106,0,0,5,192,202,220,131,140,5,57,201,66,64,6,196,231,5,198,129,4,76,96,201,107,169,54,46,224,57,0,161,70,0,8,0,79,176,20,48,0,45,202,176,64,5,49,159,209,47,177,145,36,190,71,246,3,86,222,63,36,147,227,99,98,25,237,89,53,237,218,148,103,218,243,135,134,109,65,124,169,8,50,91,151,100,54,68,201,77,14,143,197,21,103,11,106,52,105,188,57,182,159,219,7,123,142,227,202,120,142,165,90,157,74,149,56,53,217,21,234,103,151,75,199,182,215,133,233,69,41,124,170,50,165,5,77,165,179,91,228,199,173,85,165,241,158,85,73,195,183,41,97,238,115,116,151,240,116,147,81,207,127,212,223,169,210,117,138,14,167,90,119,46,170,84,224,244,143,191,185,47,75,245,233,250,101,8,81,132,188,181,21,6,191,132,223,250,83,52,186,107,95,11,22,164,165,161,185,186,193,168,203,206,245,85,221,117,78,150,210,147,202,94,167,95,123,75,227,173,170,53,216,234,211,79,178,45,108,119,93,74,110,222,234,140,35,21,42,179,203,139,107,100,183,58,235,74,52,172,252,223,47,231,169,44,185,119,189,243,193,153,219,81,193,225,59,216,219,246,209,83,127,15,170,105,231,10,79,208,246,60,158,6,181,229,181,59,218,20,92,41,174,217,124,212,41,207,183,15,213,158,234,222,178,175,66,53,34,226,244,225,229,26,137,99,151,97,198,129,235,169,125,130,38,233,229,104,117,221,71,163,88,117,56,246,221,218,194,170,198,237,106,142,203,129,253,176,21,214,116,142,135,103,209,45,149,11,39,85,81,238,114,80,148,219,73,179,178,228,184,208,12,196,10,180,33,80,20,128,114,80,27,176,0,164,9,97,21,106,240,7,95,215,47,45,236,122,37,213,107,153,168,211,236,113,213,239,157,48,124,243,240,250,23,51,241,53,101,39,221,74,157,44,201,190,61,13,255,0,19,222,86,241,77,244,45,58,69,85,90,198,210,164,161,123,23,29,19,91,52,255,0,38,114,254,30,70,52,238,250,156,214,169,246,226,79,148,103,99,83,212,123,204,106,205,30,161,86,52,113,41,39,36,146,93,191,158,230,231,114,238,121,122,30,115,171,117,73,81,173,118,212,20,233,184,37,20,248,123,11,137,198,107,161,235,242,125,71,196,116,166,148,213,36,225,4,248,126,231,161,181,183,157,178,173,138,202,125,201,36,154,213,29,7,72,130,183,161,115,89,83,117,41,81,151,119,117,73,105,20,150,116,247,108,222,232,157,94,151,91,233,85,43,82,165,82,149,72,86,74,106,94,187,232,97,171,253,61,21,10,49,132,105,66,17,74,49,215,6,245,101,221,73,233,238,104,219,212,126,108,99,217,37,141,228,222,230,244,229,138,110,92,26,101,105,84,114,227,84,143,57,214,106,59,42,179,194,111,204,155,169,36,182,216,239,104,201,121,154,242,116,253,122,140,106,222,82,167,42,158,95,124,160,243,143,169,107,148,91,209,59,121,78,159,9,212,181,185,188,130,236,169,47,151,87,171,105,250,110,125,3,163,213,149,110,149,70,83,121,158,48,207,156,202,202,20,188,73,94,157,42,63,226,104,212,196,36,243,219,135,182,79,121,209,37,42,148,171,82,156,150,105,73,39,219,234,209,152,215,57,29,186,7,26,131,79,73,50,230,162,225,51,76,51,67,38,30,99,95,92,49,143,66,170,145,126,165,25,0,152,64,80,144,5,4,1,0,160,0,128,3,144,0,172,128,94,8,94,0,0,0,0,8,1,189,64,4,2,20,5,112,92,201,70,150,95,26,146,211,252,172,235,174,186,146,233,246,164,178,181,223,37,180,195,167,156,231,93,9,244,248,231,40,216,26,64,156,142,65,21,73,176,40,16,112,25,54,2,144,188,0,32,90,177,130,240,4,192,3,0,51,166,128,110,131,223,216,170,103,114,44,228,104,222,74,244,122,110,4,99,87,174,67,122,96,110,5,102,32,54,68,27,35,41,0,0,0,16,164,102,68,108,14,65,20,46,164,42,8,229,167,166,14,115,130,158,231,54,190,167,94,61,51,95,149,127,8,51,47,26,116,174,34,167,38,255,0,40,179,244,206,126,103,247,63,52,126,14,44,248,223,167,101,127,50,95,161,250,93,97,182,243,201,124,139,25,225,181,168,8,167,53,22,64,0,0,28,20,0,229,141,136,41,51,166,224,20,0,32,2,140,123,140,234,0,21,108,69,184,0,57,15,32,7,1,152,169,70,79,71,176,20,168,133,64,82,23,251,141,192,12,234,68,80,170,65,143,80,5,69,49,40,20,0,16,0,0,41,0,4,1,80,2,79,90,114,246,64,147,214,44,14,10,77,124,60,148,49,171,54,41,44,65,38,105,209,132,149,42,145,111,121,103,236,110,195,72,44,250,18,12,136,181,69,89,6,145,197,113,63,46,222,111,56,211,70,121,5,65,202,238,117,39,82,85,50,179,39,196,79,83,213,27,93,58,171,79,13,44,158,102,156,37,121,40,203,204,237,164,150,90,90,57,63,115,28,187,106,116,222,138,132,44,174,42,169,62,218,80,114,203,212,232,58,207,249,20,42,70,130,242,106,226,82,168,229,140,29,197,204,177,208,238,188,182,214,87,107,58,207,20,81,248,127,12,91,80,155,156,234,198,48,148,97,21,163,203,73,254,137,228,99,81,233,58,36,105,202,85,170,82,195,138,73,104,253,142,106,125,70,53,111,254,26,147,140,166,181,146,124,47,83,91,194,148,220,58,83,82,88,114,107,251,35,87,160,165,91,175,245,58,177,199,109,28,69,53,187,43,63,107,176,234,244,177,86,21,241,41,37,30,215,142,13,43,170,213,45,44,123,232,211,115,124,207,77,50,118,23,213,148,113,74,41,202,172,191,135,209,28,21,105,230,143,151,44,118,74,58,175,66,125,88,242,189,98,210,218,206,202,175,88,163,89,82,234,114,138,75,204,89,140,189,142,203,193,190,37,183,234,22,142,21,35,240,245,20,177,42,85,48,155,126,171,93,141,170,93,65,91,210,237,184,242,213,47,165,75,183,116,116,125,87,193,246,61,94,21,47,250,35,156,46,100,241,39,10,174,29,175,215,4,223,109,124,246,247,238,170,140,98,241,136,190,125,14,92,96,249,141,46,187,127,225,110,153,70,199,171,80,185,175,39,44,74,172,234,247,63,111,254,114,118,222,31,241,143,237,62,171,42,85,39,86,157,23,14,232,58,208,236,89,244,77,238,95,206,51,248,215,184,208,152,202,111,56,75,212,41,38,147,227,131,172,241,37,181,213,215,65,185,167,100,223,159,219,149,21,252,75,208,213,101,46,188,81,208,108,165,40,92,245,123,74,82,79,15,186,162,209,154,95,237,247,132,179,219,251,126,211,63,241,51,202,248,111,194,158,15,234,49,114,157,162,253,161,5,251,250,21,50,156,37,246,61,91,240,135,134,221,58,73,244,187,119,229,188,199,228,49,63,42,213,146,57,40,248,211,195,151,19,80,163,213,33,57,61,146,132,181,254,135,63,78,241,47,75,234,247,117,173,172,42,74,172,169,125,82,75,8,232,124,97,86,199,160,116,133,79,167,244,250,111,168,221,167,74,222,16,130,211,59,201,250,36,108,120,35,160,211,232,157,41,84,151,205,115,90,41,206,79,93,121,254,163,110,226,103,173,122,199,163,69,56,227,38,204,245,55,168,200,156,133,170,111,56,75,212,243,30,32,241,109,62,153,109,82,116,48,149,58,138,18,171,82,63,44,189,163,234,198,143,78,246,203,105,47,92,158,51,174,120,182,173,122,213,58,111,70,138,146,105,198,165,236,94,99,73,166,178,190,248,52,170,93,245,111,20,70,42,113,184,233,189,38,48,126,99,168,187,42,85,127,126,17,122,61,149,141,43,58,148,108,105,206,54,125,174,156,123,181,238,147,254,38,204,222,95,22,70,159,134,173,63,103,223,245,152,74,162,85,170,89,42,243,138,250,90,125,201,75,63,116,205,143,195,186,17,165,103,82,162,170,234,42,177,74,57,126,141,231,251,156,177,179,116,58,23,83,133,73,37,115,86,202,86,78,113,254,85,220,227,250,119,72,220,240,77,172,109,124,49,98,229,56,102,112,111,185,122,235,148,73,58,105,232,234,92,70,149,188,171,73,166,158,199,131,241,85,229,126,202,158,69,40,70,167,111,109,57,99,45,201,251,30,147,169,87,95,179,173,82,165,41,186,146,249,98,158,48,189,78,150,173,178,234,61,102,222,131,154,82,83,85,59,19,224,156,137,233,216,220,89,213,181,240,58,133,205,117,43,186,177,131,146,75,10,82,202,105,35,79,192,54,177,167,225,154,221,237,57,74,242,82,120,215,13,36,177,253,14,239,197,115,118,221,23,52,96,167,82,50,74,49,107,58,156,126,18,177,169,103,225,91,106,50,139,132,187,156,229,157,219,111,45,178,253,196,248,236,210,237,113,142,86,13,154,145,237,179,150,117,94,135,5,108,57,197,71,70,181,251,156,242,147,116,28,82,203,198,112,88,141,127,59,203,113,147,88,151,111,114,134,120,56,250,181,63,49,210,169,141,113,255,0,148,226,141,57,230,85,22,141,97,52,247,192,190,184,113,78,11,235,168,151,110,120,26,125,121,254,165,70,188,58,164,167,74,186,146,184,112,149,76,243,166,63,208,237,124,55,39,30,167,127,73,185,53,56,70,73,183,163,237,120,207,245,58,249,84,84,175,37,74,189,5,41,69,44,235,174,187,27,189,14,173,72,245,117,9,81,84,251,163,37,247,91,228,159,90,189,61,67,4,228,166,216,50,26,76,0,49,113,201,143,108,212,179,22,180,225,156,128,14,31,54,170,150,37,75,79,84,203,231,37,142,228,210,108,229,38,19,38,2,156,94,210,69,90,163,23,78,45,231,26,238,98,169,56,203,49,147,89,221,23,216,229,220,28,88,170,158,178,78,63,97,230,73,99,49,99,71,40,48,243,98,183,208,170,164,37,179,26,50,41,19,65,238,84,80,0,0,136,0,164,0,0,27,0,4,41,8,173,75,189,103,141,211,137,201,103,25,70,156,155,217,227,11,208,194,225,47,50,79,151,28,35,150,221,118,208,138,206,87,169,62,159,28,163,150,66,149,0,24,228,40,0,0,0,1,200,96,128,25,86,192,0,246,28,17,122,151,157,192,37,130,61,203,177,55,251,20,48,145,31,245,43,250,137,166,66,153,245,28,17,232,94,0,152,212,33,157,0,64,6,242,66,0,41,8,4,228,164,193,4,228,96,188,141,16,81,4,77,74,130,57,169,238,142,99,134,159,7,49,211,143,76,215,229,191,193,164,223,140,250,123,91,71,45,254,135,233,76,98,111,238,126,111,252,22,166,229,227,11,70,210,81,237,150,61,244,63,71,165,137,123,151,203,219,83,166,123,33,157,0,122,51,144,48,78,75,177,64,0,80,220,0,64,216,123,129,236,3,70,54,27,12,148,0,0,7,35,0,3,69,32,1,131,137,82,197,94,252,100,229,0,82,32,2,43,207,3,144,24,81,130,106,85,234,0,50,145,106,194,170,67,144,2,40,3,32,0,0,57,0,160,66,144,184,2,164,113,212,210,18,51,224,194,167,209,47,177,71,13,55,136,54,253,77,165,244,163,86,11,52,115,140,101,155,81,250,76,194,174,192,49,170,123,149,26,125,82,113,133,157,71,52,177,218,247,60,215,76,110,165,163,115,143,108,156,190,73,123,122,157,247,94,148,41,116,217,214,171,23,40,199,132,121,126,147,114,235,88,186,153,154,140,167,242,182,184,70,111,109,201,233,183,240,245,46,171,82,165,41,98,18,168,178,189,112,206,63,27,221,70,210,116,42,201,181,24,190,198,184,213,104,118,125,9,171,187,169,206,113,214,147,194,95,234,117,158,48,149,181,106,78,141,212,37,82,50,111,10,58,52,253,71,194,118,236,188,31,82,164,186,21,105,212,159,119,108,223,108,184,237,72,225,240,125,90,85,31,85,175,26,138,79,206,196,231,235,166,81,159,133,220,106,248,90,116,225,136,65,185,83,79,109,48,104,120,86,173,58,61,14,250,17,214,14,234,80,132,177,245,97,45,73,190,225,126,189,5,172,233,221,78,230,187,211,179,76,250,35,142,165,95,59,167,170,144,77,119,55,20,215,167,169,167,99,57,47,15,86,148,241,23,58,178,134,126,207,6,252,251,104,244,154,52,214,169,149,29,51,162,221,11,59,106,175,204,73,206,106,81,90,181,166,63,185,130,165,95,166,94,87,133,11,142,216,75,181,202,45,107,150,109,249,147,135,239,33,79,51,132,221,56,45,178,158,63,228,112,69,73,215,189,175,113,56,74,106,143,114,138,121,249,191,250,36,76,105,201,212,101,77,210,117,174,147,116,233,181,243,53,147,161,241,85,133,27,249,80,181,183,80,140,170,36,225,81,203,24,121,90,254,135,168,165,7,91,166,169,213,78,62,106,82,140,94,167,21,79,38,181,106,48,157,181,57,121,120,114,148,163,151,246,68,246,74,234,122,109,126,171,208,225,70,206,173,196,239,97,7,140,202,56,211,217,158,178,207,170,208,185,169,42,74,93,181,32,254,104,190,13,91,200,41,244,233,201,197,46,217,46,221,54,52,175,58,61,59,187,105,86,167,55,73,212,143,122,156,30,37,156,26,158,146,251,111,117,94,129,103,212,235,124,76,42,78,218,237,71,17,171,79,71,249,250,154,212,97,214,108,113,9,210,141,234,230,106,93,191,208,211,181,234,215,54,106,156,46,117,166,146,78,77,234,253,207,67,111,121,66,230,154,149,26,177,148,113,195,30,173,212,219,30,110,243,195,215,221,111,173,59,171,236,81,161,78,10,20,212,101,150,147,90,254,103,167,165,70,157,26,112,165,74,56,132,18,72,190,100,118,220,206,45,118,185,45,18,245,18,97,110,170,209,152,220,92,209,179,166,165,90,105,57,60,66,60,201,250,35,175,234,61,102,22,208,157,59,100,170,220,168,55,133,170,71,134,143,64,235,254,38,106,242,250,226,234,222,148,164,255,0,119,42,184,202,245,81,67,242,254,137,199,251,115,117,159,18,221,117,155,111,128,181,85,163,117,78,166,107,91,80,150,95,98,124,201,115,182,134,239,67,240,172,238,151,237,47,16,67,42,21,59,237,237,106,107,216,146,73,57,122,188,235,131,208,116,46,133,101,209,109,187,104,209,143,153,204,222,178,147,247,102,229,219,197,39,156,225,188,188,5,255,0,227,206,120,129,94,93,121,246,52,41,183,73,225,62,215,174,31,246,51,171,105,240,157,51,224,169,201,81,167,9,211,140,100,165,151,150,118,119,95,53,196,30,123,123,165,21,44,122,114,116,247,50,173,240,241,139,141,58,141,220,119,83,83,151,111,114,143,190,12,145,185,10,42,146,156,43,253,93,173,167,41,38,117,253,14,241,117,14,159,21,42,106,143,76,163,86,84,233,225,252,233,246,180,223,219,83,43,174,167,66,173,164,234,198,157,120,212,132,241,58,110,56,111,28,39,182,25,171,209,229,123,91,169,220,245,27,216,66,218,157,71,221,78,219,191,185,36,189,116,220,177,91,247,23,126,100,169,70,156,241,70,155,110,62,100,26,148,184,253,14,95,10,218,73,220,212,234,21,33,218,231,21,5,23,253,206,159,168,187,155,170,119,82,163,82,73,213,210,139,90,118,235,143,208,244,157,51,168,218,219,217,197,252,205,69,249,73,198,45,247,74,58,55,250,143,165,233,193,226,217,39,107,20,228,148,96,220,164,223,24,71,115,211,147,143,75,180,79,119,77,55,247,60,95,136,111,37,212,111,107,116,175,39,201,167,117,40,40,84,238,207,124,123,148,155,94,154,69,163,215,74,245,209,236,167,74,158,99,219,219,150,254,156,18,95,122,206,122,100,244,186,150,117,195,202,56,239,37,56,90,206,80,155,140,220,180,148,120,49,181,239,155,148,234,65,41,55,252,47,57,69,184,106,80,150,143,62,158,165,24,82,148,227,78,188,155,114,77,166,181,213,152,95,212,163,66,50,115,121,155,194,131,124,75,211,250,150,41,194,156,106,54,161,149,174,78,187,196,19,180,184,117,236,107,214,196,228,233,213,81,89,206,35,37,45,63,65,84,185,130,125,86,162,156,83,106,49,140,222,127,137,28,246,151,20,87,80,163,94,53,30,37,85,81,198,57,52,39,111,78,180,161,86,132,170,42,181,39,82,180,220,158,84,187,158,222,216,56,108,228,232,221,209,162,150,84,239,35,53,254,238,155,15,163,221,203,234,99,82,73,225,176,153,182,87,37,201,17,80,19,146,250,144,1,64,0,81,140,16,165,19,146,132,8,14,41,238,140,93,40,188,153,0,56,93,7,149,138,146,68,242,234,197,124,146,203,247,57,222,160,98,107,139,190,162,90,197,15,63,11,88,180,114,238,6,43,8,213,140,179,134,102,165,22,183,70,46,156,100,176,226,140,124,149,198,159,96,142,64,112,249,85,22,177,169,175,184,82,172,180,112,207,186,99,85,204,14,7,114,163,56,198,81,107,60,179,56,215,167,37,149,45,6,142,69,184,228,138,73,236,208,121,40,212,185,127,52,179,193,207,111,30,218,17,95,169,173,116,243,57,235,140,35,102,223,252,136,179,63,86,244,228,41,50,10,129,88,35,40,0,8,15,146,45,202,192,7,186,4,96,0,198,155,130,241,128,38,165,38,53,26,126,133,85,122,162,108,82,53,144,31,125,137,184,101,254,224,70,76,54,86,131,122,96,136,133,201,30,193,176,28,16,188,19,1,0,3,34,132,201,72,192,153,11,81,177,112,69,2,5,8,229,166,177,131,151,39,21,55,178,193,204,116,226,205,126,99,252,20,130,126,48,180,255,0,118,148,223,223,67,244,100,119,126,167,231,63,193,55,37,226,250,13,107,23,70,104,253,21,7,163,201,124,189,172,233,200,66,131,146,160,41,10,1,0,128,160,6,5,32,224,140,160,25,120,0,66,133,246,0,27,0,128,81,130,20,0,209,4,2,5,68,0,80,6,193,98,25,16,112,20,227,64,150,6,52,47,32,77,74,82,32,135,3,32,96,10,8,80,0,0,5,33,120,1,236,99,53,242,63,177,145,38,241,78,79,216,13,120,127,148,178,109,45,35,177,167,70,94,101,60,172,238,110,71,233,201,34,174,75,131,21,185,91,194,42,58,127,18,252,253,30,116,162,210,156,182,215,208,243,125,51,229,232,48,173,219,9,246,75,178,25,151,106,213,238,119,158,40,165,81,89,170,244,35,41,214,140,146,237,207,13,234,116,183,181,97,97,210,232,170,244,106,78,57,114,237,164,150,35,232,98,254,205,78,158,131,195,84,156,45,238,43,74,112,151,116,240,156,54,217,104,121,175,24,212,165,59,74,142,77,42,153,196,99,158,15,101,209,226,191,100,219,56,199,10,164,123,241,182,231,142,241,85,186,141,189,71,26,74,85,29,94,216,101,243,171,207,216,182,122,195,141,246,220,181,165,70,207,240,254,130,151,119,100,146,147,125,216,111,147,131,164,86,130,232,86,146,165,21,10,110,115,158,62,229,188,238,173,225,158,145,70,174,97,7,106,231,52,189,112,114,90,194,180,58,117,180,45,233,195,178,52,215,106,146,208,159,87,227,121,70,82,233,54,180,31,114,78,109,201,175,119,159,245,55,122,180,188,181,111,73,60,67,181,38,105,46,250,178,183,167,25,97,169,167,55,195,245,72,221,187,156,157,105,102,11,71,141,86,203,216,172,181,107,247,212,132,99,4,240,181,72,225,183,183,141,27,121,205,193,41,207,61,210,146,54,93,71,28,75,204,199,230,102,233,194,118,179,169,85,191,154,88,75,212,43,150,141,172,19,161,82,85,101,149,28,53,198,14,43,104,170,119,55,82,107,48,143,210,217,154,175,251,222,248,205,118,211,135,108,253,50,113,42,169,219,85,89,120,155,88,151,176,28,247,80,149,110,149,39,26,137,57,190,69,75,251,126,143,103,78,181,253,104,194,149,42,89,146,251,35,134,189,221,181,157,164,171,94,213,141,42,16,215,230,120,71,204,122,135,82,234,223,136,29,90,22,29,42,141,79,134,146,196,234,73,98,49,142,117,109,146,242,202,102,183,122,167,137,42,95,117,24,94,193,37,66,226,16,149,24,71,136,181,207,185,233,250,71,77,185,248,23,121,105,93,57,180,220,105,62,89,230,250,215,133,234,244,94,155,74,198,140,97,219,8,118,198,170,122,229,115,246,59,143,195,126,165,66,191,71,159,77,157,194,157,228,37,41,61,117,223,6,119,219,87,175,78,213,117,238,161,211,45,98,239,250,116,229,81,231,61,175,72,250,125,206,150,251,169,248,143,196,116,161,70,194,50,179,139,169,22,227,217,171,89,215,47,211,7,187,149,56,78,164,85,88,169,44,242,103,70,52,226,156,169,193,69,183,131,89,127,182,99,172,233,221,26,22,50,156,171,86,149,121,77,242,177,131,182,168,219,196,123,177,166,196,130,238,169,190,139,115,25,197,78,225,73,231,228,90,122,21,45,218,201,174,222,216,173,150,167,21,204,229,228,168,164,179,41,101,231,132,114,73,229,228,213,175,85,185,197,40,60,45,222,65,26,215,178,81,181,157,101,245,41,39,147,77,230,173,148,32,160,177,22,223,119,183,161,179,122,233,212,140,173,227,37,231,85,93,208,206,137,35,78,234,171,179,178,167,70,24,147,111,230,146,91,25,170,234,186,156,231,240,80,183,180,165,115,69,252,66,143,154,162,187,26,198,94,91,227,253,77,187,106,182,113,179,74,189,86,167,81,53,77,181,158,230,116,157,103,177,84,133,10,215,144,156,107,47,146,140,164,211,111,213,27,144,242,45,45,233,42,245,20,106,118,97,57,109,8,251,26,145,113,177,116,170,124,15,147,82,171,149,85,77,40,202,49,237,199,232,78,143,42,95,7,69,198,165,90,243,90,118,60,37,153,61,91,50,234,212,169,58,150,52,41,93,226,87,49,93,178,111,89,97,17,82,141,41,119,74,127,15,74,16,239,173,63,101,185,44,62,52,239,175,42,47,16,88,169,209,135,159,10,253,148,164,165,149,132,159,240,254,103,125,55,82,116,167,81,60,84,161,85,121,177,159,202,247,203,199,7,157,240,245,14,159,212,122,175,83,234,86,170,53,237,235,83,135,151,137,118,203,57,121,105,238,153,223,212,121,74,133,180,39,82,132,34,251,221,73,247,73,201,181,207,58,100,145,43,183,133,213,24,94,212,183,140,227,78,106,17,154,82,123,167,232,101,46,206,231,172,83,73,181,238,106,124,44,42,86,149,105,83,140,218,158,32,229,243,99,77,142,21,94,119,85,252,223,42,154,81,126,87,124,119,207,49,42,54,228,212,164,151,116,82,154,90,75,131,82,116,169,203,169,70,173,74,137,202,17,149,73,118,242,210,105,103,245,23,147,84,46,221,71,73,206,106,156,83,237,107,228,89,221,156,17,74,253,215,88,112,135,119,100,85,5,172,254,193,166,141,91,186,245,106,84,171,70,149,73,66,131,82,237,156,184,198,113,247,53,172,107,84,189,234,212,174,45,165,251,138,181,251,156,98,190,88,61,57,252,141,58,183,85,171,90,84,180,132,165,5,26,238,51,173,79,228,115,140,116,237,123,225,251,163,139,161,220,211,171,215,44,41,170,110,149,56,84,81,105,60,167,36,103,234,72,250,164,244,147,192,72,147,210,76,171,99,171,38,50,100,77,177,130,128,0,44,128,0,0,40,5,0,6,0,112,0,0,0,8,0,2,169,0,0,66,128,38,153,216,198,84,161,44,233,140,238,102,8,53,149,164,35,151,9,206,47,239,147,37,26,177,109,247,103,67,155,26,141,134,14,182,172,166,227,94,115,134,137,107,142,77,139,91,132,237,233,247,69,199,43,69,190,12,42,198,82,161,115,239,9,96,228,181,138,248,90,89,195,249,86,72,57,212,225,39,132,209,146,56,101,111,78,73,227,49,126,177,122,152,186,53,148,179,10,218,99,102,138,54,50,76,154,249,186,140,99,244,73,231,95,177,126,37,198,88,169,78,73,122,164,52,108,19,243,48,87,20,229,179,201,154,104,0,12,128,84,50,0,20,19,236,82,137,201,72,216,6,169,25,114,135,0,99,246,24,46,195,64,169,200,227,220,48,240,17,143,1,141,129,17,115,160,33,66,160,0,128,99,238,93,144,220,8,2,220,36,65,86,136,164,0,115,83,224,230,56,105,240,76,175,83,167,30,153,175,205,159,129,233,127,182,45,255,0,45,188,176,190,248,255,0,145,250,38,15,86,126,122,252,14,78,62,47,150,86,142,218,95,232,126,133,130,211,98,249,59,88,204,140,188,16,230,160,75,0,1,89,10,192,23,130,12,228,133,20,143,114,128,35,26,48,8,41,0,40,172,156,128,5,251,2,13,208,20,4,0,110,85,247,4,206,129,0,10,21,10,66,240,0,100,21,5,10,64,183,2,130,20,34,100,164,228,160,0,24,0,92,232,69,169,88,21,53,141,140,42,107,9,125,140,150,228,169,244,75,236,81,171,65,118,210,77,239,147,113,108,106,83,203,166,243,177,182,182,88,36,85,193,36,80,214,74,142,135,196,247,18,161,102,215,150,231,25,65,247,37,191,228,121,158,171,119,70,61,63,167,91,58,85,155,169,140,36,240,163,255,0,23,169,233,188,81,9,206,214,133,58,105,230,83,195,113,225,30,114,162,85,105,90,74,89,158,42,168,167,37,198,76,114,106,116,247,182,240,242,173,40,83,90,118,193,35,202,120,178,206,156,169,121,189,239,186,15,185,36,244,201,235,154,249,82,94,135,67,214,165,41,206,149,162,237,94,100,241,38,214,116,47,46,147,143,109,78,179,111,85,252,21,189,53,5,111,10,42,51,215,85,149,232,115,198,188,41,80,141,8,67,46,26,38,253,14,167,196,87,17,119,246,54,169,252,149,43,167,82,109,255,0,12,83,194,253,112,110,202,81,141,39,53,152,229,225,97,110,201,245,172,118,84,107,194,55,84,169,66,51,169,54,178,228,150,136,227,171,113,59,171,134,233,211,150,143,18,111,131,94,211,252,232,213,159,215,20,215,110,116,193,196,225,229,211,171,40,55,218,228,222,173,234,68,115,206,49,174,227,74,77,74,25,206,49,202,54,47,101,229,216,81,130,135,214,245,127,202,104,40,212,167,94,218,10,81,133,22,211,148,222,235,216,218,234,149,97,241,113,166,162,255,0,117,4,214,94,143,60,149,43,10,115,183,183,181,149,8,101,213,147,239,146,195,215,238,204,104,201,84,234,52,232,41,170,138,75,185,165,252,36,163,111,82,250,173,88,198,94,91,150,137,189,240,101,212,186,197,151,135,105,211,177,181,167,26,247,205,108,150,92,125,223,252,134,43,205,120,159,166,63,16,117,197,105,127,25,210,181,131,236,167,70,45,230,163,245,103,186,232,253,46,219,162,116,202,86,118,180,163,5,24,165,38,150,173,157,103,64,233,149,163,55,213,186,162,205,237,93,97,9,127,217,199,254,103,123,41,74,111,11,243,18,123,210,223,141,43,219,72,221,247,66,105,74,19,139,140,179,232,207,150,71,167,214,240,143,140,163,27,106,19,141,9,207,190,58,125,113,254,37,147,236,74,24,137,169,127,99,70,254,143,101,72,39,56,167,217,44,106,133,227,167,30,88,198,133,221,27,218,20,235,219,203,190,149,79,154,18,94,231,60,101,141,113,178,212,240,83,173,113,208,250,125,213,197,189,199,125,165,187,93,240,230,140,227,254,141,51,214,116,110,173,75,172,88,66,234,142,207,70,77,44,199,105,77,40,193,201,233,147,142,50,79,45,108,249,44,230,188,167,221,159,67,142,154,80,130,131,122,47,67,76,162,238,157,212,97,20,220,123,187,91,52,242,174,186,164,233,119,226,20,243,133,195,198,226,87,208,182,109,57,225,188,255,0,245,58,218,212,174,44,186,125,155,171,82,53,36,231,86,83,149,55,133,44,182,210,111,236,75,90,141,46,177,86,140,239,45,174,158,182,241,125,244,230,190,248,55,47,42,255,0,129,157,85,29,100,150,51,201,211,217,217,127,180,94,33,197,15,54,29,58,218,140,105,206,13,124,189,203,87,134,119,62,49,186,167,210,172,34,161,13,105,209,114,138,107,71,131,51,251,91,253,60,229,123,75,75,251,250,50,189,183,140,161,77,118,167,156,52,223,185,216,218,244,10,29,99,166,75,167,84,171,81,98,61,179,147,127,52,94,94,169,251,172,126,135,153,253,163,119,212,186,29,43,186,148,161,26,213,106,118,199,31,44,116,228,250,95,65,132,37,103,10,241,75,51,130,77,174,125,205,45,245,30,59,174,248,19,170,252,117,157,207,74,186,243,35,65,165,219,86,120,237,138,215,67,82,159,138,167,59,153,80,189,233,146,141,24,212,242,43,85,127,67,147,95,78,187,159,80,150,169,175,83,207,248,159,160,218,117,110,153,58,85,104,182,251,187,226,169,232,251,189,114,49,153,119,183,79,97,107,240,54,119,87,118,116,21,230,171,178,149,12,39,143,69,246,54,172,237,169,94,74,143,159,15,42,189,88,121,189,149,86,180,254,231,158,135,129,122,197,173,213,91,174,137,213,42,81,82,164,163,40,85,122,165,206,49,203,58,251,207,18,94,210,187,176,163,213,58,101,204,39,103,55,223,113,156,121,169,46,61,117,39,85,172,222,158,202,206,112,184,181,117,163,57,70,162,184,154,158,39,218,150,52,210,39,93,105,212,43,71,168,58,61,70,203,201,147,143,125,9,198,61,209,82,77,172,233,203,70,213,143,83,149,226,180,157,191,109,189,58,208,114,149,58,144,89,148,184,90,234,108,91,116,218,146,233,245,106,86,114,87,147,125,216,111,49,167,143,97,102,166,127,110,158,61,102,149,122,157,81,59,222,218,21,39,77,83,173,45,92,158,189,241,75,219,8,231,176,234,111,166,244,183,40,58,184,163,70,78,47,214,90,234,189,222,199,95,70,218,20,173,175,46,229,70,53,110,124,245,8,66,16,206,19,221,165,249,155,23,30,93,181,149,58,179,132,251,28,219,148,99,23,46,121,70,104,214,186,161,127,105,210,33,81,80,169,41,74,51,171,115,66,82,253,228,243,171,104,208,232,19,165,95,197,118,234,84,46,40,194,83,141,74,106,75,24,126,135,101,117,43,203,170,214,157,74,181,196,33,11,104,185,69,52,225,83,182,92,201,114,142,191,160,95,171,207,27,91,212,171,62,248,206,179,80,169,24,165,23,169,175,132,125,133,175,157,228,137,240,31,214,203,131,108,11,66,147,147,32,49,228,188,16,160,68,80,54,2,128,10,0,0,0,0,41,0,8,0,2,128,0,38,133,0,128,0,40,17,148,132,26,201,57,66,170,111,26,49,104,215,195,164,184,48,148,177,10,185,221,167,177,201,102,191,195,197,99,12,131,156,187,160,56,40,152,192,227,5,0,97,40,69,199,14,40,199,201,73,104,218,252,206,92,122,144,14,15,46,170,122,85,211,211,6,89,168,183,73,253,142,92,123,140,114,48,112,249,147,77,39,7,183,27,25,58,209,209,52,215,221,25,189,26,43,73,251,228,12,124,216,99,89,36,78,248,101,98,72,146,163,78,107,182,80,77,122,24,202,222,156,162,151,110,18,215,64,57,101,166,161,28,81,164,227,55,251,214,227,232,204,84,107,70,79,231,78,60,104,17,207,162,27,191,67,94,83,185,139,249,105,198,107,239,169,101,113,56,253,116,158,49,186,212,106,185,245,99,39,10,184,139,195,195,195,245,69,141,120,62,70,142,87,234,76,182,59,162,246,105,132,192,155,23,24,38,10,192,17,178,144,0,0,130,49,157,48,86,76,104,4,42,0,128,22,128,114,7,52,52,104,228,242,225,252,167,20,55,138,247,57,60,196,116,226,205,126,111,252,15,146,126,43,138,127,254,175,47,237,147,244,12,23,182,135,192,191,3,161,255,0,222,89,191,255,0,197,147,95,219,253,79,190,210,47,62,214,51,40,99,67,154,160,64,47,64,25,8,20,0,38,229,0,7,221,19,144,0,164,0,8,80,40,216,133,101,15,112,77,202,247,2,21,108,0,0,24,1,200,200,0,95,65,184,8,10,9,178,13,129,72,183,8,175,96,12,13,198,192,80,8,5,0,1,118,99,243,32,40,202,59,24,85,150,41,201,189,176,100,158,132,169,142,199,149,148,65,173,9,102,29,175,147,110,47,229,199,161,215,80,250,229,41,54,222,78,197,104,151,184,133,81,239,146,54,56,42,58,158,188,170,74,217,121,43,186,167,9,158,95,167,220,171,169,168,213,163,56,58,85,251,113,53,132,241,202,61,135,81,138,156,98,181,215,76,163,204,93,71,225,186,189,173,188,219,106,179,238,143,228,209,155,61,183,30,217,189,143,61,212,218,151,85,167,159,84,190,199,160,107,45,47,99,198,117,43,170,176,234,213,227,82,164,31,110,180,212,118,143,187,47,36,226,235,163,74,183,81,241,125,75,89,193,66,20,90,157,44,243,135,156,254,167,113,93,102,18,166,218,237,115,111,49,245,103,95,209,35,59,191,16,93,220,247,183,26,84,176,166,255,0,139,38,205,221,196,169,219,80,242,105,37,37,89,169,103,209,242,70,173,246,218,176,166,233,218,85,81,79,9,225,101,229,234,115,38,169,246,46,222,249,71,84,140,233,195,202,177,237,135,204,234,79,89,122,104,37,81,82,132,234,83,198,159,47,115,217,25,70,180,218,175,53,42,211,169,42,114,120,236,113,88,137,169,213,239,29,106,240,149,179,79,247,138,18,124,40,237,249,157,231,70,133,42,253,62,162,172,227,56,169,188,201,236,120,239,25,120,187,160,116,216,74,218,198,143,197,93,219,237,228,205,118,65,231,103,174,165,147,209,245,221,117,110,177,15,14,88,170,175,182,181,237,86,161,109,65,188,57,73,242,117,29,13,208,233,87,85,110,174,224,250,143,87,175,46,249,211,182,95,37,44,241,151,187,62,121,211,171,117,239,27,248,146,119,110,110,62,68,87,117,79,47,42,57,209,69,122,31,117,232,125,26,143,69,233,212,232,70,42,85,177,154,149,30,242,102,169,184,234,235,245,190,169,73,211,188,173,211,212,44,251,177,56,183,153,99,216,237,63,111,116,217,82,82,133,94,233,183,133,79,248,179,246,59,10,240,141,197,25,82,154,78,50,88,122,30,114,151,70,163,119,94,234,133,72,249,117,99,164,42,69,97,164,103,220,232,244,217,151,86,171,109,93,92,92,230,86,181,100,163,24,199,248,23,169,221,210,169,78,181,53,86,140,213,72,61,156,94,79,27,87,167,245,187,9,43,107,106,42,226,48,249,163,42,143,57,246,49,182,184,242,46,41,193,92,62,139,121,135,39,111,85,254,230,163,123,178,75,253,150,107,181,234,246,52,237,42,221,213,84,99,42,23,148,220,43,66,81,204,91,245,103,138,240,189,122,253,11,172,91,211,85,37,86,202,180,213,29,254,156,188,236,123,155,203,234,245,186,101,123,91,187,121,57,85,166,226,171,208,214,13,181,186,244,60,5,24,82,143,133,170,59,136,247,221,82,147,149,25,39,143,158,47,70,197,237,102,230,62,171,119,24,180,211,210,49,249,179,232,117,85,186,133,178,236,167,241,81,85,42,183,8,69,110,222,50,121,139,207,18,93,248,138,141,151,76,233,121,167,82,181,56,252,68,219,237,114,124,164,248,91,157,253,151,131,108,233,202,157,199,80,149,75,139,148,177,151,61,19,198,52,198,11,187,210,102,118,225,151,91,232,54,142,42,242,230,42,172,35,40,185,225,180,223,228,116,189,62,149,231,91,188,248,203,27,53,10,107,49,78,173,195,196,151,170,143,7,183,163,209,250,109,10,50,163,74,206,148,35,37,134,210,203,253,78,169,91,75,165,117,42,93,137,66,222,43,86,182,252,204,229,250,187,62,59,174,159,106,172,237,225,73,36,165,143,155,27,100,249,247,141,110,171,92,248,150,227,166,86,238,84,60,136,118,103,110,217,103,47,245,88,252,143,161,66,242,221,218,212,188,133,69,58,48,77,183,29,118,244,62,67,213,102,186,149,205,122,150,216,141,197,229,105,56,205,229,207,25,209,60,236,106,220,56,251,174,215,165,116,216,221,86,179,177,166,148,172,103,12,97,188,201,75,25,122,122,113,147,233,22,150,244,237,45,105,219,210,138,140,33,20,146,92,29,15,133,58,124,173,105,41,92,37,231,198,222,157,41,227,108,165,191,220,244,145,212,79,237,42,227,44,142,62,139,38,88,105,142,77,50,215,116,48,243,22,226,105,223,216,89,245,43,101,109,123,70,53,97,45,158,53,142,61,25,218,227,43,114,118,250,197,51,56,186,249,125,255,0,128,172,229,213,32,186,125,105,58,144,204,254,106,178,238,166,253,181,58,219,202,61,107,166,202,171,167,121,119,94,181,8,102,125,223,198,189,49,201,245,239,135,183,114,203,163,21,46,100,150,166,189,78,159,9,232,154,120,219,43,98,126,43,249,62,57,111,227,106,253,54,141,47,218,93,62,165,59,137,199,204,131,140,176,240,246,202,59,11,143,22,80,185,171,210,225,11,217,89,194,189,73,78,242,88,79,181,229,99,95,212,247,183,94,16,233,183,147,157,75,155,120,84,169,40,168,230,113,202,209,229,30,94,235,240,158,194,164,229,82,50,170,165,38,219,236,107,183,244,37,226,108,109,120,163,166,218,245,94,155,105,90,210,250,18,171,115,25,219,82,106,90,84,238,139,211,79,177,214,126,29,116,154,148,58,132,45,46,226,211,180,172,234,71,49,250,190,93,243,247,48,159,225,199,87,177,165,74,93,51,168,71,20,106,121,148,96,226,227,216,253,126,227,160,87,241,69,133,213,205,27,136,202,78,139,105,84,154,199,115,126,254,134,189,31,31,88,122,73,148,243,29,3,196,23,23,157,214,183,248,167,112,151,201,38,176,164,207,65,70,191,124,112,241,156,224,78,82,165,152,230,69,36,76,141,34,19,212,173,144,10,128,200,192,20,0,80,0,0,64,0,128,0,40,0,0,76,106,80,0,2,16,80,1,64,140,164,123,1,166,255,0,202,174,211,215,181,254,76,206,197,183,107,6,247,123,191,83,26,43,186,157,92,242,158,81,135,75,155,149,154,207,15,25,245,50,173,224,194,12,210,0,133,0,0,0,69,169,118,27,32,35,90,140,106,50,137,157,114,20,123,151,24,68,121,43,247,34,49,211,37,107,3,118,2,35,96,50,231,64,35,94,166,14,149,57,104,226,140,248,8,17,193,240,148,214,176,238,139,246,101,116,101,141,42,72,231,224,108,130,181,148,43,199,105,169,123,96,42,181,148,51,58,95,54,216,139,54,22,128,131,94,55,63,38,103,7,31,83,40,92,82,169,244,77,75,236,114,180,154,213,28,46,218,147,206,34,147,245,90,1,202,154,122,38,13,87,99,8,225,211,169,82,15,157,119,50,148,110,99,244,74,18,92,39,185,52,108,3,94,53,234,167,138,180,112,189,83,50,87,52,155,198,112,253,25,76,115,14,12,99,40,212,95,43,201,125,182,32,172,6,76,129,207,79,116,107,230,94,172,216,134,241,50,236,94,134,226,87,231,159,192,216,75,246,253,73,45,163,109,37,253,81,247,154,123,63,83,225,63,129,47,187,172,94,101,234,168,44,31,118,134,113,169,174,125,145,158,8,1,205,70,245,8,20,9,143,80,246,0,10,129,56,0,80,64,81,120,212,125,137,200,32,123,148,4,180,40,100,16,160,8,10,0,112,94,8,3,60,0,182,28,128,28,20,106,10,1,143,212,2,41,26,0,11,193,11,129,128,166,10,64,16,193,64,0,0,8,54,1,125,48,20,49,169,244,53,253,76,190,236,147,210,44,35,70,154,74,171,198,207,250,155,249,120,73,236,104,210,111,206,148,28,116,90,166,111,39,148,132,90,184,4,212,191,114,163,86,231,252,232,35,206,117,218,115,165,214,236,46,114,187,37,82,48,88,227,44,244,245,104,42,147,83,238,107,180,233,58,213,40,70,181,23,81,188,66,164,103,23,142,81,142,77,74,244,21,102,169,70,165,71,244,197,54,124,250,173,141,90,148,47,39,26,109,194,172,210,115,254,38,183,103,182,234,21,59,237,163,4,242,234,172,29,13,213,164,233,206,80,85,37,133,30,232,198,62,184,53,78,62,153,244,117,66,149,141,229,106,73,42,106,93,144,199,56,91,126,166,189,175,196,93,203,254,169,37,243,60,189,209,187,209,122,108,255,0,217,154,52,165,79,203,169,39,41,184,203,213,183,185,199,62,177,211,252,45,97,83,227,46,225,57,166,228,212,53,127,108,25,190,149,218,90,219,170,177,167,77,197,168,69,55,44,250,154,61,107,171,116,238,153,101,40,40,194,172,150,138,11,150,121,170,126,33,241,23,141,60,202,29,18,206,86,22,47,73,94,214,211,79,101,201,222,120,123,193,22,93,10,157,42,151,55,21,58,141,229,61,170,214,218,63,100,38,252,79,254,188,221,247,75,241,63,136,58,119,195,244,234,111,166,210,171,36,220,166,240,154,251,110,112,244,207,193,200,81,189,119,61,83,168,82,173,221,245,66,141,62,220,191,185,245,14,230,214,50,51,177,79,201,167,210,250,63,79,232,214,113,181,176,183,141,42,107,86,210,214,79,213,179,116,2,162,228,212,173,15,46,237,87,90,103,229,120,54,204,101,21,40,180,201,70,81,147,75,115,75,168,244,171,14,173,26,113,190,161,26,190,92,148,163,147,101,60,35,37,234,55,71,95,83,165,78,52,60,171,11,153,219,164,177,24,164,154,95,147,62,93,215,172,234,198,119,253,42,21,93,196,233,254,242,50,148,20,91,150,117,90,31,100,143,212,143,154,215,239,191,241,189,220,160,148,41,210,184,84,219,254,108,45,76,242,142,156,47,183,119,208,44,172,188,61,210,45,110,238,169,102,250,181,56,165,14,83,244,71,123,79,168,221,247,165,115,211,93,26,111,94,255,0,49,75,6,140,108,227,87,169,211,169,38,230,233,107,30,230,119,23,117,84,45,106,78,91,118,151,25,190,220,208,148,103,77,78,15,49,146,202,53,250,141,172,111,44,167,73,190,214,150,83,29,63,63,1,71,56,219,131,97,231,130,163,197,194,133,197,199,134,235,116,245,86,181,170,243,50,165,24,225,180,153,228,45,45,170,75,174,194,222,52,221,122,212,234,39,223,230,36,212,125,112,122,239,22,199,224,107,66,162,169,83,53,54,249,180,142,119,208,207,195,93,14,132,110,227,212,251,19,168,225,142,231,29,90,252,201,108,111,169,175,81,101,70,172,32,229,87,9,180,180,54,204,83,111,116,82,177,89,21,44,50,101,250,20,168,53,232,76,149,190,64,12,163,5,20,219,224,173,96,106,64,105,173,158,82,245,49,237,251,153,167,166,161,109,184,4,241,246,56,110,173,41,221,83,146,122,77,173,25,207,128,177,201,71,159,185,233,206,22,80,116,232,169,87,165,156,123,152,89,86,106,116,164,229,217,90,81,74,80,111,103,201,232,90,215,39,83,214,44,225,77,195,168,210,135,205,79,252,200,255,0,50,49,248,253,93,118,240,146,149,56,201,61,204,142,147,160,223,170,221,246,175,57,143,205,22,222,184,103,114,153,169,81,117,41,62,225,20,82,144,1,120,0,20,1,22,229,32,133,33,64,12,128,80,1,128,0,122,147,33,20,16,160,0,0,9,45,34,254,197,49,159,209,37,236,6,149,54,229,101,113,39,164,187,36,101,210,251,126,6,154,142,202,41,10,77,58,53,227,135,132,176,255,0,67,146,194,42,22,84,177,141,81,62,171,96,0,202,3,32,152,120,10,172,99,80,76,128,123,143,98,147,156,1,1,73,183,32,63,132,107,129,198,129,1,53,64,164,220,137,78,67,88,27,0,139,192,68,50,11,17,238,55,95,96,247,26,36,21,6,67,1,17,140,0,246,32,128,112,80,33,140,169,194,75,88,69,254,70,73,133,176,26,147,177,167,44,121,115,157,38,191,145,133,70,234,150,213,227,86,62,146,88,102,215,37,32,212,248,154,144,120,169,66,120,254,104,173,17,148,47,104,77,165,221,135,239,161,177,247,48,169,66,149,85,137,211,139,247,192,86,205,54,155,77,60,163,31,59,221,146,132,35,77,66,49,209,35,15,151,212,223,22,107,224,255,0,129,75,255,0,106,223,97,99,22,241,201,247,56,75,9,35,226,95,129,52,90,189,234,50,111,122,17,254,231,219,105,199,37,231,217,28,143,216,139,97,200,102,20,76,16,160,71,238,80,0,168,17,2,138,70,10,4,5,224,196,130,253,195,89,27,0,15,66,166,56,25,88,40,155,132,176,84,49,141,64,112,8,242,94,0,153,42,200,42,2,0,0,190,224,136,160,16,5,1,200,67,129,246,10,32,0,69,192,0,7,32,0,46,137,123,145,23,66,47,184,69,56,235,60,83,147,57,48,113,214,105,82,150,118,10,212,140,187,59,92,150,101,57,99,62,134,250,208,235,33,55,39,135,178,103,100,177,218,153,33,89,51,28,140,144,163,39,37,140,37,169,173,119,105,11,202,78,19,89,120,54,14,179,174,117,170,61,30,206,82,148,226,171,74,45,193,73,232,177,200,87,95,214,250,197,175,71,183,161,10,242,253,226,218,61,222,135,148,233,191,136,19,189,187,148,43,90,193,210,115,113,202,214,88,227,7,158,182,157,215,139,250,180,42,201,59,152,210,110,42,93,184,82,147,227,223,7,211,60,53,225,27,78,133,77,86,173,24,85,190,121,125,248,210,25,225,17,125,71,93,27,47,19,248,146,155,239,187,159,69,233,205,252,176,138,78,173,72,251,255,0,42,57,173,63,14,58,21,12,202,229,86,188,155,121,238,171,35,215,57,55,185,6,68,218,198,149,42,86,244,33,70,133,56,211,165,5,136,198,43,9,25,96,20,168,133,192,42,94,160,64,87,183,176,224,2,24,64,112,4,107,92,151,24,65,53,156,6,1,105,151,157,145,243,234,117,93,111,16,210,141,26,112,93,243,115,147,142,249,219,44,247,119,117,225,111,99,113,90,122,70,20,219,108,240,94,27,82,169,226,119,93,118,186,9,60,202,47,56,211,102,70,163,217,86,74,214,246,51,155,237,132,176,150,135,31,84,185,149,76,217,219,71,190,115,134,178,254,83,91,168,93,86,234,151,42,222,222,180,168,82,139,195,154,95,51,251,24,93,88,213,233,84,233,215,181,187,171,82,167,119,239,29,77,114,133,164,255,0,110,246,222,147,163,109,74,155,121,113,138,76,207,92,238,113,81,186,167,94,222,149,110,236,119,197,60,61,14,94,229,234,178,246,212,51,94,87,196,214,87,157,74,226,157,4,163,11,103,86,42,85,49,153,118,243,143,67,210,219,208,141,10,49,167,79,72,40,164,145,132,237,251,238,20,230,242,150,200,217,81,106,40,146,53,111,161,32,145,113,128,138,201,130,173,136,147,47,37,21,16,61,202,3,25,100,228,164,1,130,118,148,160,99,170,42,213,2,165,160,19,6,183,83,207,236,171,156,99,61,143,25,219,38,209,171,212,38,161,211,234,202,88,198,139,95,118,7,143,240,165,205,43,142,175,78,164,42,194,163,242,156,170,56,237,23,232,123,135,245,31,51,240,85,179,165,113,213,233,165,164,170,74,42,81,245,132,164,159,245,201,244,168,60,211,140,150,205,35,60,90,229,219,60,0,25,166,87,144,183,33,64,100,0,128,160,133,101,0,78,74,0,0,4,5,0,27,212,133,0,64,10,0,16,160,12,101,179,40,122,38,253,136,52,211,197,173,95,70,108,80,199,145,20,150,48,182,53,160,252,203,74,142,58,54,109,65,226,43,66,65,150,48,80,135,6,148,228,50,123,140,242,17,72,18,38,66,174,118,4,90,242,86,1,147,130,189,201,239,168,7,176,215,180,112,24,4,244,38,116,47,4,91,4,160,24,211,32,136,188,7,177,56,247,27,129,50,80,247,35,10,0,0,0,50,0,133,90,147,98,7,3,98,0,41,1,121,32,156,151,1,142,64,229,135,213,19,143,15,249,78,74,127,82,53,124,216,255,0,49,190,61,37,124,91,240,35,181,222,117,40,173,213,8,127,118,125,166,12,248,167,224,60,31,198,117,89,46,105,67,251,159,106,130,195,194,212,188,251,35,147,4,43,219,4,48,160,4,94,160,25,119,24,0,17,115,160,5,16,110,153,120,38,8,1,134,0,103,1,13,128,20,140,187,145,148,17,114,64,1,60,148,47,80,0,153,5,193,0,1,130,138,85,185,10,128,152,42,28,147,144,170,16,0,10,66,132,61,192,0,0,1,14,125,128,224,21,71,37,24,202,82,217,44,156,17,114,173,77,84,109,40,181,162,54,52,107,93,76,37,24,168,183,140,34,17,162,211,117,49,133,143,99,125,60,69,125,141,52,191,122,109,239,130,21,83,206,165,8,171,24,109,180,146,213,182,84,113,215,175,74,218,132,235,86,146,140,34,183,103,200,186,205,212,188,71,214,110,169,249,55,21,37,95,247,116,100,222,35,77,47,68,122,191,22,120,150,139,169,107,99,107,40,206,165,74,137,164,245,77,123,153,120,99,195,208,167,112,238,171,77,212,171,39,221,39,194,246,94,136,205,219,211,114,103,109,255,0,8,120,110,159,68,233,180,229,82,9,85,75,229,95,202,189,126,231,163,123,149,188,176,105,144,140,160,6,6,1,64,137,50,238,64,5,121,3,58,128,3,128,57,2,36,145,151,4,46,61,64,208,235,80,117,58,37,221,52,179,223,77,172,30,91,192,246,116,45,213,90,105,102,106,46,114,252,244,61,101,251,115,182,171,4,241,242,188,157,39,132,109,37,77,93,86,157,40,211,140,241,21,137,103,38,111,109,207,213,216,244,235,63,46,230,172,230,212,148,158,86,120,57,58,188,164,169,82,167,4,156,170,77,69,163,26,202,181,149,89,86,167,9,85,89,214,49,92,24,244,218,119,55,119,21,47,110,162,224,148,159,149,77,173,82,44,254,147,253,187,47,34,146,140,32,226,154,138,73,24,206,133,41,201,62,197,148,114,191,82,173,75,140,181,254,14,139,89,195,77,250,51,138,86,21,115,154,55,181,233,254,121,95,212,222,89,15,250,19,241,138,208,169,67,168,168,37,75,168,67,63,239,210,76,180,255,0,105,198,73,84,118,243,143,170,77,54,110,189,244,8,97,168,156,240,212,162,147,251,133,38,247,70,88,11,82,162,38,51,234,83,23,162,2,231,59,23,92,28,112,146,145,200,0,5,177,120,2,23,130,23,144,7,87,226,42,148,233,116,74,211,168,212,96,183,108,237,15,49,227,233,246,248,94,113,217,74,164,114,47,68,237,228,252,23,78,189,85,82,140,37,42,116,229,90,78,53,51,172,147,147,127,234,125,62,43,182,156,97,232,120,79,6,82,85,177,86,56,237,222,47,109,62,199,188,90,179,60,122,107,147,36,0,111,6,153,11,192,201,0,187,1,146,128,4,0,17,91,211,66,2,130,41,0,20,139,220,160,1,10,0,2,0,41,1,64,132,150,123,30,55,193,70,64,208,167,36,237,106,56,238,158,198,228,62,136,233,193,165,74,29,176,149,62,50,222,125,77,232,191,146,63,99,48,100,76,140,232,53,224,210,166,153,213,23,118,50,61,194,13,145,1,236,67,77,51,161,91,215,4,198,16,88,41,162,25,192,206,184,35,217,160,106,238,24,198,132,122,176,106,189,25,6,115,129,206,129,6,194,244,33,146,68,17,106,199,35,96,1,238,66,144,40,0,8,0,0,16,188,144,138,114,64,56,0,16,224,112,64,5,224,152,10,230,167,188,77,15,37,27,240,95,52,77,47,58,62,167,94,61,49,95,31,252,10,209,245,102,178,241,78,9,100,251,28,87,169,242,15,192,200,199,200,234,53,115,172,163,24,184,253,143,175,172,101,224,156,214,50,198,129,148,143,6,20,90,106,8,253,138,128,103,208,0,5,228,13,8,5,38,117,192,209,2,131,0,16,57,41,16,206,161,0,82,114,20,42,68,41,68,220,164,101,32,15,114,23,130,160,7,216,1,74,66,167,168,83,36,46,8,5,0,1,119,68,99,220,113,144,40,32,228,10,0,8,21,104,16,101,6,140,42,191,221,225,108,103,193,199,87,232,34,198,180,191,207,73,99,47,95,200,218,138,52,222,124,244,247,111,251,27,209,206,9,10,167,144,241,207,91,149,133,42,22,75,49,167,93,102,165,72,191,165,38,178,159,220,245,235,86,124,131,196,181,39,214,124,124,236,188,217,212,183,168,251,92,33,252,41,45,135,34,118,238,122,77,149,189,207,80,157,234,138,175,5,133,110,218,210,43,27,158,235,167,208,116,45,20,95,212,222,78,171,163,244,122,22,86,145,163,30,238,200,225,71,59,232,142,254,43,9,34,173,170,181,0,4,0,228,1,65,27,70,50,169,24,53,220,240,152,25,100,13,192,21,2,0,42,220,164,0,94,125,195,212,136,185,40,226,157,56,205,52,214,115,163,49,182,183,167,107,69,82,165,28,68,229,101,50,46,95,3,157,66,30,197,19,157,54,50,91,140,4,12,82,122,128,3,146,20,1,86,192,139,80,216,21,234,116,157,87,175,218,90,206,226,210,157,78,251,202,120,139,165,29,242,214,87,247,59,107,171,136,89,217,87,186,168,254,74,52,229,82,95,100,178,124,223,162,116,217,245,59,186,221,90,187,173,25,222,214,133,108,203,79,147,76,127,68,103,149,197,145,244,43,21,82,54,20,21,109,106,246,39,63,185,178,181,35,107,44,168,168,187,0,84,80,223,0,136,188,128,231,7,143,252,66,169,75,224,44,173,107,57,70,53,42,247,102,47,124,99,71,250,158,193,111,147,230,255,0,137,151,145,173,121,71,167,39,20,232,219,78,230,82,147,244,209,47,187,100,229,210,206,221,247,131,237,229,27,105,220,84,135,103,119,203,8,37,164,98,182,61,50,58,127,11,208,149,175,64,183,165,56,118,73,69,101,53,141,78,231,146,206,138,191,194,22,187,141,222,163,102,17,64,99,0,11,193,10,183,0,3,96,0,0,160,82,20,8,10,64,0,0,0,14,64,14,7,32,7,220,139,64,194,215,64,52,232,229,41,57,111,151,253,205,184,173,13,91,105,41,186,152,217,73,163,109,125,36,130,227,8,155,13,86,161,253,202,34,122,229,148,152,215,35,144,128,231,65,128,64,27,6,77,115,177,67,220,63,82,236,137,140,32,47,4,91,140,147,58,144,93,198,195,145,198,165,0,137,192,91,129,119,11,81,252,33,104,64,123,16,164,0,48,3,0,0,64,30,228,27,149,145,83,220,7,176,2,48,0,15,200,168,5,163,34,185,105,253,81,52,60,216,127,42,59,8,111,19,169,195,244,103,78,61,51,95,44,252,10,74,86,61,70,79,234,204,116,62,189,21,169,242,79,192,200,227,167,117,38,254,166,160,207,173,195,108,142,125,172,102,77,198,192,192,96,96,112,92,129,7,57,15,96,3,156,12,96,188,134,80,35,40,2,2,140,16,78,3,208,13,2,3,145,184,0,10,10,33,113,161,48,94,24,1,141,6,6,192,2,1,1,64,224,0,122,45,10,76,234,2,133,208,155,20,0,12,1,65,11,144,0,0,128,0,162,156,117,117,131,57,14,58,171,16,212,131,87,45,86,213,99,220,220,135,210,141,46,230,230,147,215,83,118,56,194,36,90,227,185,168,233,89,87,168,183,141,54,255,0,161,224,191,15,233,67,168,117,14,167,127,86,9,206,156,188,181,45,218,111,83,215,248,150,227,225,60,51,125,90,58,73,82,105,126,122,29,71,225,255,0,78,133,135,135,37,83,122,183,21,59,231,47,86,44,218,179,167,165,167,30,211,147,118,68,82,178,160,13,0,161,32,136,183,10,233,171,245,72,207,170,209,181,163,36,218,158,42,39,233,236,103,127,119,83,246,213,189,172,41,247,195,121,61,176,143,41,214,186,156,122,55,139,172,229,90,202,180,165,58,157,148,106,45,32,219,89,198,121,103,161,180,116,238,58,252,219,109,215,80,82,146,111,233,94,200,196,106,200,239,209,70,65,182,64,80,0,112,66,129,82,200,104,139,216,36,81,10,55,43,193,4,91,151,141,66,120,5,21,45,53,28,7,169,51,161,5,219,112,139,157,8,183,251,0,228,114,198,64,13,185,26,15,114,107,176,29,15,141,111,163,99,225,75,164,222,29,124,81,88,223,230,223,250,100,232,60,33,82,226,246,188,235,207,42,210,154,81,167,25,61,116,216,218,252,64,175,109,42,54,118,215,19,106,148,42,121,147,237,225,173,142,63,6,221,82,175,86,230,20,99,138,93,201,197,250,172,28,239,236,212,233,237,22,171,39,34,88,70,49,213,96,185,212,232,202,151,146,33,184,7,162,200,142,171,33,234,210,25,192,21,232,155,124,31,40,241,7,81,93,75,198,42,132,58,91,169,86,78,29,149,36,251,177,8,191,171,28,106,125,35,172,222,254,207,233,21,238,26,109,225,69,99,223,67,231,190,22,84,110,250,205,213,205,24,253,114,80,156,231,22,219,107,132,248,70,121,123,184,215,31,237,244,170,113,113,167,21,156,182,147,103,47,6,9,99,9,45,18,51,247,52,201,177,82,245,24,9,128,216,13,202,128,32,6,232,0,8,20,3,28,128,160,0,32,0,0,0,0,61,192,0,9,201,121,64,2,250,136,244,68,114,237,156,125,194,53,45,113,26,238,9,105,37,41,255,0,83,115,56,208,211,181,203,184,172,219,88,79,11,216,219,228,145,85,100,154,128,244,40,123,13,134,113,176,111,112,131,99,35,129,184,12,106,16,195,38,88,20,140,175,238,98,245,2,144,168,45,192,37,168,221,140,13,144,12,101,132,19,24,208,2,15,3,100,0,129,12,20,130,0,0,112,16,0,8,202,70,0,152,193,71,4,16,114,48,92,17,83,98,144,161,92,180,245,148,78,179,43,249,153,218,67,73,68,233,188,198,116,227,211,21,243,79,192,231,255,0,179,122,156,154,213,118,69,126,135,211,229,121,26,73,252,178,211,118,124,207,240,70,57,233,29,74,88,211,204,143,246,62,160,169,66,95,84,114,57,246,211,42,117,124,212,154,88,207,169,202,179,234,113,74,222,50,105,166,215,177,140,168,205,235,26,146,137,205,91,30,219,12,154,159,191,166,177,25,58,158,242,44,107,215,80,125,244,49,175,169,81,183,185,13,85,127,24,175,222,82,146,247,90,153,252,101,191,111,115,158,16,92,115,160,113,70,230,140,227,221,10,139,7,36,100,165,179,65,25,110,64,218,227,112,81,86,228,121,41,2,3,3,32,0,25,0,7,184,42,0,24,38,117,192,12,151,125,65,80,16,168,153,244,8,10,54,3,144,8,15,113,200,83,26,228,188,130,1,121,41,17,121,2,20,133,220,1,119,33,120,42,33,113,166,64,96,83,142,166,145,102,108,194,170,148,161,149,192,163,78,24,169,81,56,236,141,200,236,142,190,218,172,42,198,94,91,202,140,156,91,247,79,83,126,11,16,73,25,139,94,123,199,55,62,87,135,254,29,39,154,243,81,207,162,90,155,30,14,164,232,248,82,214,47,151,39,175,221,157,71,226,5,89,78,198,22,244,227,149,4,234,74,94,158,199,160,240,244,92,60,49,211,211,209,249,73,146,126,203,127,87,96,147,91,179,35,20,95,177,166,87,60,20,211,167,86,83,189,155,79,228,75,24,247,55,24,85,38,50,3,3,192,126,37,167,109,103,105,114,168,186,181,163,113,26,148,36,223,201,9,197,166,187,151,228,122,79,15,81,156,169,74,254,188,41,171,139,136,70,114,236,206,19,107,45,28,94,51,176,167,212,60,51,115,9,188,74,24,148,31,186,54,60,51,91,226,186,29,58,174,61,178,203,77,19,235,86,255,0,23,110,80,208,43,33,72,128,2,241,130,23,64,8,103,26,132,0,14,0,2,160,254,228,40,5,168,198,131,236,85,177,84,193,120,34,43,219,80,137,128,138,246,38,164,4,85,186,35,44,117,105,1,242,127,26,215,87,222,42,167,211,46,227,82,17,157,92,46,23,111,12,239,124,11,102,172,238,110,237,32,177,26,112,141,70,223,164,179,133,253,14,175,197,53,29,127,19,186,208,130,173,138,241,183,132,113,244,188,101,191,232,122,191,12,218,85,163,82,226,230,164,87,239,34,161,22,158,248,57,255,0,217,187,211,209,38,22,228,201,78,140,25,99,83,34,1,48,248,17,88,220,165,64,121,127,30,220,184,116,26,150,148,234,83,85,171,83,155,81,147,195,105,45,209,208,126,31,116,202,209,167,66,237,201,121,46,155,204,63,223,111,127,209,27,254,56,180,165,117,94,164,171,73,255,0,213,157,58,122,236,243,151,254,134,199,129,172,46,173,250,101,26,183,85,227,44,65,246,211,140,20,82,77,233,159,208,199,124,155,159,171,214,165,161,72,140,177,133,185,182,19,37,217,17,104,94,62,229,83,124,234,84,68,176,194,221,132,80,0,1,140,14,70,2,128,0,128,3,144,0,0,0,164,121,252,192,0,16,0,9,200,20,227,150,29,88,231,116,140,217,195,85,181,87,63,238,129,193,101,20,167,85,174,100,243,147,108,215,180,75,21,31,171,54,116,203,32,188,234,99,158,10,52,202,42,15,68,66,178,60,96,2,212,50,189,17,30,160,54,97,147,146,250,144,51,148,66,135,184,19,109,25,117,244,39,59,23,96,11,69,146,125,198,48,85,207,168,2,23,113,236,80,192,208,36,57,0,244,32,122,148,130,6,92,16,0,24,126,128,1,10,24,16,32,57,0,3,33,149,10,71,145,174,72,174,106,127,90,58,111,135,145,220,83,250,209,212,124,87,251,167,94,61,51,95,58,252,15,142,122,7,83,75,254,250,58,254,71,212,162,176,124,199,240,74,61,190,24,191,154,88,238,175,31,254,19,233,209,28,251,88,207,28,140,6,12,6,23,161,26,211,28,12,148,12,29,56,181,170,68,116,105,53,172,22,14,66,224,13,121,91,83,147,206,48,189,140,37,103,252,147,146,54,199,35,13,105,198,141,104,61,42,247,123,96,207,53,162,190,103,159,100,108,99,245,13,3,90,206,230,164,112,157,38,243,232,29,211,131,249,233,180,141,134,177,247,49,73,55,150,147,10,226,141,221,30,95,107,244,102,113,175,74,75,42,107,243,43,163,78,123,197,51,142,118,148,228,214,86,219,103,96,57,99,56,189,166,140,159,255,0,56,53,101,211,233,203,87,57,126,79,5,248,105,69,53,78,110,56,217,100,168,218,64,213,74,233,65,226,73,203,221,22,53,107,66,11,204,74,83,231,181,16,198,200,53,221,223,108,115,58,114,88,244,212,198,61,70,132,184,169,23,239,18,152,218,70,71,10,184,164,214,84,215,230,103,230,71,153,199,245,6,51,25,68,202,107,41,172,125,201,249,129,153,56,39,5,2,13,74,10,25,46,232,137,106,93,128,1,184,123,16,48,138,66,128,224,0,5,15,238,16,42,24,48,171,165,39,135,169,158,78,58,175,20,219,20,105,91,194,52,163,217,5,219,153,57,53,247,55,160,243,20,106,81,199,119,115,215,209,179,106,154,196,17,152,181,226,188,100,166,239,102,181,236,157,53,15,108,158,198,206,151,145,211,109,104,243,10,81,95,208,242,158,40,84,235,117,171,91,121,119,121,146,146,237,244,103,175,202,113,73,60,246,164,137,59,180,162,220,185,237,77,190,17,3,143,116,90,245,70,145,209,216,87,173,241,181,41,84,158,181,38,229,24,251,29,250,217,30,74,149,196,127,111,210,80,109,181,6,165,44,232,181,61,110,49,132,72,213,0,15,125,10,142,191,172,198,50,233,23,29,241,77,40,228,234,252,21,58,147,233,53,28,247,149,71,133,236,142,231,169,229,244,218,253,171,47,183,39,158,240,69,195,175,11,164,190,136,201,168,175,238,102,246,191,30,176,16,26,69,224,2,99,32,100,135,36,40,2,110,82,1,65,11,192,2,162,100,34,139,18,166,98,159,161,81,5,4,123,132,245,198,0,188,12,147,66,148,77,48,74,181,99,111,66,165,105,52,148,98,222,76,179,250,29,55,138,238,124,142,133,56,41,118,74,180,148,34,201,78,238,60,167,75,182,93,75,171,210,171,40,74,77,212,149,106,153,209,107,179,61,229,189,189,59,106,94,93,53,133,156,227,208,243,222,18,181,84,149,123,149,83,190,18,81,140,62,199,166,91,146,45,100,182,246,42,216,136,34,162,231,26,17,133,171,201,144,19,216,190,131,58,145,180,158,94,201,1,225,124,107,63,62,165,27,120,53,25,57,184,57,55,182,79,83,210,104,198,141,132,59,53,140,163,20,159,174,17,224,186,213,213,197,231,85,186,95,186,242,93,72,197,124,185,159,254,135,209,173,41,170,54,54,244,150,138,48,72,204,158,215,227,98,59,7,232,69,170,198,66,54,135,167,161,119,212,154,228,187,112,20,224,45,131,245,42,8,0,0,0,0,164,0,0,28,128,0,12,5,8,245,41,50,17,121,3,32,3,39,35,98,129,25,193,116,251,105,103,25,202,113,127,161,206,205,46,166,223,192,205,166,210,202,201,21,149,151,249,56,105,233,161,179,131,130,197,247,208,114,254,25,75,43,236,108,48,149,30,128,173,106,70,202,128,101,216,143,112,28,134,198,164,34,133,32,228,10,8,0,20,129,96,10,217,3,124,32,0,186,238,65,144,139,157,2,32,216,160,92,97,19,32,128,16,8,0,96,1,1,72,1,172,128,80,33,25,88,224,138,131,118,84,69,185,149,115,211,250,145,230,124,201,122,163,211,67,61,200,243,57,135,253,219,253,14,188,122,102,188,127,224,162,236,240,157,218,95,247,201,180,248,109,31,74,142,167,204,255,0,4,212,255,0,217,91,202,141,232,235,165,247,194,255,0,212,250,100,117,28,251,88,203,27,23,145,128,96,0,28,228,1,114,66,149,0,194,15,112,32,0,128,56,41,10,160,38,74,138,3,60,128,3,238,76,34,228,1,59,98,222,198,46,17,254,84,103,156,14,66,56,188,138,127,202,69,107,77,55,166,254,167,49,66,235,90,86,188,198,114,75,211,39,28,232,212,203,249,222,13,198,48,76,53,167,73,92,211,244,107,220,205,215,172,155,253,222,222,134,214,197,211,113,138,215,87,19,211,186,158,152,213,153,124,84,57,132,241,140,231,7,54,143,58,17,198,47,117,160,77,113,43,152,61,140,149,197,22,154,239,89,79,24,100,149,24,75,63,42,71,26,179,162,155,113,134,36,247,96,244,216,83,139,122,73,63,179,41,173,240,84,146,249,87,107,231,2,86,207,11,182,164,178,189,88,61,54,145,121,53,21,43,132,181,173,159,111,67,62,250,208,138,202,239,126,192,108,3,93,92,75,185,41,83,121,246,31,25,5,44,56,201,106,52,198,193,94,167,28,106,211,146,210,95,169,146,148,95,40,168,166,53,62,159,99,53,170,56,234,227,203,121,98,141,58,29,206,179,254,94,17,187,31,165,26,150,177,74,164,181,111,147,109,108,102,45,120,239,17,254,243,197,253,58,9,54,233,174,228,146,221,234,122,232,165,74,132,115,166,154,228,243,221,70,156,42,248,219,165,42,116,211,112,140,229,82,109,241,140,36,122,42,221,173,73,54,176,193,85,60,153,69,174,229,157,140,32,177,20,146,48,184,159,101,9,203,148,138,143,21,210,230,215,92,169,78,78,42,52,106,202,19,202,213,203,45,175,232,123,180,251,181,60,29,221,39,119,58,82,178,75,207,248,152,212,155,107,159,253,17,239,120,88,124,25,141,80,61,137,130,154,70,167,81,140,165,211,110,59,22,101,216,240,121,79,2,202,16,185,187,165,25,247,73,167,38,150,203,92,30,186,237,57,216,220,70,63,83,166,240,121,15,6,194,52,58,245,229,21,21,220,168,167,39,249,153,189,198,190,87,182,69,228,156,4,242,141,50,160,0,1,0,3,56,0,160,56,32,43,90,128,198,88,122,48,135,32,112,94,94,82,177,164,170,214,207,107,146,138,194,215,44,214,151,91,181,82,237,166,251,146,222,79,99,46,161,24,92,93,89,219,205,119,39,55,54,159,56,95,250,150,234,86,150,105,37,66,46,171,217,40,228,104,215,167,214,231,112,231,26,54,178,249,98,218,147,79,13,146,61,82,188,224,187,237,106,194,75,118,163,163,103,97,78,178,167,70,156,107,56,42,179,217,37,143,232,107,245,91,213,109,74,156,84,28,221,73,168,180,184,208,81,193,59,190,165,78,109,249,42,112,146,93,169,112,114,210,190,187,105,202,181,187,132,18,203,109,156,179,191,133,27,55,82,81,195,81,73,103,213,232,137,86,162,143,73,156,234,203,57,88,127,153,6,221,58,170,164,83,72,233,60,99,105,111,119,208,177,113,82,112,132,38,164,251,30,27,245,59,138,15,252,60,22,154,35,173,241,37,189,189,199,75,82,185,156,161,10,114,238,194,254,47,102,62,19,213,58,36,45,161,107,70,22,205,198,151,148,165,24,123,51,182,88,193,230,236,58,205,189,172,148,170,211,113,141,72,197,69,236,162,150,135,163,132,163,81,70,84,228,165,25,44,166,158,68,90,201,32,247,11,49,215,4,215,118,138,138,138,183,220,139,66,160,41,175,119,94,22,246,213,43,84,120,132,98,219,54,15,51,227,78,161,90,203,164,198,22,244,225,82,181,89,118,198,51,89,95,113,122,62,188,143,135,232,254,208,241,36,157,195,237,117,42,230,9,63,173,45,217,245,25,111,162,246,62,127,224,170,19,187,235,146,175,93,211,117,45,105,181,242,71,26,189,53,62,128,243,147,60,102,70,185,118,14,1,118,201,166,87,148,200,247,24,245,43,220,170,99,68,93,145,19,0,80,0,65,114,1,142,126,96,50,12,100,110,0,12,225,14,16,0,78,89,64,141,234,57,208,184,207,4,75,80,130,213,2,103,76,21,5,27,193,50,86,24,84,122,154,125,81,75,246,85,110,213,243,105,253,205,196,253,77,110,163,37,14,153,93,189,210,211,238,68,250,202,213,40,91,83,138,225,28,255,0,153,175,103,39,82,130,168,222,248,211,211,67,99,145,10,125,201,185,95,169,55,121,42,1,110,0,21,234,99,184,69,217,16,50,76,20,32,171,18,12,144,168,0,8,12,0,0,103,64,0,21,238,66,240,1,50,103,5,13,104,4,206,2,245,3,32,0,0,3,4,0,0,0,66,142,12,213,128,252,130,40,87,44,62,180,121,239,53,122,196,244,20,254,180,124,235,225,238,253,255,0,169,215,140,214,90,127,131,49,107,193,85,51,255,0,235,13,255,0,67,232,177,60,7,224,253,53,15,3,167,150,220,170,54,207,127,7,201,57,172,102,82,20,192,5,184,0,52,27,128,3,144,94,3,101,68,47,4,0,8,81,130,170,34,242,0,0,57,40,16,1,140,132,26,24,26,240,2,174,192,152,215,37,0,64,57,8,187,148,114,24,80,96,48,68,57,1,160,128,106,80,0,12,122,0,158,74,46,12,123,23,162,50,0,113,186,81,150,141,17,219,199,57,212,229,3,6,181,122,117,99,73,186,50,125,233,236,249,48,147,172,225,31,49,118,201,240,110,28,117,162,156,25,44,93,105,209,171,37,86,112,81,109,163,97,214,112,142,177,102,54,241,197,73,122,180,115,246,167,28,50,66,188,213,205,88,207,198,182,10,13,227,178,93,235,242,208,244,178,140,59,155,80,142,125,113,169,231,235,208,183,94,35,165,85,60,74,49,213,228,239,115,81,60,181,22,154,209,161,10,201,61,206,43,201,118,217,212,151,162,14,85,22,20,97,148,247,201,175,125,86,113,166,160,226,241,40,229,224,35,164,165,71,179,202,185,148,227,70,13,252,221,207,115,213,70,116,231,4,225,82,50,88,223,59,158,42,183,86,233,208,186,141,181,236,212,82,127,198,180,55,107,117,190,130,171,193,74,156,147,88,81,146,209,99,215,236,103,100,105,234,86,67,58,234,110,23,84,252,203,14,167,77,183,183,204,166,191,67,134,188,188,69,108,179,78,149,141,242,244,77,210,127,234,141,233,142,214,162,205,41,175,247,89,228,124,31,149,214,122,140,101,77,198,78,11,45,243,171,59,183,117,214,103,107,89,213,233,244,40,75,203,120,237,171,223,137,126,135,145,240,45,14,187,113,115,121,115,117,117,24,193,73,197,62,221,115,159,236,69,157,87,208,222,154,3,24,247,36,148,158,101,203,50,43,39,35,26,228,61,74,0,188,16,122,128,15,76,14,9,184,20,168,129,1,66,8,169,1,161,93,168,245,138,83,107,85,66,93,191,169,162,235,193,93,73,199,21,174,36,241,234,162,142,75,233,92,126,221,139,141,55,58,10,150,30,61,205,106,52,171,58,213,105,208,183,148,123,36,243,38,176,155,51,107,81,187,78,159,239,213,76,41,85,225,190,13,202,54,169,85,149,74,143,185,201,229,39,195,52,168,124,86,170,165,6,159,169,159,195,222,165,89,185,238,177,5,157,139,184,142,30,162,169,93,92,199,167,210,199,114,138,173,38,159,41,232,142,78,187,138,125,58,222,130,120,117,43,65,126,154,156,118,189,62,165,164,156,225,21,230,201,102,82,56,239,109,47,43,223,88,197,183,42,105,57,78,82,254,23,148,23,35,187,140,123,105,193,115,141,78,183,172,121,110,217,249,249,113,79,69,198,78,205,246,198,41,103,69,161,231,122,221,213,24,230,149,106,176,140,37,37,135,157,141,86,103,101,157,42,87,52,163,78,165,24,203,187,235,206,186,27,213,250,66,148,35,11,74,142,138,75,9,101,233,246,56,58,115,143,122,113,146,113,138,194,121,224,238,83,93,185,109,35,43,93,36,250,95,87,236,166,255,0,104,73,246,203,88,169,110,184,56,168,211,241,68,99,25,84,169,111,220,160,163,134,242,179,203,103,161,79,41,52,244,43,24,107,172,253,163,125,108,163,241,118,170,164,87,213,58,102,221,14,163,107,116,210,165,81,119,127,43,209,155,43,102,158,171,208,234,250,175,76,183,188,135,108,115,66,171,213,84,166,176,208,61,59,57,190,200,101,236,143,156,126,33,245,149,105,212,44,168,169,181,46,215,44,113,236,122,57,81,235,54,86,174,133,75,216,220,39,40,246,74,81,195,81,206,167,144,235,118,213,122,143,143,108,109,228,233,191,34,10,163,243,22,83,196,95,250,147,125,226,200,244,62,7,161,86,218,55,149,60,182,167,87,182,83,111,134,209,233,231,113,89,77,69,82,151,205,252,77,104,141,47,13,211,173,30,155,42,213,241,154,210,115,88,244,224,237,243,161,98,90,210,125,78,222,132,177,113,81,67,238,176,114,174,161,104,226,166,171,197,193,236,209,206,233,211,155,249,233,198,127,241,44,156,115,179,183,169,164,169,199,9,229,97,96,190,207,76,227,115,74,75,185,77,118,237,147,56,206,50,122,52,112,187,74,45,44,199,69,193,140,236,227,38,220,101,40,55,202,30,209,181,148,31,177,167,26,23,20,225,36,171,42,146,206,157,218,97,14,235,200,210,147,106,57,198,137,122,129,186,158,132,111,27,157,124,239,110,40,209,140,254,30,82,127,197,18,86,234,18,73,102,148,211,107,59,108,6,251,121,216,177,70,157,27,234,85,98,230,148,210,91,247,44,25,71,169,217,169,168,74,174,37,45,147,3,111,129,149,234,189,245,58,10,190,36,161,58,202,133,56,75,187,56,198,236,215,157,43,235,171,136,170,53,163,110,167,23,243,205,239,249,19,87,29,205,110,173,109,78,13,197,247,97,224,214,151,88,115,184,133,42,109,62,95,110,184,49,182,233,182,22,81,113,157,87,86,77,124,217,121,203,55,169,59,90,61,190,85,42,112,238,120,90,106,199,179,211,172,171,127,212,93,84,168,194,167,110,117,249,50,101,11,158,173,53,90,85,99,229,69,103,179,49,111,251,29,183,124,223,41,35,37,83,10,89,146,107,96,143,42,250,159,88,84,170,70,147,173,57,61,99,87,177,118,125,176,99,254,211,245,155,105,91,211,253,159,78,227,52,147,169,60,246,190,255,0,68,122,154,106,48,78,49,210,57,216,228,115,131,95,52,19,199,177,63,31,246,187,29,39,76,241,101,143,80,151,147,113,74,173,141,202,151,111,151,93,104,223,180,182,103,122,176,226,165,6,165,23,202,121,52,47,122,109,149,254,29,122,107,41,229,105,179,245,58,213,211,111,122,83,149,94,157,53,56,97,230,148,155,193,101,179,180,244,244,57,229,110,94,14,183,165,245,138,93,78,14,18,135,147,119,79,74,148,155,231,213,122,163,177,224,208,113,177,165,213,42,118,116,250,139,183,38,238,152,52,122,140,146,164,162,246,109,18,244,125,114,217,225,91,83,73,231,229,95,216,216,92,154,182,122,194,90,232,158,52,54,158,56,16,163,97,110,78,75,234,202,130,13,141,134,117,0,70,192,228,130,172,114,27,77,149,104,99,177,67,128,30,192,128,56,0,0,28,134,0,32,56,0,139,141,8,0,187,232,66,165,144,216,16,1,157,64,0,0,16,160,8,10,64,12,136,164,37,20,114,1,21,205,5,243,35,194,252,114,255,0,187,145,238,161,245,172,158,23,54,254,168,235,197,55,26,95,132,93,207,192,48,156,150,51,86,73,125,143,117,3,196,126,19,164,191,15,45,26,206,181,36,123,120,108,103,146,198,122,20,33,162,50,0,48,4,215,208,169,228,140,45,128,163,98,160,84,65,201,113,168,40,19,112,2,132,209,148,1,81,11,201,0,114,57,8,0,31,112,0,188,147,144,30,112,16,40,224,187,32,34,40,28,128,220,155,114,92,130,3,41,55,67,112,47,0,133,96,48,16,0,81,128,17,65,151,4,228,185,0,253,142,58,191,67,51,48,169,244,49,71,5,162,151,124,220,241,159,99,99,56,70,189,174,93,73,183,232,115,118,39,46,231,191,4,139,90,23,125,34,149,213,69,85,75,203,150,185,107,147,174,169,107,214,250,125,38,168,220,124,76,23,211,149,134,143,69,142,11,175,12,26,241,113,187,241,101,226,116,173,233,170,114,138,255,0,50,166,18,151,216,235,170,216,117,219,222,164,232,221,245,74,144,156,99,30,238,215,162,245,193,244,41,62,214,143,55,213,59,151,87,141,104,188,37,28,75,220,141,75,174,194,215,195,189,50,138,133,73,208,141,122,137,47,158,175,204,206,106,221,23,166,92,182,235,217,211,168,222,154,196,219,183,121,181,166,243,148,215,7,42,25,25,215,74,252,45,211,148,99,26,49,149,8,175,251,188,44,125,133,14,133,123,100,171,43,62,179,113,137,199,17,141,117,222,160,243,186,255,0,231,147,187,13,15,198,30,221,19,93,78,211,205,119,23,10,190,105,188,118,172,38,207,45,225,149,214,223,87,184,163,7,42,112,199,115,243,18,75,115,218,117,89,249,112,139,111,9,232,121,186,55,87,20,252,87,37,70,93,208,141,52,230,184,104,154,212,190,158,206,29,221,145,239,105,203,26,181,234,102,206,58,85,97,90,154,169,15,205,122,51,144,211,48,41,0,20,17,108,0,164,201,76,30,216,3,52,57,34,122,20,10,183,46,73,145,249,0,81,90,188,106,22,123,113,157,11,146,103,128,106,167,234,76,188,134,16,69,73,231,37,198,94,73,159,98,183,132,7,13,204,188,171,106,147,236,115,237,139,120,71,205,60,81,57,83,186,233,85,169,82,115,133,197,127,222,67,253,220,165,254,167,185,235,183,109,89,212,183,161,37,230,203,125,79,53,121,56,186,214,57,236,83,167,163,79,221,112,74,223,31,79,75,109,109,26,112,81,131,75,24,216,237,28,19,142,30,169,250,157,87,72,239,146,78,163,79,43,76,62,81,218,199,45,101,136,148,140,84,86,34,180,50,224,36,25,80,224,214,168,251,238,62,109,34,141,134,244,103,85,127,117,27,90,21,46,39,40,199,76,65,61,53,32,206,181,111,137,186,116,162,177,24,238,254,199,143,234,82,167,121,212,168,220,80,146,141,220,90,81,247,93,199,168,183,143,194,116,90,247,53,37,151,42,78,110,95,145,228,186,45,130,173,215,173,238,97,81,206,140,169,201,225,188,231,36,106,116,250,29,44,42,113,140,116,93,171,68,103,176,237,81,209,122,20,211,42,159,228,87,185,22,187,151,114,162,162,48,180,0,76,23,24,251,0,5,147,208,214,175,115,70,148,42,202,109,98,156,123,165,149,178,52,250,159,86,133,165,7,217,25,57,101,38,241,166,14,178,113,185,189,85,168,210,173,58,84,107,44,84,170,214,100,215,162,201,155,87,25,212,234,209,191,126,69,180,19,238,89,78,43,101,234,113,81,232,50,169,115,26,215,117,148,113,170,132,119,59,78,159,211,169,88,82,116,173,232,249,113,198,181,36,243,41,125,217,180,169,66,53,60,201,55,41,53,130,103,218,187,157,53,233,217,89,81,165,219,66,130,82,207,214,245,109,253,204,235,89,80,157,88,202,73,201,193,44,107,179,57,162,211,120,138,202,76,185,74,77,189,74,107,93,218,42,175,56,105,251,10,157,61,77,71,186,187,139,77,181,133,174,199,37,91,142,200,246,199,71,253,141,74,215,82,132,163,86,49,110,52,226,214,63,153,178,35,105,81,116,167,133,55,36,249,102,42,234,16,147,163,23,9,84,142,201,154,177,171,86,183,116,33,149,40,99,57,57,233,219,190,199,242,252,238,89,207,62,187,133,40,86,174,233,183,57,39,151,175,24,51,82,113,105,169,207,181,233,218,181,19,141,106,84,102,187,82,147,142,19,122,224,226,236,169,11,88,119,180,234,194,120,110,43,234,72,13,165,42,142,93,206,123,181,136,250,46,77,136,202,46,61,241,146,215,67,74,117,110,99,229,226,48,250,150,83,92,114,42,87,113,162,157,24,41,56,203,24,219,5,70,143,81,181,185,141,244,106,90,65,65,69,54,231,254,243,55,58,103,86,167,125,73,211,168,212,110,105,253,113,251,114,89,215,156,28,99,83,105,44,184,179,169,171,8,219,245,122,87,240,166,162,148,37,9,165,252,89,123,141,197,143,73,230,65,237,36,205,59,231,7,26,81,122,185,207,11,238,114,84,181,165,86,156,59,103,40,197,252,223,43,220,210,234,150,61,208,160,213,196,227,219,44,197,123,138,141,219,88,186,114,156,100,176,242,108,157,101,58,23,21,28,160,171,235,191,118,57,57,252,187,213,142,233,193,233,192,232,110,242,27,227,6,151,155,117,9,168,249,13,167,187,76,228,248,137,71,234,165,47,177,116,198,198,204,60,100,211,253,165,74,47,19,82,79,236,114,43,218,18,90,55,160,216,99,99,35,147,131,227,40,45,29,72,172,153,43,138,47,85,82,44,106,57,114,55,56,213,197,38,218,85,22,125,7,159,79,212,14,70,14,23,117,73,52,156,226,155,225,176,174,41,181,148,244,245,3,152,184,228,224,141,205,41,73,199,189,101,110,114,121,144,198,123,215,234,6,100,29,203,249,150,217,220,137,166,147,91,1,86,160,143,60,110,19,211,93,192,163,125,64,2,237,161,54,67,32,7,220,2,1,64,0,8,82,0,15,96,0,32,6,72,33,81,24,79,82,52,231,135,214,143,5,228,213,255,0,185,71,189,135,214,143,31,229,191,87,250,157,56,244,197,142,191,240,174,41,126,29,88,165,167,205,63,254,38,123,72,172,35,201,126,26,166,191,15,186,118,112,178,229,253,207,91,4,57,43,62,112,152,38,125,11,147,10,48,76,233,146,240,0,1,157,61,128,0,10,128,46,8,85,0,0,6,71,4,228,11,238,51,144,22,128,48,7,59,128,0,20,0,32,228,11,192,201,17,66,27,23,4,47,176,16,163,130,34,11,192,12,1,65,17,145,68,212,20,128,82,20,128,80,7,32,25,195,86,93,176,121,122,28,204,224,174,241,2,81,199,103,46,234,147,120,211,26,63,83,102,41,235,157,77,123,70,220,103,207,161,178,33,64,48,0,197,164,217,231,252,69,77,211,166,234,198,125,153,228,244,44,232,188,83,23,251,62,51,105,184,119,165,132,245,111,130,85,227,219,179,233,141,62,149,111,141,212,117,54,142,183,163,84,156,173,41,198,107,18,198,123,125,14,204,162,236,66,128,58,254,167,24,121,84,231,61,148,214,23,169,231,40,70,167,251,87,37,229,78,81,149,188,165,41,40,124,171,93,22,79,85,121,30,250,13,97,53,29,117,60,84,171,43,175,24,219,249,23,21,21,40,70,93,241,132,154,79,79,249,146,175,23,167,181,171,42,55,117,35,24,98,138,138,110,75,108,243,169,216,91,92,209,187,167,230,80,154,148,115,135,247,58,120,209,248,74,42,10,73,211,154,195,215,47,44,202,222,157,91,43,151,82,21,41,249,10,57,237,142,242,145,52,174,240,51,164,180,234,151,213,170,207,226,105,70,148,84,159,106,79,57,71,111,10,244,234,53,218,247,225,151,96,229,217,3,26,185,242,220,84,148,115,166,125,14,166,181,245,237,175,81,141,31,35,205,182,80,249,234,231,85,47,177,109,71,111,147,141,74,50,111,15,56,56,35,118,170,199,16,140,148,159,243,44,17,201,83,91,124,210,231,36,49,181,222,145,153,171,9,252,201,203,149,169,177,23,159,207,96,50,41,52,193,81,80,227,98,23,62,172,128,87,192,216,155,145,212,132,62,166,128,205,180,151,219,147,169,234,253,90,165,164,33,78,218,49,117,38,210,139,126,162,227,168,169,207,181,46,218,74,88,109,233,147,172,176,243,58,141,89,220,84,142,33,70,179,80,126,171,85,254,164,181,100,35,109,94,165,141,205,123,153,175,54,111,142,62,199,77,113,105,77,117,10,58,57,206,73,71,47,143,115,185,235,87,243,182,140,45,233,211,106,59,73,250,233,159,244,53,122,69,74,149,232,70,230,112,214,170,106,15,28,122,145,167,160,233,180,93,42,105,63,178,246,59,19,90,210,41,91,82,195,238,196,112,223,169,178,88,203,46,9,128,181,5,28,87,115,84,237,39,60,164,215,185,229,250,252,227,121,82,135,73,106,78,165,212,100,233,75,132,210,223,250,157,245,221,79,50,171,131,107,182,156,123,165,159,67,70,198,214,157,231,89,87,85,41,231,225,99,136,55,195,105,109,249,51,55,181,142,110,183,14,206,129,43,92,239,79,177,251,225,29,15,131,173,234,74,237,212,168,146,141,24,118,193,46,50,118,126,37,169,82,86,245,101,74,164,84,169,36,212,100,241,221,151,134,99,224,245,155,107,185,58,125,146,243,22,86,125,147,31,246,39,79,70,222,164,192,124,20,210,8,203,212,196,186,149,23,101,236,30,140,158,198,53,42,66,148,123,166,214,61,200,50,155,140,33,41,212,146,132,34,178,228,222,199,158,234,158,34,165,66,147,149,46,236,53,149,21,245,53,234,215,8,199,170,222,206,242,172,40,198,41,209,82,253,228,83,213,224,227,177,232,51,186,168,239,186,188,18,169,63,162,138,150,84,98,190,156,251,227,131,54,239,168,212,146,118,224,233,212,110,58,149,180,21,71,25,82,83,238,148,253,125,143,69,66,148,109,224,220,228,156,219,211,211,6,30,101,43,58,14,20,233,46,212,180,132,86,53,49,85,234,47,243,96,161,166,113,156,137,232,115,202,122,173,113,169,106,56,210,141,73,78,90,70,61,207,236,107,66,188,96,170,58,144,114,148,86,99,31,230,121,52,239,111,103,112,165,8,252,144,112,215,215,125,139,166,54,223,81,162,237,29,122,82,81,131,217,190,78,47,63,204,75,230,107,187,68,217,164,168,171,136,83,163,62,216,210,132,18,140,86,143,40,198,254,164,34,234,83,127,42,132,86,190,185,223,251,25,218,99,178,140,94,84,82,109,67,31,55,169,133,221,106,22,213,225,231,84,198,99,156,29,63,237,58,213,42,86,157,42,178,133,8,207,48,165,5,172,150,54,253,76,35,209,174,122,204,225,113,94,117,41,99,49,125,203,86,178,95,203,250,92,118,107,174,82,173,38,173,213,57,36,254,103,201,105,95,94,213,110,165,61,98,180,138,198,230,199,76,232,22,189,53,74,81,93,211,147,221,240,118,169,36,146,140,82,75,208,177,54,58,24,127,180,19,156,157,72,195,202,107,43,15,111,200,235,46,186,167,92,179,181,132,173,236,254,34,241,203,181,195,219,212,246,121,120,193,229,60,105,66,119,49,233,246,84,43,78,222,165,121,202,78,181,63,170,42,56,255,0,153,44,250,107,62,153,117,119,212,39,26,215,182,243,183,173,140,78,156,247,71,99,86,106,21,123,158,83,206,145,245,58,58,85,174,108,237,157,118,167,21,9,198,130,148,158,123,164,240,179,253,69,175,83,175,86,226,230,149,204,115,58,114,113,82,75,73,47,249,141,43,208,78,226,172,233,209,148,168,40,67,230,82,148,183,94,135,91,123,82,30,85,74,210,168,154,155,75,179,209,26,119,106,178,166,165,221,86,75,136,170,152,137,195,66,61,65,55,78,229,167,26,139,17,164,146,120,206,205,176,177,235,122,124,221,78,155,111,38,191,135,6,29,66,30,99,161,12,127,22,89,207,111,73,91,219,83,162,159,211,20,142,59,150,221,74,81,198,143,57,126,134,153,99,75,62,126,190,187,27,121,208,213,139,127,25,218,246,81,76,218,0,52,0,34,118,197,239,21,250,18,84,105,75,234,132,90,247,70,75,86,0,227,118,180,27,214,140,31,228,79,133,162,180,84,226,191,35,148,1,195,43,74,19,250,169,167,131,37,66,158,48,162,176,114,232,136,6,187,179,183,115,239,116,162,229,234,102,173,233,168,225,71,11,216,229,1,90,242,177,161,57,73,184,225,203,70,211,48,143,78,161,13,98,164,190,236,219,3,6,147,233,180,148,251,161,82,172,125,187,131,178,169,24,165,78,226,107,15,158,77,198,92,19,19,90,85,105,223,198,110,86,245,105,227,152,205,110,97,27,155,250,81,197,91,69,54,245,249,37,162,59,5,141,72,85,214,156,47,165,47,243,45,231,7,233,131,63,143,161,221,135,44,60,242,141,156,39,186,35,132,91,203,138,127,144,70,31,19,71,56,239,73,153,185,197,44,229,24,74,222,156,154,110,11,79,99,31,134,130,219,33,92,158,100,31,241,47,212,203,62,230,164,250,125,41,255,0,20,179,234,153,151,194,205,60,170,178,219,24,32,218,6,183,145,89,75,42,187,107,152,180,69,74,178,198,42,126,168,105,141,175,114,26,242,167,93,175,150,166,63,34,69,92,175,170,106,95,144,211,27,32,215,82,185,254,88,254,161,74,182,169,164,176,52,199,56,90,28,10,87,10,90,194,56,251,153,186,178,79,29,154,147,76,103,247,41,196,234,203,79,144,138,187,83,74,81,194,124,133,110,195,235,71,140,243,23,255,0,44,246,84,223,207,19,197,252,29,63,251,201,29,56,244,201,248,115,23,31,195,254,152,155,207,203,39,159,187,103,169,79,41,45,143,53,224,24,202,62,5,233,138,75,31,35,122,123,188,158,154,40,114,25,12,100,3,10,143,114,238,128,0,0,0,6,128,168,172,152,193,72,20,228,0,80,99,0,0,76,19,98,128,231,96,55,13,128,0,114,0,20,128,1,71,33,4,131,9,148,6,232,32,189,3,0,55,216,33,168,20,100,129,251,1,66,206,64,2,144,20,7,4,216,172,156,1,78,11,148,188,150,222,201,112,114,152,213,255,0,46,90,112,74,56,44,114,232,41,61,27,215,30,134,202,105,182,106,216,167,138,175,187,43,56,251,27,96,170,64,0,112,116,254,35,243,63,98,213,156,22,92,117,251,29,193,163,213,82,151,73,187,79,138,108,149,99,170,240,188,228,233,102,163,204,167,28,225,188,180,122,51,196,248,54,235,204,169,86,90,183,221,140,189,177,236,123,110,71,30,139,218,128,10,48,169,30,248,56,250,163,194,244,232,247,120,246,234,82,88,161,74,131,165,21,141,242,243,38,123,183,201,224,186,149,228,108,60,101,74,53,22,59,227,138,49,89,249,159,57,37,244,177,233,229,24,92,91,206,20,33,41,39,36,243,140,108,112,252,37,106,120,143,150,182,202,132,78,206,210,180,171,82,204,169,186,122,109,131,57,83,148,167,230,70,88,105,108,246,22,26,235,230,179,71,51,165,217,56,173,185,44,156,106,82,131,149,61,119,202,120,104,219,169,70,165,74,82,82,146,140,158,206,39,95,46,159,122,241,78,50,74,31,207,157,112,67,92,23,212,234,221,70,156,41,57,246,198,73,201,247,112,109,202,164,190,50,82,143,205,9,69,37,247,70,205,43,21,74,140,35,37,222,226,181,121,220,197,90,194,9,186,109,225,235,218,248,3,93,198,165,121,78,81,150,39,183,244,14,55,93,176,85,161,151,23,245,71,99,146,80,172,169,206,84,233,225,169,108,249,247,50,149,105,197,83,167,58,51,83,146,202,195,209,3,92,112,169,37,85,199,92,168,238,246,70,212,42,41,211,78,19,239,138,88,102,50,80,243,86,118,88,56,107,194,157,58,109,58,206,148,101,166,99,166,165,27,148,174,28,170,42,111,6,195,146,142,237,126,167,93,229,169,118,205,77,230,58,41,46,77,42,179,163,111,91,21,149,73,70,121,196,211,122,49,168,238,39,119,109,79,73,215,130,147,217,103,114,124,85,37,38,187,211,107,26,26,45,165,104,157,189,181,57,118,99,73,199,45,146,157,10,143,189,206,73,201,188,229,240,189,6,171,102,226,250,52,227,220,244,138,89,111,38,180,238,59,234,211,113,214,15,212,191,5,42,137,69,193,77,119,247,62,239,236,103,43,118,222,84,113,130,14,146,82,175,121,125,82,218,127,187,181,166,243,57,61,228,253,17,223,81,138,116,98,169,199,182,148,117,211,76,150,157,190,97,219,218,154,109,112,110,170,113,80,236,198,141,97,137,10,242,189,78,181,55,21,85,181,229,166,211,111,221,53,254,167,103,97,104,233,244,59,122,54,210,89,80,80,82,124,35,74,242,141,11,190,171,66,130,130,116,37,46,214,151,177,232,168,209,141,8,42,113,250,81,112,90,84,149,26,81,166,158,84,81,159,0,37,141,202,130,25,194,207,161,78,43,140,246,40,39,172,158,0,243,93,126,165,106,240,149,36,228,161,87,69,216,241,147,189,233,212,231,74,221,84,169,28,78,105,55,250,30,122,229,126,209,235,116,109,35,142,206,201,119,53,196,147,61,76,99,217,77,69,63,165,96,204,237,107,205,120,153,66,118,243,167,180,234,53,171,244,59,95,15,211,112,232,148,101,40,226,117,51,41,123,235,133,253,48,116,222,33,140,231,82,151,125,101,78,18,211,15,67,212,209,138,133,189,40,69,98,49,132,82,253,11,11,211,54,85,176,5,69,228,131,131,134,238,234,22,86,238,164,215,116,176,218,138,221,129,201,58,176,162,187,170,75,8,242,151,221,94,183,85,234,14,207,164,183,82,75,234,158,52,143,220,213,248,222,165,215,186,141,75,58,49,84,212,226,165,82,107,106,81,124,63,115,211,244,238,159,107,209,172,221,27,104,99,58,206,111,121,191,86,103,109,107,51,183,15,77,232,244,250,122,117,171,212,243,238,165,188,158,203,236,141,167,42,147,110,74,162,80,142,178,88,48,148,157,89,185,55,166,203,6,173,75,151,8,78,17,82,106,107,181,40,250,250,151,164,114,81,184,132,170,41,110,158,120,216,227,169,41,118,74,88,77,183,187,102,181,59,138,84,106,121,51,147,204,97,41,99,26,104,189,78,90,46,165,104,37,5,242,172,125,94,230,85,195,90,173,106,215,16,140,99,219,8,44,101,114,99,142,216,201,203,9,167,254,167,61,106,145,181,115,143,119,116,141,74,118,87,189,78,113,140,99,229,91,101,57,205,238,254,194,140,97,41,213,234,16,163,73,74,79,181,231,28,29,156,122,71,157,26,145,175,39,153,173,215,7,97,107,107,70,210,24,165,21,156,107,39,187,57,209,103,31,237,53,215,116,238,145,70,194,18,77,249,147,111,42,82,90,224,236,91,238,123,99,30,129,236,11,38,37,80,1,80,60,199,142,93,74,93,62,133,106,17,110,180,59,160,154,222,61,216,255,0,146,61,57,197,117,111,74,242,218,116,106,173,37,207,160,189,44,237,224,58,76,42,245,107,27,123,139,248,221,168,219,98,52,215,153,133,41,70,73,229,199,157,86,236,225,169,93,212,187,191,151,76,238,87,30,122,237,238,90,99,11,58,125,242,122,175,216,183,118,112,139,183,186,82,156,118,115,142,159,161,197,91,195,183,19,163,58,244,231,74,55,82,125,249,140,113,150,73,191,87,211,206,245,104,95,221,120,98,230,23,19,94,116,26,253,218,88,105,111,253,205,222,159,111,40,117,11,106,241,171,42,148,190,26,148,84,179,163,146,250,159,220,237,237,250,37,236,105,203,207,184,83,149,88,230,80,237,210,47,211,220,230,233,221,18,118,116,85,57,201,52,164,222,158,134,113,173,142,234,24,105,53,183,7,21,103,153,165,234,114,168,246,197,37,178,71,5,196,155,169,78,49,221,190,77,185,175,110,46,214,187,163,100,214,169,255,0,91,132,150,221,184,54,61,216,85,224,48,64,149,115,128,16,1,200,123,134,0,187,134,78,11,161,68,221,134,52,27,178,2,69,67,60,19,32,0,0,64,0,2,128,0,0,20,0,1,1,72,5,38,10,0,19,0,106,64,49,198,117,101,4,17,149,69,55,169,55,42,122,236,7,52,127,205,71,149,236,167,252,199,170,139,249,227,238,121,143,37,127,33,211,140,74,226,240,63,255,0,136,253,47,26,167,73,30,138,62,231,69,224,216,165,224,174,147,133,142,234,17,150,61,50,119,171,62,163,145,25,231,77,9,247,11,97,147,42,158,197,211,0,34,2,27,24,229,182,100,0,23,130,21,20,14,9,156,0,41,50,11,20,3,156,0,0,100,153,212,34,140,97,234,0,0,128,200,83,145,184,0,54,67,0,4,84,6,0,13,216,228,23,144,8,1,148,5,212,19,236,84,0,0,0,164,0,82,20,128,17,199,95,62,91,198,231,34,88,48,172,241,73,129,193,103,180,241,182,77,172,235,131,83,167,174,202,78,58,189,115,147,111,146,45,82,20,129,15,115,94,242,10,118,149,224,241,137,83,146,127,161,204,254,230,53,32,170,83,237,123,61,192,241,221,18,156,173,250,132,167,71,182,22,173,226,9,191,115,218,103,92,231,57,60,167,81,165,26,53,110,41,211,142,35,6,156,23,232,207,71,97,85,87,178,163,85,37,172,86,82,225,147,143,244,181,180,66,38,83,72,140,241,94,34,172,169,120,174,194,159,116,169,66,120,114,154,130,109,235,178,108,246,172,242,94,50,182,138,173,99,213,38,190,75,85,53,36,185,122,53,253,153,158,93,53,199,183,172,206,125,180,24,53,237,107,42,214,244,170,165,245,193,63,232,108,242,86,105,129,141,10,0,96,36,80,3,119,146,52,158,229,220,32,48,242,163,252,166,49,183,167,151,222,187,150,116,76,229,228,1,197,228,67,24,75,11,216,194,181,149,42,212,187,92,116,224,217,217,13,198,13,26,118,51,167,90,78,50,210,88,206,94,199,55,195,231,25,123,127,83,103,100,81,134,181,225,75,229,249,183,246,42,132,163,166,52,57,244,224,143,145,131,21,183,177,141,73,118,193,191,99,62,13,59,250,202,22,211,140,53,155,139,192,29,119,73,132,43,221,198,172,26,113,167,41,103,238,119,135,71,225,122,51,167,107,90,82,158,99,54,165,31,207,115,189,254,193,106,12,128,5,220,214,185,169,229,39,81,231,17,139,253,77,147,161,241,77,74,142,193,66,222,107,204,206,26,126,226,244,178,123,107,244,42,106,165,87,115,25,102,46,77,119,53,187,251,158,134,111,178,141,74,143,104,197,179,75,163,116,248,244,254,157,26,25,111,94,231,158,25,181,119,23,59,90,144,95,196,176,209,36,74,242,93,74,164,107,202,214,85,94,124,219,138,112,94,139,44,246,173,40,188,45,146,193,228,46,168,167,87,166,219,77,44,187,202,78,41,123,60,255,0,161,235,229,245,50,113,213,168,70,245,9,152,213,156,104,211,117,42,60,69,26,101,141,205,197,43,91,121,85,171,52,146,219,220,243,85,101,117,215,110,93,58,82,149,58,107,122,159,202,189,140,110,175,231,214,186,187,176,182,131,106,11,46,79,104,175,83,208,91,208,165,99,109,26,52,191,241,75,213,153,221,111,164,182,183,182,233,86,190,93,188,49,157,100,249,155,245,108,197,247,206,57,155,249,94,166,79,58,79,73,56,240,205,9,222,198,173,204,28,158,35,41,118,53,157,145,122,136,87,175,154,244,45,147,249,37,39,156,104,113,203,191,226,188,149,40,198,138,77,44,127,169,161,25,184,212,156,164,156,229,230,124,139,60,27,177,138,149,71,242,60,99,127,83,42,206,222,221,220,220,77,202,89,162,162,226,209,143,83,234,49,165,14,202,41,37,149,22,215,169,193,113,115,73,212,157,10,121,133,56,47,153,242,217,179,210,58,100,231,47,140,187,135,106,146,204,41,53,253,89,168,142,46,159,211,174,239,46,93,197,236,187,105,103,49,135,243,127,232,122,36,148,82,140,86,23,162,1,21,14,125,140,136,0,184,4,0,80,0,64,114,2,1,249,13,199,32,1,10,0,152,53,174,114,174,45,218,90,44,231,28,27,77,228,214,174,219,184,141,62,28,65,25,84,73,202,44,230,224,213,169,220,171,209,130,219,147,104,40,82,20,33,192,4,2,132,0,0,0,2,114,82,1,72,82,1,70,196,0,80,78,0,23,57,25,208,128,10,9,192,2,128,2,132,41,0,36,0,0,66,147,36,0,136,50,5,65,110,16,64,115,65,98,162,58,30,222,161,255,0,119,76,239,227,172,209,213,249,111,249,205,198,107,175,240,172,29,63,7,116,136,231,107,104,231,244,59,149,182,231,83,225,196,151,133,250,92,87,22,240,254,199,108,150,132,228,172,183,27,17,48,222,134,85,90,201,18,11,82,128,192,3,60,128,228,185,4,40,2,160,202,32,0,9,187,40,0,65,160,40,14,8,86,0,153,25,41,30,192,94,67,221,5,160,0,63,34,144,10,182,25,201,25,86,64,4,7,33,23,146,20,103,77,64,114,6,193,1,80,3,32,1,25,120,0,6,6,0,24,87,207,149,47,177,158,53,56,238,36,213,25,99,124,104,7,13,147,78,50,195,209,104,109,154,125,57,175,42,166,22,62,99,108,133,10,68,82,140,100,78,12,217,197,252,88,32,243,221,110,51,133,210,105,226,18,142,106,73,112,141,191,15,84,138,179,157,24,207,187,178,89,90,240,97,226,56,255,0,133,140,211,147,113,122,165,201,161,225,186,244,35,117,81,211,249,188,199,140,189,48,103,235,87,167,171,69,38,207,212,45,77,32,209,213,120,138,222,23,61,14,188,103,164,99,243,237,232,118,199,21,197,56,212,161,56,75,14,50,88,105,131,167,85,225,203,165,115,210,148,100,215,125,39,219,131,185,71,147,169,40,244,110,183,69,66,77,90,215,154,239,194,218,91,35,213,172,167,185,33,201,144,0,168,23,146,20,0,0,0,25,30,192,93,2,223,216,103,64,138,27,178,235,146,108,139,198,8,46,200,199,114,165,128,180,251,20,97,85,246,210,111,212,243,221,74,173,101,42,110,156,187,97,41,246,73,253,206,250,187,204,113,237,201,230,45,234,84,190,235,82,161,61,40,208,125,223,241,75,131,53,168,244,150,118,240,182,183,141,58,105,40,165,133,246,57,196,95,202,144,111,37,64,114,56,56,174,46,35,111,5,41,38,251,154,72,14,89,212,140,34,231,45,35,21,147,162,148,62,58,174,106,71,49,114,206,231,99,212,106,40,249,84,154,207,123,213,28,93,54,146,85,231,81,107,24,172,47,185,47,177,190,163,136,165,131,94,234,82,84,177,23,243,61,53,54,183,122,154,149,211,119,52,209,106,71,67,119,56,209,241,47,76,167,220,187,243,221,135,190,54,109,126,167,169,155,249,153,229,250,229,188,127,218,14,159,120,245,116,218,130,199,187,61,60,245,147,36,106,162,120,77,189,18,60,159,93,234,245,174,122,140,108,108,227,220,220,113,29,116,238,111,31,219,36,241,239,136,37,210,122,100,237,237,170,98,226,166,18,73,101,235,253,141,79,7,218,84,173,82,149,213,117,41,213,133,4,220,222,201,182,78,87,228,89,234,107,208,244,78,151,251,38,206,78,172,252,219,170,186,213,169,143,232,109,202,80,140,101,82,77,165,247,37,213,105,170,51,84,210,206,53,108,235,111,239,243,23,105,66,146,147,132,51,38,222,141,250,14,162,109,174,74,215,117,37,231,70,146,89,133,62,236,122,179,78,133,57,58,125,245,99,229,202,95,51,77,25,210,186,117,97,82,138,160,225,87,179,185,212,114,209,122,36,88,201,207,90,179,147,140,87,174,173,145,89,194,157,42,48,117,41,98,80,111,181,55,169,163,125,212,252,184,78,149,28,170,152,194,236,142,114,204,175,174,107,81,177,183,163,74,45,39,81,185,70,59,251,35,182,233,189,50,170,113,186,189,73,85,122,198,146,218,62,239,213,149,53,175,208,186,76,233,70,87,87,171,53,106,73,73,66,95,194,119,219,177,200,70,145,121,4,42,10,1,200,192,64,164,40,64,0,0,164,0,0,192,0,0,224,8,107,86,105,93,83,251,104,108,179,94,225,126,242,57,75,41,104,193,24,205,102,241,75,140,37,131,103,156,154,235,75,149,15,108,228,218,224,45,0,32,69,1,0,0,0,3,144,0,14,8,138,4,0,1,72,56,0,10,136,16,0,82,0,41,10,4,41,3,10,33,200,75,82,129,24,41,0,19,3,58,2,3,216,133,201,0,36,101,19,20,103,29,196,28,177,250,209,214,121,175,249,14,206,63,90,56,60,152,255,0,50,253,13,179,93,95,69,135,149,225,238,155,4,177,139,104,127,99,126,6,151,74,77,116,78,156,179,156,91,83,203,255,0,194,141,229,182,71,37,87,163,31,113,140,160,180,48,177,65,81,48,0,172,133,42,39,1,14,74,153,21,72,198,184,38,77,6,0,0,7,32,1,30,197,1,3,65,200,0,209,188,48,182,35,220,160,0,201,24,23,128,144,91,21,108,1,16,20,32,77,202,64,171,182,160,128,34,167,174,10,76,0,40,25,212,54,5,25,9,152,176,42,101,38,204,1,78,11,182,227,71,229,223,40,230,56,174,86,104,73,45,200,56,172,82,140,100,151,169,182,106,217,67,178,51,138,225,227,94,77,156,2,170,102,73,152,224,187,148,55,56,170,172,51,148,198,122,161,71,91,213,20,93,174,169,61,115,169,229,58,77,194,125,90,84,41,75,51,131,239,159,177,237,106,209,141,122,82,132,190,150,181,62,113,97,86,86,222,45,186,181,140,18,171,57,118,199,78,61,76,125,106,123,125,54,47,187,15,212,201,51,78,194,230,53,109,35,14,236,202,159,202,253,205,181,234,105,42,189,204,43,67,190,147,89,193,152,107,43,1,30,79,197,20,28,236,41,84,167,158,232,84,78,88,221,164,122,43,11,152,221,90,82,173,23,156,193,103,239,131,70,250,147,154,171,73,44,207,181,254,71,15,134,235,199,201,157,178,105,249,105,101,173,155,228,204,245,90,189,59,237,202,69,177,77,50,49,192,0,160,3,144,40,90,48,0,23,220,132,115,140,90,78,75,47,128,50,122,151,44,196,200,161,145,157,117,15,4,220,13,14,173,91,225,236,231,81,202,49,75,25,109,224,235,58,13,5,58,213,110,91,77,212,122,99,208,229,241,85,194,167,98,168,58,113,169,222,212,166,164,246,75,211,220,219,233,86,208,182,165,24,211,77,44,114,103,234,252,118,32,172,137,114,84,55,208,233,122,140,227,125,125,27,117,60,121,18,78,41,61,229,206,78,238,59,231,211,83,207,124,144,149,91,149,75,19,156,229,170,221,147,146,198,119,21,37,94,243,19,203,204,179,47,183,177,220,209,166,169,210,140,98,176,176,117,157,30,218,93,146,171,85,185,46,247,217,157,210,59,113,10,141,232,205,55,243,207,205,121,238,143,6,197,197,85,66,222,165,71,196,116,52,97,85,202,148,99,36,163,41,201,38,211,245,45,35,207,245,108,194,84,95,124,165,41,92,193,229,241,243,35,216,92,212,141,10,53,43,207,233,132,92,158,14,134,189,41,62,164,237,234,199,49,114,94,91,107,126,77,79,30,117,85,99,208,174,105,194,183,101,87,69,246,164,245,109,233,254,166,103,173,94,223,60,189,184,143,139,188,108,169,212,175,217,109,108,149,90,233,127,22,191,44,15,172,244,218,46,199,167,70,19,73,85,168,220,164,151,11,132,120,63,195,62,129,65,216,214,234,87,84,155,169,82,74,82,114,229,175,167,250,97,158,234,242,233,208,125,243,73,203,77,31,161,39,246,84,173,93,58,117,34,242,187,176,155,71,87,113,57,66,141,106,180,35,154,205,40,164,189,50,114,85,119,53,227,119,42,88,166,151,107,139,123,106,116,247,49,157,91,232,91,209,171,60,74,154,127,44,177,153,103,102,197,36,118,176,116,104,78,227,205,171,46,203,74,113,169,60,44,239,156,35,138,149,213,10,146,161,223,81,69,74,57,236,123,200,228,189,84,186,127,79,186,131,125,213,107,74,10,182,30,91,126,134,231,73,232,212,220,232,222,215,162,148,161,79,182,156,101,171,75,212,101,209,179,210,186,122,249,110,238,41,165,91,85,20,248,92,63,190,14,221,109,174,227,57,212,155,155,69,228,13,0,0,10,4,228,160,156,129,64,0,50,0,0,24,12,0,0,0,123,1,140,175,176,68,208,225,172,215,159,5,234,176,115,28,55,41,118,198,92,169,32,71,27,95,226,210,91,37,156,155,70,180,23,248,169,75,57,82,54,66,208,14,0,69,33,72,5,32,40,2,1,144,0,0,3,35,242,0,6,224,0,28,0,1,104,131,208,0,0,0,104,9,190,165,192,80,48,64,5,32,206,128,209,142,9,207,184,32,106,52,0,1,156,117,123,24,35,56,103,36,131,154,63,82,53,116,54,99,245,163,79,225,165,252,204,220,102,180,250,107,95,178,58,126,22,19,183,134,159,145,186,141,43,21,219,211,173,34,182,84,98,179,249,27,105,142,77,51,43,58,251,201,222,193,119,219,201,99,249,94,231,91,86,250,254,210,143,157,37,220,226,179,36,99,76,122,46,1,230,233,117,203,234,144,88,182,154,111,125,54,57,23,89,189,156,163,78,52,148,95,50,148,71,229,23,30,135,82,29,39,237,59,181,40,46,232,231,58,232,63,106,245,41,206,74,149,4,224,158,51,130,203,12,119,96,234,149,223,80,84,243,229,39,60,233,18,252,71,82,147,121,167,8,174,48,53,49,218,110,93,142,153,93,117,121,57,124,148,147,225,36,115,211,184,234,10,17,85,40,194,83,229,199,97,166,59,29,199,216,235,190,50,251,204,195,180,138,143,170,150,89,103,115,119,132,161,71,230,111,86,246,69,209,216,19,115,175,149,213,212,37,217,26,74,89,229,236,98,239,238,92,156,85,180,176,183,99,76,118,69,58,213,125,93,182,149,36,176,179,169,151,199,212,116,251,188,181,140,13,49,216,14,118,58,186,93,89,85,88,133,57,55,156,108,115,78,246,165,60,69,209,147,151,178,27,12,111,52,77,205,5,212,228,224,243,111,38,253,11,78,254,74,159,125,90,78,158,186,103,81,166,55,222,222,228,224,211,167,212,105,77,54,148,218,92,246,180,103,27,200,73,54,147,211,216,24,218,90,12,154,223,27,79,209,228,198,125,66,132,36,162,223,204,248,64,198,216,53,165,121,77,99,92,103,93,78,53,212,232,63,226,69,49,189,144,107,124,101,61,50,240,100,174,233,53,148,211,136,49,207,144,112,70,230,148,166,226,165,175,161,200,170,71,249,150,88,49,200,253,9,146,41,69,236,209,120,8,161,172,128,152,19,146,135,160,1,145,144,78,72,42,71,21,195,253,211,193,202,142,27,151,251,188,108,128,198,209,247,70,79,6,201,175,107,165,60,238,108,136,0,125,129,64,141,101,20,1,193,44,167,166,199,131,235,246,117,186,119,137,35,212,161,37,24,52,245,103,191,154,60,143,141,236,163,86,222,133,226,115,239,167,37,20,147,211,238,204,253,107,143,109,254,145,26,118,244,35,70,148,37,172,124,201,205,255,0,19,103,125,7,152,163,206,216,220,202,86,148,170,119,39,9,197,101,250,253,142,250,147,204,86,53,194,41,92,201,149,61,76,81,74,203,74,243,48,148,165,31,170,95,216,234,109,212,108,58,197,8,91,193,42,51,78,82,75,213,157,237,221,63,54,214,75,213,52,121,62,174,234,80,179,181,169,69,191,54,140,210,159,216,205,184,215,23,179,206,74,107,88,214,117,236,233,213,105,252,209,206,166,193,119,89,80,0,20,17,128,41,57,41,0,100,209,187,156,124,250,29,201,182,164,241,143,177,187,39,219,22,249,72,233,105,85,119,93,70,49,239,239,204,178,146,217,34,85,142,234,156,156,161,25,62,86,78,68,96,151,110,18,224,207,56,42,30,164,122,107,192,71,29,205,69,78,222,164,189,22,128,121,94,179,15,140,235,180,90,146,78,47,247,145,127,202,159,7,167,182,81,84,179,22,245,122,30,54,202,53,175,250,220,101,58,189,243,130,125,203,136,163,219,66,42,52,227,20,180,138,36,106,244,203,57,25,211,0,190,197,101,193,119,81,210,180,171,40,253,93,186,125,206,138,75,205,182,133,25,74,90,239,37,185,217,245,55,222,225,69,237,187,94,166,157,26,107,190,77,232,150,200,149,168,237,44,168,198,222,210,20,160,219,138,229,189,205,140,152,194,56,167,28,250,20,35,79,170,79,203,177,158,142,77,236,146,203,102,181,12,86,157,154,122,56,201,77,175,83,31,16,185,66,133,10,144,159,111,151,39,41,125,177,131,62,157,86,87,17,165,83,179,182,49,134,51,234,194,252,97,113,57,46,171,151,28,246,54,214,15,152,248,158,82,235,62,56,165,101,94,16,249,220,105,168,194,163,111,181,242,255,0,67,233,61,111,204,131,135,150,241,222,165,223,63,79,67,198,244,206,135,100,250,242,190,163,22,235,80,90,206,77,182,229,202,34,202,246,61,58,132,58,87,72,167,69,181,39,220,229,44,122,112,191,68,142,187,168,221,74,180,169,210,84,220,171,86,168,147,199,9,107,253,141,250,141,215,147,164,254,89,40,38,254,254,134,188,27,182,171,231,79,230,169,53,162,95,194,42,58,169,83,175,116,167,211,231,94,84,222,83,156,151,6,119,114,165,109,113,70,52,48,163,9,168,185,114,240,183,102,221,197,221,25,46,222,231,42,233,234,146,198,14,126,157,211,163,125,87,204,173,75,20,161,44,235,252,108,102,43,151,164,216,43,153,74,246,238,57,141,73,119,66,18,254,231,160,211,56,38,23,162,211,100,94,114,86,77,17,150,9,133,249,149,21,81,148,20,32,57,0,32,8,80,0,0,0,110,54,96,56,0,0,34,40,0,19,192,0,14,58,241,114,163,44,110,102,222,17,139,146,198,160,107,219,55,42,205,231,68,176,109,51,82,197,40,249,190,242,111,83,108,69,0,40,74,128,0,41,0,0,0,2,144,0,0,0,12,0,0,0,128,100,2,114,0,99,68,0,4,86,17,55,220,40,3,39,32,0,224,60,178,32,194,38,71,168,88,21,96,139,82,144,94,76,161,186,49,206,134,81,64,114,175,173,20,145,127,58,56,123,101,234,205,198,107,70,213,56,244,251,88,61,227,74,43,250,28,233,60,103,131,130,147,253,197,31,79,46,63,216,216,130,28,154,139,132,244,100,80,89,203,254,198,105,20,200,226,242,227,174,154,151,202,131,88,237,70,122,100,44,228,14,21,107,71,57,242,227,159,92,28,145,132,96,176,150,17,144,220,8,227,202,220,157,190,198,64,8,162,163,249,143,200,171,96,84,76,47,68,26,91,20,114,65,131,75,59,23,177,122,96,169,96,165,193,135,100,86,112,145,138,167,31,68,114,129,131,142,52,161,5,242,193,47,178,50,237,94,134,67,130,171,21,78,41,125,40,74,17,147,203,70,64,26,193,211,139,88,198,7,149,23,163,89,70,97,3,92,74,141,53,162,130,42,161,8,183,37,21,151,236,114,2,98,56,165,111,78,111,50,138,127,145,197,240,84,28,242,233,172,173,189,141,160,146,195,42,181,254,6,140,165,151,29,80,248,74,107,109,13,142,52,4,26,170,221,167,242,165,250,24,70,214,163,158,91,216,221,42,251,12,53,169,43,122,154,118,205,166,140,93,189,195,139,253,246,188,104,110,176,13,105,42,21,163,140,85,109,243,238,114,246,86,107,89,99,236,108,24,205,168,193,202,79,9,12,26,113,163,123,9,55,26,170,107,133,35,26,189,82,157,180,149,26,207,53,154,249,82,216,146,186,169,114,220,40,66,93,171,78,239,83,78,173,8,220,245,26,116,156,114,233,97,202,94,172,139,29,212,36,229,8,202,75,13,173,140,248,49,231,24,216,165,198,89,35,134,225,175,47,28,182,114,154,215,73,57,194,92,196,14,91,116,148,26,94,167,50,56,104,54,224,219,228,230,216,162,160,66,128,0,1,139,90,29,103,90,181,248,190,147,90,139,194,238,91,227,44,237,78,11,154,126,101,39,18,88,75,143,39,210,177,11,73,91,247,57,249,51,113,75,24,193,232,58,91,237,182,80,148,148,231,201,231,168,102,219,196,21,41,44,42,115,134,171,223,212,238,45,106,198,131,110,60,251,14,45,114,119,5,49,139,110,17,211,26,25,21,146,75,49,194,221,163,205,94,74,48,175,82,206,16,83,151,103,124,228,248,124,30,149,110,116,221,70,154,165,94,74,17,81,149,85,164,253,76,242,246,177,60,51,113,82,125,61,66,173,79,49,255,0,11,244,94,135,116,121,63,13,65,219,94,85,181,110,93,240,120,146,123,35,214,38,39,69,237,144,68,67,130,162,148,136,161,66,12,240,97,86,180,40,82,149,74,146,196,98,178,194,52,122,165,242,183,163,219,26,137,84,147,81,75,150,99,210,45,35,70,165,75,133,159,153,99,83,168,138,171,123,118,229,40,166,231,44,195,77,145,234,41,193,83,167,24,45,18,68,141,95,81,158,74,64,86,87,216,233,252,71,118,173,122,85,211,110,42,74,139,112,207,50,225,35,184,95,109,22,231,136,241,101,212,122,148,109,41,167,133,43,148,224,211,219,181,173,127,81,122,88,218,240,197,130,176,163,26,21,19,119,10,78,83,159,175,118,167,172,219,78,14,167,166,37,90,238,173,198,91,88,81,75,237,201,218,147,143,69,82,103,26,148,224,189,173,27,123,42,181,100,241,132,90,142,178,238,83,117,21,87,47,227,113,252,176,114,217,82,149,88,185,54,176,158,158,231,91,116,231,43,118,212,219,169,217,221,161,222,217,82,242,237,169,175,247,83,51,21,178,182,27,176,70,240,105,29,87,87,167,26,149,100,164,147,139,138,139,89,221,100,189,30,164,123,37,73,44,36,222,35,236,142,46,173,56,247,202,120,109,40,109,156,106,94,131,25,165,42,181,96,211,113,74,57,121,193,159,173,124,107,248,162,149,73,217,247,210,209,65,174,247,158,13,111,13,209,163,81,202,109,247,37,202,245,59,78,189,216,250,109,69,82,42,84,255,0,138,62,168,209,232,212,97,97,210,51,74,29,170,123,103,116,189,196,236,248,217,174,212,110,234,78,56,246,53,43,86,84,84,31,213,57,44,191,185,106,212,138,132,167,230,37,24,253,77,158,113,245,122,157,74,188,221,133,47,54,48,159,99,109,227,62,173,11,113,113,218,91,91,212,185,169,40,70,56,243,37,153,77,238,143,93,74,156,104,210,141,40,237,21,131,75,165,216,171,91,88,201,167,222,253,94,77,252,235,128,149,71,4,212,165,69,91,228,200,197,106,100,80,27,130,128,0,0,0,4,77,74,177,200,123,128,162,25,30,128,32,26,192,39,171,2,130,24,85,239,236,249,49,144,172,219,74,57,220,199,205,132,99,153,73,35,172,146,159,153,221,26,207,229,223,15,70,105,213,161,212,106,181,228,190,245,174,84,158,62,196,181,113,221,206,165,58,159,47,118,133,209,69,98,73,224,233,85,151,81,81,134,90,82,79,46,49,121,79,243,43,182,234,158,90,81,75,186,79,25,111,98,105,145,219,80,73,78,95,50,195,224,231,109,122,163,166,248,11,186,89,106,167,115,147,212,148,173,175,233,229,74,77,164,218,90,238,134,142,237,232,179,144,181,228,233,101,101,115,230,247,57,79,108,110,97,90,222,249,41,249,85,228,179,29,23,191,220,186,142,247,15,244,24,201,231,149,189,244,97,25,74,180,229,133,135,31,87,234,51,212,149,63,221,205,167,221,134,229,232,52,199,161,195,192,60,245,11,142,161,10,243,141,74,210,157,56,173,12,159,88,188,138,109,83,82,105,227,18,223,3,76,119,197,201,211,190,173,81,65,202,73,83,140,117,203,91,150,29,101,84,166,167,20,177,157,91,27,19,29,187,33,215,46,175,73,201,164,156,190,198,20,250,229,9,220,186,78,18,88,89,76,106,227,181,67,147,175,93,102,205,54,164,220,90,223,39,37,30,169,101,94,167,101,42,234,82,229,34,233,141,178,156,113,173,74,111,74,145,253,75,230,83,111,10,105,132,102,246,34,35,169,28,227,40,107,144,40,200,111,67,28,233,144,50,96,153,38,117,3,36,83,28,134,192,54,8,76,229,144,101,146,2,48,170,56,251,140,232,51,132,0,17,63,212,185,212,138,166,113,126,135,31,38,113,3,149,63,222,35,155,181,122,28,49,195,154,57,60,197,232,116,224,197,117,80,142,45,232,250,246,47,236,115,64,195,136,199,59,69,28,145,51,107,76,179,159,97,177,90,33,1,106,0,2,144,96,106,144,64,19,82,128,200,0,160,7,0,160,24,228,0,100,89,99,47,1,108,5,25,4,198,64,160,133,228,0,8,0,12,13,216,85,32,216,0,192,0,1,73,146,132,8,217,141,90,144,165,7,58,147,81,138,89,109,179,173,159,81,119,111,182,210,50,237,255,0,188,198,255,0,98,106,200,222,175,119,70,217,168,205,230,79,104,173,206,189,211,187,234,149,187,170,57,81,183,79,72,174,77,139,110,155,78,159,239,106,183,58,175,86,229,169,191,253,0,226,80,167,107,109,46,223,150,49,70,143,73,143,153,9,214,151,213,41,54,109,223,83,117,173,101,4,241,238,97,99,20,168,172,60,160,54,128,5,70,72,211,187,150,37,28,181,134,109,26,183,177,238,237,211,85,179,37,35,146,213,191,39,243,54,22,167,13,182,21,5,218,115,136,40,68,69,40,0,0,24,201,105,185,144,96,120,175,16,219,58,10,87,80,146,83,139,207,119,177,207,211,234,171,154,20,107,188,168,70,61,237,103,115,176,235,244,84,172,230,220,20,240,179,134,179,147,164,232,189,82,21,165,78,148,227,229,181,46,199,12,99,66,72,211,216,81,154,148,22,186,227,39,50,216,211,140,177,60,99,11,208,220,90,149,145,163,71,170,209,243,168,194,92,193,229,27,216,121,56,110,99,221,111,61,120,37,88,243,22,181,103,79,196,174,172,100,149,58,171,15,60,224,245,139,28,108,124,246,238,191,195,117,140,84,146,74,148,227,37,246,62,129,74,93,212,97,47,230,138,100,158,214,185,17,120,49,69,69,101,67,99,36,96,51,132,219,58,94,171,119,230,41,209,194,116,187,114,230,159,244,55,47,239,35,66,147,139,154,139,151,202,159,187,58,234,118,113,184,173,26,112,203,132,113,156,242,22,54,186,29,20,232,74,229,175,171,72,254,94,135,108,72,69,83,130,132,82,81,138,194,94,133,219,80,154,165,224,131,128,56,174,234,58,54,53,103,22,148,148,116,201,224,103,74,21,234,66,218,188,101,138,47,185,184,188,61,94,116,61,151,85,150,105,42,111,56,250,159,161,209,217,119,212,187,156,28,179,78,88,106,47,114,89,173,113,122,46,159,78,48,180,139,130,249,94,207,212,218,49,167,21,78,148,96,184,50,44,102,135,89,213,42,82,169,155,105,75,45,199,61,167,100,222,34,217,230,250,205,95,132,133,123,138,116,251,170,246,105,203,108,150,172,113,90,212,117,103,242,175,150,45,211,150,120,193,233,104,101,211,94,137,29,21,148,157,74,42,83,166,163,57,252,210,88,228,239,104,44,82,66,14,92,17,250,149,179,25,60,65,178,142,131,172,194,85,96,220,103,24,56,190,229,147,159,164,78,172,227,10,147,199,151,40,247,101,62,78,175,175,84,159,122,148,83,196,115,150,141,254,140,210,176,165,217,164,22,217,228,202,252,108,245,168,194,118,106,83,255,0,45,105,45,117,215,208,233,104,213,148,37,82,154,114,116,147,78,9,240,142,227,170,188,219,71,78,229,23,220,209,229,46,122,181,43,26,245,103,90,105,82,146,205,52,248,247,11,35,103,171,95,170,18,181,165,24,198,93,213,123,166,159,177,124,49,209,95,98,186,194,141,42,149,39,81,44,112,228,244,58,43,91,74,93,99,171,90,87,157,74,147,165,151,41,189,179,141,145,244,187,106,84,232,219,194,149,24,246,211,138,196,81,156,213,190,156,209,93,176,81,91,45,140,136,150,17,77,176,169,132,68,80,47,185,117,28,2,170,132,0,100,0,0,4,40,80,131,32,10,66,128,25,33,133,106,144,165,22,230,246,52,229,127,41,84,237,132,27,143,110,116,90,177,164,141,185,215,133,52,242,214,156,26,170,181,197,203,93,137,42,82,77,63,97,70,218,85,38,234,86,217,237,23,193,187,24,40,37,24,172,36,65,193,74,214,17,130,77,101,163,154,49,81,217,25,2,160,0,0,9,246,42,1,185,26,79,130,242,192,19,181,14,196,248,42,1,92,63,13,77,41,39,20,212,183,49,118,180,90,194,130,54,8,76,70,187,178,160,214,60,180,215,163,49,248,10,18,138,139,142,23,162,54,201,129,139,174,189,116,184,65,203,18,249,95,7,21,126,150,167,77,194,47,11,213,110,118,158,192,97,174,166,93,54,140,251,124,202,91,123,154,82,232,150,208,148,231,15,50,147,107,71,13,207,69,140,177,218,182,192,195,94,105,216,54,163,217,90,178,75,150,247,49,86,55,49,74,48,190,148,121,111,58,158,159,203,79,248,81,198,237,233,247,55,218,181,38,26,243,53,46,47,104,193,202,165,94,239,45,105,21,252,70,212,111,174,151,182,153,198,119,208,238,39,101,111,56,164,233,166,243,187,56,42,116,170,51,171,223,243,38,150,139,33,125,58,245,213,43,66,167,107,203,139,250,113,254,166,204,58,140,220,90,105,36,183,121,51,125,59,18,106,45,126,104,215,151,75,170,226,212,171,37,238,144,61,50,135,83,159,152,210,217,122,156,243,190,196,147,114,73,53,186,53,223,78,173,5,243,56,201,251,104,96,172,234,103,230,134,207,40,104,236,225,112,156,20,149,72,231,59,51,47,54,164,158,157,191,108,157,37,123,43,138,189,170,21,101,77,231,76,45,63,51,134,165,27,202,57,125,245,92,158,19,105,108,52,199,125,82,230,106,158,113,218,253,25,35,118,177,44,60,180,143,55,94,173,236,97,15,50,172,252,201,52,150,86,127,177,101,112,233,37,86,162,154,73,227,41,63,234,52,199,164,133,210,107,39,44,107,41,182,121,200,245,44,234,218,84,227,29,82,220,229,93,82,14,47,230,204,86,205,61,70,195,30,138,44,114,121,239,219,80,202,80,156,162,147,250,222,204,223,165,214,237,106,75,61,235,183,103,141,117,26,99,179,192,206,55,52,35,214,44,167,39,21,86,42,73,103,18,120,102,112,234,182,21,32,223,197,83,247,89,216,24,221,206,134,81,122,154,74,246,21,33,223,78,74,80,254,108,155,84,235,70,166,169,175,200,13,152,253,81,57,123,87,161,198,151,206,153,204,116,227,24,174,178,73,38,159,178,50,142,198,51,210,75,215,11,38,113,216,205,105,67,0,1,112,52,35,97,21,190,1,19,28,128,24,46,228,32,63,176,0,208,20,133,224,8,9,174,75,200,6,22,193,178,106,1,110,80,64,15,87,128,180,40,0,9,168,224,10,1,50,13,82,34,145,188,5,208,166,21,37,229,211,239,111,68,117,87,125,122,149,42,83,116,147,156,147,198,99,170,68,209,219,85,171,74,222,155,157,89,168,164,117,117,58,196,170,201,171,92,99,56,121,70,140,108,174,58,173,88,202,180,234,40,105,44,189,14,238,133,149,27,117,242,193,57,114,196,186,190,163,71,224,167,123,137,93,54,210,127,79,7,103,74,148,41,69,40,69,36,140,192,77,94,73,41,40,172,201,225,28,85,110,33,69,60,188,203,126,212,107,82,167,113,119,37,86,180,148,33,157,33,142,1,133,229,199,125,25,70,57,237,122,105,185,179,111,14,202,81,142,216,70,78,148,94,142,41,165,236,103,140,1,74,68,82,161,157,13,107,164,219,138,70,206,13,107,132,229,132,74,177,201,110,191,116,142,115,134,143,249,105,28,194,32,128,41,64,16,160,0,0,105,223,74,138,163,40,213,113,213,101,231,208,249,109,133,197,189,47,23,37,74,78,95,51,154,195,217,31,64,241,93,104,90,244,199,113,55,132,190,95,190,79,149,116,219,187,127,218,107,168,81,151,253,97,186,113,148,244,209,111,143,232,102,251,173,241,233,246,12,185,74,53,123,92,98,215,38,237,57,169,195,42,73,240,240,116,125,26,241,117,30,145,10,171,46,80,147,132,181,244,59,107,89,195,14,25,74,91,227,212,177,154,217,224,141,119,38,176,84,200,150,185,228,15,15,226,235,24,57,83,157,42,120,155,109,74,124,30,179,164,213,85,186,61,165,69,37,44,211,73,181,234,116,254,49,233,242,234,61,38,165,4,220,31,110,99,40,188,60,156,158,14,185,133,126,139,10,48,209,82,73,99,36,139,122,122,21,169,150,48,96,140,178,84,62,199,21,122,222,85,63,87,193,200,254,150,253,14,174,242,188,187,23,150,148,167,150,148,94,192,105,93,197,222,93,40,195,50,123,169,113,148,119,118,54,234,222,217,102,89,169,45,100,205,94,157,105,40,175,62,180,84,103,37,244,174,14,201,108,18,132,126,129,190,212,69,174,160,102,1,51,128,58,190,181,56,198,219,87,142,231,218,117,189,14,156,110,43,85,173,42,125,173,75,181,47,100,114,245,233,66,164,161,25,255,0,148,165,243,240,109,116,59,104,211,85,106,39,242,77,247,34,53,58,118,197,68,42,52,203,94,246,175,149,66,93,187,157,37,212,83,141,38,231,47,49,71,248,119,59,43,201,70,191,153,66,57,238,73,75,39,73,104,149,223,82,147,115,202,140,146,105,25,171,142,226,202,147,84,98,230,243,54,142,205,36,176,106,193,119,86,88,88,81,209,27,69,133,9,52,164,187,94,204,185,49,150,69,71,142,235,202,238,113,184,86,14,155,157,25,226,94,102,154,99,57,71,97,208,123,159,73,78,117,20,165,44,63,151,103,147,175,241,77,88,208,180,188,199,202,165,22,155,206,27,109,96,228,240,238,97,209,233,65,230,50,165,24,211,81,123,227,26,51,31,91,248,237,46,171,119,41,83,81,195,199,106,103,135,241,197,56,75,161,169,209,140,97,59,88,184,172,173,100,123,11,140,186,144,80,89,111,127,186,60,119,88,169,75,170,215,248,38,147,175,25,172,172,236,219,216,183,218,207,78,215,193,118,21,103,87,204,214,86,202,17,125,207,151,141,87,234,123,196,177,249,29,119,66,233,223,178,186,53,11,101,150,210,203,111,119,147,177,219,113,35,54,237,82,130,106,84,81,128,82,138,57,33,120,10,1,192,8,164,64,0,0,4,10,136,182,56,170,220,66,134,28,249,217,5,215,44,154,140,92,155,194,52,235,117,24,42,145,165,74,46,114,148,123,147,91,110,113,121,149,175,112,215,203,6,246,199,6,213,43,120,83,237,113,138,202,88,32,212,141,189,197,197,121,202,172,254,70,150,23,161,191,78,154,130,73,45,140,192,195,76,0,10,139,193,0,0,76,148,128,54,42,220,16,10,66,128,0,14,0,0,248,8,1,10,64,24,13,106,16,0,57,0,6,89,10,64,24,24,3,32,98,208,88,198,197,4,19,11,33,165,187,75,37,35,2,56,164,180,68,80,90,232,101,193,22,156,238,13,99,40,83,146,73,193,60,123,24,58,52,228,177,40,197,251,99,115,151,57,24,228,43,137,91,80,73,175,42,9,62,59,78,31,217,246,184,199,145,79,255,0,41,182,204,89,21,165,62,151,103,56,98,86,240,105,241,131,85,244,46,159,28,203,201,194,244,77,224,237,37,40,197,252,204,214,155,149,76,69,105,246,228,131,66,125,46,201,213,249,32,148,220,90,201,33,225,219,87,78,29,217,219,100,118,148,233,40,203,187,26,189,217,205,140,49,139,174,191,224,233,219,210,141,56,37,24,47,165,27,212,41,70,18,74,59,28,117,101,23,53,236,206,123,117,167,118,119,224,35,118,47,230,137,201,220,253,14,53,245,68,231,58,241,98,186,233,235,60,250,150,59,61,76,42,188,79,17,217,35,70,234,181,195,169,31,46,25,94,236,205,244,212,142,207,41,162,106,117,50,187,234,25,88,183,130,143,223,115,24,222,117,61,101,42,80,140,82,206,18,203,100,213,202,238,86,89,123,91,58,104,212,234,181,34,165,242,195,58,225,173,80,149,62,164,226,243,118,242,249,75,24,67,83,29,199,107,244,38,14,158,157,27,136,75,49,184,173,39,233,39,185,184,169,214,73,57,55,246,26,99,115,62,131,56,89,102,146,119,15,57,139,138,91,123,156,83,243,178,147,109,47,97,166,59,45,113,176,58,202,142,171,138,78,82,126,217,193,140,37,94,49,222,88,46,152,237,27,192,79,39,90,167,95,26,101,253,217,201,231,74,41,103,57,228,24,222,207,11,114,45,205,15,139,169,140,70,15,185,236,98,238,106,38,150,36,223,37,49,217,39,151,236,51,131,172,248,203,134,191,203,237,246,48,125,66,235,233,196,19,223,98,105,142,217,189,6,135,81,46,167,114,147,78,154,94,248,39,237,59,158,228,149,22,227,203,104,105,149,220,45,81,116,91,157,58,234,151,89,203,160,147,124,118,188,17,245,106,209,147,117,41,98,43,210,12,105,142,228,136,233,233,245,154,149,59,159,144,212,120,109,53,147,9,245,186,206,15,202,163,40,227,158,220,228,108,49,221,224,99,83,163,167,213,110,39,30,233,124,139,150,214,14,88,245,170,113,140,167,57,103,11,68,150,229,49,219,243,132,106,117,30,163,109,211,109,165,90,189,72,172,109,30,95,228,116,151,254,39,185,163,108,225,105,107,223,94,75,234,226,38,173,133,173,207,90,189,87,87,127,37,24,97,45,62,169,115,143,98,106,231,246,206,119,23,222,33,130,113,238,161,67,58,65,188,101,123,157,181,135,72,133,10,113,238,140,112,184,198,140,236,105,219,194,154,81,142,199,50,92,33,137,169,246,88,251,21,52,146,92,134,155,209,35,90,230,246,222,211,10,172,155,155,213,66,59,132,109,99,212,235,235,117,36,229,228,219,193,212,170,229,219,182,136,212,167,94,235,168,213,83,80,149,58,57,210,47,118,142,206,222,140,40,125,49,213,238,198,174,99,142,149,146,243,188,218,185,115,126,172,219,194,217,109,232,27,24,8,184,212,0,81,120,11,97,192,0,107,213,207,152,108,100,214,186,120,107,28,232,74,177,207,73,124,169,156,153,49,135,210,151,177,86,133,70,68,216,0,28,20,16,12,176,76,224,14,51,194,3,201,248,218,222,165,245,181,27,104,84,80,75,50,109,253,143,59,111,225,30,157,91,194,180,161,42,80,115,181,169,42,148,231,77,56,252,207,127,212,239,124,67,123,70,119,157,141,232,227,140,39,169,219,116,75,106,53,58,37,55,24,184,198,113,206,37,193,158,235,94,228,121,127,8,220,198,221,206,210,148,180,169,159,149,255,0,12,145,236,169,195,186,113,82,127,52,121,228,249,166,127,103,120,194,173,56,198,162,156,39,221,236,243,174,87,234,125,42,45,121,180,220,94,123,150,115,129,11,27,201,156,116,124,233,58,146,170,187,87,119,202,189,140,227,157,217,150,66,53,58,141,31,58,198,172,118,146,131,199,223,7,146,240,163,133,135,90,169,211,41,197,225,65,78,77,242,241,169,236,106,78,155,168,233,202,88,202,60,114,189,165,211,188,99,74,77,105,93,246,188,175,201,22,44,123,126,74,181,65,252,186,21,6,88,205,55,14,212,106,211,178,138,175,41,205,183,166,134,226,220,5,213,90,12,224,28,53,107,194,13,44,234,194,57,26,238,69,65,13,128,166,45,225,100,201,179,138,189,72,209,182,169,82,111,233,77,253,192,242,221,81,86,188,170,233,199,29,157,250,235,186,61,39,79,143,109,170,196,112,121,75,26,238,231,168,57,170,115,167,111,12,203,50,229,179,215,89,172,91,71,92,166,178,132,87,55,1,188,83,148,182,73,3,79,171,92,124,63,76,171,40,225,73,172,38,194,71,85,94,226,156,149,122,221,205,52,154,143,185,195,209,210,183,181,117,165,31,154,82,215,30,156,28,53,107,78,214,181,42,82,164,165,86,73,99,243,55,39,109,86,222,213,65,60,225,119,61,55,121,51,43,78,226,206,164,106,208,243,23,44,216,77,112,117,179,168,172,122,59,148,178,240,229,45,30,52,220,230,178,189,165,90,218,148,211,73,84,147,138,203,228,212,172,183,91,73,103,56,53,175,171,59,123,89,213,79,85,169,167,213,39,82,165,74,49,165,55,24,211,154,117,23,170,53,58,149,252,175,149,123,88,211,146,130,142,241,221,228,205,191,23,29,111,84,182,167,213,32,220,219,242,101,30,236,251,156,221,34,112,185,163,113,82,156,178,227,86,48,155,245,105,28,29,102,165,110,157,209,237,229,31,147,177,226,93,203,120,165,177,210,244,46,185,86,214,50,161,26,9,74,230,227,45,165,157,26,224,146,43,189,186,234,80,180,131,168,187,156,229,62,213,166,137,103,115,168,240,23,72,119,146,234,125,98,246,14,83,169,117,47,41,77,109,31,254,81,193,53,91,169,248,143,224,169,39,58,52,95,117,72,247,31,66,180,183,167,107,109,10,20,163,219,21,171,94,230,162,244,218,206,113,246,24,34,216,186,224,172,168,251,4,135,1,4,16,224,32,172,129,50,80,0,37,160,122,5,82,3,25,78,48,150,36,245,8,201,236,99,82,74,156,84,165,162,56,93,127,51,42,154,206,235,39,21,75,106,149,218,243,106,183,4,190,148,77,49,159,196,58,171,20,246,245,17,180,79,235,125,218,228,231,140,35,8,165,21,132,180,50,91,100,38,138,42,56,73,97,23,4,41,64,0,0,0,0,19,92,128,43,3,130,38,5,0,156,1,65,57,43,0,9,146,231,32,0,39,32,94,50,70,202,70,184,2,240,77,138,136,3,238,6,227,57,0,70,203,185,0,13,192,228,8,194,40,193,4,12,17,229,224,42,111,144,131,79,33,132,76,240,11,166,78,26,149,233,211,238,204,180,65,92,141,226,57,123,26,181,174,32,229,24,167,187,194,193,192,238,39,115,158,206,248,197,60,97,173,25,205,70,130,210,88,213,17,86,52,221,70,251,179,132,115,66,42,47,8,201,36,144,198,30,112,5,224,61,53,46,230,18,122,1,173,37,223,54,189,77,202,49,237,130,94,134,181,38,187,241,131,106,41,189,153,32,219,91,196,231,56,97,186,103,49,215,131,21,214,207,89,52,88,165,130,73,230,109,153,71,24,37,85,81,142,118,29,171,208,20,202,167,185,26,77,163,44,100,99,5,24,246,173,240,92,101,229,135,176,0,214,119,35,91,104,139,184,3,30,200,250,17,193,104,218,51,38,117,8,197,83,75,47,3,203,79,132,100,245,13,225,17,83,178,47,129,24,37,194,46,125,135,184,24,202,9,188,224,199,203,142,253,168,174,162,238,193,83,203,2,58,112,230,40,158,84,90,199,106,51,123,140,242,17,138,164,150,193,193,119,103,25,206,249,50,79,82,251,133,96,225,25,44,56,232,96,232,67,31,74,57,115,171,35,251,149,92,82,183,167,45,224,177,246,53,167,102,170,62,200,194,41,103,59,27,249,15,113,131,172,171,97,10,112,209,47,115,154,214,56,156,84,116,132,86,199,45,198,90,81,51,163,30,196,151,168,131,153,60,34,78,113,167,14,233,201,70,62,230,173,207,80,163,111,81,210,140,187,171,127,42,227,238,106,81,161,113,123,86,85,174,31,203,252,49,124,13,76,91,142,167,86,181,87,111,103,23,220,247,150,9,107,210,85,38,235,92,205,214,184,147,221,237,20,111,81,165,11,117,242,69,38,206,76,183,166,2,164,98,163,21,218,146,72,205,100,37,161,113,250,6,68,202,130,69,40,0,0,160,19,144,42,53,110,94,38,109,45,141,74,255,0,53,111,82,81,181,79,61,145,238,223,5,201,35,178,251,25,20,10,66,160,30,197,68,247,42,64,13,123,235,168,90,218,78,115,250,113,175,177,176,116,94,38,114,169,210,235,82,131,195,112,111,34,145,227,44,254,31,169,223,214,184,167,221,229,67,61,210,146,223,236,122,95,14,222,54,230,231,41,118,73,118,197,61,162,120,168,117,57,89,244,181,27,72,226,17,93,205,99,234,92,157,255,0,76,186,169,230,90,86,90,81,168,156,167,12,108,99,140,110,235,151,198,189,61,127,135,173,69,202,19,114,74,164,226,180,237,251,157,183,76,168,167,210,225,26,51,125,212,211,135,118,238,38,215,89,167,241,254,29,173,42,113,90,195,186,41,163,200,120,59,168,202,218,170,233,245,160,251,234,230,74,89,221,154,39,79,161,219,188,208,139,122,183,169,202,106,89,207,20,187,90,215,212,218,200,97,193,94,29,212,155,198,167,134,241,21,24,210,189,161,115,82,88,116,228,176,177,190,190,167,191,107,49,122,104,120,127,26,213,157,189,133,41,36,147,148,241,151,194,34,203,237,237,105,214,85,232,66,172,126,153,163,42,85,59,251,190,70,146,120,77,242,116,157,46,242,79,161,219,82,163,53,57,231,183,187,216,238,148,90,74,47,255,0,169,106,57,119,67,26,145,38,117,29,103,173,208,233,182,174,110,105,212,114,81,132,35,187,109,224,17,183,127,121,10,20,156,59,179,85,236,145,201,111,65,198,17,149,70,165,38,189,14,163,165,81,169,121,94,173,237,223,119,100,37,219,14,237,59,151,169,219,43,234,115,170,161,22,181,37,90,219,15,131,134,165,197,56,198,125,178,82,148,86,199,89,46,171,42,183,29,141,199,182,26,98,60,178,163,181,171,82,20,162,229,55,161,209,245,59,169,84,181,73,175,150,9,201,179,170,235,221,86,116,220,168,82,154,149,196,154,140,97,31,225,94,172,214,234,243,171,30,159,78,221,73,247,86,131,207,190,134,118,44,142,79,14,187,171,216,78,165,197,53,10,18,250,48,254,164,123,122,113,140,41,70,17,217,45,15,53,208,104,73,116,206,155,111,171,169,8,169,212,151,250,30,157,155,45,25,211,245,201,249,180,227,105,31,154,114,105,246,157,186,231,209,110,121,91,203,245,25,223,95,186,114,237,162,187,105,181,187,51,72,194,218,50,191,189,243,166,190,106,90,101,147,168,245,42,180,106,66,114,168,251,100,241,133,180,112,117,125,54,231,175,221,213,171,26,118,81,167,74,75,229,146,227,62,167,125,211,252,63,86,93,207,169,77,78,45,125,8,152,213,116,183,183,84,252,77,208,43,88,219,220,74,47,186,74,164,254,239,111,208,220,172,157,59,219,24,81,159,109,11,123,125,155,211,185,172,106,119,112,240,245,141,9,37,109,79,202,77,230,88,228,214,255,0,101,169,74,164,221,91,202,243,132,155,125,173,255,0,65,79,78,178,125,122,221,244,170,242,115,135,153,110,218,114,215,117,247,220,214,181,185,173,42,86,215,190,69,86,238,84,36,218,142,177,77,29,197,127,7,116,235,171,127,34,188,171,78,138,79,228,239,194,121,223,99,186,149,40,70,10,52,224,162,162,146,75,27,34,123,54,60,127,136,44,186,175,84,233,117,163,9,73,40,60,195,158,239,186,60,189,173,202,233,125,106,218,222,170,149,74,180,233,169,56,173,242,125,113,83,204,53,75,213,179,229,222,53,171,73,245,170,215,253,34,14,164,168,208,242,235,213,199,203,221,159,225,124,180,137,238,118,107,213,248,70,157,189,73,222,93,53,21,115,87,18,154,229,30,153,106,211,62,107,225,27,250,150,116,97,41,46,233,87,95,44,222,141,225,236,255,0,83,232,182,85,221,205,53,81,199,11,208,223,26,205,109,20,136,184,42,39,37,28,19,96,171,176,11,82,160,42,24,212,133,0,61,91,7,21,220,187,45,106,60,225,99,86,6,165,205,252,161,115,74,141,57,70,49,169,22,212,219,229,28,116,41,81,85,170,86,184,185,204,218,89,139,122,35,90,202,218,149,199,81,140,106,165,56,210,163,152,39,238,247,59,111,133,160,147,81,166,137,21,35,113,109,180,106,197,253,139,241,20,154,109,77,97,110,99,27,42,16,155,146,166,187,154,220,191,13,73,183,242,238,176,19,208,239,40,54,146,168,158,121,50,248,138,43,11,204,89,244,31,15,75,69,216,180,224,190,69,62,32,151,184,48,85,233,53,164,209,151,153,23,170,146,49,242,97,232,89,81,131,198,155,122,3,211,46,232,243,37,250,133,36,214,140,195,200,131,254,20,60,164,150,54,251,3,211,147,62,225,60,242,112,121,17,202,195,107,28,33,228,62,236,169,191,176,71,62,65,193,229,180,146,238,121,206,74,168,124,221,238,82,206,49,184,28,217,9,156,74,50,75,14,77,142,202,157,203,18,194,76,104,230,99,83,134,94,107,121,79,236,142,40,202,229,101,62,220,241,129,166,54,193,171,223,95,26,227,238,88,74,225,207,92,118,173,244,41,141,142,74,141,57,212,185,85,162,160,147,135,241,54,101,42,149,243,164,118,33,141,144,104,85,184,185,140,51,24,119,53,194,47,157,119,44,225,70,43,28,141,92,111,131,66,55,87,19,142,176,198,55,201,154,173,89,233,140,123,177,166,54,222,143,64,105,66,189,102,222,112,177,237,184,119,117,177,44,211,198,30,16,211,27,188,12,96,213,87,21,247,116,227,140,15,62,172,162,190,68,155,46,166,54,184,33,170,235,85,113,122,97,231,115,137,212,184,83,203,199,186,68,92,111,234,248,28,104,117,235,226,146,115,239,148,179,252,44,202,50,175,56,235,221,28,251,236,52,198,241,13,71,231,118,231,185,188,24,98,183,243,49,166,55,177,234,70,241,169,166,188,252,234,222,134,89,173,163,123,122,1,181,190,4,182,102,171,157,124,173,18,36,156,219,206,90,245,3,142,246,188,161,71,190,140,227,223,156,36,217,175,27,73,87,237,117,118,229,103,115,27,56,53,121,90,15,230,167,221,152,231,134,118,139,17,88,102,85,21,56,197,44,36,176,176,140,212,82,139,15,216,45,16,16,61,55,36,234,70,59,254,135,3,168,229,47,153,226,40,163,147,204,237,210,40,198,110,77,233,202,48,93,207,233,216,231,140,127,82,14,59,122,111,45,203,242,55,18,211,99,8,172,35,61,144,28,240,221,28,231,4,56,57,206,188,43,21,212,202,180,123,218,123,153,70,162,193,175,44,42,146,237,142,94,121,31,226,37,132,148,82,37,106,54,29,111,97,231,197,47,115,130,164,46,26,74,18,95,161,194,172,107,205,183,82,174,23,24,70,106,227,111,226,225,20,243,130,59,218,73,125,75,60,164,112,174,157,14,212,156,155,193,204,173,41,69,52,161,29,119,99,216,194,55,244,230,241,8,73,231,84,199,197,252,221,177,142,94,50,218,224,225,189,169,43,68,165,74,156,90,219,208,202,146,82,158,113,171,90,32,57,99,117,166,113,175,163,31,20,229,156,65,233,234,114,198,148,84,117,70,74,17,244,208,35,131,226,251,113,221,207,161,21,218,148,154,81,216,216,116,227,45,92,81,143,149,28,63,149,3,211,129,221,190,199,40,199,58,108,35,117,44,101,197,99,209,156,190,76,120,70,62,66,206,119,251,133,244,227,248,218,156,83,138,193,93,238,49,152,172,251,25,252,58,251,100,198,86,201,108,84,113,74,234,49,146,169,37,167,161,93,255,0,242,211,198,78,187,171,209,159,149,42,112,147,79,70,165,232,109,90,218,186,150,180,251,165,157,55,228,106,250,198,204,111,51,12,200,201,221,38,147,198,135,31,192,71,24,82,120,50,118,106,47,73,50,167,165,248,181,132,229,128,239,83,125,176,244,215,38,14,205,56,164,217,85,141,52,154,139,107,43,116,69,244,198,183,82,133,24,167,37,220,223,17,245,17,189,114,143,119,102,22,2,233,180,252,200,207,186,82,146,88,203,102,110,198,29,219,203,3,210,122,96,239,227,20,219,88,72,194,29,110,202,113,148,188,198,229,29,210,66,175,76,163,87,10,93,218,108,147,208,180,122,77,173,22,218,167,137,75,86,193,233,35,121,230,53,53,22,211,213,25,206,181,90,221,212,233,167,12,255,0,22,14,106,118,212,233,253,40,228,140,18,96,214,157,175,75,161,109,41,84,150,106,86,158,178,156,141,237,94,137,36,144,211,98,224,26,199,11,57,51,198,16,88,43,220,34,53,157,139,177,30,154,100,103,95,114,138,181,41,51,169,64,163,238,66,128,251,16,4,176,5,224,211,174,154,171,21,198,117,54,205,122,152,119,9,189,82,91,18,141,148,176,150,6,72,182,201,81,69,9,60,228,156,153,112,0,168,133,0,106,95,89,198,246,132,169,75,78,228,214,126,230,217,62,192,124,235,169,248,126,214,210,242,141,56,86,139,167,77,52,233,63,226,208,193,194,186,173,79,202,237,140,114,180,59,30,175,42,111,196,212,227,86,73,42,42,83,199,243,55,161,179,30,157,26,214,238,165,55,219,41,250,241,161,153,93,28,54,93,102,53,110,42,244,197,153,252,186,191,67,204,87,132,108,188,67,26,170,226,113,112,125,180,227,29,209,234,186,111,135,110,108,20,234,119,211,149,73,201,183,44,188,188,158,75,199,61,58,227,166,221,66,234,41,67,205,146,140,170,39,186,55,108,198,103,244,250,63,79,170,230,227,174,114,178,206,205,30,91,194,215,170,246,205,212,167,30,218,116,187,96,177,190,220,158,162,47,43,57,51,219,54,99,36,190,83,203,248,151,167,252,117,165,197,41,45,169,185,166,248,120,61,66,219,7,81,215,97,57,89,214,140,62,170,145,112,207,166,83,37,88,242,31,135,183,138,167,79,148,106,84,114,236,150,239,132,122,8,245,169,75,171,205,57,229,195,61,148,253,99,234,120,95,6,91,205,244,138,177,162,234,70,74,164,162,163,179,194,223,63,158,78,235,167,94,214,234,93,79,225,33,106,211,237,242,221,71,163,142,55,101,248,182,123,119,85,188,87,47,131,147,168,187,28,243,137,45,18,58,107,15,46,167,81,85,107,41,212,143,214,157,76,155,84,124,29,212,46,238,233,252,117,104,70,214,149,79,146,17,221,197,62,79,93,117,210,237,110,104,186,50,131,132,49,132,225,166,6,153,142,163,246,197,189,213,39,74,202,183,127,151,164,146,139,211,254,102,20,107,66,146,146,155,196,229,36,181,244,55,237,188,63,107,107,44,209,88,120,107,185,238,108,208,233,54,240,170,231,56,169,203,213,129,213,218,117,40,214,167,113,79,203,154,113,122,77,173,206,182,233,85,182,183,117,104,211,111,185,235,141,245,61,122,178,162,150,59,48,140,213,189,37,133,217,28,123,162,98,60,151,236,10,208,165,27,185,168,70,73,119,77,189,223,230,104,215,148,101,44,212,148,59,220,92,35,38,206,235,199,125,70,181,159,72,141,58,43,90,178,73,191,68,116,182,52,33,90,165,26,213,62,103,229,102,17,107,78,239,81,138,245,93,6,135,151,101,22,158,84,116,201,218,189,205,110,159,25,66,202,10,75,18,122,188,27,28,132,113,92,206,116,237,106,56,69,202,77,118,164,189,207,23,119,11,167,117,14,152,160,221,55,20,167,83,58,110,123,75,138,158,85,41,203,41,97,106,223,7,148,233,61,247,189,82,75,204,238,130,151,115,109,106,255,0,244,37,88,245,54,118,240,182,182,141,56,45,34,177,159,83,99,11,6,52,215,106,195,50,215,132,105,145,45,67,56,235,92,91,90,195,190,230,189,58,75,253,233,96,232,223,139,173,110,39,42,125,54,210,189,220,178,226,164,163,136,228,150,197,145,232,20,91,215,143,83,168,234,62,34,233,125,58,47,186,225,87,170,158,21,42,47,185,183,232,106,126,206,234,93,91,94,163,89,194,150,53,163,77,225,27,246,29,11,166,116,216,118,218,89,81,167,151,220,218,138,220,158,234,186,74,80,235,190,35,175,221,123,15,217,189,49,45,40,194,95,188,169,247,124,29,237,94,149,109,14,149,43,59,122,80,140,84,123,86,98,118,11,87,169,36,178,180,24,150,190,75,212,104,220,244,238,173,78,46,166,105,208,121,75,24,95,145,244,126,135,115,241,61,58,157,76,174,230,179,131,161,241,101,132,39,103,42,147,165,41,44,235,218,181,68,240,237,245,42,118,180,124,167,44,83,249,36,158,236,147,181,237,236,86,230,70,48,151,116,20,253,86,75,202,52,140,137,200,200,224,10,84,99,147,36,80,200,79,65,130,133,13,30,173,39,251,54,164,98,191,204,249,13,211,79,169,98,86,244,169,255,0,61,85,16,56,172,104,70,149,210,156,115,159,43,176,236,77,91,60,230,163,195,194,120,90,155,68,137,76,1,144,211,253,74,3,56,38,112,203,168,4,52,39,5,8,17,21,231,3,100,3,26,151,98,32,3,5,193,0,4,16,69,200,16,2,129,49,149,168,198,54,40,2,97,12,20,1,139,75,13,96,40,174,221,140,153,56,2,118,172,45,8,226,179,178,50,206,160,134,176,236,88,122,34,168,37,198,75,192,224,161,140,34,118,172,236,11,156,1,48,177,176,113,94,133,220,1,143,106,244,9,20,168,130,96,137,47,66,130,137,140,100,152,89,46,125,136,217,3,11,115,9,44,156,141,225,24,243,247,3,175,160,241,121,37,149,148,111,99,59,157,117,156,39,59,218,245,59,147,138,120,88,245,228,236,223,220,203,85,140,184,103,5,91,156,55,26,107,186,70,53,235,169,126,238,155,249,179,171,49,167,30,217,54,150,91,220,9,38,161,172,219,150,125,22,198,106,159,123,78,91,112,114,70,155,213,189,204,148,64,177,134,48,114,69,36,99,141,10,150,187,129,154,198,203,113,157,53,34,13,225,106,6,212,30,145,57,206,8,47,164,231,58,240,98,186,188,124,242,251,156,139,93,142,55,164,159,58,153,163,53,166,73,106,2,4,13,180,46,120,4,228,13,27,248,169,246,229,108,242,91,86,229,86,25,88,73,24,245,7,219,30,228,214,81,44,231,222,224,253,66,187,6,192,251,13,131,38,8,214,10,11,130,37,128,214,160,103,80,1,173,6,9,234,81,212,245,41,118,201,98,57,124,39,234,110,217,44,91,69,63,169,29,127,87,148,105,56,202,163,125,169,173,22,167,105,109,143,34,18,219,43,56,102,103,109,94,156,154,162,239,168,100,121,206,133,101,113,161,138,69,224,175,68,1,104,137,160,211,3,127,184,5,184,122,0,202,11,77,75,187,208,115,170,38,125,0,39,184,206,154,6,145,94,84,64,110,177,176,244,12,45,208,23,11,57,228,0,182,1,132,31,179,43,39,0,92,140,251,19,130,129,72,7,0,13,75,150,227,86,18,143,174,26,54,206,10,255,0,84,127,169,145,206,190,146,146,47,9,25,114,104,16,76,0,40,0,1,121,68,43,32,240,93,98,222,218,231,196,21,171,86,155,88,146,142,158,199,103,210,238,126,34,17,123,69,45,179,238,116,222,34,167,113,79,175,201,91,197,73,86,150,31,251,167,121,211,44,154,182,74,162,90,44,54,140,183,241,232,84,126,69,149,178,58,15,24,90,82,184,232,210,169,86,43,178,158,178,109,108,189,77,232,82,187,164,187,232,214,204,95,240,73,101,126,167,15,85,169,125,115,210,110,104,252,12,107,119,211,148,28,35,60,55,148,106,244,207,215,151,240,37,72,83,182,175,70,156,251,187,230,229,171,203,199,7,190,164,215,98,62,101,225,59,106,253,51,196,151,246,243,162,161,56,40,252,189,249,236,139,73,165,238,245,62,149,73,167,77,60,234,201,199,166,185,57,178,117,221,106,81,167,101,42,146,106,49,75,45,190,48,118,38,181,245,180,110,237,165,74,164,83,130,89,107,212,88,196,120,127,13,213,133,91,186,151,20,220,84,107,86,125,241,92,250,105,193,237,109,58,125,181,163,155,163,74,49,156,158,92,177,171,62,83,209,186,189,43,46,178,158,82,167,42,211,139,142,61,30,17,245,203,86,231,70,19,206,173,100,182,53,103,183,54,188,129,175,244,42,79,33,2,240,20,36,248,47,108,158,184,0,18,203,29,175,208,224,186,187,182,178,161,42,183,21,225,78,41,114,202,143,29,227,171,215,94,230,211,167,82,81,146,168,165,230,203,152,165,131,31,11,216,74,175,83,117,234,73,202,149,58,125,170,45,233,147,172,234,119,80,187,175,42,150,78,46,51,146,140,165,140,180,189,143,101,208,44,39,101,67,93,156,82,203,221,227,147,31,90,190,163,184,198,18,75,129,201,113,150,112,94,86,248,123,74,179,95,95,110,35,247,43,47,51,226,190,167,82,53,169,216,210,148,124,186,171,51,121,212,207,194,180,20,35,58,245,39,28,231,11,93,145,228,58,220,47,110,47,173,105,210,151,117,72,169,119,205,241,147,211,120,119,193,177,163,211,97,62,167,115,94,189,90,159,51,131,155,81,143,217,19,62,181,102,59,78,167,226,203,43,10,210,163,111,78,119,117,146,218,154,202,95,118,106,91,94,117,254,183,69,77,63,217,212,106,47,165,71,50,75,238,119,214,157,62,210,202,61,182,244,33,15,116,181,54,176,145,77,143,63,111,225,59,90,82,243,43,85,171,117,81,173,101,90,93,218,157,213,181,157,27,74,49,167,66,156,105,197,113,21,131,159,32,100,77,52,8,5,172,83,8,190,195,13,157,119,83,235,182,29,38,49,141,197,94,234,211,127,37,24,107,41,126,71,81,115,121,215,122,170,242,237,109,221,157,25,109,41,253,77,11,87,27,221,106,238,63,13,82,218,221,121,183,13,109,29,84,126,231,150,233,182,207,166,210,110,114,114,156,170,55,63,67,218,88,244,184,89,90,121,109,247,213,146,249,230,247,108,211,188,179,167,10,83,82,167,152,225,236,76,247,171,28,253,26,242,23,22,158,90,147,147,167,162,150,119,59,46,15,45,210,42,198,198,246,52,251,146,163,56,181,20,253,79,79,156,165,130,165,103,157,7,4,224,161,12,23,130,20,170,23,114,100,191,96,27,28,87,17,78,150,92,114,211,77,125,206,94,70,141,96,13,42,117,170,66,47,22,210,195,123,156,47,169,213,141,94,215,69,107,253,14,205,235,246,56,106,81,132,245,104,138,215,159,80,196,187,84,53,254,199,5,46,165,82,113,90,37,175,169,184,173,169,169,103,180,191,9,69,71,72,34,123,70,164,250,140,182,105,101,190,14,74,87,216,158,42,69,164,182,102,117,58,125,173,92,57,83,215,215,38,95,7,69,97,118,112,61,140,85,253,54,223,6,78,254,146,79,57,211,114,59,10,59,40,172,28,83,233,22,210,166,233,168,52,155,204,176,222,165,61,57,126,58,151,110,115,253,75,27,234,18,150,59,177,169,199,251,50,132,82,75,187,9,109,147,134,167,72,167,82,105,202,164,215,252,32,111,124,69,38,241,222,103,26,208,107,42,72,209,151,77,110,89,141,89,45,48,88,244,249,70,61,174,179,121,26,142,193,202,42,57,238,95,168,201,211,190,149,112,235,77,58,207,203,150,218,236,109,43,23,28,98,164,155,75,25,108,106,250,111,232,67,70,84,46,94,144,175,143,186,201,197,94,222,226,22,149,92,103,57,77,69,188,183,201,76,118,107,26,234,83,168,183,165,121,39,42,158,127,203,56,174,213,232,108,78,133,204,241,31,57,165,203,192,49,190,113,66,188,103,94,165,20,243,56,97,181,236,205,87,78,234,50,73,77,56,173,253,78,186,218,141,90,222,33,234,55,48,175,56,201,66,20,227,5,244,227,26,191,190,81,45,49,232,48,67,175,133,59,216,166,149,88,247,122,179,46,203,158,207,175,51,251,233,144,99,120,61,141,53,78,229,82,138,115,77,173,114,112,85,133,238,62,89,71,243,6,59,33,162,213,179,66,149,59,173,84,219,122,105,131,94,246,53,161,67,189,247,56,197,235,168,49,219,101,109,148,86,210,122,180,117,144,165,90,81,121,109,44,104,21,25,107,153,73,182,53,113,217,102,63,204,191,81,149,141,26,52,35,66,111,45,73,231,57,56,233,210,184,117,37,223,63,149,236,144,212,199,102,189,80,202,53,29,58,171,72,201,152,211,133,120,69,226,89,111,212,105,141,221,209,77,56,124,82,250,165,31,178,70,75,207,113,121,150,220,224,24,217,35,120,228,225,78,186,127,54,169,236,97,85,214,106,13,69,54,159,174,1,142,121,73,105,168,82,139,222,75,216,214,113,148,155,194,107,30,165,133,55,141,87,216,24,216,114,89,195,224,141,251,156,56,125,207,71,247,46,175,40,24,214,178,93,178,171,235,222,219,57,106,212,202,113,253,89,131,115,160,230,241,22,229,178,50,140,39,81,230,88,193,150,156,112,134,116,75,11,212,218,167,77,67,243,50,140,20,94,136,187,112,84,57,201,48,86,0,107,246,42,254,160,168,130,165,232,196,182,42,216,141,101,129,181,77,101,68,231,56,33,156,68,231,58,240,98,186,182,243,39,131,40,172,238,98,158,175,208,206,56,51,201,89,21,19,96,182,208,138,172,152,229,177,156,14,0,208,191,93,209,37,178,237,116,210,45,246,191,34,221,152,209,204,93,53,187,200,87,98,2,200,52,202,232,65,192,251,1,50,6,16,120,1,145,176,221,145,232,152,29,71,83,155,119,48,130,198,173,103,62,135,103,69,118,211,138,211,24,58,218,241,243,47,162,229,193,218,37,132,189,9,158,218,172,183,38,112,193,51,168,100,43,123,17,178,34,138,134,53,32,10,200,61,194,122,17,32,139,206,229,217,152,251,149,60,176,38,173,153,110,56,11,156,144,82,147,98,48,26,231,83,35,28,151,37,5,239,185,73,185,64,6,0,20,50,100,0,53,110,37,138,177,143,13,155,75,208,215,175,20,156,94,117,37,27,11,130,152,172,225,100,203,112,47,4,65,253,47,212,44,181,238,81,150,125,134,228,228,100,10,51,168,49,148,176,178,65,243,126,191,82,188,186,196,169,210,171,63,49,86,238,146,143,242,231,99,215,116,166,235,89,170,138,88,215,13,30,94,242,135,119,89,184,151,152,149,90,146,114,199,164,114,122,139,107,37,59,10,93,149,29,46,229,156,162,70,171,183,130,196,113,141,12,180,202,213,157,109,55,127,111,23,30,232,213,75,108,172,28,142,254,189,53,23,82,210,82,238,226,15,56,52,152,241,151,240,118,159,136,148,170,246,202,48,175,31,157,241,182,15,105,105,46,250,42,75,69,151,167,161,231,60,75,115,109,82,17,172,233,84,132,233,181,46,246,189,56,55,186,47,83,167,112,168,71,41,202,80,82,146,95,195,147,57,149,171,238,61,10,124,151,234,77,122,145,123,108,100,105,135,202,124,71,208,172,173,188,89,110,168,70,80,156,230,165,40,173,143,103,27,78,165,78,149,58,150,151,141,77,172,56,181,162,71,55,89,233,144,185,170,238,227,21,241,16,217,180,101,107,212,37,228,70,21,99,137,45,30,152,201,27,221,142,14,169,125,213,186,103,74,173,120,170,198,126,92,51,44,199,111,200,232,237,58,175,141,122,149,189,58,246,178,162,168,212,167,221,25,78,146,79,93,143,75,215,234,198,167,135,235,70,24,106,107,181,174,113,201,203,208,23,103,69,162,241,140,233,31,176,181,30,114,222,199,198,177,187,165,86,181,253,10,138,31,52,162,243,219,47,108,44,28,84,110,124,103,109,112,167,82,210,155,135,127,204,187,244,73,179,221,40,239,151,185,151,110,86,56,38,38,188,135,77,234,61,71,171,206,181,58,181,252,184,185,181,23,31,67,180,186,232,214,180,237,21,57,41,85,75,87,230,60,229,157,111,70,175,74,93,126,234,210,132,113,26,53,53,151,187,55,60,93,213,99,211,44,41,37,23,59,138,178,74,17,142,175,239,143,66,78,150,215,153,165,99,37,214,232,89,210,116,211,149,101,41,40,235,136,163,233,15,9,97,108,121,15,7,244,170,244,235,92,117,75,200,37,86,179,110,41,189,143,93,252,37,144,229,68,121,223,24,117,88,116,187,43,126,249,97,212,169,135,206,135,162,91,159,58,252,69,140,238,188,186,82,204,105,252,76,34,218,223,177,106,216,189,36,237,219,89,91,198,242,246,130,140,53,150,37,54,214,241,71,173,194,219,142,15,35,211,252,75,210,104,57,84,138,184,169,36,146,74,52,94,136,236,159,137,106,85,89,180,233,55,21,52,207,206,212,71,75,125,187,213,160,198,78,142,125,91,172,202,10,84,186,77,56,231,117,58,187,18,85,60,67,95,10,50,182,182,95,197,163,147,252,130,99,190,146,236,143,116,165,24,175,86,240,105,87,234,182,22,201,247,220,193,203,210,15,187,251,26,18,232,147,184,195,187,185,169,85,242,155,194,55,173,250,93,181,188,82,133,40,44,123,3,211,81,245,154,247,81,197,141,156,154,226,165,68,210,42,177,234,23,20,251,110,175,114,164,245,141,21,219,143,99,183,81,72,203,43,208,26,235,41,116,59,10,53,213,127,33,74,180,118,156,245,103,98,146,206,112,94,71,220,162,73,100,213,189,167,230,89,215,88,249,187,31,111,223,6,217,131,213,181,234,134,143,158,89,249,174,189,140,110,20,149,72,82,243,92,95,56,120,61,205,133,211,187,183,238,150,146,142,140,242,158,34,182,149,30,179,78,234,18,113,138,163,229,203,92,97,103,39,119,209,170,197,80,140,33,174,82,204,159,38,126,173,119,121,208,45,73,151,156,34,149,149,224,164,1,87,157,0,38,219,148,92,149,17,133,176,20,99,66,38,24,1,128,54,8,6,50,57,1,177,73,201,64,96,143,96,0,34,224,33,141,114,0,20,152,3,29,153,199,95,74,18,92,53,131,149,156,23,114,113,160,241,234,136,45,180,59,40,66,27,227,67,155,99,10,90,65,25,176,11,86,142,178,194,41,245,75,249,167,174,82,103,102,183,88,58,238,158,179,212,175,251,127,133,197,63,185,86,59,46,8,11,176,68,220,61,130,15,112,30,198,151,83,158,45,157,55,252,111,25,70,233,215,245,69,37,78,155,142,53,146,76,43,122,63,74,120,221,12,44,149,44,69,47,68,5,64,152,89,90,23,129,157,8,105,133,144,150,129,7,176,93,69,170,46,2,211,30,131,63,160,68,193,49,134,92,46,70,232,42,97,6,191,80,63,48,168,248,24,228,175,15,113,192,71,28,162,164,246,79,5,75,24,199,6,75,25,11,244,34,166,3,43,209,147,112,24,24,211,80,30,185,64,12,147,203,201,138,216,171,86,65,153,9,187,47,37,27,80,224,230,56,97,178,57,142,188,88,174,175,102,242,103,29,12,55,201,154,216,197,237,87,58,7,247,24,192,75,215,114,44,84,176,54,200,224,123,129,215,221,39,42,216,253,75,78,41,85,137,46,154,85,146,75,87,185,96,215,196,70,30,161,91,235,96,57,246,46,134,153,64,202,201,186,212,8,130,121,220,5,160,20,198,114,74,45,178,229,250,24,84,250,36,253,136,58,104,205,86,234,146,155,250,86,136,237,103,117,70,17,250,227,167,185,211,218,118,221,222,78,52,241,229,46,87,169,217,124,5,60,231,25,126,162,171,153,220,82,226,105,191,66,186,180,212,123,156,146,71,19,181,134,191,166,76,62,6,154,111,25,195,224,158,199,63,157,6,244,146,104,190,100,30,189,218,28,14,214,45,97,104,145,29,174,101,220,158,163,76,108,247,44,103,58,5,36,249,70,186,162,215,45,178,121,114,90,38,203,163,105,191,66,238,141,101,25,38,93,82,203,114,95,97,163,99,26,172,142,77,127,157,71,57,97,78,77,110,52,198,195,102,79,250,154,253,210,211,47,66,249,175,59,100,35,99,101,238,99,247,56,124,233,101,104,100,235,127,186,7,38,197,76,226,243,161,156,52,204,149,72,238,152,209,201,145,157,76,59,162,195,154,79,57,26,51,41,143,114,122,229,21,60,162,138,6,125,192,4,181,53,238,18,117,33,158,30,77,149,170,120,53,110,22,42,70,95,195,201,41,27,43,93,74,72,225,36,94,74,28,21,109,238,0,4,63,177,27,215,67,36,64,228,194,127,76,159,9,25,154,189,66,183,145,211,174,106,255,0,44,27,3,231,182,202,117,60,91,115,117,86,113,116,228,187,32,184,198,79,123,105,28,209,132,113,162,62,115,209,160,238,46,162,167,22,229,58,157,209,251,159,78,167,30,212,151,162,51,26,174,69,28,34,118,165,198,166,65,154,101,193,94,133,26,150,245,35,90,17,148,59,117,82,71,139,232,210,81,186,184,168,154,203,169,216,177,196,115,177,237,238,30,45,170,39,156,56,240,120,62,145,82,110,234,189,26,77,63,223,54,242,137,201,99,222,69,188,44,238,102,97,30,52,224,204,168,199,183,57,108,210,173,105,9,60,99,125,240,111,231,6,45,101,100,139,30,63,196,125,30,250,116,84,172,174,37,140,99,181,234,103,208,58,245,106,84,232,116,170,212,96,171,211,138,139,82,120,109,122,158,154,117,41,210,79,205,199,110,199,149,241,67,161,66,86,117,232,226,19,115,199,119,56,200,213,255,0,79,69,83,168,94,70,78,52,237,35,45,116,110,70,189,105,117,138,246,213,19,116,160,230,176,187,120,59,104,66,62,84,48,180,237,70,122,115,178,45,154,143,59,210,58,61,46,129,78,230,242,226,175,125,106,210,79,95,182,14,158,181,31,140,187,125,66,229,73,213,169,56,211,164,179,177,218,221,94,85,191,234,82,167,74,15,203,82,84,214,118,94,172,236,105,116,232,70,238,19,158,177,134,90,94,254,163,36,86,229,157,39,66,218,157,39,252,49,75,62,166,206,228,64,50,185,193,227,60,109,251,187,57,92,75,9,197,168,199,62,172,246,79,99,203,248,206,151,119,75,141,110,206,254,217,253,47,108,19,151,75,199,183,99,208,173,237,223,69,183,156,99,25,55,21,153,99,115,179,140,98,163,132,146,252,142,163,194,181,149,110,137,21,157,99,44,99,211,216,238,120,10,157,171,5,75,5,202,42,213,1,26,215,37,192,228,165,100,201,22,112,48,8,41,120,32,40,104,99,45,22,74,71,156,96,139,175,61,226,171,41,220,116,170,245,41,53,223,8,55,170,53,188,49,89,213,183,166,159,17,88,208,244,181,33,26,176,157,57,237,40,184,191,204,241,94,22,171,86,149,197,122,117,91,156,163,81,198,56,225,100,139,175,111,23,147,62,50,113,192,205,21,25,48,79,82,251,128,28,251,140,0,47,8,23,129,193,68,11,112,0,114,24,212,4,22,195,144,57,1,201,57,42,223,112,222,160,94,8,50,0,20,152,46,64,19,32,1,14,11,180,231,111,40,167,140,224,216,53,238,159,238,212,125,89,21,201,75,30,92,82,51,56,232,226,52,162,151,234,114,132,19,229,29,87,77,127,251,95,169,52,242,166,226,241,232,206,214,59,235,177,212,244,168,118,245,46,163,53,244,247,164,159,168,88,237,217,56,42,35,42,25,27,140,224,5,67,175,234,242,112,163,73,225,188,212,138,211,238,118,39,89,214,106,194,148,109,187,165,142,250,170,43,239,144,78,221,146,217,33,163,127,97,179,198,64,69,32,96,128,70,80,4,207,4,193,147,34,2,238,136,247,65,254,132,90,128,200,94,163,40,38,194,170,68,15,15,81,200,84,206,3,121,100,211,35,26,144,94,53,33,94,164,1,145,141,11,156,24,176,41,148,118,48,224,201,103,128,50,228,55,249,12,18,91,16,109,193,252,177,57,142,10,123,68,231,58,240,98,186,206,127,51,60,250,24,39,171,247,50,75,63,145,154,170,216,221,16,38,65,146,220,75,96,153,36,240,130,186,250,176,238,186,211,117,187,45,24,255,0,138,122,106,102,255,0,206,215,129,71,46,227,86,240,216,86,234,41,30,224,211,32,24,15,124,0,193,25,121,35,199,32,129,197,113,242,209,151,57,88,57,126,200,215,187,151,109,188,228,219,73,34,43,174,232,234,62,108,212,35,136,197,180,119,15,7,89,209,222,104,57,73,98,77,115,201,217,165,169,105,81,110,26,72,201,163,25,53,249,17,19,6,56,206,197,91,151,0,76,12,34,231,66,105,185,3,11,4,105,55,182,133,221,149,34,170,96,142,9,173,140,240,177,144,182,200,28,110,156,116,194,39,150,214,135,46,53,212,4,113,121,120,91,147,202,111,147,155,145,184,28,62,87,31,220,174,155,228,229,193,95,185,13,112,168,183,193,28,125,141,141,48,99,140,131,92,56,212,106,185,57,146,73,140,34,174,184,91,150,152,100,117,106,101,99,24,247,57,157,53,144,224,158,203,66,27,24,42,178,207,13,114,113,213,175,221,83,181,45,142,103,8,227,84,107,206,48,139,204,86,168,163,102,21,86,23,113,124,232,231,6,10,142,99,163,213,133,65,167,184,71,42,154,124,153,100,215,242,158,119,29,179,89,107,65,163,99,26,228,203,56,208,215,143,152,147,203,202,244,10,85,35,151,171,108,24,231,122,35,168,241,37,120,219,116,11,154,147,203,142,62,100,158,52,59,55,55,141,81,209,248,167,202,184,233,52,237,171,103,182,181,85,23,246,228,106,200,243,30,8,181,169,118,233,95,182,212,36,220,163,7,194,224,250,34,213,225,157,63,65,179,165,101,103,42,52,34,163,78,47,229,94,199,113,29,17,11,219,44,17,149,17,149,26,247,243,156,58,125,196,160,179,37,78,78,43,223,7,128,240,244,157,62,175,109,111,83,234,171,153,60,122,243,147,232,85,223,248,122,143,132,158,79,1,225,170,83,184,241,229,70,226,148,104,208,148,154,244,213,37,253,217,158,93,197,143,161,99,19,224,166,59,200,201,108,105,2,189,180,4,192,29,23,136,161,86,167,195,211,165,37,20,164,165,39,141,240,116,253,113,198,234,210,133,85,74,19,236,154,203,245,199,8,238,252,73,230,43,40,212,163,77,202,73,238,184,60,87,84,175,212,110,171,83,182,131,116,169,66,25,148,163,140,103,254,99,211,113,244,155,57,170,150,212,112,214,92,22,153,216,213,234,183,115,163,24,209,166,210,157,71,143,124,29,103,72,233,95,13,209,233,87,157,197,89,84,148,123,179,147,139,167,89,92,95,245,186,157,70,189,89,168,82,196,99,9,46,10,204,142,199,163,218,202,149,57,202,162,106,93,207,25,59,101,30,76,119,121,51,91,103,36,45,92,135,176,246,35,120,65,23,143,99,75,168,80,167,113,99,90,21,35,221,242,188,47,115,113,238,112,94,86,133,11,90,147,168,240,177,132,192,243,254,12,204,87,85,162,241,219,74,180,82,94,159,41,233,112,121,239,9,219,198,133,78,164,227,55,39,82,172,100,243,246,103,161,102,99,87,181,76,201,108,96,182,50,69,69,96,0,139,161,57,200,42,40,14,6,228,96,50,27,195,1,234,6,18,75,71,201,227,221,180,122,111,138,238,43,197,203,178,230,61,221,184,209,52,123,22,181,201,211,245,251,111,51,167,78,180,30,42,195,103,234,76,88,236,104,201,184,69,190,86,78,100,155,70,157,141,71,90,222,157,71,191,98,207,232,110,167,160,85,206,131,129,158,24,206,154,4,84,51,169,22,133,200,21,106,1,25,69,4,46,64,14,0,227,1,0,76,107,144,5,28,16,191,194,1,108,7,5,1,246,32,216,100,6,227,141,128,0,106,222,188,83,139,206,210,76,218,52,239,154,94,90,215,45,227,238,74,71,61,45,105,166,182,57,57,49,164,191,118,184,50,2,157,71,70,75,226,122,138,254,90,184,75,220,237,211,198,121,208,234,186,52,28,106,95,206,91,212,174,229,143,201,21,103,78,217,2,100,112,16,193,113,132,64,1,179,168,235,25,117,109,86,50,149,88,191,234,118,184,215,39,93,127,243,93,219,197,173,20,243,130,44,118,77,110,211,27,19,81,184,69,13,19,159,200,0,111,66,240,76,100,63,234,0,139,212,175,96,182,1,192,79,216,164,114,10,131,97,166,7,56,1,132,195,194,122,236,23,169,55,212,40,64,217,22,8,47,247,15,128,254,225,238,64,111,64,182,0,161,141,74,137,144,128,205,60,163,23,170,94,133,201,140,155,215,4,70,221,55,242,197,27,6,189,63,165,51,96,235,193,154,234,211,204,204,247,48,207,206,204,209,154,166,66,223,81,129,141,72,177,145,140,243,218,93,112,99,47,164,17,169,13,106,181,202,45,186,114,187,109,236,162,97,9,37,57,38,245,251,25,217,247,78,172,243,178,219,1,107,116,17,108,83,76,132,198,25,120,0,66,227,64,27,2,54,104,245,74,157,150,83,138,120,114,211,44,222,194,193,214,117,86,149,8,231,110,226,43,147,164,195,182,209,39,172,177,171,55,148,113,201,173,104,187,104,197,173,153,178,138,138,217,139,89,50,224,143,212,12,112,147,34,220,188,160,244,32,108,30,8,6,46,9,151,56,68,224,106,12,92,251,17,237,132,101,149,176,198,116,40,198,59,106,181,247,50,247,108,126,68,206,126,196,69,123,232,78,48,195,43,0,246,209,4,179,45,199,1,99,30,227,5,122,141,2,43,64,22,168,96,104,77,8,40,254,197,201,57,3,22,244,193,175,47,168,217,150,198,172,243,167,223,0,110,45,150,0,91,32,90,11,65,202,208,161,20,49,160,193,72,4,107,77,81,230,60,103,221,11,123,25,65,227,182,182,95,219,7,167,122,189,207,17,227,91,151,46,175,101,107,175,149,216,220,254,249,88,37,88,244,221,33,202,125,50,149,73,227,50,206,90,59,24,165,190,77,91,74,48,163,103,74,16,127,42,138,193,178,158,129,20,50,163,94,242,246,133,141,31,54,180,176,184,94,165,28,125,78,163,165,211,234,53,140,180,247,60,191,132,225,78,143,80,188,188,105,187,139,168,168,53,232,145,193,212,175,122,135,88,157,79,38,84,213,21,233,157,14,211,193,118,234,151,76,173,25,37,42,176,169,172,218,220,153,181,115,211,209,173,189,194,251,145,239,238,17,81,146,97,189,89,142,78,63,62,18,171,42,73,252,233,126,164,24,223,71,204,179,171,13,254,93,143,11,82,149,106,169,82,141,63,222,84,150,39,198,61,207,118,164,156,251,83,60,151,94,157,75,110,177,74,20,227,216,231,52,251,146,224,151,211,92,93,255,0,81,114,180,233,180,105,193,225,66,9,27,212,35,229,219,83,107,71,40,169,51,174,235,120,171,211,41,213,132,254,156,103,221,29,133,188,213,91,42,19,79,57,138,52,48,169,82,173,5,220,228,158,94,134,175,74,234,238,251,169,93,217,184,252,180,99,22,167,234,222,116,54,58,148,115,103,148,240,226,121,254,148,190,23,174,210,141,55,219,26,137,186,141,255,0,19,39,211,227,191,191,187,149,189,72,71,56,82,201,205,25,212,135,76,141,92,41,212,113,206,187,51,83,172,195,190,181,40,180,187,112,245,57,122,109,72,221,116,198,151,202,163,152,225,241,130,202,159,28,150,55,212,175,168,183,77,165,82,31,92,51,177,211,248,227,170,218,116,159,14,186,151,170,110,51,120,138,131,215,36,240,196,227,59,238,167,37,29,34,227,135,140,54,181,53,124,116,161,125,97,75,167,67,181,213,173,36,163,220,178,163,238,75,209,59,107,126,26,245,23,212,122,77,213,105,47,153,205,97,103,58,96,246,136,249,87,130,239,127,217,110,175,113,210,47,39,137,58,138,59,99,57,216,250,179,77,63,99,49,111,106,158,129,103,57,38,125,12,150,155,21,21,176,76,148,0,38,53,47,5,64,164,94,227,32,94,72,192,108,65,139,53,239,160,167,105,56,181,148,227,140,27,60,152,74,61,209,146,211,84,214,164,29,103,72,168,165,109,218,158,145,211,7,102,145,231,60,55,82,141,42,183,54,146,168,221,88,75,47,63,115,209,70,113,120,75,112,213,103,185,118,49,142,61,74,182,208,34,235,130,147,37,2,128,70,80,122,32,27,208,34,10,50,130,122,17,191,114,131,121,192,28,19,36,69,247,1,20,6,3,8,61,74,31,112,61,131,1,201,100,57,35,0,141,27,244,220,169,188,236,242,111,55,141,13,11,200,202,117,35,137,36,145,42,198,228,63,203,143,216,200,198,9,70,11,236,100,1,233,9,99,208,209,233,147,85,41,85,150,49,251,198,153,185,81,246,209,156,151,8,209,232,201,171,41,182,244,149,89,73,125,152,29,136,38,7,37,69,224,143,114,228,197,172,129,78,154,246,163,253,179,108,154,77,202,88,215,131,185,91,125,142,150,164,156,252,65,74,56,249,28,91,251,50,85,142,235,215,4,39,168,8,168,6,240,180,32,21,49,185,22,133,64,55,67,100,53,67,128,34,198,53,35,245,47,221,144,40,8,204,183,64,8,85,162,49,77,234,20,38,204,185,77,135,190,164,14,50,71,162,28,12,233,140,0,200,123,133,185,26,2,173,241,168,122,108,19,89,28,129,98,244,201,51,184,206,30,17,140,164,148,119,32,222,165,142,212,115,154,244,190,136,191,99,100,233,193,138,234,162,242,223,161,200,113,37,243,60,109,234,114,146,169,176,220,139,84,139,201,6,70,51,250,89,81,140,246,101,26,116,226,187,170,74,75,58,28,150,89,73,163,8,182,187,243,166,78,75,84,187,164,200,213,109,0,181,41,99,40,24,13,240,52,66,147,26,228,48,35,122,96,234,122,204,101,86,141,40,45,49,52,223,185,219,103,212,234,122,133,111,241,116,233,227,212,94,150,59,42,17,138,163,8,175,67,149,122,28,84,52,161,28,239,131,147,58,110,84,86,99,37,161,115,149,130,100,130,63,185,30,193,232,68,180,200,85,95,108,0,245,251,13,128,190,195,60,17,50,233,144,38,229,254,29,135,5,75,76,148,212,77,224,23,251,16,152,135,37,68,198,168,163,161,18,195,101,73,13,11,166,16,209,72,245,11,129,187,38,128,123,224,187,16,11,130,240,98,216,198,153,2,75,88,236,107,75,45,173,52,238,212,216,159,210,209,192,214,152,111,157,4,27,107,100,57,11,101,246,5,6,84,76,148,162,238,9,201,94,192,97,188,183,60,31,136,101,241,158,46,133,180,117,112,148,18,247,123,158,242,82,73,57,55,164,86,89,224,236,29,43,239,22,186,209,89,83,155,105,75,125,8,177,238,97,30,218,112,135,41,106,100,150,62,193,172,73,175,66,132,84,210,122,188,37,171,103,129,235,61,82,227,171,245,231,103,109,153,71,187,202,84,253,87,44,245,221,102,234,54,61,38,230,179,151,107,84,222,14,163,193,221,53,43,79,218,87,48,253,245,86,218,111,116,152,171,58,214,227,178,176,232,61,22,82,174,227,10,112,135,207,54,245,111,216,235,252,35,114,229,78,181,89,101,91,215,158,105,55,201,229,122,215,87,175,226,255,0,196,27,62,133,109,55,251,50,132,251,170,246,236,251,95,205,159,236,123,219,142,151,78,151,78,141,181,191,200,169,188,199,28,9,117,125,59,71,131,163,235,117,239,45,239,45,43,208,168,227,111,9,126,249,122,163,122,202,250,53,113,109,82,73,86,130,195,203,220,189,74,217,221,88,214,161,141,103,23,20,197,70,205,26,176,173,74,53,105,203,186,18,217,154,55,115,118,215,84,171,173,19,125,178,126,204,234,124,57,212,169,91,86,159,135,231,41,58,214,240,206,101,201,219,117,74,115,175,107,217,28,189,71,99,98,105,170,177,146,102,143,136,109,229,90,197,214,140,213,55,73,101,191,95,99,101,212,74,217,57,190,215,24,37,150,117,247,53,106,223,89,171,74,56,159,115,74,79,253,73,103,162,57,169,210,171,95,195,208,239,142,178,142,112,189,14,110,131,93,213,178,116,100,154,157,54,240,158,248,59,11,122,78,157,173,58,51,121,237,142,25,161,90,214,165,173,207,196,208,124,97,199,212,181,91,247,17,85,45,167,7,166,135,140,188,171,56,58,53,41,183,242,215,73,191,108,238,122,235,91,151,115,9,119,233,60,108,124,243,171,214,185,163,215,35,99,65,119,70,85,147,147,92,71,58,146,175,23,187,235,21,146,84,231,157,26,202,56,58,45,105,42,55,146,198,99,22,187,86,61,153,135,88,171,21,70,135,116,210,211,17,77,239,132,99,209,43,186,157,42,234,187,142,34,228,226,146,231,5,103,227,139,195,45,252,125,250,107,10,88,109,122,106,206,158,218,167,237,255,0,196,90,234,18,82,180,177,195,105,63,226,75,254,103,53,27,232,244,59,46,165,125,113,41,101,193,246,105,151,236,117,127,134,115,165,108,174,170,215,150,43,221,201,73,119,110,211,121,27,177,175,172,127,20,236,85,186,176,234,244,162,162,149,104,211,156,162,181,238,123,55,250,30,215,195,93,79,246,199,135,173,110,84,187,167,142,217,254,90,26,126,57,233,149,58,175,131,239,237,104,36,234,164,170,67,62,177,121,255,0,67,206,254,24,215,149,181,140,109,165,54,213,86,244,151,13,25,190,169,38,199,208,246,102,89,193,131,201,150,115,161,113,147,25,50,91,28,82,169,133,136,238,76,182,88,57,114,68,204,83,193,115,250,21,89,104,86,140,81,145,25,52,0,136,64,102,47,109,204,249,49,107,64,60,117,149,41,90,248,194,230,19,131,74,188,255,0,118,253,177,159,238,122,127,135,89,77,182,165,23,186,58,78,189,9,90,117,142,157,212,22,60,186,109,247,125,217,223,83,169,231,69,84,95,76,150,81,159,244,211,135,202,169,22,220,102,222,188,153,226,225,40,226,75,220,216,73,23,26,149,53,175,37,85,109,36,142,72,212,168,244,112,198,57,206,231,54,19,212,99,80,107,143,190,107,141,12,85,108,38,228,158,135,55,6,45,100,12,124,248,62,116,42,171,28,48,233,172,108,135,147,28,61,55,10,42,176,211,95,200,203,41,189,204,85,40,173,145,132,168,97,60,55,168,76,115,54,177,184,202,255,0,208,225,242,219,106,41,181,161,21,25,180,215,126,61,218,6,54,51,145,157,112,112,121,117,51,173,78,48,180,10,19,88,110,121,99,76,108,45,9,174,14,23,230,61,158,164,140,170,71,73,107,238,12,108,32,142,46,249,71,116,217,151,154,146,215,71,232,84,198,121,38,76,60,216,224,170,105,144,100,214,134,141,211,106,178,140,86,235,127,67,119,58,100,210,170,223,197,246,189,177,144,177,185,29,32,179,174,134,89,49,143,210,190,198,75,84,7,21,195,197,189,76,188,38,141,126,146,154,233,240,202,198,175,251,156,183,255,0,245,10,203,156,104,204,58,116,191,246,117,6,247,237,31,70,222,64,5,65,239,236,30,129,176,65,54,201,210,70,19,169,226,8,214,77,121,113,88,75,220,238,206,162,213,170,157,94,105,55,242,39,242,250,146,172,118,223,234,2,209,253,138,191,161,80,122,177,130,232,137,144,39,220,171,127,118,30,54,25,64,30,70,116,38,117,46,48,152,86,56,212,55,145,157,54,35,192,81,61,76,179,161,56,246,49,111,79,185,5,206,74,76,37,143,86,51,168,19,119,144,247,24,200,192,12,6,245,28,100,32,32,8,53,148,3,220,61,81,49,166,11,176,7,132,142,42,146,206,199,38,143,147,134,163,194,207,169,7,103,71,62,92,114,108,26,214,250,82,130,246,223,212,218,58,112,98,186,136,110,217,202,189,206,56,165,147,151,130,86,130,250,17,151,65,17,76,101,183,185,150,72,245,69,86,181,72,97,50,218,227,18,198,112,133,214,148,127,50,90,181,228,182,182,108,138,231,131,213,255,0,99,52,113,83,210,76,230,34,80,143,216,14,66,38,184,203,30,136,49,187,2,52,240,116,215,137,212,190,75,104,236,240,119,57,211,93,142,154,113,239,234,59,180,253,5,246,177,219,199,233,88,88,208,203,25,38,189,169,123,13,202,139,162,49,102,79,108,147,144,35,38,50,134,134,51,111,1,165,79,50,194,224,172,198,26,47,114,181,144,28,23,251,132,57,2,114,100,158,118,38,30,70,113,162,8,169,234,48,69,177,113,200,212,7,35,1,110,5,95,98,227,221,13,6,54,100,24,233,157,118,50,126,196,107,109,70,112,192,25,103,67,28,153,61,128,155,143,184,227,220,169,104,6,50,228,215,198,101,135,203,54,100,210,77,28,11,73,38,22,54,22,133,91,147,113,130,163,38,64,92,100,162,20,128,13,30,173,117,11,62,147,115,113,81,181,24,67,141,207,37,224,88,194,234,189,205,244,233,201,73,60,65,191,67,186,241,133,218,183,233,84,233,180,154,171,81,39,159,212,158,21,132,29,165,74,244,148,85,41,75,78,221,153,159,173,124,119,235,212,177,77,189,240,76,24,86,109,91,212,126,145,111,62,133,101,225,188,83,212,234,245,26,159,179,233,47,150,181,72,210,82,123,227,58,179,215,93,56,244,95,15,85,116,98,177,109,65,246,39,179,120,60,151,79,179,167,212,124,67,78,187,114,74,148,240,179,201,232,188,89,94,16,232,211,132,244,85,30,49,234,70,191,211,202,126,23,244,153,39,119,214,174,33,138,213,91,138,94,237,229,179,232,50,74,89,207,39,93,225,171,117,109,208,45,210,88,115,93,207,243,59,44,224,113,245,14,93,186,155,174,155,23,114,174,98,159,153,23,157,30,50,114,216,245,8,223,208,146,105,194,180,101,172,100,176,205,247,170,103,77,212,237,238,124,206,235,76,70,105,39,220,42,58,158,173,211,101,67,196,20,250,157,25,42,117,37,21,10,143,212,238,237,238,42,214,73,84,221,237,238,117,212,58,125,221,213,197,59,139,218,205,201,127,2,216,239,233,209,140,112,210,44,106,184,43,217,59,136,118,205,252,175,116,141,139,43,26,86,116,220,105,195,25,212,231,75,6,126,129,21,174,76,92,83,193,158,132,198,165,101,173,82,210,51,170,167,31,149,165,141,25,210,215,240,212,39,113,43,138,50,242,171,75,248,183,252,207,70,70,147,37,139,43,192,117,207,14,248,134,250,242,21,35,213,227,86,20,224,227,8,186,106,46,57,247,71,109,209,236,250,149,151,66,161,97,152,185,70,77,206,126,185,61,55,100,119,198,188,133,77,99,41,6,183,93,116,122,101,58,182,254,93,120,70,89,221,53,148,207,59,226,90,11,164,223,216,220,90,211,138,78,74,10,41,122,30,211,116,116,94,41,179,248,139,74,53,150,174,139,127,212,156,186,37,119,84,218,186,180,167,39,181,72,107,250,31,49,233,211,143,72,252,67,118,89,84,232,209,169,183,243,38,180,254,231,209,122,85,76,88,82,166,242,220,87,39,129,241,221,164,172,124,91,97,212,104,172,70,189,41,70,163,244,107,102,78,94,242,156,63,167,210,155,82,109,199,84,205,122,151,15,205,84,41,235,38,178,253,141,122,87,142,61,34,219,177,169,85,148,34,101,107,75,201,239,171,90,73,212,168,245,126,136,213,71,61,40,56,168,197,188,201,238,204,234,84,141,41,97,189,125,13,57,222,74,85,85,58,11,47,153,122,11,107,57,247,202,117,170,74,109,183,163,96,110,83,159,155,30,228,180,57,81,34,148,35,218,144,3,61,184,39,24,11,116,100,84,78,74,7,1,2,53,161,145,24,29,79,93,180,141,223,78,112,109,252,175,187,67,139,195,215,127,21,210,40,247,53,230,67,49,107,211,7,111,90,154,169,66,173,63,230,131,71,149,240,204,149,47,50,147,209,198,79,70,98,207,122,220,233,234,139,246,49,224,201,21,134,73,224,185,39,216,112,3,92,134,55,200,10,55,160,79,65,156,140,99,33,68,31,0,161,19,24,100,192,114,195,104,199,186,79,140,5,102,151,169,120,56,241,47,83,141,212,173,78,50,148,151,122,79,128,142,124,106,48,179,147,163,181,235,55,21,122,253,213,23,109,39,98,163,14,201,227,105,99,83,189,120,221,61,0,53,151,182,132,237,94,133,201,74,142,62,196,219,201,123,87,8,200,16,76,97,29,124,101,223,116,228,244,237,109,51,176,123,51,65,47,157,190,50,85,141,245,182,133,36,62,148,80,56,47,82,118,179,82,120,202,37,156,20,44,169,47,68,78,162,219,176,173,141,90,89,72,202,223,75,90,75,253,212,79,163,157,23,216,139,84,57,8,100,33,177,61,128,75,232,151,217,157,61,139,239,191,167,38,241,39,71,185,253,242,118,245,19,116,166,147,215,7,69,99,31,58,189,104,83,120,149,61,218,24,177,223,110,27,208,212,84,234,66,57,79,44,142,117,163,182,172,24,220,79,96,106,121,245,113,243,83,199,216,199,227,159,124,224,232,201,118,225,167,195,33,141,188,228,171,115,94,55,81,198,169,172,172,224,205,220,210,73,62,237,193,142,71,201,115,161,130,169,25,108,211,70,93,201,148,8,27,237,100,238,203,34,178,246,49,123,224,103,220,105,184,23,129,193,51,132,50,5,209,14,12,88,227,80,1,36,158,0,1,201,72,55,0,178,30,133,76,141,114,6,47,124,240,112,214,138,80,121,216,230,123,28,85,62,150,136,59,27,100,149,10,127,100,109,154,182,235,20,160,189,141,172,157,56,116,197,117,17,94,135,44,94,154,156,113,51,206,73,90,101,142,121,25,32,113,16,101,198,132,99,96,218,104,148,112,87,214,24,37,175,249,114,75,100,240,90,203,49,212,150,250,82,151,220,14,84,245,209,28,171,99,134,45,229,100,230,91,2,141,227,114,21,224,154,21,6,6,51,184,120,75,81,131,25,53,218,245,212,233,237,177,83,170,86,159,163,194,59,105,165,218,254,199,87,99,143,142,169,44,101,39,143,98,53,29,182,188,133,191,176,27,34,178,173,227,99,30,74,222,12,83,91,133,131,202,102,13,101,156,153,201,140,150,187,224,40,182,43,216,197,25,0,143,175,168,192,97,188,1,73,141,2,108,54,16,79,130,231,208,139,223,114,231,32,196,223,66,167,175,216,154,39,176,64,100,245,42,211,254,70,40,191,112,134,131,220,152,208,172,130,25,19,56,67,26,128,213,178,130,173,128,147,213,26,235,70,191,161,178,246,107,212,214,127,94,61,5,88,217,142,197,38,56,41,98,40,201,25,22,251,148,82,50,152,189,25,40,242,190,54,167,74,226,218,157,41,55,230,99,186,62,199,109,225,219,74,118,62,31,181,163,4,215,203,157,125,78,155,170,191,218,93,103,182,149,76,121,114,84,218,245,61,84,98,169,194,48,95,194,146,36,158,218,183,214,50,213,157,119,93,172,232,244,154,202,51,80,148,227,218,155,224,236,50,117,157,122,42,86,78,114,89,80,139,145,106,58,95,12,56,249,243,157,87,30,250,107,79,118,249,57,252,95,90,140,169,90,209,170,222,106,55,218,162,247,102,143,131,97,42,247,119,85,103,39,81,40,173,215,60,27,94,40,163,26,189,79,166,45,165,10,174,82,255,0,135,4,207,75,123,122,75,40,58,61,58,218,158,253,180,210,201,202,214,135,21,188,211,182,166,211,202,198,14,76,166,203,16,104,194,164,83,142,198,108,141,106,42,177,140,87,106,122,25,168,237,132,18,203,92,28,145,138,65,17,104,202,158,163,96,245,147,33,172,131,38,218,12,151,81,72,247,8,0,123,5,162,208,155,103,37,206,133,24,227,8,212,234,52,188,222,157,94,41,102,93,173,175,190,13,183,185,18,203,195,34,188,231,134,46,103,40,59,122,243,205,70,148,150,125,14,163,241,70,206,117,122,85,141,212,42,118,249,85,148,26,245,82,255,0,232,111,116,151,53,226,74,235,177,198,154,168,241,232,146,47,141,87,198,116,43,184,38,219,88,149,60,45,19,139,200,110,118,219,232,169,78,206,206,225,102,84,169,208,73,39,252,198,237,92,215,168,163,174,190,135,93,225,218,177,175,225,139,57,65,253,121,143,230,155,76,239,41,81,236,147,147,195,97,155,218,91,219,198,146,206,53,54,50,150,199,4,165,41,207,178,27,114,253,14,104,199,9,71,211,64,50,111,58,148,197,104,176,100,81,81,150,228,79,76,178,160,131,212,4,56,0,16,69,8,141,124,203,83,201,212,167,251,63,172,87,156,98,223,153,53,167,162,61,99,60,199,138,84,232,211,117,233,125,121,142,126,217,51,90,226,244,20,231,23,6,251,138,167,22,254,165,250,157,45,180,220,232,209,159,155,40,198,77,55,166,232,223,133,26,117,42,183,221,221,128,55,187,162,218,213,126,76,70,81,111,116,106,58,81,238,73,105,166,52,17,183,89,125,173,166,247,121,8,219,148,224,158,59,150,126,225,84,139,109,119,44,163,86,54,177,139,221,191,99,63,33,41,55,150,159,176,25,249,241,142,19,228,198,87,116,227,188,176,97,240,171,181,229,189,249,42,183,142,22,86,64,228,141,204,37,28,240,60,248,203,147,21,65,103,24,50,116,98,189,223,216,163,37,82,155,206,171,66,249,144,199,212,180,247,56,157,173,36,164,240,245,247,48,248,72,38,176,65,206,171,65,203,13,224,207,43,116,105,74,210,82,151,119,126,48,201,58,117,41,199,229,168,6,202,167,4,229,132,151,118,230,84,215,99,107,45,167,235,193,161,43,138,157,202,61,239,115,106,218,234,23,18,157,39,149,86,158,233,242,189,64,218,228,24,196,200,7,32,114,10,137,55,136,73,250,35,172,140,252,200,70,166,126,86,246,103,97,93,246,209,150,55,122,26,84,227,137,45,54,126,132,171,29,138,250,87,216,156,151,130,51,72,214,191,199,192,92,101,255,0,3,51,182,121,180,161,235,216,178,191,35,14,162,241,99,85,181,149,219,169,157,175,107,180,163,37,183,106,50,191,28,209,88,97,114,50,76,238,16,202,238,46,230,40,188,0,147,106,18,198,248,58,110,131,153,220,222,75,24,237,106,50,247,103,111,81,254,238,88,225,29,119,70,206,110,229,140,119,85,121,247,198,193,103,78,209,173,12,90,79,116,101,141,242,77,152,24,180,145,28,84,150,168,184,206,143,115,37,191,176,87,27,165,31,68,98,237,233,203,78,212,142,101,170,38,87,112,26,223,11,21,164,27,65,219,184,167,21,55,150,108,181,190,134,36,26,254,85,68,242,231,159,67,9,83,172,155,113,159,110,155,155,120,111,83,28,1,169,219,113,162,90,250,182,114,247,213,139,219,79,67,153,104,49,158,0,215,117,170,36,223,107,208,174,229,173,233,191,186,90,28,210,73,238,28,83,88,192,28,74,227,229,250,88,141,196,113,232,114,118,167,188,67,167,23,194,3,15,137,143,118,36,210,224,205,84,143,169,132,173,169,75,234,134,94,71,147,21,30,212,180,3,147,185,53,185,83,94,167,20,41,246,67,11,110,51,169,59,39,134,148,183,3,159,69,162,212,60,224,224,143,155,28,106,153,147,149,87,186,32,207,115,142,127,75,101,114,113,142,191,208,227,173,90,156,105,234,244,228,14,210,132,148,233,65,199,233,198,134,206,13,91,116,149,42,120,209,97,27,71,78,12,87,84,177,141,76,210,244,56,163,150,115,45,150,76,214,142,76,184,206,12,112,11,10,191,116,49,168,94,172,168,149,28,23,43,228,207,38,22,159,67,143,41,228,228,174,241,22,97,106,190,70,253,66,179,202,85,22,135,50,53,234,103,185,99,212,216,91,33,10,186,130,114,87,169,164,8,22,229,3,138,179,237,166,254,199,91,211,191,235,50,139,89,89,111,40,222,187,151,101,189,73,99,56,137,167,210,150,83,170,147,195,212,138,236,182,43,219,220,153,200,91,166,17,25,49,169,148,183,38,66,161,26,46,22,119,97,234,6,38,70,45,234,100,130,166,10,254,149,144,55,99,3,157,139,193,139,47,0,86,245,68,90,147,77,203,237,234,16,122,106,3,105,140,133,84,140,190,230,63,220,189,203,144,148,195,67,35,58,110,48,16,228,186,242,69,161,120,32,5,185,55,252,139,128,36,164,112,53,243,101,156,248,245,56,165,158,255,0,100,7,50,224,200,139,98,148,6,220,140,147,118,52,83,142,165,72,210,167,58,147,120,140,98,219,51,215,99,170,241,5,73,46,151,58,112,146,82,168,212,114,253,8,71,157,240,157,10,215,61,78,181,221,120,63,243,37,44,191,76,188,30,209,190,230,217,211,120,106,143,145,211,234,103,95,155,181,55,232,119,9,137,211,85,112,116,254,39,238,253,145,42,106,88,238,223,221,29,210,254,167,75,226,152,70,125,41,55,23,47,155,84,158,133,169,59,117,62,4,139,82,234,43,187,48,211,176,190,49,165,230,78,131,130,146,170,214,20,151,11,147,31,6,92,247,212,184,242,252,181,8,172,118,193,108,109,248,174,167,151,105,26,208,93,205,124,169,63,114,94,154,250,236,122,117,72,187,10,78,9,168,225,99,220,220,132,215,169,211,248,102,172,238,58,52,85,72,165,216,251,82,71,104,225,216,242,39,73,123,108,39,220,178,140,212,116,201,193,78,121,201,176,158,82,69,74,71,24,50,72,159,161,114,16,193,19,211,35,58,147,0,19,228,203,38,43,99,45,120,24,34,46,121,11,77,8,181,96,93,25,53,224,61,216,225,36,52,30,169,49,135,220,177,185,90,216,106,158,64,242,105,206,223,196,55,49,207,26,35,99,174,209,168,252,43,212,42,37,158,203,121,84,253,22,77,30,164,167,67,198,176,156,229,251,186,177,88,75,212,239,186,221,55,47,11,245,72,172,101,218,84,75,255,0,43,13,223,142,147,240,250,49,184,240,197,26,219,41,124,209,94,135,168,121,202,72,241,191,134,55,50,171,208,101,66,90,42,48,138,138,94,152,61,159,241,55,192,157,23,178,49,73,60,114,242,114,99,67,20,180,51,79,212,51,88,225,114,92,228,61,67,42,104,102,180,49,75,92,236,92,232,21,145,56,44,72,192,101,164,93,113,145,184,8,198,95,73,228,252,107,70,74,198,157,88,54,179,37,77,188,237,147,214,75,99,174,235,86,177,188,233,23,84,156,59,154,135,124,87,186,212,207,41,233,174,53,210,116,202,117,39,105,75,53,28,177,242,167,157,30,14,119,82,230,133,73,202,53,62,85,140,233,190,166,175,133,84,165,211,188,153,203,62,92,156,183,215,87,147,209,124,53,57,180,220,68,233,107,169,175,212,29,181,5,82,164,102,228,255,0,134,43,56,252,206,105,245,41,83,182,141,85,9,104,181,72,236,29,172,28,182,202,199,37,248,120,183,178,195,8,211,135,87,237,167,41,58,82,109,27,48,234,116,228,162,234,37,28,152,174,159,75,178,73,230,78,79,82,75,166,83,148,97,140,165,22,83,28,234,238,156,163,220,159,203,156,100,174,246,130,113,78,88,201,196,236,99,218,151,115,81,244,43,177,166,231,221,37,151,140,15,73,142,79,141,167,167,106,122,237,161,156,174,161,20,178,183,48,248,104,246,198,41,99,26,35,41,91,197,227,26,224,154,172,101,117,38,220,99,31,115,138,87,85,101,132,161,249,155,17,160,147,111,213,108,101,26,74,47,68,6,164,190,39,61,170,88,76,191,15,82,74,61,210,89,79,38,234,142,185,50,105,23,19,90,244,237,226,164,155,75,40,215,191,132,40,93,82,188,134,84,163,30,217,227,148,118,9,115,201,161,212,245,132,61,158,113,234,75,209,27,148,166,170,82,141,72,188,167,201,159,59,154,214,90,90,199,28,240,108,55,170,5,86,203,193,143,37,206,232,35,130,229,191,45,225,232,112,101,185,67,11,157,77,139,175,150,150,217,201,175,69,47,54,49,206,94,236,53,27,228,228,105,143,98,112,86,90,221,70,95,224,106,172,103,229,51,180,138,141,157,21,140,62,213,156,156,61,78,89,179,148,18,249,164,180,71,53,190,182,244,177,162,237,72,159,87,227,153,14,66,15,1,2,236,69,140,100,143,86,4,170,191,117,60,190,13,30,146,154,181,148,165,164,165,55,159,177,185,115,165,181,105,45,251,94,13,110,153,173,190,155,63,80,179,166,234,220,49,144,214,161,83,105,23,147,23,161,94,49,238,5,206,17,22,186,140,112,68,189,0,175,98,99,12,184,193,24,13,137,47,98,183,132,99,142,91,32,164,97,134,128,197,240,87,236,56,15,124,129,120,216,139,220,164,91,239,160,4,76,103,47,37,228,155,182,1,236,84,183,35,89,88,201,82,198,141,229,129,86,134,92,96,196,169,129,140,146,199,169,193,86,156,106,71,181,172,155,15,115,138,75,24,208,131,176,183,88,165,77,112,146,193,180,106,210,250,35,246,216,217,201,211,131,21,212,173,12,215,220,227,142,231,34,68,173,50,9,105,193,63,50,167,130,42,173,25,76,121,47,246,3,138,191,208,204,45,30,96,219,211,216,228,175,173,55,141,206,43,53,251,182,6,115,75,187,83,149,60,236,113,84,95,213,156,171,77,139,6,75,98,145,103,5,43,40,192,100,150,82,73,34,13,46,167,44,89,85,123,69,44,182,99,210,154,157,163,156,86,32,241,143,127,115,46,169,31,50,194,164,51,140,172,25,88,70,52,236,41,194,43,68,136,173,156,149,16,171,66,196,27,203,201,138,50,49,206,184,11,23,118,78,70,113,166,73,182,225,76,36,242,242,27,202,208,61,95,176,107,236,65,118,67,146,99,40,45,10,42,247,38,225,172,132,4,198,166,75,216,115,144,180,24,38,53,47,220,197,243,232,101,140,44,1,116,91,136,147,216,171,64,138,183,12,143,44,107,144,98,151,12,1,80,216,103,125,8,183,28,16,56,220,227,121,207,177,203,185,199,60,167,234,128,228,77,224,203,147,20,202,192,106,55,97,107,246,30,160,52,228,242,254,42,187,78,234,141,170,147,138,88,147,194,221,240,122,149,134,214,79,21,113,82,61,67,174,198,163,105,195,226,20,99,238,162,201,203,166,184,189,77,165,31,135,180,165,79,24,211,44,216,68,122,205,151,56,70,147,181,76,234,188,78,170,75,160,215,141,56,167,39,23,187,198,52,59,92,154,157,94,30,103,73,185,93,177,147,242,228,210,146,211,56,208,205,30,51,192,51,113,187,186,180,113,140,93,56,36,251,127,137,242,119,94,36,183,156,250,116,229,20,218,77,51,204,254,27,73,203,168,78,165,77,42,78,139,148,155,229,183,255,0,161,238,122,212,37,62,143,116,162,190,101,12,161,239,26,189,186,79,11,87,141,188,188,182,254,74,175,229,94,231,168,113,207,7,132,233,85,229,83,168,80,75,72,211,89,147,225,179,222,69,182,147,245,69,227,210,114,237,195,26,125,179,211,102,108,69,106,68,178,242,18,212,168,205,108,199,36,79,25,11,86,16,231,66,235,157,7,58,14,70,152,185,198,227,144,253,66,220,161,162,49,91,149,178,165,132,102,136,180,26,12,149,2,143,212,143,124,21,232,34,178,194,199,143,235,157,181,124,91,26,110,81,78,157,56,37,158,27,108,244,61,91,181,120,126,254,53,31,108,85,172,251,159,162,237,103,144,151,155,115,248,157,94,13,185,81,194,79,27,44,67,69,250,158,131,198,119,10,207,193,29,82,114,127,93,23,73,127,226,211,253,75,62,213,254,158,127,240,174,42,94,31,175,95,92,247,118,47,178,61,207,7,144,252,53,88,240,179,219,181,85,106,56,61,134,4,233,111,107,18,236,245,34,246,43,220,172,211,128,246,247,30,239,97,186,8,188,20,197,54,100,1,23,26,147,98,240,20,91,134,68,80,38,230,50,135,124,101,15,84,209,147,209,104,96,183,244,3,196,248,79,186,133,205,253,156,222,106,211,175,40,63,120,173,159,245,61,148,22,34,121,26,180,85,135,142,43,86,109,197,214,138,112,75,105,122,158,193,61,244,49,199,250,106,166,53,194,47,168,91,153,47,83,72,145,69,123,224,191,98,53,168,69,198,196,94,230,79,72,173,117,39,187,10,35,37,185,57,202,30,232,50,175,1,148,153,211,98,137,156,123,34,224,197,234,88,236,6,76,235,186,175,122,164,187,113,156,114,111,60,240,107,245,8,185,81,249,126,162,85,142,75,104,246,91,83,75,27,28,171,212,227,183,255,0,34,11,209,28,143,215,223,98,72,149,12,177,177,143,57,50,40,224,185,109,83,53,237,94,107,39,206,236,216,175,243,37,30,25,195,107,5,26,205,251,5,110,182,50,77,200,253,136,52,250,156,227,27,103,85,234,225,178,57,172,220,213,157,37,61,101,218,113,117,53,254,21,166,190,164,115,90,255,0,213,105,122,246,131,227,155,96,189,73,199,184,64,87,176,222,36,107,92,112,0,225,185,147,133,25,75,209,28,61,53,53,110,240,150,28,158,167,37,227,126,67,73,24,216,37,27,101,21,178,122,17,91,60,151,113,162,220,20,30,169,13,247,25,208,140,10,23,36,47,56,2,12,101,130,250,240,6,47,98,7,182,25,142,119,68,25,103,92,17,173,119,46,19,121,15,128,24,12,18,79,11,96,8,136,111,28,151,26,129,31,160,219,62,172,164,90,176,50,91,19,118,50,23,160,21,105,145,184,231,5,208,12,100,96,214,76,228,179,143,185,140,222,98,243,177,6,253,53,242,71,157,14,115,130,139,205,56,122,96,231,58,113,98,186,165,29,83,57,51,131,8,232,150,166,100,173,12,6,85,146,40,145,120,1,132,113,213,95,35,56,237,190,151,232,114,212,203,134,56,102,173,9,63,153,61,48,194,185,170,51,150,47,67,94,167,26,156,180,155,105,8,57,125,140,137,130,163,76,163,35,124,151,86,204,50,100,105,117,103,40,216,201,195,115,146,193,63,128,134,119,122,156,61,78,105,208,237,142,115,201,177,109,136,81,166,189,86,112,26,248,230,252,204,240,146,38,53,216,165,101,139,99,220,114,51,128,168,244,31,220,111,177,51,128,171,133,148,71,134,59,181,192,107,70,249,32,44,224,103,26,5,178,123,151,144,12,139,127,176,193,118,96,70,179,47,97,192,251,110,55,211,32,83,28,234,100,26,203,40,37,166,88,206,129,106,195,123,4,101,174,1,54,47,1,8,151,57,38,125,11,178,3,18,173,73,246,101,79,4,5,166,204,194,123,100,228,198,190,199,28,241,148,7,36,117,138,101,127,73,140,112,162,138,215,32,51,133,146,250,19,62,160,14,27,186,202,222,210,173,105,109,8,57,127,67,199,248,86,10,238,181,59,135,23,217,6,222,188,183,174,159,169,222,248,158,230,81,233,238,222,45,197,213,88,147,75,58,26,158,20,164,169,116,228,151,11,24,244,121,37,110,116,244,17,121,126,230,70,49,208,202,40,172,174,48,151,185,197,119,71,206,179,171,75,95,158,13,51,149,189,112,73,188,83,147,246,3,229,30,14,184,169,111,227,15,131,156,154,132,101,42,106,7,212,174,161,221,109,90,155,213,74,46,63,208,249,143,81,148,58,71,136,227,112,161,218,229,94,53,28,191,208,250,122,154,175,66,149,94,39,21,47,212,147,165,175,8,170,187,85,74,140,163,135,77,182,218,225,112,123,155,73,170,182,52,106,167,245,197,51,201,248,154,202,230,218,181,58,148,22,105,84,210,88,90,163,208,244,107,154,55,22,17,165,70,125,222,67,237,107,211,216,78,214,244,236,82,208,53,166,129,190,75,157,10,201,247,11,66,96,45,11,163,34,7,190,67,100,7,182,139,82,224,137,161,146,134,53,220,185,194,49,111,82,162,1,150,117,38,61,25,89,98,84,108,194,173,88,208,161,86,180,218,81,132,92,181,43,229,190,15,49,227,110,171,27,46,147,240,138,95,189,175,163,75,132,22,53,60,25,78,87,50,93,66,172,127,121,117,58,149,178,247,73,189,23,246,53,255,0,21,239,252,142,131,105,97,7,154,151,23,10,93,190,169,39,254,184,61,23,135,233,70,143,77,181,141,56,56,175,41,37,149,134,143,7,227,43,137,245,207,196,174,157,210,168,174,248,91,97,75,30,175,87,250,34,124,107,186,246,222,12,233,242,233,158,15,177,183,170,191,125,44,212,159,221,188,157,243,36,98,169,66,48,143,240,197,34,182,84,168,214,190,230,92,24,165,174,166,79,209,21,41,198,16,192,91,153,3,17,105,140,109,146,224,137,234,80,134,184,220,0,222,86,128,211,216,114,48,16,53,37,182,166,7,35,216,227,97,99,202,248,154,51,165,215,44,43,165,162,142,152,251,158,146,141,95,54,222,53,22,189,199,91,226,122,111,246,90,184,140,115,58,95,79,230,79,13,86,119,29,14,159,205,220,226,240,217,137,251,53,241,220,34,162,47,92,21,127,83,76,178,111,8,155,147,58,21,1,121,13,124,161,61,48,86,254,82,154,137,232,144,94,164,224,108,253,64,201,63,155,12,153,121,126,132,75,84,242,101,234,17,56,50,70,62,229,68,21,172,35,94,237,230,139,77,180,248,57,103,86,17,89,114,95,99,169,173,117,82,245,84,167,111,22,180,194,150,55,37,88,236,109,242,173,224,158,248,57,179,140,156,84,34,225,70,17,150,233,96,230,88,44,42,96,97,110,27,202,11,85,168,71,29,127,150,25,198,167,13,166,189,242,111,45,179,158,174,60,166,223,8,225,182,73,83,147,79,41,188,138,212,108,241,161,86,166,41,149,61,70,35,90,254,61,244,48,114,219,174,219,90,113,92,44,28,87,115,73,28,244,158,104,197,164,65,151,220,168,199,58,21,48,43,34,97,234,48,40,214,187,127,186,105,110,75,45,104,231,220,151,154,66,57,229,224,202,199,31,15,175,18,100,95,141,135,171,208,153,211,82,232,73,20,22,197,107,147,29,190,230,89,206,117,0,218,67,57,97,37,247,25,196,128,175,213,9,61,136,244,49,122,128,224,104,0,2,61,53,46,190,132,126,156,16,3,122,4,24,7,158,8,247,40,192,0,181,200,120,72,128,93,223,37,49,88,252,138,158,160,92,234,77,100,177,201,113,168,90,61,0,197,145,188,47,102,87,185,140,155,81,211,114,14,194,147,74,156,48,184,57,242,112,82,250,35,159,67,155,6,248,177,93,92,86,86,78,68,97,22,103,129,84,127,114,162,108,202,181,100,105,119,12,99,3,79,64,48,171,165,54,105,66,77,60,114,111,79,233,103,91,73,183,57,54,177,168,27,117,23,200,150,236,229,167,162,88,49,168,190,76,107,177,105,107,77,20,115,38,56,38,168,100,172,175,216,197,236,202,246,18,73,34,96,235,47,218,239,132,99,187,123,27,49,194,237,75,100,107,94,246,198,230,155,105,247,97,224,231,79,29,153,219,208,138,218,92,96,173,240,214,8,153,100,81,25,26,200,224,5,84,190,82,108,131,209,19,57,123,132,30,249,15,97,206,197,238,193,21,49,238,93,17,52,216,189,168,160,217,142,27,47,177,88,17,12,106,50,62,228,23,1,104,66,235,128,46,112,137,239,192,121,122,23,68,208,12,100,115,168,123,5,190,187,21,14,66,219,80,85,177,16,142,227,146,172,145,188,125,192,185,126,135,29,76,44,25,156,117,22,119,216,14,72,164,214,114,95,204,198,27,104,101,148,182,16,50,217,30,114,23,213,247,47,241,101,110,81,228,60,83,113,85,117,74,42,63,229,70,63,49,223,244,218,42,135,75,165,136,226,85,62,109,143,39,212,159,237,63,22,194,217,41,102,45,121,152,219,25,61,182,26,196,86,138,43,9,19,235,76,150,156,153,47,99,20,101,144,139,156,147,117,236,100,131,213,224,163,195,120,203,163,203,201,248,232,174,232,210,215,183,238,109,248,95,196,78,238,149,43,11,133,21,86,49,249,23,46,40,244,215,84,33,113,66,165,25,44,198,113,198,167,204,175,173,238,58,47,137,169,213,163,23,251,152,101,183,252,72,157,53,61,190,149,119,111,27,138,14,15,25,107,118,120,238,141,115,46,153,214,171,57,124,176,148,221,53,7,252,90,238,122,174,147,213,173,250,189,170,171,110,243,36,190,104,189,209,213,248,143,164,58,214,255,0,27,108,154,184,163,175,203,252,67,83,253,61,31,203,45,99,244,178,227,220,243,125,27,175,119,226,218,226,46,13,47,170,92,158,134,53,35,60,74,18,77,8,102,57,18,193,0,224,168,185,12,152,200,217,104,12,56,8,153,46,112,64,201,83,211,67,28,162,173,80,85,15,238,77,145,139,154,130,110,77,37,234,88,142,43,235,235,126,157,101,82,238,188,177,8,47,213,250,31,61,232,116,170,248,195,171,93,95,215,238,84,37,86,61,138,92,40,240,143,71,215,28,58,133,60,78,61,208,135,249,112,254,102,118,93,50,214,143,68,233,48,117,156,41,235,221,39,140,106,201,219,95,27,151,247,214,253,35,167,213,188,184,148,97,70,140,50,219,246,60,47,128,122,21,75,139,250,222,40,188,147,115,185,148,170,81,139,225,73,231,63,161,124,85,120,252,91,215,40,120,118,198,93,214,212,103,26,151,82,91,125,143,123,111,111,11,75,106,118,212,162,149,58,113,81,73,123,14,233,212,103,187,201,113,161,57,25,206,152,42,42,209,149,44,101,145,106,82,160,180,43,121,34,43,90,172,3,81,106,183,46,117,11,66,55,134,68,85,129,182,72,222,187,149,227,5,23,33,106,76,133,157,193,132,185,70,56,50,245,208,199,25,212,43,173,235,249,126,31,188,195,195,242,222,31,161,229,188,39,94,172,58,93,72,80,238,114,140,243,190,237,158,222,234,132,46,108,170,219,207,13,84,139,88,60,119,130,148,41,245,27,202,46,95,229,201,197,47,177,155,222,181,58,119,176,185,186,93,241,168,187,113,171,121,224,225,183,234,119,53,40,84,184,81,78,156,94,23,171,212,236,111,33,25,91,214,73,226,88,109,51,79,164,219,41,88,41,45,19,122,175,114,93,209,207,79,168,213,80,115,149,31,201,28,178,234,116,84,227,9,198,125,207,69,132,114,202,140,123,48,150,167,4,169,84,125,201,37,158,25,125,163,151,227,233,101,225,232,114,198,226,147,138,110,105,125,205,21,105,83,179,12,148,250,124,169,211,73,203,185,231,63,51,30,204,142,198,85,97,25,118,229,100,189,209,111,42,75,7,69,90,164,227,215,107,193,201,184,42,112,237,94,237,157,143,193,97,180,231,47,92,16,198,203,185,161,15,251,88,182,183,73,135,125,111,149,137,228,214,93,62,138,155,146,88,108,229,141,165,52,246,69,218,56,223,80,239,109,81,134,139,150,99,58,181,171,36,163,38,190,198,220,40,197,69,188,96,206,49,73,103,8,13,31,133,156,147,110,111,60,27,52,104,70,150,177,213,224,229,246,40,193,86,229,122,34,97,162,231,98,179,69,202,193,143,5,111,130,112,21,141,87,138,114,211,47,7,21,190,48,215,246,45,195,106,140,154,224,198,211,233,114,107,114,125,86,199,1,111,145,128,218,69,70,149,220,187,170,97,237,234,109,65,230,11,236,106,94,111,142,90,208,219,167,254,92,53,215,181,100,131,61,94,61,11,157,116,39,5,75,80,9,96,108,76,234,30,160,107,221,253,9,251,146,209,98,156,151,185,110,190,152,175,87,160,180,73,65,164,222,115,169,21,207,146,147,66,20,51,174,230,90,110,99,238,94,0,170,90,63,114,2,39,168,25,60,224,133,111,35,243,3,28,44,147,43,37,32,4,242,181,35,209,133,185,113,161,3,35,128,219,92,12,107,150,3,215,146,113,185,94,193,234,128,143,109,67,90,101,17,229,188,61,134,191,112,42,120,69,76,158,136,201,123,0,35,101,245,35,89,64,98,151,244,31,114,152,190,25,7,97,73,230,40,231,202,56,41,99,181,28,185,246,55,198,179,93,98,109,236,114,24,66,56,51,122,10,170,139,140,178,45,178,84,247,34,143,5,91,4,76,132,99,53,242,188,122,29,99,207,154,146,250,81,218,79,156,29,115,255,0,59,30,228,86,212,242,226,103,75,233,75,38,50,88,134,12,233,125,9,26,131,145,189,116,212,24,25,103,59,238,86,87,144,201,151,144,192,235,238,251,190,34,13,99,125,14,86,254,120,229,156,23,49,205,210,127,146,246,57,235,197,121,144,142,235,58,153,86,210,206,3,244,25,206,195,5,7,130,52,84,24,19,79,81,141,114,134,172,106,20,251,13,2,251,13,244,2,37,203,40,26,129,50,19,212,53,129,193,0,103,130,147,28,20,22,248,41,27,42,246,2,145,5,184,96,27,69,34,69,107,36,74,63,64,158,163,25,42,209,4,19,195,27,176,70,5,70,19,124,25,113,161,132,245,192,25,199,96,153,140,86,133,206,16,23,58,152,212,155,133,41,202,43,50,81,120,50,220,235,186,205,211,179,233,245,42,44,247,99,11,1,94,115,195,52,231,115,215,47,46,39,142,234,120,83,151,174,153,255,0,83,216,39,147,162,240,213,162,183,233,211,168,243,230,87,151,116,142,234,57,111,217,9,61,45,237,203,143,149,53,185,146,126,198,43,250,153,112,17,123,155,212,99,4,79,82,242,4,104,232,252,65,210,93,237,14,234,45,42,201,110,214,254,199,123,202,92,24,246,167,159,112,107,229,253,42,234,243,163,245,26,149,124,185,168,169,118,206,17,89,90,114,125,22,206,254,215,170,90,170,148,102,165,157,36,189,14,191,168,244,138,53,220,219,74,49,150,115,131,161,176,178,175,208,238,101,83,167,198,115,167,171,116,241,253,16,214,187,119,151,61,10,155,156,234,210,110,50,150,184,94,166,191,73,169,115,103,85,194,187,93,145,202,213,239,238,118,93,43,173,91,117,72,184,127,149,93,105,42,114,223,39,53,205,141,58,218,77,107,234,137,39,216,154,217,163,113,78,191,249,115,82,246,57,62,231,159,159,78,185,181,170,170,219,84,74,60,174,78,91,126,179,94,157,25,124,93,9,102,50,194,120,220,186,59,196,248,38,77,42,29,94,206,170,89,147,132,223,240,180,109,70,181,41,253,53,34,223,166,70,12,134,173,234,48,203,244,199,80,33,146,122,108,113,78,189,42,73,119,84,130,111,101,147,94,183,80,133,57,246,83,167,58,179,123,118,236,6,212,165,26,116,251,234,73,69,113,147,168,184,187,117,37,63,147,189,125,48,138,123,150,173,173,229,253,72,202,181,79,46,146,218,17,127,234,108,168,218,116,219,105,212,155,74,20,151,116,158,224,107,219,91,70,206,218,87,221,66,106,61,171,187,15,104,163,199,117,190,187,212,60,73,86,165,159,73,161,41,83,88,93,203,211,212,239,122,165,31,246,174,214,22,234,117,33,109,57,41,118,195,76,175,115,187,233,93,34,215,164,219,170,86,244,210,124,203,150,23,167,95,225,95,11,210,240,245,58,181,167,55,82,234,186,94,100,158,249,61,10,215,118,57,39,216,177,14,75,130,99,212,168,161,141,11,141,9,148,86,200,9,12,238,222,220,140,17,174,237,30,204,35,86,222,254,157,205,205,74,48,206,34,109,51,202,116,155,139,139,95,27,92,216,207,252,185,230,75,43,140,44,30,171,121,54,34,216,203,130,55,149,160,209,60,21,101,21,147,130,173,86,166,12,203,58,44,50,42,251,24,183,174,134,93,191,169,131,250,153,69,139,195,203,60,47,69,243,45,188,113,212,233,86,74,20,251,165,82,45,115,156,96,247,15,63,153,225,60,89,41,89,120,195,167,84,132,176,171,246,167,142,113,45,95,232,204,213,143,105,90,63,185,169,156,97,196,215,232,141,62,152,189,84,228,191,169,183,89,169,81,110,58,167,28,157,119,64,83,133,165,88,205,231,231,111,250,147,234,252,118,184,194,46,16,198,136,188,26,101,30,222,200,118,231,24,25,212,171,25,3,163,168,211,241,5,198,34,176,188,189,127,83,187,221,228,232,233,127,248,203,121,221,180,156,28,127,36,119,153,212,139,77,16,43,72,196,98,44,121,198,153,101,246,36,116,43,122,148,54,99,56,99,113,206,0,188,23,248,117,49,95,219,212,169,252,160,9,177,91,207,230,79,64,142,27,135,138,114,244,37,174,123,48,245,75,97,119,254,83,88,220,91,255,0,150,177,177,26,248,231,121,192,221,12,114,2,52,110,99,153,191,182,134,213,47,242,227,235,131,86,237,98,174,222,232,218,165,254,76,125,112,21,201,45,88,138,107,81,247,42,9,83,212,188,106,51,169,30,224,107,94,167,138,122,103,18,201,109,154,112,109,114,201,123,164,99,169,44,159,117,54,214,217,10,217,15,96,180,35,244,1,166,71,27,141,180,13,128,220,35,20,219,121,51,221,1,83,246,35,46,48,98,192,24,203,99,44,152,255,0,16,5,198,125,10,244,39,26,149,225,165,185,5,122,240,49,146,104,130,2,99,93,244,38,112,245,25,211,40,178,250,119,212,6,141,234,52,203,100,223,108,145,110,6,77,232,85,162,38,112,85,170,2,173,134,89,113,161,0,156,24,54,243,255,0,51,44,252,173,152,106,214,64,236,41,63,146,57,223,7,54,17,193,75,232,76,231,44,102,186,200,61,91,57,22,167,20,27,201,202,144,171,14,75,176,111,82,112,21,115,160,209,35,24,229,238,176,100,215,184,9,237,238,117,242,210,231,13,110,111,238,205,10,185,87,18,124,18,141,169,60,199,38,84,27,116,50,244,121,103,19,127,187,139,57,40,188,83,199,185,96,205,60,179,44,123,132,66,162,140,16,112,19,26,21,165,155,216,46,19,201,201,94,93,181,99,238,255,0,67,138,107,252,102,125,52,51,184,107,207,138,209,234,69,110,97,173,11,174,53,24,198,50,71,170,208,164,93,137,146,191,66,48,163,8,139,82,128,35,246,50,90,141,0,155,49,151,128,209,27,2,55,145,158,8,158,91,70,88,216,128,130,203,24,13,231,64,27,179,47,177,18,200,250,86,50,80,207,176,111,3,36,150,160,99,78,82,147,150,86,153,208,228,201,199,6,184,57,50,5,79,67,23,185,150,62,93,9,177,43,39,11,1,142,228,245,39,247,3,36,158,231,28,223,234,102,183,56,230,150,114,6,80,214,63,114,172,228,144,250,81,154,96,18,110,88,245,60,231,136,106,121,181,169,81,203,105,63,167,213,158,130,165,85,74,156,234,201,165,24,166,217,230,186,98,151,84,235,21,46,106,47,221,210,213,69,175,208,53,29,237,181,21,66,206,156,26,196,146,212,206,11,25,201,149,89,235,176,142,168,12,227,171,43,102,43,69,234,84,6,89,68,228,50,100,168,201,232,223,169,9,187,47,185,4,151,111,107,77,101,179,82,173,23,7,152,188,167,199,161,182,26,76,18,188,183,80,232,174,164,165,94,210,94,85,100,251,162,227,234,109,216,117,233,194,140,105,245,42,78,51,90,121,137,110,119,157,145,229,26,149,236,105,214,142,37,20,211,220,13,136,74,141,196,20,232,213,140,227,190,140,198,165,188,42,37,25,69,53,185,211,85,233,55,22,127,63,78,172,169,182,190,153,106,153,201,103,214,111,99,85,211,190,182,249,49,136,206,43,113,191,23,27,147,233,86,245,42,249,157,145,82,91,52,112,79,162,211,213,211,169,56,75,57,88,121,54,41,117,123,58,180,220,187,154,75,216,213,255,0,105,44,85,21,82,110,81,139,109,38,246,208,105,172,97,97,212,169,227,23,178,223,77,50,205,133,105,115,42,157,245,46,103,44,113,193,149,62,185,211,234,201,198,21,91,120,206,136,84,235,22,84,96,229,58,142,62,205,110,48,213,167,211,168,170,170,114,166,165,45,243,45,77,213,8,65,125,41,29,85,79,19,244,154,48,139,117,223,115,218,56,212,235,234,120,186,62,100,146,84,105,197,108,228,242,223,228,54,30,222,151,186,16,89,148,163,24,173,242,207,45,212,42,84,235,221,70,93,54,197,184,219,70,107,226,106,250,175,67,130,157,123,190,181,115,217,111,26,138,50,214,117,36,154,138,94,199,166,233,189,58,143,75,181,84,40,235,151,221,41,61,228,249,108,118,116,229,180,181,133,165,40,210,166,146,75,148,108,145,7,254,133,21,21,147,132,57,40,48,153,72,64,244,101,224,45,30,112,2,42,67,248,145,127,132,197,188,44,133,121,94,177,25,88,248,150,157,250,122,203,182,7,170,90,197,75,134,121,47,25,57,83,185,180,171,143,151,181,253,155,59,222,133,115,241,157,30,141,103,44,188,97,251,19,86,187,13,216,123,141,152,201,89,69,201,87,12,37,144,180,3,63,185,198,183,51,206,114,113,255,0,19,41,21,234,189,143,23,227,202,46,55,93,22,242,49,204,163,86,84,151,230,179,254,135,180,109,35,204,248,226,140,171,244,107,110,221,227,93,60,250,25,171,29,181,181,87,30,153,20,245,106,24,77,156,125,29,118,83,148,95,223,44,211,233,53,99,87,161,246,198,171,156,224,176,228,253,78,195,167,97,211,82,90,250,145,93,128,90,44,13,8,205,34,190,9,132,244,47,13,250,5,174,193,29,54,83,235,146,139,143,204,176,179,236,119,14,41,61,63,51,168,113,238,234,112,113,122,247,106,206,221,183,221,185,34,153,196,177,201,91,38,53,79,144,202,25,216,163,140,140,16,100,182,34,223,36,203,70,75,233,42,26,231,66,114,19,215,64,222,0,45,116,42,211,95,200,145,210,89,25,194,192,49,195,113,143,41,233,150,75,95,242,180,50,185,199,146,244,37,174,60,173,8,174,126,9,140,17,189,70,2,53,46,177,42,254,186,27,20,191,203,137,171,116,218,168,240,253,205,154,31,228,71,213,234,194,185,147,244,28,179,29,87,37,91,0,68,120,30,195,58,129,195,114,151,106,111,212,194,203,252,182,243,201,157,210,238,164,151,29,217,201,199,99,159,134,105,199,15,185,233,249,143,163,105,144,50,183,166,0,197,107,156,236,87,167,216,108,71,174,153,0,138,183,34,208,200,7,161,27,43,122,224,128,27,215,6,58,185,123,23,127,249,147,41,61,53,96,49,145,246,8,103,125,8,15,45,172,6,214,73,182,52,15,87,140,129,20,178,202,214,155,253,136,177,159,114,234,1,99,26,147,76,32,211,203,225,4,5,199,119,228,100,182,38,197,64,100,182,49,206,129,45,72,214,88,24,201,240,68,155,88,224,72,199,56,206,17,7,101,73,252,139,208,231,208,215,165,172,34,253,141,140,253,205,198,107,169,140,163,23,151,36,140,103,125,70,154,109,207,66,42,9,238,95,134,164,246,130,252,201,86,48,87,212,231,243,71,45,123,156,145,187,165,53,150,241,143,82,187,120,109,218,191,64,237,224,222,48,176,69,244,202,55,84,51,133,85,55,232,101,26,176,150,90,154,103,15,193,210,75,29,191,161,198,236,97,195,105,46,50,93,27,157,203,27,175,212,208,184,120,169,186,193,140,186,127,204,228,231,44,227,77,116,52,238,108,106,67,95,136,169,156,233,232,65,216,183,154,72,216,163,244,35,78,139,146,161,137,60,175,83,110,142,148,242,203,18,185,144,217,152,167,152,166,139,176,129,185,115,132,244,216,35,25,37,219,238,88,52,103,255,0,89,126,153,48,185,156,41,221,210,140,158,141,238,101,81,230,231,30,134,189,197,56,214,185,138,148,83,193,42,187,126,245,46,83,69,217,165,235,169,163,228,77,175,150,114,138,244,193,148,40,78,26,249,141,132,198,230,86,72,218,124,154,158,85,95,231,42,163,83,249,158,66,182,150,54,31,153,171,42,19,147,79,189,172,17,80,170,227,143,50,88,227,3,70,226,247,39,116,115,171,53,85,180,176,190,119,250,238,100,233,73,227,47,68,52,115,74,172,19,198,86,51,140,146,117,33,21,245,35,89,219,101,229,183,167,6,78,134,189,217,215,208,104,230,85,96,214,251,153,41,199,25,238,95,169,171,27,78,220,252,239,86,95,133,94,172,13,158,232,255,0,50,43,194,123,163,91,225,176,179,221,156,109,147,10,180,42,75,31,59,95,96,55,50,132,166,150,143,25,52,188,154,139,78,231,235,147,145,82,158,55,111,28,177,163,99,49,219,40,101,55,133,185,171,228,207,187,73,50,170,83,142,170,90,129,157,40,56,92,74,93,255,0,43,254,19,99,41,236,205,47,46,182,116,153,151,151,87,108,166,192,219,206,8,222,166,186,141,95,227,150,126,196,82,168,178,159,228,17,177,141,114,100,181,102,171,169,85,44,40,229,153,66,172,210,110,80,198,128,198,211,145,195,60,247,125,140,126,33,44,101,52,217,132,174,35,41,225,111,232,21,205,13,178,103,193,197,10,177,75,125,204,157,88,40,57,74,88,94,161,29,127,92,185,133,11,40,210,114,249,234,188,40,250,163,46,145,105,240,182,77,191,174,172,156,159,219,131,172,82,93,91,173,57,63,154,20,116,129,232,22,137,46,49,130,125,95,140,100,140,163,147,29,91,216,177,202,120,223,212,163,144,53,160,122,32,3,5,126,228,200,107,58,151,64,103,70,76,234,82,32,181,212,108,80,192,156,149,99,58,178,17,44,203,96,58,122,157,90,148,186,203,179,250,82,90,103,118,206,201,82,77,97,164,209,226,250,131,84,252,81,82,80,127,52,100,146,92,247,51,219,195,42,156,91,213,227,15,4,238,181,103,167,95,113,210,109,235,229,44,195,43,24,79,7,91,254,206,66,20,149,58,114,151,108,115,133,45,79,73,218,191,34,164,141,36,244,232,236,58,61,91,62,231,230,65,185,239,242,155,87,22,46,181,179,166,148,126,109,164,214,199,101,141,67,213,37,192,30,82,223,193,246,49,159,117,116,234,79,57,109,105,147,185,180,232,246,54,157,206,149,188,19,124,181,147,176,113,77,61,2,68,193,140,34,227,20,162,146,94,137,25,165,249,151,5,40,100,47,70,67,37,184,7,186,5,126,196,40,61,198,53,124,140,234,18,2,160,181,201,48,88,172,100,11,186,49,147,206,134,70,12,131,207,248,209,37,209,163,87,56,80,249,115,142,94,136,207,193,117,97,46,131,74,10,95,50,143,115,251,157,143,86,180,133,255,0,78,149,10,144,238,91,165,238,116,254,22,169,26,114,157,175,240,168,246,173,53,209,153,189,172,233,233,117,33,90,39,230,105,23,57,70,73,44,110,99,232,55,126,193,21,24,53,243,100,207,131,138,79,18,3,39,193,161,214,233,70,183,71,175,220,178,169,174,252,125,141,236,233,238,112,93,165,46,157,117,25,61,37,74,75,245,65,101,121,79,15,213,84,186,53,101,157,101,153,44,30,151,166,197,170,17,203,215,181,54,120,111,15,186,212,108,227,78,113,78,77,236,248,61,213,132,187,169,185,53,166,18,49,199,117,121,118,222,92,122,21,145,13,77,160,138,180,199,4,217,125,203,134,248,212,14,162,149,101,62,189,59,117,140,71,50,88,59,119,170,201,212,218,67,62,35,175,38,181,141,44,231,238,118,207,229,51,4,91,13,198,172,108,81,120,75,208,169,173,76,113,147,36,128,104,27,28,142,11,169,130,24,200,67,157,0,61,240,36,181,75,220,164,211,32,107,223,73,83,160,228,219,194,225,22,209,127,135,142,155,152,222,203,178,138,147,95,43,150,25,201,69,98,148,99,182,17,21,202,84,204,95,220,169,149,26,119,74,42,171,121,224,230,182,207,195,197,179,134,242,58,41,123,28,182,178,114,180,129,149,115,53,183,184,94,196,203,114,198,52,41,67,12,175,27,145,13,244,3,134,226,61,212,154,231,131,11,55,41,82,204,183,57,106,167,240,243,75,124,26,157,46,170,171,106,156,117,245,100,250,55,117,200,121,107,5,221,111,130,107,203,40,54,27,212,50,45,114,5,206,12,140,30,114,176,100,158,86,64,103,63,114,125,195,121,107,208,103,92,0,201,31,5,247,34,88,120,198,80,4,176,223,216,143,93,139,156,104,182,49,211,98,11,159,182,132,111,86,204,146,215,83,7,203,2,106,204,179,167,185,17,82,215,80,13,182,93,151,160,206,161,224,10,180,11,67,29,112,93,115,160,21,191,77,200,181,231,80,181,121,96,12,101,148,204,92,154,199,220,173,152,55,177,21,218,83,250,81,205,249,154,240,127,36,126,198,193,184,197,117,198,91,108,98,180,247,50,37,83,33,108,2,34,175,176,101,72,171,236,6,13,26,151,75,56,108,222,146,53,171,197,96,13,88,233,77,155,116,211,242,215,174,13,101,254,134,213,31,242,209,96,228,130,106,11,37,96,54,81,53,13,149,108,70,7,95,89,165,93,69,189,222,81,134,36,239,33,141,179,169,201,94,9,221,66,82,89,107,98,173,110,32,241,187,34,55,91,200,198,131,242,43,219,66,137,218,82,20,42,96,187,34,100,106,192,2,60,240,92,234,4,224,53,236,10,192,197,12,96,168,173,96,9,141,12,112,222,11,39,161,82,201,40,155,44,23,76,21,32,88,35,9,13,135,175,0,48,183,46,50,193,112,6,47,113,218,153,88,109,144,48,137,129,147,46,10,140,60,165,143,118,112,58,120,155,201,178,217,198,215,204,217,21,199,228,199,183,147,171,234,144,171,85,211,183,131,113,167,39,243,181,232,119,73,37,19,169,186,169,40,220,57,77,181,21,178,8,220,180,182,163,105,71,178,140,20,115,187,57,215,161,171,105,91,205,79,213,27,107,70,21,140,180,50,130,228,198,89,238,219,67,36,241,176,25,54,25,27,123,162,189,128,184,35,92,5,250,6,5,225,12,147,129,192,20,54,12,83,105,100,35,45,30,130,63,86,164,142,112,202,155,77,227,144,62,125,42,106,227,198,247,87,18,158,148,106,37,21,198,199,190,206,99,22,185,71,206,114,233,120,166,238,156,53,78,239,86,255,0,95,245,62,141,31,162,57,225,19,139,124,151,92,122,34,167,132,181,12,21,145,110,30,168,175,102,98,151,25,0,194,209,9,60,110,50,6,68,27,17,99,95,238,81,75,182,9,198,229,2,173,64,67,37,6,22,72,247,46,64,113,161,94,196,229,21,160,38,76,91,249,146,43,219,25,38,57,220,130,73,41,105,193,230,236,148,173,60,72,232,193,254,238,77,188,227,215,131,210,113,143,204,243,93,70,162,180,241,5,54,242,225,40,247,44,240,209,158,75,30,162,90,54,76,17,75,52,225,44,253,73,50,154,69,28,19,62,133,111,64,13,233,140,28,15,252,205,118,57,183,71,12,154,238,100,70,88,195,207,169,193,123,39,27,58,143,77,177,171,208,231,127,195,161,171,125,21,43,90,137,227,24,216,164,121,184,116,185,90,170,53,37,83,185,207,50,88,122,30,151,167,235,107,175,169,215,117,56,202,61,34,149,72,225,74,156,73,225,62,163,30,175,208,99,116,164,178,170,202,15,30,169,224,196,253,154,255,0,110,249,60,162,152,165,131,36,109,4,87,190,120,35,107,56,56,110,43,84,82,197,53,140,45,91,3,82,194,106,93,94,249,60,119,211,81,79,243,216,236,79,59,209,235,215,159,82,234,110,73,57,58,145,89,94,152,59,168,202,174,62,109,201,58,27,57,224,153,203,56,85,89,99,12,70,191,204,187,150,52,3,157,111,145,157,48,107,198,225,183,151,22,95,137,134,113,174,64,231,72,69,100,225,87,16,107,57,107,82,198,226,24,255,0,80,57,121,200,206,167,27,175,79,183,57,208,207,186,45,39,220,154,101,209,155,219,36,38,99,204,145,83,238,228,35,86,255,0,230,133,58,105,172,185,100,231,138,125,171,236,107,94,53,230,83,146,225,232,109,103,48,76,135,196,238,207,177,146,120,34,75,126,66,209,136,53,175,115,216,190,230,118,127,245,88,250,152,221,172,199,11,113,103,44,208,89,198,86,225,91,56,195,202,97,16,108,192,186,5,163,38,224,4,254,137,255,0,194,206,163,195,173,66,214,116,51,151,73,181,253,78,210,164,187,105,201,231,100,117,29,37,194,141,213,196,32,222,38,220,182,3,186,134,171,4,195,193,98,255,0,92,100,110,6,41,96,103,24,69,199,161,49,176,18,77,174,3,209,111,185,94,88,107,68,128,186,104,136,209,31,212,180,213,25,101,129,22,200,54,181,225,137,104,179,201,53,203,0,241,159,112,215,36,107,13,178,234,209,4,206,116,90,19,140,44,149,164,146,34,198,172,3,252,128,200,90,110,5,79,87,143,65,239,187,11,60,5,249,1,118,228,185,88,35,198,80,79,80,43,223,78,76,86,18,215,82,203,93,140,118,90,129,139,245,49,151,24,70,79,108,152,57,126,97,93,165,63,242,162,253,142,95,204,226,167,143,42,62,203,99,151,242,53,25,174,189,44,182,102,150,135,28,94,76,243,166,9,69,211,37,88,34,42,75,211,36,86,89,92,19,187,208,156,140,250,4,86,219,71,5,79,165,179,151,143,115,25,125,12,43,73,75,230,198,230,237,47,242,209,165,132,234,45,26,126,166,244,23,201,31,177,96,201,176,49,238,76,154,25,61,136,200,179,249,21,239,128,52,46,127,235,16,254,166,43,31,25,12,238,115,215,138,117,35,44,106,142,24,190,235,248,109,147,35,125,45,0,246,5,19,147,38,242,180,32,127,73,68,212,52,199,177,72,32,107,65,192,122,160,4,40,90,13,19,1,134,52,123,141,13,63,48,159,4,220,164,1,192,76,23,68,195,92,228,175,96,51,145,160,140,178,98,93,145,3,243,34,221,140,228,0,40,41,98,35,193,199,45,240,103,140,179,141,233,33,85,150,48,177,253,141,43,218,74,165,54,177,150,110,236,178,113,201,41,61,118,8,233,109,170,74,222,191,150,251,148,94,189,204,238,227,40,202,9,166,159,216,212,175,103,26,147,238,142,20,177,131,77,92,206,218,183,110,27,73,234,137,210,187,86,242,102,158,23,169,167,66,238,157,84,229,223,21,237,157,141,168,53,63,167,92,1,200,184,42,220,197,53,156,103,38,64,87,185,22,115,141,199,220,61,189,2,9,235,140,7,168,206,55,35,250,66,169,30,132,79,108,178,231,80,149,83,53,175,110,29,173,187,170,150,90,123,123,28,249,121,206,52,58,127,16,92,186,86,209,142,113,222,240,47,162,60,159,70,207,87,241,21,90,148,214,51,120,167,60,255,0,42,77,127,162,62,138,254,166,120,159,3,80,140,122,149,220,147,238,73,60,191,115,217,47,232,140,241,233,174,93,185,51,134,93,94,207,6,43,233,122,25,26,69,193,22,27,120,224,61,117,68,213,44,32,36,179,141,138,182,200,43,195,88,2,251,145,33,246,5,130,105,183,38,73,107,159,98,61,253,194,208,11,195,28,17,23,94,117,2,127,114,174,61,72,244,8,12,176,27,211,4,200,101,16,125,198,228,123,16,49,171,58,15,21,175,38,210,157,212,35,243,44,199,45,29,251,109,52,116,222,40,75,246,13,196,167,180,92,90,253,76,246,179,183,101,107,87,205,176,182,168,176,211,130,122,28,235,85,156,157,127,66,207,236,107,120,205,99,177,97,96,236,18,202,105,114,105,12,153,35,7,136,203,14,75,38,189,123,235,122,50,74,85,98,147,94,160,109,191,161,227,133,147,167,165,125,241,23,83,167,29,20,90,70,221,199,88,179,182,181,117,179,58,169,175,251,53,147,198,208,235,116,173,175,221,205,75,122,202,21,42,97,225,44,234,22,71,187,111,187,85,177,197,94,151,155,66,112,123,52,105,71,173,81,236,243,29,25,170,126,175,115,146,61,86,218,164,91,74,106,45,114,129,140,47,169,103,162,212,74,61,205,69,255,0,99,164,252,60,183,141,175,135,174,168,197,73,69,93,201,197,61,240,210,207,245,201,216,87,235,170,52,28,40,218,206,125,186,107,162,102,135,70,187,234,10,117,168,91,218,194,17,117,187,231,38,184,124,34,103,189,30,185,237,130,69,231,78,80,238,204,153,98,177,159,114,234,46,57,48,168,190,73,63,102,100,179,201,43,56,170,19,114,225,104,128,234,122,28,62,123,154,184,199,153,44,191,203,67,184,105,51,172,232,249,248,121,101,97,228,236,247,73,147,226,86,61,186,227,76,5,4,179,161,151,33,111,168,86,62,92,95,28,145,210,135,8,205,142,10,56,252,152,227,24,49,118,240,108,231,73,7,142,225,134,184,29,186,194,92,34,124,60,83,109,54,141,135,236,66,14,31,45,227,81,229,203,212,231,224,45,128,235,174,251,215,98,139,89,223,99,97,78,170,140,91,213,37,168,185,214,81,246,57,146,249,16,28,80,172,240,158,52,51,243,87,161,146,140,87,6,93,177,244,3,78,246,105,66,53,19,194,139,212,206,205,175,37,227,102,242,78,161,7,43,26,173,99,185,45,19,229,152,244,200,230,197,55,140,231,18,199,169,62,141,205,57,27,225,134,146,26,21,5,187,100,155,194,199,38,95,99,138,166,86,49,203,10,85,77,209,151,216,233,58,91,255,0,218,109,181,164,160,218,59,217,84,81,165,45,52,198,15,59,211,166,229,114,233,173,103,24,180,201,86,61,28,121,68,77,99,25,212,213,138,172,223,220,177,132,212,178,223,254,129,27,77,61,8,241,131,94,94,103,13,235,234,204,99,42,159,197,184,27,57,255,0,229,21,103,181,235,169,172,231,56,152,170,211,79,87,166,219,1,177,156,60,179,45,253,77,73,220,202,13,36,181,101,87,143,24,113,198,57,26,54,218,88,102,57,209,224,226,243,242,150,140,158,122,206,48,242,134,142,108,231,86,59,177,177,130,171,23,171,120,10,112,245,3,44,239,238,93,90,126,136,193,191,71,161,148,31,118,112,1,147,56,42,127,152,198,160,94,237,62,229,75,31,153,140,158,112,102,158,158,224,76,182,222,8,147,198,74,244,122,19,13,167,150,1,234,146,39,15,63,144,203,90,100,146,121,88,32,197,179,9,97,201,45,140,240,96,245,158,155,21,93,164,31,238,214,61,14,76,251,51,142,31,229,163,151,185,26,140,186,216,180,142,68,243,169,199,21,141,89,201,186,37,85,254,198,75,216,197,23,98,3,8,34,231,26,0,228,194,107,229,102,123,18,122,199,64,58,236,190,252,251,157,133,63,165,35,175,171,165,120,164,249,59,24,39,218,179,232,88,50,245,228,134,72,143,66,140,120,15,114,145,129,193,91,230,148,112,107,82,89,234,57,246,208,218,169,162,201,173,109,151,121,38,211,219,114,13,253,144,27,145,20,51,175,185,112,217,26,212,160,92,99,125,76,90,212,184,201,48,4,221,149,236,22,195,58,10,33,112,22,187,2,8,30,193,106,0,156,20,16,10,92,105,147,20,180,220,160,51,161,49,156,151,33,1,70,196,25,126,160,26,202,100,94,225,60,229,148,6,75,158,89,0,17,203,93,14,63,226,211,115,145,172,44,156,88,93,217,26,51,109,224,226,127,86,14,70,242,183,193,199,135,156,141,70,120,202,255,0,83,86,189,5,81,188,227,38,222,19,49,105,97,189,0,242,157,106,175,236,158,149,94,226,56,83,115,75,24,56,60,51,212,110,37,26,213,156,164,219,73,73,55,147,91,241,18,231,178,211,167,219,193,127,155,89,247,115,199,254,166,247,133,232,121,116,106,210,113,204,149,56,229,50,72,214,122,119,118,221,90,17,148,252,206,112,110,83,234,22,181,103,216,170,40,188,103,18,103,93,97,99,25,74,188,234,199,87,47,149,123,27,47,166,81,106,95,46,59,183,8,222,149,213,188,82,126,116,90,126,230,18,189,182,75,185,215,134,54,88,102,154,233,116,211,198,90,194,198,17,140,122,61,10,109,181,28,55,185,116,200,222,93,66,214,75,229,175,7,143,114,85,234,22,116,160,220,235,197,26,159,178,168,41,119,99,30,200,126,203,162,241,39,5,44,109,146,104,205,117,123,57,201,198,18,114,150,116,194,19,234,244,97,55,25,91,215,147,198,142,17,201,203,78,214,141,56,226,52,226,191,35,145,81,138,89,199,230,6,172,122,164,171,229,82,181,170,151,172,244,58,111,21,78,126,69,189,89,69,225,75,13,35,210,168,69,113,169,208,248,155,167,92,117,27,106,116,232,84,237,80,249,165,234,197,89,219,95,193,54,191,15,101,117,89,183,138,213,51,22,207,82,178,227,131,169,233,52,225,109,211,168,218,167,172,86,185,229,157,138,114,131,28,125,66,246,231,215,98,163,142,50,215,6,121,89,194,102,153,101,182,195,66,114,70,214,119,10,53,170,101,90,28,110,73,52,155,253,12,251,147,24,106,130,55,132,241,201,198,229,38,240,158,0,229,202,6,17,143,110,27,221,153,189,192,109,176,238,193,173,113,117,74,214,14,85,234,40,37,234,116,213,122,165,205,78,233,219,81,147,143,240,185,114,75,76,122,39,174,172,139,208,232,99,214,47,33,77,58,150,237,225,107,131,179,135,81,162,233,247,203,49,211,47,60,19,96,221,217,236,70,150,77,9,245,139,78,199,46,233,56,45,228,145,143,237,139,126,213,40,169,201,61,180,46,152,236,94,196,90,239,131,170,93,101,212,214,52,42,118,231,148,85,213,164,231,133,109,55,239,141,16,92,118,146,58,143,20,83,149,94,133,38,146,113,140,147,146,51,169,212,238,97,219,58,86,253,241,231,58,28,55,127,29,125,211,106,210,236,138,148,248,96,142,62,149,212,232,218,244,248,211,171,44,183,172,82,228,231,87,215,23,181,165,27,104,121,116,82,121,147,90,228,227,233,157,27,201,163,9,92,246,206,172,86,154,108,118,180,233,40,67,68,151,176,210,246,234,163,107,113,38,231,86,172,179,236,205,111,134,133,197,127,46,77,180,150,20,142,222,226,170,132,59,63,155,67,167,166,213,59,142,216,61,101,179,33,27,145,179,161,66,133,88,65,104,150,153,103,65,59,62,238,169,111,132,148,39,81,119,38,119,117,154,237,134,106,98,73,252,218,236,117,214,149,106,92,120,130,157,57,195,20,233,230,93,222,162,213,143,65,43,58,105,118,168,164,151,2,22,116,164,240,226,176,108,201,188,54,84,84,105,221,81,167,26,73,70,49,213,235,161,203,99,14,218,45,165,218,179,140,96,198,251,61,177,247,29,53,75,225,230,229,39,47,153,181,159,66,127,161,186,158,216,50,56,226,249,50,201,81,78,27,198,149,180,155,57,145,167,213,95,254,205,173,135,134,181,64,99,209,225,40,217,203,204,95,63,115,215,216,236,56,53,58,111,127,192,83,117,62,188,107,247,54,150,218,132,17,52,203,104,111,169,80,81,232,101,143,148,196,186,99,64,3,31,54,73,177,121,40,7,184,4,0,247,200,24,204,128,212,173,151,113,20,141,174,17,175,45,107,105,177,177,135,132,64,43,203,73,145,237,238,139,157,17,70,189,220,177,70,77,236,97,211,211,86,243,139,219,187,40,202,241,55,110,214,52,228,226,233,207,252,62,249,224,131,113,236,131,120,255,0,208,119,104,92,60,104,84,14,42,250,40,181,174,187,28,177,212,224,185,120,142,136,17,107,252,180,191,35,169,233,143,255,0,104,84,109,122,229,157,173,104,230,142,19,222,39,79,211,215,109,253,76,189,113,174,9,90,157,59,180,146,89,101,88,220,139,61,163,26,224,34,229,110,240,145,142,19,97,234,198,152,247,2,225,118,232,20,82,90,236,59,180,194,35,206,10,49,116,211,121,198,134,14,140,30,114,142,84,154,223,97,130,14,47,45,105,236,73,82,77,231,39,51,68,96,112,249,92,231,92,24,121,114,239,206,198,202,199,118,228,146,244,34,181,251,101,157,30,204,189,179,237,125,179,237,108,230,237,198,161,45,6,14,62,233,38,181,201,146,169,47,71,147,44,106,92,104,6,42,77,253,135,153,166,10,150,22,133,105,53,177,81,84,242,182,252,217,28,210,228,152,88,212,142,62,161,89,57,166,189,201,157,125,76,28,22,57,193,143,108,151,210,200,57,51,140,152,163,22,218,49,85,49,44,61,70,142,226,11,16,75,216,229,237,94,135,12,31,201,23,234,142,111,200,212,101,214,44,156,177,216,227,90,179,145,99,242,20,87,140,128,200,69,92,175,92,25,24,246,169,112,84,1,107,161,90,46,116,38,82,220,14,190,186,74,186,206,249,55,233,255,0,150,141,10,143,247,201,181,201,189,7,152,68,176,114,47,236,73,12,224,155,183,168,2,53,161,112,78,64,227,170,151,99,124,154,150,73,252,85,87,150,222,61,116,70,213,124,121,108,214,177,146,117,170,160,55,131,194,228,47,112,222,25,116,66,240,78,10,74,15,66,21,144,11,166,9,128,76,129,118,25,38,66,199,32,61,198,52,43,212,155,44,128,217,96,197,250,148,184,200,19,60,107,169,66,73,106,49,160,6,51,193,22,91,0,84,194,213,145,52,222,11,246,1,156,34,45,9,190,75,175,106,2,145,103,144,181,28,128,147,208,226,90,183,147,145,225,250,254,103,18,127,48,25,188,46,52,34,89,101,211,255,0,66,197,105,144,48,100,127,67,51,194,48,172,154,166,214,116,198,160,124,247,199,145,119,20,45,169,172,39,10,202,89,246,59,63,15,86,155,175,89,182,187,176,146,107,147,169,241,165,92,82,167,8,54,231,221,194,254,198,247,135,251,161,117,37,39,136,198,130,125,191,239,25,173,78,158,186,222,88,155,88,209,227,83,114,41,119,232,246,52,44,101,223,55,158,17,183,74,93,205,251,236,86,92,139,89,240,195,221,229,234,99,174,156,25,47,80,18,198,50,69,177,30,225,97,175,176,25,37,166,165,147,211,4,206,54,36,128,143,27,224,152,202,122,106,18,196,125,204,138,58,235,155,70,220,101,74,78,14,47,58,28,150,247,74,84,218,169,163,82,198,77,214,176,178,183,53,106,91,119,190,231,190,115,177,6,194,75,25,76,201,104,104,67,206,167,54,181,107,131,145,222,202,24,82,128,209,185,219,141,114,198,143,242,53,213,253,6,210,111,13,240,217,156,110,105,60,183,45,182,208,214,163,38,215,118,57,57,23,182,199,95,27,218,110,233,174,232,225,28,238,250,135,114,140,91,238,124,5,109,181,242,156,50,156,97,56,174,100,244,70,179,191,125,253,190,91,223,118,116,181,175,43,221,117,120,210,165,221,77,65,188,200,150,145,222,213,185,166,170,197,57,168,198,47,86,217,193,43,234,151,53,101,74,213,102,156,126,170,140,224,133,172,35,25,58,142,83,147,213,54,242,109,218,199,202,183,236,73,44,234,201,236,107,209,177,243,171,202,119,15,204,112,88,142,118,59,37,70,61,169,97,36,184,193,133,12,54,217,204,216,133,113,59,120,55,175,223,99,134,86,52,170,71,18,138,121,225,163,115,58,14,61,11,145,53,171,27,74,48,130,138,167,24,165,166,18,224,201,91,194,63,76,82,95,99,155,234,42,247,254,129,88,42,48,195,74,40,170,148,113,170,95,99,52,214,230,57,122,1,139,164,154,198,52,102,105,97,123,14,236,45,73,206,128,84,176,176,97,38,227,190,198,124,251,28,117,31,202,242,128,235,235,174,231,57,55,178,207,216,235,40,74,51,184,165,91,41,168,107,143,83,107,168,220,124,53,157,74,181,19,236,198,30,14,167,90,52,157,89,103,108,168,164,102,213,142,194,85,188,239,58,110,158,59,164,251,126,216,56,173,84,95,80,161,86,59,183,218,254,198,73,39,99,12,253,79,87,249,151,164,246,213,188,113,198,29,61,208,87,162,107,13,161,7,166,172,203,26,54,242,72,97,199,56,193,166,90,119,223,52,169,38,240,179,147,62,157,63,240,105,118,181,153,103,83,138,253,57,74,59,180,158,25,203,104,219,134,23,28,8,173,204,97,12,164,18,35,211,128,140,150,198,151,86,255,0,240,124,191,226,77,253,178,110,38,176,104,245,153,168,244,230,147,93,210,146,75,63,112,125,110,91,255,0,213,160,241,140,172,153,189,201,5,219,78,49,244,70,88,208,7,3,33,106,139,179,2,21,108,7,32,71,177,113,146,103,37,207,234,5,123,232,70,57,35,121,2,236,56,13,122,134,6,158,142,237,234,247,216,219,111,67,86,147,205,119,44,233,158,77,158,72,24,201,113,232,11,149,141,10,56,174,35,221,66,124,104,105,244,185,169,210,173,195,83,198,25,187,89,55,74,73,122,26,86,19,79,204,88,198,30,9,244,111,173,158,118,17,100,209,34,197,234,80,202,206,250,156,87,17,94,83,147,224,229,198,167,29,85,221,9,69,189,0,198,79,20,63,240,157,111,78,140,126,34,114,146,203,122,228,222,109,186,13,239,166,13,14,157,44,221,212,90,180,145,42,187,100,222,75,150,165,170,193,138,120,123,135,44,114,155,8,178,120,120,198,164,68,148,187,152,198,153,2,243,143,81,39,135,160,107,41,103,242,100,75,185,172,129,27,120,201,86,172,53,134,53,0,247,193,27,217,96,201,237,238,97,157,64,190,164,121,111,5,25,228,3,244,8,143,234,46,116,1,131,35,30,76,154,199,32,26,49,123,225,25,105,141,200,2,79,45,7,130,61,137,184,6,98,203,206,72,65,139,67,181,45,75,157,117,25,203,92,5,118,80,88,132,82,219,7,54,31,169,197,31,165,28,157,190,230,184,177,90,17,209,234,114,44,28,75,245,103,34,247,10,160,156,148,138,34,145,110,80,42,35,101,68,104,163,175,185,210,182,22,143,38,245,44,249,104,208,175,135,95,126,77,250,95,229,146,12,139,192,224,34,129,51,160,97,239,128,56,234,172,194,95,99,82,193,198,85,170,165,198,141,155,85,190,90,76,211,233,208,113,149,71,132,187,181,127,112,59,22,66,128,13,224,136,156,149,52,5,208,0,4,122,60,17,104,244,1,104,65,72,92,225,145,128,35,215,66,144,11,249,130,14,64,184,192,111,8,24,176,50,254,228,206,186,108,93,140,91,215,77,185,1,238,202,185,39,13,32,155,122,99,0,95,184,211,24,34,90,238,22,239,0,22,155,6,198,250,145,167,167,169,68,147,195,193,199,31,169,179,57,104,206,52,245,32,229,75,41,189,138,177,219,190,164,77,236,139,166,61,64,143,11,111,212,225,171,244,191,94,78,86,214,200,227,168,243,23,238,185,3,192,245,250,80,184,234,148,42,77,226,52,179,133,193,203,210,170,169,94,207,177,166,218,225,240,107,120,154,148,235,93,211,84,223,238,226,243,39,234,113,244,47,151,172,246,181,244,211,206,219,24,183,107,83,167,178,167,81,82,88,139,249,158,231,107,77,98,43,215,7,75,4,234,95,80,135,25,203,59,172,188,191,185,184,139,207,177,150,114,242,96,244,79,6,73,173,50,84,48,245,200,195,211,208,153,111,109,2,121,96,93,153,120,201,2,121,105,16,27,211,60,142,238,231,183,232,50,181,17,96,85,175,216,61,246,27,60,176,222,86,24,17,172,172,24,186,122,229,99,243,57,18,95,151,169,37,236,7,90,232,39,115,55,36,158,54,100,161,78,10,149,122,178,127,44,31,44,206,230,125,138,114,89,201,214,95,220,78,29,50,181,40,52,157,71,156,190,81,54,106,199,83,225,244,250,135,137,238,123,106,74,84,41,38,247,209,167,183,250,158,197,80,130,154,105,109,177,228,188,7,108,237,170,222,201,52,246,140,158,125,178,123,38,245,66,116,94,220,85,212,41,219,202,82,90,47,68,121,219,58,211,169,213,170,201,206,50,142,62,88,118,227,31,153,223,95,212,81,180,168,147,195,60,207,79,148,159,90,161,73,197,181,82,14,114,124,15,164,119,117,107,98,106,158,117,223,8,218,132,252,187,120,195,156,106,206,186,81,205,252,231,149,232,189,145,185,20,228,189,138,55,97,36,169,172,45,89,207,28,242,205,68,219,112,138,217,110,109,69,231,40,68,84,176,93,117,92,12,227,220,138,77,178,137,200,77,45,138,247,43,90,104,4,198,54,27,127,169,115,149,134,76,229,233,249,1,63,139,6,88,229,24,188,247,100,55,140,1,36,247,89,56,238,26,84,222,12,218,125,204,224,187,125,182,239,93,114,65,209,245,235,142,219,122,116,32,211,243,103,20,215,230,113,201,74,165,237,90,9,230,52,210,111,67,14,179,55,78,230,221,198,41,230,61,216,126,185,220,230,237,154,185,78,24,249,214,37,238,102,180,205,205,82,161,42,146,143,203,29,189,206,95,14,210,204,235,86,211,186,77,182,105,117,121,249,125,34,180,184,142,223,115,123,195,112,113,176,82,79,43,27,228,176,248,238,229,44,173,89,148,22,32,113,73,101,28,139,233,72,211,45,42,250,78,109,232,91,6,231,221,63,77,12,175,242,163,152,172,225,126,167,31,73,131,133,9,78,77,252,207,36,87,97,29,94,187,149,236,68,242,214,152,100,121,203,212,168,103,25,103,89,214,254,103,107,65,255,0,28,211,253,53,59,72,172,157,71,84,114,119,54,210,77,101,77,37,159,185,40,238,86,84,86,125,0,147,77,233,249,17,233,178,40,203,97,156,189,8,181,28,129,118,3,3,32,18,208,97,24,191,245,51,2,12,142,9,174,88,25,231,131,22,241,25,75,25,194,41,133,73,98,148,177,184,26,214,209,249,165,39,179,150,77,173,56,216,214,182,88,79,63,212,217,209,44,2,133,72,114,24,71,29,103,242,181,236,104,216,212,82,184,169,24,172,52,245,59,9,105,28,251,29,117,158,183,115,107,103,177,62,171,178,198,185,8,137,232,51,249,148,51,142,12,101,172,116,46,114,151,184,121,105,173,48,7,10,214,222,102,135,76,255,0,54,171,206,166,228,37,242,84,95,116,209,163,211,100,213,122,180,253,53,79,212,138,237,62,225,175,155,28,14,51,200,206,22,66,24,203,208,179,209,35,21,162,203,43,249,189,128,139,68,54,97,233,160,247,228,1,114,71,170,97,100,11,191,216,139,113,128,3,216,140,99,69,146,189,180,252,192,137,227,114,233,250,145,99,98,182,5,227,5,142,186,61,76,113,135,169,120,1,236,131,90,141,56,38,224,87,236,98,244,90,25,97,152,183,149,130,9,190,132,150,136,108,51,160,86,60,13,150,80,97,54,32,236,225,172,23,216,231,237,56,41,253,11,236,114,225,250,154,140,52,147,113,219,25,50,75,60,152,164,92,101,133,50,67,38,129,20,69,68,40,20,54,62,195,114,142,190,226,41,79,187,69,175,234,111,82,105,210,137,169,115,135,83,47,131,98,139,78,154,104,131,144,13,89,118,40,63,80,134,50,0,225,173,172,25,173,100,190,105,188,188,228,217,172,190,70,112,90,105,41,96,13,188,232,1,0,188,147,26,228,163,0,7,35,98,0,247,26,128,65,76,121,43,34,90,128,200,69,39,63,96,25,246,194,25,72,153,89,15,84,192,169,133,201,22,197,120,64,57,49,126,161,232,30,168,4,101,159,177,117,206,154,17,36,182,15,69,184,23,41,54,130,198,230,62,230,73,96,8,218,90,45,6,156,145,227,130,55,158,0,77,166,113,175,171,38,111,9,24,103,15,0,102,181,212,50,103,65,178,109,129,94,134,19,89,88,246,50,221,100,198,92,122,176,60,47,94,135,117,250,167,25,52,158,248,51,233,52,163,14,183,115,36,211,135,195,104,179,175,255,0,57,57,58,204,82,234,179,168,254,168,252,184,201,143,78,146,125,103,50,138,138,156,113,159,85,232,99,235,88,239,236,231,31,143,77,253,73,97,35,183,206,167,75,70,46,61,86,45,191,146,57,77,122,157,206,236,212,74,188,236,92,147,58,147,25,42,50,142,18,38,86,70,127,81,200,23,114,167,170,192,254,18,45,48,128,47,118,84,176,216,199,35,95,80,42,89,203,68,122,253,203,157,52,201,49,144,43,120,143,177,27,105,54,177,170,15,24,208,198,89,84,228,223,8,14,186,230,41,195,46,88,89,195,58,94,167,91,29,58,171,147,95,34,202,111,147,179,190,146,169,105,82,47,102,150,49,185,208,245,169,194,135,73,142,118,202,131,71,58,212,118,94,14,167,254,14,180,187,112,229,62,230,253,79,70,219,202,202,201,213,248,114,148,104,116,122,110,57,81,154,202,247,59,77,243,140,27,157,37,237,167,212,34,167,70,107,210,45,158,127,163,124,215,178,156,214,42,121,120,88,227,67,208,94,63,220,212,150,191,75,60,183,71,169,39,115,46,236,167,170,114,198,232,125,89,211,188,164,158,20,164,242,205,218,11,20,242,107,211,131,113,79,140,104,141,138,24,237,104,35,98,140,126,117,157,90,54,53,82,201,197,71,29,185,245,57,147,249,75,17,86,51,161,138,208,185,208,54,80,122,227,82,239,169,26,211,60,147,58,225,126,160,86,255,0,66,103,86,177,192,198,90,30,171,212,2,217,177,134,247,46,82,92,137,112,145,6,47,58,35,86,243,252,164,158,141,179,107,58,224,211,188,203,75,78,64,232,186,236,123,186,165,157,60,124,174,139,238,246,212,231,161,56,202,171,134,126,108,103,62,135,21,234,85,186,157,54,229,135,28,38,191,35,59,122,126,82,172,229,79,230,122,251,153,173,124,107,117,214,167,211,21,25,253,53,42,197,105,206,187,29,207,65,162,237,237,234,195,181,40,41,98,30,235,8,232,250,215,114,163,111,5,28,183,81,61,125,50,122,123,72,184,80,75,57,92,8,94,156,252,224,207,58,164,98,101,202,52,203,130,237,63,41,246,238,113,244,249,119,209,105,107,141,13,170,203,52,228,253,141,78,154,251,97,40,231,56,122,143,163,127,9,113,169,139,89,70,89,206,198,57,206,136,162,196,234,250,148,28,238,40,38,179,217,81,72,237,49,133,131,70,230,50,248,170,127,124,191,177,40,236,51,145,236,99,12,227,83,32,50,193,54,96,141,225,129,115,192,192,91,166,57,246,40,22,36,211,187,1,225,45,25,5,199,28,17,44,101,151,68,137,191,216,7,6,21,94,33,201,151,178,56,235,101,172,108,81,141,8,230,45,251,156,219,156,118,233,170,56,122,181,166,125,78,80,81,108,70,189,76,182,70,47,59,144,89,63,149,253,142,178,215,75,153,44,45,27,212,236,102,212,96,243,232,117,182,223,45,105,75,249,152,29,142,68,114,56,207,44,61,150,63,82,131,255,0,231,1,203,114,188,225,36,96,215,160,26,176,156,157,106,244,220,114,148,114,159,169,165,211,37,23,90,179,139,204,150,19,246,59,10,107,247,210,126,205,29,95,70,80,85,235,40,63,171,50,127,169,13,119,80,194,120,104,53,148,69,171,255,0,80,5,237,107,64,247,39,115,120,200,111,92,129,113,148,71,182,219,7,166,235,80,192,137,101,21,111,128,180,38,126,96,40,67,129,141,72,13,135,151,177,54,251,149,108,202,38,197,120,201,50,61,218,208,12,158,233,110,19,125,217,35,91,62,70,112,128,54,219,42,105,126,68,200,202,32,55,207,169,131,246,41,24,6,77,216,122,108,66,41,206,72,153,30,225,234,177,156,100,176,118,180,243,218,179,232,114,96,226,162,254,68,189,142,124,63,83,81,134,140,22,121,50,79,7,28,86,185,203,57,55,251,6,148,156,15,97,140,144,56,42,4,198,160,92,52,51,167,185,86,164,101,26,87,90,75,47,233,245,57,237,215,203,247,56,111,27,113,199,7,53,190,123,48,65,207,219,128,200,222,2,212,162,162,61,245,41,48,6,51,89,139,124,26,214,169,41,207,11,238,109,203,72,104,105,219,102,83,156,179,187,209,16,141,189,18,39,32,188,20,57,26,147,96,254,224,30,24,193,57,69,206,8,38,207,5,252,245,28,147,220,2,208,114,69,190,75,144,24,213,177,253,6,200,197,232,192,169,36,241,160,146,215,114,45,94,186,21,188,48,38,116,200,212,143,87,182,165,111,11,0,59,184,68,79,144,150,53,89,24,212,7,115,67,62,164,151,168,238,127,144,23,103,236,27,111,71,253,9,134,222,91,211,212,146,194,198,0,100,169,229,107,193,54,72,203,117,156,32,39,213,166,78,36,241,60,25,239,236,96,151,204,253,66,179,200,111,38,41,188,236,101,206,66,13,225,24,169,99,87,193,89,199,60,227,24,220,43,200,117,90,13,245,119,57,235,223,170,79,130,244,168,199,246,172,242,227,221,78,155,194,124,27,29,94,151,127,80,167,81,44,74,47,13,231,58,125,142,59,59,120,174,163,113,91,57,111,9,25,90,237,108,224,213,215,124,222,92,158,135,115,162,90,157,101,9,37,90,58,126,103,100,222,121,42,50,209,140,127,66,108,137,185,81,86,229,107,92,152,114,100,222,126,192,81,146,39,150,84,3,33,234,180,34,216,45,0,203,117,129,177,30,34,247,9,172,123,129,81,197,113,62,218,19,120,206,135,34,56,171,101,211,100,87,77,95,71,22,184,89,103,65,226,106,85,42,116,186,113,138,93,178,154,147,127,99,191,191,146,135,108,154,195,250,82,92,157,23,138,20,161,105,211,41,65,189,106,193,53,235,243,45,12,181,197,236,172,233,59,123,10,84,180,196,52,201,204,150,22,73,163,121,91,61,81,100,241,31,200,219,13,59,185,168,89,214,158,235,24,103,65,102,148,122,132,150,112,251,115,131,183,235,9,174,153,40,183,133,55,185,213,89,211,155,171,40,205,174,230,150,25,154,212,233,220,83,155,195,203,217,99,99,158,130,74,30,219,154,148,179,42,115,122,45,112,189,205,218,47,20,98,158,233,21,27,84,90,242,210,57,36,240,190,198,48,194,142,89,114,222,236,168,137,233,130,188,233,166,72,180,101,114,121,192,21,126,134,33,50,105,143,112,50,79,11,220,143,24,247,38,117,9,239,234,80,206,112,100,246,198,12,98,178,179,202,15,238,65,30,113,161,193,118,156,86,205,44,110,115,199,221,251,154,119,149,49,153,110,148,27,72,43,166,130,255,0,22,167,156,225,247,99,38,116,156,157,197,73,61,20,165,157,206,26,93,208,166,219,211,229,217,155,54,80,117,91,75,124,103,6,21,163,120,213,126,185,109,4,219,133,58,122,227,212,245,22,184,116,34,255,0,35,203,91,84,140,250,197,206,49,148,148,113,232,207,87,66,61,150,208,142,154,110,88,86,122,106,145,202,182,71,18,89,223,250,28,152,229,51,76,176,155,202,122,107,131,86,198,42,14,170,77,97,203,244,54,222,145,108,215,182,93,181,100,189,117,216,43,105,47,185,56,97,236,219,101,95,78,133,69,217,26,55,114,116,238,173,176,178,231,36,158,56,70,247,240,236,107,212,195,184,166,241,170,100,163,101,125,79,77,12,182,122,152,167,149,149,234,100,192,141,234,137,157,53,212,173,104,18,75,216,2,88,68,89,75,37,76,104,192,53,151,232,101,185,49,163,203,11,128,27,39,146,164,31,160,79,0,98,206,42,205,168,103,133,171,57,91,95,155,56,46,97,223,74,81,215,85,134,4,233,247,49,186,183,117,96,159,99,147,75,220,218,53,236,232,66,210,214,20,105,172,66,43,67,157,55,234,4,123,141,195,221,96,171,112,49,154,204,113,234,117,180,36,165,90,110,60,73,163,180,75,185,235,253,14,174,151,238,174,50,150,243,121,3,127,45,164,94,2,215,95,81,174,16,25,54,177,156,152,51,45,50,151,4,150,224,107,75,229,172,241,171,195,58,175,15,201,58,247,148,223,213,9,227,39,109,83,229,169,25,231,9,229,29,87,71,166,161,212,239,219,111,13,172,123,191,81,170,238,135,176,127,79,184,192,67,41,189,30,133,245,200,227,36,224,8,245,42,68,122,50,172,254,64,22,228,111,13,96,188,4,3,119,247,15,125,6,113,167,3,56,64,78,67,208,45,242,71,171,203,2,176,182,192,91,135,166,61,24,4,26,211,220,139,78,71,32,86,253,72,190,225,234,79,204,130,191,99,22,82,61,178,69,78,117,13,135,201,142,117,96,31,175,161,22,179,88,15,56,34,209,166,183,40,237,233,225,197,47,99,156,215,166,245,88,224,228,249,191,152,220,97,169,21,167,177,154,70,41,100,200,42,189,136,202,222,76,115,147,42,164,220,171,80,147,206,128,85,232,9,156,61,195,107,112,53,47,34,229,20,151,169,203,106,241,79,25,76,198,231,211,57,200,183,209,118,250,1,176,24,201,113,133,144,32,195,228,155,23,58,234,81,140,223,202,240,106,91,101,214,146,219,6,220,177,134,176,107,209,88,169,36,136,70,202,219,36,206,153,28,110,0,101,114,70,241,234,70,155,46,48,176,128,61,54,228,171,109,73,129,174,64,108,200,215,162,42,247,100,109,241,192,23,100,98,158,184,29,217,69,75,92,128,109,173,134,114,134,121,224,141,252,173,242,3,45,108,62,228,111,56,32,23,103,167,36,97,191,112,158,114,242,6,90,105,170,252,137,175,228,71,168,254,16,24,200,150,155,32,145,94,112,245,253,64,197,183,219,255,0,34,45,131,79,243,38,192,100,216,79,66,13,84,95,232,1,188,234,97,206,117,50,198,134,43,234,208,43,44,44,33,187,8,199,58,129,150,250,28,117,30,112,253,12,218,56,155,107,58,106,65,208,245,42,46,189,204,59,100,163,137,106,253,141,110,149,53,43,202,244,220,95,116,103,133,238,111,220,197,252,92,34,185,150,126,230,191,78,138,93,66,172,215,213,221,131,50,149,218,83,88,170,242,118,24,124,157,122,143,206,210,209,41,110,118,11,115,82,138,246,69,79,66,61,81,18,224,168,188,14,17,94,233,122,7,140,97,236,128,44,46,8,222,184,47,118,16,92,48,4,228,185,203,126,152,35,121,2,241,161,22,207,212,169,52,178,52,121,212,11,252,59,234,112,214,127,47,230,114,250,28,53,177,170,37,87,89,212,99,231,78,155,211,17,127,153,209,245,137,252,71,85,233,180,63,133,205,73,233,147,208,92,47,222,39,252,191,212,233,37,47,136,241,141,164,28,112,187,112,162,69,226,245,211,195,113,198,216,70,50,89,71,36,146,82,120,209,112,96,246,216,211,46,179,173,255,0,212,113,156,37,234,117,253,19,53,250,125,74,207,50,169,7,133,41,122,27,157,117,186,148,225,70,57,93,218,103,211,220,215,232,180,99,67,166,85,132,117,94,99,78,94,184,102,90,248,220,181,78,107,45,99,27,179,118,11,67,134,132,126,89,62,56,54,32,176,34,54,98,190,68,203,29,83,102,57,215,30,133,206,13,32,219,123,134,201,157,115,129,171,144,12,145,240,242,10,150,160,48,255,0,82,227,92,173,73,163,94,228,224,12,155,249,182,199,185,51,141,137,145,157,48,4,111,229,199,169,214,117,57,168,99,187,102,187,87,230,206,203,12,235,122,180,101,87,225,163,29,212,245,246,65,99,174,168,164,163,40,246,166,227,156,155,61,58,125,151,53,40,201,97,83,183,238,109,24,87,74,157,41,212,195,147,206,198,194,82,165,210,110,238,82,94,99,160,245,127,99,61,123,87,157,240,218,149,199,155,124,243,251,218,221,187,30,225,225,114,182,60,207,134,168,170,125,54,206,148,86,249,169,55,234,207,69,41,36,251,117,212,113,232,229,219,150,60,179,37,176,73,165,132,100,150,35,169,166,88,188,47,70,112,81,88,171,39,157,206,89,69,181,161,199,107,219,82,85,48,254,151,135,247,20,108,60,38,88,172,177,167,110,94,165,95,78,187,128,123,26,251,214,89,212,217,223,63,208,224,74,94,119,0,115,173,176,158,193,47,80,145,118,2,75,77,136,243,156,227,66,181,175,112,111,9,227,148,4,215,47,37,75,65,28,180,155,50,224,9,166,10,48,64,13,188,172,23,140,147,129,144,24,56,107,125,80,94,167,54,78,25,188,84,90,106,7,55,3,24,70,29,248,221,24,185,188,1,203,193,27,244,56,59,244,34,155,198,160,115,185,99,147,175,122,92,164,151,241,27,14,111,242,52,162,223,198,44,101,234,192,236,147,224,169,164,217,140,52,107,35,144,50,206,113,166,3,89,228,184,209,114,99,170,195,3,130,230,15,9,103,25,52,58,124,103,26,181,123,218,109,203,76,122,29,141,196,123,148,117,231,38,133,156,177,125,86,148,87,210,245,108,151,181,118,79,100,92,126,129,233,185,19,202,192,67,211,208,61,48,84,145,22,218,148,27,91,224,107,129,247,224,185,94,128,98,62,192,36,241,166,192,94,3,244,15,36,228,2,75,96,210,245,28,160,247,195,64,54,200,221,37,194,32,224,6,112,194,223,112,222,67,217,0,123,147,148,8,69,8,94,48,98,217,1,152,229,103,60,21,236,76,233,160,24,183,167,183,220,139,235,143,187,36,229,186,193,140,101,243,199,85,185,85,220,82,122,224,231,252,205,106,15,85,236,110,102,62,133,154,231,90,81,211,114,253,130,137,86,230,168,33,130,227,146,25,104,34,50,38,53,1,140,140,96,23,28,129,193,112,190,70,255,0,169,141,190,116,193,205,89,102,155,56,168,44,47,114,14,109,83,216,203,26,17,2,131,31,168,207,0,162,112,112,65,102,187,208,216,109,108,112,166,149,76,144,114,61,201,18,119,102,69,200,23,114,75,238,85,132,78,64,103,65,247,49,89,206,10,254,193,70,240,209,138,121,120,65,231,33,38,158,64,37,143,204,187,60,140,151,157,152,70,58,51,41,62,17,18,14,62,192,97,236,92,233,161,82,100,237,126,132,16,169,99,58,13,248,50,195,112,40,197,178,99,146,181,161,0,171,94,72,223,28,5,152,229,96,197,231,133,200,4,195,121,102,93,175,31,113,216,243,176,24,253,134,200,203,181,165,176,237,120,216,12,20,180,49,138,249,155,245,50,105,239,130,40,182,222,83,208,43,52,76,44,234,69,151,195,193,94,159,253,2,49,147,206,204,199,141,181,44,147,237,209,48,151,202,178,69,117,23,145,197,202,151,40,224,179,143,248,218,149,84,112,158,35,156,155,189,69,40,39,41,55,135,182,135,5,189,90,29,202,42,113,82,126,175,6,58,184,124,111,66,41,198,82,92,60,35,106,59,24,83,164,163,73,99,26,234,114,164,251,77,192,72,99,93,76,158,49,156,145,60,32,11,213,6,189,66,146,217,106,101,141,10,140,112,155,11,115,44,48,177,246,3,6,176,198,86,230,79,93,17,143,111,255,0,64,42,111,208,99,5,198,153,35,213,123,1,27,212,197,235,184,120,79,212,55,232,7,93,89,119,94,47,229,71,79,74,18,151,140,105,45,51,8,185,103,27,35,190,143,108,235,205,71,13,197,225,154,116,45,98,188,77,82,170,223,200,219,243,51,61,53,43,185,122,188,144,169,123,13,51,131,76,180,239,41,198,84,219,194,251,179,83,167,66,15,166,118,167,188,222,49,201,181,213,62,91,9,184,191,159,41,35,11,10,42,151,79,183,135,162,215,238,102,198,167,78,90,49,237,132,146,229,236,108,69,106,140,112,180,194,212,229,81,227,119,236,89,17,146,77,172,142,70,26,194,77,151,102,84,66,165,174,112,55,34,111,95,64,24,195,219,112,215,168,231,97,37,175,176,12,227,66,110,140,103,45,176,101,149,128,38,193,237,161,94,139,109,200,4,123,166,105,95,195,229,85,53,249,77,211,94,234,61,244,101,31,212,43,173,170,165,53,229,255,0,11,89,102,197,212,163,15,14,212,138,199,211,218,209,197,82,74,50,165,77,39,171,50,190,89,180,141,53,172,37,36,229,175,25,49,122,24,116,90,42,150,34,155,113,81,249,87,161,218,69,57,92,168,239,133,150,106,244,244,254,121,53,162,120,95,99,118,134,29,73,75,30,198,184,204,152,86,204,83,122,250,134,181,107,115,36,176,180,35,221,178,163,9,104,142,43,74,125,145,147,237,195,109,254,103,36,245,94,226,13,168,129,101,245,25,227,147,20,211,111,67,52,155,0,113,36,213,103,141,142,99,141,37,220,253,64,200,99,98,240,70,3,13,203,108,161,36,35,156,19,32,85,177,114,97,146,74,90,104,191,48,57,50,55,53,251,245,200,117,94,201,129,204,164,159,33,201,97,229,224,214,115,211,114,57,233,185,52,108,119,164,205,121,202,82,185,202,219,27,24,74,120,91,156,78,163,239,211,65,163,109,188,7,36,107,121,154,234,114,39,179,26,172,219,201,139,121,71,30,95,118,198,107,72,172,238,6,73,104,107,66,45,93,228,217,139,56,212,95,196,197,240,192,219,105,160,181,46,236,37,169,89,93,147,70,15,59,25,227,130,69,106,128,226,184,77,81,202,221,51,66,214,56,234,117,170,47,251,69,175,220,236,171,127,150,215,7,95,71,75,185,190,31,4,179,218,199,98,150,132,122,60,22,59,114,68,185,121,199,168,13,162,66,189,23,250,0,38,252,12,106,85,238,93,50,181,220,163,22,181,42,79,183,35,26,133,232,64,75,220,143,70,244,43,211,35,25,69,24,251,132,95,82,109,128,34,203,201,115,166,1,24,16,113,184,216,159,98,42,183,161,137,90,223,4,217,16,48,57,38,117,228,141,235,160,18,122,104,153,198,222,22,229,148,158,14,57,75,64,164,229,166,125,14,24,205,202,172,52,203,114,68,156,240,153,141,9,37,94,154,247,10,239,232,181,221,131,103,44,212,163,254,110,34,110,118,251,162,199,58,235,173,122,165,133,244,220,44,238,233,92,73,106,252,185,39,143,208,219,252,143,142,126,5,69,121,55,245,106,75,19,202,81,79,211,7,217,52,123,179,167,40,140,114,194,89,13,172,232,84,244,48,208,209,49,161,73,144,46,48,76,123,140,140,232,4,194,122,50,70,148,98,220,150,114,92,99,82,225,129,26,67,66,127,17,64,96,61,54,69,121,111,98,52,253,64,131,9,23,181,250,151,183,60,148,97,141,70,50,100,225,149,184,198,155,144,77,25,48,101,218,179,161,22,224,99,201,75,132,28,87,32,49,169,139,90,232,101,185,82,89,212,12,22,197,107,37,105,99,5,75,8,12,94,222,128,184,24,75,112,38,117,193,140,140,240,184,49,120,206,0,195,37,111,66,232,158,161,106,223,32,77,214,164,194,206,134,91,45,112,22,27,200,19,28,228,207,131,23,129,148,158,0,200,141,227,87,176,114,193,86,217,221,48,34,101,220,38,150,255,0,208,99,212,6,128,171,5,198,128,99,166,2,195,50,219,128,177,183,244,3,23,174,228,196,91,213,28,152,77,140,32,56,229,8,201,97,197,53,238,142,56,219,81,89,126,92,114,249,237,54,52,193,18,206,132,193,143,100,48,146,75,5,236,90,46,12,182,5,19,182,59,97,50,56,67,209,125,204,155,213,135,170,208,131,23,8,113,20,179,232,79,46,43,26,25,236,182,13,232,81,60,184,224,42,81,206,168,66,89,130,108,228,206,128,113,121,84,241,242,162,121,20,249,91,156,185,209,149,97,1,198,232,195,183,24,102,42,132,115,170,127,169,204,222,131,109,64,215,149,188,30,86,25,131,181,142,55,151,234,109,228,187,236,65,211,219,244,168,210,175,58,174,117,62,105,119,53,147,102,22,52,213,196,171,44,247,53,131,125,225,110,22,16,195,90,190,70,52,215,245,30,71,163,102,208,208,184,142,178,247,166,70,242,154,140,229,40,246,188,232,114,82,179,242,233,198,25,120,138,55,241,174,161,140,54,181,21,182,139,87,190,74,168,73,201,241,249,27,107,238,27,244,6,181,124,173,121,39,145,44,183,157,25,183,166,3,41,173,79,34,78,57,200,84,36,178,109,233,129,192,193,168,232,200,121,50,102,215,5,100,53,166,237,229,45,154,31,13,83,25,202,199,216,220,88,244,50,91,23,13,105,124,60,183,108,174,218,77,238,141,182,6,26,210,118,211,93,218,175,99,23,103,57,55,170,212,236,52,9,172,19,13,116,117,250,77,89,78,50,140,146,105,154,215,125,35,168,85,194,165,86,156,34,158,117,89,201,233,115,236,77,27,37,139,174,162,215,167,215,163,73,198,114,82,147,244,216,217,182,183,173,24,75,204,198,91,206,134,246,86,249,25,94,165,133,174,31,42,94,195,203,150,49,131,155,40,191,153,81,171,43,121,99,70,35,66,88,213,44,253,205,172,161,148,76,53,192,168,207,28,15,46,75,208,231,82,89,29,201,242,92,53,174,233,72,227,84,42,41,189,181,91,155,125,203,213,19,185,33,134,184,21,41,246,241,147,25,66,113,107,148,108,247,175,83,25,78,11,25,123,131,92,29,178,123,44,34,42,83,198,52,54,83,143,5,202,228,97,173,71,70,170,92,126,70,19,161,87,57,209,163,119,186,62,164,238,68,53,215,249,21,127,148,124,61,103,252,41,63,185,216,41,169,106,139,220,178,67,93,115,183,171,156,56,156,114,181,172,222,139,79,67,180,114,89,192,114,67,13,117,110,214,179,142,145,249,140,85,149,110,237,99,140,157,175,122,39,122,247,24,186,234,221,173,108,225,36,223,187,57,21,189,85,140,199,238,118,29,201,106,60,213,232,49,53,162,173,230,248,47,195,207,63,73,185,223,247,253,11,230,172,101,228,184,107,77,80,154,127,72,242,165,223,140,126,102,239,122,211,127,208,157,235,144,107,141,65,237,129,216,222,200,229,239,142,232,41,172,32,174,53,22,181,192,75,212,228,243,18,89,49,115,91,132,113,213,135,116,116,52,103,109,40,213,140,163,7,158,78,201,73,100,74,107,26,238,8,215,237,113,138,206,83,38,185,209,60,28,181,154,148,22,30,218,153,210,107,181,17,90,248,151,163,43,139,79,216,217,249,125,176,92,199,24,108,163,87,110,52,34,121,127,99,109,56,235,177,27,135,4,26,207,9,167,253,7,25,57,220,99,156,149,168,190,0,213,114,92,17,189,116,102,218,132,53,14,157,62,18,3,85,60,176,205,159,46,15,132,73,82,131,198,133,26,198,47,115,111,201,142,14,55,110,150,53,3,95,30,163,236,108,124,50,122,182,204,126,31,220,131,135,58,19,62,187,28,206,221,165,185,139,163,38,150,164,87,12,165,174,78,55,45,78,105,80,150,14,57,80,169,166,154,133,112,202,89,123,232,112,206,122,250,107,234,114,202,149,69,157,53,53,170,66,121,207,107,34,199,21,73,107,249,22,211,51,188,132,86,203,115,138,106,120,203,132,142,40,92,211,181,163,123,86,114,75,203,183,156,255,0,68,201,190,241,108,200,244,55,215,49,233,189,42,246,242,173,72,211,133,42,19,146,155,123,60,104,124,15,255,0,180,255,0,16,127,253,214,95,169,226,167,226,78,169,115,74,226,141,107,218,243,163,81,184,184,202,163,122,29,87,151,31,95,234,123,124,126,25,39,183,154,243,246,250,135,224,173,122,149,60,92,173,149,73,121,126,76,167,40,231,71,140,127,204,253,13,236,126,117,252,11,255,0,241,186,179,231,200,150,191,154,63,68,156,252,147,249,58,78,142,70,80,124,147,248,78,117,76,161,147,30,17,94,228,25,119,105,238,76,232,178,98,254,165,247,34,122,160,57,50,209,123,142,57,54,87,192,23,37,83,211,67,9,109,249,23,11,209,19,70,93,235,118,78,244,113,162,37,129,163,153,201,100,185,73,111,185,131,216,141,190,221,249,46,140,162,251,182,212,185,121,53,107,73,198,11,13,175,177,193,58,147,242,254,185,126,163,70,244,170,168,194,82,229,112,74,57,84,214,115,151,171,58,167,41,119,47,153,254,166,50,171,83,183,235,150,207,144,184,238,179,171,49,115,75,76,235,246,58,57,214,170,162,241,82,107,69,252,76,211,169,94,183,107,253,236,247,254,102,77,107,241,122,111,53,101,44,235,236,95,49,60,164,121,42,117,170,249,255,0,230,79,111,230,103,45,205,106,177,81,197,73,173,86,210,100,252,151,240,122,143,51,27,178,249,139,110,232,254,167,149,85,170,121,171,247,147,250,127,153,156,55,213,170,198,154,197,89,175,180,152,252,147,240,123,7,86,42,57,109,47,177,199,42,244,211,214,75,30,185,62,107,43,187,151,112,211,184,170,210,245,155,37,123,171,135,69,167,94,174,51,183,123,39,228,215,252,111,162,171,154,121,110,53,98,210,120,122,153,186,240,221,77,126,167,202,47,239,46,161,10,106,23,53,162,189,20,218,58,138,61,70,251,226,87,248,203,141,101,175,239,101,255,0,49,249,37,224,251,111,155,23,171,169,20,190,225,86,167,166,42,69,254,103,197,227,125,119,42,175,55,85,222,143,122,140,194,149,245,231,149,21,241,117,241,151,255,0,104,205,75,169,248,190,223,230,66,73,37,82,57,246,97,84,139,79,231,143,219,39,196,254,54,235,190,154,248,154,216,124,121,140,198,119,151,61,221,191,17,91,183,27,119,188,20,252,95,108,117,96,181,85,33,143,190,195,204,167,191,153,28,122,228,248,28,111,46,149,242,74,230,182,53,211,204,126,230,197,43,219,191,131,159,248,170,219,127,222,50,95,76,217,143,186,58,212,164,191,205,167,143,248,145,124,250,81,138,78,165,53,255,0,141,31,158,42,95,93,170,145,197,213,117,242,241,81,156,63,180,47,93,41,183,119,112,218,134,143,204,122,127,83,114,108,77,126,139,248,138,93,221,190,125,63,252,197,119,54,248,255,0,172,83,95,248,143,206,14,250,237,211,167,39,117,95,187,27,249,143,38,255,0,197,220,202,233,167,113,89,252,188,205,139,196,215,232,37,113,73,172,170,212,218,90,101,50,202,180,18,250,150,79,207,138,246,233,93,208,74,230,182,60,183,167,152,253,75,46,165,125,221,81,124,109,206,19,211,247,178,255,0,153,103,15,67,244,3,185,142,139,43,245,51,133,85,157,113,159,185,249,238,135,80,189,114,134,111,46,31,205,255,0,123,47,249,158,129,95,93,252,58,127,21,95,63,254,81,147,241,91,31,102,117,82,120,90,178,58,143,249,94,167,200,173,47,110,157,85,254,38,183,211,255,0,120,204,157,253,226,165,54,174,235,167,235,230,63,249,146,207,120,143,174,119,73,37,242,182,69,81,164,223,107,103,200,99,212,111,149,174,85,237,198,116,215,205,151,252,205,197,123,116,187,49,115,91,93,255,0,120,198,15,168,186,146,223,181,150,51,169,37,149,7,161,243,90,215,215,106,230,41,93,87,75,181,127,218,51,150,119,183,112,148,59,110,171,71,50,89,197,70,179,160,156,71,209,123,167,191,99,39,116,244,204,117,103,207,169,94,221,187,202,137,221,86,105,45,23,152,244,36,47,110,251,32,254,38,182,239,254,209,140,31,64,83,155,147,94,92,140,156,231,140,118,179,231,115,190,187,85,101,139,170,235,79,251,198,109,217,94,221,73,199,55,53,159,222,163,22,97,30,234,19,147,222,18,252,140,157,71,22,215,107,60,213,91,171,136,194,29,181,234,172,190,38,201,43,138,251,249,213,63,243,50,35,210,169,207,183,88,50,186,146,107,233,60,229,43,154,239,57,175,81,233,252,236,203,226,107,246,195,247,213,55,254,102,92,87,160,239,155,218,12,203,186,123,118,179,161,141,205,127,37,254,250,167,63,198,204,106,92,215,196,127,125,83,255,0,51,31,138,59,252,205,234,162,100,157,69,143,148,232,103,113,91,49,94,117,76,99,249,153,193,27,154,237,213,205,122,143,27,124,239,65,248,171,211,102,105,106,139,153,122,29,20,107,214,236,111,205,169,159,248,153,203,82,189,110,213,251,217,255,0,230,98,68,118,178,148,244,196,116,47,239,26,206,53,71,71,58,245,150,213,106,111,252,204,229,165,94,182,101,251,217,253,47,248,152,197,119,31,58,107,76,228,146,115,206,59,89,231,41,221,92,121,211,253,253,93,23,243,178,221,93,92,43,108,170,245,83,245,239,101,193,233,62,116,182,47,207,194,60,101,75,187,158,236,124,69,95,167,249,217,212,93,95,93,170,43,23,85,214,175,106,140,136,250,86,42,53,176,74,163,203,109,96,249,165,149,237,220,168,84,114,186,172,218,146,195,117,25,175,127,121,117,22,187,110,107,45,30,211,97,95,82,151,114,244,251,228,199,50,217,73,126,167,201,108,175,174,231,47,154,234,188,190,245,27,54,105,94,92,184,172,220,86,213,107,243,178,217,131,234,105,63,231,142,62,229,113,146,215,189,31,46,149,197,116,165,138,213,23,254,38,117,85,175,174,254,51,179,226,171,246,164,176,188,199,129,56,143,178,225,255,0,60,127,80,165,199,124,115,247,62,51,74,246,235,203,187,255,0,19,91,76,227,247,140,198,87,215,107,107,170,235,228,95,246,140,184,206,190,208,158,255,0,189,135,231,36,71,82,9,127,157,12,255,0,196,143,132,190,161,123,41,194,50,188,184,113,123,167,86,88,127,212,206,242,238,231,178,95,226,42,233,44,125,111,208,191,137,175,186,74,173,24,172,206,226,156,126,242,70,46,226,217,111,115,77,103,103,220,143,131,202,226,183,155,5,231,84,198,159,196,253,14,10,183,87,14,77,59,138,184,215,78,246,63,20,215,223,190,38,219,155,170,95,156,209,135,199,88,166,211,188,163,159,105,163,224,180,110,107,254,200,111,207,169,158,239,231,102,172,171,85,133,24,184,85,156,126,94,36,209,127,3,95,160,95,80,233,205,246,187,202,121,223,29,196,125,79,166,164,243,119,79,79,247,143,206,147,187,185,237,131,248,138,185,109,229,247,179,94,119,151,74,155,146,185,172,159,118,253,239,212,126,17,63,47,111,210,75,172,116,188,47,241,180,255,0,83,23,214,186,86,101,31,141,167,148,240,254,99,243,141,59,138,207,45,214,168,223,252,76,224,165,86,164,104,84,148,106,73,55,83,86,153,127,227,139,175,210,139,174,116,165,255,0,233,148,222,184,206,76,127,218,46,143,243,63,142,165,242,239,169,249,166,242,230,188,105,199,21,170,47,180,153,201,109,113,93,211,89,173,81,233,252,207,212,191,241,195,95,164,23,136,250,43,79,255,0,104,81,73,122,201,18,62,37,232,178,138,113,190,164,211,231,58,31,156,42,55,240,148,222,94,123,206,9,214,171,220,227,230,79,183,211,185,224,127,199,13,126,150,126,37,232,125,206,63,180,104,182,184,238,18,241,31,70,75,91,218,107,243,220,252,229,10,147,141,56,246,206,75,17,211,12,234,213,106,179,115,114,171,55,243,115,38,103,240,134,191,81,47,17,116,117,46,215,123,75,62,242,36,124,73,209,102,244,234,20,91,246,150,79,204,213,42,212,239,166,251,229,156,250,138,181,170,197,83,106,164,211,203,213,73,143,248,225,249,63,77,63,17,244,72,197,201,245,10,88,92,228,191,237,15,71,198,99,125,77,165,190,15,204,53,46,107,205,246,202,181,73,46,221,156,155,54,46,46,43,198,214,135,109,106,139,78,36,203,255,0,20,63,47,111,211,11,174,244,153,52,149,245,39,157,113,157,73,251,127,163,226,77,223,210,88,245,103,230,213,94,170,157,25,42,179,82,238,223,185,154,190,117,89,54,221,73,183,234,228,199,252,112,215,233,232,117,174,147,56,167,79,168,81,148,125,84,140,151,89,233,141,38,175,104,188,255,0,188,126,112,87,21,148,26,85,170,37,149,162,147,51,149,106,170,182,149,38,177,183,204,204,94,38,191,71,46,171,211,181,74,242,139,107,127,153,17,245,94,155,36,210,189,163,233,245,31,155,237,174,43,59,138,185,173,83,111,230,103,61,90,245,162,218,141,89,173,56,147,44,225,171,175,209,159,29,101,141,46,168,175,188,180,44,111,108,165,134,174,169,99,213,73,31,155,161,113,94,84,233,41,86,168,245,230,76,220,248,171,136,93,82,140,107,213,75,93,20,217,63,17,250,25,221,90,191,255,0,74,165,255,0,152,203,206,183,255,0,245,138,120,255,0,141,31,159,231,121,117,241,177,95,19,91,29,155,119,191,83,82,143,81,190,248,251,152,124,101,199,106,155,74,62,108,176,180,94,227,241,53,250,57,84,162,255,0,237,105,191,180,147,30,109,15,251,218,127,110,228,126,123,180,191,188,242,171,127,139,175,164,244,253,227,55,237,110,174,37,47,154,226,171,251,205,151,143,13,31,117,239,162,246,173,79,255,0,49,146,242,219,250,225,250,159,2,141,213,196,103,165,122,171,230,123,77,156,177,191,188,85,235,98,238,186,194,88,253,227,211,250,146,240,137,175,188,98,47,152,254,163,183,209,163,226,46,254,241,82,160,213,221,124,185,172,191,49,234,118,54,215,215,110,233,230,234,191,213,255,0,120,201,120,52,250,239,150,253,80,236,213,35,228,244,111,239,28,171,127,139,175,162,120,253,227,211,83,56,95,222,56,82,255,0,23,95,92,231,247,143,81,248,143,171,118,99,130,118,96,249,221,59,235,190,202,107,226,171,97,241,230,51,98,149,229,206,37,254,34,183,254,118,63,17,239,123,51,166,12,124,183,232,120,202,87,151,78,43,55,53,190,159,231,102,197,165,221,204,146,82,184,170,245,123,205,143,197,53,235,59,61,136,161,174,26,60,253,59,138,254,124,151,157,83,24,254,102,79,137,175,231,255,0,157,83,255,0,51,38,43,208,118,107,156,19,179,60,26,116,106,212,113,142,106,77,254,102,227,148,149,20,212,158,115,234,76,21,83,211,102,78,220,45,140,161,41,118,173,94,254,166,116,219,125,217,215,82,200,107,143,176,56,38,141,172,47,68,113,203,146,96,224,237,215,220,173,96,231,73,103,99,22,150,88,195,92,11,69,130,127,83,149,165,151,162,49,150,207,238,21,134,95,168,109,250,153,79,120,152,240,65,27,211,83,29,87,186,41,120,32,197,231,141,204,49,46,94,112,114,114,78,31,220,65,199,40,188,101,164,113,85,112,165,111,86,189,70,161,78,148,92,231,39,180,98,183,102,211,217,157,119,91,255,0,241,91,173,255,0,255,0,62,191,255,0,195,101,193,163,71,196,254,27,173,20,233,245,107,89,57,45,34,229,135,250,51,203,248,179,197,221,6,207,162,223,83,167,119,74,181,213,90,82,80,140,22,117,219,86,124,10,117,106,121,171,231,150,254,165,174,219,167,44,182,254,231,167,143,130,122,172,115,242,89,211,74,109,119,205,173,19,121,193,142,61,196,247,49,61,78,15,255,217
     ];
     neko_u8
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_frame_directory_input() {
+        let dir = tempfile::tempdir().unwrap();
+        assert!(source_args(dir.path(), None).is_err());
+        for name in ["b.TIF", "a.TIF", "c.png", "notes.txt"] {
+            std::fs::write(dir.path().join(name), b"").unwrap();
+        }
+        let args = source_args(dir.path(), None).unwrap();
+        assert_eq!(args[..5], ["-f", "image2", "-pattern_type", "glob", "-i"]);
+        assert_eq!(args[5], format!("{}/*.TIF", dir.path().display()));
+        let video = Path::new("in.mp4");
+        assert_eq!(source_args(video, None).unwrap(), ["-i", "in.mp4"]);
+        assert_eq!(
+            source_args(video, Some(2)).unwrap(),
+            ["-threads", "2", "-i", "in.mp4"]
+        );
+    }
+
+    #[test]
+    fn test_work_dir_and_kept_frames() {
+        let root = tempfile::tempdir().unwrap();
+        let work = root.path().join("work/nested");
+        let mut workspace = Workspace {
+            dir: Some(work.clone()),
+            ..Workspace::default()
+        };
+        let dir = temp_dir(&workspace).unwrap();
+        let path = dir.path().to_path_buf();
+        assert_eq!(path.parent(), Some(work.as_path()));
+        drop(dir);
+        assert!(!path.exists());
+
+        workspace.keep_frames = true;
+        let kept = temp_dir(&workspace).unwrap().path().to_path_buf();
+        assert!(kept.is_dir());
+        remove_work_dir(&kept, &workspace).unwrap();
+        assert!(kept.is_dir());
+        remove_work_dir(&kept, &Workspace::default()).unwrap();
+        assert!(!kept.exists());
+    }
+
+    #[test]
+    fn test_container_tags() {
+        let config = FrameConfig::new(2, 4, 32, 30, 18).unwrap();
+        let (title, comment) = container_tags(&config, 312);
+        assert_eq!(
+            title,
+            format!("vstorage v{PROTOCOL_VERSION}, 312 frames, block=2 levels=4 ecc=32")
+        );
+        assert!(comment.contains("github.com/levish0/Vstorage"));
+    }
+
+    #[test]
+    fn test_intermediate_roundtrip() {
+        let dir = tempfile::tempdir().unwrap();
+        let img = image::RgbImage::from_fn(5, 3, |x, y| image::Rgb([x as u8, y as u8, 200]));
+        for format in [Intermediate::Png, Intermediate::Ppm, Intermediate::Bmp] {
+            assert_eq!(format.to_string().parse::<Intermediate>().unwrap(), format);
+            let path = dir.path().join(format!("frame.{}", format.extension()));
+            save_frame(&img, &path).unwrap();
+            assert_eq!(load_frame(&path).unwrap(), img, "{format}");
+        }
+        assert!("tiff".parse::<Intermediate>().is_err());
+    }
+
+    #[test]
+    fn test_codec_args_two_pass() {
+        let mut config = FrameConfig::new(2, 4, 32, 30, 18).unwrap();
+        let args = codec_args(&config, None);
+        assert!(args.windows(2).any(|w| w == ["-crf", "18"]));
+
+        config.codec = VideoCodec::Vp9;
+        config.target_bitrate = Some(8000);
+        let args = codec_args(&config, Some((1, Path::new("log"))));
+        assert!(args.windows(2).any(|w| w == ["-c:v", "libvpx-vp9"]));
+        assert!(args.windows(2).any(|w| w == ["-b:v", "8000k"]));
+        assert!(args.windows(2).any(|w| w == ["-pass", "1"]));
+        assert!(!args.iter().any(|a| a == "-crf"));
+    }
+
+    #[test]
+    fn test_parse_probe() {
+        let info = parse_probe(
+            "codec_name=h264\npix_fmt=yuv444p\navg_frame_rate=30000/1001\nnb_read_packets=42\n",
+        )
+        .unwrap();
+        assert_eq!(info.codec, VideoCodec::H264);
+        assert_eq!(info.pix_fmt, "yuv444p");
+        assert_eq!(info.fps, 30);
+        assert_eq!(info.frames, 42);
+        assert!(parse_probe("codec_name=hevc\n").is_err());
+        assert_eq!(
+            ffmpeg_frame(b"frame=  120 fps= 30 q=-1.0 size=  512kB time=00:00:04.00"),
+            Some(120)
+        );
+        assert_eq!(
+            ffmpeg_frame(b"Input #0, image2, from 'frames/%08d.png':"),
+            None
+        );
+    }
+}
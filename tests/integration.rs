@@ -1,3 +1,6 @@
+// The frame pipeline around the codec, which needs std
+#![cfg(feature = "std")]
+
 use vstorage::config::FrameConfig;
 use vstorage::header::FrameHeader;
 use vstorage::{config, crypto, ecc, frame, header};
@@ -13,35 +16,61 @@ fn test_roundtrip_no_ffmpeg() {
     let config = FrameConfig::new(2, 4, 32, 30, 18).unwrap();
 
     // ── Encode ──────────────────────────────────────────────────────
-    let (ciphertext, nonce, salt) = crypto::encrypt(&original, password).unwrap();
+    let (cipher, nonce, salt) =
+        crypto::FrameCipher::generate(password, config.kdf(), config.token(), None).unwrap();
     let file_size = original.len() as u64;
 
-    let max_raw = config.max_raw_per_frame();
-    let num_frames = (ciphertext.len() + max_raw - 1) / max_raw;
+    let per_frame = config.max_plain_per_frame(true);
+    let num_frames = original.len().div_ceil(per_frame);
 
     let mut frame_images = Vec::new();
 
     for i in 0..num_frames {
-        let start = i * max_raw;
-        let end = std::cmp::min(start + max_raw, ciphertext.len());
-        let frame_data = &ciphertext[start..end];
-
-        let rs_encoded = ecc::rs_encode(frame_data, config.ecc_len as usize, config.rs_data_len());
+        let start = i * per_frame;
+        let end = std::cmp::min(start + per_frame, original.len());
+        let frame_data = cipher
+            .encrypt_frame(i as u32, &original[start..end])
+            .unwrap();
+
+        let rs_encoded = ecc::rs_encode(
+            &frame_data,
+            config.ecc_len() as usize,
+            config.rs_data_len(),
+            config.worker_threads(),
+        );
         let data_hash: [u8; 32] = Sha256::digest(&rs_encoded).into();
 
         let hdr = FrameHeader {
             version: config::PROTOCOL_VERSION,
             frame_number: i as u32,
             total_frames: num_frames as u32,
-            block_size: config.block_size,
-            levels: config.levels,
+            block_size: config.block_size(),
+            levels: config.levels(),
             file_size,
             data_length: frame_data.len() as u32,
-            ecc_len: config.ecc_len,
+            ecc_len: config.ecc_len(),
             rs_data_len: config.rs_data_len() as u16,
             nonce,
             salt,
             data_sha256: data_hash,
+            kind: header::KIND_DATA,
+            payload: 0,
+            part: 0,
+            total_parts: 1,
+            set_id: [0; 16],
+            kdf: config.kdf(),
+            token: config.token(),
+            compression: config.compression(),
+            data_levels: config.levels(),
+            channel_levels: config.channel_levels(),
+            layout: config.layout(),
+            crc_map: config.crc_map(),
+            bottom_header: config.bottom_header(),
+            banner: config.banner(),
+            barcode: config.barcode(),
+            pilots: config.pilots(),
+            bin_centers: config.bin_centers(),
+            streamed: false,
         };
 
         let header_bytes = header::encode_header_triple(&hdr);
@@ -51,40 +80,42 @@ fn test_roundtrip_no_ffmpeg() {
 
     // ── Decode ──────────────────────────────────────────────────────
     let first_header_bytes =
-        frame::decode_header_area(&frame_images[0], config.block_size, config.levels);
+        frame::decode_header_area(&frame_images[0], config.block_size(), config.levels());
     let first_header = header::decode_header_triple(&first_header_bytes).unwrap();
 
     assert_eq!(first_header.total_frames, num_frames as u32);
     assert_eq!(first_header.file_size, file_size);
 
-    let mut recovered_ciphertext = Vec::new();
+    let decipher = crypto::FrameCipher::new(
+        password,
+        first_header.kdf,
+        first_header.token,
+        None,
+        &first_header.salt,
+        first_header.nonce,
+    )
+    .unwrap();
+    let mut plaintext = Vec::new();
 
     for (i, img) in frame_images.iter().enumerate() {
-        let header_bytes = frame::decode_header_area(img, config.block_size, config.levels);
+        let header_bytes = frame::decode_header_area(img, config.block_size(), config.levels());
         let frame_hdr = header::decode_header_triple(&header_bytes).unwrap();
         assert_eq!(frame_hdr.frame_number, i as u32);
 
-        let data_bytes = frame::decode_data_area(img, &config);
+        let data_bytes = frame::decode_data_area(img, &config, config.channel_levels(), 0);
         let data_len = frame_hdr.data_length as usize;
         let rs_decoded = ecc::rs_decode(
             &data_bytes,
-            config.ecc_len as usize,
+            config.ecc_len() as usize,
             config.rs_data_len(),
             data_len,
+            config.worker_threads(),
         )
         .unwrap();
 
-        recovered_ciphertext.extend_from_slice(&rs_decoded);
+        plaintext.extend_from_slice(&decipher.decrypt_frame(i as u32, &rs_decoded).unwrap());
     }
 
-    let plaintext = crypto::decrypt(
-        &recovered_ciphertext,
-        password,
-        &first_header.nonce,
-        &first_header.salt,
-    )
-    .unwrap();
-
     let recovered = &plaintext[..file_size as usize];
     assert_eq!(recovered, &original[..], "roundtrip mismatch!");
 }
@@ -98,33 +129,59 @@ fn test_roundtrip_with_noise() {
     let config = FrameConfig::new(4, 4, 32, 30, 18).unwrap();
 
     // Encode
-    let (ciphertext, nonce, salt) = crypto::encrypt(&original, password).unwrap();
+    let (cipher, nonce, salt) =
+        crypto::FrameCipher::generate(password, config.kdf(), config.token(), None).unwrap();
     let file_size = original.len() as u64;
-    let max_raw = config.max_raw_per_frame();
-    let num_frames = (ciphertext.len() + max_raw - 1) / max_raw;
+    let per_frame = config.max_plain_per_frame(true);
+    let num_frames = original.len().div_ceil(per_frame);
 
     let mut frame_images = Vec::new();
     for i in 0..num_frames {
-        let start = i * max_raw;
-        let end = std::cmp::min(start + max_raw, ciphertext.len());
-        let frame_data = &ciphertext[start..end];
-
-        let rs_encoded = ecc::rs_encode(frame_data, config.ecc_len as usize, config.rs_data_len());
+        let start = i * per_frame;
+        let end = std::cmp::min(start + per_frame, original.len());
+        let frame_data = cipher
+            .encrypt_frame(i as u32, &original[start..end])
+            .unwrap();
+
+        let rs_encoded = ecc::rs_encode(
+            &frame_data,
+            config.ecc_len() as usize,
+            config.rs_data_len(),
+            config.worker_threads(),
+        );
         let data_hash: [u8; 32] = Sha256::digest(&rs_encoded).into();
 
         let hdr = header::FrameHeader {
             version: config::PROTOCOL_VERSION,
             frame_number: i as u32,
             total_frames: num_frames as u32,
-            block_size: config.block_size,
-            levels: config.levels,
+            block_size: config.block_size(),
+            levels: config.levels(),
             file_size,
             data_length: frame_data.len() as u32,
-            ecc_len: config.ecc_len,
+            ecc_len: config.ecc_len(),
             rs_data_len: config.rs_data_len() as u16,
             nonce,
             salt,
             data_sha256: data_hash,
+            kind: header::KIND_DATA,
+            payload: 0,
+            part: 0,
+            total_parts: 1,
+            set_id: [0; 16],
+            kdf: config.kdf(),
+            token: config.token(),
+            compression: config.compression(),
+            data_levels: config.levels(),
+            channel_levels: config.channel_levels(),
+            layout: config.layout(),
+            crc_map: config.crc_map(),
+            bottom_header: config.bottom_header(),
+            banner: config.banner(),
+            barcode: config.barcode(),
+            pilots: config.pilots(),
+            bin_centers: config.bin_centers(),
+            streamed: false,
         };
 
         let header_bytes = header::encode_header_triple(&hdr);
@@ -149,30 +206,38 @@ fn test_roundtrip_with_noise() {
 
     // Decode
     let first_hdr_bytes =
-        frame::decode_header_area(&frame_images[0], config.block_size, config.levels);
+        frame::decode_header_area(&frame_images[0], config.block_size(), config.levels());
     let first_hdr = header::decode_header_triple(&first_hdr_bytes).unwrap();
 
-    let mut recovered_ct = Vec::new();
+    let decipher = crypto::FrameCipher::new(
+        password,
+        first_hdr.kdf,
+        first_hdr.token,
+        None,
+        &first_hdr.salt,
+        first_hdr.nonce,
+    )
+    .unwrap();
+    let mut plaintext = Vec::new();
     for (i, img) in frame_images.iter().enumerate() {
         if i >= first_hdr.total_frames as usize {
             break;
         }
-        let hdr_bytes = frame::decode_header_area(img, config.block_size, config.levels);
+        let hdr_bytes = frame::decode_header_area(img, config.block_size(), config.levels());
         let fhdr = header::decode_header_triple(&hdr_bytes).unwrap();
 
-        let data_bytes = frame::decode_data_area(img, &config);
+        let data_bytes = frame::decode_data_area(img, &config, config.channel_levels(), 0);
         let rs_decoded = ecc::rs_decode(
             &data_bytes,
-            config.ecc_len as usize,
+            config.ecc_len() as usize,
             config.rs_data_len(),
             fhdr.data_length as usize,
+            config.worker_threads(),
         )
         .unwrap();
-        recovered_ct.extend_from_slice(&rs_decoded);
+        plaintext.extend_from_slice(&decipher.decrypt_frame(i as u32, &rs_decoded).unwrap());
     }
 
-    let plaintext =
-        crypto::decrypt(&recovered_ct, password, &first_hdr.nonce, &first_hdr.salt).unwrap();
     let recovered = &plaintext[..file_size as usize];
     assert_eq!(recovered, &original[..], "noisy roundtrip failed!");
 }